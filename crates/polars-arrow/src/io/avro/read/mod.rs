@@ -8,7 +8,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_bail};
 
 mod nested;
 mod schema;
@@ -66,3 +66,55 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// Location of a single block inside an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInfo {
+    /// Byte offset of the block (including its header) from the start of the file.
+    pub offset: usize,
+    /// Number of rows in the block.
+    pub num_rows: usize,
+}
+
+/// Walks all blocks of an in-memory Avro file without decompressing or deserializing them.
+///
+/// `data_start` is the offset of the first block, i.e. the position right after the file
+/// metadata has been read.
+pub fn read_block_infos(
+    bytes: &[u8],
+    data_start: usize,
+    marker: &[u8; 16],
+) -> PolarsResult<Vec<BlockInfo>> {
+    let mut blocks = vec![];
+    let mut reader = &bytes[data_start..];
+
+    while !reader.is_empty() {
+        let offset = bytes.len() - reader.len();
+        let num_rows = util::zigzag_i64(&mut reader)?;
+        let num_bytes = util::zigzag_i64(&mut reader)?;
+
+        if num_rows < 0 || num_bytes < 0 {
+            polars_bail!(oos = "negative block size - corrupt avro file")
+        }
+        // Consistent with the block iterator, which stops at the first empty block.
+        if num_rows == 0 {
+            break;
+        }
+
+        let num_bytes = num_bytes as usize;
+        if reader.len() < num_bytes + marker.len() {
+            polars_bail!(oos = "avro block is truncated")
+        }
+        if &reader[num_bytes..num_bytes + marker.len()] != marker {
+            polars_bail!(oos = "avro block marker does not match the file marker")
+        }
+        reader = &reader[num_bytes + marker.len()..];
+
+        blocks.push(BlockInfo {
+            offset,
+            num_rows: num_rows as usize,
+        });
+    }
+
+    Ok(blocks)
+}
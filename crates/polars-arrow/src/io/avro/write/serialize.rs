@@ -107,6 +107,10 @@ fn list_required<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
         lengths,
         move |length, buf| {
             encode::zigzag_encode(length, buf).unwrap();
+            // An empty array is encoded as just the final zero-length block.
+            if length == 0 {
+                return;
+            }
             let mut rows = 0;
             while let Some(item) = inner.next() {
                 buf.extend_from_slice(item);
@@ -136,6 +140,9 @@ fn list_optional<'a, O: Offset>(array: &'a ListArray<O>, schema: &AvroSchema) ->
             if let Some(length) = length {
                 buf.push(IS_VALID);
                 encode::zigzag_encode(length, buf).unwrap();
+                if length == 0 {
+                    return;
+                }
                 let mut rows = 0;
                 while let Some(item) = inner.next() {
                     buf.extend_from_slice(item);
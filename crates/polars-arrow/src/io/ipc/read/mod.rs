@@ -28,7 +28,10 @@ pub use file::{
 use polars_utils::aliases::PlHashMap;
 pub use reader::FileReader;
 pub use schema::deserialize_schema;
-pub use stream::{StreamMetadata, StreamReader, StreamState, read_stream_metadata};
+pub use stream::{
+    StreamMetadata, StreamReader, StreamState, get_stream_row_count, read_stream_metadata,
+    skip_next_record_batch,
+};
 
 /// how dictionaries are tracked in this crate
 pub type Dictionaries = PlHashMap<i64, Box<dyn Array>>;
@@ -1,7 +1,7 @@
 use std::io::Read;
 
 use arrow_format::ipc::planus::ReadAsRoot;
-use polars_error::{PolarsError, PolarsResult, polars_bail, polars_err};
+use polars_error::{PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err};
 
 use super::super::CONTINUATION_MARKER;
 use super::common::*;
@@ -224,6 +224,94 @@ fn read_next<R: Read>(
     }
 }
 
+/// Reads the length of the next record batch of the stream without deserializing it, yielding
+/// `None` if the stream is done.
+///
+/// The bodies of the record batch and of any dictionary batches before it are skipped, so this
+/// only needs to be called after [`read_stream_metadata`].
+pub fn skip_next_record_batch<R: Read>(
+    reader: &mut R,
+    message_buffer: &mut Vec<u8>,
+) -> PolarsResult<Option<usize>> {
+    loop {
+        let mut meta_length: [u8; 4] = [0; 4];
+        match reader.read_exact(&mut meta_length) {
+            Ok(()) => (),
+            // EOF without the end-of-stream marker, see `read_next`.
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(PolarsError::from(e)),
+        }
+
+        if meta_length == CONTINUATION_MARKER {
+            reader.read_exact(&mut meta_length)?;
+        }
+        let meta_length: usize = i32::from_le_bytes(meta_length)
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::NegativeFooterLength))?;
+
+        if meta_length == 0 {
+            return Ok(None);
+        }
+
+        message_buffer.clear();
+        message_buffer.try_reserve(meta_length)?;
+        reader
+            .by_ref()
+            .take(meta_length as u64)
+            .read_to_end(message_buffer)?;
+
+        let message = arrow_format::ipc::MessageRef::read_as_root(message_buffer.as_ref())
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+
+        let header = message
+            .header()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferHeader(err)))?
+            .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageHeader))?;
+
+        let block_length: u64 = message
+            .body_length()
+            .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferBodyLength(err)))?
+            .try_into()
+            .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?;
+
+        let length = match header {
+            arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => Some(
+                batch
+                    .length()
+                    .map_err(|_| polars_err!(oos = OutOfSpecKind::MissingData))?
+                    .try_into()
+                    .map_err(|_| polars_err!(oos = OutOfSpecKind::UnexpectedNegativeInteger))?,
+            ),
+            arrow_format::ipc::MessageHeaderRef::DictionaryBatch(_) => None,
+            _ => polars_bail!(oos = OutOfSpecKind::UnexpectedMessageType),
+        };
+
+        let skipped = std::io::copy(
+            &mut reader.by_ref().take(block_length),
+            &mut std::io::sink(),
+        )?;
+        polars_ensure!(
+            skipped == block_length,
+            oos = "unexpected end of stream in the body of a message"
+        );
+
+        if length.is_some() {
+            return Ok(length);
+        }
+    }
+}
+
+/// Read the row count of a stream by summing the lengths of its record batches, this must be
+/// called after [`read_stream_metadata`].
+pub fn get_stream_row_count<R: Read>(reader: &mut R) -> PolarsResult<i64> {
+    let mut message_buffer = vec![];
+    let mut n_rows: i64 = 0;
+    while let Some(length) = skip_next_record_batch(reader, &mut message_buffer)? {
+        n_rows += length as i64;
+    }
+    Ok(n_rows)
+}
+
 /// Arrow Stream reader.
 ///
 /// An [`Iterator`] over an Arrow stream that yields a result of [`StreamState`]s.
@@ -281,6 +369,11 @@ impl<R: Read> StreamReader<R> {
         self.finished
     }
 
+    /// Consumes itself, returning the inner reader positioned after the last read message.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
     fn maybe_next(&mut self) -> PolarsResult<Option<StreamState>> {
         if self.finished {
             return Ok(None);
@@ -137,6 +137,16 @@ impl VarState {
             })
         }
     }
+
+    /// Returns the weight, mean and sum of squared deviations of this state.
+    pub fn into_parts(self) -> (f64, f64, f64) {
+        (self.weight, self.mean, self.dp)
+    }
+
+    /// Creates a state from the parts returned by [`VarState::into_parts`].
+    pub fn from_parts(weight: f64, mean: f64, dp: f64) -> Self {
+        Self { weight, mean, dp }
+    }
 }
 
 impl CovState {
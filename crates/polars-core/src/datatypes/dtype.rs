@@ -429,6 +429,54 @@ impl DataType {
         })
     }
 
+    /// Return whether the cast to `to` widens the type without losing values, as happens when
+    /// the schema of a dataset evolves.
+    ///
+    /// This allows integers to integers that can hold all their values, `Float32` to `Float64`,
+    /// decimals to decimals with at least as many integer and fractional digits, and structs
+    /// whose fields are widened, reordered, added or dropped.
+    pub fn is_lossless_widening_to(&self, to: &DataType) -> bool {
+        if self == to {
+            return true;
+        }
+
+        use DataType as D;
+        match (self, to) {
+            (D::Int8, D::Int16 | D::Int32 | D::Int64 | D::Int128)
+            | (D::Int16, D::Int32 | D::Int64 | D::Int128)
+            | (D::Int32, D::Int64 | D::Int128)
+            | (D::Int64, D::Int128)
+            | (
+                D::UInt8,
+                D::UInt16 | D::UInt32 | D::UInt64 | D::Int16 | D::Int32 | D::Int64 | D::Int128,
+            )
+            | (D::UInt16, D::UInt32 | D::UInt64 | D::Int32 | D::Int64 | D::Int128)
+            | (D::UInt32, D::UInt64 | D::Int64 | D::Int128)
+            | (D::UInt64, D::Int128)
+            | (D::Float32, D::Float64) => true,
+            #[cfg(feature = "dtype-decimal")]
+            (D::Decimal(l_precision, Some(l_scale)), D::Decimal(r_precision, Some(r_scale))) => {
+                let l_precision = l_precision.unwrap_or(38);
+                let r_precision = r_precision.unwrap_or(38);
+                l_scale <= r_scale
+                    && l_precision.saturating_sub(*l_scale) <= r_precision.saturating_sub(*r_scale)
+            },
+            (D::List(from), D::List(to)) => from.is_lossless_widening_to(to),
+            #[cfg(feature = "dtype-array")]
+            (D::Array(from, l_width), D::Array(to, r_width)) => {
+                l_width == r_width && from.is_lossless_widening_to(to)
+            },
+            #[cfg(feature = "dtype-struct")]
+            (D::Struct(l_fields), D::Struct(r_fields)) => l_fields.iter().all(|l| {
+                r_fields
+                    .iter()
+                    .find(|r| r.name() == l.name())
+                    .is_none_or(|r| l.dtype().is_lossless_widening_to(r.dtype()))
+            }),
+            _ => false,
+        }
+    }
+
     pub fn implode(self) -> DataType {
         DataType::List(Box::new(self))
     }
@@ -1168,4 +1216,45 @@ mod tests {
 
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn test_is_lossless_widening_to() {
+        use DataType as D;
+
+        assert!(D::Int32.is_lossless_widening_to(&D::Int64));
+        assert!(D::UInt32.is_lossless_widening_to(&D::Int64));
+        assert!(D::Float32.is_lossless_widening_to(&D::Float64));
+        assert!(!D::Int64.is_lossless_widening_to(&D::Int32));
+        assert!(!D::UInt64.is_lossless_widening_to(&D::Int64));
+        assert!(!D::Int32.is_lossless_widening_to(&D::Float64));
+        assert!(D::List(Box::new(D::Int16)).is_lossless_widening_to(&D::List(Box::new(D::Int32))));
+    }
+
+    #[cfg(all(feature = "dtype-decimal", feature = "dtype-struct"))]
+    #[test]
+    fn test_is_lossless_widening_to_nested() {
+        use DataType as D;
+
+        assert!(
+            D::Decimal(Some(10), Some(2)).is_lossless_widening_to(&D::Decimal(Some(12), Some(2)))
+        );
+        assert!(
+            D::Decimal(Some(10), Some(2)).is_lossless_widening_to(&D::Decimal(Some(12), Some(4)))
+        );
+        assert!(
+            !D::Decimal(Some(10), Some(2)).is_lossless_widening_to(&D::Decimal(Some(11), Some(4)))
+        );
+
+        let from = D::Struct(vec![
+            Field::new("a".into(), D::Int32),
+            Field::new("b".into(), D::String),
+        ]);
+        let to = D::Struct(vec![
+            Field::new("c".into(), D::Boolean),
+            Field::new("b".into(), D::String),
+            Field::new("a".into(), D::Int64),
+        ]);
+        assert!(from.is_lossless_widening_to(&to));
+        assert!(!to.is_lossless_widening_to(&from));
+    }
 }
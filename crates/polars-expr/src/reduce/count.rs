@@ -116,6 +116,18 @@ impl GroupedReduction for CountReduce {
         Ok(ca.into_series())
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        vec![native_state(core::mem::take(&mut self.counts))]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            counts: native_values(&state[0])?,
+            evicted_counts: Vec::new(),
+            include_nulls: self.include_nulls,
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
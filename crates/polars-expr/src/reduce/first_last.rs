@@ -130,6 +130,18 @@ where
         let ca: ChunkedArray<T> = v.into_iter().map(|(x, _s)| x).collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        let (values, seqs): (Vec<_>, Vec<_>) = v.into_iter().unzip();
+        let ca: ChunkedArray<T> = values.into_iter().collect_ca(PlSmallStr::EMPTY);
+        vec![ca.into_series(), native_state(seqs)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let values = s[0].unpack::<T>()?;
+        let seqs = native_values::<u64>(&s[1])?;
+        Ok(values.iter().zip(seqs).collect())
+    }
 }
 
 struct BinaryFirstLastReducer<P>(PhantomData<P>);
@@ -196,6 +208,22 @@ where
         let ca: BinaryChunked = v.into_iter().map(|(x, _s)| x).collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        let (values, seqs): (Vec<_>, Vec<_>) = v.into_iter().unzip();
+        let ca: BinaryChunked = values.into_iter().collect_ca(PlSmallStr::EMPTY);
+        vec![ca.into_series(), native_state(seqs)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let values = s[0].binary()?;
+        let seqs = native_values::<u64>(&s[1])?;
+        Ok(values
+            .iter()
+            .map(|x| x.map(<[u8]>::to_vec))
+            .zip(seqs)
+            .collect())
+    }
 }
 
 struct BoolFirstLastReducer<P>(PhantomData<P>);
@@ -247,6 +275,18 @@ where
         let ca: BooleanChunked = v.into_iter().map(|(x, _s)| x).collect_ca(PlSmallStr::EMPTY);
         Ok(ca.into_series())
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        let (values, seqs): (Vec<_>, Vec<_>) = v.into_iter().unzip();
+        let ca: BooleanChunked = values.into_iter().collect_ca(PlSmallStr::EMPTY);
+        vec![ca.into_series(), native_state(seqs)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let values = s[0].bool()?;
+        let seqs = native_values::<u64>(&s[1])?;
+        Ok(values.iter().zip(seqs).collect())
+    }
 }
 
 pub struct GenericFirstLastGroupedReduction<P> {
@@ -369,6 +409,23 @@ impl<P: Policy + 'static> GroupedReduction for GenericFirstLastGroupedReduction<
         }
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let seqs = core::mem::take(&mut self.seqs);
+        let values = self.finalize().unwrap();
+        vec![values, native_state(seqs)]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            values: state[0].iter().map(|v| v.into_static()).collect(),
+            seqs: native_values(&state[1])?,
+            evicted_values: Vec::new(),
+            evicted_seqs: Vec::new(),
+            policy: PhantomData,
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
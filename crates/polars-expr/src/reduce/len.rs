@@ -87,6 +87,17 @@ impl GroupedReduction for LenReduce {
         Ok(ca.into_series())
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        vec![native_state(core::mem::take(&mut self.groups))]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            groups: native_values(&state[0])?,
+            evictions: Vec::new(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
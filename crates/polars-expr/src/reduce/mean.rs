@@ -120,6 +120,22 @@ where
         assert!(m.is_none());
         Ok(finish_output(v, dtype))
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        let (sums, counts): (Vec<f64>, Vec<u64>) =
+            v.into_iter().map(|(s, c)| (s, c as u64)).unzip();
+        vec![native_state(sums), native_state(counts)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let sums = native_values::<f64>(&s[0])?;
+        let counts = native_values::<u64>(&s[1])?;
+        Ok(sums
+            .into_iter()
+            .zip(counts)
+            .map(|(s, c)| (s, c as usize))
+            .collect())
+    }
 }
 
 #[derive(Clone)]
@@ -165,4 +181,12 @@ impl Reducer for BoolMeanReducer {
             .collect_ca(PlSmallStr::EMPTY);
         Ok(ca.into_series())
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        count_pair_state(v)
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        count_pair_values(s)
+    }
 }
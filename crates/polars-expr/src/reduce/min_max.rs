@@ -171,6 +171,15 @@ impl<T: PolarsFloatType> NumericReduction for NanMax<T> {
     }
 }
 
+fn binary_state(v: Vec<Option<Vec<u8>>>) -> Vec<Series> {
+    let ca: BinaryChunked = v.into_iter().collect_ca(PlSmallStr::EMPTY);
+    vec![ca.into_series()]
+}
+
+fn binary_values(s: &Series) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    Ok(s.binary()?.iter().map(|x| x.map(<[u8]>::to_vec)).collect())
+}
+
 #[derive(Clone)]
 struct BinaryMinReducer;
 #[derive(Clone)]
@@ -220,6 +229,14 @@ impl Reducer for BinaryMinReducer {
         let ca: BinaryChunked = v.into_iter().collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        binary_state(v)
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        binary_values(&s[0])
+    }
 }
 
 impl Reducer for BinaryMaxReducer {
@@ -271,6 +288,14 @@ impl Reducer for BinaryMaxReducer {
         let ca: BinaryChunked = v.into_iter().collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        binary_state(v)
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        binary_values(&s[0])
+    }
 }
 
 #[derive(Default)]
@@ -390,6 +415,21 @@ impl GroupedReduction for BoolMinGroupedReduction {
         })
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let v = core::mem::take(&mut self.values);
+        let m = core::mem::take(&mut self.mask);
+        vec![bitmap_state(v), bitmap_state(m)]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            values: bitmap_values(&state[0])?,
+            mask: bitmap_values(&state[1])?,
+            evicted_values: BitmapBuilder::new(),
+            evicted_mask: BitmapBuilder::new(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -512,6 +552,21 @@ impl GroupedReduction for BoolMaxGroupedReduction {
         })
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let v = core::mem::take(&mut self.values);
+        let m = core::mem::take(&mut self.mask);
+        vec![bitmap_state(v), bitmap_state(m)]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            values: bitmap_values(&state[0])?,
+            mask: bitmap_values(&state[1])?,
+            evicted_values: BitmapBuilder::new(),
+            evicted_mask: BitmapBuilder::new(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -102,6 +102,16 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// After this operation the number of groups is reset to 0.
     fn finalize(&mut self) -> PolarsResult<Series>;
 
+    /// Returns the state of every group as Series, so it can be written to
+    /// disk and read back with [`GroupedReduction::load_state`].
+    ///
+    /// After this operation the number of groups is reset to 0.
+    fn take_state(&mut self) -> Vec<Series>;
+
+    /// Returns a new reduction with a group for every row of `state`, as
+    /// returned by [`GroupedReduction::take_state`].
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>>;
+
     /// Returns this GroupedReduction as a dyn Any.
     fn as_any(&self) -> &dyn Any;
 }
@@ -130,6 +140,61 @@ pub trait Reducer: Send + Sync + Clone + 'static {
         m: Option<Bitmap>,
         dtype: &DataType,
     ) -> PolarsResult<Series>;
+    /// Converts the states to Series, see [`GroupedReduction::take_state`].
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series>;
+    /// Reads back the states written by [`Reducer::states_to_series`].
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>>;
+}
+
+/// Stores native reduction states as a Series.
+fn native_state<T: NumericNative>(v: Vec<T>) -> Series {
+    let arr = Box::new(PrimitiveArray::from_vec(v));
+    unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            PlSmallStr::EMPTY,
+            vec![arr],
+            &T::PolarsType::get_dtype(),
+        )
+    }
+}
+
+/// Reads back native reduction states stored with [`native_state`].
+fn native_values<T: NumericNative>(s: &Series) -> PolarsResult<Vec<T>> {
+    s.unpack::<T::PolarsType>()?;
+    let mut out = Vec::with_capacity(s.len());
+    for arr in s.chunks() {
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        out.extend_from_slice(arr.values());
+    }
+    Ok(out)
+}
+
+/// Stores (true count, non-null count) reduction states as two Series.
+fn count_pair_state(v: Vec<(usize, usize)>) -> Vec<Series> {
+    let (trues, counts): (Vec<u64>, Vec<u64>) =
+        v.into_iter().map(|(t, c)| (t as u64, c as u64)).unzip();
+    vec![native_state(trues), native_state(counts)]
+}
+
+/// Reads back reduction states stored with [`count_pair_state`].
+fn count_pair_values(s: &[Series]) -> PolarsResult<Vec<(usize, usize)>> {
+    let trues = native_values::<u64>(&s[0])?;
+    let counts = native_values::<u64>(&s[1])?;
+    Ok(trues
+        .into_iter()
+        .zip(counts)
+        .map(|(t, c)| (t as usize, c as usize))
+        .collect())
+}
+
+/// Stores a bitmap of reduction states as a Series.
+fn bitmap_state(bm: MutableBitmap) -> Series {
+    BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bm.freeze()).into_series()
+}
+
+/// Reads back a bitmap of reduction states stored with [`bitmap_state`].
+fn bitmap_values(s: &Series) -> PolarsResult<MutableBitmap> {
+    Ok(s.bool()?.into_no_null_iter().collect())
 }
 
 pub trait NumericReduction: Send + Sync + 'static {
@@ -203,6 +268,14 @@ impl<R: NumericReduction> Reducer for NumReducer<R> {
         let arr = Box::new(PrimitiveArray::<Self::Value>::from_vec(v).with_validity(m));
         Ok(unsafe { Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], dtype) })
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        vec![native_state(v)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        native_values(&s[0])
+    }
 }
 
 pub struct VecGroupedReduction<R: Reducer> {
@@ -335,6 +408,20 @@ where
         self.reducer.finish(v, None, &self.in_dtype)
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let v = core::mem::take(&mut self.values);
+        self.reducer.states_to_series(v)
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            values: self.reducer.states_from_series(state)?,
+            evicted_values: Vec::new(),
+            in_dtype: self.in_dtype.clone(),
+            reducer: self.reducer.clone(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -474,6 +561,26 @@ where
         self.reducer.finish(v, Some(m.freeze()), &self.in_dtype)
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let v = core::mem::take(&mut self.values);
+        let m = core::mem::take(&mut self.mask);
+        let mut state = self.reducer.states_to_series(v);
+        state.push(bitmap_state(m));
+        state
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        let (mask, values) = state.split_last().unwrap();
+        Ok(Box::new(Self {
+            values: self.reducer.states_from_series(values)?,
+            mask: bitmap_values(mask)?,
+            evicted_values: Vec::new(),
+            evicted_mask: BitmapBuilder::new(),
+            in_dtype: self.in_dtype.clone(),
+            reducer: self.reducer.clone(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -553,6 +660,19 @@ impl GroupedReduction for NullGroupedReduction {
         ))
     }
 
+    fn take_state(&mut self) -> Vec<Series> {
+        let num_groups = core::mem::replace(&mut self.num_groups, 0);
+        vec![Series::new_null(PlSmallStr::EMPTY, num_groups as usize)]
+    }
+
+    fn load_state(&self, state: &[Series]) -> PolarsResult<Box<dyn GroupedReduction>> {
+        Ok(Box::new(Self {
+            num_groups: state[0].len() as IdxSize,
+            num_evictions: 0,
+            dtype: self.dtype.clone(),
+        }))
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -124,6 +124,14 @@ where
             Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], &out_dtype(dtype))
         })
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        vec![native_state(v)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        native_values(&s[0])
+    }
 }
 
 #[derive(Clone)]
@@ -162,4 +170,12 @@ impl Reducer for BoolSumReducer {
         assert!(dtype == &DataType::Boolean);
         Ok(IdxCa::from_vec(PlSmallStr::EMPTY, v).into_series())
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        vec![native_state(v)]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        native_values(&s[0])
+    }
 }
@@ -103,6 +103,35 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
             .collect_ca(PlSmallStr::EMPTY);
         Ok(ca.into_series())
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        let mut weights = Vec::with_capacity(v.len());
+        let mut means = Vec::with_capacity(v.len());
+        let mut dps = Vec::with_capacity(v.len());
+        for s in v {
+            let (weight, mean, dp) = s.into_parts();
+            weights.push(weight);
+            means.push(mean);
+            dps.push(dp);
+        }
+        vec![
+            native_state(weights),
+            native_state(means),
+            native_state(dps),
+        ]
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        let weights = native_values::<f64>(&s[0])?;
+        let means = native_values::<f64>(&s[1])?;
+        let dps = native_values::<f64>(&s[2])?;
+        Ok(weights
+            .into_iter()
+            .zip(means)
+            .zip(dps)
+            .map(|((weight, mean), dp)| VarState::from_parts(weight, mean, dp))
+            .collect())
+    }
 }
 
 #[derive(Clone)]
@@ -161,4 +190,12 @@ impl Reducer for BoolVarStdReducer {
             .collect_ca(PlSmallStr::EMPTY);
         Ok(ca.into_series())
     }
+
+    fn states_to_series(&self, v: Vec<Self::Value>) -> Vec<Series> {
+        count_pair_state(v)
+    }
+
+    fn states_from_series(&self, s: &[Series]) -> PolarsResult<Vec<Self::Value>> {
+        count_pair_values(s)
+    }
 }
@@ -1,13 +1,62 @@
-use std::io::{Read, Seek};
+use std::io::{Cursor, Read, Seek};
 
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::read::BlockInfo;
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvroScanOptions;
+
+/// Header information of an in-memory Avro file.
+#[derive(Debug, Clone)]
+pub struct AvroFileInfo {
+    pub metadata: FileMetadata,
+    pub schema: ArrowSchemaRef,
+    /// Byte offset of the first block.
+    pub data_start: usize,
+}
+
+impl AvroFileInfo {
+    /// Read the header of an Avro file. This does not touch any of the blocks.
+    pub fn try_from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut reader = Cursor::new(bytes);
+        let metadata =
+            avro::avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+        let schema = Arc::new(read::infer_schema(&metadata.record)?);
+
+        Ok(Self {
+            metadata,
+            schema,
+            data_start: reader.position() as usize,
+        })
+    }
+
+    /// Locate all blocks in `bytes`, which must be the same file this header was read from.
+    pub fn block_infos(&self, bytes: &[u8]) -> PolarsResult<Vec<BlockInfo>> {
+        read::read_block_infos(bytes, self.data_start, &self.metadata.marker)
+    }
+}
+
+/// Count the rows of an in-memory Avro file from the block headers, without decoding any data.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let file_info = AvroFileInfo::try_from_bytes(bytes)?;
+    Ok(file_info
+        .block_infos(bytes)?
+        .iter()
+        .map(|block| block.num_rows)
+        .sum())
+}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
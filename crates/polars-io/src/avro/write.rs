@@ -2,13 +2,35 @@ use std::io::Write;
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvroWriterOptions {
+    /// Block compression.
+    #[cfg_attr(feature = "serde", serde(with = "serde_compression"))]
+    pub compression: Option<AvroCompression>,
+    /// Name of the top-level Avro record.
+    pub name: PlSmallStr,
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_compression(self.compression)
+            .with_name(self.name.to_string())
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -49,6 +71,17 @@ where
         self.name = name;
         self
     }
+
+    /// Write the file header and return a writer that can write [`DataFrame`]s batch by batch.
+    pub fn batched(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let encoder = AvroBlockEncoder::new(schema, &self.name, self.compression)?;
+        encoder.write_header(&mut self.writer)?;
+
+        Ok(BatchedWriter {
+            writer: self.writer,
+            encoder,
+        })
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -64,39 +97,136 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let mut encoder = AvroBlockEncoder::new(df.schema(), &self.name, self.compression)?;
+        encoder.write_header(&mut self.writer)?;
+        encoder.write_blocks(df, &mut self.writer)
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    encoder: AvroBlockEncoder,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the avro writer. Every chunk of the [`DataFrame`] is written as a
+    /// separate block.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.encoder.write_blocks(df, &mut self.writer)
+    }
+
+    /// Flush the underlying writer. Avro files have no footer, so there is nothing else to write.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Encodes [`DataFrame`]s into Avro blocks of a fixed schema.
+///
+/// The header and the blocks can be written separately, which allows encoding blocks in
+/// parallel and concatenating them afterwards.
+#[derive(Clone)]
+pub struct AvroBlockEncoder {
+    record: Record,
+    compression: Option<AvroCompression>,
+    compat_level: CompatLevel,
+
+    // Scratch space, re-used between blocks.
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl AvroBlockEncoder {
+    pub fn new(
+        schema: &Schema,
+        name: &str,
+        compression: Option<AvroCompression>,
+    ) -> PolarsResult<Self> {
+        let compat_level = CompatLevel::oldest();
+        let schema = schema_to_arrow_checked(schema, compat_level, "avro")?;
+        let record = write::to_record(&schema, name.to_string())?;
+
+        Ok(Self {
+            record,
+            compression,
+            compat_level,
+            block: Block::default(),
+            compressed_block: CompressedBlock::default(),
+        })
+    }
+
+    /// Write the magic bytes, the schema and the sync marker.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        avro_schema::write::write_metadata(writer, self.record.clone(), self.compression)
+            .map_err(to_compute_err)
+    }
+
+    /// Write every non-empty chunk of `df` as a single block.
+    pub fn write_blocks<W: Write>(&mut self, df: &DataFrame, writer: &mut W) -> PolarsResult<()> {
+        for chunk in df.iter_chunks(self.compat_level, true) {
+            // An empty block marks the end of the file for readers.
+            if chunk.is_empty() {
+                continue;
+            }
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+            self.block.number_of_rows = chunk.len();
+            self.block.data.clear();
+            write::serialize(&mut serializers, &mut self.block);
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0;
+            let _was_compressed = avro_schema::write::compress(
+                &mut self.block,
+                &mut self.compressed_block,
+                self.compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
+            avro_schema::write::write_block(writer, &self.compressed_block)
                 .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
         }
 
         Ok(())
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_compression {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::AvroCompression;
+
+    #[derive(Serialize, Deserialize)]
+    enum Repr {
+        Deflate,
+        Snappy,
+    }
+
+    pub fn serialize<S: Serializer>(
+        compression: &Option<AvroCompression>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        compression
+            .map(|c| match c {
+                AvroCompression::Deflate => Repr::Deflate,
+                AvroCompression::Snappy => Repr::Snappy,
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<AvroCompression>, D::Error> {
+        Ok(Option::<Repr>::deserialize(deserializer)?.map(|r| match r {
+            Repr::Deflate => AvroCompression::Deflate,
+            Repr::Snappy => AvroCompression::Snappy,
+        }))
+    }
+}
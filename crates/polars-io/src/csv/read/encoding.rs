@@ -0,0 +1,344 @@
+use encoding_rs::{Decoder, DecoderResult, Encoding};
+use polars_core::prelude::*;
+use polars_utils::mmap::MemSlice;
+
+use super::options::{CsvEncoding, CsvReadOptions};
+use super::parser::CountLines;
+
+/// Number of input bytes handed to the decoder at once. This bounds the amount of scratch
+/// output we need to reserve, independent of the file size.
+const TRANSCODE_CHUNK_SIZE: usize = 1 << 16;
+
+enum Transcoder {
+    /// `encoding_rs` maps the "latin1" label to Windows-1252, so ISO-8859-1 is handled separately.
+    Latin1,
+    Decoder(Decoder),
+}
+
+/// Incrementally transcodes CSV input to UTF-8, one chunk at a time. Multi-byte sequences that
+/// are split over chunk boundaries are carried over by the decoder.
+struct CsvTranscoder {
+    encoding: CsvEncoding,
+    inner: Transcoder,
+    // Number of input bytes consumed so far, used for error reporting.
+    offset: usize,
+}
+
+impl CsvTranscoder {
+    /// Returns `None` if input in `encoding` can be parsed without transcoding. `head` is the
+    /// start of the input, it is used to look for a byte order mark.
+    fn new(encoding: CsvEncoding, head: &[u8]) -> Option<Self> {
+        let decoder = |encoding: &'static Encoding| {
+            Transcoder::Decoder(encoding.new_decoder_with_bom_removal())
+        };
+
+        let inner = match encoding {
+            CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => return None,
+            CsvEncoding::Latin1 => Transcoder::Latin1,
+            CsvEncoding::Windows1252 => decoder(encoding_rs::WINDOWS_1252),
+            CsvEncoding::ShiftJis => decoder(encoding_rs::SHIFT_JIS),
+            CsvEncoding::Utf16Le => decoder(encoding_rs::UTF_16LE),
+            CsvEncoding::Utf16Be => decoder(encoding_rs::UTF_16BE),
+            CsvEncoding::Auto => match Encoding::for_bom(head) {
+                // A UTF-8 BOM is skipped by the parser.
+                Some((encoding, _)) if encoding != encoding_rs::UTF_8 => decoder(encoding),
+                _ => return None,
+            },
+        };
+
+        Some(Self {
+            encoding,
+            inner,
+            offset: 0,
+        })
+    }
+
+    /// Transcode `chunk` and append the UTF-8 output to `out`. `last` must be set on the final
+    /// chunk so that a truncated trailing sequence is reported.
+    fn transcode_chunk(&mut self, chunk: &[u8], last: bool, out: &mut Vec<u8>) -> PolarsResult<()> {
+        let decoder = match &mut self.inner {
+            Transcoder::Latin1 => {
+                let start = out.len();
+                out.resize(start + 2 * chunk.len(), 0);
+                let written = encoding_rs::mem::convert_latin1_to_utf8(chunk, &mut out[start..]);
+                out.truncate(start + written);
+                self.offset += chunk.len();
+                return Ok(());
+            },
+            Transcoder::Decoder(decoder) => decoder,
+        };
+
+        let mut src = chunk;
+        loop {
+            let max_len = decoder
+                .max_utf8_buffer_length_without_replacement(src.len())
+                .ok_or_else(|| polars_err!(ComputeError: "CSV chunk too large to transcode"))?;
+            let start = out.len();
+            out.resize(start + max_len, 0);
+
+            let (result, read, written) =
+                decoder.decode_to_utf8_without_replacement(src, &mut out[start..], last);
+            out.truncate(start + written);
+            src = &src[read..];
+            self.offset += read;
+
+            match result {
+                DecoderResult::InputEmpty => return Ok(()),
+                DecoderResult::OutputFull => continue,
+                DecoderResult::Malformed(..) => polars_bail!(
+                    ComputeError: "invalid {:?} sequence before byte offset {}",
+                    self.encoding, self.offset
+                ),
+            }
+        }
+    }
+}
+
+/// Transcodes CSV input to UTF-8 in pieces that end on a line ending, so that the input can be
+/// parsed while it is being transcoded instead of after the whole file is transcoded. The decoder
+/// state is carried from one piece to the next.
+pub struct LineAlignedTranscoder {
+    transcoder: CsvTranscoder,
+    input: MemSlice,
+    // Number of input bytes transcoded so far.
+    position: usize,
+    eol_char: u8,
+    // Output after the last line ending, it is prepended to the next piece.
+    remainder: Vec<u8>,
+}
+
+impl LineAlignedTranscoder {
+    /// Returns `None` if input in `encoding` can be parsed without transcoding.
+    pub fn new(input: MemSlice, encoding: CsvEncoding, eol_char: u8) -> Option<Self> {
+        let transcoder = CsvTranscoder::new(encoding, &input)?;
+
+        Some(Self {
+            transcoder,
+            input,
+            position: 0,
+            eol_char,
+            remainder: vec![],
+        })
+    }
+
+    /// Length of the input before transcoding.
+    pub fn input_len(&self) -> usize {
+        self.input.len()
+    }
+
+    /// Transcodes at least `min_len` more input bytes and returns the output up to and including
+    /// the last line ending. If a piece has no line ending, more input is transcoded until it has
+    /// one. The last piece holds all remaining output, and `None` is returned after it.
+    pub fn next_piece(&mut self, min_len: usize) -> PolarsResult<Option<Vec<u8>>> {
+        let mut out = std::mem::take(&mut self.remainder);
+        // The remainder never contains a line ending.
+        let mut searched = out.len();
+
+        loop {
+            if self.position == self.input.len() {
+                return Ok((!out.is_empty()).then_some(out));
+            }
+
+            let end = self.input.len().min(self.position + min_len.max(1));
+            let last = end == self.input.len();
+            let mut chunks = self.input[self.position..end]
+                .chunks(TRANSCODE_CHUNK_SIZE)
+                .peekable();
+            while let Some(chunk) = chunks.next() {
+                self.transcoder.transcode_chunk(
+                    chunk,
+                    last && chunks.peek().is_none(),
+                    &mut out,
+                )?;
+            }
+            self.position = end;
+
+            if last {
+                continue;
+            }
+
+            if let Some(i) = memchr::memrchr(self.eol_char, &out[searched..]) {
+                self.remainder = out.split_off(searched + i + 1);
+                return Ok(Some(out));
+            }
+            searched = out.len();
+        }
+    }
+}
+
+/// Transcode `bytes` to UTF-8 if `encoding` requires it, otherwise simply return it.
+/// An `out` vec must be given for ownership of the transcoded data.
+///
+/// If `n_lines` is set, transcoding stops once at least that many lines, as counted by the given
+/// [`CountLines`], are transcoded.
+pub fn maybe_transcode_bytes<'a>(
+    bytes: &'a [u8],
+    encoding: CsvEncoding,
+    n_lines: Option<(usize, &CountLines)>,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    let Some(mut transcoder) = CsvTranscoder::new(encoding, bytes) else {
+        return Ok(bytes);
+    };
+
+    out.reserve(match n_lines {
+        Some(_) => TRANSCODE_CHUNK_SIZE,
+        None => bytes.len(),
+    });
+
+    // Start of the first line that is not complete yet, and the number of complete lines.
+    let mut line_start = 0;
+    let mut n_lines_seen = 0;
+
+    let mut chunks = bytes.chunks(TRANSCODE_CHUNK_SIZE).peekable();
+    while let Some(chunk) = chunks.next() {
+        transcoder.transcode_chunk(chunk, chunks.peek().is_none(), out)?;
+
+        if let Some((n_lines, line_counter)) = n_lines {
+            let (count, position) = line_counter.count(&out[line_start..]);
+            if count > 0 {
+                n_lines_seen += count;
+                line_start += position + 1;
+            }
+            if n_lines_seen >= n_lines {
+                // Drop the trailing partial line, it may end inside a quoted field.
+                if chunks.peek().is_some() {
+                    out.truncate(line_start);
+                }
+                break;
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// Transcode the start of `bytes` that schema inference with `options` looks at, see
+/// [`maybe_transcode_bytes`].
+pub fn maybe_transcode_schema_inference_bytes<'a>(
+    bytes: &'a [u8],
+    options: &CsvReadOptions,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    let parse_options = &options.parse_options;
+    let line_counter = CountLines::new(
+        parse_options.quote_char,
+        parse_options.escape_char,
+        parse_options.eol_char,
+    );
+    // Comment lines are skipped by the inference, so we cannot tell how many lines it needs.
+    let n_lines = options
+        .infer_schema_length
+        .filter(|_| parse_options.comment_prefix.is_none())
+        .map(|n| {
+            [
+                options.skip_lines,
+                options.skip_rows,
+                options.has_header as usize,
+                options.skip_rows_after_header,
+                n,
+            ]
+            .into_iter()
+            .fold(1, usize::saturating_add)
+        });
+
+    maybe_transcode_bytes(
+        bytes,
+        parse_options.encoding,
+        n_lines.map(|n| (n, &line_counter)),
+        out,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn transcode(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<String> {
+        let mut out = vec![];
+        let bytes = maybe_transcode_bytes(bytes, encoding, None, &mut out)?;
+        Ok(String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn test_transcode_single_byte() {
+        let bytes = b"name,city\nJos\xe9,K\xf6ln\n\x80,\x9f\n";
+        assert_eq!(
+            transcode(bytes, CsvEncoding::Latin1).unwrap(),
+            "name,city\nJosé,Köln\n\u{80},\u{9f}\n"
+        );
+        assert_eq!(
+            transcode(bytes, CsvEncoding::Windows1252).unwrap(),
+            "name,city\nJosé,Köln\n€,Ÿ\n"
+        );
+    }
+
+    #[test]
+    fn test_transcode_utf16_bom() {
+        let text = "a,b\nü,日本\n";
+        let le = [0xFF, 0xFE]
+            .into_iter()
+            .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect::<Vec<_>>();
+        let be = [0xFE, 0xFF]
+            .into_iter()
+            .chain(text.encode_utf16().flat_map(u16::to_be_bytes))
+            .collect::<Vec<_>>();
+
+        assert_eq!(transcode(&le, CsvEncoding::Utf16Le).unwrap(), text);
+        assert_eq!(transcode(&be, CsvEncoding::Utf16Be).unwrap(), text);
+        assert_eq!(transcode(&le, CsvEncoding::Auto).unwrap(), text);
+        assert_eq!(transcode(&be, CsvEncoding::Auto).unwrap(), text);
+        // Without a BOM `Auto` leaves the input untouched.
+        assert_eq!(transcode(text.as_bytes(), CsvEncoding::Auto).unwrap(), text);
+    }
+
+    #[test]
+    fn test_transcode_across_chunks() {
+        // Place a two-byte Shift_JIS character on the chunk boundary.
+        let mut bytes = vec![b'a'; TRANSCODE_CHUNK_SIZE - 1];
+        bytes.extend_from_slice(b"\x93\xfa\n");
+        let out = transcode(&bytes, CsvEncoding::ShiftJis).unwrap();
+        assert!(out.ends_with("a日\n"));
+        assert_eq!(out.len(), TRANSCODE_CHUNK_SIZE - 1 + "日\n".len());
+
+        // A truncated trailing sequence is an error.
+        assert!(transcode(b"a,\x93", CsvEncoding::ShiftJis).is_err());
+    }
+
+    #[test]
+    fn test_transcode_line_aligned() {
+        // Lines with a two-byte Shift_JIS character, the pieces end in the middle of it.
+        let line = b"\x93\xfa,abc\n";
+        let bytes = line.repeat(10);
+        let input = MemSlice::from_vec(bytes.clone());
+        let mut transcoder =
+            LineAlignedTranscoder::new(input, CsvEncoding::ShiftJis, b'\n').unwrap();
+
+        let mut pieces = vec![];
+        while let Some(piece) = transcoder.next_piece(line.len() + 1).unwrap() {
+            assert!(piece.ends_with(b"\n"));
+            pieces.push(String::from_utf8(piece).unwrap());
+        }
+        assert!(pieces.len() > 1);
+        assert_eq!(pieces.concat(), "日,abc\n".repeat(10));
+
+        // Transcoding stops once enough lines are transcoded.
+        let bytes = line.repeat(TRANSCODE_CHUNK_SIZE);
+        let line_counter = CountLines::new(Some(b'"'), None, b'\n');
+        let mut out = vec![];
+        maybe_transcode_bytes(
+            &bytes,
+            CsvEncoding::ShiftJis,
+            Some((2, &line_counter)),
+            &mut out,
+        )
+        .unwrap();
+        assert!(out.starts_with("日,abc\n日,abc\n".as_bytes()));
+        assert!(out.len() < bytes.len());
+        // The output ends with a complete line.
+        assert!(out.ends_with(b"\n"));
+    }
+}
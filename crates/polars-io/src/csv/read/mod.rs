@@ -17,6 +17,7 @@
 //! ```
 
 pub mod buffer;
+mod encoding;
 mod options;
 mod parser;
 mod read_impl;
@@ -25,16 +26,23 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+pub use encoding::{maybe_transcode_bytes, maybe_transcode_schema_inference_bytes};
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
 pub use schema_inference::infer_file_schema;
 
+pub use crate::utils::rejected_rows::{RejectedRows, RejectedRowsOptions};
+
 pub mod _csv_read_internal {
     pub use super::buffer::validate_utf8;
+    pub use super::encoding::LineAlignedTranscoder;
     pub use super::options::NullValuesCompiled;
     pub use super::parser::CountLines;
-    pub use super::read_impl::{cast_columns, find_starting_point, read_chunk};
+    pub use super::read_impl::{
+        cast_columns, find_starting_point, read_chunk, read_chunk_rejecting,
+    };
     pub use super::reader::prepare_csv_schema;
+    pub use crate::utils::rejected_rows::LineCounter;
 }
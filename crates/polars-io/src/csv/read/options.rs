@@ -10,6 +10,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::RowIndex;
+use crate::utils::rejected_rows::RejectedRowsOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -41,13 +42,18 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Writes rows that fail to parse to files instead of raising an error.
+    pub rejected_rows: Option<RejectedRowsOptions>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParseOptions {
     pub separator: u8,
+    /// Separator of more than one byte. If set, this is used instead of `separator`.
+    pub multi_byte_separator: Option<PlSmallStr>,
     pub quote_char: Option<u8>,
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
@@ -86,6 +92,7 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            rejected_rows: None,
         }
     }
 }
@@ -95,7 +102,9 @@ impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
             separator: b',',
+            multi_byte_separator: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
             encoding: Default::default(),
             null_values: None,
@@ -245,6 +254,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// Write rows that are ragged or contain values that cannot be parsed to the files described
+    /// by `rejected_rows` instead of raising an error. Takes precedence over `ignore_errors`.
+    pub fn with_rejected_rows(mut self, rejected_rows: Option<RejectedRowsOptions>) -> Self {
+        self.rejected_rows = rejected_rows;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -261,6 +277,19 @@ impl CsvParseOptions {
     /// is most often a comma ','.
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.separator = separator;
+        self.multi_byte_separator = None;
+        self
+    }
+
+    /// Separate fields by a sequence of bytes, e.g. `||`. A separator of a single byte is the
+    /// same as [`CsvParseOptions::with_separator`].
+    pub fn with_multi_byte_separator(mut self, separator: &str) -> Self {
+        match separator.as_bytes() {
+            [separator] => return self.with_separator(*separator),
+            [first, ..] => self.separator = *first,
+            [] => {},
+        }
+        self.multi_byte_separator = Some(separator.into());
         self
     }
 
@@ -271,6 +300,15 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set the character used to escape the byte that follows it, e.g. `\` for files written
+    /// by MySQL or Hive. An escaped separator, quote or end-of-line character is read as part of
+    /// the field; the escape sequences `n`, `r`, `t` and `0` stand for a line feed, carriage
+    /// return, tab and NUL byte. Escaping is disabled by default.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the character used to indicate an end-of-line (eol).
     pub fn with_eol_char(mut self, eol_char: u8) -> Self {
         self.eol_char = eol_char;
@@ -326,6 +364,19 @@ impl CsvParseOptions {
         self.decimal_comma = decimal_comma;
         self
     }
+
+    /// The bytes that separate fields.
+    pub fn separator_bytes(&self) -> &[u8] {
+        match &self.multi_byte_separator {
+            Some(separator) => separator.as_bytes(),
+            None => std::slice::from_ref(&self.separator),
+        }
+    }
+
+    /// Whether fields can be split with the single-byte fast paths.
+    pub(crate) fn is_simple_dialect(&self) -> bool {
+        self.multi_byte_separator.is_none() && self.escape_char.is_none()
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
@@ -336,6 +387,19 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1, every byte maps to the code point of the same value.
+    Latin1,
+    /// Windows-1252, the superset of Latin-1 used by most Western Windows software.
+    Windows1252,
+    /// Shift_JIS.
+    ShiftJis,
+    /// UTF-16 little endian. A leading byte order mark is skipped.
+    Utf16Le,
+    /// UTF-16 big endian. A leading byte order mark is skipped.
+    Utf16Be,
+    /// Detect UTF-8 or UTF-16 from the byte order mark, falling back to UTF-8 when
+    /// there is none.
+    Auto,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -418,7 +482,7 @@ impl NullValuesCompiled {
     /// # Safety
     ///
     /// The caller must ensure that `index` is in bounds
-    pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
+    pub(crate) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
         use NullValuesCompiled::*;
         match self {
             AllColumnsSingle(v) => v.as_bytes() == field,
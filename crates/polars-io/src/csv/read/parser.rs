@@ -13,7 +13,7 @@ use super::CsvParseOptions;
 use super::buffer::Buffer;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
-use super::utils::get_file_chunks;
+use super::utils::{get_file_chunks, unescape_field};
 use crate::path_utils::is_cloud_url;
 use crate::utils::compression::maybe_decompress_bytes;
 
@@ -23,6 +23,7 @@ pub fn count_rows(
     path: &Path,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -47,6 +48,7 @@ pub fn count_rows(
         reader_bytes,
         separator,
         quote_char,
+        escape_char,
         comment_prefix,
         eol_char,
         has_header,
@@ -59,6 +61,7 @@ pub fn count_rows_from_slice_par(
     mut bytes: &[u8],
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -89,8 +92,17 @@ pub fn count_rows_from_slice_par(
     })
     .unwrap_or(1);
 
-    if n_threads == 1 {
-        return count_rows_from_slice(bytes, quote_char, comment_prefix, eol_char, has_header);
+    // The chunk boundaries are found without looking at escape characters, so an escaped line
+    // ending could be taken as the end of a row.
+    if n_threads == 1 || escape_char.is_some() {
+        return count_rows_from_slice(
+            bytes,
+            quote_char,
+            escape_char,
+            comment_prefix,
+            eol_char,
+            has_header,
+        );
     }
 
     let file_chunks: Vec<(usize, usize)> =
@@ -100,11 +112,11 @@ pub fn count_rows_from_slice_par(
         let bytes = &bytes[start..stop];
 
         if comment_prefix.is_some() {
-            SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+            SplitLines::new(bytes, quote_char, None, eol_char, comment_prefix)
                 .filter(|line| !is_comment_line(line, comment_prefix))
                 .count()
         } else {
-            CountLines::new(quote_char, eol_char).count(bytes).0
+            CountLines::new(quote_char, None, eol_char).count(bytes).0
         }
     });
 
@@ -117,6 +129,7 @@ pub fn count_rows_from_slice_par(
 pub fn count_rows_from_slice(
     mut bytes: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -130,11 +143,13 @@ pub fn count_rows_from_slice(
     }
 
     let n = if comment_prefix.is_some() {
-        SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+        SplitLines::new(bytes, quote_char, escape_char, eol_char, comment_prefix)
             .filter(|line| !is_comment_line(line, comment_prefix))
             .count()
     } else {
-        CountLines::new(quote_char, eol_char).count(bytes).0
+        CountLines::new(quote_char, escape_char, eol_char)
+            .count(bytes)
+            .0
     };
 
     Ok(n - (has_header as usize))
@@ -246,7 +261,7 @@ pub(super) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char, eol_char, None);
+        let mut lines = SplitLines::new(new_input, quote_char, None, eol_char, None);
         let line = lines.next();
 
         match (line, expected_fields) {
@@ -385,6 +400,7 @@ pub(super) struct SplitLines<'a> {
     previous_valid_eols: u64,
     total_index: usize,
     quoting: bool,
+    escape_char: Option<u8>,
     comment_prefix: Option<&'a CommentPrefix>,
 }
 
@@ -403,6 +419,7 @@ impl<'a> SplitLines<'a> {
     pub(super) fn new(
         slice: &'a [u8],
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         comment_prefix: Option<&'a CommentPrefix>,
     ) -> Self {
@@ -424,6 +441,7 @@ impl<'a> SplitLines<'a> {
             previous_valid_eols: 0,
             total_index: 0,
             quoting,
+            escape_char,
             comment_prefix,
         }
     }
@@ -442,12 +460,17 @@ impl<'a> SplitLines<'a> {
             let mut pos = 0u32;
             let mut iter = self.v.iter();
             let mut in_field = false;
+            let mut escaped = false;
             loop {
                 match iter.next() {
                     Some(&c) => {
                         pos += 1;
 
-                        if self.quoting && c == self.quote_char {
+                        if escaped {
+                            escaped = false;
+                        } else if Some(c) == self.escape_char {
+                            escaped = true;
+                        } else if self.quoting && c == self.quote_char {
                             // toggle between string field enclosure
                             //      if we encounter a starting '"' -> in_field = true;
                             //      if we encounter a closing '"' -> in_field = false;
@@ -527,7 +550,7 @@ impl<'a> Iterator for SplitLines<'a> {
         if self.v.is_empty() {
             return None;
         }
-        if self.comment_prefix.is_some() {
+        if self.comment_prefix.is_some() || self.escape_char.is_some() {
             return self.next_scalar();
         }
 
@@ -634,6 +657,7 @@ pub struct CountLines {
     #[cfg(feature = "simd")]
     simd_quote_char: SimdVec,
     quoting: bool,
+    escape_char: Option<u8>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -644,7 +668,7 @@ pub struct LineStats {
 }
 
 impl CountLines {
-    pub fn new(quote_char: Option<u8>, eol_char: u8) -> Self {
+    pub fn new(quote_char: Option<u8>, escape_char: Option<u8>, eol_char: u8) -> Self {
         let quoting = quote_char.is_some();
         let quote_char = quote_char.unwrap_or(b'\"');
         #[cfg(feature = "simd")]
@@ -659,6 +683,7 @@ impl CountLines {
             #[cfg(feature = "simd")]
             simd_quote_char,
             quoting,
+            escape_char,
         }
     }
 
@@ -686,8 +711,9 @@ impl CountLines {
         #[allow(unused_assignments)]
         let mut global_quote_parity = false;
 
+        // Escaped bytes are skipped in the scalar loop below.
         #[cfg(feature = "simd")]
-        {
+        if self.escape_char.is_none() {
             // 0 if even number of quotes seen so far, u64::MAX otherwise.
             let mut global_quote_parity_mask = 0;
             while scan_offset + 64 <= bytes.len() {
@@ -739,6 +765,10 @@ impl CountLines {
 
         while scan_offset < bytes.len() {
             let c = unsafe { *bytes.get_unchecked(scan_offset) };
+            if Some(c) == self.escape_char {
+                scan_offset += 2;
+                continue;
+            }
             global_quote_parity ^= (c == self.quote_char) & self.quoting;
 
             let state = &mut states[global_quote_parity as usize];
@@ -771,6 +801,10 @@ impl CountLines {
     /// Returns count and offset to split for remainder in slice.
     #[cfg(feature = "simd")]
     pub fn count(&self, bytes: &[u8]) -> (usize, usize) {
+        if self.escape_char.is_some() {
+            return self.count_no_simd(bytes, false);
+        }
+
         let mut total_idx = 0;
         let original_bytes = bytes;
         let mut count = 0;
@@ -835,12 +869,17 @@ impl CountLines {
     fn count_no_simd(&self, bytes: &[u8], in_field: bool) -> (usize, usize) {
         let iter = bytes.iter();
         let mut in_field = in_field;
+        let mut escaped = false;
         let mut count = 0;
         let mut position = 0;
 
         for b in iter {
             let c = *b;
-            if self.quoting && c == self.quote_char {
+            if escaped {
+                escaped = false;
+            } else if Some(c) == self.escape_char {
+                escaped = true;
+            } else if self.quoting && c == self.quote_char {
                 // toggle between string field enclosure
                 //      if we encounter a starting '"' -> in_field = true;
                 //      if we encounter a closing '"' -> in_field = false;
@@ -859,14 +898,27 @@ impl CountLines {
 }
 
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
+fn find_quoted(
+    bytes: &[u8],
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    needle: u8,
+) -> Option<usize> {
     let mut in_field = false;
+    let mut escaped = false;
 
     let mut idx = 0u32;
     // micro optimizations
     #[allow(clippy::explicit_counter_loop)]
     for &c in bytes.iter() {
-        if c == quote_char {
+        if escaped {
+            escaped = false;
+            idx += 1;
+            continue;
+        }
+        if Some(c) == escape_char {
+            escaped = true;
+        } else if Some(c) == quote_char {
             // toggle between string field enclosure
             //      if we encounter a starting '"' -> in_field = true;
             //      if we encounter a closing '"' -> in_field = false;
@@ -882,10 +934,15 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
-    let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
-        None => bytes.iter().position(|x| *x == eol_char),
+pub(super) fn skip_this_line(
+    bytes: &[u8],
+    quote: Option<u8>,
+    escape_char: Option<u8>,
+    eol_char: u8,
+) -> &[u8] {
+    let pos = match (quote, escape_char) {
+        (None, None) => bytes.iter().position(|x| *x == eol_char),
+        _ => find_quoted(bytes, quote, escape_char, eol_char),
     };
     match pos {
         None => &[],
@@ -941,6 +998,8 @@ pub(super) fn parse_lines(
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
     let n_lines = n_lines as u32;
+    let separator_len = parse_options.separator_bytes().len();
+    let mut unescaped = vec![];
 
     let mut line_count = 0u32;
     loop {
@@ -965,12 +1024,7 @@ pub(super) fn parse_lines(
         let mut next_projected = unsafe { projection_iter.next().unwrap_unchecked() };
         let mut processed_fields = 0;
 
-        let mut iter = SplitFields::new(
-            bytes,
-            parse_options.separator,
-            parse_options.quote_char,
-            parse_options.eol_char,
-        );
+        let mut iter = SplitFields::from_options(bytes, parse_options);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
@@ -985,6 +1039,13 @@ pub(super) fn parse_lines(
 
                     // +1 is the split character that is consumed by the iterator.
                     read_sol += field_len + 1;
+                    if separator_len > 1
+                        && bytes
+                            .get(read_sol - 1)
+                            .is_some_and(|c| *c != parse_options.eol_char)
+                    {
+                        read_sol += separator_len - 1;
+                    }
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -1017,7 +1078,21 @@ pub(super) fn parse_lines(
                         if add_null {
                             buf.add_null(!parse_options.missing_is_null && field.is_empty())
                         } else {
-                            buf.add(field, ignore_errors, needs_escaping, parse_options.missing_is_null)
+                            let (value, needs_escaping) = match parse_options.escape_char {
+                                Some(escape_char)
+                                    if memchr::memchr(escape_char, field).is_some() =>
+                                {
+                                    unescape_field(
+                                        field,
+                                        parse_options.quote_char,
+                                        escape_char,
+                                        &mut unescaped,
+                                    );
+                                    (unescaped.as_slice(), false)
+                                },
+                                _ => (field, needs_escaping),
+                            };
+                            buf.add(value, ignore_errors, needs_escaping, parse_options.missing_is_null)
                                 .map_err(|e| {
                                     let bytes_offset = offset + field.as_ptr() as usize - start;
                                     let unparsable = String::from_utf8_lossy(field);
@@ -1060,6 +1135,7 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked(read_sol - 1..) },
                                         parse_options.quote_char,
+                                        parse_options.escape_char,
                                         parse_options.eol_char,
                                     );
                                     bytes = bytes_rem;
@@ -1096,13 +1172,13 @@ mod test {
     #[test]
     fn test_splitlines() {
         let input = "1,\"foo\n\"\n2,\"foo\n\"\n";
-        let mut lines = SplitLines::new(input.as_bytes(), Some(b'"'), b'\n', None);
+        let mut lines = SplitLines::new(input.as_bytes(), Some(b'"'), None, b'\n', None);
         assert_eq!(lines.next(), Some("1,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), Some("2,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), None);
 
         let input2 = "1,'foo\n'\n2,'foo\n'\n";
-        let mut lines2 = SplitLines::new(input2.as_bytes(), Some(b'\''), b'\n', None);
+        let mut lines2 = SplitLines::new(input2.as_bytes(), Some(b'\''), None, b'\n', None);
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
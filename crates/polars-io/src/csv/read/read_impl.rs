@@ -13,13 +13,15 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
+use super::encoding::maybe_transcode_bytes;
 use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::parser::{
     CountLines, SplitLines, is_comment_line, parse_lines, skip_bom, skip_line_ending,
     skip_lines_naive, skip_this_line,
 };
 use super::reader::prepare_csv_schema;
-use super::schema_inference::{check_decimal_comma, infer_file_schema};
+use super::schema_inference::{check_decimal_comma, check_dialect, infer_file_schema};
+use super::splitfields::SplitFields;
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
@@ -27,6 +29,7 @@ use crate::csv::read::parser::skip_this_line_naive;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::compression::SupportedCompression;
+use crate::utils::rejected_rows::{LineCounter, RawLine, RejectedRows, read_lines_rejecting};
 use crate::utils::update_row_counts2;
 
 pub fn cast_columns(
@@ -123,6 +126,9 @@ pub(crate) struct CoreReader<'a> {
     row_index: Option<RowIndex>,
     #[cfg_attr(not(feature = "dtype-categorical"), allow(unused))]
     has_categorical: bool,
+    rejected_rows: Option<RejectedRows>,
+    /// Path of the file, recorded with rejected rows.
+    path: Option<PlSmallStr>,
 }
 
 impl fmt::Debug for CoreReader<'_> {
@@ -158,10 +164,13 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
+        rejected_rows: Option<RejectedRows>,
+        path: Option<PlSmallStr>,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
         check_decimal_comma(parse_options.decimal_comma, separator)?;
+        check_dialect(&parse_options)?;
         #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
@@ -171,13 +180,15 @@ impl<'a> CoreReader<'a> {
                 compile with feature 'decompress'"
             );
         }
+        // Only the simple dialect can be cut after the first rows without parsing them.
+        let total_n_rows = n_rows
+            .filter(|_| parse_options.is_simple_dialect())
+            .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
         // We keep track of the inferred schema bool
         // In case the file is compressed this schema inference is wrong and has to be done
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -189,6 +200,29 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        // Transcoding happens after decompression, the parser only ever sees UTF-8. Comment lines
+        // and rejected rows are not rows, so the input is only cut without them.
+        let transcode_n_lines = total_n_rows
+            .filter(|_| parse_options.comment_prefix.is_none() && rejected_rows.is_none())
+            .map(|n| n + skip_lines);
+        let line_counter = CountLines::new(
+            parse_options.quote_char,
+            parse_options.escape_char,
+            parse_options.eol_char,
+        );
+        let mut transcoded = vec![];
+        maybe_transcode_bytes(
+            &reader_bytes,
+            parse_options.encoding,
+            transcode_n_lines.map(|n| (n, &line_counter)),
+            &mut transcoded,
+        )?;
+        let reader_bytes = if transcoded.is_empty() {
+            reader_bytes
+        } else {
+            ReaderBytes::Owned(transcoded.into())
+        };
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
@@ -254,6 +288,8 @@ impl<'a> CoreReader<'a> {
             to_cast,
             row_index,
             has_categorical,
+            rejected_rows,
+            path,
         })
     }
 
@@ -266,6 +302,7 @@ impl<'a> CoreReader<'a> {
         let i = find_starting_point(
             bytes,
             quote_char,
+            self.parse_options.escape_char,
             eol_char,
             self.schema.len(),
             self.skip_lines,
@@ -293,6 +330,7 @@ impl<'a> CoreReader<'a> {
             .unwrap_or_else(|| Ok((0..self.schema.len()).collect()))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn read_chunk(
         &self,
         bytes: &[u8],
@@ -301,7 +339,22 @@ impl<'a> CoreReader<'a> {
         capacity: usize,
         starting_point_offset: Option<usize>,
         stop_at_nbytes: usize,
+        first_line: usize,
     ) -> PolarsResult<DataFrame> {
+        if let Some(rejected_rows) = &self.rejected_rows {
+            return read_chunk_rejecting(
+                &bytes[bytes_offset..stop_at_nbytes],
+                &self.parse_options,
+                self.schema.as_ref(),
+                projection,
+                self.null_values.as_ref(),
+                &self.to_cast,
+                rejected_rows,
+                self.path.as_ref(),
+                first_line,
+            );
+        }
+
         let mut df = read_chunk(
             bytes,
             &self.parse_options,
@@ -320,9 +373,9 @@ impl<'a> CoreReader<'a> {
         Ok(df)
     }
 
-    fn parse_csv(&mut self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+    fn parse_csv(&mut self, full_bytes: &[u8]) -> PolarsResult<DataFrame> {
         let (bytes, _) = self.find_starting_point(
-            bytes,
+            full_bytes,
             self.parse_options.quote_char,
             self.parse_options.eol_char,
         )?;
@@ -388,10 +441,17 @@ impl<'a> CoreReader<'a> {
         #[cfg(target_family = "wasm")]
         let pool = &POOL;
 
-        let counter = CountLines::new(self.parse_options.quote_char, self.parse_options.eol_char);
+        let counter = CountLines::new(
+            self.parse_options.quote_char,
+            self.parse_options.escape_char,
+            self.parse_options.eol_char,
+        );
         let mut total_offset = 0;
-        let check_utf8 = matches!(self.parse_options.encoding, CsvEncoding::Utf8)
+        // In rejecting mode invalid UTF-8 is checked per row.
+        let check_utf8 = !matches!(self.parse_options.encoding, CsvEncoding::LossyUtf8)
+            && self.rejected_rows.is_none()
             && self.schema.iter_fields().any(|f| f.dtype().is_string());
+        let mut line_counter = LineCounter::new(self.parse_options.eol_char);
 
         pool.scope(|s| {
             loop {
@@ -425,6 +485,12 @@ impl<'a> CoreReader<'a> {
                 };
 
                 if !b.is_empty() {
+                    let first_line = if self.rejected_rows.is_some() {
+                        let offset = b.as_ptr() as usize - full_bytes.as_ptr() as usize;
+                        line_counter.line_at(full_bytes, offset)
+                    } else {
+                        0
+                    };
                     let results = results.clone();
                     let projection = projection.as_ref();
                     let slf = &(*self);
@@ -439,7 +505,7 @@ impl<'a> CoreReader<'a> {
                         }
 
                         let result = slf
-                            .read_chunk(b, projection, 0, count, Some(0), b.len())
+                            .read_chunk(b, projection, 0, count, Some(0), b.len(), first_line)
                             .and_then(|mut df| {
                                 debug_assert!(df.height() <= count);
 
@@ -506,6 +572,9 @@ impl<'a> CoreReader<'a> {
         let reader_bytes = self.reader_bytes.take().unwrap();
 
         let mut df = self.parse_csv(&reader_bytes)?;
+        if let Some(rejected_rows) = &self.rejected_rows {
+            rejected_rows.finish()?;
+        }
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -579,10 +648,108 @@ pub fn read_chunk(
     Ok(unsafe { DataFrame::new_no_checks_height_from_first(columns) })
 }
 
+/// Reads a chunk of whole lines like [`read_chunk`] followed by [`cast_columns`], but rows that
+/// are ragged or fail to parse are recorded in `rejected_rows` and left out of the output.
+///
+/// All columns are parsed regardless of `projection`, so the rejected rows do not depend on which
+/// columns are selected.
+///
+/// `first_line` is the line number of the first line of `bytes` in the file.
+#[allow(clippy::too_many_arguments)]
+pub fn read_chunk_rejecting(
+    bytes: &[u8],
+    parse_options: &CsvParseOptions,
+    schema: &Schema,
+    projection: &[usize],
+    null_values: Option<&NullValuesCompiled>,
+    fields_to_cast: &[Field],
+    rejected_rows: &RejectedRows,
+    path: Option<&PlSmallStr>,
+    first_line: usize,
+) -> PolarsResult<DataFrame> {
+    let eol_char = parse_options.eol_char;
+    let comment_prefix = parse_options.comment_prefix.as_ref();
+    let check_utf8 = !matches!(parse_options.encoding, CsvEncoding::LossyUtf8)
+        && schema.iter_values().any(|dtype| dtype.is_string());
+    let full_projection = (0..schema.len()).collect::<Vec<_>>();
+
+    let mut lines = vec![];
+    let mut line_number = first_line;
+    for line in SplitLines::new(
+        bytes,
+        parse_options.quote_char,
+        parse_options.escape_char,
+        eol_char,
+        comment_prefix,
+    ) {
+        let start = line.as_ptr() as usize - bytes.as_ptr() as usize;
+        let line_start = line_number;
+        // Quoted fields can span multiple lines.
+        line_number += 1 + memchr::memchr_iter(eol_char, line).count();
+
+        if is_comment_line(line, comment_prefix) {
+            continue;
+        }
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        // Empty lines are read as a row of nulls, as in the regular reader.
+        let n_fields = if line.is_empty() {
+            schema.len()
+        } else {
+            SplitFields::from_options(line, parse_options).count()
+        };
+        let error = (n_fields < schema.len()
+            || (n_fields > schema.len() && !parse_options.truncate_ragged_lines))
+            .then(|| format!("expected {} fields, found {}", schema.len(), n_fields));
+
+        lines.push(RawLine {
+            start,
+            end: start + line.len(),
+            line: line_start,
+            error,
+        });
+    }
+
+    let read = |bytes: &[u8], n_lines: usize| {
+        if check_utf8 && !super::buffer::validate_utf8(bytes) {
+            polars_bail!(ComputeError: "invalid utf-8 sequence")
+        }
+
+        let mut df = read_chunk(
+            bytes,
+            parse_options,
+            schema,
+            false,
+            &full_projection,
+            0,
+            n_lines,
+            null_values,
+            usize::MAX,
+            bytes.len(),
+            Some(0),
+        )?;
+        cast_columns(&mut df, fields_to_cast, false, false)?;
+
+        let columns = projection
+            .iter()
+            .map(|&i| df.get_columns()[i].clone())
+            .collect();
+        Ok(unsafe { DataFrame::new_no_checks(df.height(), columns) })
+    };
+
+    let dfs = read_lines_rejecting(bytes, &lines, &read, rejected_rows, path)?;
+    if dfs.is_empty() {
+        read(&[], 0)
+    } else {
+        accumulate_dataframes_vertical(dfs)
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn find_starting_point(
     mut bytes: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     schema_len: usize,
     skip_lines: usize,
@@ -611,7 +778,8 @@ pub fn find_starting_point(
 
     // skip 'n' leading rows
     if skip_rows_before_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines =
+            SplitLines::new(bytes, quote_char, escape_char, eol_char, comment_prefix);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_before_header {
@@ -633,11 +801,12 @@ pub fn find_starting_point(
 
     // skip header row
     if has_header {
-        bytes = skip_this_line(bytes, quote_char, eol_char);
+        bytes = skip_this_line(bytes, quote_char, escape_char, eol_char);
     }
     // skip 'n' rows following the header
     if skip_rows_after_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines =
+            SplitLines::new(bytes, quote_char, escape_char, eol_char, comment_prefix);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_after_header {
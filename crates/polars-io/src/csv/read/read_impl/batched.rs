@@ -7,14 +7,16 @@ use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_utils::IdxSize;
-use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use polars_utils::pl_str::PlSmallStr;
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
-use super::{CoreReader, CountLines, cast_columns, read_chunk};
+use super::{CoreReader, CountLines, cast_columns, read_chunk, read_chunk_rejecting};
 use crate::RowIndex;
 use crate::csv::read::CsvReader;
 use crate::csv::read::options::NullValuesCompiled;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::{CsvParseOptions, update_row_counts2};
+use crate::utils::rejected_rows::{LineCounter, RejectedRows};
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn get_file_chunks_iterator(
@@ -24,9 +26,10 @@ pub(crate) fn get_file_chunks_iterator(
     chunk_size: &mut usize,
     bytes: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) {
-    let cl = CountLines::new(quote_char, eol_char);
+    let cl = CountLines::new(quote_char, escape_char, eol_char);
 
     for _ in 0..n_chunks {
         let bytes = &bytes[*last_pos..];
@@ -79,6 +82,7 @@ struct ChunkOffsetIter<'a> {
     #[allow(unused)]
     rows_per_batch: usize,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 }
 
@@ -99,6 +103,7 @@ impl Iterator for ChunkOffsetIter<'_> {
                     &mut self.chunk_size,
                     self.bytes,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                 );
                 match self.offsets.pop_front() {
@@ -155,6 +160,7 @@ impl<'a> CoreReader<'a> {
             chunk_size,
             rows_per_batch: self.chunk_size,
             quote_char: self.parse_options.quote_char,
+            escape_char: self.parse_options.escape_char,
             eol_char: self.parse_options.eol_char,
         };
 
@@ -171,6 +177,8 @@ impl<'a> CoreReader<'a> {
         #[cfg(not(feature = "dtype-categorical"))]
         let _cat_lock = None;
 
+        let line_counter = LineCounter::new(self.parse_options.eol_char);
+
         Ok(BatchedCsvReader {
             reader_bytes,
             parse_options: self.parse_options,
@@ -186,6 +194,9 @@ impl<'a> CoreReader<'a> {
             remaining: self.n_rows.unwrap_or(usize::MAX),
             schema: self.schema,
             rows_read: 0,
+            line_counter,
+            rejected_rows: self.rejected_rows,
+            path: self.path,
             _cat_lock,
         })
     }
@@ -206,6 +217,9 @@ pub struct BatchedCsvReader<'a> {
     remaining: usize,
     schema: SchemaRef,
     rows_read: IdxSize,
+    line_counter: LineCounter,
+    rejected_rows: Option<RejectedRows>,
+    path: Option<PlSmallStr>,
     #[cfg(feature = "dtype-categorical")]
     _cat_lock: Option<polars_core::StringCacheHolder>,
     #[cfg(not(feature = "dtype-categorical"))]
@@ -214,44 +228,72 @@ pub struct BatchedCsvReader<'a> {
 
 impl BatchedCsvReader<'_> {
     pub fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
-        if n == 0 || self.remaining == 0 {
+        if n == 0 {
             return Ok(None);
         }
+        if self.remaining == 0 {
+            return self.finish();
+        }
 
         // get next `n` offset positions.
         let file_chunks_iter = (&mut self.file_chunks_iter).take(n);
         self.file_chunks.extend(file_chunks_iter);
         // depleted the offsets iterator, we are done as well.
         if self.file_chunks.is_empty() {
-            return Ok(None);
+            return self.finish();
         }
         let chunks = &self.file_chunks;
 
-        let mut bytes = self.reader_bytes.deref();
-        if let Some(pos) = self.starting_point_offset {
-            bytes = &bytes[pos..];
-        }
+        let full_bytes = self.reader_bytes.deref();
+        let starting_point_offset = self.starting_point_offset.unwrap_or(0);
+        let bytes = &full_bytes[starting_point_offset..];
+
+        let first_lines = match &self.rejected_rows {
+            Some(_) => chunks
+                .iter()
+                .map(|(offset, _)| {
+                    self.line_counter
+                        .line_at(full_bytes, starting_point_offset + offset)
+                })
+                .collect(),
+            None => vec![0; chunks.len()],
+        };
 
         let mut chunks = POOL.install(|| {
             chunks
                 .into_par_iter()
                 .copied()
-                .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                    let mut df = read_chunk(
-                        bytes,
-                        &self.parse_options,
-                        self.schema.as_ref(),
-                        self.ignore_errors,
-                        &self.projection,
-                        bytes_offset_thread,
-                        self.chunk_size,
-                        self.null_values.as_ref(),
-                        usize::MAX,
-                        stop_at_nbytes,
-                        self.starting_point_offset,
-                    )?;
-
-                    cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                .zip(first_lines)
+                .map(|((bytes_offset_thread, stop_at_nbytes), first_line)| {
+                    let mut df = if let Some(rejected_rows) = &self.rejected_rows {
+                        read_chunk_rejecting(
+                            &bytes[bytes_offset_thread..stop_at_nbytes],
+                            &self.parse_options,
+                            self.schema.as_ref(),
+                            &self.projection,
+                            self.null_values.as_ref(),
+                            &self.to_cast,
+                            rejected_rows,
+                            self.path.as_ref(),
+                            first_line,
+                        )?
+                    } else {
+                        let mut df = read_chunk(
+                            bytes,
+                            &self.parse_options,
+                            self.schema.as_ref(),
+                            self.ignore_errors,
+                            &self.projection,
+                            bytes_offset_thread,
+                            self.chunk_size,
+                            self.null_values.as_ref(),
+                            usize::MAX,
+                            stop_at_nbytes,
+                            self.starting_point_offset,
+                        )?;
+                        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                        df
+                    };
 
                     if let Some(rc) = &self.row_index {
                         unsafe { df.with_row_index_mut(rc.name.clone(), Some(rc.offset)) };
@@ -277,6 +319,14 @@ impl BatchedCsvReader<'_> {
         }
         Ok(Some(chunks))
     }
+
+    /// Called once all batches are read.
+    fn finish(&self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        if let Some(rejected_rows) = &self.rejected_rows {
+            rejected_rows.finish()?;
+        }
+        Ok(None)
+    }
 }
 
 pub struct OwnedBatchedCsvReader {
@@ -12,6 +12,7 @@ use crate::path_utils::resolve_homedir;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::SerReader;
 use crate::utils::get_reader_bytes;
+use crate::utils::rejected_rows::RejectedRows;
 
 /// Create a new DataFrame by reading a csv file.
 ///
@@ -131,6 +132,15 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
+            self.options
+                .rejected_rows
+                .as_ref()
+                .map(|options| RejectedRows::try_new(options, 0))
+                .transpose()?,
+            self.options
+                .path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )
     }
 
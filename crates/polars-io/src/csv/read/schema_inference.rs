@@ -9,6 +9,7 @@ use polars_utils::format_pl_smallstr;
 
 use super::parser::{SplitLines, is_comment_line, skip_bom, skip_line_ending};
 use super::splitfields::SplitFields;
+use super::utils::unescape_field;
 use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 use crate::csv::read::parser::skip_lines_naive;
 use crate::mmap::ReaderBytes;
@@ -70,6 +71,12 @@ impl SchemaInferenceResult {
         self
     }
 
+    /// Set the size of the whole input, if the schema was inferred from the start of it.
+    pub fn with_bytes_total(mut self, bytes_total: usize) -> Self {
+        self.bytes_total = bytes_total;
+        self
+    }
+
     pub fn get_inferred_schema(&self) -> SchemaRef {
         self.inferred_schema.clone()
     }
@@ -173,13 +180,32 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // Other encodings have been transcoded to UTF-8 at this point.
+        _ => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
-        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     })
 }
 
+/// Returns the value of a field for inference. Enclosing quotes are stripped and fields that
+/// contain the escape character are unescaped into `unescaped`.
+fn unquote_field<'a>(
+    field: &'a [u8],
+    needs_escaping: bool,
+    parse_options: &CsvParseOptions,
+    unescaped: &'a mut Vec<u8>,
+) -> &'a [u8] {
+    match parse_options.escape_char {
+        Some(escape_char) if memchr::memchr(escape_char, field).is_some() => {
+            unescape_field(field, parse_options.quote_char, escape_char, unescaped);
+            unescaped
+        },
+        _ if needs_escaping && field.len() >= 2 => &field[1..field.len() - 1],
+        _ => field,
+    }
+}
+
 fn column_name(i: usize) -> PlSmallStr {
     format_pl_smallstr!("column_{}", i + 1)
 }
@@ -212,6 +238,7 @@ fn infer_file_schema_inner(
     let mut lines = SplitLines::new(
         bytes,
         parse_options.quote_char,
+        parse_options.escape_char,
         parse_options.eol_char,
         parse_options.comment_prefix.as_ref(),
     )
@@ -246,22 +273,15 @@ fn infer_file_schema_inner(
             }
         }
 
-        let byterecord = SplitFields::new(
-            header_line,
-            parse_options.separator,
-            parse_options.quote_char,
-            parse_options.eol_char,
-        );
+        let byterecord = SplitFields::from_options(header_line, parse_options);
         if has_header {
+            let mut unescaped = vec![];
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
-                    let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                        &slice[1..(slice.len() - 1)]
-                    } else {
-                        slice
-                    };
+                    let slice_escaped =
+                        unquote_field(slice, needs_escaping, parse_options, &mut unescaped);
                     let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
-                    Ok(s)
+                    Ok(s.into_owned())
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -270,7 +290,7 @@ fn infer_file_schema_inner(
             let mut header_names = PlHashMap::with_capacity(headers.len());
 
             for name in &headers {
-                let count = header_names.entry(name.as_ref()).or_insert(0usize);
+                let count = header_names.entry(name.as_str()).or_insert(0usize);
                 if *count != 0 {
                     final_headers.push(format_pl_smallstr!("{}_duplicated_{}", name, *count - 1))
                 } else {
@@ -313,6 +333,7 @@ fn infer_file_schema_inner(
         lines = SplitLines::new(
             bytes,
             parse_options.quote_char,
+            parse_options.escape_char,
             parse_options.eol_char,
             parse_options.comment_prefix.as_ref(),
         )
@@ -327,6 +348,7 @@ fn infer_file_schema_inner(
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(headers.len());
+    let mut unescaped = vec![];
 
     // needed to prevent ownership going into the iterator loop
     let records_ref = &mut lines;
@@ -370,12 +392,7 @@ fn infer_file_schema_inner(
             }
         }
 
-        let record = SplitFields::new(
-            line,
-            parse_options.separator,
-            parse_options.quote_char,
-            parse_options.eol_char,
-        );
+        let record = SplitFields::from_options(line, parse_options);
 
         for (i, (slice, needs_escaping)) in record.enumerate() {
             // When `has_header = False` and ``
@@ -393,11 +410,8 @@ fn infer_file_schema_inner(
             if slice.is_empty() {
                 unsafe { *nulls.get_unchecked_mut(i) = true };
             } else {
-                let slice_escaped = if needs_escaping && (slice.len() >= 2) {
-                    &slice[1..(slice.len() - 1)]
-                } else {
-                    slice
-                };
+                let slice_escaped =
+                    unquote_field(slice, needs_escaping, parse_options, &mut unescaped);
                 let s = parse_bytes_with_encoding(slice_escaped, encoding)?;
                 let dtype = match &parse_options.null_values {
                     None => Some(infer_field_schema(
@@ -517,6 +531,27 @@ pub(super) fn check_decimal_comma(decimal_comma: bool, separator: u8) -> PolarsR
     Ok(())
 }
 
+pub(super) fn check_dialect(parse_options: &CsvParseOptions) -> PolarsResult<()> {
+    let separator = parse_options.separator_bytes();
+    if parse_options.multi_byte_separator.is_some() {
+        polars_ensure!(!separator.is_empty(), InvalidOperation: "CSV separator must not be empty");
+        polars_ensure!(
+            !separator.contains(&parse_options.eol_char)
+                && parse_options.quote_char.is_none_or(|q| !separator.contains(&q)),
+            InvalidOperation: "CSV separator must not contain the quote or end-of-line character"
+        );
+    }
+    if let Some(escape_char) = parse_options.escape_char {
+        polars_ensure!(
+            Some(escape_char) != parse_options.quote_char
+                && escape_char != parse_options.eol_char
+                && !separator.contains(&escape_char),
+            InvalidOperation: "CSV escape character must differ from the separator, quote and end-of-line characters"
+        );
+    }
+    Ok(())
+}
+
 /// Infer the schema of a CSV file by reading through the first n rows of the file,
 /// with `max_read_rows` controlling the maximum number of rows to read.
 ///
@@ -539,6 +574,7 @@ pub fn infer_file_schema(
     raise_if_empty: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
     check_decimal_comma(parse_options.decimal_comma, parse_options.separator)?;
+    check_dialect(parse_options)?;
 
     if skip_lines > 0 {
         polars_ensure!(skip_rows == 0, InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set");
@@ -10,6 +10,7 @@ mod inner {
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
+        pub(super) dialect: Option<super::Dialect<'a>>,
     }
 
     impl<'a> SplitFields<'a> {
@@ -26,6 +27,7 @@ mod inner {
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
                 eol_char,
+                dialect: None,
             }
         }
 
@@ -57,6 +59,14 @@ mod inner {
         fn next(&mut self) -> Option<(&'a [u8], bool)> {
             if self.finished {
                 return None;
+            } else if let Some(dialect) = &self.dialect {
+                let quote_char = self.quoting.then_some(self.quote_char);
+                return dialect.next_field(
+                    &mut self.v,
+                    &mut self.finished,
+                    quote_char,
+                    self.eol_char,
+                );
             } else if self.v.is_empty() {
                 return self.finish(false);
             }
@@ -157,6 +167,7 @@ mod inner {
         simd_eol_char: SimdVec,
         simd_quote_char: SimdVec,
         previous_valid_ends: u64,
+        pub(super) dialect: Option<super::Dialect<'a>>,
     }
 
     impl<'a> SplitFields<'a> {
@@ -183,6 +194,7 @@ mod inner {
                 simd_eol_char,
                 simd_quote_char,
                 previous_valid_ends: 0,
+                dialect: None,
             }
         }
 
@@ -217,6 +229,15 @@ mod inner {
             if self.finished {
                 return None;
             }
+            if let Some(dialect) = &self.dialect {
+                let quote_char = self.quoting.then_some(self.quote_char);
+                return dialect.next_field(
+                    &mut self.v,
+                    &mut self.finished,
+                    quote_char,
+                    self.eol_char,
+                );
+            }
             // Then check cached value as this is hot.
             if self.previous_valid_ends != 0 {
                 let pos = self.previous_valid_ends.trailing_zeros() as usize;
@@ -408,9 +429,79 @@ mod inner {
 
 pub(crate) use inner::SplitFields;
 
+use super::CsvParseOptions;
+
+/// Field splitting for dialects the single byte fast paths don't handle, i.e. with a separator of
+/// more than one byte or with an escape character.
+#[derive(Clone, Copy)]
+struct Dialect<'a> {
+    separator: &'a [u8],
+    escape_char: Option<u8>,
+}
+
+impl<'a> Dialect<'a> {
+    fn next_field(
+        &self,
+        v: &mut &'a [u8],
+        finished: &mut bool,
+        quote_char: Option<u8>,
+        eol_char: u8,
+    ) -> Option<(&'a [u8], bool)> {
+        let bytes = *v;
+        let needs_escaping = quote_char.is_some() && bytes.first() == quote_char.as_ref();
+
+        let mut in_field = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if Some(c) == self.escape_char {
+                // The escaped byte can't end the field.
+                i += 2;
+                continue;
+            }
+            if needs_escaping && Some(c) == quote_char {
+                in_field = !in_field;
+            } else if !in_field {
+                if c == eol_char {
+                    break;
+                }
+                if bytes[i..].starts_with(self.separator) {
+                    *v = &bytes[i + self.separator.len()..];
+                    return Some((&bytes[..i], needs_escaping));
+                }
+            }
+            i += 1;
+        }
+
+        *finished = true;
+        Some((&bytes[..i.min(bytes.len())], needs_escaping))
+    }
+}
+
+impl<'a> SplitFields<'a> {
+    /// Creates a field splitter that honors the separator, quoting and escaping settings of
+    /// `parse_options`.
+    pub(crate) fn from_options(slice: &'a [u8], parse_options: &'a CsvParseOptions) -> Self {
+        let mut split = Self::new(
+            slice,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        );
+        if !parse_options.is_simple_dialect() {
+            split.dialect = Some(Dialect {
+                separator: parse_options.separator_bytes(),
+                escape_char: parse_options.escape_char,
+            });
+        }
+        split
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::SplitFields;
+    use crate::csv::read::CsvParseOptions;
 
     #[test]
     fn test_splitfields() {
@@ -429,4 +520,32 @@ mod test {
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_dialect() {
+        let options = CsvParseOptions::default()
+            .with_multi_byte_separator("||")
+            .with_escape_char(Some(b'\\'));
+
+        let input = "a|b||\"c||d\"||e\\||f||\\\n\ng";
+        let fields = SplitFields::from_options(input.as_bytes(), &options).collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("a|b".as_bytes(), false),
+                ("\"c||d\"".as_bytes(), true),
+                ("e\\||f".as_bytes(), false),
+                ("\\\n".as_bytes(), false),
+            ]
+        );
+
+        // An escaped quote doesn't close the field.
+        let options = CsvParseOptions::default().with_escape_char(Some(b'\\'));
+        let input = "\"a\\\",b\",c";
+        let fields = SplitFields::from_options(input.as_bytes(), &options).collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [("\"a\\\",b\"".as_bytes(), true), ("c".as_bytes(), false)]
+        );
+    }
 }
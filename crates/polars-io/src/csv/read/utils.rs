@@ -188,6 +188,44 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
     count
 }
 
+/// Write the value of a field that contains `escape_char` to `out`.
+///
+/// Enclosing quotes are removed and doubled quotes are replaced by single ones. The escape
+/// character makes the byte that follows it literal, except for the sequences `n`, `r`, `t` and
+/// `0` which stand for a line feed, carriage return, tab and NUL byte.
+pub(super) fn unescape_field(
+    field: &[u8],
+    quote_char: Option<u8>,
+    escape_char: u8,
+    out: &mut Vec<u8>,
+) {
+    out.clear();
+    let quoted = quote_char.is_some() && field.first() == quote_char.as_ref();
+    let mut iter = field[quoted as usize..].iter();
+
+    while let Some(&c) = iter.next() {
+        if c == escape_char {
+            match iter.next() {
+                Some(b'n') => out.push(b'\n'),
+                Some(b'r') => out.push(b'\r'),
+                Some(b't') => out.push(b'\t'),
+                Some(b'0') => out.push(0),
+                Some(&c) => out.push(c),
+                // A trailing escape character is kept as is.
+                None => out.push(c),
+            }
+        } else if quoted && Some(c) == quote_char {
+            // A single quote closes the field, a doubled one is a literal quote.
+            if iter.as_slice().first() == Some(&c) {
+                iter.next();
+                out.push(c);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::get_file_chunks;
@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::utils::compression::ExternalCompression;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -11,6 +13,7 @@ pub struct CsvWriterOptions {
     pub include_header: bool,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
+    pub compression: ExternalCompression,
 }
 
 impl Default for CsvWriterOptions {
@@ -20,6 +23,7 @@ impl Default for CsvWriterOptions {
             include_header: true,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
+            compression: ExternalCompression::default(),
         }
     }
 }
@@ -42,8 +46,14 @@ pub struct SerializeOptions {
     pub float_precision: Option<usize>,
     /// Used as separator.
     pub separator: u8,
+    /// Separator of more than one byte. If set, this is used instead of `separator`.
+    pub multi_byte_separator: Option<String>,
     /// Quoting character.
     pub quote_char: u8,
+    /// If set, quote and escape characters in string fields are escaped with this character
+    /// instead of doubling the quote character. Unquoted fields also have their line endings and
+    /// separators escaped, as done by MySQL and Hive.
+    pub escape_char: Option<u8>,
     /// Null value representation.
     pub null: String,
     /// String appended after every row.
@@ -61,7 +71,9 @@ impl Default for SerializeOptions {
             float_scientific: None,
             float_precision: None,
             separator: b',',
+            multi_byte_separator: None,
             quote_char: b'"',
+            escape_char: None,
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
@@ -69,6 +81,16 @@ impl Default for SerializeOptions {
     }
 }
 
+impl SerializeOptions {
+    /// The bytes that separate fields.
+    pub fn separator_bytes(&self) -> &[u8] {
+        match &self.multi_byte_separator {
+            Some(separator) => separator.as_bytes(),
+            None => std::slice::from_ref(&self.separator),
+        }
+    }
+}
+
 /// Quote style indicating when to insert quotes around a field.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
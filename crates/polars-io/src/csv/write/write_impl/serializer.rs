@@ -38,7 +38,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr_iter, memchr3};
+use memchr::{memchr_iter, memchr2, memchr3, memmem};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 
@@ -406,6 +406,36 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
+    fn serialize_str_with_escape_char(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        options: &SerializeOptions,
+        escape_char: u8,
+        quoted: bool,
+    ) {
+        // Escaping the first byte of the separator is enough for it not to be matched.
+        let separator = options.separator_bytes()[0];
+        for &c in s {
+            match c {
+                LF if !quoted => buf.extend_from_slice(&[escape_char, b'n']),
+                CR if !quoted => buf.extend_from_slice(&[escape_char, b'r']),
+                _ if c == options.quote_char || c == escape_char || (!quoted && c == separator) => {
+                    buf.extend_from_slice(&[escape_char, c])
+                },
+                _ => buf.push(c),
+            }
+        }
+    }
+
+    fn serialize_str(buf: &mut Vec<u8>, s: &[u8], options: &SerializeOptions, quoted: bool) {
+        match options.escape_char {
+            Some(escape_char) => {
+                serialize_str_with_escape_char(buf, s, options, escape_char, quoted)
+            },
+            None => serialize_str_escaped(buf, s, options.quote_char, quoted),
+        }
+    }
+
     let iter = update(array);
     match options.quote_style {
         QuoteStyle::Always => {
@@ -418,7 +448,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.push(quote_char);
                         return;
                     };
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str(buf, s.as_bytes(), options, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -436,7 +466,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     };
                     let quote_char = options.quote_char;
                     buf.push(quote_char);
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str(buf, s.as_bytes(), options, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -458,11 +488,17 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.extend_from_slice(&[quote_char, quote_char]);
                         return;
                     }
-                    let needs_quote = memchr3(options.separator, LF, CR, s.as_bytes()).is_some();
+                    let needs_quote = match &options.multi_byte_separator {
+                        None => memchr3(options.separator, LF, CR, s.as_bytes()).is_some(),
+                        Some(separator) => {
+                            memchr2(LF, CR, s.as_bytes()).is_some()
+                                || memmem::find(s.as_bytes(), separator.as_bytes()).is_some()
+                        },
+                    };
                     if needs_quote {
                         buf.push(quote_char);
                     }
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, needs_quote);
+                    serialize_str(buf, s.as_bytes(), options, needs_quote);
                     if needs_quote {
                         buf.push(quote_char);
                     }
@@ -480,7 +516,11 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.extend_from_slice(options.null.as_bytes());
                         return;
                     };
-                    buf.extend_from_slice(s.as_bytes());
+                    if options.escape_char.is_some() {
+                        serialize_str(buf, s.as_bytes(), options, false);
+                    } else {
+                        buf.extend_from_slice(s.as_bytes());
+                    }
                 };
             Box::new(StringSerializer {
                 serialize,
@@ -773,5 +813,30 @@ mod test {
         check_string_serialization(&non_numeric_quote, Some("a,b"), r#""a,b""#);
         check_string_serialization(&non_numeric_quote, Some("a\nb"), "\"a\nb\"");
         check_string_serialization(&non_numeric_quote, Some("a\rb"), "\"a\rb\"");
+
+        let escape_necessary = SerializeOptions {
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&escape_necessary, Some("a\"b"), r#"a\"b"#);
+        check_string_serialization(&escape_necessary, Some("a\\b"), r#"a\\b"#);
+        check_string_serialization(&escape_necessary, Some("a,\"b"), r#""a,\"b""#);
+        check_string_serialization(&escape_necessary, Some("a\nb"), "\"a\nb\"");
+
+        let escape_never = SerializeOptions {
+            escape_char: Some(b'\\'),
+            quote_style: QuoteStyle::Never,
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&escape_never, Some("a,\"b"), r#"a\,\"b"#);
+        check_string_serialization(&escape_never, Some("a\nb\r"), r#"a\nb\r"#);
+
+        let multi_byte_separator = SerializeOptions {
+            multi_byte_separator: Some("||".into()),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&multi_byte_separator, Some("a|b"), "a|b");
+        check_string_serialization(&multi_byte_separator, Some("a||b"), r#""a||b""#);
+        check_string_serialization(&multi_byte_separator, Some("a,b"), "a,b");
     }
 }
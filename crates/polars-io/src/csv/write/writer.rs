@@ -9,6 +9,7 @@ use polars_error::PolarsResult;
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
 use crate::shared::SerWriter;
+use crate::utils::compression::{CompressedWriter, ExternalCompression};
 
 /// Write a DataFrame to csv.
 ///
@@ -22,6 +23,7 @@ pub struct CsvWriter<W: Write> {
     bom: bool,
     batch_size: NonZeroUsize,
     n_threads: usize,
+    compression: ExternalCompression,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -42,12 +44,14 @@ where
             bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
+            compression: ExternalCompression::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut writer = CompressedWriter::new(&mut self.buffer, self.compression);
         if self.bom {
-            write_bom(&mut self.buffer)?;
+            write_bom(&mut writer)?;
         }
         let names = df
             .get_column_names()
@@ -55,15 +59,17 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+            write_header(&mut writer, names.as_slice(), &self.options)?;
         }
         write(
-            &mut self.buffer,
+            &mut writer,
             df,
             self.batch_size.into(),
             &self.options,
             self.n_threads,
-        )
+        )?;
+        writer.finish()?;
+        Ok(())
     }
 }
 
@@ -89,6 +95,13 @@ where
         self
     }
 
+    /// Set the CSV file's column separator to a sequence of bytes, e.g. `||`. This takes
+    /// precedence over [`CsvWriter::with_separator`].
+    pub fn with_multi_byte_separator(mut self, separator: Option<String>) -> Self {
+        self.options.multi_byte_separator = separator;
+        self
+    }
+
     /// Set the batch size to use while writing the CSV.
     pub fn with_batch_size(mut self, batch_size: NonZeroUsize) -> Self {
         self.batch_size = batch_size;
@@ -141,6 +154,13 @@ where
         self
     }
 
+    /// Set the single byte character used for escaping quotes, see
+    /// [`SerializeOptions::escape_char`].
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.options.escape_char = escape_char;
+        self
+    }
+
     /// Set the CSV file's null value representation.
     pub fn with_null_value(mut self, null_value: String) -> Self {
         self.options.null = null_value;
@@ -160,6 +180,12 @@ where
         self
     }
 
+    /// Set the compression of the output, see [`ExternalCompression`].
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
@@ -190,9 +216,10 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut writer = CompressedWriter::new(&mut self.writer.buffer, self.writer.compression);
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut writer)?;
         }
 
         if !self.has_written_header {
@@ -202,28 +229,30 @@ impl<W: Write> BatchedWriter<W> {
                 .into_iter()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(
-                &mut self.writer.buffer,
-                names.as_slice(),
-                &self.writer.options,
-            )?;
+            write_header(&mut writer, names.as_slice(), &self.writer.options)?;
         }
 
         write(
-            &mut self.writer.buffer,
+            &mut writer,
             df,
             self.writer.batch_size.into(),
             &self.writer.options,
             self.writer.n_threads,
         )?;
+        writer.finish()?;
         Ok(())
     }
 
     /// Writes the header of the csv file if not done already. Returns the total size of the file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if self.has_written_bom && self.has_written_header {
+            return Ok(());
+        }
+
+        let mut writer = CompressedWriter::new(&mut self.writer.buffer, self.writer.compression);
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut writer)?;
         }
 
         if !self.has_written_header {
@@ -233,9 +262,10 @@ impl<W: Write> BatchedWriter<W> {
                 .iter_names()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
+            write_header(&mut writer, &names, &self.writer.options)?;
         };
 
+        writer.finish()?;
         Ok(())
     }
 }
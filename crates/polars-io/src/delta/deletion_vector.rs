@@ -0,0 +1,301 @@
+use polars_core::prelude::IdxSize;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use serde::Deserialize;
+
+use super::storage::TableStorage;
+
+/// Magic number at the start of a serialized `RoaringBitmapArray`.
+const BITMAP_ARRAY_MAGIC: u32 = 1681511377;
+/// Cookie of a 32-bit roaring bitmap that contains run containers.
+const SERIAL_COOKIE: u16 = 12347;
+/// Cookie of a 32-bit roaring bitmap without run containers.
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+/// Containers with at most this cardinality are stored as sorted arrays.
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+/// Bitmaps with run containers and at least this many containers store container offsets.
+const NO_OFFSET_THRESHOLD: usize = 4;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Points to the rows of a data file that are marked as deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct DeletionVectorDescriptor {
+    /// `u` for a file relative to the table identified by a UUID, `p` for an absolute path and
+    /// `i` for a deletion vector stored inline.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifies the deletion vector together with the path of the data file.
+    pub(super) fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{offset}", self.storage_type, self.path_or_inline_dv),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Reads the sorted positions of the deleted rows.
+    pub(super) fn read(&self, storage: &TableStorage) -> PolarsResult<Vec<IdxSize>> {
+        let size = usize::try_from(self.size_in_bytes)
+            .map_err(|_| polars_err!(ComputeError: "invalid deletion vector size"))?;
+
+        let bytes = match self.storage_type.as_str() {
+            "i" => {
+                let mut bytes = z85_decode(&self.path_or_inline_dv)?;
+                polars_ensure!(
+                    bytes.len() >= size,
+                    ComputeError: "inline deletion vector is shorter than its size"
+                );
+                bytes.truncate(size);
+                bytes
+            },
+            "u" | "p" => {
+                let path = if self.storage_type == "u" {
+                    relative_uuid_path(&self.path_or_inline_dv)?
+                } else {
+                    self.path_or_inline_dv.clone()
+                };
+                let offset = self.offset.unwrap_or(1) as usize;
+
+                // The bitmap is prefixed by its size as a big-endian u32.
+                let bytes = storage.read_range(&path, offset..offset + 4 + size)?;
+                let stored_size = u32::from_be_bytes(bytes[..4].try_into().unwrap()) as usize;
+                polars_ensure!(
+                    stored_size == size,
+                    ComputeError: "deletion vector in '{}' has size {} but {} was expected",
+                    path, stored_size, size
+                );
+                bytes[4..].to_vec()
+            },
+            storage_type => {
+                polars_bail!(ComputeError: "unknown deletion vector storage type '{}'", storage_type)
+            },
+        };
+
+        let rows = deserialize_bitmap_array(&bytes)?;
+        polars_ensure!(
+            rows.len() as i64 == self.cardinality,
+            ComputeError: "deletion vector contains {} rows but its cardinality is {}",
+            rows.len(), self.cardinality
+        );
+
+        rows.into_iter()
+            .map(|row| {
+                IdxSize::try_from(row).map_err(|_| {
+                    polars_err!(ComputeError: "deleted row {} does not fit in the row index type", row)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the path of a deletion vector file stored relative to the table. The last 20
+/// characters encode a UUID, anything before that is a directory prefix.
+fn relative_uuid_path(path_or_inline_dv: &str) -> PolarsResult<String> {
+    polars_ensure!(
+        path_or_inline_dv.len() >= 20 && path_or_inline_dv.is_char_boundary(path_or_inline_dv.len() - 20),
+        ComputeError: "invalid deletion vector path '{}'", path_or_inline_dv
+    );
+    let (prefix, uuid) = path_or_inline_dv.split_at(path_or_inline_dv.len() - 20);
+    let uuid = z85_decode(uuid)?;
+
+    let hex = uuid.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    let file_name = format!(
+        "deletion_vector_{}-{}-{}-{}-{}.bin",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    );
+
+    Ok(if prefix.is_empty() {
+        file_name
+    } else {
+        format!("{prefix}/{file_name}")
+    })
+}
+
+fn z85_decode(encoded: &str) -> PolarsResult<Vec<u8>> {
+    polars_ensure!(
+        encoded.len() % 5 == 0,
+        ComputeError: "Z85 encoded data must have a length that is a multiple of 5"
+    );
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.as_bytes().chunks_exact(5) {
+        let mut value: u64 = 0;
+        for &c in chunk {
+            let digit = Z85_ALPHABET.iter().position(|&a| a == c).ok_or_else(
+                || polars_err!(ComputeError: "invalid Z85 character '{}'", c as char),
+            )?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid Z85 encoded data"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(out)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            self.0.len() >= n,
+            ComputeError: "deletion vector bitmap is truncated"
+        );
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Deserializes a `RoaringBitmapArray`: a magic number followed by 64-bit roaring bitmap in the
+/// portable format, i.e. a list of 32-bit bitmaps keyed by the high 32 bits of their values.
+fn deserialize_bitmap_array(bytes: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut reader = Reader(bytes);
+    polars_ensure!(
+        reader.u32()? == BITMAP_ARRAY_MAGIC,
+        ComputeError: "deletion vector has an invalid magic number"
+    );
+
+    let num_bitmaps = reader.u64()?;
+    let mut out = vec![];
+    for _ in 0..num_bitmaps {
+        let high = (reader.u32()? as u64) << 32;
+        deserialize_roaring_bitmap(&mut reader, high, &mut out)?;
+    }
+    Ok(out)
+}
+
+/// Deserializes a 32-bit roaring bitmap in the portable format, adding `high` to its values.
+fn deserialize_roaring_bitmap(
+    reader: &mut Reader<'_>,
+    high: u64,
+    out: &mut Vec<u64>,
+) -> PolarsResult<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE as u32 {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_flags = reader.take(num_containers.div_ceil(8))?;
+        (num_containers, Some(run_flags))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        polars_bail!(ComputeError: "deletion vector contains an invalid roaring bitmap");
+    };
+
+    let header = (0..num_containers)
+        .map(|_| Ok((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if run_flags.is_none() || num_containers >= NO_OFFSET_THRESHOLD {
+        reader.take(4 * num_containers)?;
+    }
+
+    for (i, (key, cardinality)) in header.into_iter().enumerate() {
+        let base = high | ((key as u64) << 16);
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u64;
+                let length = reader.u16()? as u64;
+                out.extend((start..=start + length).map(|v| base | v));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                out.push(base | reader.u16()? as u64);
+            }
+        } else {
+            let words = reader.take(8192)?;
+            for (w, word) in words.chunks_exact(8).enumerate() {
+                let mut word = u64::from_le_bytes(word.try_into().unwrap());
+                while word != 0 {
+                    let bit = word.trailing_zeros() as u64;
+                    out.push(base | (w as u64 * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // Example from the Z85 specification.
+        assert_eq!(
+            z85_decode("HelloWorld").unwrap(),
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_relative_uuid_path() {
+        assert_eq!(
+            relative_uuid_path("ab^-aqEH.-t@S}K{vb[*k^").unwrap(),
+            "ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_bitmap_array() {
+        let mut bytes = BITMAP_ARRAY_MAGIC.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+
+        // An array container without run containers.
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0, 2, 0]);
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        for v in [3u16, 5, 8] {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+
+        // A run container for the values 2^32 + 10..=2^32 + 12.
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&(SERIAL_COOKIE as u32).to_le_bytes());
+        bytes.push(1);
+        bytes.extend_from_slice(&[0, 0, 2, 0]);
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&10u16.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+
+        assert_eq!(
+            deserialize_bitmap_array(&bytes).unwrap(),
+            [3, 5, 8, (1 << 32) + 10, (1 << 32) + 11, (1 << 32) + 12]
+        );
+        assert!(deserialize_bitmap_array(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
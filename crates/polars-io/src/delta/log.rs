@@ -0,0 +1,288 @@
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::aliases::PlHashMap;
+use serde::Deserialize;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use crate::SerReader;
+use crate::parquet::read::ParquetReader;
+
+/// Adds a data file to the table.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Add {
+    /// Percent-encoded path of the file, relative to the table or absolute.
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: PlHashMap<String, Option<String>>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// Removes a data file from the table.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Remove {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: PlHashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Protocol {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+/// A single action of a commit. Actions that do not affect reading (e.g. `commitInfo` and `txn`)
+/// are ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Action {
+    pub add: Option<Add>,
+    pub remove: Option<Remove>,
+    pub meta_data: Option<Metadata>,
+    pub protocol: Option<Protocol>,
+}
+
+/// Kind of a file in the `_delta_log` directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum LogFile {
+    Commit {
+        version: i64,
+    },
+    Checkpoint {
+        version: i64,
+        part: u32,
+        num_parts: u32,
+    },
+}
+
+impl LogFile {
+    /// Parses names of the form `<version>.json`, `<version>.checkpoint.parquet` and
+    /// `<version>.checkpoint.<part>.<num_parts>.parquet`. Returns `None` for other files.
+    pub(super) fn parse(name: &str) -> Option<Self> {
+        let (version, rest) = name.split_once('.')?;
+        if version.len() != 20 {
+            return None;
+        }
+        let version = version.parse().ok()?;
+
+        match rest.split('.').collect::<Vec<_>>().as_slice() {
+            ["json"] => Some(Self::Commit { version }),
+            ["checkpoint", "parquet"] => Some(Self::Checkpoint {
+                version,
+                part: 1,
+                num_parts: 1,
+            }),
+            ["checkpoint", part, num_parts, "parquet"] if part.len() == 10 => {
+                Some(Self::Checkpoint {
+                    version,
+                    part: part.parse().ok()?,
+                    num_parts: num_parts.parse().ok()?,
+                })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parses the newline-delimited actions of a JSON commit file.
+pub(super) fn parse_commit(bytes: &[u8]) -> PolarsResult<Vec<Action>> {
+    bytes
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| serde_json::from_slice(line).map_err(to_compute_err))
+        .collect()
+}
+
+/// Reads the `add`, `metaData` and `protocol` actions of a Parquet checkpoint file.
+pub(super) fn parse_checkpoint(bytes: Vec<u8>) -> PolarsResult<Vec<Action>> {
+    let mut reader = ParquetReader::new(Cursor::new(bytes));
+    let schema = reader.schema()?;
+    let columns = ["add", "metaData", "protocol"]
+        .into_iter()
+        .filter(|name| schema.contains(name))
+        .map(String::from)
+        .collect();
+    let df = reader.with_columns(Some(columns)).finish()?;
+
+    let mut actions = vec![];
+
+    if let Ok(add) = df.column("add") {
+        let add = add.struct_()?;
+        let is_add = add.is_not_null();
+        let path = string_field(add, "path")?;
+        let partition_values = add.field_by_name("partitionValues").ok();
+        let partition_values = partition_values.as_ref().map(|s| s.list()).transpose()?;
+        let deletion_vector = add.field_by_name("deletionVector").ok();
+        let deletion_vector = deletion_vector
+            .as_ref()
+            .map(|s| DeletionVectors::new(s.struct_()?))
+            .transpose()?;
+
+        for i in 0..add.len() {
+            let (Some(true), Some(path)) = (is_add.get(i), path.get(i)) else {
+                continue;
+            };
+            let partition_values = match partition_values.and_then(|pv| pv.get_as_series(i)) {
+                Some(map) => map_entries(&map)?.into_iter().collect(),
+                None => PlHashMap::default(),
+            };
+
+            actions.push(Action {
+                add: Some(Add {
+                    path: path.to_string(),
+                    partition_values,
+                    deletion_vector: match &deletion_vector {
+                        Some(dv) => dv.get(i)?,
+                        None => None,
+                    },
+                }),
+                ..Default::default()
+            });
+        }
+    }
+
+    if let Ok(metadata) = df.column("metaData") {
+        let metadata = metadata.struct_()?;
+        let schema_string = string_field(metadata, "schemaString")?;
+        let partition_columns = metadata.field_by_name("partitionColumns")?;
+        let partition_columns = partition_columns.list()?;
+        let configuration = metadata.field_by_name("configuration").ok();
+        let configuration = configuration.as_ref().map(|s| s.list()).transpose()?;
+
+        for i in 0..metadata.len() {
+            let Some(schema_string) = schema_string.get(i) else {
+                continue;
+            };
+            let partition_columns = match partition_columns.get_as_series(i) {
+                Some(s) => s.str()?.into_no_null_iter().map(String::from).collect(),
+                None => vec![],
+            };
+            let configuration = match configuration.and_then(|c| c.get_as_series(i)) {
+                Some(map) => map_entries(&map)?.into_iter().collect(),
+                None => PlHashMap::default(),
+            };
+
+            actions.push(Action {
+                meta_data: Some(Metadata {
+                    schema_string: schema_string.to_string(),
+                    partition_columns,
+                    configuration,
+                }),
+                ..Default::default()
+            });
+        }
+    }
+
+    if let Ok(protocol) = df.column("protocol") {
+        let protocol = protocol.struct_()?;
+        let min_reader_version = protocol
+            .field_by_name("minReaderVersion")?
+            .cast(&DataType::Int32)?;
+        let min_reader_version = min_reader_version.i32()?;
+        let reader_features = protocol.field_by_name("readerFeatures").ok();
+        let reader_features = reader_features.as_ref().map(|s| s.list()).transpose()?;
+
+        for i in 0..protocol.len() {
+            let Some(min_reader_version) = min_reader_version.get(i) else {
+                continue;
+            };
+            let reader_features = reader_features
+                .and_then(|f| f.get_as_series(i))
+                .map(|s| PolarsResult::Ok(s.str()?.into_no_null_iter().map(String::from).collect()))
+                .transpose()?;
+
+            actions.push(Action {
+                protocol: Some(Protocol {
+                    min_reader_version,
+                    reader_features,
+                }),
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(actions)
+}
+
+fn string_field(ca: &StructChunked, name: &str) -> PolarsResult<StringChunked> {
+    Ok(ca.field_by_name(name)?.str()?.clone())
+}
+
+/// Returns the entries of a map read from Parquet as a list of key-value structs.
+fn map_entries(map: &Series) -> PolarsResult<Vec<(String, Option<String>)>> {
+    let fields = map.struct_()?.fields_as_series();
+    let [keys, values] = fields.as_slice() else {
+        polars_bail!(ComputeError: "invalid map in Delta checkpoint");
+    };
+    let values = values.cast(&DataType::String)?;
+
+    Ok(keys
+        .str()?
+        .iter()
+        .zip(values.str()?.iter())
+        .filter_map(|(k, v)| Some((k?.to_string(), v.map(String::from))))
+        .collect())
+}
+
+/// Fields of the `deletionVector` struct of a checkpoint.
+struct DeletionVectors {
+    is_valid: BooleanChunked,
+    storage_type: StringChunked,
+    path_or_inline_dv: StringChunked,
+    offset: Int32Chunked,
+    size_in_bytes: Int32Chunked,
+    cardinality: Int64Chunked,
+}
+
+impl DeletionVectors {
+    fn new(ca: &StructChunked) -> PolarsResult<Self> {
+        let int_field = |name: &str, dtype: &DataType| ca.field_by_name(name)?.cast(dtype);
+
+        Ok(Self {
+            is_valid: ca.is_not_null(),
+            storage_type: string_field(ca, "storageType")?,
+            path_or_inline_dv: string_field(ca, "pathOrInlineDv")?,
+            offset: int_field("offset", &DataType::Int32)?.i32()?.clone(),
+            size_in_bytes: int_field("sizeInBytes", &DataType::Int32)?.i32()?.clone(),
+            cardinality: int_field("cardinality", &DataType::Int64)?.i64()?.clone(),
+        })
+    }
+
+    fn get(&self, i: usize) -> PolarsResult<Option<DeletionVectorDescriptor>> {
+        let (Some(true), Some(storage_type), Some(path_or_inline_dv)) = (
+            self.is_valid.get(i),
+            self.storage_type.get(i),
+            self.path_or_inline_dv.get(i),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(DeletionVectorDescriptor {
+            storage_type: storage_type.to_string(),
+            path_or_inline_dv: path_or_inline_dv.to_string(),
+            offset: self.offset.get(i),
+            size_in_bytes: self
+                .size_in_bytes
+                .get(i)
+                .ok_or_else(|| polars_err!(ComputeError: "deletion vector without size"))?,
+            cardinality: self.cardinality.get(i).unwrap_or(0),
+        }))
+    }
+}
@@ -0,0 +1,25 @@
+//! Reading of [Delta Lake](https://delta.io) tables.
+//!
+//! The active data files of a table are resolved by replaying the JSON commits and Parquet
+//! checkpoints in its `_delta_log` directory. The data files themselves are plain Parquet files
+//! and are read by the Parquet reader, with the partition values as hive columns.
+mod deletion_vector;
+mod log;
+mod schema;
+mod snapshot;
+mod storage;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use snapshot::{DeltaFile, DeltaSnapshot};
+
+/// Selects the version of a Delta table that is read. By default the latest version is read.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeltaScanOptions {
+    /// Read the table at this version.
+    pub version: Option<i64>,
+    /// Read the latest version committed at or before this time, in milliseconds since the Unix
+    /// epoch.
+    pub timestamp: Option<i64>,
+}
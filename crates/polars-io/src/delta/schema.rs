@@ -0,0 +1,145 @@
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use serde_json::Value;
+
+/// Converts the JSON `schemaString` of a Delta table to a Polars schema.
+pub(super) fn parse_schema(schema_string: &str) -> PolarsResult<Schema> {
+    let value: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+    let DataType::Struct(fields) = to_dtype(&value)? else {
+        polars_bail!(ComputeError: "the schema of a Delta table must be a struct");
+    };
+    Ok(Schema::from_iter(fields))
+}
+
+fn to_dtype(value: &Value) -> PolarsResult<DataType> {
+    let dtype = match value {
+        Value::String(name) => primitive_to_dtype(name)?,
+        Value::Object(object) => {
+            let field = |name: &str| {
+                object.get(name).ok_or_else(
+                    || polars_err!(ComputeError: "Delta schema type is missing '{}'", name),
+                )
+            };
+
+            match field("type")?.as_str() {
+                Some("struct") => {
+                    let Value::Array(fields) = field("fields")? else {
+                        polars_bail!(ComputeError: "invalid Delta struct type: {}", value);
+                    };
+                    let fields = fields
+                        .iter()
+                        .map(|field| {
+                            let name = field
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .ok_or_else(|| polars_err!(ComputeError: "Delta struct field without a name"))?;
+                            let dtype = field.get("type").ok_or_else(
+                                || polars_err!(ComputeError: "Delta struct field '{}' without a type", name),
+                            )?;
+                            Ok(Field::new(name.into(), to_dtype(dtype)?))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    DataType::Struct(fields)
+                },
+                Some("array") => DataType::List(Box::new(to_dtype(field("elementType")?)?)),
+                Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key".into(), to_dtype(field("keyType")?)?),
+                    Field::new("value".into(), to_dtype(field("valueType")?)?),
+                ]))),
+                _ => polars_bail!(ComputeError: "unsupported Delta type: {}", value),
+            }
+        },
+        _ => polars_bail!(ComputeError: "invalid Delta type: {}", value),
+    };
+    Ok(dtype)
+}
+
+fn primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    let dtype = match name {
+        "string" => DataType::String,
+        "long" => DataType::Int64,
+        "integer" => DataType::Int32,
+        "short" => DataType::Int16,
+        "byte" => DataType::Int8,
+        "float" => DataType::Float32,
+        "double" => DataType::Float64,
+        "boolean" => DataType::Boolean,
+        "binary" => DataType::Binary,
+        "date" => DataType::Date,
+        "timestamp" => {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        },
+        "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+        _ => {
+            if let Some(args) = name
+                .strip_prefix("decimal(")
+                .and_then(|rest| rest.strip_suffix(')'))
+            {
+                return decimal_to_dtype(name, args);
+            }
+            polars_bail!(ComputeError: "unsupported Delta type '{}'", name)
+        },
+    };
+    Ok(dtype)
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn decimal_to_dtype(name: &str, args: &str) -> PolarsResult<DataType> {
+    let parse = |s: Option<&str>| s.and_then(|s| s.trim().parse::<usize>().ok());
+    let mut args = args.split(',');
+    match (parse(args.next()), parse(args.next())) {
+        (Some(precision), Some(scale)) => Ok(DataType::Decimal(Some(precision), Some(scale))),
+        _ => polars_bail!(ComputeError: "invalid Delta type '{}'", name),
+    }
+}
+
+#[cfg(not(feature = "dtype-decimal"))]
+fn decimal_to_dtype(name: &str, _args: &str) -> PolarsResult<DataType> {
+    polars_bail!(ComputeError: "reading Delta type '{}' requires the 'dtype-decimal' feature", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = parse_schema(
+            r#"{"type":"struct","fields":[
+                {"name":"id","type":"long","nullable":true,"metadata":{}},
+                {"name":"ts","type":"timestamp","nullable":true,"metadata":{}},
+                {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+                {"name":"attrs","type":{"type":"map","keyType":"string","valueType":"integer","valueContainsNull":true},"nullable":true,"metadata":{}},
+                {"name":"nested","type":{"type":"struct","fields":[{"name":"d","type":"date","nullable":true,"metadata":{}}]},"nullable":true,"metadata":{}}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schema,
+            Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into()))
+                ),
+                Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+                Field::new(
+                    "attrs".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+                Field::new(
+                    "nested".into(),
+                    DataType::Struct(vec![Field::new("d".into(), DataType::Date)])
+                ),
+            ])
+        );
+
+        assert!(
+            parse_schema(r#"{"type":"struct","fields":[{"name":"v","type":"variant"}]}"#).is_err()
+        );
+    }
+}
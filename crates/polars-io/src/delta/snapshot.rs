@@ -0,0 +1,386 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::aliases::PlHashMap;
+
+use super::DeltaScanOptions;
+use super::log::{Action, Add, LogFile, Metadata, Protocol, parse_checkpoint, parse_commit};
+use super::schema::parse_schema;
+use super::storage::{LogEntry, TableStorage};
+use crate::cloud::CloudOptions;
+
+/// Highest reader protocol version that is supported.
+const MAX_READER_VERSION: i32 = 3;
+/// Reader features (protocol version 3) that can be read.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// A data file of a Delta table snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaFile {
+    /// Full path or URI of the Parquet file.
+    pub path: PathBuf,
+    /// Serialized values of the partition columns, in the order of
+    /// [`DeltaSnapshot::partition_columns`].
+    pub partition_values: Vec<Option<PlSmallStr>>,
+    /// Sorted positions of the rows that are marked as deleted by a deletion vector.
+    pub deleted_rows: Option<Arc<[IdxSize]>>,
+}
+
+/// The state of a Delta table at a specific version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    /// Schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    /// Active data files of the snapshot.
+    pub files: Vec<DeltaFile>,
+}
+
+impl DeltaSnapshot {
+    /// Resolves the snapshot of the table at `uri` selected by `options` by replaying its
+    /// transaction log from the latest checkpoint.
+    pub fn try_new(
+        uri: &str,
+        options: &DeltaScanOptions,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.version.is_none() || options.timestamp.is_none(),
+            InvalidOperation: "cannot time travel a Delta table by both version and timestamp"
+        );
+
+        let storage = TableStorage::try_new(uri, cloud_options)?;
+        let log = LogListing::new(storage.list_log()?);
+        let version = log.target_version(options)?;
+
+        let checkpoint = log.checkpoint_before(version);
+        let first_commit = checkpoint.as_ref().map_or(0, |(v, _)| v + 1);
+        for v in first_commit..=version {
+            polars_ensure!(
+                log.commits.contains_key(&v),
+                ComputeError: "cannot load version {} of the Delta table: commit {} is missing from the log",
+                version, v
+            );
+        }
+
+        let mut state = LogReplay::default();
+        if let Some((_, parts)) = checkpoint {
+            for name in parts {
+                let bytes = storage.read(&format!("_delta_log/{name}"))?;
+                state.apply(parse_checkpoint(bytes)?)?;
+            }
+        }
+        for v in first_commit..=version {
+            let bytes = storage.read(&format!("_delta_log/{}", log.commits[&v].name))?;
+            state.apply(parse_commit(&bytes)?)?;
+        }
+
+        let protocol = state
+            .protocol
+            .ok_or_else(|| polars_err!(ComputeError: "Delta table has no protocol action"))?;
+        let metadata = state
+            .metadata
+            .ok_or_else(|| polars_err!(ComputeError: "Delta table has no metadata action"))?;
+        check_protocol(&protocol, &metadata)?;
+
+        let schema = parse_schema(&metadata.schema_string)?;
+        for name in &metadata.partition_columns {
+            polars_ensure!(
+                schema.contains(name),
+                ComputeError: "partition column '{}' is not in the schema of the Delta table", name
+            );
+        }
+
+        let mut files = state.files.into_values().collect::<Vec<_>>();
+        files.sort_unstable_by_key(|(seq, _)| *seq);
+        let files = files
+            .into_iter()
+            .map(|(_, add)| {
+                let partition_values = metadata
+                    .partition_columns
+                    .iter()
+                    .map(|name| {
+                        add.partition_values
+                            .get(name)
+                            .cloned()
+                            .flatten()
+                            .map(PlSmallStr::from)
+                    })
+                    .collect();
+                let deleted_rows = add
+                    .deletion_vector
+                    .as_ref()
+                    .map(|dv| dv.read(&storage).map(Arc::from))
+                    .transpose()?;
+
+                Ok(DeltaFile {
+                    path: resolve_path(&storage, &add.path)?,
+                    partition_values,
+                    deleted_rows,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(Self {
+            version,
+            schema: Arc::new(schema),
+            partition_columns: metadata
+                .partition_columns
+                .iter()
+                .map(PlSmallStr::from)
+                .collect(),
+            files,
+        })
+    }
+}
+
+/// Commits and checkpoints found in the `_delta_log` directory.
+struct LogListing {
+    commits: PlHashMap<i64, LogEntry>,
+    /// Checkpoint parts by version, with the total number of parts.
+    checkpoints: PlHashMap<i64, Vec<(LogEntry, u32, u32)>>,
+}
+
+impl LogListing {
+    fn new(entries: Vec<LogEntry>) -> Self {
+        let mut commits = PlHashMap::default();
+        let mut checkpoints = PlHashMap::<_, Vec<_>>::default();
+
+        for entry in entries {
+            match LogFile::parse(&entry.name) {
+                Some(LogFile::Commit { version }) => {
+                    commits.insert(version, entry);
+                },
+                Some(LogFile::Checkpoint {
+                    version,
+                    part,
+                    num_parts,
+                }) => checkpoints
+                    .entry(version)
+                    .or_default()
+                    .push((entry, part, num_parts)),
+                None => {},
+            }
+        }
+
+        Self {
+            commits,
+            checkpoints,
+        }
+    }
+
+    /// Returns the version of the snapshot that is selected by the options.
+    fn target_version(&self, options: &DeltaScanOptions) -> PolarsResult<i64> {
+        let latest = self
+            .commits
+            .keys()
+            .chain(self.checkpoints.keys())
+            .copied()
+            .max()
+            .ok_or_else(
+                || polars_err!(ComputeError: "Delta table log does not contain any commits"),
+            )?;
+
+        if let Some(version) = options.version {
+            polars_ensure!(
+                (0..=latest).contains(&version),
+                ComputeError: "version {} of the Delta table does not exist, the latest version is {}",
+                version, latest
+            );
+            return Ok(version);
+        }
+
+        if let Some(timestamp) = options.timestamp {
+            return self
+                .commits
+                .iter()
+                .filter(|(_, entry)| entry.last_modified <= timestamp)
+                .map(|(version, _)| *version)
+                .max()
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "timestamp {} is before the earliest available commit of the Delta table",
+                        timestamp
+                    )
+                });
+        }
+
+        Ok(latest)
+    }
+
+    /// Returns the latest complete checkpoint at or before `version`, as the file names of its
+    /// parts.
+    fn checkpoint_before(&self, version: i64) -> Option<(i64, Vec<String>)> {
+        let mut candidates = self
+            .checkpoints
+            .keys()
+            .copied()
+            .filter(|v| *v <= version)
+            .collect::<Vec<_>>();
+        candidates.sort_unstable();
+
+        candidates.into_iter().rev().find_map(|v| {
+            let parts = &self.checkpoints[&v];
+            // Multi-part checkpoints are only usable when all of their parts have been written.
+            // A version may also have several checkpoints with a different number of parts.
+            let mut by_num_parts = PlHashMap::<u32, Vec<(u32, &str)>>::default();
+            for (entry, part, num_parts) in parts {
+                by_num_parts
+                    .entry(*num_parts)
+                    .or_default()
+                    .push((*part, entry.name.as_str()));
+            }
+            by_num_parts.into_iter().find_map(|(num_parts, mut parts)| {
+                parts.sort_unstable();
+                parts.dedup_by_key(|(part, _)| *part);
+                let complete = parts.len() == num_parts as usize
+                    && parts.iter().zip(1..).all(|((part, _), i)| *part == i);
+                complete.then(|| (v, parts.into_iter().map(|(_, n)| n.to_string()).collect()))
+            })
+        })
+    }
+}
+
+/// State of the table while the log is replayed.
+#[derive(Default)]
+struct LogReplay {
+    protocol: Option<Protocol>,
+    metadata: Option<Metadata>,
+    /// Active files keyed by their decoded path and deletion vector, with the order in which they
+    /// were added.
+    files: PlHashMap<(String, Option<String>), (usize, Add)>,
+    num_added: usize,
+}
+
+impl LogReplay {
+    fn apply(&mut self, actions: Vec<Action>) -> PolarsResult<()> {
+        for action in actions {
+            if let Some(protocol) = action.protocol {
+                self.protocol = Some(protocol);
+            }
+            if let Some(metadata) = action.meta_data {
+                self.metadata = Some(metadata);
+            }
+            if let Some(remove) = action.remove {
+                let dv = remove.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                self.files.remove(&(decode(&remove.path)?, dv));
+            }
+            if let Some(add) = action.add {
+                let dv = add.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                self.files
+                    .insert((decode(&add.path)?, dv), (self.num_added, add));
+                self.num_added += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_protocol(protocol: &Protocol, metadata: &Metadata) -> PolarsResult<()> {
+    polars_ensure!(
+        protocol.min_reader_version <= MAX_READER_VERSION,
+        ComputeError: "Delta table requires reader version {}, but at most {} is supported",
+        protocol.min_reader_version, MAX_READER_VERSION
+    );
+
+    if protocol.min_reader_version == MAX_READER_VERSION {
+        for feature in protocol.reader_features.iter().flatten() {
+            polars_ensure!(
+                SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+                ComputeError: "Delta table requires unsupported reader feature '{}'", feature
+            );
+        }
+    }
+
+    match metadata
+        .configuration
+        .get("delta.columnMapping.mode")
+        .cloned()
+        .flatten()
+        .as_deref()
+    {
+        None | Some("none") => Ok(()),
+        Some(mode) => {
+            polars_bail!(
+                nyi = "reading Delta tables with column mapping mode '{}'",
+                mode
+            )
+        },
+    }
+}
+
+/// Resolves the path of an `add` action, which is either a percent-encoded path relative to the
+/// table root or an absolute URI.
+fn resolve_path(storage: &TableStorage, path: &str) -> PolarsResult<PathBuf> {
+    if let Some(path) = path.strip_prefix("file://") {
+        return Ok(PathBuf::from(decode(path)?));
+    }
+    if path.contains("://") {
+        return Ok(PathBuf::from(path));
+    }
+    Ok(storage.full_path(&decode(path)?))
+}
+
+fn decode(path: &str) -> PolarsResult<String> {
+    percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map(|path| path.into_owned())
+        .map_err(|_| polars_err!(ComputeError: "invalid path '{}' in Delta log", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(names: &[&str]) -> LogListing {
+        LogListing::new(
+            names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| LogEntry {
+                    name: name.to_string(),
+                    last_modified: 1000 * i as i64,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_log_listing() {
+        let log = listing(&[
+            "00000000000000000000.json",
+            "00000000000000000001.json",
+            "00000000000000000002.json",
+            "00000000000000000002.checkpoint.parquet",
+            "00000000000000000003.json",
+            "00000000000000000004.checkpoint.0000000001.0000000002.parquet",
+            "00000000000000000004.json",
+            "_last_checkpoint",
+        ]);
+
+        let options = |version, timestamp| DeltaScanOptions { version, timestamp };
+        assert_eq!(log.target_version(&options(None, None)).unwrap(), 4);
+        assert_eq!(log.target_version(&options(Some(1), None)).unwrap(), 1);
+        assert!(log.target_version(&options(Some(5), None)).is_err());
+        assert_eq!(log.target_version(&options(None, Some(2500))).unwrap(), 2);
+        assert!(log.target_version(&options(None, Some(-1))).is_err());
+
+        // The checkpoint of version 4 is missing its second part.
+        assert_eq!(
+            log.checkpoint_before(4),
+            Some((
+                2,
+                vec!["00000000000000000002.checkpoint.parquet".to_string()]
+            ))
+        );
+        assert_eq!(log.checkpoint_before(1), None);
+    }
+}
@@ -0,0 +1,196 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+
+use crate::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use crate::cloud::PolarsObjectStore;
+use crate::path_utils::is_cloud_url;
+
+/// A file in the `_delta_log` directory of a table.
+pub(super) struct LogEntry {
+    pub name: String,
+    /// Last modification time in milliseconds since the Unix epoch.
+    pub last_modified: i64,
+}
+
+/// Location of a table, either on the local file system or in an object store.
+pub(super) enum TableStorage {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        uri: String,
+        store: PolarsObjectStore,
+        /// Path of the table inside the object store.
+        prefix: String,
+    },
+}
+
+impl TableStorage {
+    pub(super) fn try_new(
+        uri: &str,
+        #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+            &CloudOptions,
+        >,
+    ) -> PolarsResult<Self> {
+        let uri = uri.trim_end_matches('/');
+
+        if is_cloud_url(uri) {
+            #[cfg(feature = "cloud")]
+            {
+                let (location, store) = crate::pl_async::get_runtime().block_in_place_on(
+                    crate::cloud::build_object_store(uri, cloud_options, false),
+                )?;
+                return Ok(Self::Cloud {
+                    uri: uri.to_string(),
+                    store,
+                    prefix: location.prefix.trim_end_matches('/').to_string(),
+                });
+            }
+            #[cfg(not(feature = "cloud"))]
+            polars_bail!(ComputeError: "reading Delta tables from cloud storage requires the 'cloud' feature");
+        }
+
+        Ok(Self::Local(PathBuf::from(uri)))
+    }
+
+    /// Returns the full path of a file given relative to the root of the table.
+    pub(super) fn full_path(&self, relative_path: &str) -> PathBuf {
+        match self {
+            Self::Local(root) => root.join(relative_path),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { uri, .. } => PathBuf::from(format!("{uri}/{relative_path}")),
+        }
+    }
+
+    /// Lists the files in the `_delta_log` directory.
+    pub(super) fn list_log(&self) -> PolarsResult<Vec<LogEntry>> {
+        let entries = match self {
+            Self::Local(root) => {
+                let log_dir = root.join("_delta_log");
+                if !log_dir.is_dir() {
+                    polars_bail!(
+                        ComputeError: "no Delta table found at '{}': missing '_delta_log' directory",
+                        root.display()
+                    );
+                }
+
+                std::fs::read_dir(&log_dir)?
+                    .map(|entry| {
+                        let entry = entry?;
+                        let last_modified = entry
+                            .metadata()?
+                            .modified()?
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .map_err(to_compute_err)?
+                            .as_millis() as i64;
+
+                        Ok(LogEntry {
+                            name: entry.file_name().to_string_lossy().into_owned(),
+                            last_modified,
+                        })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { uri, store, prefix } => {
+                use futures::TryStreamExt;
+
+                let log_prefix = object_path(prefix, "_delta_log");
+                let log_prefix = &log_prefix;
+
+                let entries = crate::pl_async::get_runtime().block_in_place_on(
+                    store.try_exec_rebuild_on_err(|store| {
+                        let store = store.clone();
+
+                        async move {
+                            store
+                                .list(Some(log_prefix))
+                                .try_filter_map(|meta| async move {
+                                    // Skip the contents of nested directories.
+                                    let is_child = meta
+                                        .location
+                                        .prefix_match(log_prefix)
+                                        .is_some_and(|parts| parts.count() == 1);
+                                    let out = is_child
+                                        .then(|| meta.location.filename())
+                                        .flatten()
+                                        .map(|name| LogEntry {
+                                            name: name.to_string(),
+                                            last_modified: meta.last_modified.timestamp_millis(),
+                                        });
+                                    Ok(out)
+                                })
+                                .try_collect::<Vec<_>>()
+                                .await
+                                .map_err(to_compute_err)
+                        }
+                    }),
+                )?;
+
+                if entries.is_empty() {
+                    polars_bail!(
+                        ComputeError: "no Delta table found at '{}': missing '_delta_log' directory",
+                        uri
+                    );
+                }
+                entries
+            },
+        };
+
+        Ok(entries)
+    }
+
+    /// Reads a file given relative to the root of the table.
+    pub(super) fn read(&self, relative_path: &str) -> PolarsResult<Vec<u8>> {
+        match self {
+            Self::Local(root) => Ok(std::fs::read(root.join(relative_path))?),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                let path = object_path(prefix, relative_path);
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let size = store.head(&path).await?.size as usize;
+                    Ok(store.get_range(&path, 0..size).await?.to_vec())
+                })
+            },
+        }
+    }
+
+    /// Reads a byte range of a file given relative to the root of the table.
+    pub(super) fn read_range(
+        &self,
+        relative_path: &str,
+        range: Range<usize>,
+    ) -> PolarsResult<Vec<u8>> {
+        match self {
+            Self::Local(root) => read_local_range(&root.join(relative_path), range),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                let path = object_path(prefix, relative_path);
+                let bytes = crate::pl_async::get_runtime()
+                    .block_in_place_on(store.get_range(&path, range))?;
+                Ok(bytes.to_vec())
+            },
+        }
+    }
+}
+
+fn read_local_range(path: &Path, range: Range<usize>) -> PolarsResult<Vec<u8>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path)?;
+    file.seek(SeekFrom::Start(range.start as u64))?;
+    let mut bytes = vec![0; range.len()];
+    file.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(feature = "cloud")]
+fn object_path(prefix: &str, relative_path: &str) -> crate::cloud::ObjectStorePath {
+    if prefix.is_empty() {
+        crate::cloud::ObjectStorePath::from(relative_path)
+    } else {
+        crate::cloud::ObjectStorePath::from(format!("{prefix}/{relative_path}"))
+    }
+}
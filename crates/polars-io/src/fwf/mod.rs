@@ -0,0 +1,10 @@
+//! Reading of fixed-width text files.
+//!
+//! Every line holds one row and every column sits at a fixed position in the line, given by a
+//! column spec of name, offset, width and dtype. The fields are parsed with the CSV buffers, so
+//! the number and temporal parsing is the same as for CSV.
+mod options;
+mod read;
+
+pub use options::*;
+pub use read::*;
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::NullValues;
+
+/// A column of a fixed-width file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FwfColumn {
+    pub name: PlSmallStr,
+    /// Start of the column in the line, counted in [`FwfOffsetUnit`]s.
+    pub offset: usize,
+    /// Width of the column, counted in [`FwfOffsetUnit`]s.
+    pub width: usize,
+    pub dtype: DataType,
+}
+
+impl FwfColumn {
+    pub fn new(name: impl Into<PlSmallStr>, offset: usize, width: usize, dtype: DataType) -> Self {
+        Self {
+            name: name.into(),
+            offset,
+            width,
+            dtype,
+        }
+    }
+}
+
+/// The unit in which the offsets and widths of [`FwfColumn`]s are counted.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FwfOffsetUnit {
+    /// Byte positions in the line.
+    #[default]
+    Bytes,
+    /// Unicode character positions in the line. Lines that are pure ASCII take the same path as
+    /// [`FwfOffsetUnit::Bytes`].
+    Characters,
+}
+
+/// Options for reading fixed-width text files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FwfReadOptions {
+    pub columns: Arc<[FwfColumn]>,
+    pub offset_unit: FwfOffsetUnit,
+    /// Number of lines to skip at the start of the file, e.g. a header.
+    pub skip_rows: usize,
+    /// Strip leading and trailing whitespace from every field.
+    pub trim: bool,
+    pub null_values: Option<NullValues>,
+    pub eol_char: u8,
+    pub decimal_comma: bool,
+    pub ignore_errors: bool,
+}
+
+impl Default for FwfReadOptions {
+    fn default() -> Self {
+        Self {
+            columns: Arc::from([]),
+            offset_unit: FwfOffsetUnit::default(),
+            skip_rows: 0,
+            trim: true,
+            null_values: None,
+            eol_char: b'\n',
+            decimal_comma: false,
+            ignore_errors: false,
+        }
+    }
+}
+
+impl FwfReadOptions {
+    pub fn new(columns: impl Into<Arc<[FwfColumn]>>) -> Self {
+        Self {
+            columns: columns.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Count offsets and widths in bytes or in characters.
+    pub fn with_offset_unit(mut self, offset_unit: FwfOffsetUnit) -> Self {
+        self.offset_unit = offset_unit;
+        self
+    }
+
+    /// Number of lines to skip at the start of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Strip leading and trailing whitespace from every field.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Parse floats with a comma as decimal separator.
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Continue with next row when a field fails to parse, setting it to null.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// The schema of the file, as given by the column spec.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        polars_ensure!(
+            !self.columns.is_empty(),
+            InvalidOperation: "fixed-width reading requires at least one column"
+        );
+
+        let mut schema = Schema::with_capacity(self.columns.len());
+        for column in self.columns.iter() {
+            polars_ensure!(
+                column.width > 0,
+                InvalidOperation: "fixed-width column '{}' has a width of 0", column.name
+            );
+            polars_ensure!(
+                schema
+                    .insert(column.name.clone(), column.dtype.clone())
+                    .is_none(),
+                Duplicate: "fixed-width column '{}' is specified more than once", column.name
+            );
+        }
+
+        Ok(schema)
+    }
+}
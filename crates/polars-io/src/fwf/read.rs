@@ -0,0 +1,395 @@
+use std::ops::Range;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use rayon::prelude::*;
+
+use super::options::{FwfOffsetUnit, FwfReadOptions};
+use crate::csv::read::_csv_read_internal::{NullValuesCompiled, cast_columns, prepare_csv_schema};
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::buffer::{Buffer, init_buffers, validate_utf8};
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::get_reader_bytes;
+
+const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+/// A range of whole lines of a fixed-width file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FwfChunk {
+    /// Byte range of the chunk in the file.
+    pub range: Range<usize>,
+    /// Number of rows in the chunk.
+    pub n_rows: usize,
+}
+
+/// Offset of the first data line, after the BOM and the skipped lines.
+fn data_start(bytes: &[u8], options: &FwfReadOptions) -> usize {
+    let mut start = if bytes.starts_with(&UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    };
+    for _ in 0..options.skip_rows {
+        match memchr::memchr(options.eol_char, &bytes[start..]) {
+            Some(pos) => start += pos + 1,
+            None => return bytes.len(),
+        }
+    }
+    start
+}
+
+/// Iterates the rows of `bytes`. A trailing carriage return is not part of a row and empty lines
+/// are skipped.
+fn rows(bytes: &[u8], eol_char: u8) -> impl Iterator<Item = &[u8]> {
+    let mut start = 0;
+    memchr::memchr_iter(eol_char, bytes)
+        .chain(std::iter::once(bytes.len()))
+        .map(move |end| {
+            let line = &bytes[start..end];
+            start = end + 1;
+            line.strip_suffix(b"\r").unwrap_or(line)
+        })
+        .filter(|line| !line.is_empty())
+}
+
+/// Split the data lines of a fixed-width file into chunks of roughly `chunk_size` bytes and count
+/// the rows of every chunk.
+pub fn split_chunks(bytes: &[u8], options: &FwfReadOptions, chunk_size: usize) -> Vec<FwfChunk> {
+    let mut chunks = vec![];
+    let mut start = data_start(bytes, options);
+
+    while start < bytes.len() {
+        let target = (start + chunk_size.max(1)).min(bytes.len());
+        let end = match memchr::memchr(options.eol_char, &bytes[target..]) {
+            Some(pos) => target + pos + 1,
+            None => bytes.len(),
+        };
+        let range = start..end;
+        let n_rows = rows(&bytes[range.clone()], options.eol_char).count();
+        if n_rows > 0 {
+            chunks.push(FwfChunk { range, n_rows });
+        }
+        start = end;
+    }
+
+    chunks
+}
+
+/// Count the rows of an in-memory fixed-width file.
+pub fn count_rows(bytes: &[u8], options: &FwfReadOptions) -> usize {
+    rows(&bytes[data_start(bytes, options)..], options.eol_char).count()
+}
+
+/// Parses the rows of a fixed-width file into [`DataFrame`]s. Only the byte ranges of the
+/// projected columns are looked at.
+#[derive(Clone, Debug)]
+pub struct FwfChunkParser {
+    options: FwfReadOptions,
+    /// Indices of the projected columns in the column spec.
+    projection: Vec<usize>,
+    /// Schema with the dtypes that are parsed, the others are cast afterwards.
+    parse_schema: SchemaRef,
+    fields_to_cast: Vec<Field>,
+    null_values: Option<NullValuesCompiled>,
+}
+
+impl FwfChunkParser {
+    pub fn new(options: &FwfReadOptions, projection: Option<Vec<usize>>) -> PolarsResult<Self> {
+        let schema = options.schema()?;
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|null_values| null_values.compile(&schema))
+            .transpose()?;
+
+        let mut parse_schema = Arc::new(schema);
+        let mut fields_to_cast = vec![];
+        prepare_csv_schema(&mut parse_schema, &mut fields_to_cast)?;
+
+        let mut projection = projection.unwrap_or_else(|| (0..options.columns.len()).collect());
+        projection.sort_unstable();
+        if let Some(&i) = projection.last() {
+            polars_ensure!(
+                i < options.columns.len(),
+                OutOfBounds: "projection index {} is out of bounds for {} fixed-width columns",
+                i, options.columns.len()
+            );
+        }
+
+        Ok(Self {
+            options: options.clone(),
+            projection,
+            parse_schema,
+            fields_to_cast,
+            null_values,
+        })
+    }
+
+    /// Parse `n_rows` rows of `bytes`, after skipping the first `skip` rows.
+    pub fn parse(&self, bytes: &[u8], skip: usize, n_rows: usize) -> PolarsResult<DataFrame> {
+        let mut buffers = init_buffers(
+            &self.projection,
+            n_rows,
+            &self.parse_schema,
+            None,
+            CsvEncoding::Utf8,
+            self.options.decimal_comma,
+        )?;
+
+        let mut char_offsets = vec![];
+        let mut height = 0;
+        for line in rows(bytes, self.options.eol_char).skip(skip).take(n_rows) {
+            self.parse_line(line, &mut buffers, &mut char_offsets)?;
+            height += 1;
+        }
+
+        let columns = buffers
+            .into_iter()
+            .map(|buf| buf.into_series().map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
+        cast_columns(
+            &mut df,
+            &self.fields_to_cast,
+            false,
+            self.options.ignore_errors,
+        )?;
+        Ok(df)
+    }
+
+    fn parse_line(
+        &self,
+        line: &[u8],
+        buffers: &mut [Buffer],
+        char_offsets: &mut Vec<usize>,
+    ) -> PolarsResult<()> {
+        let ignore_errors = self.options.ignore_errors;
+        let is_ascii = line.is_ascii();
+
+        if !is_ascii && !validate_utf8(line) {
+            polars_ensure!(ignore_errors, ComputeError: "invalid utf-8 sequence in fixed-width line");
+            buffers.iter_mut().for_each(|buf| buf.add_null(false));
+            return Ok(());
+        }
+
+        // Byte position of every character and the end of the line.
+        let by_chars = !is_ascii && self.options.offset_unit == FwfOffsetUnit::Characters;
+        if by_chars {
+            char_offsets.clear();
+            char_offsets.extend(
+                line.iter()
+                    .enumerate()
+                    .filter(|(_, b)| is_char_start(**b))
+                    .map(|(i, _)| i),
+            );
+            char_offsets.push(line.len());
+        }
+
+        for (buf, &i) in buffers.iter_mut().zip(&self.projection) {
+            let column = &self.options.columns[i];
+            let end = column.offset.saturating_add(column.width);
+
+            let (start, end) = if by_chars {
+                let n_chars = char_offsets.len() - 1;
+                (
+                    char_offsets[column.offset.min(n_chars)],
+                    char_offsets[end.min(n_chars)],
+                )
+            } else {
+                (column.offset.min(line.len()), end.min(line.len()))
+            };
+
+            // With byte offsets a column may cut through a multi-byte character.
+            if !is_ascii
+                && ![start, end]
+                    .iter()
+                    .all(|&pos| pos == line.len() || is_char_start(line[pos]))
+            {
+                polars_ensure!(
+                    ignore_errors,
+                    ComputeError: "the byte range of fixed-width column '{}' splits a multi-byte character",
+                    column.name
+                );
+                buf.add_null(false);
+                continue;
+            }
+
+            let mut field = &line[start..end];
+            if self.options.trim {
+                field = field.trim_ascii();
+            }
+
+            // SAFETY: `i` is in bounds of the column spec the null values were compiled for.
+            let is_null = self
+                .null_values
+                .as_ref()
+                .is_some_and(|null_values| unsafe { null_values.is_null(field, i) });
+            if is_null {
+                buf.add_null(false);
+                continue;
+            }
+
+            buf.add(field, ignore_errors, false, true).map_err(|e| {
+                polars_err!(
+                    ComputeError:
+                    "could not parse `{}` as dtype `{}` at fixed-width column '{}'\n\n\
+                    Original error: ```{}```",
+                    String::from_utf8_lossy(field),
+                    buf.dtype(),
+                    column.name,
+                    e
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Schema of the [`DataFrame`]s returned by [`FwfChunkParser::parse`].
+    pub fn projected_schema(&self) -> Schema {
+        self.projection
+            .iter()
+            .map(|&i| {
+                let column = &self.options.columns[i];
+                Field::new(column.name.clone(), column.dtype.clone())
+            })
+            .collect()
+    }
+}
+
+#[inline]
+fn is_char_start(b: u8) -> bool {
+    // Continuation bytes of multi-byte characters are of the form 0b10xxxxxx.
+    (b as i8) >= -0x40
+}
+
+/// Read fixed-width text files into a [`DataFrame`].
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::fwf::{FwfColumn, FwfReadOptions, FwfReader};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("accounts.txt").expect("file not found");
+///     let options = FwfReadOptions::new([
+///         FwfColumn::new("account", 0, 10, DataType::String),
+///         FwfColumn::new("balance", 10, 12, DataType::Float64),
+///     ]);
+///
+///     FwfReader::new(file)
+///             .with_options(options)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FwfReader<R: MmapBytesReader> {
+    reader: R,
+    options: FwfReadOptions,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: MmapBytesReader> FwfReader<R> {
+    /// Set the column spec and parse options.
+    pub fn with_options(mut self, options: FwfReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for FwfReader<R> {
+    fn new(reader: R) -> Self {
+        FwfReader {
+            reader,
+            options: FwfReadOptions::default(),
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let schema = self.options.schema()?;
+        if let Some(columns) = &self.columns {
+            self.projection = Some(
+                columns
+                    .iter()
+                    .map(|name| schema.try_index_of(name))
+                    .collect::<PolarsResult<_>>()?,
+            );
+        }
+        let parser = FwfChunkParser::new(&self.options, self.projection)?;
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut decompressed = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut decompressed)?;
+
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = (bytes.len() / n_threads).clamp(1 << 16, 1 << 24);
+
+        // Assign the number of rows to read to every chunk.
+        let mut n_rows_left = self.n_rows.unwrap_or(usize::MAX);
+        let chunks = split_chunks(bytes, &self.options, chunk_size)
+            .into_iter()
+            .map_while(|chunk| {
+                let n_rows = chunk.n_rows.min(n_rows_left);
+                n_rows_left -= n_rows;
+                (n_rows > 0).then_some((chunk.range, n_rows))
+            })
+            .collect::<Vec<_>>();
+
+        if parser.projection.is_empty() {
+            let height = chunks.iter().map(|(_, n_rows)| n_rows).sum();
+            return Ok(DataFrame::empty_with_height(height));
+        }
+        if chunks.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&parser.projected_schema()));
+        }
+
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|(range, n_rows)| parser.parse(&bytes[range], 0, n_rows))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
@@ -0,0 +1,259 @@
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_utils::aliases::PlHashMap;
+
+use super::metadata::PartitionField;
+use crate::SerReader;
+use crate::avro::AvroReader;
+
+/// An entry of a manifest list.
+#[derive(Debug, Clone)]
+pub(super) struct ManifestFile {
+    pub path: String,
+    pub partition_spec_id: i32,
+    /// Whether the manifest tracks delete files instead of data files.
+    pub is_deletes: bool,
+    pub sequence_number: i64,
+    /// Summaries of the partition values of the files in the manifest, in the order of the fields
+    /// of its partition spec.
+    pub partitions: Option<Vec<FieldSummary>>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct FieldSummary {
+    pub contains_null: bool,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A live data or delete file tracked by a manifest.
+#[derive(Debug, Clone)]
+pub(super) struct DataFile {
+    pub content: FileContent,
+    pub sequence_number: i64,
+    pub path: String,
+    pub file_format: String,
+    pub partition_spec_id: i32,
+    /// Partition values in the order of the fields of the partition spec.
+    pub partition: Vec<AnyValue<'static>>,
+    pub record_count: i64,
+    pub value_counts: PlHashMap<i32, i64>,
+    pub null_value_counts: PlHashMap<i32, i64>,
+    pub lower_bounds: PlHashMap<i32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<i32, Vec<u8>>,
+    pub equality_ids: Vec<i32>,
+}
+
+const STATUS_DELETED: i32 = 2;
+
+pub(super) fn read_manifest_list(bytes: Vec<u8>) -> PolarsResult<Vec<ManifestFile>> {
+    let df = AvroReader::new(Cursor::new(bytes)).finish()?;
+
+    let path = df.column("manifest_path")?.str()?.clone();
+    let partition_spec_id = int_column(&df, "partition_spec_id", DataType::Int32)?;
+    let partition_spec_id = partition_spec_id.i32()?;
+    // Format version 1 manifest lists only track data manifests and have no sequence numbers.
+    let content = optional_int_column(&df, "content", DataType::Int32)?;
+    let content = content.as_ref().map(|s| s.i32()).transpose()?;
+    let sequence_number = optional_int_column(&df, "sequence_number", DataType::Int64)?;
+    let sequence_number = sequence_number.as_ref().map(|s| s.i64()).transpose()?;
+    let partitions = df.column("partitions").ok();
+    let partitions = partitions.map(|c| c.list()).transpose()?;
+
+    (0..df.height())
+        .map(|i| {
+            let path = path
+                .get(i)
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg manifest without a path"))?;
+            let partitions = match partitions.and_then(|p| p.get_as_series(i)) {
+                Some(summaries) => Some(field_summaries(&summaries)?),
+                None => None,
+            };
+
+            Ok(ManifestFile {
+                path: path.to_string(),
+                partition_spec_id: partition_spec_id.get(i).unwrap_or_default(),
+                is_deletes: content.and_then(|c| c.get(i)).unwrap_or_default() == 1,
+                sequence_number: sequence_number.and_then(|s| s.get(i)).unwrap_or_default(),
+                partitions,
+            })
+        })
+        .collect()
+}
+
+fn field_summaries(summaries: &Series) -> PolarsResult<Vec<FieldSummary>> {
+    let summaries = summaries.struct_()?;
+    let contains_null = summaries.field_by_name("contains_null")?;
+    let contains_null = contains_null.bool()?;
+    let lower_bound = summaries.field_by_name("lower_bound").ok();
+    let lower_bound = lower_bound.as_ref().map(|s| s.binary()).transpose()?;
+    let upper_bound = summaries.field_by_name("upper_bound").ok();
+    let upper_bound = upper_bound.as_ref().map(|s| s.binary()).transpose()?;
+
+    Ok((0..summaries.len())
+        .map(|i| FieldSummary {
+            contains_null: contains_null.get(i).unwrap_or(true),
+            lower_bound: lower_bound.and_then(|b| b.get(i)).map(<[u8]>::to_vec),
+            upper_bound: upper_bound.and_then(|b| b.get(i)).map(<[u8]>::to_vec),
+        })
+        .collect())
+}
+
+/// Reads the live entries of a manifest. Entries without a sequence number inherit the sequence
+/// number of the manifest.
+pub(super) fn read_manifest(
+    bytes: Vec<u8>,
+    manifest: &ManifestFile,
+    partition_fields: &[PartitionField],
+) -> PolarsResult<Vec<DataFile>> {
+    let df = AvroReader::new(Cursor::new(bytes)).finish()?;
+
+    let status = int_column(&df, "status", DataType::Int32)?;
+    let status = status.i32()?;
+    let sequence_number = optional_int_column(&df, "sequence_number", DataType::Int64)?;
+    let sequence_number = sequence_number.as_ref().map(|s| s.i64()).transpose()?;
+
+    let data_file = df.column("data_file")?.struct_()?.clone();
+    let field = |name: &str| data_file.field_by_name(name).ok();
+    let content = field("content")
+        .map(|s| s.cast(&DataType::Int32))
+        .transpose()?;
+    let content = content.as_ref().map(|s| s.i32()).transpose()?;
+    let path = data_file.field_by_name("file_path")?;
+    let path = path.str()?;
+    let file_format = data_file.field_by_name("file_format")?;
+    let file_format = file_format.str()?;
+    let record_count = data_file
+        .field_by_name("record_count")?
+        .cast(&DataType::Int64)?;
+    let record_count = record_count.i64()?;
+    let partition = field("partition");
+    let partition = partition.as_ref().map(|s| s.struct_()).transpose()?;
+    let partition = partition_fields
+        .iter()
+        .map(|f| {
+            partition
+                .and_then(|p| p.field_by_name(&f.name).ok())
+                .ok_or_else(|| {
+                    polars_err!(ComputeError: "Iceberg manifest is missing partition field '{}'", f.name)
+                })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let value_counts = MapField::new(field("value_counts"))?;
+    let null_value_counts = MapField::new(field("null_value_counts"))?;
+    let lower_bounds = MapField::new(field("lower_bounds"))?;
+    let upper_bounds = MapField::new(field("upper_bounds"))?;
+    let equality_ids = field("equality_ids");
+    let equality_ids = equality_ids.as_ref().map(|s| s.list()).transpose()?;
+
+    let mut files = vec![];
+    for i in 0..df.height() {
+        if status.get(i) == Some(STATUS_DELETED) {
+            continue;
+        }
+        let (Some(path), Some(file_format)) = (path.get(i), file_format.get(i)) else {
+            polars_bail!(ComputeError: "invalid entry in Iceberg manifest '{}'", manifest.path);
+        };
+        let content = match content.and_then(|c| c.get(i)).unwrap_or_default() {
+            0 => FileContent::Data,
+            1 => FileContent::PositionDeletes,
+            2 => FileContent::EqualityDeletes,
+            v => polars_bail!(ComputeError: "invalid Iceberg file content {}", v),
+        };
+        let equality_ids = match equality_ids.and_then(|ids| ids.get_as_series(i)) {
+            Some(ids) => ids
+                .cast(&DataType::Int32)?
+                .i32()?
+                .into_no_null_iter()
+                .collect(),
+            None => vec![],
+        };
+
+        files.push(DataFile {
+            content,
+            sequence_number: sequence_number
+                .and_then(|s| s.get(i))
+                .unwrap_or(manifest.sequence_number),
+            path: path.to_string(),
+            file_format: file_format.to_string(),
+            partition_spec_id: manifest.partition_spec_id,
+            partition: partition
+                .iter()
+                .map(|s| s.get(i).map(AnyValue::into_static))
+                .collect::<PolarsResult<_>>()?,
+            record_count: record_count.get(i).unwrap_or_default(),
+            value_counts: value_counts.ints(i)?,
+            null_value_counts: null_value_counts.ints(i)?,
+            lower_bounds: lower_bounds.bytes(i)?,
+            upper_bounds: upper_bounds.bytes(i)?,
+            equality_ids,
+        });
+    }
+    Ok(files)
+}
+
+fn int_column(df: &DataFrame, name: &str, dtype: DataType) -> PolarsResult<Series> {
+    df.column(name)?.as_materialized_series().cast(&dtype)
+}
+
+fn optional_int_column(
+    df: &DataFrame,
+    name: &str,
+    dtype: DataType,
+) -> PolarsResult<Option<Series>> {
+    df.column(name)
+        .ok()
+        .map(|c| c.as_materialized_series().cast(&dtype))
+        .transpose()
+}
+
+/// A map keyed by field id, which Avro stores as a list of key-value records.
+struct MapField(Option<ListChunked>);
+
+impl MapField {
+    fn new(series: Option<Series>) -> PolarsResult<Self> {
+        Ok(Self(series.map(|s| s.list().cloned()).transpose()?))
+    }
+
+    fn entries(&self, i: usize) -> PolarsResult<Option<(Series, Series)>> {
+        let Some(entries) = self.0.as_ref().and_then(|ca| ca.get_as_series(i)) else {
+            return Ok(None);
+        };
+        let entries = entries.struct_()?;
+        let keys = entries.field_by_name("key")?.cast(&DataType::Int32)?;
+        Ok(Some((keys, entries.field_by_name("value")?)))
+    }
+
+    fn ints(&self, i: usize) -> PolarsResult<PlHashMap<i32, i64>> {
+        let Some((keys, values)) = self.entries(i)? else {
+            return Ok(PlHashMap::default());
+        };
+        let values = values.cast(&DataType::Int64)?;
+        Ok(keys
+            .i32()?
+            .iter()
+            .zip(values.i64()?.iter())
+            .filter_map(|(k, v)| Some((k?, v?)))
+            .collect())
+    }
+
+    fn bytes(&self, i: usize) -> PolarsResult<PlHashMap<i32, Vec<u8>>> {
+        let Some((keys, values)) = self.entries(i)? else {
+            return Ok(PlHashMap::default());
+        };
+        Ok(keys
+            .i32()?
+            .iter()
+            .zip(values.binary()?.iter())
+            .filter_map(|(k, v)| Some((k?, v?.to_vec())))
+            .collect())
+    }
+}
@@ -0,0 +1,204 @@
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::aliases::PlHashMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::schema::IcebergSchema;
+
+/// The table metadata file (`*.metadata.json`) of format version 1 or 2. Fields that do not affect
+/// reading are ignored.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TableMetadata {
+    pub format_version: i32,
+    #[serde(default)]
+    pub schemas: Vec<Value>,
+    pub current_schema_id: Option<i32>,
+    /// Only schema of format version 1 tables that do not list their `schemas`.
+    pub schema: Option<Value>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// Only partition spec of format version 1 tables that do not list their `partition-specs`.
+    pub partition_spec: Option<Vec<PartitionField>>,
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<SnapshotLogEntry>,
+    #[serde(default)]
+    pub properties: PlHashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct Snapshot {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+    pub manifest_list: Option<String>,
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct SnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct PartitionField {
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+impl TableMetadata {
+    pub(super) fn parse(bytes: &[u8]) -> PolarsResult<Self> {
+        let metadata: Self = serde_json::from_slice(bytes).map_err(to_compute_err)?;
+        polars_ensure!(
+            (1..=2).contains(&metadata.format_version),
+            ComputeError: "Iceberg table format version {} is not supported", metadata.format_version
+        );
+        Ok(metadata)
+    }
+
+    pub(super) fn schemas(&self) -> PolarsResult<Vec<IcebergSchema>> {
+        match &self.schema {
+            Some(schema) if self.schemas.is_empty() => Ok(vec![IcebergSchema::parse(schema)?]),
+            _ => self.schemas.iter().map(IcebergSchema::parse).collect(),
+        }
+    }
+
+    pub(super) fn partition_specs(&self) -> Vec<PartitionSpec> {
+        match &self.partition_spec {
+            Some(fields) if self.partition_specs.is_empty() => vec![PartitionSpec {
+                spec_id: 0,
+                fields: fields.clone(),
+            }],
+            _ => self.partition_specs.clone(),
+        }
+    }
+
+    /// Returns the snapshot selected by the options, or `None` for a table without snapshots.
+    pub(super) fn snapshot(
+        &self,
+        snapshot_id: Option<i64>,
+        timestamp: Option<i64>,
+    ) -> PolarsResult<Option<&Snapshot>> {
+        let find = |id: i64| {
+            self.snapshots
+                .iter()
+                .find(|s| s.snapshot_id == id)
+                .ok_or_else(
+                    || polars_err!(ComputeError: "snapshot {} of the Iceberg table does not exist", id),
+                )
+        };
+
+        if let Some(id) = snapshot_id {
+            return find(id).map(Some);
+        }
+
+        if let Some(timestamp) = timestamp {
+            // The snapshot log records which snapshot was current at what time, which excludes
+            // snapshots that were rolled back.
+            let id = if self.snapshot_log.is_empty() {
+                self.snapshots
+                    .iter()
+                    .filter(|s| s.timestamp_ms <= timestamp)
+                    .max_by_key(|s| s.timestamp_ms)
+                    .map(|s| s.snapshot_id)
+            } else {
+                self.snapshot_log
+                    .iter()
+                    .filter(|e| e.timestamp_ms <= timestamp)
+                    .max_by_key(|e| e.timestamp_ms)
+                    .map(|e| e.snapshot_id)
+            };
+            let id = id.ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "timestamp {} is before the earliest snapshot of the Iceberg table",
+                    timestamp
+                )
+            })?;
+            return find(id).map(Some);
+        }
+
+        match self.current_snapshot_id {
+            None | Some(-1) => Ok(None),
+            Some(id) => find(id).map(Some),
+        }
+    }
+}
+
+/// Parses the `schema.name-mapping.default` table property, which maps field ids to the column
+/// names of data files that were written without field ids.
+pub(super) fn parse_name_mapping(json: &str) -> PolarsResult<PlHashMap<i32, Vec<PlSmallStr>>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct MappedField {
+        field_id: Option<i32>,
+        #[serde(default)]
+        names: Vec<String>,
+    }
+
+    let fields: Vec<MappedField> = serde_json::from_str(json).map_err(to_compute_err)?;
+    Ok(fields
+        .into_iter()
+        .filter_map(|f| Some((f.field_id?, f.names.into_iter().map(Into::into).collect())))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_snapshot() {
+        let metadata = TableMetadata::parse(
+            br#"{
+                "format-version": 2,
+                "location": "/tmp/table",
+                "current-schema-id": 0,
+                "schemas": [{"type": "struct", "schema-id": 0, "fields": []}],
+                "current-snapshot-id": 3,
+                "snapshots": [
+                    {"snapshot-id": 1, "sequence-number": 1, "timestamp-ms": 1000, "manifest-list": "a.avro"},
+                    {"snapshot-id": 2, "sequence-number": 2, "timestamp-ms": 2000, "manifest-list": "b.avro"},
+                    {"snapshot-id": 3, "sequence-number": 3, "timestamp-ms": 3000, "manifest-list": "c.avro"}
+                ],
+                "snapshot-log": [
+                    {"snapshot-id": 1, "timestamp-ms": 1000},
+                    {"snapshot-id": 3, "timestamp-ms": 3000}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let id = |snapshot_id, timestamp| {
+            metadata
+                .snapshot(snapshot_id, timestamp)
+                .map(|s| s.map(|s| s.snapshot_id))
+        };
+        assert_eq!(id(None, None).unwrap(), Some(3));
+        assert_eq!(id(Some(2), None).unwrap(), Some(2));
+        assert!(id(Some(4), None).is_err());
+        // Snapshot 2 was never the current snapshot.
+        assert_eq!(id(None, Some(2500)).unwrap(), Some(1));
+        assert!(id(None, Some(500)).is_err());
+
+        assert_eq!(
+            parse_name_mapping(r#"[{"field-id": 1, "names": ["id", "key"]}, {"names": ["x"]}]"#)
+                .unwrap(),
+            PlHashMap::from_iter([(1, vec!["id".into(), "key".into()])])
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Reading of [Apache Iceberg](https://iceberg.apache.org) tables.
+//!
+//! The metadata file of a table selects a snapshot, whose manifest list and manifests (Avro files)
+//! track the data and delete files of the table. Manifests and data files are skipped based on
+//! their partition summaries and column statistics. The data files themselves are plain Parquet
+//! files and are read by the Parquet reader, after which the deletes are applied.
+mod manifest;
+mod metadata;
+mod predicate;
+mod schema;
+mod storage;
+mod table;
+mod values;
+
+pub use predicate::{Comparison, IcebergPredicate};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use table::{EqualityDeletes, IcebergDataFile, IcebergTable};
+
+/// Selects the snapshot of an Iceberg table that is read. By default the current snapshot is read.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IcebergScanOptions {
+    /// Read the snapshot with this id.
+    pub snapshot_id: Option<i64>,
+    /// Read the snapshot that was current at this time, in milliseconds since the Unix epoch.
+    pub timestamp: Option<i64>,
+}
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+
+use polars_core::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A predicate on the columns of an Iceberg table that is used to skip manifests and data files
+/// based on their statistics. Values must have the data type of their column.
+#[derive(Debug, Clone)]
+pub enum IcebergPredicate {
+    And(Vec<IcebergPredicate>),
+    Or(Vec<IcebergPredicate>),
+    Compare {
+        column: PlSmallStr,
+        op: Comparison,
+        value: Scalar,
+    },
+    IsIn {
+        column: PlSmallStr,
+        values: Vec<Scalar>,
+    },
+    IsNull(PlSmallStr),
+    IsNotNull(PlSmallStr),
+}
+
+/// Statistics of a column within a manifest or data file. Missing statistics are unknown.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ColumnStats {
+    pub lower: Option<AnyValue<'static>>,
+    pub upper: Option<AnyValue<'static>>,
+    pub null_count: Option<u64>,
+    pub value_count: Option<u64>,
+}
+
+impl IcebergPredicate {
+    /// Returns `false` if no row with the given column statistics can match the predicate.
+    pub(super) fn may_match(&self, stats: &dyn Fn(&str) -> Option<ColumnStats>) -> bool {
+        match self {
+            Self::And(predicates) => predicates.iter().all(|p| p.may_match(stats)),
+            Self::Or(predicates) => predicates.iter().any(|p| p.may_match(stats)),
+            Self::IsNull(column) => stats(column).is_none_or(|s| s.null_count != Some(0)),
+            Self::IsNotNull(column) => stats(column).is_none_or(|s| !s.is_all_null()),
+            Self::Compare { column, op, value } => {
+                stats(column).is_none_or(|s| s.may_match(*op, value.value()))
+            },
+            Self::IsIn { column, values } => stats(column).is_none_or(|s| {
+                values
+                    .iter()
+                    .any(|value| s.may_match(Comparison::Eq, value.value()))
+            }),
+        }
+    }
+}
+
+impl ColumnStats {
+    fn is_all_null(&self) -> bool {
+        matches!((self.null_count, self.value_count), (Some(n), Some(v)) if n == v)
+    }
+
+    fn may_match(&self, op: Comparison, value: &AnyValue) -> bool {
+        // Comparisons with null are never true.
+        if self.is_all_null() {
+            return false;
+        }
+        // The ordering of floats in the bounds does not account for NaN.
+        if value.is_null() || value.is_float() {
+            return true;
+        }
+
+        let cmp = |bound: &Option<AnyValue>| bound.as_ref().and_then(|b| b.partial_cmp(value));
+        let lower = cmp(&self.lower);
+        let upper = cmp(&self.upper);
+
+        use Comparison as C;
+        use Ordering::*;
+        match op {
+            C::Eq => lower != Some(Greater) && upper != Some(Less),
+            C::NotEq => !(lower == Some(Equal) && upper == Some(Equal)),
+            C::Lt => lower.is_none_or(|o| o == Less),
+            C::LtEq => lower.is_none_or(|o| o != Greater),
+            C::Gt => upper.is_none_or(|o| o == Greater),
+            C::GtEq => upper.is_none_or(|o| o != Less),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_may_match() {
+        let stats = |column: &str| match column {
+            "a" => Some(ColumnStats {
+                lower: Some(AnyValue::Int64(10)),
+                upper: Some(AnyValue::Int64(20)),
+                null_count: Some(0),
+                value_count: Some(5),
+            }),
+            "b" => Some(ColumnStats {
+                null_count: Some(5),
+                value_count: Some(5),
+                ..Default::default()
+            }),
+            _ => None,
+        };
+        let compare = |column: &str, op, v: i64| IcebergPredicate::Compare {
+            column: column.into(),
+            op,
+            value: Scalar::new(DataType::Int64, AnyValue::Int64(v)),
+        };
+
+        assert!(compare("a", Comparison::Eq, 15).may_match(&stats));
+        assert!(!compare("a", Comparison::Eq, 21).may_match(&stats));
+        assert!(!compare("a", Comparison::Lt, 10).may_match(&stats));
+        assert!(compare("a", Comparison::LtEq, 10).may_match(&stats));
+        assert!(!compare("a", Comparison::Gt, 20).may_match(&stats));
+        assert!(compare("a", Comparison::NotEq, 20).may_match(&stats));
+        assert!(!compare("b", Comparison::Eq, 1).may_match(&stats));
+        assert!(compare("c", Comparison::Eq, 1).may_match(&stats));
+
+        assert!(!IcebergPredicate::IsNull("a".into()).may_match(&stats));
+        assert!(!IcebergPredicate::IsNotNull("b".into()).may_match(&stats));
+        assert!(
+            IcebergPredicate::Or(vec![
+                compare("a", Comparison::Eq, 1),
+                compare("a", Comparison::Eq, 12)
+            ])
+            .may_match(&stats)
+        );
+        assert!(
+            !IcebergPredicate::And(vec![
+                compare("a", Comparison::GtEq, 12),
+                IcebergPredicate::IsNull("a".into())
+            ])
+            .may_match(&stats)
+        );
+        assert!(
+            !IcebergPredicate::IsIn {
+                column: "a".into(),
+                values: vec![
+                    Scalar::new(DataType::Int64, AnyValue::Int64(1)),
+                    Scalar::new(DataType::Int64, AnyValue::Int64(30))
+                ],
+            }
+            .may_match(&stats)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use polars_core::prelude::*;
+use serde_json::Value;
+
+/// A top-level column of an Iceberg schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct IcebergField {
+    pub id: i32,
+    pub name: PlSmallStr,
+    pub dtype: DataType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct IcebergSchema {
+    pub schema_id: i32,
+    pub fields: Vec<IcebergField>,
+}
+
+impl IcebergSchema {
+    /// Parses a schema of the table metadata.
+    pub(super) fn parse(value: &Value) -> PolarsResult<Self> {
+        let schema_id = value
+            .get("schema-id")
+            .and_then(Value::as_i64)
+            .unwrap_or_default() as i32;
+        let fields = struct_fields(value)?
+            .into_iter()
+            .map(|(id, field)| IcebergField {
+                id,
+                name: field.name,
+                dtype: field.dtype,
+            })
+            .collect();
+
+        Ok(Self { schema_id, fields })
+    }
+
+    pub(super) fn to_schema(&self) -> Schema {
+        self.fields
+            .iter()
+            .map(|f| Field::new(f.name.clone(), f.dtype.clone()))
+            .collect()
+    }
+}
+
+/// Returns the fields of a struct type with their field ids.
+fn struct_fields(value: &Value) -> PolarsResult<Vec<(i32, Field)>> {
+    let Some(Value::Array(fields)) = value.get("fields") else {
+        polars_bail!(ComputeError: "invalid Iceberg struct type: {}", value);
+    };
+
+    fields
+        .iter()
+        .map(|field| {
+            let id = field.get("id").and_then(Value::as_i64).ok_or_else(
+                || polars_err!(ComputeError: "Iceberg field without an id: {}", field),
+            )?;
+            let name = field.get("name").and_then(Value::as_str).ok_or_else(
+                || polars_err!(ComputeError: "Iceberg field without a name: {}", field),
+            )?;
+            let dtype = field.get("type").ok_or_else(
+                || polars_err!(ComputeError: "Iceberg field '{}' without a type", name),
+            )?;
+            Ok((id as i32, Field::new(name.into(), to_dtype(dtype)?)))
+        })
+        .collect()
+}
+
+fn to_dtype(value: &Value) -> PolarsResult<DataType> {
+    let dtype = match value {
+        Value::String(name) => primitive_to_dtype(name)?,
+        Value::Object(object) => {
+            let field = |name: &str| {
+                object
+                    .get(name)
+                    .ok_or_else(|| polars_err!(ComputeError: "Iceberg type is missing '{}'", name))
+            };
+
+            match field("type")?.as_str() {
+                Some("struct") => DataType::Struct(
+                    struct_fields(value)?
+                        .into_iter()
+                        .map(|(_, field)| field)
+                        .collect(),
+                ),
+                Some("list") => DataType::List(Box::new(to_dtype(field("element")?)?)),
+                Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key".into(), to_dtype(field("key")?)?),
+                    Field::new("value".into(), to_dtype(field("value")?)?),
+                ]))),
+                _ => polars_bail!(ComputeError: "unsupported Iceberg type: {}", value),
+            }
+        },
+        _ => polars_bail!(ComputeError: "invalid Iceberg type: {}", value),
+    };
+    Ok(dtype)
+}
+
+fn primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    let dtype = match name {
+        "boolean" => DataType::Boolean,
+        "int" => DataType::Int32,
+        "long" => DataType::Int64,
+        "float" => DataType::Float32,
+        "double" => DataType::Float64,
+        "date" => DataType::Date,
+        "time" => DataType::Time,
+        "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        },
+        "timestamp_ns" => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => {
+            DataType::Datetime(TimeUnit::Nanoseconds, Some(PlSmallStr::from_static("UTC")))
+        },
+        "string" => DataType::String,
+        "uuid" | "binary" => DataType::Binary,
+        _ if name.starts_with("fixed[") => DataType::Binary,
+        _ => {
+            if let Some(args) = name
+                .strip_prefix("decimal(")
+                .and_then(|rest| rest.strip_suffix(')'))
+            {
+                return decimal_to_dtype(name, args);
+            }
+            polars_bail!(ComputeError: "unsupported Iceberg type '{}'", name)
+        },
+    };
+    Ok(dtype)
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn decimal_to_dtype(name: &str, args: &str) -> PolarsResult<DataType> {
+    let parse = |s: Option<&str>| s.and_then(|s| s.trim().parse::<usize>().ok());
+    let mut args = args.split(',');
+    match (parse(args.next()), parse(args.next())) {
+        (Some(precision), Some(scale)) => Ok(DataType::Decimal(Some(precision), Some(scale))),
+        _ => polars_bail!(ComputeError: "invalid Iceberg type '{}'", name),
+    }
+}
+
+#[cfg(not(feature = "dtype-decimal"))]
+fn decimal_to_dtype(name: &str, _args: &str) -> PolarsResult<DataType> {
+    polars_bail!(ComputeError: "reading Iceberg type '{}' requires the 'dtype-decimal' feature", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let value: Value = serde_json::from_str(
+            r#"{"type":"struct","schema-id":1,"fields":[
+                {"id":1,"name":"id","required":true,"type":"long"},
+                {"id":2,"name":"ts","required":false,"type":"timestamptz"},
+                {"id":3,"name":"tags","required":false,"type":{"type":"list","element-id":6,"element":"string","element-required":false}},
+                {"id":4,"name":"attrs","required":false,"type":{"type":"map","key-id":7,"key":"string","value-id":8,"value":"int","value-required":false}},
+                {"id":5,"name":"nested","required":false,"type":{"type":"struct","fields":[{"id":9,"name":"d","required":false,"type":"date"}]}}
+            ]}"#,
+        )
+        .unwrap();
+        let schema = IcebergSchema::parse(&value).unwrap();
+
+        assert_eq!(schema.schema_id, 1);
+        assert_eq!(
+            schema.fields.iter().map(|f| f.id).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            schema.to_schema(),
+            Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into()))
+                ),
+                Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+                Field::new(
+                    "attrs".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+                Field::new(
+                    "nested".into(),
+                    DataType::Struct(vec![Field::new("d".into(), DataType::Date)])
+                ),
+            ])
+        );
+
+        let value: Value = serde_json::from_str(
+            r#"{"type":"struct","fields":[{"id":1,"name":"v","required":false,"type":"variant"}]}"#,
+        )
+        .unwrap();
+        assert!(IcebergSchema::parse(&value).is_err());
+    }
+}
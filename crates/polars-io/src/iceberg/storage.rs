@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use polars_error::PolarsResult;
+use polars_parquet::read::FileMetadata;
+
+use crate::cloud::CloudOptions;
+use crate::path_utils::is_cloud_url;
+
+/// Returns the local path of a location written by an Iceberg writer, which is either a plain path
+/// or a `file:` URI. Returns `None` for object store URIs.
+pub(super) fn local_path(uri: &str) -> Option<PathBuf> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+    if let Some(path) = uri.strip_prefix("file:") {
+        return Some(PathBuf::from(path));
+    }
+    (!is_cloud_url(uri)).then(|| PathBuf::from(uri))
+}
+
+/// Returns the path under which a data file is passed to the Parquet scan.
+pub(super) fn scan_path(uri: &str) -> PathBuf {
+    local_path(uri).unwrap_or_else(|| PathBuf::from(uri))
+}
+
+/// Reads a whole file.
+pub(super) fn read(
+    uri: &str,
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+        &CloudOptions,
+    >,
+) -> PolarsResult<Vec<u8>> {
+    if let Some(path) = local_path(uri) {
+        return Ok(std::fs::read(path)?);
+    }
+
+    #[cfg(feature = "cloud")]
+    {
+        crate::pl_async::get_runtime().block_in_place_on(async {
+            let (location, store) =
+                crate::cloud::build_object_store(uri, cloud_options, false).await?;
+            let path = crate::cloud::object_path_from_str(&location.prefix)?;
+            let size = store.head(&path).await?.size as usize;
+            Ok(store.get_range(&path, 0..size).await?.to_vec())
+        })
+    }
+    #[cfg(not(feature = "cloud"))]
+    polars_error::polars_bail!(ComputeError: "reading Iceberg tables from cloud storage requires the 'cloud' feature")
+}
+
+/// Returns the names of the files in a directory, or an empty list if it does not exist.
+pub(super) fn list_dir(
+    uri: &str,
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+        &CloudOptions,
+    >,
+) -> PolarsResult<Vec<String>> {
+    if let Some(path) = local_path(uri) {
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+        return std::fs::read_dir(path)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect();
+    }
+
+    #[cfg(feature = "cloud")]
+    {
+        use futures::TryStreamExt;
+        use polars_error::to_compute_err;
+
+        crate::pl_async::get_runtime().block_in_place_on(async {
+            let (location, store) =
+                crate::cloud::build_object_store(uri, cloud_options, false).await?;
+            let prefix = crate::cloud::object_path_from_str(&location.prefix)?;
+            let prefix = &prefix;
+
+            store
+                .try_exec_rebuild_on_err(|store| {
+                    let store = store.clone();
+
+                    async move {
+                        store
+                            .list(Some(prefix))
+                            .try_filter_map(|meta| async move {
+                                // Skip the contents of nested directories.
+                                let is_child = meta
+                                    .location
+                                    .prefix_match(prefix)
+                                    .is_some_and(|parts| parts.count() == 1);
+                                Ok(is_child
+                                    .then(|| meta.location.filename().map(String::from))
+                                    .flatten())
+                            })
+                            .try_collect::<Vec<_>>()
+                            .await
+                            .map_err(to_compute_err)
+                    }
+                })
+                .await
+        })
+    }
+    #[cfg(not(feature = "cloud"))]
+    polars_error::polars_bail!(ComputeError: "reading Iceberg tables from cloud storage requires the 'cloud' feature")
+}
+
+/// Reads the footer of a Parquet file.
+pub(super) fn read_parquet_metadata(
+    uri: &str,
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))] cloud_options: Option<
+        &CloudOptions,
+    >,
+) -> PolarsResult<FileMetadata> {
+    if let Some(path) = local_path(uri) {
+        let mut file = std::fs::File::open(path)?;
+        return polars_parquet::read::read_metadata(&mut file);
+    }
+
+    #[cfg(feature = "cloud")]
+    {
+        crate::pl_async::get_runtime().block_in_place_on(async {
+            let mut reader =
+                crate::parquet::read::ParquetObjectStore::from_uri(uri, cloud_options, None)
+                    .await?;
+            Ok(reader.get_metadata().await?.as_ref().clone())
+        })
+    }
+    #[cfg(not(feature = "cloud"))]
+    polars_error::polars_bail!(ComputeError: "reading Iceberg tables from cloud storage requires the 'cloud' feature")
+}
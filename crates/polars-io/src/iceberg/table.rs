@@ -0,0 +1,515 @@
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_parquet::parquet::schema::types::ParquetType;
+use polars_utils::aliases::PlHashMap;
+use polars_utils::format_pl_smallstr;
+
+use super::manifest::{DataFile, FieldSummary, FileContent, read_manifest, read_manifest_list};
+use super::metadata::{PartitionSpec, Snapshot, TableMetadata, parse_name_mapping};
+use super::predicate::{ColumnStats, IcebergPredicate};
+use super::schema::{IcebergField, IcebergSchema};
+use super::values::decode_bound;
+use super::{IcebergScanOptions, storage};
+use crate::SerReader;
+use crate::cloud::CloudOptions;
+use crate::parquet::read::ParquetReader;
+
+const NAME_MAPPING_PROPERTY: &str = "schema.name-mapping.default";
+const MISSING_COLUMN_PREFIX: &str = "__POLARS_ICEBERG_MISSING_";
+
+/// An Iceberg table at a specific snapshot.
+#[derive(Debug)]
+pub struct IcebergTable {
+    /// Snapshot that is read, or `None` if the table does not have any snapshots.
+    pub snapshot_id: Option<i64>,
+    /// Schema of the table at the snapshot.
+    pub schema: SchemaRef,
+    snapshot: Option<Snapshot>,
+    table_schema: IcebergSchema,
+    partition_specs: Vec<PartitionSpec>,
+    /// Set when the columns of data files have to be matched by field id instead of by name,
+    /// because columns were renamed or the table has a name mapping.
+    match_field_ids: bool,
+    name_mapping: PlHashMap<i32, Vec<PlSmallStr>>,
+    cloud_options: Option<CloudOptions>,
+}
+
+/// A Parquet data file of an Iceberg table with the deletes that apply to it.
+#[derive(Debug, Clone)]
+pub struct IcebergDataFile {
+    pub path: PathBuf,
+    /// Sorted positions of the rows that are deleted by position delete files.
+    pub deleted_rows: Option<Arc<[IdxSize]>>,
+    /// Equality delete files that apply to the file. Files that share deletes share the same
+    /// `Arc`s.
+    pub equality_deletes: Vec<Arc<EqualityDeletes>>,
+    /// Columns that are named differently in the file than in the table, as pairs of the name in
+    /// the file and the name in the table. Columns that the file does not contain, but that share
+    /// their name with another column of the file, are mapped from a name that does not exist in
+    /// the file. Files with the same renames share the same `Arc`.
+    pub renamed_columns: Option<Arc<[(PlSmallStr, PlSmallStr)]>>,
+}
+
+/// The rows deleted by an equality delete file.
+#[derive(Debug)]
+pub struct EqualityDeletes {
+    /// Values of the equality columns of the deleted rows, with the names and data types of the
+    /// table schema. A row of a data file is deleted if its values in these columns are equal,
+    /// with nulls being equal to each other.
+    pub rows: DataFrame,
+}
+
+impl IcebergTable {
+    /// Loads the metadata of the table at `uri` and selects the snapshot given by `options`.
+    ///
+    /// `uri` is either the root directory of the table, in which case the latest metadata file in
+    /// its `metadata` directory is used, or the path of a `*.metadata.json` file.
+    pub fn try_new(
+        uri: &str,
+        options: &IcebergScanOptions,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            options.snapshot_id.is_none() || options.timestamp.is_none(),
+            InvalidOperation: "cannot time travel an Iceberg table by both snapshot id and timestamp"
+        );
+
+        let metadata_uri = find_metadata_file(uri, cloud_options)?;
+        let metadata = TableMetadata::parse(&storage::read(&metadata_uri, cloud_options)?)?;
+        let snapshot = metadata
+            .snapshot(options.snapshot_id, options.timestamp)?
+            .cloned();
+
+        // Time travel reads a snapshot with the schema it was written with.
+        let is_time_travel = options.snapshot_id.is_some() || options.timestamp.is_some();
+        let schema_id = match &snapshot {
+            Some(snapshot) if is_time_travel => snapshot.schema_id.or(metadata.current_schema_id),
+            _ => metadata.current_schema_id,
+        };
+        let schemas = metadata.schemas()?;
+        let table_schema = match schema_id {
+            Some(id) => schemas.iter().find(|s| s.schema_id == id),
+            None => schemas.first(),
+        }
+        .cloned()
+        .ok_or_else(|| polars_err!(ComputeError: "Iceberg table metadata has no current schema"))?;
+
+        let name_mapping = match metadata.properties.get(NAME_MAPPING_PROPERTY) {
+            Some(json) => parse_name_mapping(json)?,
+            None => PlHashMap::default(),
+        };
+        let has_renames = schemas.iter().flat_map(|s| &s.fields).any(|f| {
+            table_schema
+                .fields
+                .iter()
+                .any(|t| (t.id == f.id) != (t.name == f.name))
+        });
+
+        Ok(Self {
+            snapshot_id: snapshot.as_ref().map(|s| s.snapshot_id),
+            schema: Arc::new(table_schema.to_schema()),
+            snapshot,
+            table_schema,
+            partition_specs: metadata.partition_specs(),
+            match_field_ids: has_renames || !name_mapping.is_empty(),
+            name_mapping,
+            cloud_options: cloud_options.cloned(),
+        })
+    }
+
+    /// Returns the data files of the snapshot with the deletes that apply to them. Manifests and
+    /// data files whose statistics show that none of their rows can match `predicate` are
+    /// skipped.
+    pub fn plan_files(
+        &self,
+        predicate: Option<&IcebergPredicate>,
+    ) -> PolarsResult<Vec<IcebergDataFile>> {
+        let Some(snapshot) = &self.snapshot else {
+            return Ok(vec![]);
+        };
+        let Some(manifest_list) = &snapshot.manifest_list else {
+            polars_bail!(nyi = "reading Iceberg snapshots without a manifest list");
+        };
+
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+        for manifest in read_manifest_list(self.read(manifest_list)?)? {
+            let spec = self.partition_spec(manifest.partition_spec_id)?;
+
+            if let (false, Some(predicate), Some(summaries)) =
+                (manifest.is_deletes, predicate, &manifest.partitions)
+            {
+                if !predicate.may_match(&|column| self.summary_stats(spec, summaries, column)) {
+                    continue;
+                }
+            }
+
+            for file in read_manifest(self.read(&manifest.path)?, &manifest, &spec.fields)? {
+                polars_ensure!(
+                    file.file_format.eq_ignore_ascii_case("parquet"),
+                    nyi = "reading Iceberg {} files",
+                    file.file_format
+                );
+                match file.content {
+                    FileContent::Data => {
+                        if predicate.is_none_or(|p| {
+                            p.may_match(&|column| self.file_stats(spec, &file, column))
+                        }) {
+                            data_files.push(file)
+                        }
+                    },
+                    FileContent::PositionDeletes | FileContent::EqualityDeletes => {
+                        delete_files.push(file)
+                    },
+                }
+            }
+        }
+
+        let mut deleted_rows = vec![vec![]; data_files.len()];
+        let mut equality_deletes = vec![vec![]; data_files.len()];
+        for delete in &delete_files {
+            let targets = (0..data_files.len())
+                .filter(|&i| self.delete_applies(delete, &data_files[i]))
+                .collect::<Vec<_>>();
+            if targets.is_empty() {
+                continue;
+            }
+
+            match delete.content {
+                FileContent::PositionDeletes => {
+                    self.read_position_deletes(delete, &data_files, &targets, &mut deleted_rows)?
+                },
+                FileContent::EqualityDeletes => {
+                    let rows = Arc::new(self.read_equality_deletes(delete)?);
+                    for i in targets {
+                        equality_deletes[i].push(rows.clone());
+                    }
+                },
+                FileContent::Data => unreachable!(),
+            }
+        }
+
+        let mut renames = PlHashMap::<Vec<_>, Arc<[_]>>::default();
+        data_files
+            .into_iter()
+            .zip(deleted_rows)
+            .zip(equality_deletes)
+            .map(|((file, mut deleted_rows), equality_deletes)| {
+                let renamed_columns = if self.match_field_ids {
+                    let metadata =
+                        storage::read_parquet_metadata(&file.path, self.cloud_options.as_ref())?;
+                    let renamed = self.renamed_columns(metadata.schema().fields());
+                    (!renamed.is_empty()).then(|| {
+                        renames
+                            .entry(renamed)
+                            .or_insert_with_key(|renamed| renamed.as_slice().into())
+                            .clone()
+                    })
+                } else {
+                    None
+                };
+
+                deleted_rows.sort_unstable();
+                deleted_rows.dedup();
+
+                Ok(IcebergDataFile {
+                    path: storage::scan_path(&file.path),
+                    deleted_rows: (!deleted_rows.is_empty()).then(|| deleted_rows.into()),
+                    equality_deletes,
+                    renamed_columns,
+                })
+            })
+            .collect()
+    }
+
+    fn read(&self, uri: &str) -> PolarsResult<Vec<u8>> {
+        storage::read(uri, self.cloud_options.as_ref())
+    }
+
+    fn partition_spec(&self, spec_id: i32) -> PolarsResult<&PartitionSpec> {
+        self.partition_specs
+            .iter()
+            .find(|s| s.spec_id == spec_id)
+            .ok_or_else(
+                || polars_err!(ComputeError: "partition spec {} of the Iceberg table does not exist", spec_id),
+            )
+    }
+
+    fn field(&self, column: &str) -> Option<&IcebergField> {
+        self.table_schema.fields.iter().find(|f| f.name == column)
+    }
+
+    /// Returns the position of the identity partition field of a column in a partition spec.
+    fn identity_partition(&self, spec: &PartitionSpec, field: &IcebergField) -> Option<usize> {
+        spec.fields
+            .iter()
+            .position(|f| f.source_id == field.id && f.transform == "identity")
+    }
+
+    fn summary_stats(
+        &self,
+        spec: &PartitionSpec,
+        summaries: &[FieldSummary],
+        column: &str,
+    ) -> Option<ColumnStats> {
+        let field = self.field(column)?;
+        let summary = summaries.get(self.identity_partition(spec, field)?)?;
+        let decode = |bound: &Option<Vec<u8>>| {
+            bound
+                .as_deref()
+                .and_then(|bound| decode_bound(bound, &field.dtype))
+        };
+
+        Some(ColumnStats {
+            lower: decode(&summary.lower_bound),
+            upper: decode(&summary.upper_bound),
+            null_count: (!summary.contains_null).then_some(0),
+            value_count: None,
+        })
+    }
+
+    fn file_stats(
+        &self,
+        spec: &PartitionSpec,
+        file: &DataFile,
+        column: &str,
+    ) -> Option<ColumnStats> {
+        let field = self.field(column)?;
+        let decode = |bounds: &PlHashMap<i32, Vec<u8>>| {
+            bounds
+                .get(&field.id)
+                .and_then(|bound| decode_bound(bound, &field.dtype))
+        };
+        let mut stats = ColumnStats {
+            lower: decode(&file.lower_bounds),
+            upper: decode(&file.upper_bounds),
+            null_count: file.null_value_counts.get(&field.id).map(|&n| n as u64),
+            value_count: file.value_counts.get(&field.id).map(|&n| n as u64),
+        };
+
+        // All rows of a file have the same value in an identity partitioned column.
+        if let Some(value) = self
+            .identity_partition(spec, field)
+            .and_then(|i| file.partition.get(i))
+        {
+            let record_count = file.record_count as u64;
+            if value.is_null() {
+                stats.null_count = Some(record_count);
+                stats.value_count = Some(record_count);
+            } else if value.dtype() == field.dtype {
+                stats.lower = Some(value.clone());
+                stats.upper = Some(value.clone());
+                stats.null_count = Some(0);
+            }
+        }
+
+        Some(stats)
+    }
+
+    /// Position deletes apply to data files of the same or an earlier sequence number, equality
+    /// deletes only to data files of an earlier sequence number. Deletes of a partitioned spec only
+    /// apply to data files of the same partition.
+    fn delete_applies(&self, delete: &DataFile, data: &DataFile) -> bool {
+        let sequence_number_matches = match delete.content {
+            FileContent::PositionDeletes => data.sequence_number <= delete.sequence_number,
+            FileContent::EqualityDeletes => data.sequence_number < delete.sequence_number,
+            FileContent::Data => false,
+        };
+        let is_global = self
+            .partition_spec(delete.partition_spec_id)
+            .is_ok_and(|spec| spec.fields.is_empty());
+
+        sequence_number_matches
+            && (is_global
+                || (delete.partition_spec_id == data.partition_spec_id
+                    && delete.partition == data.partition))
+    }
+
+    fn read_position_deletes(
+        &self,
+        delete: &DataFile,
+        data_files: &[DataFile],
+        targets: &[usize],
+        deleted_rows: &mut [Vec<IdxSize>],
+    ) -> PolarsResult<()> {
+        let df = ParquetReader::new(Cursor::new(self.read(&delete.path)?))
+            .with_columns(Some(vec!["file_path".into(), "pos".into()]))
+            .finish()?;
+        let paths = df.column("file_path")?.str()?;
+        let positions = df.column("pos")?.cast(&DataType::Int64)?;
+        let positions = positions.i64()?;
+
+        let targets = targets
+            .iter()
+            .map(|&i| (data_files[i].path.as_str(), i))
+            .collect::<PlHashMap<_, _>>();
+        for (path, pos) in paths.iter().zip(positions.iter()) {
+            let (Some(path), Some(pos)) = (path, pos) else {
+                continue;
+            };
+            if let Some(&i) = targets.get(path) {
+                let pos = IdxSize::try_from(pos).map_err(
+                    |_| polars_err!(ComputeError: "invalid position {} in Iceberg position delete file", pos),
+                )?;
+                deleted_rows[i].push(pos);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_equality_deletes(&self, delete: &DataFile) -> PolarsResult<EqualityDeletes> {
+        polars_ensure!(
+            !delete.equality_ids.is_empty(),
+            ComputeError: "Iceberg equality delete file '{}' has no equality ids", delete.path
+        );
+
+        let mut reader = ParquetReader::new(Cursor::new(self.read(&delete.path)?));
+        let file_names = self.file_column_names(reader.get_metadata()?.schema().fields());
+
+        let mut columns = vec![];
+        let mut table_fields = vec![];
+        for id in &delete.equality_ids {
+            let position = self.table_schema.fields.iter().position(|f| f.id == *id);
+            let (Some(position), Some(Some(file_name))) =
+                (position, position.map(|i| &file_names[i]))
+            else {
+                polars_bail!(
+                    nyi = "Iceberg equality deletes on field {} that is not a column of the table and the delete file",
+                    id
+                );
+            };
+            columns.push(file_name.to_string());
+            table_fields.push(&self.table_schema.fields[position]);
+        }
+
+        let df = reader.with_columns(Some(columns.clone())).finish()?;
+        let rows = columns
+            .iter()
+            .zip(table_fields)
+            .map(|(name, field)| {
+                Ok(df
+                    .column(name)?
+                    .cast(&field.dtype)?
+                    .with_name(field.name.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(EqualityDeletes {
+            rows: DataFrame::new(rows)?,
+        })
+    }
+
+    /// Returns the name of every column of the table in a Parquet file, or `None` for columns
+    /// that the file does not contain. Columns are matched by field id, unless the file was
+    /// written without field ids, in which case the name mapping of the table is used.
+    fn file_column_names(&self, fields: &[ParquetType]) -> Vec<Option<PlSmallStr>> {
+        let has_field_ids = fields.iter().any(|f| f.get_field_info().id.is_some());
+        let find = |name: &str| {
+            fields
+                .iter()
+                .map(|f| &f.get_field_info().name)
+                .find(|n| n.as_str() == name)
+                .cloned()
+        };
+
+        self.table_schema
+            .fields
+            .iter()
+            .map(|field| {
+                if has_field_ids {
+                    return fields
+                        .iter()
+                        .map(ParquetType::get_field_info)
+                        .find(|info| info.id == Some(field.id))
+                        .map(|info| info.name.clone());
+                }
+                match self.name_mapping.get(&field.id) {
+                    Some(names) => names.iter().find_map(|name| find(name)),
+                    None => find(&field.name),
+                }
+            })
+            .collect()
+    }
+
+    fn renamed_columns(&self, fields: &[ParquetType]) -> Vec<(PlSmallStr, PlSmallStr)> {
+        let contains = |name: &str| fields.iter().any(|f| f.get_field_info().name == name);
+
+        self.file_column_names(fields)
+            .into_iter()
+            .zip(&self.table_schema.fields)
+            .filter_map(|(file_name, field)| match file_name {
+                Some(name) => (name != field.name).then(|| (name, field.name.clone())),
+                // A column that was dropped can have the name of a column that was added later.
+                // The column is read under a name that does not exist, which fills it with nulls.
+                None if contains(&field.name) => Some((
+                    format_pl_smallstr!("{}{}", MISSING_COLUMN_PREFIX, field.name),
+                    field.name.clone(),
+                )),
+                None => None,
+            })
+            .collect()
+    }
+}
+
+/// Returns the location of the metadata file of the table at `uri`.
+fn find_metadata_file(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<String> {
+    let uri = uri.trim_end_matches('/');
+    if uri.ends_with(".metadata.json") {
+        return Ok(uri.to_string());
+    }
+
+    let metadata_dir = format!("{uri}/metadata");
+    let names = storage::list_dir(&metadata_dir, cloud_options)?;
+
+    // Tables written without a catalog record their current version in a hint file.
+    if names.iter().any(|name| name == "version-hint.text") {
+        let hint = storage::read(&format!("{metadata_dir}/version-hint.text"), cloud_options)?;
+        let hint = String::from_utf8_lossy(&hint);
+        let name = format!("v{}.metadata.json", hint.trim());
+        if names.contains(&name) {
+            return Ok(format!("{metadata_dir}/{name}"));
+        }
+    }
+
+    names
+        .iter()
+        .filter_map(|name| Some((metadata_version(name)?, name)))
+        .max()
+        .map(|(_, name)| format!("{metadata_dir}/{name}"))
+        .ok_or_else(|| {
+            polars_err!(
+                ComputeError: "no Iceberg table found at '{}': missing metadata files", uri
+            )
+        })
+}
+
+/// Parses the version of metadata files named `v<version>.metadata.json` or
+/// `<version>-<uuid>.metadata.json`.
+fn metadata_version(name: &str) -> Option<u64> {
+    let stem = name.strip_suffix(".metadata.json")?;
+    let version = match stem.strip_prefix('v') {
+        Some(version) => version,
+        None => stem.split_once('-')?.0,
+    };
+    version.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_version() {
+        assert_eq!(metadata_version("v12.metadata.json"), Some(12));
+        assert_eq!(
+            metadata_version("00003-5b3c1d2e-0b8f-4d2a-9c1e-3f0a8e6b7d41.metadata.json"),
+            Some(3)
+        );
+        assert_eq!(metadata_version("version-hint.text"), None);
+        assert_eq!(metadata_version("snap-1-1-abc.avro"), None);
+    }
+}
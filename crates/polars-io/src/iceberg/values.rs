@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+
+/// Decodes a value in Iceberg's single-value binary serialization, as used for the bounds of
+/// column statistics and partition summaries. Returns `None` for types whose bounds are not used.
+pub(super) fn decode_bound(bytes: &[u8], dtype: &DataType) -> Option<AnyValue<'static>> {
+    // Bounds written before a column was promoted (int to long, float to double) keep the size of
+    // the original type.
+    let int = || -> Option<i64> {
+        match bytes.len() {
+            4 => Some(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+            8 => Some(i64::from_le_bytes(bytes.try_into().ok()?)),
+            _ => None,
+        }
+    };
+
+    let value = match dtype {
+        DataType::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+        DataType::Int32 => AnyValue::Int32(i32::from_le_bytes(bytes.try_into().ok()?)),
+        DataType::Int64 => AnyValue::Int64(int()?),
+        DataType::Float32 => AnyValue::Float32(f32::from_le_bytes(bytes.try_into().ok()?)),
+        DataType::Float64 => match bytes.len() {
+            4 => AnyValue::Float64(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+            _ => AnyValue::Float64(f64::from_le_bytes(bytes.try_into().ok()?)),
+        },
+        DataType::Date => AnyValue::Date(i32::from_le_bytes(bytes.try_into().ok()?)),
+        // Iceberg stores times in microseconds.
+        DataType::Time => AnyValue::Time(int()?.checked_mul(1000)?),
+        DataType::Datetime(tu, tz) => {
+            AnyValue::DatetimeOwned(int()?, *tu, tz.as_ref().map(|tz| Arc::new(tz.clone())))
+        },
+        DataType::String => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+        DataType::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, Some(scale)) => {
+            // Big-endian two's complement with the minimum number of bytes.
+            if bytes.is_empty() || bytes.len() > 16 {
+                return None;
+            }
+            let fill = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+            let mut buf = [fill; 16];
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            AnyValue::Decimal(i128::from_be_bytes(buf), *scale)
+        },
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bound() {
+        assert_eq!(
+            decode_bound(&5i32.to_le_bytes(), &DataType::Int32),
+            Some(AnyValue::Int32(5))
+        );
+        assert_eq!(
+            decode_bound(&(-5i32).to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(-5))
+        );
+        assert_eq!(
+            decode_bound(b"abc", &DataType::String),
+            Some(AnyValue::StringOwned("abc".into()))
+        );
+        assert_eq!(
+            decode_bound(&1_500i64.to_le_bytes(), &DataType::Time),
+            Some(AnyValue::Time(1_500_000))
+        );
+        assert_eq!(decode_bound(&[1, 2, 3], &DataType::Int32), None);
+        #[cfg(feature = "dtype-decimal")]
+        assert_eq!(
+            decode_bound(&[0xff, 0x38], &DataType::Decimal(Some(10), Some(2))),
+            Some(AnyValue::Decimal(-200, 2))
+        );
+    }
+}
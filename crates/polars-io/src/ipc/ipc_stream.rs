@@ -38,13 +38,25 @@ use std::path::PathBuf;
 
 use arrow::datatypes::Metadata;
 use arrow::io::ipc::read::{StreamMetadata, StreamState};
-use arrow::io::ipc::write::WriteOptions;
+use arrow::io::ipc::write::{EncodedData, WriteOptions};
 use arrow::io::ipc::{read, write};
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
-use crate::shared::{ArrowReader, finish_reader};
+use crate::shared::{ArrowReader, finish_reader, schema_to_arrow_checked};
+
+/// Options for scanning files in Arrow's Streaming IPC format.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IpcStreamScanOptions {
+    /// Schema of the streams. If set, the schema is not read from the first source while
+    /// planning the query, which is required for sources that can only be read once, such as
+    /// pipes and stdin.
+    pub schema: Option<SchemaRef>,
+}
 
 /// Read Arrows Stream IPC format into a DataFrame
 ///
@@ -116,6 +128,13 @@ impl<R: Read> IpcStreamReader<R> {
         self
     }
 
+    /// Count the rows of the stream without deserializing its record batches.
+    pub fn count_rows(mut self) -> PolarsResult<usize> {
+        self.metadata()?;
+        let n_rows = read::get_stream_row_count(&mut self.reader)?;
+        Ok(n_rows as usize)
+    }
+
     fn metadata(&mut self) -> PolarsResult<StreamMetadata> {
         match &self.metadata {
             None => {
@@ -251,6 +270,27 @@ impl<W> IpcStreamWriter<W> {
     }
 }
 
+impl<W: Write> IpcStreamWriter<W> {
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedStreamWriter<W>> {
+        let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        let mut writer = write::StreamWriter::new(
+            self.writer,
+            WriteOptions {
+                compression: self.compression.map(|c| c.into()),
+            },
+        );
+        if let Some(custom_metadata) = self.custom_schema_metadata {
+            writer.set_custom_schema_metadata(custom_metadata);
+        }
+        writer.start(&schema, None)?;
+
+        Ok(BatchedStreamWriter {
+            writer,
+            compat_level: self.compat_level,
+        })
+    }
+}
+
 impl<W> SerWriter<W> for IpcStreamWriter<W>
 where
     W: Write,
@@ -288,6 +328,41 @@ where
     }
 }
 
+pub struct BatchedStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+    compat_level: CompatLevel,
+}
+
+impl<W: Write> BatchedStreamWriter<W> {
+    /// Write a batch to the ipc stream writer.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let iter = df.iter_chunks(self.compat_level, true);
+        for batch in iter {
+            self.writer.write(&batch, None)?
+        }
+        Ok(())
+    }
+
+    /// Write encoded data to the ipc stream writer.
+    pub fn write_encoded(
+        &mut self,
+        dictionaries: &[EncodedData],
+        message: &EncodedData,
+    ) -> PolarsResult<()> {
+        self.writer.write_encoded(dictionaries, message)?;
+        Ok(())
+    }
+
+    /// Writes the end-of-stream marker.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()?;
+        Ok(())
+    }
+}
+
 pub struct IpcStreamWriterOption {
     compression: Option<IpcCompression>,
     extension: PathBuf,
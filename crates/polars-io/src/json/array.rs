@@ -0,0 +1,312 @@
+//! Incremental reading of a top-level JSON array of records.
+//!
+//! [`JsonArraySplitter`] walks the array without parsing the records and yields chunks of whole
+//! records. Every chunk is parsed on its own by a [`JsonArrayChunkParser`], so a file never has to
+//! be materialized as a single JSON value.
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use arrow::array::StructArray;
+use arrow::datatypes::ArrowDataType;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use simd_json::BorrowedValue;
+
+use super::infer::json_values_to_supertype;
+use super::remove_bom;
+use crate::utils::overwrite_schema;
+
+/// Options for reading a JSON file that contains a single array of objects.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonArrayReadOptions {
+    /// Schema of the records. If not set, it is inferred from the first records of the file.
+    pub schema: Option<SchemaRef>,
+    /// Overwrite the dtypes of these fields of the schema.
+    pub schema_overwrite: Option<SchemaRef>,
+    /// Number of records to infer the schema from. All records are used if `None`.
+    pub infer_schema_length: Option<NonZeroUsize>,
+}
+
+impl Default for JsonArrayReadOptions {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            schema_overwrite: None,
+            infer_schema_length: Some(NonZeroUsize::new(100).unwrap()),
+        }
+    }
+}
+
+impl JsonArrayReadOptions {
+    /// Set the schema of the records, this skips schema inference.
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Overwrite the dtypes of some fields of the (inferred) schema.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// Set the number of records used to infer the schema. All records are used if `None`.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<NonZeroUsize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+}
+
+/// A range of whole records of a JSON array.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonArrayChunk {
+    /// Byte range of the records in the file, without the brackets of the array.
+    pub range: Range<usize>,
+    /// Number of records in the chunk.
+    pub n_records: usize,
+}
+
+#[inline]
+fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(|b| b.is_ascii_whitespace()) {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the position after the closing quote of a string that starts at `i`, which is the
+/// position after the opening quote.
+#[inline]
+fn skip_string(bytes: &[u8], mut i: usize) -> PolarsResult<usize> {
+    loop {
+        match bytes
+            .get(i..)
+            .and_then(|rest| memchr::memchr2(b'"', b'\\', rest))
+        {
+            // Skip the escaped character.
+            Some(pos) if bytes[i + pos] == b'\\' => i += pos + 2,
+            Some(pos) => return Ok(i + pos + 1),
+            None => polars_bail!(ComputeError: "unterminated string in JSON array"),
+        }
+    }
+}
+
+/// Walks the records of a top-level JSON array without parsing them.
+///
+/// Only strings and the nesting of objects and arrays are tracked to find the commas that
+/// separate the records. Invalid records are passed on as they are and fail when they are parsed.
+pub struct JsonArraySplitter<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    finished: bool,
+}
+
+impl<'a> JsonArraySplitter<'a> {
+    pub fn new(bytes: &'a [u8]) -> PolarsResult<Self> {
+        let bom_len = bytes.len() - remove_bom(bytes)?.len();
+        let start = skip_whitespace(bytes, bom_len);
+        polars_ensure!(
+            bytes.get(start) == Some(&b'['),
+            ComputeError: "expected a JSON array at the top level of the file"
+        );
+
+        let pos = skip_whitespace(bytes, start + 1);
+        let mut splitter = Self {
+            bytes,
+            pos,
+            finished: false,
+        };
+        if bytes.get(pos) == Some(&b']') {
+            splitter.finish(pos)?;
+        }
+        Ok(splitter)
+    }
+
+    fn finish(&mut self, closing_bracket: usize) -> PolarsResult<()> {
+        let end = skip_whitespace(self.bytes, closing_bracket + 1);
+        polars_ensure!(
+            end == self.bytes.len(),
+            ComputeError: "unexpected data after the JSON array at byte {}", end
+        );
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Byte range of the next record, or `None` after the last record.
+    pub fn next_record(&mut self) -> PolarsResult<Option<Range<usize>>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let bytes = self.bytes;
+        let start = skip_whitespace(bytes, self.pos);
+        let mut depth = 0usize;
+        let mut i = start;
+        let separator = loop {
+            let Some(&b) = bytes.get(i) else {
+                polars_bail!(ComputeError: "unexpected end of file in JSON array")
+            };
+            match b {
+                b'"' => {
+                    i = skip_string(bytes, i + 1)?;
+                    continue;
+                },
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' if depth > 0 => depth -= 1,
+                b',' | b']' if depth == 0 => break b,
+                _ => {},
+            }
+            i += 1;
+        };
+
+        let mut end = i;
+        while end > start && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        polars_ensure!(
+            end > start,
+            ComputeError: "missing value in JSON array at byte {}", start
+        );
+
+        if separator == b']' {
+            self.finish(i)?;
+        } else {
+            self.pos = i + 1;
+        }
+        Ok(Some(start..end))
+    }
+
+    /// The next records that start within `chunk_size` bytes of the first one, or `None` after
+    /// the last record.
+    pub fn next_chunk(&mut self, chunk_size: usize) -> PolarsResult<Option<JsonArrayChunk>> {
+        let Some(mut range) = self.next_record()? else {
+            return Ok(None);
+        };
+        let mut n_records = 1;
+
+        while range.len() < chunk_size {
+            let Some(record) = self.next_record()? else {
+                break;
+            };
+            range.end = record.end;
+            n_records += 1;
+        }
+
+        Ok(Some(JsonArrayChunk { range, n_records }))
+    }
+}
+
+/// Parse `records`, the comma-separated records of a chunk, as a JSON array.
+fn with_parsed_records<T>(
+    records: &[u8],
+    f: impl FnOnce(Vec<BorrowedValue>) -> PolarsResult<T>,
+) -> PolarsResult<T> {
+    let mut buf = Vec::with_capacity(records.len() + 2);
+    buf.push(b'[');
+    buf.extend_from_slice(records);
+    buf.push(b']');
+
+    match simd_json::to_borrowed_value(&mut buf).map_err(to_compute_err)? {
+        BorrowedValue::Array(values) => f(*values),
+        _ => unreachable!(),
+    }
+}
+
+/// Resolve the schema of the records: the schema of the options, or the schema inferred from the
+/// first records of `bytes`, with the schema overwrite applied.
+pub fn infer_schema(bytes: &[u8], options: &JsonArrayReadOptions) -> PolarsResult<Schema> {
+    let mut schema = if let Some(schema) = &options.schema {
+        schema.as_ref().clone()
+    } else {
+        let mut splitter = JsonArraySplitter::new(bytes)?;
+        let infer_schema_length = options.infer_schema_length.map_or(usize::MAX, |n| n.get());
+
+        let mut records: Option<Range<usize>> = None;
+        for _ in 0..infer_schema_length {
+            let Some(record) = splitter.next_record()? else {
+                break;
+            };
+            records.get_or_insert(record.clone()).end = record.end;
+        }
+
+        match records {
+            None => Schema::default(),
+            Some(records) => {
+                let dtype = with_parsed_records(&bytes[records], |values| {
+                    json_values_to_supertype(&values, NonZeroUsize::MAX)
+                })?;
+                let DataType::Struct(fields) = dtype else {
+                    polars_bail!(
+                        ComputeError: "expected a JSON array of objects, got values of dtype {}", dtype
+                    )
+                };
+                Schema::from_iter(fields)
+            },
+        }
+    };
+
+    if let Some(schema_overwrite) = &options.schema_overwrite {
+        overwrite_schema(&mut schema, schema_overwrite)?;
+    }
+
+    Ok(schema)
+}
+
+/// Count the records of a JSON array without parsing them.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let mut splitter = JsonArraySplitter::new(bytes)?;
+    let mut n_records = 0;
+    while splitter.next_record()?.is_some() {
+        n_records += 1;
+    }
+    Ok(n_records)
+}
+
+/// Parses chunks of records into [`DataFrame`]s with the fields of a schema. Fields that are
+/// missing in a record are null and fields that are not in the schema are ignored, so only the
+/// fields in the schema are materialized.
+#[derive(Clone, Debug)]
+pub struct JsonArrayChunkParser {
+    schema: SchemaRef,
+    dtype: ArrowDataType,
+}
+
+impl JsonArrayChunkParser {
+    pub fn new(schema: SchemaRef) -> Self {
+        let dtype = ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
+            PlSmallStr::from_static("item"),
+            DataType::Struct(schema.iter_fields().collect()).to_arrow(CompatLevel::newest()),
+            true,
+        )));
+        Self { schema, dtype }
+    }
+
+    /// Parse up to `n_records` records of the records of a [`JsonArrayChunk`], after skipping the
+    /// first `skip` records.
+    pub fn parse(&self, records: &[u8], skip: usize, n_records: usize) -> PolarsResult<DataFrame> {
+        if self.schema.is_empty() {
+            return Ok(DataFrame::empty_with_height(n_records));
+        }
+
+        with_parsed_records(records, |mut values| {
+            values.drain(..skip.min(values.len()));
+            values.truncate(n_records);
+
+            let arr = polars_json::json::deserialize(
+                &BorrowedValue::Array(Box::new(values)),
+                self.dtype.clone(),
+                true,
+            )?;
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            DataFrame::try_from(arr.clone())
+        })
+    }
+
+    /// Schema of the [`DataFrame`]s returned by [`JsonArrayChunkParser::parse`].
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+}
@@ -62,6 +62,7 @@
 //! +-----+--------+-------+--------+
 //! ```
 //!
+pub mod array;
 pub(crate) mod infer;
 
 use std::io::Write;
@@ -79,10 +80,13 @@ use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
+use crate::utils::compression::CompressedWriter;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct JsonWriterOptions {}
+pub struct JsonWriterOptions {
+    pub compression: ExternalCompression,
+}
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
 /// or `JsonLines` (each row output on a separate line).
@@ -117,6 +121,7 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    compression: ExternalCompression,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -124,6 +129,12 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the compression of the output, see [`ExternalCompression`].
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -136,6 +147,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            compression: ExternalCompression::default(),
         }
     }
 
@@ -153,18 +165,19 @@ where
             .iter_chunks(CompatLevel::newest(), false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
+        let mut buffer = CompressedWriter::new(&mut self.buffer, self.compression);
         match self.json_format {
             JsonFormat::JsonLines => {
                 let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                let writer = polars_json::ndjson::write::FileWriter::new(&mut buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                polars_json::json::write::write(&mut buffer, serializer)?;
             },
         }
+        buffer.finish()?;
 
         Ok(())
     }
@@ -172,6 +185,8 @@ where
 
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    compression: ExternalCompression,
+    has_written_batch: bool,
 }
 
 impl<W> BatchedWriter<W>
@@ -179,7 +194,17 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            compression: ExternalCompression::default(),
+            has_written_batch: false,
+        }
+    }
+
+    /// Set the compression of the output, see [`ExternalCompression`].
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
     }
     /// Write a batch to the json writer.
     ///
@@ -198,8 +223,20 @@ where
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
         let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+        let mut writer = CompressedWriter::new(&mut self.writer, self.compression);
         while let Some(block) = serializer.next()? {
-            self.writer.write_all(block)?;
+            writer.write_all(block)?;
+        }
+        writer.finish()?;
+        self.has_written_batch = true;
+        Ok(())
+    }
+
+    /// Ensure that compressed output is a valid stream, even if no batches were written.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        if !self.has_written_batch {
+            self.has_written_batch = true;
+            CompressedWriter::new(&mut self.writer, self.compression).finish()?;
         }
         Ok(())
     }
@@ -362,6 +399,8 @@ where
                     None,
                     None,
                     None,
+                    None,
+                    None,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -10,20 +10,32 @@ pub mod catalog;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "fwf")]
+pub mod fwf;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "lines")]
+pub mod lines;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "parquet")]
 pub mod partition;
+#[cfg(feature = "pg_copy")]
+pub mod pg_copy;
 pub mod path_utils;
 #[cfg(feature = "async")]
 pub mod pl_async;
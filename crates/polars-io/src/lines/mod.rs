@@ -0,0 +1,10 @@
+//! Regex-driven reading of line-oriented text files, such as web-server and application logs.
+//!
+//! A regex with named capture groups is applied to every line and every named group becomes a
+//! column. The captured fields are parsed with the CSV buffers, so the number and temporal parsing
+//! is the same as for CSV. Lines that don't match are either dropped or kept in a reject column.
+mod options;
+mod read;
+
+pub use options::*;
+pub use read::*;
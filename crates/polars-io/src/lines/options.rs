@@ -0,0 +1,147 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::NullValues;
+
+/// What to do with lines that are not matched by the pattern.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnmatchedLines {
+    /// Skip the line.
+    #[default]
+    Drop,
+    /// Keep the line in a String column with this name. The capture group columns of the row are
+    /// null, and the reject column is null for lines that match.
+    Reject { column: PlSmallStr },
+}
+
+/// Options for reading line-oriented text files with a regex.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinesReadOptions {
+    /// Regex with named capture groups. Every named group becomes a column, unnamed groups are
+    /// ignored.
+    pub pattern: PlSmallStr,
+    /// Dtypes of the capture groups. Groups that are not in here are read as String.
+    pub schema_overwrite: Option<SchemaRef>,
+    pub unmatched: UnmatchedLines,
+    pub null_values: Option<NullValues>,
+    pub eol_char: u8,
+    pub decimal_comma: bool,
+    pub ignore_errors: bool,
+}
+
+impl Default for LinesReadOptions {
+    fn default() -> Self {
+        Self {
+            pattern: PlSmallStr::EMPTY,
+            schema_overwrite: None,
+            unmatched: UnmatchedLines::default(),
+            null_values: None,
+            eol_char: b'\n',
+            decimal_comma: false,
+            ignore_errors: false,
+        }
+    }
+}
+
+impl LinesReadOptions {
+    pub fn new(pattern: impl Into<PlSmallStr>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the dtypes of capture groups. Groups that are not in the schema are read as String.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// Drop lines that don't match the pattern, or keep them in a reject column.
+    pub fn with_unmatched(mut self, unmatched: UnmatchedLines) -> Self {
+        self.unmatched = unmatched;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Parse floats with a comma as decimal separator.
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Continue with next row when a field fails to parse, setting it to null.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// Compile the pattern.
+    pub fn regex(&self) -> PolarsResult<regex::bytes::Regex> {
+        Ok(regex::bytes::Regex::new(&self.pattern)?)
+    }
+
+    /// Column name of every named capture group, with the index of the group.
+    pub(super) fn named_groups(regex: &regex::bytes::Regex) -> Vec<(usize, PlSmallStr)> {
+        regex
+            .capture_names()
+            .enumerate()
+            .filter_map(|(i, name)| Some((i, PlSmallStr::from_str(name?))))
+            .collect()
+    }
+
+    /// The schema of the file: the named capture groups, followed by the reject column if
+    /// unmatched lines are kept.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        let groups = Self::named_groups(&self.regex()?);
+        polars_ensure!(
+            !groups.is_empty(),
+            InvalidOperation: "the pattern '{}' has no named capture groups", self.pattern
+        );
+
+        let mut schema = Schema::with_capacity(groups.len() + 1);
+        for (_, name) in groups {
+            let dtype = self
+                .schema_overwrite
+                .as_ref()
+                .and_then(|schema| schema.get(&name).cloned())
+                .unwrap_or(DataType::String);
+            schema.insert(name, dtype);
+        }
+
+        if let Some(schema_overwrite) = &self.schema_overwrite {
+            if let Some(name) = schema_overwrite
+                .iter_names()
+                .find(|name| !schema.contains(name))
+            {
+                polars_bail!(
+                    ColumnNotFound: "'{}' in the schema overwrite is not a named capture group of the pattern",
+                    name
+                );
+            }
+        }
+
+        if let UnmatchedLines::Reject { column } = &self.unmatched {
+            polars_ensure!(
+                schema.insert(column.clone(), DataType::String).is_none(),
+                Duplicate: "reject column '{}' has the name of a capture group", column
+            );
+        }
+
+        Ok(schema)
+    }
+}
@@ -0,0 +1,437 @@
+use std::ops::Range;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use rayon::prelude::*;
+use regex::bytes::{CaptureLocations, Regex};
+
+use super::options::{LinesReadOptions, UnmatchedLines};
+use crate::csv::read::_csv_read_internal::{NullValuesCompiled, cast_columns, prepare_csv_schema};
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::buffer::{Buffer, init_buffers, validate_utf8};
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::get_reader_bytes;
+
+const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+/// A range of whole lines of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinesChunk {
+    /// Byte range of the chunk in the file.
+    pub range: Range<usize>,
+    /// Number of non-empty lines in the chunk.
+    pub n_lines: usize,
+}
+
+/// Iterates the lines of `bytes`. A trailing carriage return is not part of a line and empty lines
+/// are skipped.
+fn lines(bytes: &[u8], eol_char: u8) -> impl Iterator<Item = &[u8]> {
+    let mut start = 0;
+    memchr::memchr_iter(eol_char, bytes)
+        .chain(std::iter::once(bytes.len()))
+        .map(move |end| {
+            let line = &bytes[start..end];
+            start = end + 1;
+            line.strip_suffix(b"\r").unwrap_or(line)
+        })
+        .filter(|line| !line.is_empty())
+}
+
+/// Split a file into chunks of whole lines of roughly `chunk_size` bytes and count the lines of
+/// every chunk.
+pub fn split_chunks(bytes: &[u8], eol_char: u8, chunk_size: usize) -> Vec<LinesChunk> {
+    let mut chunks = vec![];
+    let mut start = if bytes.starts_with(&UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    };
+
+    while start < bytes.len() {
+        let target = (start + chunk_size.max(1)).min(bytes.len());
+        let end = match memchr::memchr(eol_char, &bytes[target..]) {
+            Some(pos) => target + pos + 1,
+            None => bytes.len(),
+        };
+        let range = start..end;
+        let n_lines = lines(&bytes[range.clone()], eol_char).count();
+        if n_lines > 0 {
+            chunks.push(LinesChunk { range, n_lines });
+        }
+        start = end;
+    }
+
+    chunks
+}
+
+/// Count the rows of an in-memory file. Unless unmatched lines are kept, this has to match every
+/// line against the pattern.
+pub fn count_rows(bytes: &[u8], options: &LinesReadOptions) -> PolarsResult<usize> {
+    let bytes = bytes.strip_prefix(&UTF8_BOM).unwrap_or(bytes);
+    let parser = LinesChunkParser::new(options, Some(vec![]))?;
+    Ok(parser.count_rows(bytes, options.eol_char))
+}
+
+#[inline]
+fn is_valid_line(line: &[u8]) -> bool {
+    line.is_ascii() || validate_utf8(line)
+}
+
+/// Applies the pattern to the lines of a file and parses the captures into [`DataFrame`]s. Only
+/// the projected capture groups are parsed.
+#[derive(Clone, Debug)]
+pub struct LinesChunkParser {
+    regex: Regex,
+    /// Capture group index and schema index of every projected capture group column.
+    groups: Vec<(usize, usize)>,
+    /// Name of the reject column, if unmatched lines are kept and the column is projected.
+    reject_column: Option<PlSmallStr>,
+    drop_unmatched: bool,
+    /// Schema of the capture groups.
+    schema: SchemaRef,
+    /// Schema of the capture groups with the dtypes that are parsed, the others are cast
+    /// afterwards.
+    parse_schema: SchemaRef,
+    fields_to_cast: Vec<Field>,
+    null_values: Option<NullValuesCompiled>,
+    decimal_comma: bool,
+    ignore_errors: bool,
+}
+
+impl LinesChunkParser {
+    /// Create a parser for the columns at the `projection` indices of
+    /// [`LinesReadOptions::schema`].
+    pub fn new(options: &LinesReadOptions, projection: Option<Vec<usize>>) -> PolarsResult<Self> {
+        let regex = options.regex()?;
+        let mut schema = options.schema()?;
+        let named_groups = LinesReadOptions::named_groups(&regex);
+
+        let (drop_unmatched, reject_column) = match &options.unmatched {
+            UnmatchedLines::Drop => (true, None),
+            UnmatchedLines::Reject { column } => {
+                schema.shift_remove(column);
+                (false, Some(column.clone()))
+            },
+        };
+
+        let n_columns = schema.len() + usize::from(reject_column.is_some());
+        let mut projection = projection.unwrap_or_else(|| (0..n_columns).collect());
+        projection.sort_unstable();
+        if let Some(&i) = projection.last() {
+            polars_ensure!(
+                i < n_columns,
+                OutOfBounds: "projection index {} is out of bounds for {} columns",
+                i, n_columns
+            );
+        }
+
+        let groups = projection
+            .iter()
+            .filter(|&&i| i < schema.len())
+            .map(|&i| (named_groups[i].0, i))
+            .collect();
+        let reject_column = reject_column.filter(|_| projection.contains(&schema.len()));
+
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|null_values| null_values.compile(&schema))
+            .transpose()?;
+
+        let schema = Arc::new(schema);
+        let mut parse_schema = schema.clone();
+        let mut fields_to_cast = vec![];
+        prepare_csv_schema(&mut parse_schema, &mut fields_to_cast)?;
+
+        Ok(Self {
+            regex,
+            groups,
+            reject_column,
+            drop_unmatched,
+            schema,
+            parse_schema,
+            fields_to_cast,
+            null_values,
+            decimal_comma: options.decimal_comma,
+            ignore_errors: options.ignore_errors,
+        })
+    }
+
+    /// Whether lines that don't match are dropped, in which case the number of rows is only
+    /// known after matching.
+    pub fn drops_unmatched(&self) -> bool {
+        self.drop_unmatched
+    }
+
+    /// Count the rows of `bytes` without parsing the captures.
+    pub fn count_rows(&self, bytes: &[u8], eol_char: u8) -> usize {
+        let lines = lines(bytes, eol_char);
+        if self.drop_unmatched {
+            lines
+                .filter(|line| is_valid_line(line) && self.regex.is_match(line))
+                .count()
+        } else {
+            lines.count()
+        }
+    }
+
+    /// Parse up to `n_lines` lines of `bytes`, after skipping the first `skip` lines. Lines that
+    /// are not valid UTF-8 are treated as not matching.
+    pub fn parse(
+        &self,
+        bytes: &[u8],
+        eol_char: u8,
+        skip: usize,
+        n_lines: usize,
+    ) -> PolarsResult<DataFrame> {
+        let buffer_projection = self.groups.iter().map(|(_, i)| *i).collect::<Vec<_>>();
+        let mut buffers = init_buffers(
+            &buffer_projection,
+            n_lines,
+            &self.parse_schema,
+            None,
+            CsvEncoding::Utf8,
+            self.decimal_comma,
+        )?;
+        let mut rejected = self
+            .reject_column
+            .as_ref()
+            .map(|name| StringChunkedBuilder::new(name.clone(), n_lines));
+
+        let mut locations = self.regex.capture_locations();
+        let mut height = 0;
+        for line in lines(bytes, eol_char).skip(skip).take(n_lines) {
+            let is_match = is_valid_line(line)
+                && if self.groups.is_empty() {
+                    self.regex.is_match(line)
+                } else {
+                    self.regex.captures_read(&mut locations, line).is_some()
+                };
+
+            if is_match {
+                self.add_captures(line, &locations, &mut buffers)?;
+                if let Some(rejected) = &mut rejected {
+                    rejected.append_null();
+                }
+            } else if self.drop_unmatched {
+                continue;
+            } else {
+                buffers.iter_mut().for_each(|buf| buf.add_null(false));
+                if let Some(rejected) = &mut rejected {
+                    rejected.append_value(String::from_utf8_lossy(line));
+                }
+            }
+            height += 1;
+        }
+
+        let mut columns = buffers
+            .into_iter()
+            .map(|buf| buf.into_series().map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if let Some(rejected) = rejected {
+            columns.push(rejected.finish().into_column());
+        }
+
+        let mut df = unsafe { DataFrame::new_no_checks(height, columns) };
+        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        Ok(df)
+    }
+
+    fn add_captures(
+        &self,
+        line: &[u8],
+        locations: &CaptureLocations,
+        buffers: &mut [Buffer],
+    ) -> PolarsResult<()> {
+        for (buf, &(group, i)) in buffers.iter_mut().zip(&self.groups) {
+            // Optional groups may not take part in the match.
+            let Some((start, end)) = locations.get(group) else {
+                buf.add_null(false);
+                continue;
+            };
+            let field = &line[start..end];
+
+            // SAFETY: `i` is in bounds of the schema the null values were compiled for.
+            let is_null = self
+                .null_values
+                .as_ref()
+                .is_some_and(|null_values| unsafe { null_values.is_null(field, i) });
+            if is_null {
+                buf.add_null(false);
+                continue;
+            }
+
+            buf.add(field, self.ignore_errors, false, true)
+                .map_err(|e| {
+                    polars_err!(
+                        ComputeError:
+                        "could not parse `{}` as dtype `{}` at capture group '{}'\n\n\
+                        Original error: ```{}```",
+                        String::from_utf8_lossy(field),
+                        buf.dtype(),
+                        self.schema.get_at_index(i).unwrap().0,
+                        e
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Schema of the [`DataFrame`]s returned by [`LinesChunkParser::parse`].
+    pub fn projected_schema(&self) -> Schema {
+        let mut schema: Schema = self
+            .groups
+            .iter()
+            .map(|&(_, i)| {
+                let (name, dtype) = self.schema.get_at_index(i).unwrap();
+                Field::new(name.clone(), dtype.clone())
+            })
+            .collect();
+        if let Some(name) = &self.reject_column {
+            schema.insert(name.clone(), DataType::String);
+        }
+        schema
+    }
+}
+
+/// Read line-oriented text files into a [`DataFrame`] by applying a regex with named capture
+/// groups to every line.
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::lines::{LinesReadOptions, LinesReader, UnmatchedLines};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("access.log").expect("file not found");
+///     let options = LinesReadOptions::new(
+///         r#"^(?P<host>\S+) \S+ \S+ \[[^\]]+\] "(?P<request>[^"]*)" (?P<status>\d{3}) (?P<size>\d+|-)"#,
+///     )
+///     .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+///         Field::new("status".into(), DataType::UInt16),
+///         Field::new("size".into(), DataType::UInt64),
+///     ]))))
+///     .with_unmatched(UnmatchedLines::Reject { column: "rejected".into() });
+///
+///     LinesReader::new(file)
+///             .with_options(options)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct LinesReader<R: MmapBytesReader> {
+    reader: R,
+    options: LinesReadOptions,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: MmapBytesReader> LinesReader<R> {
+    /// Set the pattern and parse options.
+    pub fn with_options(mut self, options: LinesReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for LinesReader<R> {
+    fn new(reader: R) -> Self {
+        LinesReader {
+            reader,
+            options: LinesReadOptions::default(),
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let schema = self.options.schema()?;
+        if let Some(columns) = &self.columns {
+            self.projection = Some(
+                columns
+                    .iter()
+                    .map(|name| schema.try_index_of(name))
+                    .collect::<PolarsResult<_>>()?,
+            );
+        }
+        let parser = LinesChunkParser::new(&self.options, self.projection)?;
+        let eol_char = self.options.eol_char;
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut decompressed = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut decompressed)?;
+
+        let n_threads = POOL.current_num_threads();
+        let chunk_size = (bytes.len() / n_threads).clamp(1 << 16, 1 << 24);
+        let mut chunks = split_chunks(bytes, eol_char, chunk_size);
+
+        // Every line is a row if unmatched lines are kept, so only the needed lines are parsed.
+        if let (Some(n_rows), false) = (self.n_rows, parser.drops_unmatched()) {
+            let mut n_rows_left = n_rows;
+            chunks.retain_mut(|chunk| {
+                chunk.n_lines = chunk.n_lines.min(n_rows_left);
+                n_rows_left -= chunk.n_lines;
+                chunk.n_lines > 0
+            });
+        }
+
+        if chunks.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&parser.projected_schema()));
+        }
+
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|chunk| parser.parse(&bytes[chunk.range], eol_char, 0, chunk.n_lines))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let n_rows = self.n_rows.unwrap_or(usize::MAX);
+        if dfs[0].width() == 0 {
+            let height = dfs.iter().map(|df| df.height()).sum::<usize>();
+            return Ok(DataFrame::empty_with_height(height.min(n_rows)));
+        }
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if df.height() > n_rows {
+            df = df.head(Some(n_rows));
+        }
+
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
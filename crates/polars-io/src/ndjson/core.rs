@@ -14,6 +14,7 @@ use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::utils::rejected_rows::{LineCounter, RawLine, read_lines_rejecting};
 use crate::{RowIndex, SerReader};
 const NEWLINE: u8 = b'\n';
 const CLOSING_BRACKET: u8 = b'}';
@@ -37,6 +38,7 @@ where
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    rejected_rows: Option<RejectedRowsOptions>,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -111,6 +113,13 @@ where
         self
     }
 
+    /// Write lines that are not valid JSON or do not match the schema to the files described by
+    /// `rejected_rows` instead of raising an error. Takes precedence over `ignore_errors`.
+    pub fn with_rejected_rows(mut self, rejected_rows: Option<RejectedRowsOptions>) -> Self {
+        self.rejected_rows = rejected_rows;
+        self
+    }
+
     pub fn count(mut self) -> PolarsResult<usize> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
         let json_reader = CoreJsonReader::new(
@@ -127,6 +136,8 @@ where
             self.row_index,
             self.predicate,
             self.projection,
+            None,
+            None,
         )?;
 
         json_reader.count()
@@ -162,6 +173,7 @@ where
             row_index: None,
             predicate: None,
             projection: None,
+            rejected_rows: None,
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -181,6 +193,13 @@ where
             self.row_index,
             self.predicate,
             self.projection,
+            self.rejected_rows
+                .as_ref()
+                .map(|options| RejectedRows::try_new(options, 0))
+                .transpose()?,
+            self.path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -203,6 +222,9 @@ pub(crate) struct CoreJsonReader<'a> {
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    rejected_rows: Option<RejectedRows>,
+    /// Path of the file, recorded with rejected rows.
+    path: Option<PlSmallStr>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -220,6 +242,8 @@ impl<'a> CoreJsonReader<'a> {
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         projection: Option<Arc<[PlSmallStr]>>,
+        rejected_rows: Option<RejectedRows>,
+        path: Option<PlSmallStr>,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
@@ -248,6 +272,8 @@ impl<'a> CoreJsonReader<'a> {
             row_index,
             predicate,
             projection,
+            rejected_rows,
+            path,
         })
     }
 
@@ -290,18 +316,36 @@ impl<'a> CoreJsonReader<'a> {
             std::cmp::min(rows_per_thread, max_proxy)
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
+        let first_lines = match &self.rejected_rows {
+            Some(_) => {
+                let mut line_counter = LineCounter::new(NEWLINE);
+                file_chunks
+                    .iter()
+                    .map(|(start_pos, _)| line_counter.line_at(bytes, *start_pos))
+                    .collect()
+            },
+            None => vec![0; file_chunks.len()],
+        };
 
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
         let (mut dfs, prepredicate_heights) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
-                .map(|(start_pos, stop_at_nbytes)| {
-                    let mut local_df = parse_ndjson(
-                        &bytes[start_pos..stop_at_nbytes],
-                        Some(capacity),
-                        &self.schema,
-                        self.ignore_errors,
-                    )?;
+                .zip(first_lines)
+                .map(|((start_pos, stop_at_nbytes), first_line)| {
+                    let bytes = &bytes[start_pos..stop_at_nbytes];
+                    let mut local_df = match &self.rejected_rows {
+                        Some(rejected_rows) => parse_ndjson_rejecting(
+                            bytes,
+                            &self.schema,
+                            rejected_rows,
+                            self.path.as_ref(),
+                            first_line,
+                        )?,
+                        None => {
+                            parse_ndjson(bytes, Some(capacity), &self.schema, self.ignore_errors)?
+                        },
+                    };
 
                     let prepredicate_height = local_df.height() as IdxSize;
                     if let Some(projection) = self.projection.as_deref() {
@@ -338,6 +382,9 @@ impl<'a> CoreJsonReader<'a> {
         let reader_bytes = self.reader_bytes.take().unwrap();
 
         let mut df = self.parse_json(n_threads, &reader_bytes)?;
+        if let Some(rejected_rows) = &self.rejected_rows {
+            rejected_rows.finish()?;
+        }
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -433,6 +480,53 @@ pub fn parse_ndjson(
     )
 }
 
+/// Parses `bytes` like [`parse_ndjson`], but lines that are not valid JSON or do not match the
+/// schema are recorded in `rejected_rows` and left out of the output.
+///
+/// `first_line` is the line number of the first line of `bytes` in the file.
+pub fn parse_ndjson_rejecting(
+    bytes: &[u8],
+    schema: &Schema,
+    rejected_rows: &RejectedRows,
+    path: Option<&PlSmallStr>,
+    first_line: usize,
+) -> PolarsResult<DataFrame> {
+    let mut lines = vec![];
+    let mut start = 0;
+    for (i, line) in bytes.split(|&byte| byte == NEWLINE).enumerate() {
+        let end = start + line.len();
+        // Blank lines are skipped, as in `json_lines`.
+        if line
+            .iter()
+            .any(|&byte| !matches!(byte, b' ' | b'\t' | b'\r'))
+        {
+            lines.push(RawLine {
+                start,
+                end: end - line.ends_with(b"\r") as usize,
+                line: first_line + i,
+                error: None,
+            });
+        }
+        start = end + 1;
+    }
+
+    let read = |bytes: &[u8], n_lines: usize| {
+        let df = parse_ndjson(bytes, Some(n_lines), schema, false)?;
+        // Lines are still validated on an empty projection, but the height has to be set.
+        Ok(if schema.is_empty() {
+            DataFrame::empty_with_height(n_lines)
+        } else {
+            df
+        })
+    };
+    let dfs = read_lines_rejecting(bytes, &lines, &read, rejected_rows, path)?;
+    if dfs.is_empty() {
+        read(&[], 0)
+    } else {
+        accumulate_dataframes_vertical(dfs)
+    }
+}
+
 pub fn estimate_n_lines_in_file(file_bytes: &[u8], sample_size: usize) -> usize {
     if let Some((mean, std)) = get_line_stats_json(file_bytes, sample_size) {
         (file_bytes.len() as f32 / (mean - 0.01 * std)) as usize
@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The compression codec of the streams and the metadata of an ORC file.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrcCompression {
+    Uncompressed,
+    /// Raw deflate, the default of the ORC Java writer.
+    #[default]
+    Zlib,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl OrcCompression {
+    pub(super) fn from_kind(kind: u32) -> PolarsResult<Self> {
+        Ok(match kind {
+            0 => Self::Uncompressed,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            3 => polars_bail!(ComputeError: "LZO compressed ORC files are not supported"),
+            _ => polars_bail!(ComputeError: "unknown ORC compression kind {}", kind),
+        })
+    }
+
+    pub(super) fn kind(self) -> u32 {
+        match self {
+            Self::Uncompressed => 0,
+            Self::Zlib => 1,
+            Self::Snappy => 2,
+            Self::Lz4 => 4,
+            Self::Zstd => 5,
+        }
+    }
+}
+
+/// Block size used by the writer, the same as the default of the ORC Java writer.
+pub(super) const DEFAULT_BLOCK_SIZE: usize = 256 * 1024;
+
+/// Decompresses a stream, which is a sequence of chunks that each start with a 3-byte header
+/// holding the chunk length and whether the chunk was stored uncompressed.
+pub(super) fn decompress(
+    compression: OrcCompression,
+    block_size: usize,
+    mut data: &[u8],
+) -> PolarsResult<Cow<'_, [u8]>> {
+    if compression == OrcCompression::Uncompressed {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    while !data.is_empty() {
+        polars_ensure!(data.len() >= 3, ComputeError: "invalid ORC compression chunk header");
+        let header = u32::from_le_bytes([data[0], data[1], data[2], 0]);
+        let (len, is_original) = ((header >> 1) as usize, header & 1 == 1);
+        polars_ensure!(
+            data.len() >= 3 + len,
+            ComputeError: "unexpected end of ORC compression chunk"
+        );
+        let chunk = &data[3..3 + len];
+        data = &data[3 + len..];
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match compression {
+            OrcCompression::Uncompressed => unreachable!(),
+            OrcCompression::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(to_compute_err)?;
+            },
+            OrcCompression::Snappy => {
+                let len = snap::raw::decompress_len(chunk).map_err(to_compute_err)?;
+                let start = out.len();
+                out.resize(start + len, 0);
+                snap::raw::Decoder::new()
+                    .decompress(chunk, &mut out[start..])
+                    .map_err(to_compute_err)?;
+            },
+            OrcCompression::Lz4 => {
+                // The decompressed size is not stored, but it is at most the block size.
+                let decompressed = lz4::block::decompress(chunk, Some(block_size as i32))
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            OrcCompression::Zstd => {
+                zstd::stream::read::Decoder::new(chunk)
+                    .and_then(|mut decoder| decoder.read_to_end(&mut out))
+                    .map_err(to_compute_err)?;
+            },
+        }
+    }
+    Ok(Cow::Owned(out))
+}
+
+/// Compresses a stream into chunks of at most `block_size` bytes. Chunks that do not get smaller
+/// are stored uncompressed.
+pub(super) fn compress(
+    compression: OrcCompression,
+    block_size: usize,
+    data: &[u8],
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    if compression == OrcCompression::Uncompressed {
+        out.extend_from_slice(data);
+        return Ok(());
+    }
+
+    let mut compressed = vec![];
+    for chunk in data.chunks(block_size) {
+        compressed.clear();
+        match compression {
+            OrcCompression::Uncompressed => unreachable!(),
+            OrcCompression::Zlib => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    &mut compressed,
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(chunk)?;
+                encoder.finish()?;
+            },
+            OrcCompression::Snappy => {
+                compressed = snap::raw::Encoder::new()
+                    .compress_vec(chunk)
+                    .map_err(to_compute_err)?;
+            },
+            OrcCompression::Lz4 => {
+                compressed = lz4::block::compress(chunk, None, false).map_err(to_compute_err)?;
+            },
+            OrcCompression::Zstd => {
+                compressed = zstd::bulk::compress(chunk, 0).map_err(to_compute_err)?;
+            },
+        }
+
+        let (chunk, is_original) = if compressed.len() < chunk.len() {
+            (compressed.as_slice(), 0)
+        } else {
+            (chunk, 1)
+        };
+        let header = ((chunk.len() as u32) << 1) | is_original;
+        out.extend_from_slice(&header.to_le_bytes()[..3]);
+        out.extend_from_slice(chunk);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_roundtrip() {
+        let data = (0..100_000u32)
+            .flat_map(|i| (i % 1000).to_le_bytes())
+            .collect::<Vec<_>>();
+
+        for compression in [
+            OrcCompression::Uncompressed,
+            OrcCompression::Zlib,
+            OrcCompression::Snappy,
+            OrcCompression::Lz4,
+            OrcCompression::Zstd,
+        ] {
+            let mut out = vec![];
+            compress(compression, 1 << 16, &data, &mut out).unwrap();
+            assert_eq!(decompress(compression, 1 << 16, &out).unwrap(), data.as_slice());
+        }
+
+        // A chunk that does not compress is stored as is, with the `isOriginal` bit set.
+        let mut out = vec![];
+        compress(OrcCompression::Zlib, 1 << 16, b"orc", &mut out).unwrap();
+        assert_eq!(out, [0x07, 0x00, 0x00, b'o', b'r', b'c']);
+    }
+}
@@ -0,0 +1,417 @@
+use std::borrow::Cow;
+
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, MutableBinaryViewArray, PrimitiveArray,
+    StructArray,
+};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_utils::aliases::PlHashMap;
+
+use super::compression::{OrcCompression, decompress};
+use super::proto::{
+    ColumnEncoding, StripeFooter, StripeInformation, Type, encoding_kind, stream_kind, type_kind,
+};
+use super::rle::{decode_bools, decode_bytes, decode_ints};
+
+/// Seconds between the unix epoch and 2015-01-01, the epoch of ORC timestamps.
+pub(super) const ORC_EPOCH: i64 = 1_420_070_400;
+
+/// Decodes the columns of a single stripe.
+pub(super) struct StripeDecoder<'a> {
+    types: &'a [Type],
+    compression: OrcCompression,
+    block_size: usize,
+    streams: PlHashMap<(u32, u32), &'a [u8]>,
+    encodings: Vec<ColumnEncoding>,
+}
+
+impl<'a> StripeDecoder<'a> {
+    /// Locates the streams of the columns with a type id in `columns`. `bytes` is the whole file.
+    pub(super) fn try_new(
+        bytes: &'a [u8],
+        stripe: &StripeInformation,
+        types: &'a [Type],
+        compression: OrcCompression,
+        block_size: usize,
+        columns: &[std::ops::Range<u32>],
+    ) -> PolarsResult<Self> {
+        let slice = |offset: u64, len: u64| {
+            let (offset, len) = (offset as usize, len as usize);
+            bytes.get(offset..offset + len).ok_or_else(
+                || polars_err!(ComputeError: "invalid ORC file: stripe is out of bounds"),
+            )
+        };
+
+        let footer_offset = stripe.offset + stripe.index_length + stripe.data_length;
+        let footer = slice(footer_offset, stripe.footer_length)?;
+        let footer = StripeFooter::decode(&decompress(compression, block_size, footer)?)?;
+
+        let mut streams = PlHashMap::default();
+        let mut offset = stripe.offset;
+        for stream in &footer.streams {
+            if columns.iter().any(|c| c.contains(&stream.column)) {
+                streams.insert((stream.column, stream.kind), slice(offset, stream.length)?);
+            }
+            offset += stream.length;
+        }
+
+        Ok(Self {
+            types,
+            compression,
+            block_size,
+            streams,
+            encodings: footer.columns,
+        })
+    }
+
+    /// Returns the decompressed stream. Writers leave out streams without data, which are returned
+    /// as empty streams.
+    fn stream(&self, id: u32, kind: u32) -> PolarsResult<Cow<'a, [u8]>> {
+        match self.streams.get(&(id, kind)) {
+            Some(data) => decompress(self.compression, self.block_size, data),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    }
+
+    fn encoding(&self, id: u32) -> u32 {
+        self.encodings
+            .get(id as usize)
+            .map_or(encoding_kind::DIRECT, |e| e.kind)
+    }
+
+    fn ints(&self, id: u32, kind: u32, n: usize, signed: bool) -> PolarsResult<Vec<i64>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let v2 = matches!(
+            self.encoding(id),
+            encoding_kind::DIRECT_V2 | encoding_kind::DICTIONARY_V2
+        );
+        decode_ints(&self.stream(id, kind)?, n, signed, v2)
+    }
+
+    /// Combines the validity of the parent with the present stream of the column, which only has
+    /// entries for the rows where the parent is valid.
+    fn validity(&self, id: u32, n: usize, parent: Option<&Bitmap>) -> PolarsResult<Option<Bitmap>> {
+        let present = if self.streams.contains_key(&(id, stream_kind::PRESENT)) {
+            let entries = parent.map_or(n, |p| n - p.unset_bits());
+            Some(decode_bools(&self.stream(id, stream_kind::PRESENT)?, entries)?)
+        } else {
+            None
+        };
+
+        Ok(match (parent, present) {
+            (None, present) => present,
+            (Some(parent), None) => Some(parent.clone()),
+            (Some(parent), Some(present)) => {
+                let mut present = present.iter();
+                Some(
+                    parent
+                        .iter()
+                        .map(|p| p && present.next().unwrap_or(false))
+                        .collect(),
+                )
+            },
+        })
+    }
+
+    /// Decodes `n` rows of the column with type id `id`. `parent` is the validity of the parent
+    /// struct, as the streams of a column only have entries for the rows where its parent is valid.
+    pub(super) fn decode(
+        &self,
+        id: u32,
+        dtype: &ArrowDataType,
+        n: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let validity = self.validity(id, n, parent)?;
+        let dense = validity.as_ref().map_or(n, |v| n - v.unset_bits());
+        let kind = self.types[id as usize].kind;
+        let data = || self.stream(id, stream_kind::DATA);
+
+        let array: Box<dyn Array> = match kind {
+            type_kind::BOOLEAN => {
+                let values = decode_bools(&data()?, dense)?;
+                let values = scatter(values.iter().collect(), validity.as_ref());
+                Box::new(BooleanArray::new(
+                    dtype.clone(),
+                    values.into_iter().collect(),
+                    validity,
+                ))
+            },
+            type_kind::BYTE => {
+                let values = decode_bytes(&data()?, dense)?;
+                primitive(dtype, values.into_iter().map(|v| v as i8), validity)
+            },
+            type_kind::SHORT => {
+                let values = self.ints(id, stream_kind::DATA, dense, true)?;
+                primitive(dtype, values.into_iter().map(|v| v as i16), validity)
+            },
+            type_kind::INT => {
+                let values = self.ints(id, stream_kind::DATA, dense, true)?;
+                primitive(dtype, values.into_iter().map(|v| v as i32), validity)
+            },
+            type_kind::LONG => {
+                let values = self.ints(id, stream_kind::DATA, dense, true)?;
+                primitive(dtype, values, validity)
+            },
+            type_kind::DATE => {
+                let values = self.ints(id, stream_kind::DATA, dense, true)?;
+                primitive(dtype, values.into_iter().map(|v| v as i32), validity)
+            },
+            type_kind::FLOAT => {
+                let data = data()?;
+                let values = fixed_width::<4>(&data, dense)?.map(f32::from_le_bytes);
+                primitive(dtype, values, validity)
+            },
+            type_kind::DOUBLE => {
+                let data = data()?;
+                let values = fixed_width::<8>(&data, dense)?.map(f64::from_le_bytes);
+                primitive(dtype, values, validity)
+            },
+            type_kind::STRING | type_kind::VARCHAR | type_kind::CHAR | type_kind::BINARY => {
+                let binary = self.decode_binary(id, dense, validity.as_ref())?;
+                if kind == type_kind::BINARY {
+                    Box::new(binary)
+                } else {
+                    Box::new(binary.to_utf8view()?)
+                }
+            },
+            type_kind::TIMESTAMP | type_kind::TIMESTAMP_INSTANT => {
+                let seconds = self.ints(id, stream_kind::DATA, dense, true)?;
+                let nanos = self.ints(id, stream_kind::SECONDARY, dense, false)?;
+                let values = seconds.into_iter().zip(nanos).map(|(seconds, nanos)| {
+                    let nanos = decode_nanos(nanos as u64);
+                    let mut seconds = seconds + ORC_EPOCH;
+                    // Writers store negative timestamps with the seconds rounded towards zero.
+                    if seconds < 0 && nanos > 999_999 {
+                        seconds -= 1;
+                    }
+                    seconds * 1_000_000_000 + nanos
+                });
+                primitive(dtype, values, validity)
+            },
+            type_kind::DECIMAL => {
+                let ArrowDataType::Decimal(_, target_scale) = dtype else {
+                    unreachable!()
+                };
+                let data = data()?;
+                let mut data = data.as_ref();
+                let scales = self.ints(id, stream_kind::SECONDARY, dense, true)?;
+                let values = scales
+                    .into_iter()
+                    .map(|scale| {
+                        let value = read_decimal(&mut data)?;
+                        rescale(value, scale, *target_scale as i64)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                primitive(dtype, values, validity)
+            },
+            type_kind::LIST | type_kind::MAP => {
+                let ArrowDataType::LargeList(field) = dtype else {
+                    unreachable!()
+                };
+                let lengths = self.ints(id, stream_kind::LENGTH, dense, false)?;
+                let lengths = scatter(lengths, validity.as_ref());
+                let offsets = Offsets::<i64>::try_from_lengths(lengths.iter().map(|&l| l as usize))?;
+                let child_len = *offsets.last() as usize;
+
+                let subtypes = &self.types[id as usize].subtypes;
+                let values = if kind == type_kind::LIST {
+                    self.decode(subtypes[0], &field.dtype, child_len, None)?
+                } else {
+                    let ArrowDataType::Struct(entries) = &field.dtype else {
+                        unreachable!()
+                    };
+                    let key = self.decode(subtypes[0], &entries[0].dtype, child_len, None)?;
+                    let value = self.decode(subtypes[1], &entries[1].dtype, child_len, None)?;
+                    Box::new(StructArray::new(
+                        field.dtype.clone(),
+                        child_len,
+                        vec![key, value],
+                        None,
+                    ))
+                };
+                Box::new(ListArray::<i64>::new(
+                    dtype.clone(),
+                    offsets.into(),
+                    values,
+                    validity,
+                ))
+            },
+            type_kind::STRUCT => {
+                let ArrowDataType::Struct(fields) = dtype else {
+                    unreachable!()
+                };
+                let values = self.types[id as usize]
+                    .subtypes
+                    .iter()
+                    .zip(fields)
+                    .map(|(&child, field)| self.decode(child, &field.dtype, n, validity.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Box::new(StructArray::new(dtype.clone(), n, values, validity))
+            },
+            kind => polars_bail!(ComputeError: "cannot read ORC type kind {}", kind),
+        };
+        Ok(array)
+    }
+
+    fn decode_binary(
+        &self,
+        id: u32,
+        dense: usize,
+        validity: Option<&Bitmap>,
+    ) -> PolarsResult<BinaryViewArray> {
+        let data;
+        let values: Vec<&[u8]> = match self.encoding(id) {
+            encoding_kind::DICTIONARY | encoding_kind::DICTIONARY_V2 => {
+                let dictionary_size = self.encodings[id as usize].dictionary_size.unwrap_or(0);
+                let lengths =
+                    self.ints(id, stream_kind::LENGTH, dictionary_size as usize, false)?;
+                data = self.stream(id, stream_kind::DICTIONARY_DATA)?;
+                let dictionary = split_values(&data, &lengths)?;
+                self.ints(id, stream_kind::DATA, dense, false)?
+                    .into_iter()
+                    .map(|i| {
+                        dictionary.get(i as usize).copied().ok_or_else(
+                            || polars_err!(ComputeError: "invalid ORC dictionary index {}", i),
+                        )
+                    })
+                    .collect::<PolarsResult<_>>()?
+            },
+            _ => {
+                let lengths = self.ints(id, stream_kind::LENGTH, dense, false)?;
+                data = self.stream(id, stream_kind::DATA)?;
+                split_values(&data, &lengths)?
+            },
+        };
+
+        let mut array = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+        match validity {
+            None => values.into_iter().for_each(|v| array.push_value(v)),
+            Some(validity) => {
+                let mut values = values.into_iter();
+                for valid in validity.iter() {
+                    if valid {
+                        array.push_value(values.next().unwrap_or_default());
+                    } else {
+                        array.push_null();
+                    }
+                }
+            },
+        }
+        Ok(array.into())
+    }
+}
+
+/// Spreads the values of the valid rows over all rows, with default values for the null rows.
+fn scatter<T: Default>(values: Vec<T>, validity: Option<&Bitmap>) -> Vec<T> {
+    match validity {
+        None => values,
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|valid| {
+                    if valid {
+                        values.next().unwrap_or_default()
+                    } else {
+                        T::default()
+                    }
+                })
+                .collect()
+        },
+    }
+}
+
+fn primitive<T: NativeType>(
+    dtype: &ArrowDataType,
+    values: impl IntoIterator<Item = T>,
+    validity: Option<Bitmap>,
+) -> Box<dyn Array> {
+    let values = scatter(values.into_iter().collect(), validity.as_ref());
+    Box::new(PrimitiveArray::new(dtype.clone(), values.into(), validity))
+}
+
+fn fixed_width<const N: usize>(
+    data: &[u8],
+    n: usize,
+) -> PolarsResult<impl Iterator<Item = [u8; N]> + '_> {
+    polars_ensure!(data.len() >= n * N, ComputeError: "unexpected end of ORC stream");
+    Ok(data[..n * N]
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().unwrap()))
+}
+
+fn split_values<'b>(mut data: &'b [u8], lengths: &[i64]) -> PolarsResult<Vec<&'b [u8]>> {
+    lengths
+        .iter()
+        .map(|&len| {
+            let len = len as usize;
+            polars_ensure!(len <= data.len(), ComputeError: "unexpected end of ORC stream");
+            let (value, rest) = data.split_at(len);
+            data = rest;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// The nanoseconds of timestamps are stored with the number of trailing decimal zeros, minus one,
+/// in the lowest three bits.
+fn decode_nanos(value: u64) -> i64 {
+    let zeros = (value & 0x7) as u32;
+    let nanos = (value >> 3) as i64;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros + 1)
+    }
+}
+
+pub(super) fn encode_nanos(nanos: i64) -> i64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return nanos << 3;
+    }
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    (nanos << 3) | zeros
+}
+
+/// Reads an unbounded zigzag-encoded varint.
+fn read_decimal(data: &mut &[u8]) -> PolarsResult<i128> {
+    let mut value = 0u128;
+    for shift in (0..128).step_by(7) {
+        let Some((&byte, rest)) = data.split_first() else {
+            polars_bail!(ComputeError: "unexpected end of ORC decimal stream");
+        };
+        *data = rest;
+        value |= ((byte & 0x7f) as u128) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(((value >> 1) as i128) ^ -((value & 1) as i128));
+        }
+    }
+    polars_bail!(ComputeError: "ORC decimal does not fit into 128 bits")
+}
+
+fn rescale(value: i128, scale: i64, target_scale: i64) -> PolarsResult<i128> {
+    let diff = target_scale - scale;
+    let factor = 10i128
+        .checked_pow(diff.unsigned_abs() as u32)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC decimal scale {}", scale))?;
+    if diff >= 0 {
+        value
+            .checked_mul(factor)
+            .ok_or_else(|| polars_err!(ComputeError: "ORC decimal does not fit into 128 bits"))
+    } else {
+        Ok(value / factor)
+    }
+}
+
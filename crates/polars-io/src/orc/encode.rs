@@ -0,0 +1,428 @@
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::{ArrowDataType, TimeUnit};
+use polars_core::prelude::*;
+
+use super::compression::{OrcCompression, compress};
+use super::decode::{ORC_EPOCH, encode_nanos};
+use super::proto::{
+    ColumnEncoding, ColumnStatistics, MinMax, Stream, StripeFooter, Type, encoding_kind,
+    stream_kind,
+};
+use super::rle::{encode_bools, encode_bytes, encode_ints};
+
+/// String statistics are left out for values longer than this, like the ORC Java writer does.
+const MAX_STRING_STATISTICS_LENGTH: usize = 1024;
+
+/// A stripe that was encoded into its streams and its footer.
+pub(super) struct EncodedStripe {
+    pub bytes: Vec<u8>,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub statistics: Vec<ColumnStatistics>,
+}
+
+pub(super) struct StripeEncoder<'a> {
+    types: &'a [Type],
+    compression: OrcCompression,
+    block_size: usize,
+    bytes: Vec<u8>,
+    streams: Vec<Stream>,
+    encodings: Vec<ColumnEncoding>,
+    statistics: Vec<ColumnStatistics>,
+}
+
+impl<'a> StripeEncoder<'a> {
+    pub(super) fn new(types: &'a [Type], compression: OrcCompression, block_size: usize) -> Self {
+        Self {
+            types,
+            compression,
+            block_size,
+            bytes: vec![],
+            streams: vec![],
+            encodings: vec![
+                ColumnEncoding {
+                    kind: encoding_kind::DIRECT,
+                    dictionary_size: None,
+                };
+                types.len()
+            ],
+            statistics: vec![ColumnStatistics::default(); types.len()],
+        }
+    }
+
+    /// Encodes the top-level columns of a stripe.
+    pub(super) fn encode(
+        mut self,
+        arrays: &[Box<dyn Array>],
+        num_rows: usize,
+    ) -> PolarsResult<EncodedStripe> {
+        self.statistics[0] = ColumnStatistics {
+            number_of_values: Some(num_rows as u64),
+            has_null: Some(false),
+            ..Default::default()
+        };
+        for (array, &id) in arrays.iter().zip(&self.types[0].subtypes) {
+            self.encode_column(id, array.as_ref(), None)?;
+        }
+
+        let data_length = self.bytes.len() as u64;
+        let footer = StripeFooter {
+            streams: self.streams,
+            columns: self.encodings,
+        };
+        compress(
+            self.compression,
+            self.block_size,
+            &footer.encode(),
+            &mut self.bytes,
+        )?;
+
+        Ok(EncodedStripe {
+            footer_length: self.bytes.len() as u64 - data_length,
+            data_length,
+            bytes: self.bytes,
+            statistics: self.statistics,
+        })
+    }
+
+    fn push_stream(&mut self, id: u32, kind: u32, data: &[u8]) -> PolarsResult<()> {
+        let start = self.bytes.len();
+        compress(self.compression, self.block_size, data, &mut self.bytes)?;
+        self.streams.push(Stream {
+            kind,
+            column: id,
+            length: (self.bytes.len() - start) as u64,
+        });
+        Ok(())
+    }
+
+    fn push_ints(&mut self, id: u32, kind: u32, values: &[i64], signed: bool) -> PolarsResult<()> {
+        let mut data = vec![];
+        encode_ints(values, signed, &mut data);
+        self.push_stream(id, kind, &data)
+    }
+
+    /// Encodes a column. `mask` marks the rows that have an entry in the column, which are the
+    /// rows where the parent struct is valid.
+    fn encode_column(
+        &mut self,
+        id: u32,
+        array: &dyn Array,
+        mask: Option<&Bitmap>,
+    ) -> PolarsResult<()> {
+        let entries = (0..array.len())
+            .filter(|&i| mask.is_none_or(|m| m.get_bit(i)))
+            .collect::<Vec<_>>();
+        let rows = entries
+            .iter()
+            .copied()
+            .filter(|&i| array.is_valid(i))
+            .collect::<Vec<_>>();
+        let has_null = rows.len() < entries.len();
+        if has_null {
+            let mut data = vec![];
+            encode_bools(entries.iter().map(|&i| array.is_valid(i)), &mut data);
+            self.push_stream(id, stream_kind::PRESENT, &data)?;
+        }
+
+        let mut stats = ColumnStatistics {
+            number_of_values: Some(rows.len() as u64),
+            has_null: Some(has_null),
+            ..Default::default()
+        };
+        let mut encoding = encoding_kind::DIRECT_V2;
+
+        match array.dtype() {
+            ArrowDataType::Null => {},
+            ArrowDataType::Boolean => {
+                let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                let values = array.values();
+                let mut data = vec![];
+                encode_bools(rows.iter().map(|&i| values.get_bit(i)), &mut data);
+                self.push_stream(id, stream_kind::DATA, &data)?;
+                stats.true_count = Some(rows.iter().filter(|&&i| values.get_bit(i)).count() as u64);
+                encoding = encoding_kind::DIRECT;
+            },
+            ArrowDataType::Int8 => {
+                let values = primitive_values::<i8>(array, &rows);
+                let mut data = vec![];
+                encode_bytes(&values.iter().map(|&v| v as u8).collect::<Vec<_>>(), &mut data);
+                self.push_stream(id, stream_kind::DATA, &data)?;
+                stats.int = min_max(values.iter().map(|&v| v as i64));
+                encoding = encoding_kind::DIRECT;
+            },
+            ArrowDataType::Int16 => {
+                let values = primitive_values::<i16>(array, &rows);
+                let values = values.into_iter().map(|v| v as i64).collect::<Vec<_>>();
+                self.push_ints(id, stream_kind::DATA, &values, true)?;
+                stats.int = min_max(values.iter().copied());
+            },
+            ArrowDataType::Int32 => {
+                let values = primitive_values::<i32>(array, &rows);
+                let values = values.into_iter().map(|v| v as i64).collect::<Vec<_>>();
+                self.push_ints(id, stream_kind::DATA, &values, true)?;
+                stats.int = min_max(values.iter().copied());
+            },
+            ArrowDataType::Int64 => {
+                let values = primitive_values::<i64>(array, &rows);
+                self.push_ints(id, stream_kind::DATA, &values, true)?;
+                stats.int = min_max(values.iter().copied());
+            },
+            ArrowDataType::Date32 => {
+                let values = primitive_values::<i32>(array, &rows);
+                let values = values.into_iter().map(|v| v as i64).collect::<Vec<_>>();
+                self.push_ints(id, stream_kind::DATA, &values, true)?;
+                stats.date = min_max(values.iter().map(|&v| v as i32));
+            },
+            ArrowDataType::Float32 => {
+                let values = primitive_values::<f32>(array, &rows);
+                let data = values.iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>();
+                self.push_stream(id, stream_kind::DATA, &data)?;
+                stats.double = min_max_float(values.iter().map(|&v| v as f64));
+                encoding = encoding_kind::DIRECT;
+            },
+            ArrowDataType::Float64 => {
+                let values = primitive_values::<f64>(array, &rows);
+                let data = values.iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>();
+                self.push_stream(id, stream_kind::DATA, &data)?;
+                stats.double = min_max_float(values.iter().copied());
+                encoding = encoding_kind::DIRECT;
+            },
+            ArrowDataType::Utf8View => {
+                let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+                let values = rows
+                    .iter()
+                    .map(|&i| unsafe { array.value_unchecked(i) })
+                    .collect::<Vec<_>>();
+                self.push_binary(id, values.iter().map(|v| v.as_bytes()))?;
+                if !values.is_empty() {
+                    stats.string = Some(
+                        if values.iter().any(|v| v.len() > MAX_STRING_STATISTICS_LENGTH) {
+                            MinMax::default()
+                        } else {
+                            MinMax {
+                                min: values.iter().min().map(|v| v.to_string()),
+                                max: values.iter().max().map(|v| v.to_string()),
+                            }
+                        },
+                    );
+                }
+            },
+            ArrowDataType::BinaryView => {
+                let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+                let values = rows.iter().map(|&i| unsafe { array.value_unchecked(i) });
+                self.push_binary(id, values)?;
+            },
+            ArrowDataType::Timestamp(unit, _) => {
+                let factor = match unit {
+                    TimeUnit::Second => 1_000_000_000,
+                    TimeUnit::Millisecond => 1_000_000,
+                    TimeUnit::Microsecond => 1_000,
+                    TimeUnit::Nanosecond => 1,
+                };
+                let (seconds, nanos): (Vec<_>, Vec<_>) = primitive_values::<i64>(array, &rows)
+                    .into_iter()
+                    .map(|v| {
+                        let v = v.wrapping_mul(factor);
+                        let mut seconds = v.div_euclid(1_000_000_000);
+                        let nanos = v.rem_euclid(1_000_000_000);
+                        // Readers expect negative timestamps with the seconds rounded towards
+                        // zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds += 1;
+                        }
+                        (seconds - ORC_EPOCH, encode_nanos(nanos))
+                    })
+                    .unzip();
+                self.push_ints(id, stream_kind::DATA, &seconds, true)?;
+                self.push_ints(id, stream_kind::SECONDARY, &nanos, false)?;
+            },
+            ArrowDataType::Decimal(_, scale) => {
+                let values = primitive_values::<i128>(array, &rows);
+                let mut data = vec![];
+                for v in values {
+                    write_decimal(v, &mut data);
+                }
+                self.push_stream(id, stream_kind::DATA, &data)?;
+                self.push_ints(
+                    id,
+                    stream_kind::SECONDARY,
+                    &vec![*scale as i64; rows.len()],
+                    true,
+                )?;
+            },
+            ArrowDataType::LargeList(_) => {
+                let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let offsets = array.offsets();
+                let lengths = rows
+                    .iter()
+                    .map(|&i| offsets.length_at(i) as i64)
+                    .collect::<Vec<_>>();
+                self.push_ints(id, stream_kind::LENGTH, &lengths, false)?;
+
+                let values = array.values();
+                let mut child_mask = MutableBitmap::from_len_zeroed(values.len());
+                for &i in &rows {
+                    let (start, end) = offsets.start_end(i);
+                    for j in start..end {
+                        child_mask.set(j, true);
+                    }
+                }
+                let child_mask: Bitmap = child_mask.into();
+                let child_mask = (child_mask.unset_bits() > 0).then_some(child_mask);
+                let child = self.types[id as usize].subtypes[0];
+                self.encode_column(child, values.as_ref(), child_mask.as_ref())?;
+            },
+            ArrowDataType::Struct(_) => {
+                let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+                let child_mask = has_null
+                    .then(|| {
+                        let mut child_mask = MutableBitmap::from_len_zeroed(array.len());
+                        rows.iter().for_each(|&i| child_mask.set(i, true));
+                        child_mask.into()
+                    })
+                    .or_else(|| mask.cloned());
+                let subtypes = self.types[id as usize].subtypes.clone();
+                for (child, values) in subtypes.into_iter().zip(array.values()) {
+                    self.encode_column(child, values.as_ref(), child_mask.as_ref())?;
+                }
+                encoding = encoding_kind::DIRECT;
+            },
+            dtype => polars_bail!(InvalidOperation: "cannot write arrow data type {:?} to ORC", dtype),
+        }
+
+        self.statistics[id as usize] = stats;
+        self.encodings[id as usize].kind = encoding;
+        Ok(())
+    }
+
+    fn push_binary<'b>(
+        &mut self,
+        id: u32,
+        values: impl Iterator<Item = &'b [u8]>,
+    ) -> PolarsResult<()> {
+        let mut data = vec![];
+        let mut lengths = vec![];
+        for v in values {
+            data.extend_from_slice(v);
+            lengths.push(v.len() as i64);
+        }
+        self.push_stream(id, stream_kind::DATA, &data)?;
+        self.push_ints(id, stream_kind::LENGTH, &lengths, false)
+    }
+}
+
+fn primitive_values<T: arrow::types::NativeType>(array: &dyn Array, rows: &[usize]) -> Vec<T> {
+    let values = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .values();
+    rows.iter().map(|&i| values[i]).collect()
+}
+
+fn min_max<T: Ord + Copy>(values: impl Iterator<Item = T> + Clone) -> Option<MinMax<T>> {
+    let min = values.clone().min()?;
+    Some(MinMax {
+        min: Some(min),
+        max: values.max(),
+    })
+}
+
+/// The bounds are left out when there are NaNs, as NaN compares greater than all other values in
+/// predicates.
+fn min_max_float(values: impl Iterator<Item = f64> + Clone) -> Option<MinMax<f64>> {
+    if values.clone().any(|v| v.is_nan()) {
+        return Some(MinMax::default());
+    }
+    let min = values.clone().reduce(f64::min)?;
+    Some(MinMax {
+        min: Some(min),
+        max: values.reduce(f64::max),
+    })
+}
+
+/// Writes an unbounded zigzag-encoded varint.
+fn write_decimal(value: i128, out: &mut Vec<u8>) {
+    let mut value = ((value << 1) ^ (value >> 127)) as u128;
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Merges the statistics of a stripe into the statistics of the file.
+pub(super) fn merge_statistics(into: &mut ColumnStatistics, other: &ColumnStatistics) {
+    fn merge<T: PartialOrd + Clone>(into: &mut Option<MinMax<T>>, other: &Option<MinMax<T>>) {
+        let Some(other) = other else {
+            return;
+        };
+        let Some(into) = into else {
+            *into = Some(other.clone());
+            return;
+        };
+        // A missing bound is unknown, which stays unknown.
+        into.min = match (into.min.take(), &other.min) {
+            (Some(a), Some(b)) => Some(if b < &a { b.clone() } else { a }),
+            _ => None,
+        };
+        into.max = match (into.max.take(), &other.max) {
+            (Some(a), Some(b)) => Some(if b > &a { b.clone() } else { a }),
+            _ => None,
+        };
+    }
+
+    let sum = |a: Option<u64>, b: Option<u64>| Some(a.unwrap_or(0) + b.unwrap_or(0));
+    into.number_of_values = sum(into.number_of_values, other.number_of_values);
+    into.has_null = Some(into.has_null.unwrap_or(false) || other.has_null.unwrap_or(false));
+    if other.true_count.is_some() {
+        into.true_count = sum(into.true_count, other.true_count);
+    }
+    merge(&mut into.int, &other.int);
+    merge(&mut into.double, &other.double);
+    merge(&mut into.string, &other.string);
+    merge(&mut into.date, &other.date);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_statistics() {
+        let stats = |min: i64, max: i64| ColumnStatistics {
+            number_of_values: Some(2),
+            int: Some(MinMax {
+                min: Some(min),
+                max: Some(max),
+            }),
+            has_null: Some(false),
+            ..Default::default()
+        };
+
+        let mut file = ColumnStatistics::default();
+        merge_statistics(&mut file, &stats(3, 5));
+        merge_statistics(&mut file, &stats(-1, 4));
+        merge_statistics(
+            &mut file,
+            &ColumnStatistics {
+                number_of_values: Some(0),
+                has_null: Some(true),
+                ..Default::default()
+            },
+        );
+        assert_eq!(file.number_of_values, Some(4));
+        assert_eq!(file.has_null, Some(true));
+        assert_eq!(
+            file.int,
+            Some(MinMax {
+                min: Some(-1),
+                max: Some(5)
+            })
+        );
+    }
+}
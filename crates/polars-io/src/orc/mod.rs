@@ -0,0 +1,16 @@
+//! Reading and writing of [Apache ORC](https://orc.apache.org) files.
+//!
+//! The file tail and the stripe footers are protobuf messages, which are decoded by hand as only a
+//! few of their fields are needed. The writer always uses the version 2 run length encodings and
+//! does not write row indexes.
+mod compression;
+mod decode;
+mod encode;
+mod proto;
+mod read;
+mod rle;
+mod schema;
+mod write;
+
+pub use read::*;
+pub use write::*;
@@ -0,0 +1,721 @@
+//! Hand-written protobuf (de)serialization of the messages of the ORC file tail and the stripe
+//! footers, see `orc_proto.proto` of the ORC specification. Fields that are not needed for
+//! reading are skipped.
+
+use polars_core::prelude::*;
+
+pub(super) const MAGIC: &[u8] = b"ORC";
+
+pub(super) mod type_kind {
+    pub const BOOLEAN: u32 = 0;
+    pub const BYTE: u32 = 1;
+    pub const SHORT: u32 = 2;
+    pub const INT: u32 = 3;
+    pub const LONG: u32 = 4;
+    pub const FLOAT: u32 = 5;
+    pub const DOUBLE: u32 = 6;
+    pub const STRING: u32 = 7;
+    pub const BINARY: u32 = 8;
+    pub const TIMESTAMP: u32 = 9;
+    pub const LIST: u32 = 10;
+    pub const MAP: u32 = 11;
+    pub const STRUCT: u32 = 12;
+    pub const UNION: u32 = 13;
+    pub const DECIMAL: u32 = 14;
+    pub const DATE: u32 = 15;
+    pub const VARCHAR: u32 = 16;
+    pub const CHAR: u32 = 17;
+    pub const TIMESTAMP_INSTANT: u32 = 18;
+}
+
+pub(super) mod stream_kind {
+    pub const PRESENT: u32 = 0;
+    pub const DATA: u32 = 1;
+    pub const LENGTH: u32 = 2;
+    pub const DICTIONARY_DATA: u32 = 3;
+    pub const SECONDARY: u32 = 5;
+}
+
+pub(super) mod encoding_kind {
+    pub const DIRECT: u32 = 0;
+    pub const DICTIONARY: u32 = 1;
+    pub const DIRECT_V2: u32 = 2;
+    pub const DICTIONARY_V2: u32 = 3;
+}
+
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl WireValue<'_> {
+    fn as_u64(&self) -> PolarsResult<u64> {
+        match self {
+            Self::Varint(v) | Self::Fixed64(v) => Ok(*v),
+            Self::Fixed32(v) => Ok(*v as u64),
+            Self::Bytes(_) => polars_bail!(ComputeError: "invalid ORC metadata: expected an integer"),
+        }
+    }
+
+    fn as_u32(&self) -> PolarsResult<u32> {
+        self.as_u64().map(|v| v as u32)
+    }
+
+    fn as_sint(&self) -> PolarsResult<i64> {
+        self.as_u64().map(zigzag_decode)
+    }
+
+    fn as_f64(&self) -> PolarsResult<f64> {
+        match self {
+            Self::Fixed64(v) => Ok(f64::from_bits(*v)),
+            _ => polars_bail!(ComputeError: "invalid ORC metadata: expected a double"),
+        }
+    }
+
+    fn as_bytes(&self) -> PolarsResult<&[u8]> {
+        match self {
+            Self::Bytes(b) => Ok(b),
+            _ => polars_bail!(ComputeError: "invalid ORC metadata: expected a length-delimited field"),
+        }
+    }
+
+    fn as_string(&self) -> PolarsResult<String> {
+        Ok(String::from_utf8_lossy(self.as_bytes()?).into_owned())
+    }
+
+    /// Appends a repeated integer field, which can be either packed or unpacked.
+    fn extend_u64(&self, out: &mut Vec<u64>) -> PolarsResult<()> {
+        match self {
+            Self::Bytes(bytes) => {
+                let mut bytes = *bytes;
+                while !bytes.is_empty() {
+                    out.push(read_varint(&mut bytes)?);
+                }
+            },
+            v => out.push(v.as_u64()?),
+        }
+        Ok(())
+    }
+
+    fn extend_u32(&self, out: &mut Vec<u32>) -> PolarsResult<()> {
+        let mut values = vec![];
+        self.extend_u64(&mut values)?;
+        out.extend(values.into_iter().map(|v| v as u32));
+        Ok(())
+    }
+}
+
+pub(super) fn read_varint(bytes: &mut &[u8]) -> PolarsResult<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let Some((&byte, rest)) = bytes.split_first() else {
+            polars_bail!(ComputeError: "unexpected end of ORC varint");
+        };
+        *bytes = rest;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    polars_bail!(ComputeError: "invalid ORC varint")
+}
+
+pub(super) fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub(super) fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+pub(super) fn zigzag_encode(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+/// Iterates over the fields of a message.
+struct ProtoReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn next_field(&mut self) -> PolarsResult<Option<(u32, WireValue<'a>)>> {
+        if self.bytes.is_empty() {
+            return Ok(None);
+        }
+        let key = read_varint(&mut self.bytes)?;
+        let value = match key & 0x7 {
+            0 => WireValue::Varint(read_varint(&mut self.bytes)?),
+            1 => WireValue::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            2 => {
+                let len = read_varint(&mut self.bytes)? as usize;
+                WireValue::Bytes(self.take(len)?)
+            },
+            5 => WireValue::Fixed32(u32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            wire_type => {
+                polars_bail!(ComputeError: "invalid ORC metadata: unsupported wire type {}", wire_type)
+            },
+        };
+        Ok(Some(((key >> 3) as u32, value)))
+    }
+
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.bytes.len(),
+            ComputeError: "unexpected end of ORC metadata"
+        );
+        let (value, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(value)
+    }
+}
+
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn key(&mut self, field: u32, wire_type: u32) {
+        write_varint(((field << 3) | wire_type) as u64, &mut self.buf);
+    }
+
+    fn uint(&mut self, field: u32, value: u64) {
+        self.key(field, 0);
+        write_varint(value, &mut self.buf);
+    }
+
+    fn sint(&mut self, field: u32, value: i64) {
+        self.uint(field, zigzag_encode(value));
+    }
+
+    fn double(&mut self, field: u32, value: f64) {
+        self.key(field, 1);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, value: &[u8]) {
+        self.key(field, 2);
+        write_varint(value.len() as u64, &mut self.buf);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn packed(&mut self, field: u32, values: impl IntoIterator<Item = u64>) {
+        let mut packed = vec![];
+        for v in values {
+            write_varint(v, &mut packed);
+        }
+        self.bytes(field, &packed);
+    }
+
+    fn message(&mut self, field: u32, encode: impl FnOnce(&mut ProtoWriter)) {
+        let mut writer = ProtoWriter::default();
+        encode(&mut writer);
+        self.bytes(field, &writer.buf);
+    }
+}
+
+macro_rules! decode_fields {
+    ($bytes:expr, |$field:ident, $value:ident| $body:block) => {{
+        let mut reader = ProtoReader::new($bytes);
+        while let Some(($field, $value)) = reader.next_field()? {
+            $body
+        }
+    }};
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: u32,
+    pub compression_block_size: Option<u64>,
+    pub version: Vec<u32>,
+    pub metadata_length: u64,
+    pub writer_version: Option<u32>,
+}
+
+impl PostScript {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut ps = Self::default();
+        let mut magic = None;
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => ps.footer_length = value.as_u64()?,
+                2 => ps.compression = value.as_u32()?,
+                3 => ps.compression_block_size = Some(value.as_u64()?),
+                4 => value.extend_u32(&mut ps.version)?,
+                5 => ps.metadata_length = value.as_u64()?,
+                6 => ps.writer_version = Some(value.as_u32()?),
+                8000 => magic = Some(value.as_bytes()?.to_vec()),
+                _ => {},
+            }
+        });
+        polars_ensure!(
+            magic.is_none_or(|m| m == MAGIC),
+            ComputeError: "invalid ORC file: wrong magic bytes in the postscript"
+        );
+        Ok(ps)
+    }
+
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut w = ProtoWriter::default();
+        w.uint(1, self.footer_length);
+        w.uint(2, self.compression as u64);
+        if let Some(block_size) = self.compression_block_size {
+            w.uint(3, block_size);
+        }
+        w.packed(4, self.version.iter().map(|&v| v as u64));
+        w.uint(5, self.metadata_length);
+        if let Some(writer_version) = self.writer_version {
+            w.uint(6, writer_version as u64);
+        }
+        w.bytes(8000, MAGIC);
+        w.buf
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+    pub row_index_stride: u32,
+}
+
+impl Footer {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut footer = Self::default();
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => footer.header_length = value.as_u64()?,
+                2 => footer.content_length = value.as_u64()?,
+                3 => footer
+                    .stripes
+                    .push(StripeInformation::decode(value.as_bytes()?)?),
+                4 => footer.types.push(Type::decode(value.as_bytes()?)?),
+                6 => footer.number_of_rows = value.as_u64()?,
+                7 => footer
+                    .statistics
+                    .push(ColumnStatistics::decode(value.as_bytes()?)?),
+                8 => footer.row_index_stride = value.as_u32()?,
+                _ => {},
+            }
+        });
+        Ok(footer)
+    }
+
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut w = ProtoWriter::default();
+        w.uint(1, self.header_length);
+        w.uint(2, self.content_length);
+        for stripe in &self.stripes {
+            w.message(3, |w| stripe.encode(w));
+        }
+        for ty in &self.types {
+            w.message(4, |w| ty.encode(w));
+        }
+        w.uint(6, self.number_of_rows);
+        for stats in &self.statistics {
+            w.message(7, |w| stats.encode(w));
+        }
+        w.uint(8, self.row_index_stride as u64);
+        w.buf
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut stripe = Self::default();
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => stripe.offset = value.as_u64()?,
+                2 => stripe.index_length = value.as_u64()?,
+                3 => stripe.data_length = value.as_u64()?,
+                4 => stripe.footer_length = value.as_u64()?,
+                5 => stripe.number_of_rows = value.as_u64()?,
+                _ => {},
+            }
+        });
+        Ok(stripe)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.offset);
+        w.uint(2, self.index_length);
+        w.uint(3, self.data_length);
+        w.uint(4, self.footer_length);
+        w.uint(5, self.number_of_rows);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct Type {
+    pub kind: u32,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+}
+
+impl Type {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut ty = Self::default();
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => ty.kind = value.as_u32()?,
+                2 => value.extend_u32(&mut ty.subtypes)?,
+                3 => ty.field_names.push(value.as_string()?),
+                5 => ty.precision = Some(value.as_u32()?),
+                6 => ty.scale = Some(value.as_u32()?),
+                _ => {},
+            }
+        });
+        Ok(ty)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.uint(1, self.kind as u64);
+        if !self.subtypes.is_empty() {
+            w.packed(2, self.subtypes.iter().map(|&v| v as u64));
+        }
+        for name in &self.field_names {
+            w.bytes(3, name.as_bytes());
+        }
+        if let Some(precision) = self.precision {
+            w.uint(5, precision as u64);
+        }
+        if let Some(scale) = self.scale {
+            w.uint(6, scale as u64);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Metadata {
+    pub stripe_stats: Vec<Vec<ColumnStatistics>>,
+}
+
+impl Metadata {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut metadata = Self::default();
+        decode_fields!(bytes, |field, value| {
+            if field == 1 {
+                let mut col_stats = vec![];
+                decode_fields!(value.as_bytes()?, |field, value| {
+                    if field == 1 {
+                        col_stats.push(ColumnStatistics::decode(value.as_bytes()?)?);
+                    }
+                });
+                metadata.stripe_stats.push(col_stats);
+            }
+        });
+        Ok(metadata)
+    }
+
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut w = ProtoWriter::default();
+        for col_stats in &self.stripe_stats {
+            w.message(1, |w| {
+                for stats in col_stats {
+                    w.message(1, |w| stats.encode(w));
+                }
+            });
+        }
+        w.buf
+    }
+}
+
+/// Column statistics. Only the statistics that can be used for pruning are kept.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub int: Option<MinMax<i64>>,
+    pub double: Option<MinMax<f64>>,
+    pub string: Option<MinMax<String>>,
+    /// The number of `true` values of a boolean column.
+    pub true_count: Option<u64>,
+    pub date: Option<MinMax<i32>>,
+    pub has_null: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct MinMax<T> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+}
+
+impl ColumnStatistics {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut stats = Self::default();
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => stats.number_of_values = Some(value.as_u64()?),
+                2 => {
+                    let mut int = MinMax::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => int.min = Some(value.as_sint()?),
+                            2 => int.max = Some(value.as_sint()?),
+                            _ => {},
+                        }
+                    });
+                    stats.int = Some(int);
+                },
+                3 => {
+                    let mut double = MinMax::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => double.min = Some(value.as_f64()?),
+                            2 => double.max = Some(value.as_f64()?),
+                            _ => {},
+                        }
+                    });
+                    stats.double = Some(double);
+                },
+                4 => {
+                    let mut string = MinMax::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => string.min = Some(value.as_string()?),
+                            2 => string.max = Some(value.as_string()?),
+                            _ => {},
+                        }
+                    });
+                    stats.string = Some(string);
+                },
+                5 => {
+                    let mut count = vec![];
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        if field == 1 {
+                            value.extend_u64(&mut count)?;
+                        }
+                    });
+                    stats.true_count = count.first().copied();
+                },
+                7 => {
+                    let mut date = MinMax::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => date.min = Some(value.as_sint()? as i32),
+                            2 => date.max = Some(value.as_sint()? as i32),
+                            _ => {},
+                        }
+                    });
+                    stats.date = Some(date);
+                },
+                10 => stats.has_null = Some(value.as_u64()? != 0),
+                _ => {},
+            }
+        });
+        Ok(stats)
+    }
+
+    fn encode(&self, w: &mut ProtoWriter) {
+        if let Some(n) = self.number_of_values {
+            w.uint(1, n);
+        }
+        if let Some(int) = &self.int {
+            w.message(2, |w| {
+                if let Some(v) = int.min {
+                    w.sint(1, v);
+                }
+                if let Some(v) = int.max {
+                    w.sint(2, v);
+                }
+            });
+        }
+        if let Some(double) = &self.double {
+            w.message(3, |w| {
+                if let Some(v) = double.min {
+                    w.double(1, v);
+                }
+                if let Some(v) = double.max {
+                    w.double(2, v);
+                }
+            });
+        }
+        if let Some(string) = &self.string {
+            w.message(4, |w| {
+                if let Some(v) = &string.min {
+                    w.bytes(1, v.as_bytes());
+                }
+                if let Some(v) = &string.max {
+                    w.bytes(2, v.as_bytes());
+                }
+            });
+        }
+        if let Some(true_count) = self.true_count {
+            w.message(5, |w| w.packed(1, [true_count]));
+        }
+        if let Some(date) = &self.date {
+            w.message(7, |w| {
+                if let Some(v) = date.min {
+                    w.sint(1, v as i64);
+                }
+                if let Some(v) = date.max {
+                    w.sint(2, v as i64);
+                }
+            });
+        }
+        if let Some(has_null) = self.has_null {
+            w.uint(10, has_null as u64);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Stream {
+    pub kind: u32,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct ColumnEncoding {
+    pub kind: u32,
+    pub dictionary_size: Option<u32>,
+}
+
+impl StripeFooter {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut footer = Self::default();
+        decode_fields!(bytes, |field, value| {
+            match field {
+                1 => {
+                    let mut stream = Stream::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stream.kind = value.as_u32()?,
+                            2 => stream.column = value.as_u32()?,
+                            3 => stream.length = value.as_u64()?,
+                            _ => {},
+                        }
+                    });
+                    footer.streams.push(stream);
+                },
+                2 => {
+                    let mut encoding = ColumnEncoding::default();
+                    decode_fields!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => encoding.kind = value.as_u32()?,
+                            2 => encoding.dictionary_size = Some(value.as_u32()?),
+                            _ => {},
+                        }
+                    });
+                    footer.columns.push(encoding);
+                },
+                _ => {},
+            }
+        });
+        Ok(footer)
+    }
+
+    /// Encodes the footer. The writer timezone is always UTC, as the writer stores timestamps in
+    /// UTC.
+    pub(super) fn encode(&self) -> Vec<u8> {
+        let mut w = ProtoWriter::default();
+        for stream in &self.streams {
+            w.message(1, |w| {
+                w.uint(1, stream.kind as u64);
+                w.uint(2, stream.column as u64);
+                w.uint(3, stream.length);
+            });
+        }
+        for encoding in &self.columns {
+            w.message(2, |w| {
+                w.uint(1, encoding.kind as u64);
+                if let Some(size) = encoding.dictionary_size {
+                    w.uint(2, size as u64);
+                }
+            });
+        }
+        w.bytes(3, b"UTC");
+        w.buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footer_roundtrip() {
+        let footer = Footer {
+            header_length: 3,
+            content_length: 100,
+            stripes: vec![StripeInformation {
+                offset: 3,
+                index_length: 0,
+                data_length: 80,
+                footer_length: 17,
+                number_of_rows: 10,
+            }],
+            types: vec![
+                Type {
+                    kind: type_kind::STRUCT,
+                    subtypes: vec![1, 2],
+                    field_names: vec!["a".into(), "b".into()],
+                    ..Default::default()
+                },
+                Type {
+                    kind: type_kind::LONG,
+                    ..Default::default()
+                },
+                Type {
+                    kind: type_kind::DECIMAL,
+                    precision: Some(10),
+                    scale: Some(2),
+                    ..Default::default()
+                },
+            ],
+            number_of_rows: 10,
+            statistics: vec![ColumnStatistics {
+                number_of_values: Some(10),
+                int: Some(MinMax {
+                    min: Some(-5),
+                    max: Some(7),
+                }),
+                has_null: Some(false),
+                ..Default::default()
+            }],
+            row_index_stride: 0,
+        };
+
+        let decoded = Footer::decode(&footer.encode()).unwrap();
+        assert_eq!(decoded.types, footer.types);
+        assert_eq!(decoded.statistics, footer.statistics);
+        assert_eq!(decoded.stripes[0].footer_length, 17);
+        assert_eq!(decoded.number_of_rows, 10);
+
+        // Repeated integers can also be written unpacked.
+        let ty = Type::decode(&[0x08, 0x0c, 0x10, 0x01, 0x10, 0x02]).unwrap();
+        assert_eq!(ty.subtypes, [1, 2]);
+    }
+}
@@ -0,0 +1,346 @@
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ArrowSchemaRef};
+use arrow::record_batch::RecordBatch;
+use polars_core::prelude::*;
+use polars_utils::aliases::{PlIndexMap, PlIndexSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::compression::{DEFAULT_BLOCK_SIZE, OrcCompression, decompress};
+use super::decode::StripeDecoder;
+use super::proto::{self, Footer, MAGIC, Metadata, PostScript, StripeInformation, Type};
+use super::schema::{infer_schema, subtree};
+use crate::mmap::MmapBytesReader;
+use crate::predicates::ColumnStatistics;
+use crate::prelude::*;
+use crate::shared::{ArrowReader, finish_reader};
+use crate::utils::get_reader_bytes;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrcScanOptions {
+    /// Skip stripes based on the column statistics of the file.
+    pub use_statistics: bool,
+}
+
+impl Default for OrcScanOptions {
+    fn default() -> Self {
+        Self {
+            use_statistics: true,
+        }
+    }
+}
+
+/// The file tail of an in-memory ORC file: its schema, its stripes and their statistics.
+#[derive(Debug, Clone)]
+pub struct OrcMetadata {
+    pub schema: ArrowSchemaRef,
+    pub num_rows: usize,
+    compression: OrcCompression,
+    block_size: usize,
+    stripes: Vec<StripeInformation>,
+    types: Vec<Type>,
+    /// The type id of every top-level column.
+    columns: Vec<u32>,
+    stripe_statistics: Vec<Vec<proto::ColumnStatistics>>,
+    /// Writers before HIVE-8732 wrote incorrect string statistics.
+    string_statistics: bool,
+}
+
+impl OrcMetadata {
+    /// Read the postscript, the footer and the stripe statistics at the end of an ORC file.
+    pub fn try_from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.starts_with(MAGIC) && bytes.len() > MAGIC.len() + 1,
+            ComputeError: "invalid ORC file: missing magic bytes"
+        );
+        let tail = |end: usize, len: u64| {
+            end.checked_sub(len as usize)
+                .filter(|&start| start >= MAGIC.len())
+                .map(|start| (start, &bytes[start..end]))
+                .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: truncated file tail"))
+        };
+
+        let ps_len = bytes[bytes.len() - 1] as u64;
+        let (ps_start, ps) = tail(bytes.len() - 1, ps_len)?;
+        let ps = PostScript::decode(ps)?;
+        let compression = OrcCompression::from_kind(ps.compression)?;
+        let block_size = ps
+            .compression_block_size
+            .map_or(DEFAULT_BLOCK_SIZE, |s| s as usize);
+
+        let (footer_start, footer) = tail(ps_start, ps.footer_length)?;
+        let footer = Footer::decode(&decompress(compression, block_size, footer)?)?;
+        let (_, metadata) = tail(footer_start, ps.metadata_length)?;
+        let metadata = Metadata::decode(&decompress(compression, block_size, metadata)?)?;
+
+        let (schema, columns) = infer_schema(&footer.types)?;
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            num_rows: footer.number_of_rows as usize,
+            compression,
+            block_size,
+            stripes: footer.stripes,
+            types: footer.types,
+            columns,
+            stripe_statistics: metadata.stripe_stats,
+            string_statistics: ps.writer_version.is_some_and(|v| v >= 1),
+        })
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe: usize) -> usize {
+        self.stripes[stripe].number_of_rows as usize
+    }
+
+    /// Decode the projected columns of a stripe. `bytes` must be the file the metadata was read
+    /// from.
+    pub fn read_stripe(
+        &self,
+        bytes: &[u8],
+        stripe: usize,
+        projection: &[usize],
+    ) -> PolarsResult<RecordBatch> {
+        let schema = apply_projection(&self.schema, projection);
+        let ids = projection
+            .iter()
+            .map(|&i| self.columns[i])
+            .collect::<Vec<_>>();
+        let subtrees = ids
+            .iter()
+            .map(|&id| subtree(&self.types, id))
+            .collect::<Vec<_>>();
+
+        let num_rows = self.stripe_num_rows(stripe);
+        let decoder = StripeDecoder::try_new(
+            bytes,
+            &self.stripes[stripe],
+            &self.types,
+            self.compression,
+            self.block_size,
+            &subtrees,
+        )?;
+        let arrays = ids
+            .iter()
+            .zip(schema.iter_values())
+            .map(|(&id, field)| decoder.decode(id, &field.dtype, num_rows, None))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        RecordBatch::try_new(num_rows, Arc::new(schema), arrays)
+    }
+
+    /// Returns the statistics of the columns of a stripe that can be used to skip it. Files
+    /// without stripe statistics return no statistics.
+    pub fn stripe_statistics(
+        &self,
+        stripe: usize,
+        columns: &PlIndexSet<PlSmallStr>,
+    ) -> PlIndexMap<PlSmallStr, ColumnStatistics> {
+        let Some(stripe_stats) = self.stripe_statistics.get(stripe) else {
+            return PlIndexMap::default();
+        };
+        let num_rows = self.stripe_num_rows(stripe) as u64;
+
+        columns
+            .iter()
+            .filter_map(|name| {
+                let (i, _, field) = self.schema.get_full(name)?;
+                let stats = stripe_stats.get(self.columns[i] as usize)?;
+                let dtype = DataType::from_arrow_dtype(&field.dtype);
+                let (min, max) = min_max(&field.dtype, stats, self.string_statistics)
+                    .unwrap_or_default();
+                let null_count = stats
+                    .number_of_values
+                    .map(|n| num_rows.saturating_sub(n) as IdxSize);
+                Some((
+                    name.clone(),
+                    ColumnStatistics {
+                        dtype,
+                        min,
+                        max,
+                        null_count,
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+fn min_max(
+    dtype: &ArrowDataType,
+    stats: &proto::ColumnStatistics,
+    string_statistics: bool,
+) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+    if stats.number_of_values == Some(0) {
+        return None;
+    }
+    let int = || Some((stats.int.as_ref()?.min?, stats.int.as_ref()?.max?));
+    let values = match dtype {
+        ArrowDataType::Boolean => {
+            let true_count = stats.true_count?;
+            let count = stats.number_of_values?;
+            (
+                AnyValue::Boolean(true_count == count),
+                AnyValue::Boolean(true_count > 0),
+            )
+        },
+        ArrowDataType::Int8 => int().map(|(min, max)| (AnyValue::Int8(min as i8), AnyValue::Int8(max as i8)))?,
+        ArrowDataType::Int16 => int().map(|(min, max)| (AnyValue::Int16(min as i16), AnyValue::Int16(max as i16)))?,
+        ArrowDataType::Int32 => int().map(|(min, max)| (AnyValue::Int32(min as i32), AnyValue::Int32(max as i32)))?,
+        ArrowDataType::Int64 => int().map(|(min, max)| (AnyValue::Int64(min), AnyValue::Int64(max)))?,
+        ArrowDataType::Float32 | ArrowDataType::Float64 => {
+            let double = stats.double.as_ref()?;
+            let (min, max) = (double.min?, double.max?);
+            match dtype {
+                ArrowDataType::Float32 => (AnyValue::Float32(min as f32), AnyValue::Float32(max as f32)),
+                _ => (AnyValue::Float64(min), AnyValue::Float64(max)),
+            }
+        },
+        ArrowDataType::Utf8View if string_statistics => {
+            let string = stats.string.as_ref()?;
+            (
+                AnyValue::StringOwned(string.min.as_deref()?.into()),
+                AnyValue::StringOwned(string.max.as_deref()?.into()),
+            )
+        },
+        ArrowDataType::Date32 => {
+            let date = stats.date.as_ref()?;
+            (AnyValue::Date(date.min?), AnyValue::Date(date.max?))
+        },
+        _ => return None,
+    };
+    Some(values)
+}
+
+/// Count the rows of an in-memory ORC file from its footer, without decoding any data.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    Ok(OrcMetadata::try_from_bytes(bytes)?.num_rows)
+}
+
+/// Read [Apache ORC] format into a [`DataFrame`]
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R: MmapBytesReader> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get schema of the ORC file.
+    pub fn schema(&mut self) -> PolarsResult<Schema> {
+        let schema = self.arrow_schema()?;
+        Ok(Schema::from_arrow_schema(&schema))
+    }
+
+    /// Get arrow schema of the ORC file, this is faster than a polars schema.
+    pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let metadata = OrcMetadata::try_from_bytes(&bytes)?;
+        Ok(metadata.schema.as_ref().clone())
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+struct StripeReader<'a> {
+    bytes: &'a [u8],
+    metadata: &'a OrcMetadata,
+    projection: &'a [usize],
+    next_stripe: usize,
+}
+
+impl ArrowReader for StripeReader<'_> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        if self.next_stripe == self.metadata.num_stripes() {
+            return Ok(None);
+        }
+        self.next_stripe += 1;
+        self.metadata
+            .read_stripe(self.bytes, self.next_stripe - 1, self.projection)
+            .map(Some)
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+            projection: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let metadata = OrcMetadata::try_from_bytes(&bytes)?;
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &metadata.schema)?);
+        }
+        let projection = self
+            .projection
+            .unwrap_or_else(|| (0..metadata.schema.len()).collect());
+        let projected_schema = apply_projection(&metadata.schema, &projection);
+
+        let reader = StripeReader {
+            bytes: &bytes,
+            metadata: &metadata,
+            projection: &projection,
+            next_stripe: 0,
+        };
+        finish_reader(
+            reader,
+            self.rechunk,
+            self.n_rows,
+            None,
+            &projected_schema,
+            None,
+        )
+    }
+}
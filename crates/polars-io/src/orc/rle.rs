@@ -0,0 +1,453 @@
+//! The run length encodings of ORC streams. The decoders decode an exact number of values, as the
+//! number of values of every stream follows from the stripe and the present streams.
+
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+
+use super::proto::{read_varint, write_varint, zigzag_decode, zigzag_encode};
+
+fn next_byte(data: &mut &[u8]) -> PolarsResult<u8> {
+    let Some((&byte, rest)) = data.split_first() else {
+        polars_bail!(ComputeError: "unexpected end of ORC stream");
+    };
+    *data = rest;
+    Ok(byte)
+}
+
+fn take<'a>(data: &mut &'a [u8], n: usize) -> PolarsResult<&'a [u8]> {
+    polars_ensure!(n <= data.len(), ComputeError: "unexpected end of ORC stream");
+    let (head, rest) = data.split_at(n);
+    *data = rest;
+    Ok(head)
+}
+
+pub(super) fn decode_bytes(mut data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let header = next_byte(&mut data)? as i8;
+        if header >= 0 {
+            let value = next_byte(&mut data)?;
+            out.extend(std::iter::repeat_n(value, header as usize + 3));
+        } else {
+            out.extend_from_slice(take(&mut data, header.unsigned_abs() as usize)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+pub(super) fn decode_bools(data: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let bytes = decode_bytes(data, n.div_ceil(8))?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decodes `n` integers of the integer run length encoding version 1 or 2. Unsigned values are
+/// returned as the bit pattern of an `i64`.
+pub(super) fn decode_ints(
+    mut data: &[u8],
+    n: usize,
+    signed: bool,
+    v2: bool,
+) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        if v2 {
+            decode_run_v2(&mut data, signed, &mut out)?;
+        } else {
+            decode_run_v1(&mut data, signed, &mut out)?;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int(data: &mut &[u8], signed: bool) -> PolarsResult<i64> {
+    let v = read_varint(data)?;
+    Ok(if signed { zigzag_decode(v) } else { v as i64 })
+}
+
+fn decode_run_v1(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = next_byte(data)? as i8;
+    if header >= 0 {
+        let delta = next_byte(data)? as i8 as i64;
+        let base = read_int(data, signed)?;
+        out.extend((0..header as i64 + 3).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..header.unsigned_abs() {
+            out.push(read_int(data, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Maps the 5-bit width codes of version 2 to bit widths.
+fn decode_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_width(width: usize) -> u8 {
+    match width {
+        0..=24 => width.max(1) as u8 - 1,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        _ => 31,
+    }
+}
+
+/// Rounds a bit width up to a width that can be encoded.
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Reads `count` big-endian values of `width` bits. Runs always end on a byte boundary.
+fn unpack(data: &mut &[u8], width: usize, count: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+    let bytes = take(data, (width * count).div_ceil(8))?;
+    let mut bit = 0;
+    for _ in 0..count {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let available = 8 - bit % 8;
+            let n = available.min(remaining);
+            let bits = (bytes[bit / 8] >> (available - n)) as u64 & ((1 << n) - 1);
+            value = (value << n) | bits;
+            remaining -= n;
+            bit += n;
+        }
+        out.push(value);
+    }
+    Ok(())
+}
+
+fn read_be(data: &mut &[u8], n_bytes: usize) -> PolarsResult<u64> {
+    Ok(take(data, n_bytes)?
+        .iter()
+        .fold(0u64, |acc, &b| (acc << 8) | b as u64))
+}
+
+fn decode_run_v2(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = next_byte(data)?;
+    let decode = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+
+    match header >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let width = ((header >> 3) & 0x7) as usize + 1;
+            let count = (header & 0x7) as usize + 3;
+            let value = decode(read_be(data, width)?);
+            out.extend(std::iter::repeat_n(value, count));
+        },
+        // DIRECT
+        1 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let count = (((header & 1) as usize) << 8 | next_byte(data)? as usize) + 1;
+            let mut values = Vec::with_capacity(count);
+            unpack(data, width, count, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // PATCHED_BASE
+        2 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let count = (((header & 1) as usize) << 8 | next_byte(data)? as usize) + 1;
+            let third = next_byte(data)?;
+            let base_bytes = (third >> 5) as usize + 1;
+            let patch_width = decode_width(third & 0x1f);
+            let fourth = next_byte(data)?;
+            let gap_width = (fourth >> 5) as usize + 1;
+            let patch_count = (fourth & 0x1f) as usize;
+
+            // The base is stored in sign-magnitude representation.
+            let base = read_be(data, base_bytes)?;
+            let sign_bit = 1u64 << (base_bytes * 8 - 1);
+            let base = if base & sign_bit != 0 {
+                -((base & !sign_bit) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(count);
+            unpack(data, width, count, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_count);
+            unpack(
+                data,
+                closest_fixed_bits(patch_width + gap_width),
+                patch_count,
+                &mut patches,
+            )?;
+
+            let patch_mask = u64::MAX >> (64 - patch_width);
+            let mut index = 0;
+            for patch in patches {
+                index += patch.checked_shr(patch_width as u32).unwrap_or(0) as usize;
+                let value = values.get_mut(index).ok_or_else(
+                    || polars_err!(ComputeError: "invalid ORC patched base run"),
+                )?;
+                *value |= (patch & patch_mask).checked_shl(width as u32).unwrap_or(0);
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // DELTA
+        _ => {
+            let code = (header >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_width(code) };
+            let count = (((header & 1) as usize) << 8 | next_byte(data)? as usize) + 1;
+            let base = read_int(data, signed)?;
+            let delta = read_int(data, true)?;
+
+            out.push(base);
+            if width == 0 {
+                out.extend((1..count as i64).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+            } else if count > 1 {
+                let mut previous = base.wrapping_add(delta);
+                out.push(previous);
+                let mut deltas = Vec::with_capacity(count.saturating_sub(2));
+                unpack(data, width, count.saturating_sub(2), &mut deltas)?;
+                for d in deltas {
+                    // All deltas have the sign of the first delta.
+                    previous = if delta < 0 {
+                        previous.wrapping_sub(d as i64)
+                    } else {
+                        previous.wrapping_add(d as i64)
+                    };
+                    out.push(previous);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+pub(super) fn encode_bytes(values: &[u8], out: &mut Vec<u8>) {
+    let run_length = |start: usize, max: usize| {
+        values[start..]
+            .iter()
+            .take(max)
+            .take_while(|&&v| v == values[start])
+            .count()
+    };
+
+    let mut i = 0;
+    while i < values.len() {
+        let run = run_length(i, 130);
+        if run >= 3 {
+            out.push((run - 3) as u8);
+            out.push(values[i]);
+            i += run;
+            continue;
+        }
+        let start = i;
+        while i < values.len() && i - start < 128 && run_length(i, 3) < 3 {
+            i += 1;
+        }
+        out.push((-((i - start) as i16)) as u8);
+        out.extend_from_slice(&values[start..i]);
+    }
+}
+
+pub(super) fn encode_bools(values: impl Iterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    for (i, v) in values.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if v {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_bytes(&bytes, out);
+}
+
+/// Encodes integers with the integer run length encoding version 2. Repeated values are written as
+/// SHORT_REPEAT or fixed DELTA runs and everything else as DIRECT runs.
+pub(super) fn encode_ints(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    const MAX_RUN: usize = 512;
+    let encode = |v: i64| if signed { zigzag_encode(v) } else { v as u64 };
+    let run_length = |start: usize, max: usize| {
+        values[start..]
+            .iter()
+            .take(max)
+            .take_while(|&&v| v == values[start])
+            .count()
+    };
+
+    let mut i = 0;
+    while i < values.len() {
+        let run = run_length(i, MAX_RUN);
+        if run >= 3 {
+            let value = encode(values[i]);
+            if run <= 10 {
+                let width = (64 - value.leading_zeros() as usize).div_ceil(8).max(1);
+                out.push((((width - 1) << 3) | (run - 3)) as u8);
+                out.extend_from_slice(&value.to_be_bytes()[8 - width..]);
+            } else {
+                out.push(0xc0 | ((run - 1) >> 8) as u8);
+                out.push((run - 1) as u8);
+                write_varint(value, out);
+                write_varint(0, out);
+            }
+            i += run;
+            continue;
+        }
+
+        let start = i;
+        while i < values.len() && i - start < MAX_RUN && run_length(i, 3) < 3 {
+            i += 1;
+        }
+        let literals = values[start..i].iter().map(|&v| encode(v));
+        let max = literals.clone().max().unwrap_or_default();
+        // Aligned widths are unpacked faster by most readers.
+        let width = match 64 - max.leading_zeros() as usize {
+            0..=1 => 1,
+            2 => 2,
+            3..=4 => 4,
+            w => w.next_multiple_of(8),
+        };
+        let count = i - start;
+        out.push(0x40 | (encode_width(width) << 1) | ((count - 1) >> 8) as u8);
+        out.push((count - 1) as u8);
+        pack(literals, width, out);
+    }
+}
+
+fn pack(values: impl Iterator<Item = u64>, width: usize, out: &mut Vec<u8>) {
+    let mut current = 0u8;
+    let mut used = 0;
+    for value in values {
+        let mut remaining = width;
+        while remaining > 0 {
+            let n = (8 - used).min(remaining);
+            let bits = ((value >> (remaining - n)) & ((1 << n) - 1)) as u8;
+            current |= bits << (8 - used - n);
+            used += n;
+            remaining -= n;
+            if used == 8 {
+                out.push(current);
+                current = 0;
+                used = 0;
+            }
+        }
+    }
+    if used > 0 {
+        out.push(current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_spec_examples() {
+        // The examples of the ORC specification.
+        assert_eq!(decode_bytes(&[0x61, 0x00], 100).unwrap(), [0; 100]);
+        assert_eq!(decode_bytes(&[0xfe, 0x44, 0x45], 2).unwrap(), [0x44, 0x45]);
+        assert_eq!(
+            decode_ints(&[0x61, 0x00, 0x07], 100, false, false).unwrap(),
+            [7; 100]
+        );
+        assert_eq!(
+            decode_ints(&[0x61, 0xff, 0x64], 100, false, false).unwrap(),
+            (1..=100).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decode_ints(&[0xfb, 0x02, 0x03, 0x04, 0x07, 0xb], 5, false, false).unwrap(),
+            [2, 3, 4, 7, 11]
+        );
+
+        assert_eq!(
+            decode_ints(&[0x0a, 0x27, 0x10], 5, false, true).unwrap(),
+            [10000; 5]
+        );
+        assert_eq!(
+            decode_ints(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                false,
+                true
+            )
+            .unwrap(),
+            [23713, 43806, 57005, 48879]
+        );
+        assert_eq!(
+            decode_ints(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8
+                ],
+                20,
+                false,
+                true
+            )
+            .unwrap(),
+            [
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+        assert_eq!(
+            decode_ints(
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                10,
+                false,
+                true
+            )
+            .unwrap(),
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut ints = vec![0, -1, 1, i64::MIN, i64::MAX, 5, 5, 5];
+        ints.extend([42; 700]);
+        ints.extend((0..1000).map(|i| i * 7919 % 1013 - 500));
+        ints.extend([-3; 4]);
+        for signed in [true, false] {
+            let mut out = vec![];
+            encode_ints(&ints, signed, &mut out);
+            assert_eq!(decode_ints(&out, ints.len(), signed, true).unwrap(), ints);
+        }
+
+        let mut bytes = vec![1, 2, 2, 2, 3];
+        bytes.extend([9; 300]);
+        bytes.extend((0..=255).cycle().take(500));
+        let mut out = vec![];
+        encode_bytes(&bytes, &mut out);
+        assert_eq!(decode_bytes(&out, bytes.len()).unwrap(), bytes);
+
+        let bools = (0..1001).map(|i| i % 3 == 0 || i > 900).collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_bools(bools.iter().copied(), &mut out);
+        let decoded = decode_bools(&out, bools.len()).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), bools);
+    }
+}
@@ -0,0 +1,207 @@
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field as ArrowField, TimeUnit};
+use polars_core::prelude::*;
+
+use super::proto::{Type, type_kind};
+
+/// Precision and scale of decimals written by old Hive versions, which do not store them.
+const DEFAULT_DECIMAL_PRECISION: usize = 38;
+const DEFAULT_DECIMAL_SCALE: usize = 18;
+
+/// Converts the type tree of the footer into an arrow schema. Returns the schema and the type id
+/// of every top-level column.
+pub(super) fn infer_schema(types: &[Type]) -> PolarsResult<(ArrowSchema, Vec<u32>)> {
+    let root = types
+        .first()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: no types in the footer"))?;
+    polars_ensure!(
+        root.kind == type_kind::STRUCT,
+        ComputeError: "invalid ORC file: the root type must be a struct"
+    );
+
+    let schema = struct_fields(types, root)?
+        .into_iter()
+        .map(|field| (field.name.clone(), field))
+        .collect();
+    Ok((schema, root.subtypes.clone()))
+}
+
+fn struct_fields(types: &[Type], ty: &Type) -> PolarsResult<Vec<ArrowField>> {
+    polars_ensure!(
+        ty.field_names.len() == ty.subtypes.len(),
+        ComputeError: "invalid ORC file: struct type with {} field names and {} subtypes",
+        ty.field_names.len(), ty.subtypes.len()
+    );
+    ty.field_names
+        .iter()
+        .zip(&ty.subtypes)
+        .map(|(name, &id)| Ok(ArrowField::new(name.into(), to_arrow_dtype(types, id)?, true)))
+        .collect()
+}
+
+fn subtype(types: &[Type], ty: &Type, i: usize) -> PolarsResult<ArrowDataType> {
+    let id = ty
+        .subtypes
+        .get(i)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: missing subtype"))?;
+    to_arrow_dtype(types, *id)
+}
+
+pub(super) fn to_arrow_dtype(types: &[Type], id: u32) -> PolarsResult<ArrowDataType> {
+    let ty = types
+        .get(id as usize)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: unknown type id {}", id))?;
+
+    let dtype = match ty.kind {
+        type_kind::BOOLEAN => ArrowDataType::Boolean,
+        type_kind::BYTE => ArrowDataType::Int8,
+        type_kind::SHORT => ArrowDataType::Int16,
+        type_kind::INT => ArrowDataType::Int32,
+        type_kind::LONG => ArrowDataType::Int64,
+        type_kind::FLOAT => ArrowDataType::Float32,
+        type_kind::DOUBLE => ArrowDataType::Float64,
+        type_kind::STRING | type_kind::VARCHAR | type_kind::CHAR => ArrowDataType::Utf8View,
+        type_kind::BINARY => ArrowDataType::BinaryView,
+        type_kind::TIMESTAMP => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        type_kind::TIMESTAMP_INSTANT => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some(PlSmallStr::from_static("UTC")))
+        },
+        type_kind::DATE => ArrowDataType::Date32,
+        type_kind::DECIMAL => ArrowDataType::Decimal(
+            ty.precision
+                .map_or(DEFAULT_DECIMAL_PRECISION, |p| p as usize),
+            ty.scale.map_or(DEFAULT_DECIMAL_SCALE, |s| s as usize),
+        ),
+        type_kind::LIST => {
+            ArrowDataType::LargeList(Box::new(ArrowField::new(
+                PlSmallStr::from_static("item"),
+                subtype(types, ty, 0)?,
+                true,
+            )))
+        },
+        // Maps are read as lists of key-value structs.
+        type_kind::MAP => {
+            let entries = ArrowDataType::Struct(vec![
+                ArrowField::new(PlSmallStr::from_static("key"), subtype(types, ty, 0)?, true),
+                ArrowField::new(PlSmallStr::from_static("value"), subtype(types, ty, 1)?, true),
+            ]);
+            ArrowDataType::LargeList(Box::new(ArrowField::new(
+                PlSmallStr::from_static("item"),
+                entries,
+                true,
+            )))
+        },
+        type_kind::STRUCT => ArrowDataType::Struct(struct_fields(types, ty)?),
+        type_kind::UNION => polars_bail!(ComputeError: "ORC union types are not supported"),
+        kind => polars_bail!(ComputeError: "unknown ORC type kind {}", kind),
+    };
+    Ok(dtype)
+}
+
+/// Returns the ids of `id` and all of its descendants. Type ids are assigned in pre-order, so
+/// these form a contiguous range.
+pub(super) fn subtree(types: &[Type], id: u32) -> std::ops::Range<u32> {
+    let mut end = id + 1;
+    if let Some(ty) = types.get(id as usize) {
+        for &child in &ty.subtypes {
+            end = end.max(subtree(types, child).end);
+        }
+    }
+    id..end
+}
+
+/// Casts the columns that ORC cannot represent directly to types it can.
+pub(super) fn to_writable_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    let dtype = match dtype {
+        DataType::UInt8 => DataType::Int16,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(to_writable_dtype(inner)?)),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name.clone(), to_writable_dtype(&f.dtype)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::String
+        | DataType::Binary
+        | DataType::Date
+        | DataType::Datetime(_, _)
+        | DataType::Decimal(_, _)
+        | DataType::Null => dtype.clone(),
+        dtype => polars_bail!(InvalidOperation: "cannot write data type {} to ORC", dtype),
+    };
+    Ok(dtype)
+}
+
+/// Appends the types of the arrow schema in pre-order, with the root struct at id 0.
+pub(super) fn to_orc_types(schema: &ArrowSchema) -> PolarsResult<Vec<Type>> {
+    let mut types = vec![Type {
+        kind: type_kind::STRUCT,
+        field_names: schema.iter_names().map(|n| n.to_string()).collect(),
+        ..Default::default()
+    }];
+    for field in schema.iter_values() {
+        let id = push_type(&mut types, &field.dtype)?;
+        types[0].subtypes.push(id);
+    }
+    Ok(types)
+}
+
+fn push_type(types: &mut Vec<Type>, dtype: &ArrowDataType) -> PolarsResult<u32> {
+    let id = types.len() as u32;
+    let simple = |kind| Type {
+        kind,
+        ..Default::default()
+    };
+    let ty = match dtype {
+        ArrowDataType::Boolean => simple(type_kind::BOOLEAN),
+        ArrowDataType::Int8 => simple(type_kind::BYTE),
+        ArrowDataType::Int16 => simple(type_kind::SHORT),
+        ArrowDataType::Int32 => simple(type_kind::INT),
+        // All values of a null column are written as nulls of a long column.
+        ArrowDataType::Int64 | ArrowDataType::Null => simple(type_kind::LONG),
+        ArrowDataType::Float32 => simple(type_kind::FLOAT),
+        ArrowDataType::Float64 => simple(type_kind::DOUBLE),
+        ArrowDataType::Utf8View => simple(type_kind::STRING),
+        ArrowDataType::BinaryView => simple(type_kind::BINARY),
+        ArrowDataType::Date32 => simple(type_kind::DATE),
+        ArrowDataType::Timestamp(_, None) => simple(type_kind::TIMESTAMP),
+        ArrowDataType::Timestamp(_, Some(_)) => simple(type_kind::TIMESTAMP_INSTANT),
+        ArrowDataType::Decimal(precision, scale) => Type {
+            kind: type_kind::DECIMAL,
+            precision: Some(*precision as u32),
+            scale: Some(*scale as u32),
+            ..Default::default()
+        },
+        ArrowDataType::LargeList(field) => {
+            types.push(simple(type_kind::LIST));
+            let child = push_type(types, &field.dtype)?;
+            types[id as usize].subtypes.push(child);
+            return Ok(id);
+        },
+        ArrowDataType::Struct(fields) => {
+            types.push(Type {
+                kind: type_kind::STRUCT,
+                field_names: fields.iter().map(|f| f.name.to_string()).collect(),
+                ..Default::default()
+            });
+            for field in fields {
+                let child = push_type(types, &field.dtype)?;
+                types[id as usize].subtypes.push(child);
+            }
+            return Ok(id);
+        },
+        dtype => polars_bail!(InvalidOperation: "cannot write arrow data type {:?} to ORC", dtype),
+    };
+    types.push(ty);
+    Ok(id)
+}
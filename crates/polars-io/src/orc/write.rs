@@ -0,0 +1,238 @@
+use std::io::Write;
+
+use polars_core::prelude::*;
+
+pub use super::compression::OrcCompression;
+use super::compression::{DEFAULT_BLOCK_SIZE, compress};
+use super::encode::{StripeEncoder, merge_statistics};
+use super::proto::{
+    ColumnStatistics, Footer, MAGIC, Metadata, PostScript, StripeInformation, Type,
+};
+use super::schema::{to_orc_types, to_writable_dtype};
+use crate::shared::{SerWriter, schema_to_arrow_checked};
+
+/// Default maximum number of rows of a stripe.
+const DEFAULT_STRIPE_SIZE: usize = 512 * 512;
+
+/// File format version 0.12, the version of the ORC v1 specification.
+const FILE_VERSION: [u32; 2] = [0, 12];
+
+/// Readers work around the bugs of older writers based on this version. Version 6 (ORC-135) is
+/// past all the workarounds that affect the streams and statistics this writer produces.
+const WRITER_VERSION: u32 = 6;
+
+/// Write a [`DataFrame`] to [Apache ORC] format
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///
+///     OrcWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    compression: OrcCompression,
+    stripe_size: Option<usize>,
+}
+
+impl<W> OrcWriter<W>
+where
+    W: Write,
+{
+    /// Set the compression used. Defaults to [`OrcCompression::Zlib`].
+    pub fn with_compression(mut self, compression: OrcCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the maximum number of rows of a stripe. Defaults to 512^2 rows.
+    pub fn with_stripe_size(mut self, size: Option<usize>) -> Self {
+        self.stripe_size = size;
+        self
+    }
+
+    /// Write the file header and return a writer that can write [`DataFrame`]s batch by batch.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        BatchedWriter::try_new(self.writer, schema, self.compression, self.stripe_size)
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            compression: OrcCompression::default(),
+            stripe_size: None,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut writer = BatchedWriter::try_new(
+            &mut self.writer,
+            df.schema(),
+            self.compression,
+            self.stripe_size,
+        )?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    schema: Schema,
+    types: Vec<Type>,
+    compression: OrcCompression,
+    stripe_size: usize,
+    /// Number of bytes written so far.
+    offset: u64,
+    num_rows: u64,
+    stripes: Vec<StripeInformation>,
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    file_statistics: Vec<ColumnStatistics>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    fn try_new(
+        mut writer: W,
+        schema: &Schema,
+        compression: OrcCompression,
+        stripe_size: Option<usize>,
+    ) -> PolarsResult<Self> {
+        let schema = schema
+            .iter()
+            .map(|(name, dtype)| Ok((name.clone(), to_writable_dtype(dtype)?)))
+            .collect::<PolarsResult<Schema>>()?;
+        let arrow_schema = schema_to_arrow_checked(&schema, CompatLevel::newest(), "orc")?;
+        let types = to_orc_types(&arrow_schema)?;
+        writer.write_all(MAGIC)?;
+
+        Ok(Self {
+            writer,
+            schema,
+            file_statistics: vec![ColumnStatistics::default(); types.len()],
+            types,
+            compression,
+            stripe_size: stripe_size.unwrap_or(DEFAULT_STRIPE_SIZE).max(1),
+            offset: MAGIC.len() as u64,
+            num_rows: 0,
+            stripes: vec![],
+            stripe_statistics: vec![],
+        })
+    }
+
+    /// Write a batch to the ORC writer. The batch is split into stripes of at most the stripe
+    /// size, stripes are not combined across batches.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(self.schema.iter_values())
+            .map(|(c, dtype)| {
+                if c.dtype() == dtype {
+                    Ok(c.clone())
+                } else {
+                    c.strict_cast(dtype)
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let df = unsafe { DataFrame::new_no_checks(df.height(), columns) };
+
+        for offset in (0..df.height()).step_by(self.stripe_size) {
+            let mut stripe = df.slice(offset as i64, self.stripe_size);
+            stripe.as_single_chunk_par();
+            let num_rows = stripe.height();
+            let arrays = stripe
+                .iter_chunks(CompatLevel::newest(), true)
+                .next()
+                .map(|batch| batch.into_arrays())
+                .unwrap_or_default();
+            self.write_stripe(&arrays, num_rows)?;
+        }
+        Ok(())
+    }
+
+    fn write_stripe(&mut self, arrays: &[ArrayRef], num_rows: usize) -> PolarsResult<()> {
+        let stripe = StripeEncoder::new(&self.types, self.compression, DEFAULT_BLOCK_SIZE)
+            .encode(arrays, num_rows)?;
+        self.writer.write_all(&stripe.bytes)?;
+
+        self.stripes.push(StripeInformation {
+            offset: self.offset,
+            index_length: 0,
+            data_length: stripe.data_length,
+            footer_length: stripe.footer_length,
+            number_of_rows: num_rows as u64,
+        });
+        for (file, stripe) in self.file_statistics.iter_mut().zip(&stripe.statistics) {
+            merge_statistics(file, stripe);
+        }
+        self.stripe_statistics.push(stripe.statistics);
+        self.offset += stripe.bytes.len() as u64;
+        self.num_rows += num_rows as u64;
+        Ok(())
+    }
+
+    /// Write the stripe statistics, the footer and the postscript.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        let mut tail = vec![];
+        let metadata = Metadata {
+            stripe_stats: std::mem::take(&mut self.stripe_statistics),
+        };
+        compress(
+            self.compression,
+            DEFAULT_BLOCK_SIZE,
+            &metadata.encode(),
+            &mut tail,
+        )?;
+        let metadata_length = tail.len() as u64;
+
+        let footer = Footer {
+            header_length: MAGIC.len() as u64,
+            content_length: self.offset,
+            stripes: std::mem::take(&mut self.stripes),
+            types: self.types.clone(),
+            number_of_rows: self.num_rows,
+            statistics: self.file_statistics.clone(),
+            row_index_stride: 0,
+        };
+        compress(
+            self.compression,
+            DEFAULT_BLOCK_SIZE,
+            &footer.encode(),
+            &mut tail,
+        )?;
+        let footer_length = tail.len() as u64 - metadata_length;
+
+        let postscript = PostScript {
+            footer_length,
+            compression: self.compression.kind(),
+            compression_block_size: Some(DEFAULT_BLOCK_SIZE as u64),
+            version: FILE_VERSION.to_vec(),
+            metadata_length,
+            writer_version: Some(WRITER_VERSION),
+        }
+        .encode();
+        tail.extend_from_slice(&postscript);
+        tail.push(postscript.len() as u8);
+
+        self.writer.write_all(&tail)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+//! Parquet modular encryption.
+//!
+//! Encrypted files are written with [`ParquetEncryptionOptions`] and read by setting
+//! [`ParquetOptions::key_retriever`](super::read::ParquetOptions::key_retriever). In both cases
+//! keys are never stored in the file, only key metadata that a [`ParquetKeyRetriever`] resolves
+//! to the actual key.
+//!
+//! Encrypting and decrypting requires the `parquet_encryption` feature.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::io::{Read, Seek};
+use std::sync::Arc;
+
+use polars_error::PolarsResult;
+#[cfg(not(feature = "parquet_encryption"))]
+use polars_error::polars_bail;
+#[cfg(feature = "parquet_encryption")]
+use polars_parquet::arrow::encryption::{
+    EncryptionKey, FileDecryptionProperties, FileEncryptionProperties,
+};
+use polars_parquet::read::FileMetadata;
+#[cfg(feature = "parquet_encryption")]
+use polars_parquet::read::ParquetError;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+type KeyRetrieverFunction = Arc<dyn Fn(&[u8]) -> PolarsResult<Vec<u8>> + Send + Sync>;
+
+/// Returns the AES-GCM key, 16 or 32 bytes long, for the key metadata stored in a Parquet file.
+#[derive(Clone)]
+pub struct ParquetKeyRetriever(KeyRetrieverFunction);
+
+impl ParquetKeyRetriever {
+    pub fn from_func(
+        func: impl Fn(&[u8]) -> PolarsResult<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(func))
+    }
+
+    pub fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+        self.0(key_metadata)
+    }
+
+    #[cfg(feature = "parquet_encryption")]
+    pub fn decryption_properties(&self) -> FileDecryptionProperties {
+        let func = self.0.clone();
+        FileDecryptionProperties::new(Arc::new(move |key_metadata: &[u8]| {
+            func(key_metadata).map_err(|err| {
+                ParquetError::InvalidParameter(format!("failed to retrieve key: {err}"))
+            })
+        }))
+    }
+}
+
+impl Debug for ParquetKeyRetriever {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parquet key retriever at 0x{:016x}",
+            self.0.as_ref() as *const _ as *const () as usize
+        )
+    }
+}
+
+impl Eq for ParquetKeyRetriever {}
+
+impl PartialEq for ParquetKeyRetriever {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for ParquetKeyRetriever {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ParquetKeyRetriever {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize ParquetKeyRetriever"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ParquetKeyRetriever {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {:?}", self)))
+    }
+}
+
+/// Encrypt a Parquet file following the Parquet modular encryption spec (`AES_GCM_V1`).
+///
+/// The footer is always encrypted with the footer key. If `column_key_metadata` is empty every
+/// column is encrypted with the footer key, otherwise only the given columns are encrypted, each
+/// with its own key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetEncryptionOptions {
+    /// Metadata of the footer key, stored in the file.
+    pub footer_key_metadata: Vec<u8>,
+    /// Metadata of the keys of the columns that are encrypted with their own key.
+    pub column_key_metadata: Vec<(PlSmallStr, Vec<u8>)>,
+    /// Resolves the key metadata to the keys.
+    pub key_retriever: ParquetKeyRetriever,
+}
+
+impl ParquetEncryptionOptions {
+    pub fn new(footer_key_metadata: Vec<u8>, key_retriever: ParquetKeyRetriever) -> Self {
+        Self {
+            footer_key_metadata,
+            column_key_metadata: vec![],
+            key_retriever,
+        }
+    }
+
+    /// Encrypt the column `name` with the key of `key_metadata` instead of the footer key.
+    pub fn with_column_key(mut self, name: PlSmallStr, key_metadata: Vec<u8>) -> Self {
+        self.column_key_metadata.push((name, key_metadata));
+        self
+    }
+
+    #[cfg(feature = "parquet_encryption")]
+    pub(crate) fn encryption_properties(&self) -> PolarsResult<FileEncryptionProperties> {
+        let key = |key_metadata: &Vec<u8>| -> PolarsResult<EncryptionKey> {
+            let key = self.key_retriever.retrieve_key(key_metadata)?;
+            Ok(EncryptionKey::new(key).with_key_metadata(key_metadata.clone()))
+        };
+
+        let mut properties = FileEncryptionProperties::new(key(&self.footer_key_metadata)?);
+        for (name, key_metadata) in &self.column_key_metadata {
+            properties = properties.with_column_key(name.as_str(), key(key_metadata)?);
+        }
+        Ok(properties)
+    }
+}
+
+/// Reads the metadata of a Parquet file, decrypting it with the keys of `key_retriever` if the
+/// file is encrypted.
+pub fn read_metadata<R: Read + Seek>(
+    reader: &mut R,
+    key_retriever: Option<&ParquetKeyRetriever>,
+) -> PolarsResult<FileMetadata> {
+    #[cfg(feature = "parquet_encryption")]
+    {
+        let decryption = key_retriever.map(ParquetKeyRetriever::decryption_properties);
+        polars_parquet::read::read_metadata_with_decryption(reader, decryption.as_ref())
+    }
+    #[cfg(not(feature = "parquet_encryption"))]
+    {
+        if key_retriever.is_some() {
+            polars_bail!(ComputeError: "reading encrypted parquet files requires the 'parquet_encryption' feature");
+        }
+        Ok(polars_parquet::read::read_metadata(reader)?)
+    }
+}
+
+/// Deserializes the footer of a Parquet file, which consists of the metadata followed by the 8
+/// byte length and magic, decrypting it with the keys of `key_retriever` if the file is encrypted.
+pub fn deserialize_footer(
+    footer: &[u8],
+    key_retriever: Option<&ParquetKeyRetriever>,
+) -> PolarsResult<FileMetadata> {
+    #[cfg(feature = "parquet_encryption")]
+    {
+        let decryption = key_retriever.map(ParquetKeyRetriever::decryption_properties);
+        Ok(polars_parquet::parquet::read::deserialize_footer(
+            footer,
+            decryption.as_ref(),
+        )?)
+    }
+    #[cfg(not(feature = "parquet_encryption"))]
+    {
+        if key_retriever.is_some()
+            || footer.ends_with(&polars_parquet::parquet::PARQUET_MAGIC_ENCRYPTED_FOOTER)
+        {
+            polars_bail!(ComputeError: "reading encrypted parquet files requires the 'parquet_encryption' feature");
+        }
+        // a highly nested but sparse struct could result in many allocations
+        let max_size = footer.len() * 2 + 1024;
+        Ok(polars_parquet::parquet::read::deserialize_metadata(
+            footer, max_size,
+        )?)
+    }
+}
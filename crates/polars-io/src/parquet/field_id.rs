@@ -0,0 +1,294 @@
+//! Matching the columns of Parquet files by field id.
+//!
+//! Table formats that evolve their schema give every column, and every field of a struct column,
+//! a field id that stays the same when the column is renamed or moved. Matching the columns of the
+//! files of a table by field id instead of by name lines up columns that were renamed after some
+//! of the files were written.
+use std::sync::Arc;
+
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use polars_parquet::parquet::schema::Repetition;
+use polars_parquet::parquet::schema::types::{GroupConvertedType, GroupLogicalType, ParquetType};
+use polars_parquet::read::FileMetadata;
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the names of struct fields of a file that are not in the table. These fields are
+/// dropped when the struct is cast to the type of the table.
+const UNMATCHED_FIELD_PREFIX: &str = "__POLARS_UNMATCHED_FIELD_";
+
+/// The Parquet field id of a column, or of a field of a struct column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetFieldId {
+    pub name: PlSmallStr,
+    pub id: i32,
+    /// Field ids of the fields of a struct column, or of the struct elements of a list column.
+    /// Fields that are not listed are matched by name.
+    pub fields: Vec<ParquetFieldId>,
+}
+
+impl ParquetFieldId {
+    pub fn new(name: impl Into<PlSmallStr>, id: i32) -> Self {
+        Self {
+            name: name.into(),
+            id,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Set the field ids of the fields of a struct column, or of the struct elements of a list
+    /// column.
+    pub fn with_fields(mut self, fields: Vec<ParquetFieldId>) -> Self {
+        self.fields = fields;
+        self
+    }
+}
+
+/// Returns the type of the elements of a Parquet list, or `None` if the type is not a list.
+///
+/// This follows the backward-compatibility rules of the Parquet format that are also used to
+/// infer the Arrow type of a list.
+fn list_element(parquet_type: &ParquetType) -> Option<&ParquetType> {
+    let ParquetType::GroupType {
+        field_info,
+        logical_type,
+        converted_type,
+        fields,
+    } = parquet_type
+    else {
+        return None;
+    };
+
+    // A repeated group that is not annotated is a list of structs.
+    if field_info.repetition == Repetition::Repeated {
+        return Some(parquet_type);
+    }
+
+    let is_list = matches!(
+        (logical_type, converted_type),
+        (Some(GroupLogicalType::List), _) | (None, Some(GroupConvertedType::List))
+    );
+    if !is_list {
+        return None;
+    }
+
+    match fields.first()? {
+        item @ ParquetType::GroupType { fields, .. }
+            if fields.len() == 1
+                && item.name() != "array"
+                && item.name() != format!("{}_tuple", field_info.name) =>
+        {
+            fields.first()
+        },
+        item => Some(item),
+    }
+}
+
+/// Returns the fields of a Parquet struct, or of the struct elements of a Parquet list.
+fn nested_fields(parquet_type: &ParquetType) -> &[ParquetType] {
+    match list_element(parquet_type) {
+        Some(element) if !std::ptr::eq(element, parquet_type) => nested_fields(element),
+        _ => match parquet_type {
+            ParquetType::PrimitiveType(_) => &[],
+            ParquetType::GroupType { fields, .. } => fields,
+        },
+    }
+}
+
+/// Returns the fields of an Arrow struct, or of the struct elements of an Arrow list.
+fn nested_arrow_fields_mut(dtype: &mut ArrowDataType) -> Option<&mut Vec<Field>> {
+    match dtype {
+        ArrowDataType::Struct(fields) => Some(fields),
+        ArrowDataType::List(field)
+        | ArrowDataType::LargeList(field)
+        | ArrowDataType::FixedSizeList(field, _) => nested_arrow_fields_mut(&mut field.dtype),
+        _ => None,
+    }
+}
+
+/// Collect the field ids of a Parquet schema. Columns and fields without a field id are skipped.
+pub fn field_ids_from_parquet_schema(fields: &[ParquetType]) -> Vec<ParquetFieldId> {
+    fields
+        .iter()
+        .filter_map(|parquet_type| {
+            let field_info = parquet_type.get_field_info();
+            Some(
+                ParquetFieldId::new(field_info.name.clone(), field_info.id?)
+                    .with_fields(field_ids_from_parquet_schema(nested_fields(parquet_type))),
+            )
+        })
+        .collect()
+}
+
+/// Attach the `field_ids` to the fields of `schema`, so that they are written to the Parquet
+/// schema of the file.
+pub fn add_field_ids(schema: &mut ArrowSchema, field_ids: &[ParquetFieldId]) -> PolarsResult<()> {
+    fn add_field_id(field: &mut Field, field_id: &ParquetFieldId) -> PolarsResult<()> {
+        let mut metadata = field.metadata.as_deref().cloned().unwrap_or_default();
+        metadata.insert(
+            PlSmallStr::from_static(PARQUET_FIELD_ID_META_KEY),
+            format_pl_smallstr!("{}", field_id.id),
+        );
+        field.metadata = Some(Arc::new(metadata));
+
+        if field_id.fields.is_empty() {
+            return Ok(());
+        }
+
+        let Some(fields) = nested_arrow_fields_mut(&mut field.dtype) else {
+            polars_bail!(
+                InvalidOperation: "cannot set the field ids of the fields of column '{}' of type {:?}, which is not a struct",
+                field.name, field.dtype
+            )
+        };
+        for child_id in &field_id.fields {
+            let child = fields
+                .iter_mut()
+                .find(|child| child.name == child_id.name)
+                .ok_or_else(|| polars_err!(StructFieldNotFound: "{}", child_id.name))?;
+            add_field_id(child, child_id)?;
+        }
+
+        Ok(())
+    }
+
+    for field_id in field_ids {
+        add_field_id(schema.try_get_mut(&field_id.name)?, field_id)?;
+    }
+
+    Ok(())
+}
+
+/// The columns of a Parquet file matched to the columns of a table by field id.
+#[derive(Clone, Debug)]
+pub struct FieldIdMatch {
+    /// Schema of the columns of the file that are in the table, in the order of the file. The
+    /// columns, and the fields of struct columns, have the names of the table.
+    pub schema: ArrowSchema,
+    /// The columns of `schema` as (name in the file, name in the table).
+    pub columns: Vec<(PlSmallStr, PlSmallStr)>,
+}
+
+/// Match the columns of a Parquet file to the `table` field ids.
+///
+/// `file_schema` is the Arrow schema of the file and `parquet_fields` are the root fields of its
+/// Parquet schema. Columns of the file that are not in the table are left out. Fields of struct
+/// columns that are not in the table are given a name that cannot clash with the table, see
+/// [`FieldIdMatch::table_schema`].
+pub fn match_field_ids(
+    file_schema: &ArrowSchema,
+    parquet_fields: &[ParquetType],
+    table: &[ParquetFieldId],
+) -> FieldIdMatch {
+    let mut schema = ArrowSchema::with_capacity(table.len());
+    let mut columns = Vec::with_capacity(table.len());
+
+    for field in file_schema.iter_values() {
+        let Some(parquet_field) = parquet_fields.iter().find(|p| p.name() == field.name) else {
+            continue;
+        };
+        let Some(table_field) = find_field_id(parquet_field, table) else {
+            continue;
+        };
+        if schema.contains(&table_field.name) {
+            continue;
+        }
+
+        let mut field = field.clone();
+        rename_nested_fields(&mut field.dtype, parquet_field, &table_field.fields);
+        columns.push((field.name.clone(), table_field.name.clone()));
+        field.name = table_field.name.clone();
+        schema.insert(field.name.clone(), field);
+    }
+
+    FieldIdMatch { schema, columns }
+}
+
+fn find_field_id<'a>(
+    parquet_type: &ParquetType,
+    table: &'a [ParquetFieldId],
+) -> Option<&'a ParquetFieldId> {
+    let id = parquet_type.get_field_info().id?;
+    table.iter().find(|field_id| field_id.id == id)
+}
+
+fn rename_nested_fields(
+    dtype: &mut ArrowDataType,
+    parquet_type: &ParquetType,
+    table_fields: &[ParquetFieldId],
+) {
+    match dtype {
+        // The fields are matched by name if the table has no field ids for them.
+        ArrowDataType::Struct(_) if table_fields.is_empty() => {},
+        ArrowDataType::Struct(fields) => {
+            let parquet_fields = nested_fields(parquet_type);
+            for field in fields {
+                let matched = parquet_fields
+                    .iter()
+                    .find(|p| p.name() == field.name)
+                    .and_then(|p| Some((p, find_field_id(p, table_fields)?)));
+
+                match matched {
+                    Some((parquet_field, table_field)) => {
+                        field.name = table_field.name.clone();
+                        rename_nested_fields(&mut field.dtype, parquet_field, &table_field.fields);
+                    },
+                    None => {
+                        field.name = format_pl_smallstr!("{UNMATCHED_FIELD_PREFIX}{}", field.name)
+                    },
+                }
+            }
+        },
+        ArrowDataType::List(field)
+        | ArrowDataType::LargeList(field)
+        | ArrowDataType::FixedSizeList(field, _) => {
+            if let Some(element) = list_element(parquet_type) {
+                rename_nested_fields(&mut field.dtype, element, table_fields);
+            }
+        },
+        _ => {},
+    }
+}
+
+impl FieldIdMatch {
+    /// The schema of the matched columns as seen by the table, without the struct fields that are
+    /// not in the table.
+    pub fn table_schema(&self) -> ArrowSchema {
+        fn drop_unmatched_fields(dtype: &mut ArrowDataType) {
+            match dtype {
+                ArrowDataType::Struct(fields) => {
+                    fields.retain(|field| !field.name.starts_with(UNMATCHED_FIELD_PREFIX));
+                    fields
+                        .iter_mut()
+                        .for_each(|field| drop_unmatched_fields(&mut field.dtype));
+                },
+                ArrowDataType::List(field)
+                | ArrowDataType::LargeList(field)
+                | ArrowDataType::FixedSizeList(field, _) => drop_unmatched_fields(&mut field.dtype),
+                _ => {},
+            }
+        }
+
+        let mut schema = self.schema.clone();
+        schema
+            .iter_values_mut()
+            .for_each(|field| drop_unmatched_fields(&mut field.dtype));
+        schema
+    }
+
+    /// The metadata of the file with the matched columns looked up by their names in the table.
+    pub fn rename_metadata(&self, metadata: &FileMetadata) -> FileMetadata {
+        let mut metadata = metadata.clone();
+        metadata.row_groups = metadata
+            .row_groups
+            .iter()
+            .map(|rg| rg.with_renamed_roots(&self.columns))
+            .collect();
+        metadata
+    }
+}
@@ -2,7 +2,40 @@
 
 use std::sync::Arc;
 
+use arrow::datatypes::Metadata;
+use polars_error::PolarsResult;
+use polars_parquet::arrow::ARROW_SCHEMA_META_KEY;
+use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::parquet::metadata::FileMetadata;
 pub use polars_parquet::read::statistics::{Statistics as ParquetStatistics, deserialize};
+use polars_utils::pl_str::PlSmallStr;
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+/// Returns the custom key-value metadata of the file footer.
+///
+/// The entry holding the embedded Arrow schema is not included.
+pub fn key_value_metadata(metadata: &FileMetadata) -> Vec<(PlSmallStr, Option<PlSmallStr>)> {
+    metadata
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter(|kv| kv.key != ARROW_SCHEMA_META_KEY)
+        .map(|kv| (kv.key.as_str().into(), kv.value.as_deref().map(Into::into)))
+        .collect()
+}
+
+/// Returns the Arrow metadata of the top-level fields that have any.
+///
+/// This reads the Arrow schema embedded in the file footer, so it also contains the entries
+/// Polars writes to preserve its own data types.
+pub fn field_metadata(metadata: &FileMetadata) -> PolarsResult<Vec<(PlSmallStr, Metadata)>> {
+    let schema = infer_schema(metadata)?;
+    Ok(schema
+        .into_iter()
+        .filter_map(|(name, field)| {
+            let metadata = field.metadata?;
+            (!metadata.is_empty()).then(|| (name, Arc::unwrap_or_clone(metadata)))
+        })
+        .collect())
+}
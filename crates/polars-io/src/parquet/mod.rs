@@ -1,5 +1,7 @@
 //! Functionality for reading and writing Apache Parquet files.
 
+pub mod encryption;
+pub mod field_id;
 pub mod metadata;
 pub mod read;
 pub mod write;
@@ -8,6 +8,7 @@ use polars_parquet::write::FileMetadata;
 use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
+use crate::parquet::encryption::{ParquetKeyRetriever, deserialize_footer};
 use crate::parquet::metadata::FileMetadataRef;
 
 pub struct ParquetObjectStore {
@@ -16,6 +17,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    key_retriever: Option<ParquetKeyRetriever>,
 }
 
 impl ParquetObjectStore {
@@ -33,9 +35,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            key_retriever: None,
         })
     }
 
+    /// Retrieve the keys to read a file encrypted with Parquet modular encryption.
+    pub fn with_key_retriever(mut self, key_retriever: Option<ParquetKeyRetriever>) -> Self {
+        self.key_retriever = key_retriever;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -53,7 +62,7 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        fetch_metadata(&self.store, &self.path, length, self.key_retriever.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -92,11 +101,13 @@ fn read_i32le(reader: &mut &[u8]) -> Option<i32> {
     read_n(reader).map(i32::from_le_bytes)
 }
 
-/// Asynchronously reads the files' metadata
+/// Asynchronously reads the files' metadata, decrypting it with the keys of `key_retriever` if
+/// the file is encrypted.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -116,7 +127,9 @@ pub async fn fetch_metadata(
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if magic != polars_parquet::parquet::PARQUET_MAGIC
+            && magic != polars_parquet::parquet::PARQUET_MAGIC_ENCRYPTED_FOOTER
+        {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
@@ -142,11 +155,5 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    deserialize_footer(footer_bytes.as_ref(), key_retriever)
 }
@@ -2,6 +2,7 @@ use arrow::array::Array;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_error::PolarsResult;
+use polars_parquet::parquet::read::PageMetaData;
 use polars_parquet::read::{
     BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
 };
@@ -54,19 +55,44 @@ pub fn to_deserializer(
     field: Field,
     filter: Option<Filter>,
 ) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
-    let (columns, types): (Vec<_>, Vec<_>) = columns
+    let columns = columns
         .into_iter()
-        .map(|(column_meta, chunk)| {
+        .map(|(column_meta, chunk)| (PageMetaData::from(column_meta), chunk))
+        .collect();
+
+    to_deserializer_with_page_meta(columns, field, filter)
+}
+
+/// Same as [`to_deserializer`], but the pages in each chunk are described by a [`PageMetaData`].
+///
+/// This allows deserializing a chunk that only holds a subset of the pages of a column chunk, in
+/// which case the number of values in the [`PageMetaData`] must only count the values of those
+/// pages.
+pub fn to_deserializer_with_page_meta(
+    columns: Vec<(PageMetaData, MemSlice)>,
+    field: Field,
+    filter: Option<Filter>,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let types = columns
+        .iter()
+        .map(|(page_meta, _)| page_meta.descriptor.primitive_type.clone())
+        .collect::<Vec<_>>();
+
+    let columns = columns
+        .into_iter()
+        .map(|(page_meta, chunk)| {
             // Advise fetching the data for the column chunk
             chunk.prefetch();
 
-            let pages = PageReader::new(MemReader::new(chunk), column_meta, vec![], usize::MAX);
-            (
-                BasicDecompressor::new(pages, vec![]),
-                &column_meta.descriptor().descriptor.primitive_type,
-            )
+            let pages = PageReader::new_with_page_meta(
+                MemReader::new(chunk),
+                page_meta,
+                vec![],
+                usize::MAX,
+            );
+            BasicDecompressor::new(pages, vec![])
         })
-        .unzip();
+        .collect();
 
-    column_iter_to_arrays(columns, types, field, filter)
+    column_iter_to_arrays(columns, types.iter().collect(), field, filter)
 }
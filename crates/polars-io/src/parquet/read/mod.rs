@@ -32,7 +32,7 @@ or set 'streaming'",
 
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
-pub use options::{ParallelStrategy, ParquetOptions};
+pub use options::{ParallelStrategy, ParquetColumnMatching, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
@@ -41,8 +41,11 @@ pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
-    pub use super::predicates::collect_statistics_with_live_columns;
+    pub use super::mmap::{to_deserializer, to_deserializer_with_page_meta};
+    pub use super::predicates::{
+        BLOOM_FILTER_HEADER_SIZE_HINT, bloom_filter_bitset_range, bloom_filter_byte_range,
+        bloom_filter_excludes_all, collect_statistics_with_live_columns,
+    };
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -2,6 +2,9 @@ use polars_core::schema::SchemaRef;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::parquet::encryption::ParquetKeyRetriever;
+use crate::parquet::field_id::ParquetFieldId;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetOptions {
@@ -9,6 +12,28 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Retrieves the keys to read files encrypted with Parquet modular encryption.
+    pub key_retriever: Option<ParquetKeyRetriever>,
+    /// How the columns of the files are matched to the columns of the table.
+    pub column_matching: ParquetColumnMatching,
+}
+
+/// How the columns of the files of a scan are matched to the columns of the table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetColumnMatching {
+    /// Match the columns by name.
+    #[default]
+    Name,
+    /// Match the columns, and the fields of struct columns, by Parquet field id. The columns get
+    /// the names of the table, which are taken from `field_ids`, or from the first file if it is
+    /// not set. Columns of a file that are not in the table are ignored.
+    ///
+    /// The columns of a file may have been written with a narrower type than the table, e.g.
+    /// `Int32` instead of `Int64`, these are widened to the type of the table.
+    FieldId {
+        field_ids: Option<Vec<ParquetFieldId>>,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -1,6 +1,9 @@
+use std::ops::Range;
+
 use polars_core::prelude::*;
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::arrow::bloom_filter;
 use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
+use polars_parquet::read::{ColumnChunkMetadata, PhysicalType, RowGroupMetadata};
 
 /// Collect the statistics in a row-group
 pub fn collect_statistics_with_live_columns(
@@ -36,3 +39,154 @@ pub fn collect_statistics_with_live_columns(
         })
         .collect::<PolarsResult<Vec<_>>>()
 }
+
+/// Size that is fetched for a bloom filter header if the length of the bloom filter is not stored
+/// in the column metadata. The header itself is at most a few dozen bytes.
+pub const BLOOM_FILTER_HEADER_SIZE_HINT: usize = 64;
+
+/// Byte range of the bloom filter of a column chunk in the file.
+///
+/// If the metadata does not contain the length of the bloom filter, the range only covers (at
+/// least) the header and [`bloom_filter_bitset_range`] has to be used to find the full range.
+///
+/// Returns `None` for encrypted column chunks, as their bloom filters are encrypted.
+pub fn bloom_filter_byte_range(column: &ColumnChunkMetadata) -> Option<Range<usize>> {
+    if column.is_encrypted() {
+        return None;
+    }
+    let md = column.metadata();
+    let offset = usize::try_from(md.bloom_filter_offset?).ok()?;
+    let length = match md.bloom_filter_length {
+        Some(length) => usize::try_from(length).ok()?,
+        None => BLOOM_FILTER_HEADER_SIZE_HINT,
+    };
+
+    Some(offset..offset + length)
+}
+
+/// Byte range of the bitset of the bloom filter starting at `bytes`, relative to the start of
+/// `bytes`. Returns `None` if the bloom filter is not a supported split-block bloom filter.
+///
+/// The range may extend past the end of `bytes`.
+pub fn bloom_filter_bitset_range(bytes: &[u8]) -> PolarsResult<Option<Range<usize>>> {
+    Ok(bloom_filter::read_header(bytes)?)
+}
+
+/// Returns whether the bloom filter `bitset` of `column` proves that none of the non-null `values`
+/// is in the column chunk.
+///
+/// This is conservative and returns `false` if the values cannot be checked against the bloom
+/// filter, e.g. because they are of a type that is not hashed consistently.
+pub fn bloom_filter_excludes_all(
+    bitset: &[u8],
+    column: &ColumnChunkMetadata,
+    field: &ArrowField,
+    values: &Series,
+) -> bool {
+    if bitset.is_empty() || bitset.len() % 32 != 0 {
+        return false;
+    }
+
+    let Some(hashes) = bloom_filter_hashes(column.physical_type(), field, values) else {
+        return false;
+    };
+
+    !hashes
+        .into_iter()
+        .any(|hash| bloom_filter::is_in_set(bitset, hash))
+}
+
+/// Hashes `values` the same way a Parquet writer hashes the plain encoded values of `field`.
+fn bloom_filter_hashes(
+    physical_type: PhysicalType,
+    field: &ArrowField,
+    values: &Series,
+) -> Option<Vec<u64>> {
+    use {ArrowDataType as A, DataType as D, PhysicalType as P};
+
+    // Only allow types that are stored as-is, so that e.g. timestamps with a different unit than
+    // the file are never checked.
+    let dtype = DataType::from_arrow_field(field);
+    match field.dtype() {
+        A::Int8 | A::Int16 | A::Int32 | A::Int64 => {},
+        A::UInt8 | A::UInt16 | A::UInt32 | A::UInt64 => {},
+        A::Float32 | A::Float64 => {},
+        A::Utf8 | A::LargeUtf8 | A::Utf8View => {},
+        A::Binary | A::LargeBinary | A::BinaryView => {},
+        A::Date32 => {},
+        _ => return None,
+    }
+
+    // Integers can be losslessly cast between each other, other types have to match exactly.
+    let values = if values.dtype() == &dtype {
+        values.drop_nulls()
+    } else if values.dtype().is_integer() && dtype.is_integer() {
+        values.strict_cast(&dtype).ok()?.drop_nulls()
+    } else {
+        return None;
+    };
+    let values = values.to_physical_repr();
+
+    let hashes = match (physical_type, values.dtype()) {
+        (P::Int32, D::Int8 | D::Int16 | D::Int32 | D::UInt8 | D::UInt16) => {
+            let values = values.cast(&D::Int32).ok()?;
+            let ca = values.i32().ok()?;
+            ca.into_no_null_iter()
+                .map(bloom_filter::hash_native)
+                .collect()
+        },
+        (P::Int32, D::UInt32) => values
+            .u32()
+            .ok()?
+            .into_no_null_iter()
+            .map(|v| bloom_filter::hash_native(v as i32))
+            .collect(),
+        (P::Int64, D::Int64) => values
+            .i64()
+            .ok()?
+            .into_no_null_iter()
+            .map(bloom_filter::hash_native)
+            .collect(),
+        (P::Int64, D::UInt64) => values
+            .u64()
+            .ok()?
+            .into_no_null_iter()
+            .map(|v| bloom_filter::hash_native(v as i64))
+            .collect(),
+        // The hash is over the bytes, so values that compare equal with different bit patterns
+        // (-0.0 and 0.0, NaNs) cannot be checked.
+        (P::Float, D::Float32) => {
+            let ca = values.f32().ok()?;
+            if ca.into_no_null_iter().any(|v| v == 0.0 || v.is_nan()) {
+                return None;
+            }
+            ca.into_no_null_iter()
+                .map(bloom_filter::hash_native)
+                .collect()
+        },
+        (P::Double, D::Float64) => {
+            let ca = values.f64().ok()?;
+            if ca.into_no_null_iter().any(|v| v == 0.0 || v.is_nan()) {
+                return None;
+            }
+            ca.into_no_null_iter()
+                .map(bloom_filter::hash_native)
+                .collect()
+        },
+        (P::ByteArray, D::String) => values
+            .str()
+            .ok()?
+            .into_no_null_iter()
+            .map(bloom_filter::hash_byte)
+            .collect(),
+        (P::ByteArray, D::Binary) => values
+            .binary()
+            .ok()?
+            .into_no_null_iter()
+            .map(bloom_filter::hash_byte)
+            .collect(),
+        _ => return None,
+    };
+
+    Some(hashes)
+}
@@ -9,10 +9,14 @@ use super::read_impl::read_parquet;
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::encryption::{ParquetKeyRetriever, read_metadata};
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
 
 /// Read Apache parquet format into a DataFrame.
+///
+/// This reader takes no predicate, so it never skips row groups by their statistics or bloom
+/// filters. A lazy `scan_parquet` followed by a filter does.
 #[must_use]
 pub struct ParquetReader<R: Read + Seek> {
     reader: R,
@@ -27,6 +31,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    key_retriever: Option<ParquetKeyRetriever>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -164,13 +169,22 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Retrieve the keys to read a file encrypted with Parquet modular encryption.
+    pub fn with_key_retriever(mut self, key_retriever: Option<ParquetKeyRetriever>) -> Self {
+        self.key_retriever = key_retriever;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            self.metadata = Some(Arc::new(read_metadata(
+                &mut self.reader,
+                self.key_retriever.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -192,6 +206,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            key_retriever: None,
         }
     }
 
@@ -6,12 +6,16 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
-    FileWriter, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
-    array_to_columns,
+    BloomFilterOptions, CompressedPage, CompressionOptions, Compressor, DynIter,
+    DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, KeyValue, Page,
+    ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions, array_to_bloom_filter,
+    array_to_columns, transverse,
 };
 use rayon::prelude::*;
 
+/// The bloom filter bitsets of the column chunks of a row group, one entry per leaf column.
+pub type RowGroupBloomFilters = Vec<Option<Vec<u8>>>;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -21,6 +25,9 @@ pub struct BatchedWriter<W: Write> {
     // @TODO: Remove when old streaming engine is removed
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
+    pub(super) compressions: Vec<CompressionOptions>,
+    pub(super) bloom_filters: Vec<Option<BloomFilterOptions>>,
+    pub(super) key_value_metadata: Option<Vec<KeyValue>>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
 }
@@ -29,6 +36,9 @@ impl<W: Write> BatchedWriter<W> {
     pub fn new(
         writer: Mutex<FileWriter<W>>,
         encodings: Vec<Vec<Encoding>>,
+        compressions: Vec<CompressionOptions>,
+        bloom_filters: Vec<Option<BloomFilterOptions>>,
+        key_value_metadata: Option<Vec<KeyValue>>,
         options: WriteOptions,
         parallel: bool,
     ) -> Self {
@@ -36,27 +46,38 @@ impl<W: Write> BatchedWriter<W> {
             writer,
             parquet_schema: SchemaDescriptor::new(PlSmallStr::EMPTY, vec![]),
             encodings,
+            compressions,
+            bloom_filters,
+            key_value_metadata,
             options,
             parallel,
         }
     }
 
+    #[allow(clippy::type_complexity)]
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<
+        Item = PolarsResult<(
+            RowGroupIterColumns<'static, PolarsError>,
+            Option<RowGroupBloomFilters>,
+        )>,
+    > + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let bloom_filters = create_bloom_filters(&batch, &self.bloom_filters);
                 let row_group = create_eager_serializer(
                     batch,
                     self.parquet_schema.fields(),
                     self.encodings.as_ref(),
+                    self.compressions.as_ref(),
                     self.options,
                 );
 
-                Some(row_group)
+                Some(row_group.map(|row_group| (row_group, bloom_filters)))
             },
         })
     }
@@ -70,13 +91,19 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.compressions,
+            &self.bloom_filters,
             self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write(group)?;
+            if let Some(bloom_filters) = bloom_filters {
+                writer.write_bloom_filters(&bloom_filters)?;
+            }
         }
         Ok(())
     }
@@ -97,18 +124,30 @@ impl<W: Write> BatchedWriter<W> {
         Ok(())
     }
 
+    /// Write the bloom filters of the last written row group.
+    pub fn write_bloom_filters(&mut self, bloom_filters: &[Option<Vec<u8>>]) -> PolarsResult<()> {
+        let writer = self.writer.get_mut().unwrap();
+        writer.write_bloom_filters(bloom_filters)
+    }
+
     pub fn get_writer(&self) -> &Mutex<FileWriter<W>> {
         &self.writer
     }
 
     pub fn write_row_groups(
         &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
+        rgs: Vec<(
+            RowGroupIterColumns<'static, PolarsError>,
+            Option<RowGroupBloomFilters>,
+        )>,
     ) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
+        for (group, bloom_filters) in rgs {
             writer.write(group)?;
+            if let Some(bloom_filters) = bloom_filters {
+                writer.write_bloom_filters(&bloom_filters)?;
+            }
         }
         Ok(())
     }
@@ -116,31 +155,68 @@ impl<W: Write> BatchedWriter<W> {
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&self) -> PolarsResult<u64> {
         let mut writer = self.writer.lock().unwrap();
-        let size = writer.end(None)?;
+        let size = writer.end(self.key_value_metadata.clone())?;
         Ok(size)
     }
 }
 
 // Note that the df should be rechunked
+#[allow(clippy::type_complexity)]
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    compressions: &'a [CompressionOptions],
+    bloom_filters: &'a [Option<BloomFilterOptions>],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<
+    Item = PolarsResult<(
+        RowGroupIterColumns<'static, PolarsError>,
+        Option<RowGroupBloomFilters>,
+    )>,
+> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let bloom_filters = create_bloom_filters(&batch, bloom_filters);
+            let row_group = create_serializer(
+                batch,
+                parquet_schema.fields(),
+                encodings,
+                compressions,
+                options,
+                parallel,
+            );
 
-            Some(row_group)
+            Some(row_group.map(|row_group| (row_group, bloom_filters)))
         },
     })
 }
 
+/// Builds the bloom filters of a row group. Returns `None` if no bloom filters are written.
+fn create_bloom_filters(
+    batch: &RecordBatch,
+    bloom_filters: &[Option<BloomFilterOptions>],
+) -> Option<RowGroupBloomFilters> {
+    if bloom_filters.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let bloom_filters = batch
+        .columns()
+        .iter()
+        .zip(bloom_filters)
+        .flat_map(|(array, options)| match options {
+            Some(options) => vec![array_to_bloom_filter(array.as_ref(), options)],
+            None => transverse(array.dtype(), |_| None),
+        })
+        .collect();
+
+    Some(bloom_filters)
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
@@ -171,8 +247,13 @@ fn array_to_pages_iter(
     array: &ArrayRef,
     type_: &ParquetType,
     encoding: &[Encoding],
+    compression: CompressionOptions,
     options: WriteOptions,
 ) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
+    let options = WriteOptions {
+        compression,
+        ..options
+    };
     let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
     pages_iter_to_compressor(encoded_columns, options)
 }
@@ -181,12 +262,14 @@ fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
     parallel: bool,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *compression, options) };
 
     let columns = if parallel {
         POOL.install(|| {
@@ -195,6 +278,7 @@ fn create_serializer(
                 .par_iter()
                 .zip(fields)
                 .zip(encodings)
+                .zip(compressions)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -204,6 +288,7 @@ fn create_serializer(
             .iter()
             .zip(fields)
             .zip(encodings)
+            .zip(compressions)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
@@ -219,17 +304,20 @@ fn create_eager_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
     options: WriteOptions,
 ) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        array_to_pages_iter(array, type_, encoding, options)
-    };
+    let func = move |(((array, type_), encoding), compression): (
+        ((&ArrayRef, &ParquetType), &Vec<Encoding>),
+        &CompressionOptions,
+    )| { array_to_pages_iter(array, type_, encoding, *compression, options) };
 
     let columns = batch
         .columns()
         .iter()
         .zip(fields)
         .zip(encodings)
+        .zip(compressions)
         .flat_map(func)
         .collect::<Vec<_>>();
 
@@ -4,7 +4,13 @@ mod batched_writer;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
-pub use options::{BrotliLevel, GzipLevel, ParquetCompression, ParquetWriteOptions, ZstdLevel};
+pub use batched_writer::{BatchedWriter, RowGroupBloomFilters};
+pub use options::{
+    BrotliLevel, GzipLevel, ParquetBloomFilterOptions, ParquetColumnOptions, ParquetCompression,
+    ParquetEncoding, ParquetWriteOptions, ZstdLevel,
+};
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
-pub use writer::{ParquetWriter, get_encodings};
+pub use writer::{
+    ParquetWriter, add_field_metadata, encrypt_writer, get_bloom_filters, get_compressions,
+    get_encodings, get_key_value_metadata,
+};
@@ -1,12 +1,19 @@
-use polars_error::PolarsResult;
+use std::hash::{Hash, Hasher};
+
+use arrow::datatypes::Metadata;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+use crate::parquet::encryption::ParquetEncryptionOptions;
+use crate::parquet::field_id::ParquetFieldId;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -17,6 +24,112 @@ pub struct ParquetWriteOptions {
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     pub data_page_size: Option<usize>,
+    /// Columns to write split-block bloom filters for.
+    pub bloom_filters: Vec<(PlSmallStr, ParquetBloomFilterOptions)>,
+    /// Per-column overrides of the compression and encoding.
+    pub column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    /// Custom key-value metadata to write to the file footer.
+    pub key_value_metadata: Vec<(PlSmallStr, PlSmallStr)>,
+    /// Custom Arrow metadata to attach to the fields of the given columns.
+    pub field_metadata: Vec<(PlSmallStr, Metadata)>,
+    /// Parquet field ids to write for the given columns and struct fields.
+    pub field_ids: Vec<ParquetFieldId>,
+    /// Encrypt the file with Parquet modular encryption.
+    pub encryption: Option<ParquetEncryptionOptions>,
+}
+
+/// Overrides of the writer settings for a single column.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// The compression of the column. If `None`, the compression of the file is used.
+    pub compression: Option<ParquetCompression>,
+    /// Whether to dictionary encode the column. If `None`, this is decided based on the type of
+    /// the column. Columns with many distinct values fall back to plain encoding.
+    pub dictionary: Option<bool>,
+    /// The encoding of the data pages of the column. A column with a forced encoding is not
+    /// dictionary encoded.
+    pub encoding: Option<ParquetEncoding>,
+}
+
+/// A data page encoding that can be forced for a column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Run-length encoding. Only supported for boolean columns.
+    Rle,
+    /// Only supported for integer and temporal columns.
+    DeltaBinaryPacked,
+    /// Only supported for string and binary columns.
+    DeltaLengthByteArray,
+    /// Only supported for string and binary columns.
+    DeltaByteArray,
+    /// Only supported for float, integer and temporal columns.
+    ByteStreamSplit,
+}
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        use ParquetEncoding::*;
+        match value {
+            Plain => Encoding::Plain,
+            Rle => Encoding::Rle,
+            DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            DeltaByteArray => Encoding::DeltaByteArray,
+            ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
+}
+
+/// The split-block bloom filter to write for a column.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetBloomFilterOptions {
+    /// The target false positive probability, between 0 and 1 (exclusive).
+    pub fpp: f64,
+    /// The expected number of distinct values per row group. If `None`, the bloom filter of
+    /// every row group is sized for the distinct values it actually contains.
+    pub ndv: Option<u64>,
+}
+
+impl ParquetBloomFilterOptions {
+    pub fn try_new(fpp: f64, ndv: Option<u64>) -> PolarsResult<Self> {
+        polars_ensure!(
+            fpp > 0.0 && fpp < 1.0,
+            InvalidOperation: "bloom filter false positive probability must be in (0, 1), got {}",
+            fpp
+        );
+        Ok(Self { fpp, ndv })
+    }
+}
+
+impl Default for ParquetBloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: 0.05,
+            ndv: None,
+        }
+    }
+}
+
+impl Eq for ParquetBloomFilterOptions {}
+
+impl Hash for ParquetBloomFilterOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fpp.to_bits().hash(state);
+        self.ndv.hash(state);
+    }
+}
+
+impl From<ParquetBloomFilterOptions> for BloomFilterOptions {
+    fn from(value: ParquetBloomFilterOptions) -> Self {
+        BloomFilterOptions {
+            fpp: value.fpp,
+            ndv: value.ndv,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -1,17 +1,20 @@
 use std::io::Write;
 use std::sync::Mutex;
 
-use arrow::datatypes::PhysicalType;
+use arrow::datatypes::{Metadata, PhysicalType};
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
+use polars_parquet::arrow::ARROW_SCHEMA_META_KEY;
 use polars_parquet::write::{
-    CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
-    to_parquet_schema, transverse,
+    BloomFilterOptions, CompressionOptions, Encoding, FileWriter, KeyValue, StatisticsOptions,
+    Version, WriteOptions, supports_bloom_filter, supports_encoding, to_parquet_schema, transverse,
 };
 
 use super::ParquetWriteOptions;
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{ParquetBloomFilterOptions, ParquetColumnOptions, ParquetCompression};
+use crate::parquet::encryption::ParquetEncryptionOptions;
+use crate::parquet::field_id::{ParquetFieldId, add_field_ids};
 use crate::shared::schema_to_arrow_checked;
 
 impl ParquetWriteOptions {
@@ -24,6 +27,12 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_bloom_filters(self.bloom_filters.clone())
+            .with_column_options(self.column_options.clone())
+            .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_metadata(self.field_metadata.clone())
+            .with_field_ids(self.field_ids.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -39,6 +48,18 @@ pub struct ParquetWriter<W> {
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
+    /// Columns to write bloom filters for.
+    bloom_filters: Vec<(PlSmallStr, ParquetBloomFilterOptions)>,
+    /// Per-column overrides of the compression and encoding.
+    column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    /// Custom key-value metadata written to the file footer.
+    key_value_metadata: Vec<(PlSmallStr, PlSmallStr)>,
+    /// Custom Arrow metadata attached to the fields of the given columns.
+    field_metadata: Vec<(PlSmallStr, Metadata)>,
+    /// Parquet field ids of the given columns and struct fields.
+    field_ids: Vec<ParquetFieldId>,
+    /// Parquet modular encryption of the file.
+    encryption: Option<ParquetEncryptionOptions>,
     /// Serialize columns in parallel
     parallel: bool,
 }
@@ -58,6 +79,12 @@ where
             statistics: StatisticsOptions::default(),
             row_group_size: None,
             data_page_size: None,
+            bloom_filters: Vec::new(),
+            column_options: Vec::new(),
+            key_value_metadata: Vec::new(),
+            field_metadata: Vec::new(),
+            field_ids: Vec::new(),
+            encryption: None,
             parallel: true,
         }
     }
@@ -90,6 +117,54 @@ where
         self
     }
 
+    /// Write split-block bloom filters for the given columns.
+    pub fn with_bloom_filters(
+        mut self,
+        bloom_filters: Vec<(PlSmallStr, ParquetBloomFilterOptions)>,
+    ) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// Override the compression, dictionary encoding or data page encoding of the given columns.
+    pub fn with_column_options(
+        mut self,
+        column_options: Vec<(PlSmallStr, ParquetColumnOptions)>,
+    ) -> Self {
+        self.column_options = column_options;
+        self
+    }
+
+    /// Write custom key-value metadata to the file footer.
+    pub fn with_key_value_metadata(
+        mut self,
+        key_value_metadata: Vec<(PlSmallStr, PlSmallStr)>,
+    ) -> Self {
+        self.key_value_metadata = key_value_metadata;
+        self
+    }
+
+    /// Attach custom Arrow metadata to the fields of the given columns.
+    ///
+    /// The metadata is stored in the Arrow schema that is embedded in the file footer.
+    pub fn with_field_metadata(mut self, field_metadata: Vec<(PlSmallStr, Metadata)>) -> Self {
+        self.field_metadata = field_metadata;
+        self
+    }
+
+    /// Write Parquet field ids for the given columns and struct fields, so that readers can match
+    /// the columns by field id when they are renamed.
+    pub fn with_field_ids(mut self, field_ids: Vec<ParquetFieldId>) -> Self {
+        self.field_ids = field_ids;
+        self
+    }
+
+    /// Encrypt the file with Parquet modular encryption.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -97,16 +172,26 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        let mut schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        add_field_metadata(&mut schema, &self.field_metadata)?;
+        add_field_ids(&mut schema, &self.field_ids)?;
+        let key_value_metadata = get_key_value_metadata(&self.key_value_metadata)?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
+        let encodings = get_encodings(&schema, &self.column_options)?;
+        let compressions = get_compressions(&schema, self.compression, &self.column_options)?;
+        let bloom_filters = get_bloom_filters(&schema, &self.bloom_filters)?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let writer = FileWriter::try_new(self.writer, schema, options)?;
+        let writer = encrypt_writer(writer, self.encryption.as_ref(), &self.bloom_filters)?;
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
             encodings,
+            compressions,
+            bloom_filters,
+            key_value_metadata,
             options,
             parallel: self.parallel,
         })
@@ -130,11 +215,160 @@ where
     }
 }
 
-pub fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
-    schema
+/// Resolve the encodings of the leaf columns of every column of `schema`.
+pub fn get_encodings(
+    schema: &ArrowSchema,
+    column_options: &[(PlSmallStr, ParquetColumnOptions)],
+) -> PolarsResult<Vec<Vec<Encoding>>> {
+    let mut out: Vec<Vec<Encoding>> = schema
         .iter_values()
         .map(|f| transverse(&f.dtype, encoding_map))
-        .collect()
+        .collect();
+
+    for (name, options) in column_options {
+        let (i, _, field) = schema.try_get_full(name)?;
+        let dtype = field.dtype();
+
+        out[i] = match (options.dictionary, options.encoding) {
+            (None, None) => continue,
+            (Some(true), Some(_)) => polars_bail!(
+                InvalidOperation: "cannot force an encoding for dictionary encoded parquet column '{}'",
+                name
+            ),
+            (Some(true), None) => transverse(dtype, |_| Encoding::RleDictionary),
+            (Some(false), None) => {
+                polars_ensure!(
+                    transverse(dtype, |dtype| supports_encoding(dtype, Encoding::Plain))
+                        .into_iter()
+                        .all(|supported| supported),
+                    InvalidOperation: "cannot disable dictionary encoding for parquet column '{}' of type {}",
+                    name, DataType::from_arrow_field(field)
+                );
+                transverse(dtype, |_| Encoding::Plain)
+            },
+            (_, Some(encoding)) => {
+                let encoding = encoding.into();
+                polars_ensure!(
+                    !dtype.is_nested() && supports_encoding(dtype, encoding),
+                    InvalidOperation: "cannot write parquet column '{}' of type {} with encoding {:?}",
+                    name, DataType::from_arrow_field(field), encoding
+                );
+                vec![encoding]
+            },
+        };
+    }
+
+    Ok(out)
+}
+
+/// Resolve the compression of every column of `schema`.
+pub fn get_compressions(
+    schema: &ArrowSchema,
+    compression: CompressionOptions,
+    column_options: &[(PlSmallStr, ParquetColumnOptions)],
+) -> PolarsResult<Vec<CompressionOptions>> {
+    let mut out = vec![compression; schema.len()];
+
+    for (name, options) in column_options {
+        let (i, _, _) = schema.try_get_full(name)?;
+        if let Some(compression) = options.compression {
+            out[i] = compression.into();
+        }
+    }
+
+    Ok(out)
+}
+
+/// Resolve the bloom filters to write for every column of `schema`.
+pub fn get_bloom_filters(
+    schema: &ArrowSchema,
+    bloom_filters: &[(PlSmallStr, ParquetBloomFilterOptions)],
+) -> PolarsResult<Vec<Option<BloomFilterOptions>>> {
+    let mut out = vec![None; schema.len()];
+
+    for (name, options) in bloom_filters {
+        let (i, _, field) = schema.try_get_full(name)?;
+        polars_ensure!(
+            supports_bloom_filter(field.dtype()),
+            InvalidOperation: "cannot write a parquet bloom filter for column '{}' of type {}",
+            name, DataType::from_arrow_field(field)
+        );
+        out[i] = Some((*options).into());
+    }
+
+    Ok(out)
+}
+
+/// Resolve the keys to encrypt the file written by `writer` with.
+///
+/// Bloom filters cannot be written for encrypted columns.
+pub fn encrypt_writer<W: Write>(
+    writer: FileWriter<W>,
+    encryption: Option<&ParquetEncryptionOptions>,
+    bloom_filters: &[(PlSmallStr, ParquetBloomFilterOptions)],
+) -> PolarsResult<FileWriter<W>> {
+    let Some(encryption) = encryption else {
+        return Ok(writer);
+    };
+
+    for (name, _) in bloom_filters {
+        polars_ensure!(
+            !encryption.column_key_metadata.is_empty()
+                && !encryption.column_key_metadata.iter().any(|(c, _)| c == name),
+            InvalidOperation: "cannot write a parquet bloom filter for the encrypted column '{}'",
+            name
+        );
+    }
+
+    #[cfg(feature = "parquet_encryption")]
+    {
+        writer.with_encryption(encryption.encryption_properties()?)
+    }
+    #[cfg(not(feature = "parquet_encryption"))]
+    {
+        polars_bail!(ComputeError: "writing encrypted parquet files requires the 'parquet_encryption' feature")
+    }
+}
+
+/// Attach the custom `field_metadata` to the fields of `schema`.
+///
+/// Existing metadata of a field is kept unless a key is overwritten.
+pub fn add_field_metadata(
+    schema: &mut ArrowSchema,
+    field_metadata: &[(PlSmallStr, Metadata)],
+) -> PolarsResult<()> {
+    for (name, metadata) in field_metadata {
+        let field = schema.try_get_mut(name)?;
+        let mut merged = field.metadata.as_deref().cloned().unwrap_or_default();
+        merged.extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+        field.metadata = Some(Arc::new(merged));
+    }
+
+    Ok(())
+}
+
+/// Resolve the custom key-value metadata to write to the file footer.
+pub fn get_key_value_metadata(
+    key_value_metadata: &[(PlSmallStr, PlSmallStr)],
+) -> PolarsResult<Option<Vec<KeyValue>>> {
+    if key_value_metadata.is_empty() {
+        return Ok(None);
+    }
+
+    key_value_metadata
+        .iter()
+        .map(|(key, value)| {
+            polars_ensure!(
+                key != ARROW_SCHEMA_META_KEY,
+                InvalidOperation: "the parquet key-value metadata key '{}' is reserved", key
+            );
+            Ok(KeyValue {
+                key: key.to_string(),
+                value: Some(value.to_string()),
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()
+        .map(Some)
 }
 
 /// Declare encodings
@@ -0,0 +1,17 @@
+//! Reading and writing of the PostgreSQL binary `COPY` format.
+//!
+//! This is the format of `COPY ... TO/FROM ... WITH (FORMAT binary)`. A file starts with a fixed
+//! signature, a flags word and a header extension, followed by one tuple per row and a trailer.
+//! Every tuple holds the number of fields followed by the fields as length-prefixed values in the
+//! binary send/receive representation of their Postgres type. All integers are big-endian.
+//!
+//! The format does not store the types of the columns, so reading needs a schema. The Postgres
+//! type of every column is derived from its datatype unless it is given explicitly, see
+//! [`PgType`].
+mod read;
+mod types;
+mod write;
+
+pub use read::*;
+pub use types::PgType;
+pub use write::*;
@@ -0,0 +1,623 @@
+use std::fmt::Write;
+
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+
+use super::types::{
+    FLAG_HAS_OIDS, PG_EPOCH_DAYS, PG_EPOCH_MICROS, PgType, SIGNATURE, resolve_pg_types,
+};
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::get_reader_bytes;
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NBASE: i128 = 10_000;
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+macro_rules! invalid_data {
+    ($($arg:tt)*) => {
+        polars_err!(ComputeError: "invalid PostgreSQL binary COPY data: {}", format!($($arg)*))
+    };
+}
+
+/// Reads big-endian values from the bytes of a file or of a single value.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.bytes.len(),
+            ComputeError: "invalid PostgreSQL binary COPY data: unexpected end of data"
+        );
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn i16(&mut self) -> PolarsResult<i16> {
+        Ok(i16::from_be_bytes(self.array()?))
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> PolarsResult<i32> {
+        Ok(i32::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    /// A length-prefixed field, `None` if the field is null.
+    fn field(&mut self) -> PolarsResult<Option<&'a [u8]>> {
+        match self.i32()? {
+            -1 => Ok(None),
+            len if len < 0 => Err(invalid_data!("negative field length {len}")),
+            len => self.take(len as usize).map(Some),
+        }
+    }
+}
+
+/// Parse the header of a file. Returns the cursor at the first tuple and whether every tuple has
+/// an OID field.
+fn read_header(bytes: &[u8]) -> PolarsResult<(Cursor<'_>, bool)> {
+    polars_ensure!(
+        bytes.starts_with(SIGNATURE),
+        ComputeError: "invalid PostgreSQL binary COPY data: missing the file signature"
+    );
+    let mut cursor = Cursor {
+        bytes: &bytes[SIGNATURE.len()..],
+    };
+    let flags = cursor.u32()?;
+    // The lower 16 bits flag critical format changes that a reader must understand.
+    polars_ensure!(
+        flags & 0xFFFF & !FLAG_HAS_OIDS == 0,
+        ComputeError: "invalid PostgreSQL binary COPY data: unsupported header flags {:#x}", flags
+    );
+    let extension_len = cursor.u32()?;
+    cursor.take(extension_len as usize)?;
+    Ok((cursor, flags & FLAG_HAS_OIDS != 0))
+}
+
+fn fixed<const N: usize>(value: &[u8]) -> PolarsResult<[u8; N]> {
+    value
+        .try_into()
+        .map_err(|_| invalid_data!("expected a value of {N} bytes, got {} bytes", value.len()))
+}
+
+/// Decode a `numeric` into a decimal with `scale`, rounding half away from zero.
+fn decode_numeric(value: &[u8], scale: usize) -> PolarsResult<i128> {
+    let mut cursor = Cursor { bytes: value };
+    let ndigits = cursor.i16()?;
+    let weight = cursor.i16()? as i32;
+    let negative = match cursor.u16()? {
+        NUMERIC_POS => false,
+        NUMERIC_NEG => true,
+        _ => polars_bail!(ComputeError: "NaN and infinite numeric values are not supported"),
+    };
+    let _dscale = cursor.i16()?;
+    polars_ensure!(
+        ndigits >= 0,
+        ComputeError: "invalid PostgreSQL binary COPY data: negative numeric digit count"
+    );
+
+    // The digits are in base 10000, the first one has the weight `weight`. Digits below the
+    // first digit that is rounded away don't change the result.
+    let scale = scale as i32;
+    let mut n = 0i128;
+    let mut used = 0;
+    for i in 0..ndigits as i32 {
+        let digit = cursor.u16()?;
+        polars_ensure!(
+            (digit as i128) < NUMERIC_NBASE,
+            ComputeError: "invalid PostgreSQL binary COPY data: numeric digit {} is out of range", digit
+        );
+        if 4 * (weight - i) + 3 < -(scale + 1) {
+            break;
+        }
+        n = n
+            .checked_mul(NUMERIC_NBASE)
+            .and_then(|n| n.checked_add(digit as i128))
+            .ok_or_else(|| polars_err!(ComputeError: "numeric value does not fit in a decimal"))?;
+        used += 1;
+    }
+
+    let exponent = 4 * (weight - used + 1) + scale;
+    let n = if exponent >= 0 {
+        10i128
+            .checked_pow(exponent as u32)
+            .and_then(|factor| n.checked_mul(factor))
+            .ok_or_else(|| polars_err!(ComputeError: "numeric value does not fit in a decimal"))?
+    } else if -exponent > 38 {
+        0
+    } else {
+        let divisor = 10i128.pow((-exponent) as u32);
+        let (quotient, remainder) = (n / divisor, n % divisor);
+        quotient + (remainder >= divisor - remainder) as i128
+    };
+    Ok(if negative { -n } else { n })
+}
+
+fn decode_date(value: &[u8]) -> PolarsResult<i32> {
+    let days = i32::from_be_bytes(fixed(value)?);
+    polars_ensure!(
+        days != i32::MAX && days != i32::MIN,
+        ComputeError: "infinite dates are not supported"
+    );
+    days.checked_add(PG_EPOCH_DAYS)
+        .ok_or_else(|| polars_err!(ComputeError: "date is out of range"))
+}
+
+fn decode_timestamp(value: &[u8]) -> PolarsResult<i64> {
+    let micros = i64::from_be_bytes(fixed(value)?);
+    polars_ensure!(
+        micros != i64::MAX && micros != i64::MIN,
+        ComputeError: "infinite timestamps are not supported"
+    );
+    micros
+        .checked_add(PG_EPOCH_MICROS)
+        .ok_or_else(|| polars_err!(ComputeError: "timestamp is out of range"))
+}
+
+fn decode_interval(value: &[u8]) -> PolarsResult<i64> {
+    let bytes = fixed::<16>(value)?;
+    let mut cursor = Cursor { bytes: &bytes };
+    let micros = i64::from_be_bytes(cursor.array()?);
+    let days = cursor.i32()?;
+    let months = cursor.i32()?;
+    polars_ensure!(
+        months == 0,
+        ComputeError: "intervals with months cannot be read into a duration"
+    );
+    (days as i64)
+        .checked_mul(MICROS_PER_DAY)
+        .and_then(|days| days.checked_add(micros))
+        .ok_or_else(|| polars_err!(ComputeError: "interval is out of range"))
+}
+
+fn decode_str(value: &[u8]) -> PolarsResult<&str> {
+    std::str::from_utf8(value).map_err(|_| invalid_data!("text is not valid UTF-8"))
+}
+
+fn format_uuid(value: &[u8], out: &mut String) -> PolarsResult<()> {
+    let bytes = fixed::<16>(value)?;
+    out.clear();
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        write!(out, "{byte:02x}").unwrap();
+    }
+    Ok(())
+}
+
+/// Decodes the fields of a column into the physical values of its datatype.
+enum ColumnDecoder {
+    Bool(BooleanChunkedBuilder),
+    Int2(PrimitiveChunkedBuilder<Int16Type>),
+    Int4(PrimitiveChunkedBuilder<Int32Type>),
+    Int8(PrimitiveChunkedBuilder<Int64Type>),
+    Float4(PrimitiveChunkedBuilder<Float32Type>),
+    Float8(PrimitiveChunkedBuilder<Float64Type>),
+    Numeric {
+        builder: PrimitiveChunkedBuilder<Int128Type>,
+        precision: Option<usize>,
+        scale: usize,
+    },
+    /// `text` and `json`.
+    Text(StringChunkedBuilder),
+    Jsonb(StringChunkedBuilder),
+    Uuid {
+        builder: StringChunkedBuilder,
+        scratch: String,
+    },
+    Bytea(BinaryChunkedBuilder),
+    Date(PrimitiveChunkedBuilder<Int32Type>),
+    Time(PrimitiveChunkedBuilder<Int64Type>),
+    Timestamp {
+        builder: PrimitiveChunkedBuilder<Int64Type>,
+        tz: Option<TimeZone>,
+    },
+    Interval(PrimitiveChunkedBuilder<Int64Type>),
+    Array {
+        element_type: PgType,
+        values: Box<ColumnDecoder>,
+        offsets: Vec<i64>,
+        validity: MutableBitmap,
+    },
+}
+
+impl ColumnDecoder {
+    fn new(pg_type: &PgType, capacity: usize) -> Self {
+        let name = PlSmallStr::EMPTY;
+        match pg_type {
+            PgType::Bool => Self::Bool(BooleanChunkedBuilder::new(name, capacity)),
+            PgType::Int2 => Self::Int2(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Int4 => Self::Int4(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Int8 => Self::Int8(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Float4 => Self::Float4(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Float8 => Self::Float8(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Numeric { precision, scale } => Self::Numeric {
+                builder: PrimitiveChunkedBuilder::new(name, capacity),
+                precision: *precision,
+                scale: *scale,
+            },
+            PgType::Text | PgType::Json => Self::Text(StringChunkedBuilder::new(name, capacity)),
+            PgType::Jsonb => Self::Jsonb(StringChunkedBuilder::new(name, capacity)),
+            PgType::Uuid => Self::Uuid {
+                builder: StringChunkedBuilder::new(name, capacity),
+                scratch: String::with_capacity(36),
+            },
+            PgType::Bytea => Self::Bytea(BinaryChunkedBuilder::new(name, capacity)),
+            PgType::Date => Self::Date(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Time => Self::Time(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Timestamp | PgType::Timestamptz => Self::Timestamp {
+                builder: PrimitiveChunkedBuilder::new(name, capacity),
+                tz: match pg_type.dtype() {
+                    DataType::Datetime(_, tz) => tz,
+                    _ => unreachable!(),
+                },
+            },
+            PgType::Interval => Self::Interval(PrimitiveChunkedBuilder::new(name, capacity)),
+            PgType::Array(element_type) => {
+                let mut offsets = Vec::with_capacity(capacity + 1);
+                offsets.push(0);
+                Self::Array {
+                    element_type: (**element_type).clone(),
+                    values: Box::new(Self::new(element_type, capacity)),
+                    offsets,
+                    validity: MutableBitmap::with_capacity(capacity),
+                }
+            },
+        }
+    }
+
+    fn push(&mut self, value: Option<&[u8]>) -> PolarsResult<()> {
+        let Some(value) = value else {
+            self.push_null();
+            return Ok(());
+        };
+
+        match self {
+            Self::Bool(b) => b.append_value(fixed::<1>(value)?[0] != 0),
+            Self::Int2(b) => b.append_value(i16::from_be_bytes(fixed(value)?)),
+            Self::Int4(b) => b.append_value(i32::from_be_bytes(fixed(value)?)),
+            Self::Int8(b) => b.append_value(i64::from_be_bytes(fixed(value)?)),
+            Self::Float4(b) => b.append_value(f32::from_be_bytes(fixed(value)?)),
+            Self::Float8(b) => b.append_value(f64::from_be_bytes(fixed(value)?)),
+            Self::Numeric {
+                builder,
+                precision,
+                scale,
+            } => {
+                let v = decode_numeric(value, *scale)?;
+                if let Some(precision) = *precision {
+                    polars_ensure!(
+                        precision >= 39 || v.unsigned_abs() < 10u128.pow(precision as u32),
+                        ComputeError: "numeric value does not fit in a decimal with precision {}", precision
+                    );
+                }
+                builder.append_value(v)
+            },
+            Self::Text(b) => b.append_value(decode_str(value)?),
+            Self::Jsonb(b) => match value.split_first() {
+                Some((1, json)) => b.append_value(decode_str(json)?),
+                _ => return Err(invalid_data!("unsupported jsonb version")),
+            },
+            Self::Uuid { builder, scratch } => {
+                format_uuid(value, scratch)?;
+                builder.append_value(scratch.as_str())
+            },
+            Self::Bytea(b) => b.append_value(value),
+            Self::Date(b) => b.append_value(decode_date(value)?),
+            Self::Time(b) => b.append_value(i64::from_be_bytes(fixed(value)?) * 1000),
+            Self::Timestamp { builder, .. } => builder.append_value(decode_timestamp(value)?),
+            Self::Interval(b) => b.append_value(decode_interval(value)?),
+            Self::Array {
+                element_type,
+                values,
+                offsets,
+                validity,
+            } => {
+                let mut cursor = Cursor { bytes: value };
+                let ndim = cursor.i32()?;
+                let _has_nulls = cursor.i32()?;
+                let oid = cursor.u32()?;
+                let len = match ndim {
+                    0 => 0,
+                    1 => {
+                        let len = cursor.i32()?;
+                        let _lower_bound = cursor.i32()?;
+                        polars_ensure!(
+                            len >= 0,
+                            ComputeError: "invalid PostgreSQL binary COPY data: negative array length"
+                        );
+                        len as i64
+                    },
+                    _ => polars_bail!(ComputeError: "multidimensional arrays are not supported"),
+                };
+                polars_ensure!(
+                    ndim == 0 || element_type.accepts_oid(oid),
+                    SchemaMismatch: "array elements have OID {}, expected elements of type {:?}", oid, element_type
+                );
+                for _ in 0..len {
+                    values.push(cursor.field()?)?;
+                }
+                offsets.push(offsets.last().unwrap() + len);
+                validity.push(true);
+            },
+        }
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        match self {
+            Self::Bool(b) => b.append_null(),
+            Self::Int2(b) => b.append_null(),
+            Self::Int4(b) | Self::Date(b) => b.append_null(),
+            Self::Int8(b) | Self::Time(b) | Self::Interval(b) => b.append_null(),
+            Self::Timestamp { builder, .. } => builder.append_null(),
+            Self::Float4(b) => b.append_null(),
+            Self::Float8(b) => b.append_null(),
+            Self::Numeric { builder, .. } => builder.append_null(),
+            Self::Text(b) | Self::Jsonb(b) => b.append_null(),
+            Self::Uuid { builder, .. } => builder.append_null(),
+            Self::Bytea(b) => b.append_null(),
+            Self::Array {
+                offsets, validity, ..
+            } => {
+                offsets.push(*offsets.last().unwrap());
+                validity.push(false);
+            },
+        }
+    }
+
+    fn finish(self, name: PlSmallStr) -> PolarsResult<Series> {
+        let s = match self {
+            Self::Bool(b) => b.finish().into_series(),
+            Self::Int2(b) => b.finish().into_series(),
+            Self::Int4(b) => b.finish().into_series(),
+            Self::Int8(b) => b.finish().into_series(),
+            Self::Float4(b) => b.finish().into_series(),
+            Self::Float8(b) => b.finish().into_series(),
+            Self::Numeric {
+                builder,
+                precision,
+                scale,
+            } => builder
+                .finish()
+                .into_decimal_unchecked(Some(precision.unwrap_or(38)), scale)
+                .into_series(),
+            Self::Text(b) | Self::Jsonb(b) | Self::Uuid { builder: b, .. } => {
+                b.finish().into_series()
+            },
+            Self::Bytea(b) => b.finish().into_series(),
+            Self::Date(b) => b.finish().into_date().into_series(),
+            Self::Time(b) => b.finish().into_time().into_series(),
+            Self::Timestamp { builder, tz } => builder
+                .finish()
+                .into_datetime(TimeUnit::Microseconds, tz)
+                .into_series(),
+            Self::Interval(b) => b
+                .finish()
+                .into_duration(TimeUnit::Microseconds)
+                .into_series(),
+            Self::Array {
+                values,
+                offsets,
+                validity,
+                ..
+            } => {
+                let values = values
+                    .finish(PlSmallStr::EMPTY)?
+                    .rechunk()
+                    .to_arrow(0, CompatLevel::newest());
+                let arr = LargeListArray::try_new(
+                    LargeListArray::default_datatype(values.dtype().clone()),
+                    OffsetsBuffer::try_from(offsets)?,
+                    values,
+                    validity.into(),
+                )?;
+                Series::from_arrow(PlSmallStr::EMPTY, arr.boxed())?
+            },
+        };
+        Ok(s.with_name(name))
+    }
+}
+
+/// Read the tuples of a PostgreSQL binary `COPY` file with the columns of `schema`.
+///
+/// `pg_types` are the Postgres types of the columns of `schema` and `projection` the sorted
+/// indices of the columns to read. Columns are read into the datatype of their Postgres type and
+/// cast to the datatype of the schema.
+pub fn read_pg_copy(
+    bytes: &[u8],
+    schema: &Schema,
+    pg_types: &[(PlSmallStr, PgType)],
+    projection: Option<&[usize]>,
+    n_rows: Option<usize>,
+) -> PolarsResult<DataFrame> {
+    let pg_types = resolve_pg_types(schema, pg_types)?;
+    let (mut cursor, has_oids) = read_header(bytes)?;
+
+    let capacity = n_rows.unwrap_or(0).min(1 << 16);
+    let mut decoders = (0..schema.len())
+        .map(|_| None)
+        .collect::<Vec<Option<ColumnDecoder>>>();
+    match projection {
+        Some(projection) => {
+            for &i in projection {
+                decoders[i] = Some(ColumnDecoder::new(&pg_types[i], capacity));
+            }
+        },
+        None => {
+            for (decoder, pg_type) in decoders.iter_mut().zip(&pg_types) {
+                *decoder = Some(ColumnDecoder::new(pg_type, capacity));
+            }
+        },
+    }
+
+    let n_rows = n_rows.unwrap_or(usize::MAX);
+    let mut height = 0;
+    while height < n_rows {
+        let n_fields = cursor.i16()?;
+        if n_fields == -1 {
+            break;
+        }
+        polars_ensure!(
+            n_fields as usize == schema.len(),
+            SchemaMismatch: "PostgreSQL binary COPY tuple has {} fields, but the schema has {} columns",
+            n_fields, schema.len()
+        );
+        if has_oids {
+            cursor.field()?;
+        }
+        for (decoder, name) in decoders.iter_mut().zip(schema.iter_names()) {
+            let value = cursor.field()?;
+            if let Some(decoder) = decoder {
+                decoder
+                    .push(value)
+                    .map_err(|e| e.wrap_msg(|msg| format!("column '{name}': {msg}")))?;
+            }
+        }
+        height += 1;
+    }
+
+    let columns = decoders
+        .into_iter()
+        .zip(schema.iter())
+        .filter_map(|(decoder, (name, dtype))| {
+            let s = decoder?.finish(name.clone());
+            Some(s.and_then(|s| {
+                if s.dtype() == dtype {
+                    Ok(s.into_column())
+                } else {
+                    s.strict_cast(dtype).map(Column::from)
+                }
+            }))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if columns.is_empty() {
+        return Ok(DataFrame::empty_with_height(height));
+    }
+    DataFrame::new(columns)
+}
+
+/// Read the PostgreSQL binary `COPY` format into a [`DataFrame`].
+///
+/// The format does not store the types of the columns, so the schema has to be given. The
+/// Postgres type of every column is derived from its datatype, unless it is set with
+/// [`PgCopyReader::with_pg_types`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(schema: SchemaRef) -> PolarsResult<DataFrame> {
+///     let file = File::open("table.bin")?;
+///     PgCopyReader::new(file).with_schema(schema).finish()
+/// }
+/// ```
+#[must_use]
+pub struct PgCopyReader<R: MmapBytesReader> {
+    reader: R,
+    schema: Option<SchemaRef>,
+    pg_types: Vec<(PlSmallStr, PgType)>,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<R: MmapBytesReader> PgCopyReader<R> {
+    /// Set the schema of the file.
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Set the Postgres types of columns whose type differs from the type derived from their
+    /// datatype, e.g. [`PgType::Uuid`] for a string column.
+    pub fn with_pg_types(mut self, pg_types: Vec<(PlSmallStr, PgType)>) -> Self {
+        self.pg_types = pg_types;
+        self
+    }
+
+    /// Stop reading when `n` rows are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for PgCopyReader<R> {
+    fn new(reader: R) -> Self {
+        PgCopyReader {
+            reader,
+            schema: None,
+            pg_types: vec![],
+            n_rows: None,
+            columns: None,
+            projection: None,
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let Some(schema) = self.schema else {
+            polars_bail!(
+                InvalidOperation: "reading the PostgreSQL binary COPY format requires a schema"
+            )
+        };
+        if let Some(columns) = &self.columns {
+            self.projection = Some(
+                columns
+                    .iter()
+                    .map(|name| schema.try_index_of(name))
+                    .collect::<PolarsResult<_>>()?,
+            );
+        }
+        if let Some(projection) = &mut self.projection {
+            projection.sort_unstable();
+            if let Some(&i) = projection.last() {
+                polars_ensure!(
+                    i < schema.len(),
+                    OutOfBounds: "projection index {} is out of bounds for {} columns", i, schema.len()
+                );
+            }
+        }
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        read_pg_copy(
+            &reader_bytes,
+            &schema,
+            &self.pg_types,
+            self.projection.as_deref(),
+            self.n_rows,
+        )
+    }
+}
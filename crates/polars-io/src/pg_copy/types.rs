@@ -0,0 +1,185 @@
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The signature at the start of every file, followed by the flags and the header extension.
+pub(super) const SIGNATURE: &[u8; 11] = b"PGCOPY\n\xff\r\n\0";
+
+/// Bit of the header flags that is set if every tuple has an OID field.
+pub(super) const FLAG_HAS_OIDS: u32 = 1 << 16;
+
+/// Days between the unix epoch and 2000-01-01, the epoch of Postgres dates and timestamps.
+pub(super) const PG_EPOCH_DAYS: i32 = 10_957;
+
+/// Microseconds between the unix epoch and 2000-01-01.
+pub(super) const PG_EPOCH_MICROS: i64 = PG_EPOCH_DAYS as i64 * 86_400_000_000;
+
+/// The type of a column in the PostgreSQL binary `COPY` format.
+///
+/// The format does not describe the types of its columns, so they have to be given when reading.
+/// Types that share their binary representation, like `varchar` and `text`, map to the same
+/// variant.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PgType {
+    Bool,
+    Int2,
+    Int4,
+    Int8,
+    Float4,
+    Float8,
+    /// `numeric`, read into a decimal with this scale. Values with more fractional digits are
+    /// rounded. `NaN` and infinite values are not supported.
+    Numeric {
+        precision: Option<usize>,
+        scale: usize,
+    },
+    /// `text`, `varchar`, `bpchar` and `name`.
+    Text,
+    Bytea,
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    /// `interval`, read into a duration. Intervals with months are not supported, as months have
+    /// no fixed length.
+    Interval,
+    /// `uuid`, read into its canonical string form.
+    Uuid,
+    Json,
+    Jsonb,
+    /// A one-dimensional array of the element type.
+    Array(Box<PgType>),
+}
+
+impl PgType {
+    /// The Postgres type of a column of `dtype` if it is not given.
+    pub fn from_dtype(dtype: &DataType) -> PolarsResult<Self> {
+        use DataType as D;
+        Ok(match dtype {
+            D::Boolean => Self::Bool,
+            D::Int8 | D::Int16 | D::UInt8 => Self::Int2,
+            D::Int32 | D::UInt16 => Self::Int4,
+            D::Int64 | D::UInt32 => Self::Int8,
+            D::UInt64 => Self::Numeric {
+                precision: Some(20),
+                scale: 0,
+            },
+            D::Float32 => Self::Float4,
+            D::Float64 => Self::Float8,
+            D::Decimal(precision, scale) => Self::Numeric {
+                precision: *precision,
+                scale: scale.unwrap_or(0),
+            },
+            D::String | D::Null => Self::Text,
+            #[cfg(feature = "dtype-categorical")]
+            D::Categorical(_, _) | D::Enum(_, _) => Self::Text,
+            D::Binary => Self::Bytea,
+            D::Date => Self::Date,
+            D::Time => Self::Time,
+            D::Datetime(_, None) => Self::Timestamp,
+            D::Datetime(_, Some(_)) => Self::Timestamptz,
+            D::Duration(_) => Self::Interval,
+            D::List(inner) => Self::Array(Box::new(Self::from_dtype(inner)?)),
+            #[cfg(feature = "dtype-array")]
+            D::Array(inner, _) => Self::Array(Box::new(Self::from_dtype(inner)?)),
+            dt => polars_bail!(
+                InvalidOperation: "datatype {} cannot be written to the PostgreSQL binary COPY format", dt
+            ),
+        })
+    }
+
+    /// The datatype that values of this type are read into.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Self::Bool => DataType::Boolean,
+            Self::Int2 => DataType::Int16,
+            Self::Int4 => DataType::Int32,
+            Self::Int8 => DataType::Int64,
+            Self::Float4 => DataType::Float32,
+            Self::Float8 => DataType::Float64,
+            Self::Numeric { precision, scale } => {
+                DataType::Decimal(Some(precision.unwrap_or(38)), Some(*scale))
+            },
+            Self::Text | Self::Uuid | Self::Json | Self::Jsonb => DataType::String,
+            Self::Bytea => DataType::Binary,
+            Self::Date => DataType::Date,
+            Self::Time => DataType::Time,
+            Self::Timestamp => DataType::Datetime(TimeUnit::Microseconds, None),
+            Self::Timestamptz => {
+                DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+            },
+            Self::Interval => DataType::Duration(TimeUnit::Microseconds),
+            Self::Array(inner) => DataType::List(Box::new(inner.dtype())),
+        }
+    }
+
+    /// The OID of the type, which is stored in the header of arrays.
+    pub(super) fn oid(&self) -> u32 {
+        match self {
+            Self::Bool => 16,
+            Self::Bytea => 17,
+            Self::Int8 => 20,
+            Self::Int2 => 21,
+            Self::Int4 => 23,
+            Self::Text => 25,
+            Self::Json => 114,
+            Self::Float4 => 700,
+            Self::Float8 => 701,
+            Self::Date => 1082,
+            Self::Time => 1083,
+            Self::Timestamp => 1114,
+            Self::Timestamptz => 1184,
+            Self::Interval => 1186,
+            Self::Numeric { .. } => 1700,
+            Self::Uuid => 2950,
+            Self::Jsonb => 3802,
+            Self::Array(_) => {
+                unreachable!(
+                    "impl error: nested arrays should be rejected when resolving the types"
+                )
+            },
+        }
+    }
+
+    /// Whether values of this type are read and written as values of type `oid`. Arrays store the
+    /// OID of their elements, text-like types are interchangeable.
+    pub(super) fn accepts_oid(&self, oid: u32) -> bool {
+        const VARCHAR: u32 = 1043;
+        const BPCHAR: u32 = 1042;
+        const NAME: u32 = 19;
+
+        match self {
+            Self::Text => matches!(oid, 25 | VARCHAR | BPCHAR | NAME),
+            _ => self.oid() == oid,
+        }
+    }
+}
+
+/// Resolve the Postgres types of the columns of `schema`, with the types in `pg_types` taking
+/// precedence over the types derived from the datatypes.
+pub(super) fn resolve_pg_types(
+    schema: &Schema,
+    pg_types: &[(PlSmallStr, PgType)],
+) -> PolarsResult<Vec<PgType>> {
+    for (name, _) in pg_types {
+        schema.try_index_of(name)?;
+    }
+
+    schema
+        .iter()
+        .map(|(name, dtype)| {
+            let pg_type = match pg_types.iter().find(|(n, _)| n == name) {
+                Some((_, pg_type)) => pg_type.clone(),
+                None => PgType::from_dtype(dtype)?,
+            };
+            if let PgType::Array(inner) = &pg_type {
+                polars_ensure!(
+                    !matches!(**inner, PgType::Array(_)),
+                    InvalidOperation: "column '{}': multidimensional arrays are not supported", name
+                );
+            }
+            Ok(pg_type)
+        })
+        .collect()
+}
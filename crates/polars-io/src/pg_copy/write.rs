@@ -0,0 +1,474 @@
+use std::borrow::Cow;
+use std::io::Write;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::types::{PG_EPOCH_DAYS, PG_EPOCH_MICROS, PgType, SIGNATURE, resolve_pg_types};
+use crate::shared::SerWriter;
+
+/// Number of rows that [`PgCopyWriter`] encodes at once.
+const WRITE_BATCH_SIZE: usize = 1 << 16;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PgCopyWriterOptions {
+    /// Postgres types of columns whose type differs from the type derived from their datatype.
+    pub pg_types: Vec<(PlSmallStr, PgType)>,
+}
+
+impl PgCopyWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> PgCopyWriter<W> {
+        PgCopyWriter::new(writer).with_pg_types(self.pg_types.clone())
+    }
+}
+
+/// Write a [`DataFrame`] in the PostgreSQL binary `COPY` format, which can be loaded with
+/// `COPY table FROM ... WITH (FORMAT binary)`.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::pg_copy::PgCopyWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("table.bin").expect("could not create file");
+///
+///     PgCopyWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct PgCopyWriter<W> {
+    writer: W,
+    pg_types: Vec<(PlSmallStr, PgType)>,
+}
+
+impl<W> PgCopyWriter<W>
+where
+    W: Write,
+{
+    /// Set the Postgres types of columns whose type differs from the type derived from their
+    /// datatype, e.g. [`PgType::Jsonb`] for a string column.
+    pub fn with_pg_types(mut self, pg_types: Vec<(PlSmallStr, PgType)>) -> Self {
+        self.pg_types = pg_types;
+        self
+    }
+
+    /// Write the file header and return a writer that can write [`DataFrame`]s batch by batch.
+    pub fn batched(mut self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let encoder = PgCopyEncoder::new(schema, &self.pg_types)?;
+        encoder.write_header(&mut self.writer)?;
+
+        Ok(BatchedWriter {
+            writer: self.writer,
+            encoder,
+            buf: vec![],
+        })
+    }
+}
+
+impl<W> SerWriter<W> for PgCopyWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            pg_types: vec![],
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut encoder = PgCopyEncoder::new(df.schema(), &self.pg_types)?;
+        encoder.write_header(&mut self.writer)?;
+
+        let mut buf = vec![];
+        for offset in (0..df.height()).step_by(WRITE_BATCH_SIZE) {
+            buf.clear();
+            encoder.write_rows(&df.slice(offset as i64, WRITE_BATCH_SIZE), &mut buf)?;
+            self.writer.write_all(&buf)?;
+        }
+
+        encoder.write_trailer(&mut self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    encoder: PgCopyEncoder,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.buf.clear();
+        self.encoder.write_rows(df, &mut self.buf)?;
+        self.writer.write_all(&self.buf)?;
+        Ok(())
+    }
+
+    /// Write the trailer and flush the underlying writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.encoder.write_trailer(&mut self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Length-prefixed fields of a column, one per row.
+#[derive(Clone, Default)]
+struct Fields {
+    buf: Vec<u8>,
+    /// End of the field of every row in `buf`.
+    ends: Vec<usize>,
+}
+
+impl Fields {
+    fn clear(&mut self) {
+        self.buf.clear();
+        self.ends.clear();
+    }
+
+    fn get(&self, i: usize) -> &[u8] {
+        let start = i.checked_sub(1).map_or(0, |i| self.ends[i]);
+        &self.buf[start..self.ends[i]]
+    }
+
+    fn push_null(&mut self) {
+        self.buf.extend_from_slice(&(-1i32).to_be_bytes());
+        self.ends.push(self.buf.len());
+    }
+
+    /// Fill in the length of the field that starts at `start`.
+    fn finish_field(&mut self, start: usize) {
+        let len = self.buf.len() - start - 4;
+        self.buf[start..start + 4].copy_from_slice(&(len as i32).to_be_bytes());
+        self.ends.push(self.buf.len());
+    }
+
+    fn push_opt<T>(&mut self, value: Option<T>, encode: impl FnOnce(T, &mut Vec<u8>)) {
+        match value {
+            None => self.push_null(),
+            Some(value) => {
+                let start = self.buf.len();
+                self.buf.extend_from_slice(&[0; 4]);
+                encode(value, &mut self.buf);
+                self.finish_field(start);
+            },
+        }
+    }
+
+    fn try_push_opt<T>(
+        &mut self,
+        value: Option<T>,
+        encode: impl FnOnce(T, &mut Vec<u8>) -> PolarsResult<()>,
+    ) -> PolarsResult<()> {
+        match value {
+            None => {
+                self.push_null();
+                Ok(())
+            },
+            Some(value) => {
+                let start = self.buf.len();
+                self.buf.extend_from_slice(&[0; 4]);
+                encode(value, &mut self.buf)?;
+                polars_ensure!(
+                    self.buf.len() - start - 4 <= i32::MAX as usize,
+                    ComputeError: "value is too large for the PostgreSQL binary COPY format"
+                );
+                self.finish_field(start);
+                Ok(())
+            },
+        }
+    }
+}
+
+/// The datatype that a column of `dtype` is cast to before it is encoded as `pg_type`.
+fn encode_dtype(pg_type: &PgType, dtype: &DataType) -> DataType {
+    match (pg_type, dtype) {
+        // The physical values are in UTC for every time zone.
+        (PgType::Timestamptz, DataType::Datetime(_, Some(tz))) => {
+            DataType::Datetime(TimeUnit::Microseconds, Some(tz.clone()))
+        },
+        (PgType::Array(inner), DataType::List(inner_dtype)) => {
+            DataType::List(Box::new(encode_dtype(inner, inner_dtype)))
+        },
+        #[cfg(feature = "dtype-array")]
+        (PgType::Array(inner), DataType::Array(inner_dtype, _)) => {
+            DataType::List(Box::new(encode_dtype(inner, inner_dtype)))
+        },
+        _ => pg_type.dtype(),
+    }
+}
+
+/// Encode a decimal with `scale` as a `numeric`.
+fn encode_numeric(v: i128, scale: usize, buf: &mut Vec<u8>) {
+    const NUMERIC_NEG: u16 = 0x4000;
+
+    // Base-10000 digits, least significant first. The fraction is padded to whole digits.
+    let mut digits = vec![];
+    let mut n = v.unsigned_abs();
+    let partial = scale % 4;
+    if partial != 0 {
+        let divisor = 10u128.pow(partial as u32);
+        digits.push(((n % divisor) * 10u128.pow(4 - partial as u32)) as u16);
+        n /= divisor;
+    }
+    while n > 0 {
+        digits.push((n % 10_000) as u16);
+        n /= 10_000;
+    }
+
+    let mut weight = digits.len() as i32 - scale.div_ceil(4) as i32 - 1;
+    while digits.last() == Some(&0) {
+        digits.pop();
+        weight -= 1;
+    }
+    let first_nonzero = digits.iter().position(|&d| d != 0).unwrap_or(digits.len());
+    digits.drain(..first_nonzero);
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let sign = if v < 0 { NUMERIC_NEG } else { 0 };
+    buf.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    buf.extend_from_slice(&(weight as i16).to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&(scale as i16).to_be_bytes());
+    for digit in digits.iter().rev() {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+}
+
+fn encode_uuid(s: &str, buf: &mut Vec<u8>) -> PolarsResult<()> {
+    let invalid = || polars_err!(ComputeError: "'{}' is not a valid UUID", s);
+
+    let mut nibbles = s
+        .bytes()
+        .filter(|&b| b != b'-')
+        .map(|b| (b as char).to_digit(16).map(|d| d as u8));
+    let mut n = 0;
+    while let Some(high) = nibbles.next() {
+        let low = nibbles.next().flatten().ok_or_else(invalid)?;
+        buf.push((high.ok_or_else(invalid)? << 4) | low);
+        n += 1;
+    }
+    polars_ensure!(n == 16, ComputeError: "'{}' is not a valid UUID", s);
+    Ok(())
+}
+
+/// Encode every value of `s`, which has the datatype of [`encode_dtype`], as a field of
+/// `pg_type`.
+fn encode_series(s: &Series, pg_type: &PgType, fields: &mut Fields) -> PolarsResult<()> {
+    fn out_of_range(what: &str) -> PolarsError {
+        polars_err!(ComputeError: "{} is out of range for the PostgreSQL binary COPY format", what)
+    }
+
+    match pg_type {
+        PgType::Bool => {
+            for v in s.bool()? {
+                fields.push_opt(v, |v, buf| buf.push(v as u8));
+            }
+        },
+        PgType::Int2 => {
+            for v in s.i16()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&v.to_be_bytes()));
+            }
+        },
+        PgType::Int4 => {
+            for v in s.i32()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&v.to_be_bytes()));
+            }
+        },
+        PgType::Int8 => {
+            for v in s.i64()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&v.to_be_bytes()));
+            }
+        },
+        PgType::Float4 => {
+            for v in s.f32()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&v.to_be_bytes()));
+            }
+        },
+        PgType::Float8 => {
+            for v in s.f64()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&v.to_be_bytes()));
+            }
+        },
+        PgType::Numeric { scale, .. } => {
+            for v in s.decimal()?.physical() {
+                fields.push_opt(v, |v, buf| encode_numeric(v, *scale, buf));
+            }
+        },
+        PgType::Text | PgType::Json => {
+            for v in s.str()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(v.as_bytes()));
+            }
+        },
+        PgType::Jsonb => {
+            for v in s.str()? {
+                fields.push_opt(v, |v, buf| {
+                    buf.push(1);
+                    buf.extend_from_slice(v.as_bytes());
+                });
+            }
+        },
+        PgType::Uuid => {
+            for v in s.str()? {
+                fields.try_push_opt(v, encode_uuid)?;
+            }
+        },
+        PgType::Bytea => {
+            for v in s.binary()? {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(v));
+            }
+        },
+        PgType::Date => {
+            for v in s.date()?.physical() {
+                fields.try_push_opt(v, |v, buf| {
+                    let days = v
+                        .checked_sub(PG_EPOCH_DAYS)
+                        .ok_or_else(|| out_of_range("date"))?;
+                    buf.extend_from_slice(&days.to_be_bytes());
+                    Ok(())
+                })?;
+            }
+        },
+        PgType::Time => {
+            for v in s.time()?.physical() {
+                fields.push_opt(v, |v, buf| buf.extend_from_slice(&(v / 1000).to_be_bytes()));
+            }
+        },
+        PgType::Timestamp | PgType::Timestamptz => {
+            for v in s.datetime()?.physical() {
+                fields.try_push_opt(v, |v, buf| {
+                    let micros = v
+                        .checked_sub(PG_EPOCH_MICROS)
+                        .ok_or_else(|| out_of_range("timestamp"))?;
+                    buf.extend_from_slice(&micros.to_be_bytes());
+                    Ok(())
+                })?;
+            }
+        },
+        PgType::Interval => {
+            for v in s.duration()?.physical() {
+                fields.push_opt(v, |v, buf| {
+                    // Microseconds, days and months.
+                    buf.extend_from_slice(&v.to_be_bytes());
+                    buf.extend_from_slice(&[0; 8]);
+                });
+            }
+        },
+        PgType::Array(element_type) => {
+            let oid = element_type.oid();
+            let mut elements = Fields::default();
+            for v in s.list()?.amortized_iter() {
+                fields.try_push_opt(v, |v, buf| {
+                    let v = v.as_ref();
+                    elements.clear();
+                    encode_series(v, element_type, &mut elements)?;
+
+                    // Empty arrays have no dimensions.
+                    let ndim = (!v.is_empty()) as i32;
+                    buf.extend_from_slice(&ndim.to_be_bytes());
+                    buf.extend_from_slice(&((v.null_count() > 0) as i32).to_be_bytes());
+                    buf.extend_from_slice(&oid.to_be_bytes());
+                    if ndim == 1 {
+                        let len = i32::try_from(v.len()).map_err(|_| out_of_range("array"))?;
+                        buf.extend_from_slice(&len.to_be_bytes());
+                        buf.extend_from_slice(&1i32.to_be_bytes());
+                    }
+                    buf.extend_from_slice(&elements.buf);
+                    Ok(())
+                })?;
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Encodes [`DataFrame`]s into the tuples of a PostgreSQL binary `COPY` file of a fixed schema.
+///
+/// The header, the tuples and the trailer can be written separately, which allows encoding
+/// tuples in parallel and concatenating them afterwards.
+#[derive(Clone)]
+pub struct PgCopyEncoder {
+    pg_types: Vec<PgType>,
+
+    // Scratch space, re-used between batches.
+    columns: Vec<Fields>,
+}
+
+impl PgCopyEncoder {
+    pub fn new(schema: &Schema, pg_types: &[(PlSmallStr, PgType)]) -> PolarsResult<Self> {
+        let pg_types = resolve_pg_types(schema, pg_types)?;
+        polars_ensure!(
+            pg_types.len() <= i16::MAX as usize,
+            InvalidOperation: "the PostgreSQL binary COPY format supports at most {} columns", i16::MAX
+        );
+
+        Ok(Self {
+            columns: vec![Fields::default(); pg_types.len()],
+            pg_types,
+        })
+    }
+
+    /// Write the signature, the flags and an empty header extension.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        writer.write_all(SIGNATURE)?;
+        writer.write_all(&0u32.to_be_bytes())?;
+        writer.write_all(&0u32.to_be_bytes())?;
+        Ok(())
+    }
+
+    /// Encode the rows of `df` as tuples and append them to `buf`.
+    pub fn write_rows(&mut self, df: &DataFrame, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        polars_ensure!(
+            df.width() == self.pg_types.len(),
+            ShapeMismatch: "cannot write a DataFrame with {} columns to a PostgreSQL binary COPY file with {} columns",
+            df.width(), self.pg_types.len()
+        );
+
+        for ((column, pg_type), fields) in df
+            .get_columns()
+            .iter()
+            .zip(&self.pg_types)
+            .zip(&mut self.columns)
+        {
+            let s = column.as_materialized_series();
+            let dtype = encode_dtype(pg_type, s.dtype());
+            let s = if s.dtype() == &dtype {
+                Cow::Borrowed(s)
+            } else {
+                Cow::Owned(s.strict_cast(&dtype)?)
+            };
+
+            fields.clear();
+            encode_series(&s, pg_type, fields)
+                .map_err(|e| e.wrap_msg(|msg| format!("column '{}': {msg}", s.name())))?;
+        }
+
+        let n_fields = (self.pg_types.len() as i16).to_be_bytes();
+        for i in 0..df.height() {
+            buf.extend_from_slice(&n_fields);
+            for fields in &self.columns {
+                buf.extend_from_slice(fields.get(i));
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the trailer that marks the end of the tuples.
+    pub fn write_trailer<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        writer.write_all(&(-1i16).to_be_bytes())?;
+        Ok(())
+    }
+}
@@ -458,6 +458,10 @@ pub struct ScanIOPredicate {
 
     /// A predicate that gets given statistics and evaluates whether a batch can be skipped.
     pub column_predicates: Arc<ColumnPredicates>,
+
+    /// Sets of values per column of which the column has to equal one for a row to pass the
+    /// predicate. This is used to skip batches with membership filters (e.g. bloom filters).
+    pub column_value_sets: Arc<PlHashMap<PlSmallStr, Series>>,
 }
 impl ScanIOPredicate {
     pub fn set_external_constant_columns(&mut self, constant_columns: Vec<(PlSmallStr, Scalar)>) {
@@ -496,6 +500,17 @@ impl ScanIOPredicate {
         }
         self.column_predicates = Arc::new(column_predicates);
 
+        if constant_columns
+            .iter()
+            .any(|(c, _)| self.column_value_sets.contains_key(c))
+        {
+            let mut column_value_sets = self.column_value_sets.as_ref().clone();
+            for (c, _) in constant_columns.iter() {
+                column_value_sets.remove(c);
+            }
+            self.column_value_sets = Arc::new(column_value_sets);
+        }
+
         self.predicate = Arc::new(PhysicalExprWithConstCols {
             constants: constant_columns,
             child: self.predicate.clone(),
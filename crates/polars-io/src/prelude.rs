@@ -1,16 +1,25 @@
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
+#[cfg(feature = "fwf")]
+pub use crate::fwf::{FwfColumn, FwfOffsetUnit, FwfReadOptions, FwfReader};
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
+#[cfg(feature = "lines")]
+pub use crate::lines::{LinesReadOptions, LinesReader, UnmatchedLines};
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
 #[cfg(feature = "parquet")]
-pub use crate::parquet::{metadata::*, read::*, write::*};
+pub use crate::parquet::encryption::{ParquetEncryptionOptions, ParquetKeyRetriever};
+#[cfg(feature = "parquet")]
+pub use crate::parquet::{field_id::ParquetFieldId, metadata::*, read::*, write::*};
 #[cfg(feature = "parquet")]
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
+#[cfg(feature = "pg_copy")]
+pub use crate::pg_copy::{PgCopyReader, PgCopyWriter, PgCopyWriterOptions, PgType};
 pub use crate::shared::{SerReader, SerWriter};
+pub use crate::utils::compression::ExternalCompression;
 pub use crate::utils::*;
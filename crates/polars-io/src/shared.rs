@@ -7,7 +7,12 @@ use polars_core::prelude::*;
 
 use crate::cloud::CloudOptions;
 use crate::options::RowIndex;
-#[cfg(any(feature = "ipc", feature = "avro", feature = "ipc_streaming",))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "avro",
+    feature = "ipc_streaming",
+    feature = "orc"
+))]
 use crate::predicates::PhysicalIoExpr;
 
 pub trait SerReader<R>
@@ -54,7 +59,12 @@ pub trait ArrowReader {
     fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>>;
 }
 
-#[cfg(any(feature = "ipc", feature = "avro", feature = "ipc_streaming",))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "avro",
+    feature = "ipc_streaming",
+    feature = "orc"
+))]
 pub(crate) fn finish_reader<R: ArrowReader>(
     mut reader: R,
     rechunk: bool,
@@ -1,7 +1,11 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
+use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
@@ -59,3 +63,165 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Compression of the complete output of a text format writer, such as CSV or NDJSON.
+///
+/// The output is written as a sequence of independently compressed blocks (gzip members or zstd
+/// frames), so that the blocks can be compressed in parallel. Decoders read such a concatenation
+/// as a single stream.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    /// Gzip with a level between 0 and 9. Defaults to 6.
+    Gzip { level: Option<u32> },
+    /// Zstandard with a level in `zstd::compression_level_range()`. Defaults to 3.
+    Zstd { level: Option<i32> },
+}
+
+impl ExternalCompression {
+    pub fn is_compressed(self) -> bool {
+        !matches!(self, Self::Uncompressed)
+    }
+
+    /// Check that the compression level is valid.
+    pub fn check(self) -> PolarsResult<()> {
+        match self {
+            Self::Uncompressed => {},
+            Self::Gzip { level } => {
+                if let Some(level) = level {
+                    polars_ensure!(
+                        level <= 9,
+                        InvalidOperation: "invalid gzip compression level {}, expected a value between 0 and 9", level
+                    );
+                }
+            },
+            Self::Zstd { level } => {
+                if let Some(level) = level {
+                    feature_gated!("compress", {
+                        let range = zstd::compression_level_range();
+                        polars_ensure!(
+                            range.contains(&level),
+                            InvalidOperation: "invalid zstd compression level {}, expected a value between {} and {}",
+                            level, range.start(), range.end()
+                        );
+                    })
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Compress `bytes` into a self-contained block and append it to `out`.
+    pub fn compress_block(self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        self.check()?;
+
+        match self {
+            Self::Uncompressed => out.extend_from_slice(bytes),
+            Self::Gzip { level } => feature_gated!("compress", {
+                let level = flate2::Compression::new(level.unwrap_or(6));
+                let mut encoder = flate2::write::GzEncoder::new(out, level);
+                encoder.write_all(bytes)?;
+                encoder.finish()?;
+            }),
+            Self::Zstd { level } => feature_gated!("compress", {
+                let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                zstd::stream::copy_encode(bytes, out, level)?;
+            }),
+        }
+
+        Ok(())
+    }
+}
+
+/// Size of the blocks that are compressed independently by [`CompressedWriter`].
+const COMPRESSION_BLOCK_SIZE: usize = 1 << 20;
+
+/// A writer that compresses everything written to it with [`ExternalCompression`].
+///
+/// Data is buffered until there is a block for every thread in the [`POOL`], these blocks are then
+/// compressed in parallel. [`CompressedWriter::finish`] must be called to write the remainder.
+pub struct CompressedWriter<W: Write> {
+    inner: W,
+    compression: ExternalCompression,
+    buffer: Vec<u8>,
+    has_written_block: bool,
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(inner: W, compression: ExternalCompression) -> Self {
+        Self {
+            inner,
+            compression,
+            buffer: vec![],
+            has_written_block: false,
+        }
+    }
+
+    fn write_blocks(&mut self, n_bytes: usize) -> PolarsResult<()> {
+        let compression = self.compression;
+        let compress = |block: &[u8]| {
+            let mut out = Vec::with_capacity(block.len() / 2);
+            compression.compress_block(block, &mut out)?;
+            Ok(out)
+        };
+
+        let bytes = &self.buffer[..n_bytes];
+        let blocks = if bytes.len() <= COMPRESSION_BLOCK_SIZE {
+            bytes
+                .chunks(COMPRESSION_BLOCK_SIZE)
+                .map(compress)
+                .collect::<PolarsResult<Vec<_>>>()?
+        } else {
+            POOL.install(|| {
+                bytes
+                    .par_chunks(COMPRESSION_BLOCK_SIZE)
+                    .map(compress)
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        };
+
+        for block in &blocks {
+            self.inner.write_all(block)?;
+        }
+        self.buffer.drain(..n_bytes);
+        self.has_written_block |= !blocks.is_empty();
+        Ok(())
+    }
+
+    /// Compress and write the buffered data. If nothing was written at all an empty block is
+    /// written, so that the output is always a valid compressed stream.
+    pub fn finish(mut self) -> PolarsResult<W> {
+        if self.compression.is_compressed() {
+            self.write_blocks(self.buffer.len())?;
+            if !self.has_written_block {
+                let mut out = vec![];
+                self.compression.compress_block(&[], &mut out)?;
+                self.inner.write_all(&out)?;
+            }
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if !self.compression.is_compressed() {
+            return self.inner.write(buf);
+        }
+
+        self.buffer.extend_from_slice(buf);
+        let batch_size = COMPRESSION_BLOCK_SIZE * POOL.current_num_threads();
+        if self.buffer.len() >= batch_size {
+            let n_bytes = self.buffer.len() / batch_size * batch_size;
+            self.write_blocks(n_bytes).map_err(std::io::Error::other)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
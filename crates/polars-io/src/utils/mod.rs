@@ -6,6 +6,8 @@ pub use other::*;
 pub mod byte_source;
 pub mod file;
 pub mod mkdir;
+#[cfg(feature = "csv")]
+pub mod rejected_rows;
 pub mod slice;
 pub mod sync_on_close;
 
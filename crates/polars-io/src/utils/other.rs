@@ -48,7 +48,8 @@ pub fn get_reader_bytes<R: Read + MmapBytesReader + ?Sized>(
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "parquet",
-    feature = "avro"
+    feature = "avro",
+    feature = "orc"
 ))]
 pub fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> ArrowSchema {
     projection
@@ -62,7 +63,8 @@ pub fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> ArrowSche
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "avro",
-    feature = "parquet"
+    feature = "parquet",
+    feature = "orc"
 ))]
 pub fn columns_to_projection<T: AsRef<str>>(
     columns: &[T],
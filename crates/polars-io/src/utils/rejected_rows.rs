@@ -0,0 +1,253 @@
+//! Capture of rows that fail to parse.
+//!
+//! When [`RejectedRowsOptions`] are set on a CSV or NDJSON read, rows that are ragged or contain
+//! values that cannot be parsed are removed from the output and written to a CSV file per source
+//! instead of raising an error or being turned into nulls.
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Default for [`RejectedRowsOptions::max_buffered_bytes`].
+const DEFAULT_MAX_BUFFERED_BYTES: usize = 1 << 20;
+
+/// Where rows that fail to parse are written to.
+///
+/// Every source gets its own file in the `path` directory, named after the index of the source,
+/// e.g. `0.csv`. A read fails if the file already exists, so the files of an earlier read have to
+/// be removed before the same scan runs again. The files have the columns `path`, `line`, `raw` (the bytes of the line without
+/// the line terminator) and `error`. `path` is empty if the source was not a file path. The rows
+/// are not ordered by line number.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RejectedRowsOptions {
+    pub path: PathBuf,
+    /// Rejected rows are buffered in memory up to this many bytes before they are written.
+    pub max_buffered_bytes: usize,
+}
+
+impl RejectedRowsOptions {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            max_buffered_bytes: DEFAULT_MAX_BUFFERED_BYTES,
+        }
+    }
+
+    pub fn with_max_buffered_bytes(mut self, max_buffered_bytes: usize) -> Self {
+        self.max_buffered_bytes = max_buffered_bytes;
+        self
+    }
+}
+
+struct RejectedRowsWriter {
+    file: File,
+    // Encoded rows that are not written yet.
+    buffer: Vec<u8>,
+    max_buffered_bytes: usize,
+}
+
+impl RejectedRowsWriter {
+    fn flush(&mut self) -> PolarsResult<()> {
+        self.file.write_all(&self.buffer)?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+/// Writer for the rejected rows of one source.
+///
+/// Cloning is cheap and all clones write to the same file, so the parallel parts of a read can
+/// share it. Buffered rows are only written by [`RejectedRows::finish`], which every read has to
+/// call once it is done, also if it stops early.
+#[derive(Clone, Default)]
+pub struct RejectedRows(Option<Arc<Mutex<RejectedRowsWriter>>>);
+
+impl RejectedRows {
+    /// Creates the file for the rejected rows of the source with index `source_idx`. Fails if the
+    /// file already exists.
+    pub fn try_new(options: &RejectedRowsOptions, source_idx: usize) -> PolarsResult<Self> {
+        std::fs::create_dir_all(&options.path)?;
+        let file_path = options.path.join(format!("{source_idx}.csv"));
+        let mut file = match File::create_new(&file_path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => polars_bail!(
+                ComputeError: "rejected rows file '{}' already exists", file_path.display()
+            ),
+            Err(e) => return Err(e.into()),
+        };
+        file.write_all(b"path,line,raw,error\n")?;
+
+        Ok(Self(Some(Arc::new(Mutex::new(RejectedRowsWriter {
+            file,
+            buffer: vec![],
+            max_buffered_bytes: options.max_buffered_bytes,
+        })))))
+    }
+
+    /// Returns a writer that drops all rows, for reads that only count the rows.
+    pub fn discard() -> Self {
+        Self(None)
+    }
+
+    /// Record a rejected row. `line` is the 1-based line number the row starts on.
+    pub(crate) fn push(
+        &self,
+        path: Option<&PlSmallStr>,
+        line: usize,
+        raw: &[u8],
+        error: &str,
+    ) -> PolarsResult<()> {
+        let Some(writer) = &self.0 else {
+            return Ok(());
+        };
+        let mut writer = writer.lock().unwrap();
+
+        fn write_quoted(buffer: &mut Vec<u8>, bytes: &[u8]) {
+            buffer.push(b'"');
+            for &b in bytes {
+                if b == b'"' {
+                    buffer.push(b'"');
+                }
+                buffer.push(b);
+            }
+            buffer.push(b'"');
+        }
+
+        let buffer = &mut writer.buffer;
+        if let Some(path) = path {
+            write_quoted(buffer, path.as_bytes());
+        }
+        buffer.push(b',');
+        buffer.extend_from_slice(itoa::Buffer::new().format(line).as_bytes());
+        buffer.push(b',');
+        write_quoted(buffer, raw);
+        buffer.push(b',');
+        write_quoted(buffer, error.as_bytes());
+        buffer.push(b'\n');
+
+        if writer.buffer.len() > writer.max_buffered_bytes {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write the buffered rows to the file. Rows that are still buffered when the last clone is
+    /// dropped are lost.
+    pub fn finish(&self) -> PolarsResult<()> {
+        match &self.0 {
+            Some(writer) => writer.lock().unwrap().flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for RejectedRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(_) => write!(f, "RejectedRows"),
+            None => write!(f, "RejectedRows(discard)"),
+        }
+    }
+}
+
+/// Tracks 1-based line numbers for increasing byte offsets into a buffer, without rescanning the
+/// buffer from the start for every lookup.
+pub struct LineCounter {
+    eol_char: u8,
+    offset: usize,
+    line: usize,
+}
+
+impl LineCounter {
+    pub fn new(eol_char: u8) -> Self {
+        Self {
+            eol_char,
+            offset: 0,
+            line: 1,
+        }
+    }
+
+    /// Returns the line number at `offset` in `bytes`.
+    ///
+    /// # Panics
+    /// Panics if `offset` is smaller than the offset of a previous call.
+    pub fn line_at(&mut self, bytes: &[u8], offset: usize) -> usize {
+        self.line += memchr::memchr_iter(self.eol_char, &bytes[self.offset..offset]).count();
+        self.offset = offset;
+        self.line
+    }
+
+    /// Continues counting in a new buffer that starts at `offset` in `bytes`.
+    pub fn rebase(&mut self, bytes: &[u8], offset: usize) {
+        self.line_at(bytes, offset);
+        self.offset = 0;
+    }
+}
+
+/// A line in a chunk of input that is read in rejected-row capture mode.
+pub(crate) struct RawLine {
+    /// Byte range of the line within the chunk, excluding the line terminator.
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    /// 1-based line number in the file.
+    pub(crate) line: usize,
+    /// Set if the line is rejected before parsing, e.g. because it is ragged.
+    pub(crate) error: Option<String>,
+}
+
+/// Parses `lines` of `bytes` with `read` and records the lines that fail in `rejected_rows`.
+///
+/// Consecutive lines are parsed together; on failure the range is bisected until the offending
+/// lines are isolated, so a chunk with `k` bad lines costs `O(k log n)` extra parses.
+pub(crate) fn read_lines_rejecting(
+    bytes: &[u8],
+    lines: &[RawLine],
+    read: &dyn Fn(&[u8], usize) -> PolarsResult<DataFrame>,
+    rejected_rows: &RejectedRows,
+    path: Option<&PlSmallStr>,
+) -> PolarsResult<Vec<DataFrame>> {
+    fn bisect(
+        bytes: &[u8],
+        lines: &[RawLine],
+        read: &dyn Fn(&[u8], usize) -> PolarsResult<DataFrame>,
+        reject: &dyn Fn(&RawLine, &str) -> PolarsResult<()>,
+        out: &mut Vec<DataFrame>,
+    ) -> PolarsResult<()> {
+        let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+            return Ok(());
+        };
+
+        match read(&bytes[first.start..last.end], lines.len()) {
+            Ok(df) => out.push(df),
+            Err(e) if lines.len() == 1 => reject(first, &e.to_string())?,
+            Err(_) => {
+                let (left, right) = lines.split_at(lines.len() / 2);
+                bisect(bytes, left, read, reject, out)?;
+                bisect(bytes, right, read, reject, out)?;
+            },
+        }
+        Ok(())
+    }
+
+    let reject = |line: &RawLine, error: &str| {
+        rejected_rows.push(path, line.line, &bytes[line.start..line.end], error)
+    };
+
+    let mut out = vec![];
+    for run in lines.split(|line| line.error.is_some()) {
+        bisect(bytes, run, read, &reject, &mut out)?;
+    }
+    for line in lines {
+        if let Some(error) = &line.error {
+            reject(line, error)?;
+        }
+    }
+
+    Ok(out)
+}
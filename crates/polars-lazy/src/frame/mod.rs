@@ -13,15 +13,31 @@ use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "fwf")]
+pub use fwf::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
+#[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "lines")]
+pub use lines::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
@@ -1033,6 +1049,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into a file in Arrow's Streaming IPC format. This is useful if the
+    /// final result doesn't fit into memory, or to write to a pipe. This methods will return an
+    /// error if the query cannot be completely done in a streaming fashion.
+    #[cfg(feature = "ipc_streaming")]
+    pub fn sink_ipc_stream(
+        self,
+        target: SinkTarget,
+        options: IpcWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::IpcStream(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1044,6 +1079,7 @@ impl LazyFrame {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         sink_options: SinkOptions,
     ) -> PolarsResult<Self> {
+        options.compression.check()?;
         self.sink(SinkType::File(FileSinkType {
             target,
             sink_options,
@@ -1063,6 +1099,7 @@ impl LazyFrame {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         sink_options: SinkOptions,
     ) -> PolarsResult<Self> {
+        options.compression.check()?;
         self.sink(SinkType::File(FileSinkType {
             target,
             sink_options,
@@ -1071,6 +1108,45 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
+    /// Stream a query result into a file in the PostgreSQL binary `COPY` format, which can be
+    /// loaded with `COPY ... FROM ... WITH (FORMAT binary)`. This is useful if the final result
+    /// doesn't fit into memory. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "pg_copy")]
+    pub fn sink_pg_copy(
+        self,
+        target: SinkTarget,
+        options: PgCopyWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::PgCopy(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1163,6 +1239,52 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into Avro files in a partitioned manner. This is useful if the final
+    /// result doesn't fit into memory. This methods will return an error if the query cannot be
+    /// completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PathBuf>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
+    /// Stream a query result into files in the PostgreSQL binary `COPY` format in a partitioned
+    /// manner. This is useful if the final result doesn't fit into memory. This methods will
+    /// return an error if the query cannot be completely done in a streaming fashion.
+    #[cfg(feature = "pg_copy")]
+    pub fn sink_pg_copy_partitioned(
+        self,
+        base_path: Arc<PathBuf>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: PgCopyWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::PgCopy(options),
+            cloud_options,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
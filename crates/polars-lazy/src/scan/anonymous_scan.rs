@@ -55,6 +55,7 @@ impl LazyFrame {
                 cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths: None,
+                deleted_rows: None,
             },
         )?
         .build()
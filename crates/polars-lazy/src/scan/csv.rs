@@ -3,7 +3,8 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, RejectedRowsOptions,
+    infer_file_schema, maybe_transcode_schema_inference_bytes,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::maybe_decompress_bytes;
@@ -93,6 +94,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Write rows that are ragged or contain values that cannot be parsed to the files described
+    /// by `rejected_rows` instead of raising an error, see [`RejectedRowsOptions`].
+    #[must_use]
+    pub fn with_rejected_rows(mut self, rejected_rows: Option<RejectedRowsOptions>) -> Self {
+        self.read_options.rejected_rows = rejected_rows;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -144,6 +153,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_separator(separator))
     }
 
+    /// Set the CSV file's column separator to a sequence of bytes, e.g. `||`.
+    #[must_use]
+    pub fn with_multi_byte_separator(self, separator: &str) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_byte_separator(separator))
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     #[must_use]
     pub fn with_comment_prefix(self, comment_prefix: Option<PlSmallStr>) -> Self {
@@ -164,6 +179,13 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_quote_char(quote_char))
     }
 
+    /// Set the `char` that escapes the byte following it, e.g. `b'\\'`. Escaping is disabled by
+    /// default.
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_eol_char(self, eol_char: u8) -> Self {
@@ -254,6 +276,9 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut transcoded = vec![];
+            let bytes =
+                maybe_transcode_schema_inference_bytes(bytes, &self.read_options, &mut transcoded)?;
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -342,6 +367,7 @@ impl LazyFileListReader for LazyCsvReader {
                 cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths: self.include_file_paths,
+                deleted_rows: None,
             },
         )?
         .build()
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::DeltaScanOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// Read the table at this version instead of the latest one.
+    pub version: Option<i64>,
+    /// Read the latest version committed at or before this time, in milliseconds since the Unix
+    /// epoch.
+    pub timestamp: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: None,
+            timestamp: None,
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: None,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the data files of a Delta Lake table.
+    ///
+    /// The active files are resolved from the transaction log of the table and read with the
+    /// Parquet reader. Partition columns are added after the other columns.
+    pub fn scan_delta(path: impl AsRef<Path>, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let options = DeltaScanOptions {
+            version: args.version,
+            timestamp: args.timestamp,
+        };
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_delta(
+            ScanSources::Paths([path.as_ref().to_path_buf()].into()),
+            options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: HiveOptions::new_disabled(),
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: false,
+                projection: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
+                missing_columns_policy: MissingColumnsPolicy::Insert,
+                include_file_paths: args.include_file_paths,
+                deleted_rows: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+}
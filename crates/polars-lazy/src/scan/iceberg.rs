@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::iceberg::IcebergScanOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// Read the snapshot with this id instead of the current one.
+    pub snapshot_id: Option<i64>,
+    /// Read the snapshot that was current at this time, in milliseconds since the Unix epoch.
+    pub timestamp: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            timestamp: None,
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: None,
+            include_file_paths: None,
+        }
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Apache Iceberg table.
+    ///
+    /// `path` is the root directory of the table or the path of one of its `*.metadata.json`
+    /// files. The data files of the snapshot are only listed when the query is optimized, so that
+    /// the filters of the query can be used to skip manifests and data files.
+    pub fn scan_iceberg(path: impl AsRef<Path>, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let options = IcebergScanOptions {
+            snapshot_id: args.snapshot_id,
+            timestamp: args.timestamp,
+        };
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_iceberg(
+            ScanSources::Paths([path.as_ref().to_path_buf()].into()),
+            options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: HiveOptions::new_disabled(),
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: false,
+                projection: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
+                missing_columns_policy: MissingColumnsPolicy::Insert,
+                include_file_paths: args.include_file_paths,
+                deleted_rows: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+}
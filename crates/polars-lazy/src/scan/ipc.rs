@@ -78,6 +78,7 @@ impl LazyFileListReader for LazyIpcReader {
                 cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
                 missing_columns_policy: MissingColumnsPolicy::Raise,
                 include_file_paths,
+                deleted_rows: None,
             },
         )?
         .build()
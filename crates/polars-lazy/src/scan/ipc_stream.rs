@@ -0,0 +1,158 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::ipc::IpcStreamScanOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIpcStream {
+    /// Set the schema to read sources that can only be read once, such as pipes.
+    pub options: IpcStreamScanOptions,
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsIpcStream {
+    fn default() -> Self {
+        Self {
+            options: Default::default(),
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: Default::default(),
+            include_file_paths: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyIpcStreamReader {
+    args: ScanArgsIpcStream,
+    sources: ScanSources,
+}
+
+impl LazyIpcStreamReader {
+    fn new(args: ScanArgsIpcStream) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyIpcStreamReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_ipc_stream(
+            self.sources,
+            args.options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: HiveOptions::new_disabled(),
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: true,
+                projection: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
+                missing_columns_policy: MissingColumnsPolicy::Raise,
+                include_file_paths: args.include_file_paths,
+                deleted_rows: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from a scan of files in Arrow's Streaming IPC format. The
+    /// record batches are read incrementally, so the streams don't have to fit in memory.
+    ///
+    /// Stdin and other pipes can be scanned by passing them as open files to
+    /// [`LazyFrame::scan_ipc_stream_sources`]. As these can only be read once, the schema has to
+    /// be set in the [`IpcStreamScanOptions`].
+    pub fn scan_ipc_stream(path: impl AsRef<Path>, args: ScanArgsIpcStream) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(
+            ScanSources::Paths([path.as_ref().to_path_buf()].into()),
+            args,
+        )
+    }
+
+    pub fn scan_ipc_stream_files(
+        paths: Arc<[PathBuf]>,
+        args: ScanArgsIpcStream,
+    ) -> PolarsResult<Self> {
+        Self::scan_ipc_stream_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_ipc_stream_sources(
+        sources: ScanSources,
+        args: ScanArgsIpcStream,
+    ) -> PolarsResult<Self> {
+        LazyIpcStreamReader::new(args)
+            .with_sources(sources)
+            .finish()
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::utils::rejected_rows::RejectedRowsOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_plan::dsl::{CastColumnsPolicy, DslPlan, FileScan, MissingColumnsPolicy, ScanSources};
 use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
@@ -26,6 +27,7 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) rejected_rows: Option<RejectedRowsOptions>,
 }
 
 impl LazyJsonLineReader {
@@ -47,6 +49,7 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            rejected_rows: None,
         }
     }
 
@@ -67,6 +70,14 @@ impl LazyJsonLineReader {
         self.ignore_errors = ignore_errors;
         self
     }
+
+    /// Write lines that are not valid JSON or do not match the schema to the files described by
+    /// `rejected_rows` instead of raising an error, see [`RejectedRowsOptions`].
+    #[must_use]
+    pub fn with_rejected_rows(mut self, rejected_rows: Option<RejectedRowsOptions>) -> Self {
+        self.rejected_rows = rejected_rows;
+        self
+    }
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -136,6 +147,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
             missing_columns_policy: MissingColumnsPolicy::Raise,
             include_file_paths: self.include_file_paths,
+            deleted_rows: None,
         };
 
         let options = NDJsonReadOptions {
@@ -146,6 +158,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            rejected_rows: self.rejected_rows,
         };
 
         let scan_type = Box::new(FileScan::NDJson { options });
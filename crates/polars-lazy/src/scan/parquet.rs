@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
+use polars_io::prelude::{ParquetColumnMatching, ParquetKeyRetriever, ParquetOptions};
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::slice_enum::Slice;
 
@@ -25,6 +25,11 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Retrieves the keys to read files encrypted with Parquet modular encryption.
+    pub key_retriever: Option<ParquetKeyRetriever>,
+    /// How the columns of the files are matched to the columns of the table. Matching by field id
+    /// widens columns that were written with a narrower type than the table.
+    pub column_matching: ParquetColumnMatching,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +48,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            key_retriever: None,
+            column_matching: ParquetColumnMatching::Name,
         }
     }
 }
@@ -65,11 +72,17 @@ impl LazyParquetReader {
 impl LazyFileListReader for LazyParquetReader {
     /// Get the final [LazyFrame].
     fn finish(self) -> PolarsResult<LazyFrame> {
+        let cast_columns_policy = match self.args.column_matching {
+            ParquetColumnMatching::Name => CastColumnsPolicy::ErrorOnMismatch,
+            ParquetColumnMatching::FieldId { .. } => CastColumnsPolicy::AllowWidening,
+        };
         let parquet_options = ParquetOptions {
             schema: self.args.schema,
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            key_retriever: self.args.key_retriever,
+            column_matching: self.args.column_matching,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -86,13 +99,14 @@ impl LazyFileListReader for LazyParquetReader {
                 .args
                 .n_rows
                 .map(|len| Slice::Positive { offset: 0, len }),
-            cast_columns_policy: CastColumnsPolicy::ErrorOnMismatch,
+            cast_columns_policy,
             missing_columns_policy: if self.args.allow_missing_columns {
                 MissingColumnsPolicy::Insert
             } else {
                 MissingColumnsPolicy::Raise
             },
             include_file_paths: self.args.include_file_paths,
+            deleted_rows: None,
         };
 
         let mut lf: LazyFrame =
@@ -382,6 +382,500 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "is_in")]
+fn test_parquet_bloom_filter() -> PolarsResult<()> {
+    use polars_io::parquet::write::ParquetBloomFilterOptions;
+    use polars_io::prelude::_internal::{
+        bloom_filter_bitset_range, bloom_filter_byte_range, bloom_filter_excludes_all,
+    };
+
+    let path = std::env::temp_dir().join("polars_test_parquet_bloom_filter.parquet");
+    // Both row groups span the same range, so statistics cannot skip either of them.
+    let df = df![
+        "a" => [0i64, 2, 4, 6, 8, 100, 1, 3, 5, 7, 9, 100],
+        "b" => ["x", "x", "x", "x", "x", "x", "y", "y", "y", "y", "y", "y"],
+    ]?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(Arc::new(path.clone())),
+            ParquetWriteOptions {
+                row_group_size: Some(6),
+                bloom_filters: vec![("a".into(), ParquetBloomFilterOptions::try_new(0.01, None)?)],
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let bytes = std::fs::read(&path)?;
+    let metadata = ParquetReader::new(Cursor::new(&bytes))
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let field = ArrowField::new("a".into(), ArrowDataType::Int64, true);
+    for (rg, excluded) in metadata.row_groups.iter().zip([5i64, 4]) {
+        let column = rg.columns_under_root_iter("b").unwrap().next().unwrap();
+        assert!(bloom_filter_byte_range(column).is_none());
+
+        let column = rg.columns_under_root_iter("a").unwrap().next().unwrap();
+        let range = bloom_filter_byte_range(column).unwrap();
+        let bytes = &bytes[range];
+        let bitset = &bytes[bloom_filter_bitset_range(bytes)?.unwrap()];
+
+        let values = Series::new("a".into(), [excluded]);
+        assert!(bloom_filter_excludes_all(bitset, column, &field, &values));
+        let values = Series::new("a".into(), [excluded, 100]);
+        assert!(!bloom_filter_excludes_all(bitset, column, &field, &values));
+    }
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+    let out = lf.clone().filter(col("a").eq(lit(4i64))).collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("x"));
+    assert_eq!(out.height(), 1);
+    let out = lf
+        .clone()
+        .filter(col("a").is_in(
+            lit(Scalar::new(
+                DataType::List(Box::new(DataType::Int64)),
+                AnyValue::List(Series::new("".into(), [5i64, 10])),
+            )),
+            false,
+        ))
+        .collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("y"));
+    assert_eq!(out.height(), 1);
+    let out = lf.filter(col("a").eq(lit(10i64))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    use polars_parquet::parquet::read::indexes::deserialize_offset_index;
+
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+    let n = 10_000i64;
+    // The string column has its page boundaries at other rows than the sorted integer column.
+    let df = df![
+        "t" => (0..n).collect::<Vec<_>>(),
+        "s" => (0..n).map(|i| format!("{}", i * i)).collect::<Vec<_>>(),
+    ]?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(Arc::new(path.clone())),
+            ParquetWriteOptions {
+                data_page_size: Some(1024),
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let bytes = std::fs::read(&path)?;
+    let metadata = ParquetReader::new(Cursor::new(&bytes))
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 1);
+    let column = metadata.row_groups[0]
+        .columns_under_root_iter("t")
+        .unwrap()
+        .next()
+        .unwrap();
+    assert!(column.column_index_byte_range().is_some());
+    assert!(column.offset_index_byte_range().is_some());
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+
+    let predicate = col("t").gt_eq(lit(5000i64)).and(col("t").lt(lit(5010i64)));
+    let out = lf.clone().filter(predicate.clone()).collect()?;
+    let expected = df.clone().lazy().filter(predicate.clone()).collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 10);
+
+    // Two ranges that are far apart.
+    let predicate = col("t").lt(lit(3i64)).or(col("t").gt(lit(n - 4)));
+    let out = lf
+        .clone()
+        .with_row_index("index", Some(10))
+        .filter(predicate.clone())
+        .collect()?;
+    let expected = df
+        .clone()
+        .lazy()
+        .with_row_index("index", Some(10))
+        .filter(predicate)
+        .collect()?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 6);
+
+    let out = lf.filter(col("t").gt(lit(n))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    // The rows selected by `5000 <= t < 5010` are those of the pages of `t` that contain them.
+    let page_locations = |name: &str| -> PolarsResult<_> {
+        let column = metadata.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap();
+        let range = column.offset_index_byte_range().unwrap();
+        let offset_index =
+            deserialize_offset_index(&bytes[range.start as usize..range.end as usize])?;
+        let locations = offset_index.page_locations;
+        let row_ranges = locations
+            .iter()
+            .enumerate()
+            .map(|(i, l)| {
+                let end = locations.get(i + 1).map_or(n, |next| next.first_row_index);
+                l.first_row_index..end
+            })
+            .collect::<Vec<_>>();
+        Ok(locations.into_iter().zip(row_ranges).collect::<Vec<_>>())
+    };
+    let selected = page_locations("t")?
+        .into_iter()
+        .map(|(_, rows)| rows)
+        .filter(|rows| rows.start < 5010 && rows.end > 5000)
+        .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+        .unwrap();
+    assert!(selected.end - selected.start < n / 2);
+
+    // Overwrite all other data pages, so the scan only succeeds if they are never read.
+    let mut corrupted = bytes.clone();
+    for name in ["t", "s"] {
+        for (location, rows) in page_locations(name)? {
+            if rows.end <= selected.start || rows.start >= selected.end {
+                let start = location.offset as usize;
+                let end = start + location.compressed_page_size as usize;
+                corrupted[start..end].fill(0xFF);
+            }
+        }
+    }
+    std::fs::write(&path, &corrupted)?;
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+    let predicate = col("t").gt_eq(lit(5000i64)).and(col("t").lt(lit(5010i64)));
+    let out = lf.clone().filter(predicate.clone()).collect()?;
+    let expected = df.lazy().filter(predicate).collect()?;
+    assert!(out.equals(&expected));
+    // Without the page index the corrupted pages have to be read.
+    assert!(lf.filter(col("t").lt(lit(10i64))).collect().is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_parquet_page_index_categorical() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index_categorical.parquet");
+    let n = 10_000i64;
+    let df = df![
+        "t" => (0..n).collect::<Vec<_>>(),
+        "c" => (0..n).map(|i| format!("{}", i / 1000)).collect::<Vec<_>>(),
+    ]?
+    .lazy()
+    .with_column(col("c").cast(DataType::Categorical(None, Default::default())))
+    .collect()?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(Arc::new(path.clone())),
+            ParquetWriteOptions {
+                data_page_size: Some(1024),
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+
+    // The page statistics of the categorical column are not used, but must not prevent using
+    // those of the other columns.
+    for predicate in [
+        col("c").eq(lit("3")),
+        col("c").eq(lit("3")).and(col("t").lt(lit(3010i64))),
+        col("t").lt(lit(3010i64)).or(col("c").eq(lit("9"))),
+    ] {
+        let out = lf.clone().filter(predicate.clone()).collect()?;
+        let expected = df.clone().lazy().filter(predicate).collect()?;
+        assert!(out.equals(&expected));
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_parquet_encryption_scan_sink() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use polars_io::parquet::encryption::{ParquetEncryptionOptions, ParquetKeyRetriever};
+
+    let path = std::env::temp_dir().join("polars_test_parquet_encryption_scan_sink.parquet");
+    let column_key_retrievals = Arc::new(AtomicUsize::new(0));
+    let key_retriever = {
+        let column_key_retrievals = column_key_retrievals.clone();
+        ParquetKeyRetriever::from_func(move |key_metadata| match key_metadata {
+            b"footer-key" => Ok(vec![1; 16]),
+            b"column-key" => {
+                column_key_retrievals.fetch_add(1, Ordering::Relaxed);
+                Ok(vec![2; 32])
+            },
+            _ => polars_bail!(ComputeError: "unknown key"),
+        })
+    };
+    // Only has access to the footer key, not to the key of the "name" column.
+    let footer_key_retriever = ParquetKeyRetriever::from_func(|key_metadata| match key_metadata {
+        b"footer-key" => Ok(vec![1; 16]),
+        _ => polars_bail!(ComputeError: "access denied"),
+    });
+
+    let n = 1000i64;
+    let df = df![
+        "id" => (0..n).collect::<Vec<_>>(),
+        "name" => (0..n).map(|i| format!("name-{}", i % 7)).collect::<Vec<_>>(),
+        "score" => (0..n).map(|i| i as f64 / 2.0).collect::<Vec<_>>(),
+    ]?;
+
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(Arc::new(path.clone())),
+            ParquetWriteOptions {
+                row_group_size: Some(100),
+                encryption: Some(
+                    ParquetEncryptionOptions::new(b"footer-key".to_vec(), key_retriever.clone())
+                        .with_column_key("name".into(), b"column-key".to_vec()),
+                ),
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let scan = |key_retriever: ParquetKeyRetriever| {
+        LazyFrame::scan_parquet(
+            &path,
+            ScanArgsParquet {
+                key_retriever: Some(key_retriever),
+                ..Default::default()
+            },
+        )
+    };
+
+    column_key_retrievals.store(0, Ordering::Relaxed);
+    let out = scan(key_retriever.clone())?.collect()?;
+    assert!(out.equals(&df));
+    // The key is retrieved once and cached, not once for each of the 10 row groups.
+    let retrievals = column_key_retrievals.load(Ordering::Relaxed);
+    assert!(retrievals > 0 && retrievals < 10);
+
+    // Column keys are only retrieved for the projected columns.
+    column_key_retrievals.store(0, Ordering::Relaxed);
+    let out = scan(key_retriever)?
+        .select([col("id"), col("score")])
+        .collect()?;
+    assert!(out.equals(&df.select(["id", "score"])?));
+    assert_eq!(column_key_retrievals.load(Ordering::Relaxed), 0);
+
+    // Without the key of the "name" column the other columns can still be read.
+    let out = scan(footer_key_retriever.clone())?
+        .filter(col("id").lt(lit(10i64)))
+        .select([col("id"), col("score")])
+        .collect()?;
+    assert!(out.equals(&df.head(Some(10)).select(["id", "score"])?));
+    assert!(
+        scan(footer_key_retriever)?
+            .select([col("name")])
+            .collect()
+            .is_err()
+    );
+
+    // The file cannot be read without the keys.
+    assert!(
+        LazyFrame::scan_parquet(&path, Default::default())?
+            .collect()
+            .is_err()
+    );
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_parquet_encryption_page_index() -> PolarsResult<()> {
+    use polars_io::parquet::encryption::{ParquetEncryptionOptions, ParquetKeyRetriever};
+
+    let path = std::env::temp_dir().join("polars_test_parquet_encryption_page_index.parquet");
+    let key_retriever = ParquetKeyRetriever::from_func(|key_metadata| match key_metadata {
+        b"footer-key" => Ok(vec![1; 16]),
+        b"column-key" => Ok(vec![2; 32]),
+        _ => polars_bail!(ComputeError: "unknown key"),
+    });
+
+    let n = 10_000i64;
+    let df = df![
+        "t" => (0..n).collect::<Vec<_>>(),
+        "s" => (0..n).map(|i| format!("{}", i * i)).collect::<Vec<_>>(),
+    ]?;
+
+    // Only "s" is encrypted, so only "t" has page indexes.
+    df.clone()
+        .lazy()
+        .sink_parquet(
+            SinkTarget::Path(Arc::new(path.clone())),
+            ParquetWriteOptions {
+                data_page_size: Some(1024),
+                encryption: Some(
+                    ParquetEncryptionOptions::new(b"footer-key".to_vec(), key_retriever.clone())
+                        .with_column_key("s".into(), b"column-key".to_vec()),
+                ),
+                ..Default::default()
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let lf = LazyFrame::scan_parquet(
+        &path,
+        ScanArgsParquet {
+            key_retriever: Some(key_retriever),
+            ..Default::default()
+        },
+    )?;
+
+    for predicate in [
+        col("t").gt_eq(lit(5000i64)).and(col("t").lt(lit(5010i64))),
+        col("s").eq(lit("25000000")),
+        col("t").lt(lit(3i64)).or(col("s").eq(lit("81"))),
+    ] {
+        let out = lf.clone().filter(predicate.clone()).collect()?;
+        let expected = df.clone().lazy().filter(predicate).collect()?;
+        assert!(out.equals(&expected));
+        assert!(out.height() > 0);
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_scan_parquet_field_ids() -> PolarsResult<()> {
+    use std::path::PathBuf;
+
+    use polars_io::prelude::{ParquetColumnMatching, ParquetFieldId};
+
+    let dir = std::env::temp_dir();
+    let paths: Arc<[PathBuf]> = (0..2)
+        .map(|i| dir.join(format!("polars_test_parquet_field_ids_{i}.parquet")))
+        .collect();
+    let write = |path: &PathBuf, mut df: DataFrame, field_ids: Vec<ParquetFieldId>| {
+        let file = std::fs::File::create(path)?;
+        ParquetWriter::new(file)
+            .with_field_ids(field_ids)
+            .finish(&mut df)
+            .map(|_| ())
+    };
+    let struct_column = |fields: &[Series]| {
+        StructChunked::from_series("s".into(), 2, fields.iter()).map(|s| s.into_column())
+    };
+
+    // The current layout of the table: the columns were renamed and widened, and the struct has a
+    // new field in front.
+    write(
+        &paths[0],
+        DataFrame::new(vec![
+            Column::new("key".into(), [10i64, 11]),
+            Column::new("value".into(), [1.0f64, 2.0]),
+            struct_column(&[
+                Series::new("y".into(), ["a", "b"]),
+                Series::new("x_new".into(), [100i64, 101]),
+            ])?,
+            Column::new("extra".into(), [7i64, 8]),
+        ])?,
+        vec![
+            ParquetFieldId::new("key", 1),
+            ParquetFieldId::new("value", 2),
+            ParquetFieldId::new("s", 3).with_fields(vec![
+                ParquetFieldId::new("y", 5),
+                ParquetFieldId::new("x_new", 4),
+            ]),
+            ParquetFieldId::new("extra", 6),
+        ],
+    )?;
+    // An older file with a column that was since dropped from the table.
+    write(
+        &paths[1],
+        DataFrame::new(vec![
+            Column::new("dropped".into(), ["p", "q"]),
+            Column::new("val".into(), [0.5f32, 2.5]),
+            Column::new("id".into(), [1i32, 2]),
+            struct_column(&[Series::new("x".into(), [1i32, 2])])?,
+        ])?,
+        vec![
+            ParquetFieldId::new("dropped", 9),
+            ParquetFieldId::new("val", 2),
+            ParquetFieldId::new("id", 1),
+            ParquetFieldId::new("s", 3).with_fields(vec![ParquetFieldId::new("x", 4)]),
+        ],
+    )?;
+
+    // The names of the table are taken from the first file.
+    let lf = LazyFrame::scan_parquet_files(
+        paths.clone(),
+        ScanArgsParquet {
+            allow_missing_columns: true,
+            column_matching: ParquetColumnMatching::FieldId { field_ids: None },
+            ..Default::default()
+        },
+    )?;
+
+    let expected = DataFrame::new(vec![
+        Column::new("key".into(), [10i64, 11, 1, 2]),
+        Column::new("value".into(), [1.0f64, 2.0, 0.5, 2.5]),
+        StructChunked::from_series(
+            "s".into(),
+            4,
+            [
+                Series::new("y".into(), [Some("a"), Some("b"), None, None]),
+                Series::new("x_new".into(), [100i64, 101, 1, 2]),
+            ]
+            .iter(),
+        )?
+        .into_column(),
+        Column::new("extra".into(), [Some(7i64), Some(8), None, None]),
+    ])?;
+    assert!(lf.clone().collect()?.equals_missing(&expected));
+
+    // The predicate is applied after the columns are widened.
+    let out = lf.filter(col("value").gt(lit(1.5f64))).collect()?;
+    let expected = expected.filter(&BooleanChunked::new("".into(), [false, true, false, true]))?;
+    assert!(out.equals_missing(&expected));
+
+    for path in paths.iter() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -435,6 +929,1437 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "avro", not(target_os = "windows")))]
+fn test_avro_globbing() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let glob = "../../examples/datasets/foods*.avro";
+    let df = LazyFrame::scan_avro(glob, Default::default())?.collect()?;
+    assert_eq!(df.shape(), (54, 4));
+    let cal = df.column("calories")?;
+    assert_eq!(cal.get(0)?, AnyValue::Int64(45));
+    assert_eq!(cal.get(53)?, AnyValue::Int64(194));
+
+    let df = LazyFrame::scan_avro(glob, Default::default())?
+        .slice(20, 20)
+        .select([col("calories")])
+        .collect()?;
+    assert_eq!(df.shape(), (20, 1));
+    assert_eq!(df.column("calories")?.get(7)?, cal.get(27)?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_avro_row_index() -> PolarsResult<()> {
+    init_files();
+    let lf =
+        LazyFrame::scan_avro(FOODS_AVRO, Default::default())?.with_row_index("index", Some(10));
+
+    assert!(row_index_at_scan(lf.clone()));
+    let df = lf.collect()?;
+    let idx = df.column("index")?;
+    assert_eq!(
+        idx.idx()?.into_no_null_iter().collect::<Vec<_>>(),
+        (10..37).collect::<Vec<_>>()
+    );
+
+    let df = LazyFrame::scan_avro(FOODS_AVRO, Default::default())?
+        .with_row_index("index", None)
+        .tail(5)
+        .collect()?;
+    assert_eq!(
+        df.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (22..27).collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_avro_sink_roundtrip() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_avro_sink_roundtrip.avro");
+    let expected = LazyCsvReader::new(FOODS_CSV).finish()?.collect()?;
+
+    expected
+        .clone()
+        .lazy()
+        .sink_avro(
+            SinkTarget::Path(Arc::new(path.clone())),
+            Default::default(),
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let df = LazyFrame::scan_avro(&path, Default::default())?.collect()?;
+    std::fs::remove_file(&path)?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pg_copy")]
+fn test_pg_copy_sink_roundtrip() -> PolarsResult<()> {
+    use polars_io::SerReader;
+    use polars_io::pg_copy::PgCopyReader;
+
+    let path = std::env::temp_dir().join("polars_test_pg_copy_sink_roundtrip.bin");
+    let expected = df!(
+        "id" => (0..100_000i64).collect::<Vec<_>>(),
+        "name" => (0..100_000).map(|i| (i % 7 != 0).then(|| format!("name {i}"))).collect::<Vec<_>>(),
+        "score" => (0..100_000).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+    )?;
+
+    expected
+        .clone()
+        .lazy()
+        .sink_pg_copy(
+            SinkTarget::Path(Arc::new(path.clone())),
+            Default::default(),
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+
+    let df = PgCopyReader::new(std::fs::File::open(&path)?)
+        .with_schema(expected.schema().clone())
+        .finish()?;
+    std::fs::remove_file(&path)?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "csv", feature = "json"))]
+fn test_sink_csv_json_compressed() -> PolarsResult<()> {
+    let expected = df!(
+        "a" => (0..100_000i64).collect::<Vec<_>>(),
+        "b" => (0..100_000).map(|i| format!("value {}", i % 777)).collect::<Vec<_>>(),
+    )?;
+
+    for (compression, magic) in [
+        (ExternalCompression::Gzip { level: Some(1) }, [0x1f, 0x8b]),
+        (ExternalCompression::Zstd { level: None }, [0x28, 0xb5]),
+    ] {
+        let path = std::env::temp_dir().join("polars_test_sink_csv_compressed.csv");
+        expected
+            .clone()
+            .lazy()
+            .sink_csv(
+                SinkTarget::Path(Arc::new(path.clone())),
+                CsvWriterOptions {
+                    compression,
+                    ..Default::default()
+                },
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+        let bytes = std::fs::read(&path)?;
+        assert_eq!(bytes[..2], magic);
+        let df = LazyCsvReader::new(&path).finish()?.collect()?;
+        std::fs::remove_file(&path)?;
+        assert!(df.equals(&expected));
+
+        let path = std::env::temp_dir().join("polars_test_sink_json_compressed.jsonl");
+        expected
+            .clone()
+            .lazy()
+            .sink_json(
+                SinkTarget::Path(Arc::new(path.clone())),
+                JsonWriterOptions { compression },
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+        let bytes = std::fs::read(&path)?;
+        assert_eq!(bytes[..2], magic);
+        let df = LazyJsonLineReader::new(&path).finish()?.collect()?;
+        std::fs::remove_file(&path)?;
+        assert!(df.equals(&expected));
+    }
+
+    // An empty result is still a valid compressed file.
+    let path = std::env::temp_dir().join("polars_test_sink_json_compressed_empty.jsonl");
+    expected
+        .clone()
+        .lazy()
+        .filter(col("a").lt(lit(0)))
+        .sink_json(
+            SinkTarget::Path(Arc::new(path.clone())),
+            JsonWriterOptions {
+                compression: ExternalCompression::Gzip { level: None },
+            },
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+    let bytes = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+    let mut out = vec![];
+    assert!(polars_io::utils::compression::maybe_decompress_bytes(&bytes, &mut out)?.is_empty());
+
+    let invalid = CsvWriterOptions {
+        compression: ExternalCompression::Gzip { level: Some(10) },
+        ..Default::default()
+    };
+    assert!(
+        expected
+            .lazy()
+            .sink_csv(
+                SinkTarget::Path(Arc::new(path)),
+                invalid,
+                None,
+                Default::default()
+            )
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "orc", not(target_os = "windows")))]
+fn test_orc_globbing() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let glob = "../../examples/datasets/foods*.orc";
+    let df = LazyFrame::scan_orc(glob, Default::default())?.collect()?;
+    assert_eq!(df.shape(), (54, 4));
+    let cal = df.column("calories")?;
+    assert_eq!(cal.get(0)?, AnyValue::Int64(45));
+    assert_eq!(cal.get(53)?, AnyValue::Int64(194));
+
+    let df = LazyFrame::scan_orc(glob, Default::default())?
+        .slice(20, 20)
+        .select([col("calories")])
+        .collect()?;
+    assert_eq!(df.shape(), (20, 1));
+    assert_eq!(df.column("calories")?.get(7)?, cal.get(27)?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "orc")]
+fn test_orc_row_index() -> PolarsResult<()> {
+    init_files();
+    let lf = LazyFrame::scan_orc(FOODS_ORC, Default::default())?.with_row_index("index", Some(10));
+
+    assert!(row_index_at_scan(lf.clone()));
+    let df = lf.collect()?;
+    let idx = df.column("index")?;
+    assert_eq!(
+        idx.idx()?.into_no_null_iter().collect::<Vec<_>>(),
+        (10..37).collect::<Vec<_>>()
+    );
+
+    let df = LazyFrame::scan_orc(FOODS_ORC, Default::default())?
+        .with_row_index("index", None)
+        .tail(5)
+        .collect()?;
+    assert_eq!(
+        df.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (22..27).collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "orc")]
+fn test_orc_stripe_statistics() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_orc_stripe_statistics.orc");
+    let mut df = df![
+        "a" => (0..1000).collect::<Vec<i64>>(),
+        "b" => (0..1000).map(|i| format!("{i:04}")).collect::<Vec<_>>(),
+    ]?;
+    polars_io::orc::OrcWriter::new(std::fs::File::create(&path)?)
+        .with_stripe_size(Some(100))
+        .finish(&mut df)?;
+
+    for use_statistics in [true, false] {
+        let args = ScanArgsOrc {
+            use_statistics,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_orc(&path, args.clone())?
+            .filter(col("a").gt_eq(lit(250i64)).and(col("a").lt(lit(420i64))))
+            .select([col("b")])
+            .collect()?;
+        assert_eq!(out.height(), 170);
+        assert_eq!(out.column("b")?.get(0)?, AnyValue::String("0250"));
+
+        let out = LazyFrame::scan_orc(&path, args)?
+            .filter(col("b").eq(lit("0999")))
+            .collect()?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(out.column("a")?.get(0)?, AnyValue::Int64(999));
+    }
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "fwf")]
+fn test_scan_fwf() -> PolarsResult<()> {
+    use polars_io::fwf::{FwfColumn, FwfReadOptions};
+    use polars_io::utils::compression::ExternalCompression;
+
+    let data = (0..1000)
+        .map(|i| {
+            format!(
+                "{i:>6}{:<6}{:>8.1}\n",
+                format!("k{}", i % 7),
+                i as f64 / 2.0
+            )
+        })
+        .collect::<String>();
+    let path = std::env::temp_dir().join("polars_test_scan_fwf.txt");
+    std::fs::write(&path, &data)?;
+    let gz_path = std::env::temp_dir().join("polars_test_scan_fwf.txt.gz");
+    let mut compressed = vec![];
+    ExternalCompression::Gzip { level: None }.compress_block(data.as_bytes(), &mut compressed)?;
+    std::fs::write(&gz_path, compressed)?;
+
+    let args = ScanArgsFwf {
+        options: FwfReadOptions::new([
+            FwfColumn::new("i", 0, 6, DataType::Int32),
+            FwfColumn::new("key", 6, 6, DataType::String),
+            FwfColumn::new("half", 12, 8, DataType::Float64),
+        ]),
+        ..Default::default()
+    };
+
+    for path in [&path, &gz_path] {
+        let lf = LazyFrame::scan_fwf(path, args.clone())?;
+
+        // Only the projected column is read.
+        let q = lf.clone().select([col("half")]);
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let node = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!((&lp_arena).iter(node).any(|(_, lp)| matches!(
+            lp,
+            IR::Scan { unified_scan_args, .. }
+                if unified_scan_args.projection.as_ref().is_some_and(|p| p.len() == 1)
+        )));
+        let out = q.collect()?;
+        assert_eq!(out.shape(), (1000, 1));
+        assert_eq!(out.column("half")?.get(999)?, AnyValue::Float64(499.5));
+
+        let out = lf
+            .clone()
+            .filter(col("key").eq(lit("k3")))
+            .select([col("i").sum()])
+            .collect()?;
+        let expected: i32 = (0..1000).filter(|i| i % 7 == 3).sum();
+        assert_eq!(out.column("i")?.get(0)?, AnyValue::Int32(expected));
+
+        let out = lf
+            .clone()
+            .with_row_index("index", Some(5))
+            .slice(-3, 2)
+            .collect()?;
+        assert_eq!(
+            out.column("index")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [1002, 1003]
+        );
+        assert_eq!(
+            out.column("i")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [997, 998]
+        );
+
+        let out = lf.select([len()]).collect()?;
+        assert_eq!(out.column("len")?.get(0)?, AnyValue::UInt32(1000));
+    }
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&gz_path)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lines")]
+fn test_scan_lines() -> PolarsResult<()> {
+    use polars_io::lines::{LinesReadOptions, UnmatchedLines};
+    use polars_io::utils::compression::ExternalCompression;
+
+    // Every 5th line does not match.
+    let data = (0..1000)
+        .map(|i| {
+            if i % 5 == 4 {
+                format!("-- skipped {i}\n")
+            } else {
+                format!("level=k{} code={i} ms={}\n", i % 7, i as f64 / 2.0)
+            }
+        })
+        .collect::<String>();
+    let path = std::env::temp_dir().join("polars_test_scan_lines.log");
+    std::fs::write(&path, &data)?;
+    let gz_path = std::env::temp_dir().join("polars_test_scan_lines.log.gz");
+    let mut compressed = vec![];
+    ExternalCompression::Gzip { level: None }.compress_block(data.as_bytes(), &mut compressed)?;
+    std::fs::write(&gz_path, compressed)?;
+
+    let args = ScanArgsLines {
+        options: LinesReadOptions::new(r"^level=(?P<key>\S+) code=(?P<i>\d+) ms=(?P<half>\S+)$")
+            .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+                Field::new("i".into(), DataType::Int32),
+                Field::new("half".into(), DataType::Float64),
+            ])))),
+        ..Default::default()
+    };
+    let n_matched = 800;
+
+    let lf = LazyFrame::scan_lines_files([path.clone(), gz_path.clone()].into(), args.clone())?;
+
+    // Only the projected capture group is parsed.
+    let q = lf.clone().select([col("half")]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let node = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(node).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan { unified_scan_args, .. }
+            if unified_scan_args.projection.as_ref().is_some_and(|p| p.len() == 1)
+    )));
+    let out = q.collect()?;
+    assert_eq!(out.shape(), (2 * n_matched, 1));
+    assert_eq!(
+        out.column("half")?.get(n_matched - 1)?,
+        AnyValue::Float64(499.0)
+    );
+
+    let out = lf
+        .clone()
+        .filter(col("key").eq(lit("k3")))
+        .select([col("i").sum()])
+        .collect()?;
+    let expected: i32 = (0..1000).filter(|i| i % 5 != 4 && i % 7 == 3).sum();
+    assert_eq!(out.column("i")?.get(0)?, AnyValue::Int32(2 * expected));
+
+    // The row index counts the rows that are kept.
+    let out = lf
+        .clone()
+        .with_row_index("index", Some(5))
+        .slice(-3, 2)
+        .collect()?;
+    assert_eq!(
+        out.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [1602, 1603]
+    );
+    assert_eq!(
+        out.column("i")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [996, 997]
+    );
+
+    let out = lf.select([len()]).collect()?;
+    assert_eq!(
+        out.column("len")?.get(0)?,
+        AnyValue::UInt32(2 * n_matched as u32)
+    );
+
+    // Lines that don't match are kept in the reject column.
+    let mut args = args;
+    args.options = args.options.with_unmatched(UnmatchedLines::Reject {
+        column: "rejected".into(),
+    });
+    let lf = LazyFrame::scan_lines(&gz_path, args)?;
+    let out = lf
+        .clone()
+        .with_row_index("index", None)
+        .filter(col("rejected").is_not_null())
+        .select([col("index"), col("rejected")])
+        .collect()?;
+    assert_eq!(out.height(), 200);
+    assert_eq!(out.column("index")?.get(0)?, AnyValue::UInt32(4));
+    assert_eq!(
+        out.column("rejected")?.get(0)?,
+        AnyValue::String("-- skipped 4")
+    );
+
+    let out = lf.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::UInt32(1000));
+
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&gz_path)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_json() -> PolarsResult<()> {
+    use polars_io::json::array::JsonArrayReadOptions;
+    use polars_io::utils::compression::ExternalCompression;
+
+    // Larger than a single chunk of the streaming source. Every 4th record has no "half" field.
+    let n = 200_000;
+    let records = (0..n)
+        .map(|i| {
+            if i % 4 == 3 {
+                format!(r#"{{"i":{i},"key":"k{}"}}"#, i % 7)
+            } else {
+                format!(
+                    r#"{{"i":{i},"key":"k{}","half":{}}}"#,
+                    i % 7,
+                    i as f64 / 2.0
+                )
+            }
+        })
+        .collect::<Vec<_>>();
+    let data = format!("[\n{}\n]\n", records.join(",\n"));
+    let path = std::env::temp_dir().join("polars_test_scan_json.json");
+    std::fs::write(&path, &data)?;
+    let gz_path = std::env::temp_dir().join("polars_test_scan_json.json.gz");
+    let mut compressed = vec![];
+    ExternalCompression::Gzip { level: None }.compress_block(data.as_bytes(), &mut compressed)?;
+    std::fs::write(&gz_path, compressed)?;
+
+    let args = ScanArgsJson::default();
+    let lf = LazyFrame::scan_json_files([path.clone(), gz_path.clone()].into(), args.clone())?;
+    assert_eq!(
+        lf.clone().collect_schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("i".into(), DataType::Int64),
+            Field::new("key".into(), DataType::String),
+            Field::new("half".into(), DataType::Float64),
+        ])
+    );
+
+    // Only the projected field is parsed.
+    let q = lf.clone().select([col("half")]);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let node = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(node).any(|(_, lp)| matches!(
+        lp,
+        IR::Scan { unified_scan_args, .. }
+            if unified_scan_args.projection.as_ref().is_some_and(|p| p.len() == 1)
+    )));
+    let out = q.collect()?;
+    assert_eq!(out.shape(), (2 * n, 1));
+    assert_eq!(out.column("half")?.null_count(), 2 * n / 4);
+    assert_eq!(out.column("half")?.get(n - 2)?, AnyValue::Float64(99_999.0));
+
+    let out = lf
+        .clone()
+        .filter(col("key").eq(lit("k3")))
+        .select([col("i").sum()])
+        .collect()?;
+    let expected: i64 = (0..n as i64).filter(|i| i % 7 == 3).sum();
+    assert_eq!(out.column("i")?.get(0)?, AnyValue::Int64(2 * expected));
+
+    let out = lf
+        .clone()
+        .with_row_index("index", Some(5))
+        .slice(n as i64 - 2, 3)
+        .collect()?;
+    assert_eq!(
+        out.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as IdxSize + 3, n as IdxSize + 4, n as IdxSize + 5]
+    );
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as i64 - 2, n as i64 - 1, 0]
+    );
+
+    let out = lf.clone().slice(-3, 2).select([col("i")]).collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as i64 - 3, n as i64 - 2]
+    );
+
+    let out = lf.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::UInt32(2 * n as u32));
+
+    // With a given schema, fields that are not in it are ignored.
+    let args = ScanArgsJson {
+        options: JsonArrayReadOptions::default().with_schema(Some(Arc::new(Schema::from_iter([
+            Field::new("key".into(), DataType::String),
+            Field::new("missing".into(), DataType::Boolean),
+        ])))),
+        n_rows: Some(10),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_json(&gz_path, args)?.collect()?;
+    assert_eq!(out.shape(), (10, 2));
+    assert_eq!(out.column("key")?.get(9)?, AnyValue::String("k2"));
+    assert_eq!(out.column("missing")?.null_count(), 10);
+
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&gz_path)?;
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc_streaming")]
+fn test_scan_ipc_stream() -> PolarsResult<()> {
+    use polars_io::SerWriter;
+    use polars_io::ipc::{IpcStreamScanOptions, IpcStreamWriter};
+
+    // Written as several record batches.
+    let n = 30_000;
+    let df = df![
+        "i" => (0..n as i64).collect::<Vec<_>>(),
+        "key" => (0..n).map(|i| format!("k{}", i % 7)).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_scan_ipc_stream.arrows");
+    let mut batched = IpcStreamWriter::new(std::fs::File::create(&path)?).batched(df.schema())?;
+    for offset in (0..n as i64).step_by(10_000) {
+        batched.write_batch(&df.slice(offset, 10_000))?;
+    }
+    batched.finish()?;
+    drop(batched);
+
+    let args = ScanArgsIpcStream::default();
+    let lf = LazyFrame::scan_ipc_stream_files([path.clone(), path.clone()].into(), args.clone())?;
+    assert_eq!(lf.clone().collect_schema()?.as_ref(), df.schema().as_ref());
+
+    let out = lf.clone().select([col("key")]).collect()?;
+    assert_eq!(out.shape(), (2 * n, 1));
+
+    let out = lf
+        .clone()
+        .filter(col("key").eq(lit("k3")))
+        .select([col("i").sum()])
+        .collect()?;
+    let expected: i64 = (0..n as i64).filter(|i| i % 7 == 3).sum();
+    assert_eq!(out.column("i")?.get(0)?, AnyValue::Int64(2 * expected));
+
+    let out = lf
+        .clone()
+        .with_row_index("index", Some(5))
+        .slice(n as i64 - 2, 3)
+        .collect()?;
+    assert_eq!(
+        out.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as IdxSize + 3, n as IdxSize + 4, n as IdxSize + 5]
+    );
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as i64 - 2, n as i64 - 1, 0]
+    );
+
+    let out = lf.clone().slice(-3, 2).select([col("i")]).collect()?;
+    assert_eq!(
+        out.column("i")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        [n as i64 - 3, n as i64 - 2]
+    );
+
+    let out = lf.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.get(0)?, AnyValue::UInt32(2 * n as u32));
+
+    // Open files are read like pipes, so the schema has to be given.
+    let args = ScanArgsIpcStream {
+        options: IpcStreamScanOptions {
+            schema: Some(df.schema().clone()),
+        },
+        n_rows: Some(10_005),
+        ..Default::default()
+    };
+    let sources = ScanSources::Files([std::fs::File::open(&path)?].into());
+    let out = LazyFrame::scan_ipc_stream_sources(sources, args)?.collect()?;
+    assert_eq!(out.shape(), (10_005, 2));
+    assert_eq!(out.column("key")?.get(10_004)?, AnyValue::String("k1"));
+
+    let sink_path = std::env::temp_dir().join("polars_test_sink_ipc_stream.arrows");
+    LazyFrame::scan_ipc_stream(&path, ScanArgsIpcStream::default())?
+        .filter(col("i").lt(lit(100)))
+        .sink_ipc_stream(
+            SinkTarget::Path(Arc::new(sink_path.clone())),
+            Default::default(),
+            None,
+            Default::default(),
+        )?
+        .collect()?;
+    let out = LazyFrame::scan_ipc_stream(&sink_path, ScanArgsIpcStream::default())?.collect()?;
+    assert!(out.equals(&df.slice(0, 100)));
+
+    std::fs::remove_file(&path)?;
+    std::fs::remove_file(&sink_path)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "delta")]
+mod delta {
+    use std::path::Path;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use serde_json::json;
+
+    use super::*;
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":true,"metadata":{}},{"name":"value","type":"string","nullable":true,"metadata":{}},{"name":"year","type":"integer","nullable":true,"metadata":{}}]}"#;
+
+    fn write_data_file(table: &Path, path: &str, ids: &[i64], values: &[&str]) {
+        let mut df = df!("id" => ids, "value" => values).unwrap();
+        std::fs::create_dir_all(table.join(path).parent().unwrap()).unwrap();
+        let f = std::fs::File::create(table.join(path)).unwrap();
+        ParquetWriter::new(f).finish(&mut df).unwrap();
+    }
+
+    /// Writes a commit and sets its modification time to `secs` after the Unix epoch.
+    fn write_commit(table: &Path, version: i64, actions: &[serde_json::Value], secs: u64) {
+        let path = table.join(format!("_delta_log/{version:020}.json"));
+        let lines = actions
+            .iter()
+            .map(|action| action.to_string())
+            .collect::<Vec<_>>();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(UNIX_EPOCH + Duration::from_secs(secs))
+            .unwrap();
+    }
+
+    fn z85_encode(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] =
+            b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+        let mut out = String::new();
+        for chunk in bytes.chunks(4) {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            let mut value = u32::from_be_bytes(word);
+            let mut digits = [0u8; 5];
+            for d in digits.iter_mut().rev() {
+                *d = ALPHABET[(value % 85) as usize];
+                value /= 85;
+            }
+            out.extend(digits.iter().map(|&d| d as char));
+        }
+        out
+    }
+
+    /// Returns an inline deletion vector descriptor for the given rows (all below 2^16).
+    fn inline_deletion_vector(rows: &[u16]) -> serde_json::Value {
+        let mut bytes = 1681511377u32.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&12346u32.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&(rows.len() as u16 - 1).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        for row in rows {
+            bytes.extend_from_slice(&row.to_le_bytes());
+        }
+
+        json!({
+            "storageType": "i",
+            "pathOrInlineDv": z85_encode(&bytes),
+            "sizeInBytes": bytes.len(),
+            "cardinality": rows.len(),
+        })
+    }
+
+    /// Writes a checkpoint for version 1 of the test table.
+    fn write_checkpoint(table: &Path) {
+        let add = StructChunked::from_series(
+            "add".into(),
+            2,
+            [
+                Series::new(
+                    "path".into(),
+                    ["year=2024/1.parquet", "year=2023/2.parquet"],
+                ),
+                Series::new(
+                    "partitionValues".into(),
+                    ["2024", "2023"].map(|year| {
+                        StructChunked::from_series(
+                            "".into(),
+                            1,
+                            [
+                                Series::new("key".into(), ["year"]),
+                                Series::new("value".into(), [year]),
+                            ]
+                            .iter(),
+                        )
+                        .unwrap()
+                        .into_series()
+                    }),
+                ),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+        let metadata = StructChunked::from_series(
+            "metaData".into(),
+            1,
+            [
+                Series::new("schemaString".into(), [SCHEMA]),
+                Series::new(
+                    "partitionColumns".into(),
+                    [Series::new("".into(), ["year"])],
+                ),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+        let protocol = StructChunked::from_series(
+            "protocol".into(),
+            1,
+            [Series::new("minReaderVersion".into(), [1i32])].iter(),
+        )
+        .unwrap()
+        .into_series();
+
+        // Every row of a checkpoint contains a single action.
+        let frame = |column: &Series| {
+            let len = column.len();
+            let columns = [&add, &metadata, &protocol]
+                .into_iter()
+                .map(|s| {
+                    if s.name() == column.name() {
+                        s.clone().into_column()
+                    } else {
+                        Column::full_null(s.name().clone(), len, s.dtype())
+                    }
+                })
+                .collect();
+            DataFrame::new(columns).unwrap()
+        };
+        let mut df = frame(&protocol);
+        df.vstack_mut(&frame(&metadata)).unwrap();
+        df.vstack_mut(&frame(&add)).unwrap();
+
+        let f =
+            std::fs::File::create(table.join("_delta_log/00000000000000000001.checkpoint.parquet"))
+                .unwrap();
+        ParquetWriter::new(f).finish(&mut df).unwrap();
+    }
+
+    fn scan(table: &Path, version: Option<i64>, timestamp: Option<i64>) -> LazyFrame {
+        let args = ScanArgsDelta {
+            version,
+            timestamp,
+            ..Default::default()
+        };
+        LazyFrame::scan_delta(table, args).unwrap()
+    }
+
+    fn sorted_ids(lf: LazyFrame) -> Vec<i64> {
+        lf.sort(["id"], Default::default())
+            .collect()
+            .unwrap()
+            .column("id")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_delta_scan() -> PolarsResult<()> {
+        let table = std::env::temp_dir().join("polars_test_delta_scan");
+        let _ = std::fs::remove_dir_all(&table);
+        std::fs::create_dir_all(table.join("_delta_log"))?;
+
+        write_data_file(&table, "year=2023/0.parquet", &[0, 1, 2], &["a", "b", "c"]);
+        write_data_file(&table, "year=2024/1.parquet", &[3, 4, 5], &["d", "e", "f"]);
+        write_data_file(&table, "year=2023/2.parquet", &[0, 1, 2], &["A", "B", "C"]);
+
+        write_commit(
+            &table,
+            0,
+            &[
+                json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}}),
+                json!({"metaData": {
+                    "id": "test",
+                    "format": {"provider": "parquet", "options": {}},
+                    "schemaString": SCHEMA,
+                    "partitionColumns": ["year"],
+                    "configuration": {},
+                }}),
+                json!({"add": {
+                    "path": "year=2023/0.parquet",
+                    "partitionValues": {"year": "2023"},
+                    "size": 0, "modificationTime": 0, "dataChange": true,
+                }}),
+                json!({"add": {
+                    "path": "year%3D2024/1.parquet",
+                    "partitionValues": {"year": "2024"},
+                    "size": 0, "modificationTime": 0, "dataChange": true,
+                }}),
+            ],
+            1000,
+        );
+        write_commit(
+            &table,
+            1,
+            &[
+                json!({"commitInfo": {"operation": "WRITE"}}),
+                json!({"remove": {"path": "year=2023/0.parquet", "dataChange": true}}),
+                json!({"add": {
+                    "path": "year=2023/2.parquet",
+                    "partitionValues": {"year": "2023"},
+                    "size": 0, "modificationTime": 0, "dataChange": true,
+                }}),
+            ],
+            2000,
+        );
+        // Deletes the row with id 4 through a deletion vector.
+        write_commit(
+            &table,
+            2,
+            &[
+                json!({"protocol": {
+                    "minReaderVersion": 3,
+                    "minWriterVersion": 7,
+                    "readerFeatures": ["deletionVectors"],
+                    "writerFeatures": ["deletionVectors"],
+                }}),
+                json!({"remove": {"path": "year%3D2024/1.parquet", "dataChange": true}}),
+                json!({"add": {
+                    "path": "year=2024/1.parquet",
+                    "partitionValues": {"year": "2024"},
+                    "size": 0, "modificationTime": 0, "dataChange": true,
+                    "deletionVector": inline_deletion_vector(&[1]),
+                }}),
+            ],
+            3000,
+        );
+
+        let df = scan(&table, None, None)
+            .sort(["id"], Default::default())
+            .collect()?;
+        let expected = df!(
+            "id" => [0i64, 1, 2, 3, 5],
+            "value" => ["A", "B", "C", "d", "f"],
+            "year" => [2023i32, 2023, 2023, 2024, 2024],
+        )?;
+        assert!(df.equals_missing(&expected));
+
+        // Time travel.
+        assert_eq!(sorted_ids(scan(&table, Some(0), None)), [0, 1, 2, 3, 4, 5]);
+        let df = scan(&table, Some(0), None)
+            .filter(col("year").eq(lit(2023)))
+            .collect()?;
+        assert_eq!(df.column("value")?.str()?.get(0), Some("a"));
+        assert_eq!(
+            sorted_ids(scan(&table, None, Some(2_500_000))),
+            [0, 1, 2, 3, 4, 5]
+        );
+        let df = scan(&table, None, Some(2_500_000))
+            .filter(col("id").eq(lit(0i64)))
+            .collect()?;
+        assert_eq!(df.column("value")?.str()?.get(0), Some("A"));
+        assert!(scan(&table, Some(3), None).collect().is_err());
+        assert!(scan(&table, None, Some(500_000)).collect().is_err());
+
+        // Filters, row indices and slices are applied after the deleted rows are removed.
+        assert_eq!(
+            sorted_ids(scan(&table, None, None).filter(col("year").eq(lit(2024)))),
+            [3, 5]
+        );
+        let df = LazyFrame::scan_delta(
+            &table,
+            ScanArgsDelta {
+                row_index: Some(RowIndex {
+                    name: "index".into(),
+                    offset: 0,
+                }),
+                n_rows: Some(5),
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+        assert_eq!(
+            df.column("index")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(df.column("id")?.i64()?.get(4), Some(5));
+        let df = LazyFrame::scan_delta(
+            &table,
+            ScanArgsDelta {
+                row_index: Some(RowIndex {
+                    name: "index".into(),
+                    offset: 10,
+                }),
+                ..Default::default()
+            },
+        )?
+        .filter(col("id").gt_eq(lit(3i64)))
+        .collect()?;
+        assert_eq!(
+            df.column("index")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [13, 14]
+        );
+        let df = scan(&table, None, None).slice(-2, 2).collect()?;
+        assert_eq!(
+            df.column("id")?
+                .i64()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [3, 5]
+        );
+
+        // The deleted rows are removed by the scan of the data files, not by a filter on top of
+        // a union of scans.
+        let plan = scan(&table, None, None).explain(true)?;
+        assert!(!plan.contains("UNION"), "{plan}");
+
+        // Versions before a checkpoint can no longer be read once their commits are removed.
+        write_checkpoint(&table);
+        std::fs::remove_file(table.join("_delta_log/00000000000000000000.json"))?;
+        std::fs::remove_file(table.join("_delta_log/00000000000000000001.json"))?;
+        assert_eq!(sorted_ids(scan(&table, None, None)), [0, 1, 2, 3, 5]);
+        assert_eq!(sorted_ids(scan(&table, Some(1), None)), [0, 1, 2, 3, 4, 5]);
+        assert!(scan(&table, Some(0), None).collect().is_err());
+
+        std::fs::remove_dir_all(&table)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "iceberg")]
+mod iceberg {
+    use std::path::Path;
+
+    use polars_io::avro::AvroWriter;
+    use serde_json::json;
+
+    use super::*;
+
+    /// A data or delete file of the test table, partitioned by year.
+    struct TestFile<'a> {
+        content: i32,
+        path: String,
+        year: i32,
+        record_count: i64,
+        id_bounds: Option<(i64, i64)>,
+        equality_ids: &'a [i32],
+    }
+
+    fn write_parquet(path: &Path, mut df: DataFrame) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let f = std::fs::File::create(path).unwrap();
+        ParquetWriter::new(f).finish(&mut df).unwrap();
+    }
+
+    fn write_avro(path: &Path, mut df: DataFrame) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let f = std::fs::File::create(path).unwrap();
+        AvroWriter::new(f).finish(&mut df).unwrap();
+    }
+
+    /// Returns a column of maps from field ids to binary values, stored as lists of key-value
+    /// records.
+    fn bounds(name: &str, bounds: impl Iterator<Item = Option<(i32, Vec<u8>)>>) -> Series {
+        let rows = bounds
+            .map(|entry| {
+                let (keys, values): (Vec<_>, Vec<_>) = entry.into_iter().unzip();
+                StructChunked::from_series(
+                    "".into(),
+                    keys.len(),
+                    [
+                        Series::new("key".into(), keys),
+                        Series::new("value".into(), values),
+                    ]
+                    .iter(),
+                )
+                .unwrap()
+                .into_series()
+            })
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows)
+    }
+
+    fn write_manifest(path: &Path, files: &[TestFile]) {
+        let n = files.len();
+        let data_file = StructChunked::from_series(
+            "data_file".into(),
+            n,
+            [
+                Series::new(
+                    "content".into(),
+                    files.iter().map(|f| f.content).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "file_path".into(),
+                    files.iter().map(|f| f.path.as_str()).collect::<Vec<_>>(),
+                ),
+                Series::new("file_format".into(), vec!["PARQUET"; n]),
+                StructChunked::from_series(
+                    "partition".into(),
+                    n,
+                    [Series::new(
+                        "year".into(),
+                        files.iter().map(|f| f.year).collect::<Vec<_>>(),
+                    )]
+                    .iter(),
+                )
+                .unwrap()
+                .into_series(),
+                Series::new(
+                    "record_count".into(),
+                    files.iter().map(|f| f.record_count).collect::<Vec<_>>(),
+                ),
+                bounds(
+                    "lower_bounds",
+                    files
+                        .iter()
+                        .map(|f| f.id_bounds.map(|(l, _)| (1, l.to_le_bytes().to_vec()))),
+                ),
+                bounds(
+                    "upper_bounds",
+                    files
+                        .iter()
+                        .map(|f| f.id_bounds.map(|(_, u)| (1, u.to_le_bytes().to_vec()))),
+                ),
+                Series::new(
+                    "equality_ids".into(),
+                    files
+                        .iter()
+                        .map(|f| Series::new("".into(), f.equality_ids))
+                        .collect::<Vec<_>>(),
+                ),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+
+        let df = DataFrame::new(vec![
+            Series::new("status".into(), vec![1i32; n]).into(),
+            // Entries without a sequence number inherit the one of the manifest.
+            Series::new("sequence_number".into(), vec![None::<i64>; n]).into(),
+            data_file.into(),
+        ])
+        .unwrap();
+        write_avro(path, df);
+    }
+
+    /// Writes a manifest list with entries of (path, is deletes, sequence number, years).
+    fn write_manifest_list(path: &Path, manifests: &[(&Path, bool, i64, (i32, i32))]) {
+        let partitions = manifests
+            .iter()
+            .map(|(_, _, _, (lower, upper))| {
+                StructChunked::from_series(
+                    "".into(),
+                    1,
+                    [
+                        Series::new("contains_null".into(), [false]),
+                        Series::new("lower_bound".into(), [lower.to_le_bytes().to_vec()]),
+                        Series::new("upper_bound".into(), [upper.to_le_bytes().to_vec()]),
+                    ]
+                    .iter(),
+                )
+                .unwrap()
+                .into_series()
+            })
+            .collect::<Vec<_>>();
+
+        let df = df!(
+            "manifest_path" => manifests.iter().map(|m| m.0.to_str().unwrap()).collect::<Vec<_>>(),
+            "partition_spec_id" => vec![0i32; manifests.len()],
+            "content" => manifests.iter().map(|m| m.1 as i32).collect::<Vec<_>>(),
+            "sequence_number" => manifests.iter().map(|m| m.2).collect::<Vec<_>>(),
+            "partitions" => partitions,
+        )
+        .unwrap();
+        write_avro(path, df);
+    }
+
+    fn scan(table: &Path, snapshot_id: Option<i64>) -> LazyFrame {
+        let args = ScanArgsIceberg {
+            snapshot_id,
+            ..Default::default()
+        };
+        LazyFrame::scan_iceberg(table, args).unwrap()
+    }
+
+    fn sorted_ids(lf: LazyFrame) -> Vec<i64> {
+        lf.sort(["id"], Default::default())
+            .collect()
+            .unwrap()
+            .column("id")
+            .unwrap()
+            .i64()
+            .unwrap()
+            .into_no_null_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_iceberg_scan() -> PolarsResult<()> {
+        let table = std::env::temp_dir().join("polars_test_iceberg_scan");
+        let _ = std::fs::remove_dir_all(&table);
+        let path = |name: &str| table.join(name);
+
+        // The data files are written without field ids and are matched to the table through the
+        // name mapping.
+        for (name, ids, values, year) in [
+            ("data/a.parquet", [0i64, 1, 2], ["a", "b", "c"], 2023i32),
+            ("data/b.parquet", [3, 4, 5], ["d", "e", "f"], 2024),
+            ("data/c.parquet", [0, 6, 7], ["g", "h", "i"], 2025),
+        ] {
+            write_parquet(
+                &path(name),
+                df!("id" => ids, "value" => values, "year" => [year; 3])?,
+            );
+        }
+        // Deletes the second row of b.parquet.
+        write_parquet(
+            &path("data/b-deletes.parquet"),
+            df!(
+                "file_path" => [path("data/b.parquet").to_str().unwrap()],
+                "pos" => [1i64],
+            )?,
+        );
+        // Deletes the row with id 0 from the files written before it.
+        write_parquet(&path("data/a-deletes.parquet"), df!("id" => [0i64])?);
+
+        let file = |name: &str, content, year, record_count, id_bounds| TestFile {
+            content,
+            path: path(name).to_str().unwrap().to_string(),
+            year,
+            record_count,
+            id_bounds,
+            equality_ids: &[],
+        };
+        write_manifest(
+            &path("metadata/m1.avro"),
+            &[
+                file("data/a.parquet", 0, 2023, 3, Some((0, 2))),
+                file("data/b.parquet", 0, 2024, 3, Some((3, 5))),
+            ],
+        );
+        write_manifest(
+            &path("metadata/m2.avro"),
+            &[file("data/c.parquet", 0, 2025, 3, Some((0, 7)))],
+        );
+        write_manifest(
+            &path("metadata/m2-deletes.avro"),
+            &[
+                file("data/b-deletes.parquet", 1, 2024, 1, None),
+                TestFile {
+                    equality_ids: &[1],
+                    ..file("data/a-deletes.parquet", 2, 2023, 1, None)
+                },
+            ],
+        );
+        write_manifest_list(
+            &path("metadata/snap-1.avro"),
+            &[(&path("metadata/m1.avro"), false, 1, (2023, 2024))],
+        );
+        write_manifest_list(
+            &path("metadata/snap-2.avro"),
+            &[
+                (&path("metadata/m1.avro"), false, 1, (2023, 2024)),
+                (&path("metadata/m2.avro"), false, 2, (2025, 2025)),
+                (&path("metadata/m2-deletes.avro"), true, 2, (2023, 2024)),
+            ],
+        );
+
+        // The current schema renames `value` to `name` and adds `score`.
+        let field = |id, name, ty| json!({"id": id, "name": name, "required": false, "type": ty});
+        let schemas = json!([
+            {"type": "struct", "schema-id": 0, "fields": [
+                field(1, "id", "long"), field(2, "value", "string"), field(3, "year", "int"),
+            ]},
+            {"type": "struct", "schema-id": 1, "fields": [
+                field(1, "id", "long"), field(2, "name", "string"), field(3, "year", "int"),
+                field(4, "score", "double"),
+            ]},
+        ]);
+        let snapshot = |id: i64, manifest_list: &str| {
+            json!({
+                "snapshot-id": id,
+                "sequence-number": id,
+                "timestamp-ms": id * 1000,
+                "manifest-list": path(manifest_list).to_str().unwrap(),
+                "schema-id": 0,
+            })
+        };
+        let metadata = |snapshots: Vec<serde_json::Value>, current_schema_id| {
+            json!({
+                "format-version": 2,
+                "location": table.to_str().unwrap(),
+                "current-schema-id": current_schema_id,
+                "schemas": schemas,
+                "partition-specs": [{"spec-id": 0, "fields": [
+                    {"source-id": 3, "field-id": 1000, "name": "year", "transform": "identity"},
+                ]}],
+                "current-snapshot-id": snapshots.len(),
+                "snapshots": snapshots,
+                "properties": {
+                    "schema.name-mapping.default": json!([
+                        {"field-id": 1, "names": ["id"]},
+                        {"field-id": 2, "names": ["value"]},
+                        {"field-id": 3, "names": ["year"]},
+                    ]).to_string(),
+                },
+            })
+            .to_string()
+        };
+        std::fs::write(
+            path("metadata/v1.metadata.json"),
+            metadata(vec![snapshot(1, "metadata/snap-1.avro")], 0),
+        )?;
+        std::fs::write(
+            path("metadata/v2.metadata.json"),
+            metadata(
+                vec![
+                    snapshot(1, "metadata/snap-1.avro"),
+                    snapshot(2, "metadata/snap-2.avro"),
+                ],
+                1,
+            ),
+        )?;
+
+        let df = scan(&table, None)
+            .sort(["id"], Default::default())
+            .collect()?;
+        let expected = df!(
+            "id" => [0i64, 1, 2, 3, 5, 6, 7],
+            "name" => ["g", "b", "c", "d", "f", "h", "i"],
+            "year" => [2025i32, 2023, 2023, 2024, 2024, 2025, 2025],
+            "score" => [None::<f64>; 7],
+        )?;
+        assert!(df.equals_missing(&expected));
+
+        // Time travel reads the snapshot with its schema.
+        let df = scan(&table, Some(1))
+            .sort(["id"], Default::default())
+            .collect()?;
+        assert_eq!(df.get_column_names(), ["id", "value", "year"]);
+        assert_eq!(sorted_ids(scan(&table, Some(1))), [0, 1, 2, 3, 4, 5]);
+        assert!(scan(&table, Some(3)).collect().is_err());
+        let args = ScanArgsIceberg {
+            timestamp: Some(1500),
+            ..Default::default()
+        };
+        assert_eq!(
+            sorted_ids(LazyFrame::scan_iceberg(&table, args)?),
+            [0, 1, 2, 3, 4, 5]
+        );
+        let df = scan(&table, None)
+            .filter(col("name").eq(lit("d")))
+            .select([col("id")])
+            .collect()?;
+        assert_eq!(df.column("id")?.i64()?.get(0), Some(3));
+
+        // Positional deletes do not split the scan, only the equality deletes and renames do.
+        let plan = scan(&table, None).explain(true)?;
+        assert_eq!(plan.matches(" SCAN ").count(), 2, "{plan}");
+
+        // Filters, row indices and slices are applied after the deleted rows are removed.
+        assert_eq!(
+            sorted_ids(scan(&table, None).filter(col("id").lt_eq(lit(3i64)))),
+            [0, 1, 2, 3]
+        );
+        let df = LazyFrame::scan_iceberg(
+            &table,
+            ScanArgsIceberg {
+                row_index: Some(RowIndex {
+                    name: "index".into(),
+                    offset: 0,
+                }),
+                n_rows: Some(5),
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+        assert_eq!(
+            df.column("index")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            df.column("id")?
+                .i64()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [1, 2, 3, 5, 0]
+        );
+        assert_eq!(
+            scan(&table, None)
+                .select([len()])
+                .collect()?
+                .column("len")?
+                .idx()?
+                .get(0),
+            Some(7)
+        );
+
+        // Manifests and data files that cannot contain matching rows are not read.
+        std::fs::remove_file(path("data/a.parquet"))?;
+        std::fs::remove_file(path("data/c.parquet"))?;
+        assert_eq!(
+            sorted_ids(scan(&table, None).filter(col("year").eq(lit(2024)))),
+            [3, 5]
+        );
+        assert_eq!(
+            sorted_ids(
+                scan(&table, None).filter(col("year").eq(lit(2022)).or(col("year").eq(lit(2024))))
+            ),
+            [3, 5]
+        );
+        assert!(scan(&table, None).collect().is_err());
+
+        std::fs::remove_dir_all(&table)?;
+        Ok(())
+    }
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -479,6 +2404,224 @@ fn test_csv_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_scan_csv_utf16() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_utf16.csv");
+    // The file is transcoded in pieces while it is read, some quoted values span multiple lines.
+    let name = |i: i64| match i % 1000 {
+        0 => format!("näme\n{i}"),
+        _ => format!("näme_{i}"),
+    };
+    let text = (0..10000)
+        .map(|i| format!("{i},\"{}\"\n", name(i)))
+        .fold("id,name\n".to_string(), |acc, line| acc + &line);
+    let bytes = [0xFF, 0xFE]
+        .into_iter()
+        .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect::<Vec<_>>();
+    std::fs::write(&path, bytes).unwrap();
+
+    let expected = df![
+        "id" => (0..10000i64).collect::<Vec<_>>(),
+        "name" => (0..10000).map(name).collect::<Vec<_>>(),
+    ]?;
+
+    for encoding in [CsvEncoding::Utf16Le, CsvEncoding::Auto] {
+        let lf = LazyCsvReader::new(&path).with_encoding(encoding).finish()?;
+
+        let df = lf.clone().collect()?;
+        assert_eq!(df, expected);
+
+        let df = lf.clone().slice(9990, 20).collect()?;
+        assert_eq!(df, expected.slice(9990, 20));
+
+        let df = lf.clone().slice(-5, 5).collect()?;
+        assert_eq!(df, expected.slice(-5, 5));
+
+        let df = lf.select([len()]).collect()?;
+        assert_eq!(df.column("len")?.get(0)?, AnyValue::UInt32(10000));
+    }
+
+    // Without transcoding the file is not valid UTF-8.
+    assert!(LazyCsvReader::new(&path).finish()?.collect().is_err());
+
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+fn read_rejected_rows(path: &std::path::Path) -> PolarsResult<DataFrame> {
+    let schema = Schema::from_iter([
+        Field::new("path".into(), DataType::String),
+        Field::new("line".into(), DataType::Int64),
+        Field::new("raw".into(), DataType::String),
+        Field::new("error".into(), DataType::String),
+    ]);
+    LazyCsvReader::new(path.join("0.csv"))
+        .with_schema(Some(Arc::new(schema)))
+        .finish()?
+        .sort(["line"], Default::default())
+        .collect()
+}
+
+#[test]
+fn test_scan_csv_rejected_rows() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_rejected_rows.csv");
+    let rejected_path = std::env::temp_dir().join("polars_test_scan_csv_rejected_rows");
+    let text = (0..1000)
+        .map(|i| match i {
+            10 => "10,x\n".to_string(),
+            500 => "500\n".to_string(),
+            _ => format!("{i},{}\n", i * 2),
+        })
+        .fold("id,value\n".to_string(), |acc, line| acc + &line);
+    std::fs::write(&path, text).unwrap();
+
+    let good = (0..1000i64).filter(|i| ![10, 500].contains(i));
+    let expected = df![
+        "id" => good.clone().collect::<Vec<_>>(),
+        "value" => good.map(|i| i * 2).collect::<Vec<_>>(),
+    ]?;
+
+    let lf = LazyCsvReader::new(&path)
+        .with_schema(Some(Arc::new(expected.schema().as_ref().clone())))
+        .with_rejected_rows(Some(RejectedRowsOptions::new(&rejected_path)))
+        .finish()?;
+
+    let df = lf.clone().collect()?;
+    assert_eq!(df, expected);
+
+    let rejected = read_rejected_rows(&rejected_path)?;
+    let path_str = path.to_string_lossy();
+    assert_eq!(
+        rejected.column("path")?.str()?.iter().collect::<Vec<_>>(),
+        [Some(path_str.as_ref()), Some(path_str.as_ref())]
+    );
+    assert_eq!(
+        rejected.column("line")?.i64()?.to_vec(),
+        [Some(12), Some(502)]
+    );
+    assert_eq!(
+        rejected.column("raw")?.str()?.iter().collect::<Vec<_>>(),
+        [Some("10,x"), Some("500")]
+    );
+
+    // The rejected rows of an earlier read are not overwritten.
+    assert!(lf.clone().collect().is_err());
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    // Row index and slice apply to the clean rows. The read stops early, but the rejected rows
+    // before the end of the slice are still written.
+    let df = lf
+        .clone()
+        .with_row_index("idx", None)
+        .slice(495, 10)
+        .collect()?;
+    assert_eq!(df.drop("idx")?, expected.slice(495, 10));
+    assert_eq!(
+        df.column("idx")?.idx()?.to_vec(),
+        (495..505).map(Some).collect::<Vec<_>>()
+    );
+    assert_eq!(read_rejected_rows(&rejected_path)?.height(), 2);
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    // Values in columns that are not selected are still checked.
+    let df = lf.clone().select([col("id")]).collect()?;
+    assert_eq!(df, expected.select(["id"])?);
+    assert_eq!(read_rejected_rows(&rejected_path)?.height(), 2);
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    let df = lf.select([len()]).collect()?;
+    assert_eq!(df.column("len")?.get(0)?, AnyValue::UInt32(998));
+
+    std::fs::remove_file(&path).unwrap();
+    _ = std::fs::remove_dir_all(&rejected_path);
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_escape_char_multi_byte_separator() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_csv_escape_char.csv");
+    // Escaped line endings and separators must not split rows, also across chunk boundaries.
+    let text = (0..1000)
+        .map(|i| format!("{i}::line\\\n{i}\\::x::\"q::\\\"{i}\"\n"))
+        .fold("id::text::quoted\n".to_string(), |acc, line| acc + &line);
+    std::fs::write(&path, text).unwrap();
+
+    let expected = df![
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "text" => (0..1000).map(|i| format!("line\n{i}::x")).collect::<Vec<_>>(),
+        "quoted" => (0..1000).map(|i| format!("q::\"{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    let lf = LazyCsvReader::new(&path)
+        .with_multi_byte_separator("::")
+        .with_escape_char(Some(b'\\'))
+        .finish()?;
+
+    assert_eq!(lf.clone().collect()?, expected);
+    assert_eq!(
+        lf.clone().select([col("quoted")]).slice(700, 5).collect()?,
+        expected.select(["quoted"])?.slice(700, 5)
+    );
+    let n = lf.select([len()]).collect()?;
+    assert_eq!(n.column("len")?.idx()?.get(0), Some(1000));
+
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson_rejected_rows() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_ndjson_rejected_rows.ndjson");
+    let rejected_path = std::env::temp_dir().join("polars_test_scan_ndjson_rejected_rows");
+    let text = (0..1000)
+        .map(|i| match i {
+            10 => "{\"id\":10,\"value\":\n".to_string(),
+            500 => "{\"id\":500,\"value\":\"x\"}\n".to_string(),
+            _ => format!("{{\"id\":{i},\"value\":{}}}\n", i * 2),
+        })
+        .collect::<String>();
+    std::fs::write(&path, text).unwrap();
+
+    let good = (0..1000i64).filter(|i| ![10, 500].contains(i));
+    let expected = df![
+        "id" => good.clone().collect::<Vec<_>>(),
+        "value" => good.map(|i| i * 2).collect::<Vec<_>>(),
+    ]?;
+
+    let lf = LazyJsonLineReader::new(&path)
+        .with_schema(Some(Arc::new(expected.schema().as_ref().clone())))
+        .with_rejected_rows(Some(RejectedRowsOptions::new(&rejected_path)))
+        .finish()?;
+
+    let df = lf.clone().collect()?;
+    assert_eq!(df, expected);
+
+    let rejected = read_rejected_rows(&rejected_path)?;
+    assert_eq!(
+        rejected.column("line")?.i64()?.to_vec(),
+        [Some(11), Some(501)]
+    );
+    assert_eq!(
+        rejected.column("raw")?.str()?.get(1),
+        Some(r#"{"id":500,"value":"x"}"#)
+    );
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    let df = lf.with_row_index("idx", None).tail(5).collect()?;
+    assert_eq!(df.drop("idx")?, expected.tail(Some(5)));
+    assert_eq!(
+        df.column("idx")?.idx()?.to_vec(),
+        (993..998).map(Some).collect::<Vec<_>>()
+    );
+    assert_eq!(read_rejected_rows(&rejected_path)?.height(), 2);
+
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "json")]
 fn test_ndjson_globbing() -> PolarsResult<()> {
@@ -59,6 +59,10 @@ static NUTRI_SCORE_NULL_COLUMN_PARQUET: &str = "../../examples/datasets/null_nut
 static FOODS_CSV: &str = "../../examples/datasets/foods1.csv";
 #[cfg(feature = "ipc")]
 static FOODS_IPC: &str = "../../examples/datasets/foods1.ipc";
+#[cfg(feature = "avro")]
+static FOODS_AVRO: &str = "../../examples/datasets/foods1.avro";
+#[cfg(feature = "orc")]
+static FOODS_ORC: &str = "../../examples/datasets/foods1.orc";
 
 #[cfg(feature = "csv")]
 fn scan_foods_csv() -> LazyFrame {
@@ -88,7 +92,7 @@ fn init_files() {
         "../../examples/datasets/foods2.csv",
         "../../examples/datasets/null_nutriscore.csv",
     ] {
-        for ext in [".parquet", ".ipc", ".ndjson"] {
+        for ext in [".parquet", ".ipc", ".ndjson", ".avro", ".orc"] {
             let out_path = path.replace(".csv", ext);
 
             if std::fs::metadata(&out_path).is_err() {
@@ -118,6 +122,18 @@ fn init_files() {
                             JsonWriter::new(f).finish(&mut df).unwrap()
                         }
                     },
+                    ".avro" => {
+                        #[cfg(feature = "avro")]
+                        {
+                            polars_io::avro::AvroWriter::new(f).finish(&mut df).unwrap()
+                        }
+                    },
+                    ".orc" => {
+                        #[cfg(feature = "orc")]
+                        {
+                            polars_io::orc::OrcWriter::new(f).finish(&mut df).unwrap()
+                        }
+                    },
                     _ => panic!(),
                 }
             }
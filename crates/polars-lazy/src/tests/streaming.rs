@@ -29,6 +29,36 @@ fn assert_streaming_with_default(q: LazyFrame, check_shape_only: bool) {
     }
 }
 
+/// Collects `q` on the streaming engine and compares it with the in-memory engine.
+#[cfg(feature = "new_streaming")]
+fn assert_streaming_engine(q: LazyFrame) {
+    let out = q.clone().collect_with_engine(Engine::Streaming).unwrap();
+    let expected = q.collect_with_engine(Engine::InMemory).unwrap();
+    assert_eq!(out, expected);
+}
+
+/// Collects `q` on the streaming engine with a memory budget of `budget` bytes
+/// and compares it with the in-memory engine. Returns the number of rows that
+/// were spilled.
+#[cfg(feature = "new_streaming")]
+fn assert_streaming_with_budget(q: LazyFrame, budget: usize) -> u64 {
+    let (out, spilled_rows) = polars_stream::with_memory_budget(budget, || {
+        q.clone().collect_with_engine(Engine::Streaming).unwrap()
+    });
+    let expected = q.collect_with_engine(Engine::InMemory).unwrap();
+    assert_eq!(out, expected);
+    spilled_rows
+}
+
+/// Like [`assert_streaming_with_budget`], with a budget of one byte so that
+/// the memory intensive nodes spill everything.
+#[cfg(feature = "new_streaming")]
+fn assert_streaming_spilled(q: LazyFrame) -> u64 {
+    let spilled_rows = assert_streaming_with_budget(q, 1);
+    assert!(spilled_rows > 0);
+    spilled_rows
+}
+
 #[test]
 fn test_streaming_parquet() -> PolarsResult<()> {
     let q = get_parquet_file();
@@ -417,3 +447,433 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_sort_spill() -> PolarsResult<()> {
+    // Spill every morsel as a sorted run, so the output is merged from disk.
+    let n = 10_000;
+    let df = df![
+        "a" => (0..n).map(|i| (i * 7919) % 1000).collect::<Vec<i32>>(),
+        "b" => (0..n).map(|i| (i % 10 != 0).then_some((i * 31 % 97) as f64)).collect::<Vec<_>>(),
+        "c" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let lf = concat([df.clone().lazy(), df.lazy()], Default::default())?;
+    let options = SortMultipleOptions::default()
+        .with_order_descending_multi([false, true])
+        .with_nulls_last(true)
+        .with_maintain_order(true);
+
+    let q = lf
+        .clone()
+        .sort_by_exprs([col("a"), col("b")], options.clone());
+    assert_streaming_spilled(q);
+
+    // Top-k and slices.
+    let q = lf
+        .clone()
+        .sort_by_exprs([col("a") % lit(7), col("b")], options.clone())
+        .slice(100, 50);
+    assert_streaming_spilled(q);
+    let q = lf
+        .clone()
+        .sort_by_exprs([col("b"), col("c")], options)
+        .tail(25);
+    assert_streaming_spilled(q);
+    let q = lf
+        .clone()
+        .sort(["a", "c"], Default::default())
+        .limit(300)
+        .tail(40);
+    assert_streaming_spilled(q);
+
+    let q = lf.sort(
+        ["c"],
+        SortMultipleOptions::default().with_order_descending(true),
+    );
+    assert_streaming_spilled(q);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_join_spill() -> PolarsResult<()> {
+    // The build side exceeds the memory budget right away, so both sides are
+    // partitioned to disk and joined one partition at a time.
+    let n = 5_000;
+    let left = df![
+        "a" => (0..n).map(|i| (i % 7 != 0).then_some((i * 7919) % 2000)).collect::<Vec<_>>(),
+        "l" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let right = df![
+        "a" => (0..n).map(|i| (i % 5 != 0).then_some((i * 31) % 3000)).collect::<Vec<_>>(),
+        "r" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let lf_left = concat(
+        (0..4).map(|_| left.clone().lazy()).collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+    let lf_right = concat(
+        (0..4).map(|_| right.clone().lazy()).collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+
+    for how in [
+        JoinType::Inner,
+        JoinType::Left,
+        JoinType::Right,
+        JoinType::Full,
+    ] {
+        for coalesce in [JoinCoalesce::CoalesceColumns, JoinCoalesce::KeepColumns] {
+            let q = lf_left
+                .clone()
+                .join_builder()
+                .with(lf_right.clone())
+                .left_on([col("a")])
+                .right_on([col("a")])
+                .how(how.clone())
+                .coalesce(coalesce)
+                .finish()
+                .sort(["l", "r"], Default::default());
+            assert_streaming_spilled(q);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_join_spill_repartition() -> PolarsResult<()> {
+    // With a 1 KiB budget the spilled partitions of the 160 KB build side are
+    // still over budget, so they are split again. Key 0 makes up a partition
+    // that can't be split and is joined in memory at the maximum depth.
+    let n = 20_000;
+    let left = df![
+        "a" => (0..n).map(|i| if i % 40 == 0 { 0 } else { (i * 7919) % 5000 }).collect::<Vec<i32>>(),
+        "l" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let right = df![
+        "a" => (0..n).map(|i| match i {
+            _ if i % 50 == 0 => Some(0),
+            _ if i % 7 == 0 => None,
+            _ => Some((i * 31) % 6000),
+        }).collect::<Vec<_>>(),
+        "r" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+
+    for how in [JoinType::Inner, JoinType::Full] {
+        let q = left
+            .clone()
+            .lazy()
+            .join(right.clone().lazy(), [col("a")], [col("a")], how.into())
+            .sort(["l", "r"], Default::default());
+        assert!(assert_streaming_with_budget(q, 1 << 10) > 0);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_group_by_spill() -> PolarsResult<()> {
+    // Every local spills after its first morsel, so most rows are aggregated
+    // from disk.
+    let n = 5_000;
+    let df = df![
+        "a" => (0..n).map(|i| (i * 7919) % 3000).collect::<Vec<i32>>(),
+        "b" => (0..n).map(|i| (i % 10 != 0).then_some((i * 31 % 97) as f64)).collect::<Vec<_>>(),
+        "c" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let lf = concat(
+        (0..8).map(|_| df.clone().lazy()).collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+
+    let q = lf
+        .clone()
+        .group_by([col("a")])
+        .agg([
+            col("b").sum().alias("b_sum"),
+            col("b").mean().alias("b_mean"),
+            col("c").first().alias("c_first"),
+            col("c").last().alias("c_last"),
+            len(),
+        ])
+        .sort(["a"], Default::default());
+    assert_streaming_spilled(q);
+
+    let q = lf
+        .group_by([col("a") % lit(100), col("b")])
+        .agg([col("c").max(), col("a").count().alias("a_count")])
+        .sort_by_exprs([col("a"), col("b")], Default::default());
+    assert_streaming_spilled(q);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "new_streaming", feature = "round_series"))]
+fn test_streaming_group_by_spill_state() -> PolarsResult<()> {
+    // Most rows have a null key, which always stays in the hot table, so they
+    // are aggregated in memory and only the state of their group is spilled.
+    // This writes the state of every kind of reduction to disk and reads it
+    // back, while far fewer rows are spilled than there are in the input.
+    let n = 4_000;
+    let df = df![
+        "a" => (0..n).map(|i| (i % 8 == 0).then_some((i * 7919) % 50)).collect::<Vec<_>>(),
+        "f" => (0..n).map(|i| (i % 9 != 0).then_some((i * 31 % 97) as f64)).collect::<Vec<_>>(),
+        "s" => (0..n).map(|i| (i % 7 != 0).then(|| format!("s{}", i * 13 % 101))).collect::<Vec<_>>(),
+        "b" => (0..n).map(|i| (i % 5 != 0).then_some(i % 3 == 0)).collect::<Vec<_>>(),
+        "l" => (0..n).map(|i| Series::new(PlSmallStr::EMPTY, [i, i + 1])).collect::<Vec<_>>(),
+    ]?;
+    let lf = concat(
+        (0..4).map(|_| df.clone().lazy()).collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+
+    let q = lf.group_by([col("a")]).agg([
+        col("f").sum().alias("f_sum"),
+        col("f").mean().alias("f_mean"),
+        col("f").min().alias("f_min"),
+        col("f").max().alias("f_max"),
+        col("f")
+            .var(1)
+            .round(6, polars_ops::series::RoundMode::default())
+            .alias("f_var"),
+        col("f").count().alias("f_count"),
+        col("s").min().alias("s_min"),
+        col("s").max().alias("s_max"),
+        col("s").first().alias("s_first"),
+        col("s").last().alias("s_last"),
+        col("b").min().alias("b_min"),
+        col("b").max().alias("b_max"),
+        col("b").sum().alias("b_sum"),
+        col("b").mean().alias("b_mean"),
+        col("b").last().alias("b_last"),
+        col("l").first().alias("l_first"),
+        col("l").last().alias("l_last"),
+        len(),
+    ]);
+    let (out, spilled_rows) = polars_stream::with_memory_budget(1, || {
+        q.clone().collect_with_engine(Engine::Streaming).unwrap()
+    });
+    let expected = q.collect_with_engine(Engine::InMemory)?;
+    let sort = |df: DataFrame| df.sort(["a"], Default::default());
+    assert_eq!(sort(out)?, sort(expected)?);
+    assert!(spilled_rows > 0 && spilled_rows < n as u64);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "asof_join", feature = "new_streaming"))]
+fn test_streaming_asof_join() -> PolarsResult<()> {
+    use polars_ops::frame::{AsOfOptions, AsofStrategy};
+
+    // Both sides consist of several morsels with increasing keys, so the
+    // right side is consumed incrementally.
+    let trades = concat(
+        (0..4i64)
+            .map(|c| {
+                df![
+                    "t" => (0..300).map(|i| c * 1000 + i * 3).collect::<Vec<i64>>(),
+                    "sym" => (0..300).map(|i| i % 3).collect::<Vec<i32>>(),
+                    "l" => (0..300).collect::<Vec<i32>>(),
+                ]
+                .unwrap()
+                .lazy()
+            })
+            .collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+    let quotes = concat(
+        (0..4i64)
+            .map(|c| {
+                df![
+                    "t" => (0..140).map(|i| c * 1000 + i * 7 + 1).collect::<Vec<i64>>(),
+                    "sym" => (0..140).map(|i| i % 4).collect::<Vec<i32>>(),
+                    "r" => (0..140).collect::<Vec<i32>>(),
+                ]
+                .unwrap()
+                .lazy()
+            })
+            .collect::<Vec<_>>(),
+        Default::default(),
+    )?;
+
+    for strategy in [
+        AsofStrategy::Backward,
+        AsofStrategy::Forward,
+        AsofStrategy::Nearest,
+    ] {
+        for by in [false, true] {
+            for allow_eq in [true, false] {
+                for tolerance in [None, Some(AnyValue::Int64(5))] {
+                    let by = by.then(|| vec!["sym".into()]);
+                    let q = trades
+                        .clone()
+                        .join_builder()
+                        .with(quotes.clone())
+                        .left_on([col("t")])
+                        .right_on([col("t")])
+                        .how(JoinType::AsOf(AsOfOptions {
+                            strategy,
+                            tolerance,
+                            left_by: by.clone(),
+                            right_by: by,
+                            allow_eq,
+                            check_sortedness: true,
+                            ..Default::default()
+                        }))
+                        .finish();
+                    assert_streaming_engine(q);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "asof_join", feature = "new_streaming"))]
+fn test_streaming_asof_join_by_sorted_within_groups() -> PolarsResult<()> {
+    use polars_ops::frame::{AsOfOptions, AsofStrategy};
+
+    // Both sides are sorted by (sym, t), so the keys restart in every group
+    // and are not sorted globally.
+    let frames = |n: i64, step: i64, offset: i64, value: &str| {
+        concat(
+            ["a", "b", "c"]
+                .into_iter()
+                .map(|sym| {
+                    df![
+                        "t" => (0..n).map(|i| i * step + offset).collect::<Vec<i64>>(),
+                        "sym" => vec![sym; n as usize],
+                        value => (0..n).collect::<Vec<i64>>(),
+                    ]
+                    .unwrap()
+                    .lazy()
+                })
+                .collect::<Vec<_>>(),
+            Default::default(),
+        )
+    };
+    let trades = frames(300, 3, 0, "l")?;
+    let quotes = frames(140, 7, 1, "r")?;
+
+    for strategy in [
+        AsofStrategy::Backward,
+        AsofStrategy::Forward,
+        AsofStrategy::Nearest,
+    ] {
+        let by = Some(vec!["sym".into()]);
+        let q = trades
+            .clone()
+            .join_builder()
+            .with(quotes.clone())
+            .left_on([col("t")])
+            .right_on([col("t")])
+            .how(JoinType::AsOf(AsOfOptions {
+                strategy,
+                left_by: by.clone(),
+                right_by: by,
+                ..Default::default()
+            }))
+            .finish();
+        let out = q.clone().collect_with_engine(Engine::Streaming)?;
+        // Only the first trade of each group has no earlier quote.
+        let n_unmatched = if strategy == AsofStrategy::Backward {
+            3
+        } else {
+            0
+        };
+        assert_eq!(out.column("r")?.null_count(), n_unmatched);
+        assert_streaming_engine(q);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iejoin", feature = "new_streaming"))]
+fn test_streaming_ie_join() -> PolarsResult<()> {
+    // Unequal sides, so the smaller one gets buffered, with some nulls.
+    let east = df![
+        "id" => (0..500).collect::<Vec<i32>>(),
+        "dur" => (0..500).map(|i| (i * 37 % 101) as i64).collect::<Vec<i64>>(),
+        "rev" => (0..500).map(|i| (i % 7 != 0).then_some(i * 13 % 83)).collect::<Vec<_>>(),
+    ]?
+    .lazy();
+    let west = df![
+        "t_id" => (0..60).collect::<Vec<i32>>(),
+        "time" => (0..60).map(|i| (i * 11 % 97) as i64).collect::<Vec<i64>>(),
+        "cost" => (0..60).map(|i| (i % 5 != 0).then_some(i * 7 % 61)).collect::<Vec<_>>(),
+    ]?
+    .lazy();
+
+    let predicates = [
+        vec![col("dur").lt(col("time"))],
+        vec![col("dur").gt_eq(col("time"))],
+        vec![col("dur").lt_eq(col("time")), col("rev").gt(col("cost"))],
+        vec![col("dur").gt(col("time")), col("rev").lt_eq(col("cost"))],
+    ];
+    for predicates in predicates {
+        for (left, right) in [(&east, &west), (&west, &east)] {
+            let q = left
+                .clone()
+                .join_builder()
+                .with(right.clone())
+                .join_where(predicates.clone())
+                .sort(["id", "t_id"], Default::default());
+            assert_streaming_engine(q);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iejoin", feature = "new_streaming"))]
+fn test_streaming_join_where_mixed_predicates() -> PolarsResult<()> {
+    // Equality predicates next to inequalities, some on nullable columns.
+    let east = df![
+        "id" => (0..500).collect::<Vec<i32>>(),
+        "grp" => (0..500).map(|i| (i % 9 != 0).then_some(i % 4)).collect::<Vec<_>>(),
+        "dur" => (0..500).map(|i| (i * 37 % 101) as i64).collect::<Vec<i64>>(),
+        "rev" => (0..500).map(|i| (i % 7 != 0).then_some(i * 13 % 83)).collect::<Vec<_>>(),
+    ]?
+    .lazy();
+    let west = df![
+        "t_id" => (0..60).collect::<Vec<i32>>(),
+        "t_grp" => (0..60).map(|i| (i % 11 != 0).then_some(i % 4)).collect::<Vec<_>>(),
+        "time" => (0..60).map(|i| (i * 11 % 97) as i64).collect::<Vec<i64>>(),
+        "cost" => (0..60).map(|i| (i % 5 != 0).then_some(i * 7 % 61)).collect::<Vec<_>>(),
+    ]?
+    .lazy();
+
+    let predicates = [
+        vec![col("grp").eq(col("t_grp")), col("dur").lt(col("time"))],
+        vec![
+            col("dur").gt_eq(col("time")),
+            col("grp").eq(col("t_grp")),
+            col("rev").lt(col("cost")),
+        ],
+        vec![
+            col("grp").eq(col("t_grp")),
+            col("rev").eq(col("cost")),
+            col("dur").lt_eq(col("time")),
+        ],
+    ];
+    for predicates in predicates {
+        for (left, right) in [(&east, &west), (&west, &east)] {
+            let q = left
+                .clone()
+                .join_builder()
+                .with(right.clone())
+                .join_where(predicates.clone())
+                .sort(["id", "t_id"], Default::default());
+            assert_streaming_engine(q);
+        }
+    }
+    Ok(())
+}
@@ -7,7 +7,9 @@ use polars_utils::format_pl_smallstr;
 use recursive::recursive;
 
 use self::expr_ir::OutputName;
-use self::predicates::{aexpr_to_column_predicates, aexpr_to_skip_batch_predicate};
+use self::predicates::{
+    aexpr_to_column_predicates, aexpr_to_column_value_sets, aexpr_to_skip_batch_predicate,
+};
 #[cfg(feature = "python")]
 use self::python_dsl::PythonScanSource;
 use super::super::executors::{self, Executor};
@@ -263,10 +265,16 @@ fn create_physical_plan_impl(
                         FileType::Parquet(_) => "parquet",
                         #[cfg(feature = "ipc")]
                         FileType::Ipc(_) => "ipc",
+                        #[cfg(feature = "ipc_streaming")]
+                        FileType::IpcStream(_) => "ipc_stream",
                         #[cfg(feature = "csv")]
                         FileType::Csv(_) => "csv",
                         #[cfg(feature = "json")]
                         FileType::Json(_) => "json",
+                        #[cfg(feature = "avro")]
+                        FileType::Avro(_) => "avro",
+                        #[cfg(feature = "pg_copy")]
+                        FileType::PgCopy(_) => "pg_copy",
                         #[allow(unreachable_patterns)]
                         _ => panic!("enable filetype feature"),
                     };
@@ -289,6 +297,12 @@ fn create_physical_plan_impl(
                                         .with_statistics(options.statistics)
                                         .with_row_group_size(options.row_group_size)
                                         .with_data_page_size(options.data_page_size)
+                                        .with_bloom_filters(options.bloom_filters.clone())
+                                        .with_column_options(options.column_options.clone())
+                                        .with_key_value_metadata(options.key_value_metadata.clone())
+                                        .with_field_metadata(options.field_metadata.clone())
+                                        .with_field_ids(options.field_ids.clone())
+                                        .with_encryption(options.encryption.clone())
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "ipc")]
@@ -300,6 +314,15 @@ fn create_physical_plan_impl(
                                         .with_compat_level(options.compat_level)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "ipc_streaming")]
+                                FileType::IpcStream(options) => {
+                                    use polars_io::SerWriter;
+                                    use polars_io::ipc::IpcStreamWriter;
+                                    IpcStreamWriter::new(BufWriter::new(writer))
+                                        .with_compression(options.compression)
+                                        .with_compat_level(options.compat_level)
+                                        .finish(&mut df)?;
+                                },
                                 #[cfg(feature = "csv")]
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
@@ -312,6 +335,10 @@ fn create_physical_plan_impl(
                                             options.serialize_options.line_terminator.clone(),
                                         )
                                         .with_quote_char(options.serialize_options.quote_char)
+                                        .with_multi_byte_separator(
+                                            options.serialize_options.multi_byte_separator.clone(),
+                                        )
+                                        .with_escape_char(options.serialize_options.escape_char)
                                         .with_batch_size(options.batch_size)
                                         .with_datetime_format(
                                             options.serialize_options.datetime_format.clone(),
@@ -330,17 +357,29 @@ fn create_physical_plan_impl(
                                         )
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
+                                        .with_compression(options.compression)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
 
                                     JsonWriter::new(BufWriter::new(writer))
                                         .with_json_format(JsonFormat::JsonLines)
+                                        .with_compression(options.compression)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
+                                },
+                                #[cfg(feature = "pg_copy")]
+                                FileType::PgCopy(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
+                                },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
                             }
@@ -867,8 +906,11 @@ pub fn create_scan_predicate(
     )));
 
     let mut skip_batch_predicate = None;
+    let mut column_value_sets = PlHashMap::default();
 
     if create_skip_batch_predicate {
+        column_value_sets = aexpr_to_column_value_sets(predicate.node(), expr_arena, schema);
+
         if let Some(node) = aexpr_to_skip_batch_predicate(predicate.node(), expr_arena, schema) {
             let expr = ExprIR::new(node, predicate.output_name_inner().clone());
 
@@ -952,5 +994,6 @@ pub fn create_scan_predicate(
         live_columns,
         skip_batch_predicate,
         column_predicates,
+        column_value_sets: Arc::new(column_value_sets),
     })
 }
@@ -6,6 +6,7 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, Column, Field, GroupPositions, PlHashMap, PlIndexSet};
 use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaRef};
+use polars_core::series::Series;
 use polars_error::PolarsResult;
 use polars_expr::prelude::{AggregationContext, PhysicalExpr, phys_expr_to_io_expr};
 use polars_expr::state::ExecutionState;
@@ -33,6 +34,9 @@ pub struct ScanPredicate {
 
     /// Partial predicates for each column for filter when loading columnar formats.
     pub column_predicates: PhysicalColumnPredicates,
+
+    /// See [`ScanIOPredicate::column_value_sets`].
+    pub column_value_sets: Arc<PlHashMap<PlSmallStr, Series>>,
 }
 
 impl fmt::Debug for ScanPredicate {
@@ -157,12 +161,21 @@ impl ScanPredicate {
             }) as _
         });
 
+        let column_value_sets = Arc::new(
+            self.column_value_sets
+                .iter()
+                .filter(|(name, _)| live_columns.contains(*name))
+                .map(|(name, values)| (name.clone(), values.clone()))
+                .collect(),
+        );
+
         Self {
             predicate,
             live_columns: Arc::new(live_columns),
             skip_batch_predicate,
             column_predicates: self.column_predicates.clone(), // Q? Maybe this should cull
-                                                               // predicates.
+            // predicates.
+            column_value_sets,
         }
     }
 
@@ -198,6 +211,7 @@ impl ScanPredicate {
                     .collect(),
                 is_sumwise_complete: self.column_predicates.is_sumwise_complete,
             }),
+            column_value_sets: self.column_value_sets.clone(),
         }
     }
 }
@@ -4,5 +4,12 @@ pub mod write;
 #[cfg(feature = "bloom_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bloom_filter")))]
 pub use crate::parquet::bloom_filter;
+#[cfg(feature = "encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub use crate::parquet::encryption;
 
-const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The footer key-value metadata key under which the Arrow schema of the file is stored.
+pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+
+/// The Arrow field metadata key whose value is written as the Parquet field id of the field.
+pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
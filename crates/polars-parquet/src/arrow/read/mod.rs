@@ -58,6 +58,18 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads parquets' metadata synchronously, decrypting it with `decryption` if the file is
+/// encrypted.
+#[cfg(feature = "encryption")]
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&crate::parquet::encryption::FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(crate::parquet::read::read_metadata_with_decryption(
+        reader, decryption,
+    )?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -12,7 +12,8 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::indexes::{ColumnIndex, column_index_statistics};
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -299,246 +300,291 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+    if !supports_statistics_arrays(field.dtype()) {
+        return Ok(None);
+    }
+
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+    let statistics = row_groups
+        .iter()
+        .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose())
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    deserialize_statistics_arrays(field, primitive_type, statistics)
+}
+
+/// Deserializes the per-page statistics of a [`ColumnIndex`] of a non-nested column into arrays
+/// with one element per data page.
+///
+/// Returns `None` if statistics are not supported for the type of `field`, see
+/// [`supports_page_statistics`].
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_page_statistics(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    column_index: &ColumnIndex,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    if !supports_statistics_arrays(field.dtype()) {
+        return Ok(None);
+    }
+
+    let statistics = column_index_statistics(column_index, primitive_type)?
+        .into_iter()
+        .map(Some)
+        .collect();
+
+    deserialize_statistics_arrays(field, primitive_type, statistics)
+}
+
+/// Returns whether [`deserialize_page_statistics`] can deserialize the statistics of a column of
+/// `dtype`. Dictionary encoded types (e.g. categoricals) are not supported.
+pub fn supports_page_statistics(dtype: &ArrowDataType) -> bool {
+    supports_statistics_arrays(dtype)
+}
+
+fn supports_statistics_arrays(dtype: &ArrowDataType) -> bool {
     use ArrowDataType as D;
-    match field.dtype() {
-        // @TODO: These are all a bit more complex, skip for now.
-        D::List(..) | D::LargeList(..) => Ok(None),
-        D::Dictionary(..) => Ok(None),
-        D::FixedSizeList(..) => Ok(None),
-        D::Struct(..) => Ok(None),
 
-        _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
-
-            let logical_type = &primitive_type.logical_type;
-            let physical_type = &primitive_type.physical_type;
-
-            macro_rules! rmap {
-                ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
-
-                        let (v_min, v_max, v_null_count, v_distinct_count) = match s {
-                            None => (None, None, None, None),
-                            Some(s) => {
-                                let s = s.$expect();
-
-                                let min = s.min_value;
-                                let max = s.max_value;
-
-                                let min = ($map)(min)?;
-                                let max = ($map)(max)?;
-
-                                (
-                                min,
-                                max,
-                                s.null_count.map(|v| v as IdxSize),
-                                s.distinct_count.map(|v| v as IdxSize),
-                                )
-                            }
-                        };
-
-                        min_arr.push(v_min);
-                        max_arr.push(v_max);
-                        null_count.push(v_null_count);
-                        distinct_count.push(v_distinct_count);
+    // @TODO: These are all a bit more complex, skip for now.
+    !matches!(
+        dtype,
+        D::List(..) | D::LargeList(..) | D::Dictionary(..) | D::FixedSizeList(..) | D::Struct(..)
+    )
+}
+
+fn deserialize_statistics_arrays(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    statistics: Vec<Option<ParquetStatistics>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let len = statistics.len();
+
+    let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+    let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+
+    let logical_type = &primitive_type.logical_type;
+    let physical_type = &primitive_type.physical_type;
+
+    macro_rules! rmap {
+        ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
+            let mut min_arr = <$arr>::with_capacity(len$(, $arg)?);
+            let mut max_arr = <$arr>::with_capacity(len$(, $arg)?);
+
+            for s in statistics {
+                let (v_min, v_max, v_null_count, v_distinct_count) = match s {
+                    None => (None, None, None, None),
+                    Some(s) => {
+                        let s = s.$expect();
+
+                        let min = s.min_value;
+                        let max = s.max_value;
+
+                        let min = ($map)(min)?;
+                        let max = ($map)(max)?;
+
+                        (
+                        min,
+                        max,
+                        s.null_count.map(|v| v as IdxSize),
+                        s.distinct_count.map(|v| v as IdxSize),
+                        )
                     }
+                };
 
-                    (min_arr.freeze().to_boxed(), max_arr.freeze().to_boxed())
-                }};
-                ($expect:ident, $arr:ty, @prim $from:ty $(as $to:ty)? $(, $map:expr)?) => {{
-                    rmap!(
-                        $expect,
-                        |x: Option<$from>| {
-                            $(
-                            let x = x.map(|x| x as $to);
-                            )?
-                            $(
-                            let x = x.map($map);
-                            )?
-                            ParquetResult::Ok(x)
-                        },
-                        $arr
-                    )
-                }};
-                (@binary $(, $map:expr)?) => {{
-                    rmap!(
-                        expect_binary,
-                        |x: Option<Vec<u8>>| {
-                            $(
-                            let x = x.map($map);
-                            )?
-                            ParquetResult::Ok(x)
-                        },
-                        MutableBinaryViewArray<[u8]>
-                    )
-                }};
-                (@string) => {{
-                    rmap!(
-                        expect_binary,
-                        |x: Option<Vec<u8>>| {
-                            let x = x.map(String::from_utf8).transpose().map_err(|_| {
-                                ParquetError::oos("Invalid UTF8 in Statistics")
-                            })?;
-                            ParquetResult::Ok(x)
-                        },
-                        MutableBinaryViewArray<str>
-                    )
-                }};
+                min_arr.push(v_min);
+                max_arr.push(v_max);
+                null_count.push(v_null_count);
+                distinct_count.push(v_distinct_count);
             }
 
-            use {ArrowDataType as D, ParquetPhysicalType as PPT};
-            let (min_value, max_value) = match (field.dtype(), physical_type) {
-                (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                ),
-
-                (D::Boolean, _) => rmap!(
-                    expect_boolean,
-                    |x: Option<bool>| ParquetResult::Ok(x),
-                    MutableBooleanArray
-                ),
-
-                (D::Int8, _) => rmap!(expect_int32, MutablePrimitiveArray::<i8>, @prim i32 as i8),
-                (D::Int16, _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i16>, @prim i32 as i16)
+            (min_arr.freeze().to_boxed(), max_arr.freeze().to_boxed())
+        }};
+        ($expect:ident, $arr:ty, @prim $from:ty $(as $to:ty)? $(, $map:expr)?) => {{
+            rmap!(
+                $expect,
+                |x: Option<$from>| {
+                    $(
+                    let x = x.map(|x| x as $to);
+                    )?
+                    $(
+                    let x = x.map($map);
+                    )?
+                    ParquetResult::Ok(x)
                 },
-                (D::Int32 | D::Date32 | D::Time32(_), _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i32>, @prim i32 as i32)
+                $arr
+            )
+        }};
+        (@binary $(, $map:expr)?) => {{
+            rmap!(
+                expect_binary,
+                |x: Option<Vec<u8>>| {
+                    $(
+                    let x = x.map($map);
+                    )?
+                    ParquetResult::Ok(x)
                 },
-
-                // some implementations of parquet write arrow's date64 into i32.
-                (D::Date64, PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i64>, @prim i32 as i64, |x| x * 86400000)
+                MutableBinaryViewArray<[u8]>
+            )
+        }};
+        (@string) => {{
+            rmap!(
+                expect_binary,
+                |x: Option<Vec<u8>>| {
+                    let x = x.map(String::from_utf8).transpose().map_err(|_| {
+                        ParquetError::oos("Invalid UTF8 in Statistics")
+                    })?;
+                    ParquetResult::Ok(x)
                 },
+                MutableBinaryViewArray<str>
+            )
+        }};
+    }
 
-                (D::Int64 | D::Time64(_) | D::Duration(_), _) | (D::Date64, PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64 as i64)
-                },
+    use {ArrowDataType as D, ParquetPhysicalType as PPT};
+    let (min_value, max_value) = match (field.dtype(), physical_type) {
+        (D::Null, _) => (
+            NullArray::new(ArrowDataType::Null, len).to_boxed(),
+            NullArray::new(ArrowDataType::Null, len).to_boxed(),
+        ),
+
+        (D::Boolean, _) => rmap!(
+            expect_boolean,
+            |x: Option<bool>| ParquetResult::Ok(x),
+            MutableBooleanArray
+        ),
+
+        (D::Int8, _) => rmap!(expect_int32, MutablePrimitiveArray::<i8>, @prim i32 as i8),
+        (D::Int16, _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i16>, @prim i32 as i16)
+        },
+        (D::Int32 | D::Date32 | D::Time32(_), _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i32>, @prim i32 as i32)
+        },
 
-                (D::Interval(IntervalUnit::YearMonth), _) => rmap!(
-                    expect_binary,
-                    MutablePrimitiveArray::<i32>,
-                    @prim Vec<u8>,
-                    |x| convert_year_month(&x)
-                ),
-                (D::Interval(IntervalUnit::DayTime), _) => rmap!(
-                    expect_binary,
-                    MutablePrimitiveArray::<days_ms>,
-                    @prim Vec<u8>,
-                    |x| convert_days_ms(&x)
-                ),
-
-                (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
-                (D::UInt16, _) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<u16>, @prim i32 as u16)
-                },
-                (D::UInt32, PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<u32>, @prim i32 as u32)
-                },
+        // some implementations of parquet write arrow's date64 into i32.
+        (D::Date64, PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i64>, @prim i32 as i64, |x| x * 86400000)
+        },
 
-                // some implementations of parquet write arrow's u32 into i64.
-                (D::UInt32, PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<u32>, @prim i64 as u32)
-                },
-                (D::UInt64, _) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
-                },
+        (D::Int64 | D::Time64(_) | D::Duration(_), _) | (D::Date64, PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64 as i64)
+        },
 
-                (D::Timestamp(time_unit, _), PPT::Int96) => {
-                    rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
-                        timestamp(logical_type.as_ref(), *time_unit, int96_to_i64_ns(x))
-                    })
-                },
-                (D::Timestamp(time_unit, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64, |x| {
-                        timestamp(logical_type.as_ref(), *time_unit, x)
-                    })
-                },
+        (D::Interval(IntervalUnit::YearMonth), _) => rmap!(
+            expect_binary,
+            MutablePrimitiveArray::<i32>,
+            @prim Vec<u8>,
+            |x| convert_year_month(&x)
+        ),
+        (D::Interval(IntervalUnit::DayTime), _) => rmap!(
+            expect_binary,
+            MutablePrimitiveArray::<days_ms>,
+            @prim Vec<u8>,
+            |x| convert_days_ms(&x)
+        ),
+
+        (D::UInt8, _) => rmap!(expect_int32, MutablePrimitiveArray::<u8>, @prim i32 as u8),
+        (D::UInt16, _) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<u16>, @prim i32 as u16)
+        },
+        (D::UInt32, PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<u32>, @prim i32 as u32)
+        },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
-                (_, PPT::FixedLenByteArray(2))
-                    if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
-                {
-                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f32>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]).to_f32())
-                },
-                (D::Float32, _) => rmap!(expect_float, MutablePrimitiveArray::<f32>, @prim f32),
-                (D::Float64, _) => rmap!(expect_double, MutablePrimitiveArray::<f64>, @prim f64),
+        // some implementations of parquet write arrow's u32 into i64.
+        (D::UInt32, PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<u32>, @prim i64 as u32)
+        },
+        (D::UInt64, _) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
+        },
 
-                (D::Decimal(_, _), PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i128>, @prim i32 as i128)
-                },
-                (D::Decimal(_, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i128>, @prim i64 as i128)
-                },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
-                    return Err(ParquetError::not_supported(format!(
-                        "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
-                    )));
-                },
-                (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
-                    expect_fixedlen,
-                    MutablePrimitiveArray::<i128>,
-                    @prim Vec<u8>,
-                    |x| convert_i128(&x, *n)
-                ),
-                (D::Decimal256(_, _), PPT::Int32) => {
-                    rmap!(expect_int32, MutablePrimitiveArray::<i256>, @prim i32, |x: i32| i256(I256::new(x.into())))
-                },
-                (D::Decimal256(_, _), PPT::Int64) => {
-                    rmap!(expect_int64, MutablePrimitiveArray::<i256>, @prim i64, |x: i64| i256(I256::new(x.into())))
-                },
-                (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
-                    return Err(ParquetError::not_supported(format!(
-                        "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
-                    )));
-                },
-                (D::Decimal256(_, _), PPT::FixedLenByteArray(_)) => rmap!(
-                    expect_fixedlen,
-                    MutablePrimitiveArray::<i256>,
-                    @prim Vec<u8>,
-                    |x| convert_i256(&x)
-                ),
-                (D::Binary, _) => rmap!(@binary),
-                (D::LargeBinary, _) => rmap!(@binary),
-                (D::Utf8, _) => rmap!(@string),
-                (D::LargeUtf8, _) => rmap!(@string),
-
-                (D::BinaryView, _) => rmap!(@binary),
-                (D::Utf8View, _) => rmap!(@string),
-
-                (D::FixedSizeBinary(width), _) => {
-                    rmap!(
-                        expect_fixedlen,
-                        |x: Option<Vec<u8>>| ParquetResult::Ok(x),
-                        MutableFixedSizeBinaryArray,
-                        *width
-                    )
-                },
+        (D::Timestamp(time_unit, _), PPT::Int96) => {
+            rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
+                timestamp(logical_type.as_ref(), *time_unit, int96_to_i64_ns(x))
+            })
+        },
+        (D::Timestamp(time_unit, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i64>, @prim i64, |x| {
+                timestamp(logical_type.as_ref(), *time_unit, x)
+            })
+        },
 
-                other => todo!("{:?}", other),
-            };
+        // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+        (_, PPT::FixedLenByteArray(2))
+            if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
+        {
+            rmap!(expect_fixedlen, MutablePrimitiveArray::<f32>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]).to_f32())
+        },
+        (D::Float32, _) => rmap!(expect_float, MutablePrimitiveArray::<f32>, @prim f32),
+        (D::Float64, _) => rmap!(expect_double, MutablePrimitiveArray::<f64>, @prim f64),
 
-            Ok(Some(ArrowColumnStatisticsArrays {
-                null_count: null_count.freeze(),
-                distinct_count: distinct_count.freeze(),
-                min_value,
-                max_value,
-            }))
+        (D::Decimal(_, _), PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i128>, @prim i32 as i128)
         },
-    }
+        (D::Decimal(_, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i128>, @prim i64 as i128)
+        },
+        (D::Decimal(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            return Err(ParquetError::not_supported(format!(
+                "Can't decode Decimal128 type from Fixed Size Byte Array of len {n:?}",
+            )));
+        },
+        (D::Decimal(_, _), PPT::FixedLenByteArray(n)) => rmap!(
+            expect_fixedlen,
+            MutablePrimitiveArray::<i128>,
+            @prim Vec<u8>,
+            |x| convert_i128(&x, *n)
+        ),
+        (D::Decimal256(_, _), PPT::Int32) => {
+            rmap!(expect_int32, MutablePrimitiveArray::<i256>, @prim i32, |x: i32| i256(I256::new(x.into())))
+        },
+        (D::Decimal256(_, _), PPT::Int64) => {
+            rmap!(expect_int64, MutablePrimitiveArray::<i256>, @prim i64, |x: i64| i256(I256::new(x.into())))
+        },
+        (D::Decimal256(_, _), PPT::FixedLenByteArray(n)) if *n > 16 => {
+            return Err(ParquetError::not_supported(format!(
+                "Can't decode Decimal256 type from Fixed Size Byte Array of len {n:?}",
+            )));
+        },
+        (D::Decimal256(_, _), PPT::FixedLenByteArray(_)) => rmap!(
+            expect_fixedlen,
+            MutablePrimitiveArray::<i256>,
+            @prim Vec<u8>,
+            |x| convert_i256(&x)
+        ),
+        (D::Binary, _) => rmap!(@binary),
+        (D::LargeBinary, _) => rmap!(@binary),
+        (D::Utf8, _) => rmap!(@string),
+        (D::LargeUtf8, _) => rmap!(@string),
+
+        (D::BinaryView, _) => rmap!(@binary),
+        (D::Utf8View, _) => rmap!(@string),
+
+        (D::FixedSizeBinary(width), _) => {
+            rmap!(
+                expect_fixedlen,
+                |x: Option<Vec<u8>>| ParquetResult::Ok(x),
+                MutableFixedSizeBinaryArray,
+                *width
+            )
+        },
+
+        // Statistics of other types are not supported.
+        _ => return Ok(None),
+    };
+
+    Ok(Some(ArrowColumnStatisticsArrays {
+        null_count: null_count.freeze(),
+        distinct_count: distinct_count.freeze(),
+        min_value,
+        max_value,
+    }))
 }
 
 /// Deserializes the statistics in the column chunks from a single `row_group`
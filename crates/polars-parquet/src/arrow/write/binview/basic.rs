@@ -2,7 +2,7 @@ use arrow::array::{Array, BinaryViewArray};
 use polars_compute::min_max::MinMaxKernel;
 use polars_error::PolarsResult;
 
-use crate::parquet::encoding::delta_bitpacked;
+use crate::parquet::encoding::{delta_bitpacked, delta_byte_array};
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::read::schema::is_nullable;
@@ -58,6 +58,19 @@ pub(crate) fn encode_delta(
     }
 }
 
+pub(crate) fn encode_delta_byte_array(
+    array: &BinaryViewArray,
+    options: EncodeNullability,
+    buffer: &mut Vec<u8>,
+) {
+    let values = if options.is_optional() && array.validity().is_some() {
+        array.non_null_values_iter().collect::<Vec<_>>()
+    } else {
+        array.values_iter().collect::<Vec<_>>()
+    };
+    delta_byte_array::encode(values.into_iter(), buffer);
+}
+
 pub fn array_to_page(
     array: &BinaryViewArray,
     options: WriteOptions,
@@ -82,6 +95,7 @@ pub fn array_to_page(
     match encoding {
         Encoding::Plain => encode_plain(array, encode_options, &mut buffer),
         Encoding::DeltaLengthByteArray => encode_delta(array, encode_options, &mut buffer),
+        Encoding::DeltaByteArray => encode_delta_byte_array(array, encode_options, &mut buffer),
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
@@ -0,0 +1,139 @@
+use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType as ArrowNativeType;
+use num_traits::AsPrimitive;
+use polars_utils::aliases::PlHashSet;
+
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::types::NativeType;
+
+/// The options of the split-block bloom filter written for a column chunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The target false positive probability.
+    pub fpp: f64,
+    /// The number of distinct values to size the bloom filter for. If `None`, the number of
+    /// distinct values of each column chunk is used.
+    pub ndv: Option<u64>,
+}
+
+/// Whether a bloom filter can be written for a (non-nested) column of `dtype`.
+pub fn supports_bloom_filter(dtype: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+
+    matches!(
+        dtype.to_logical_type(),
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Float32
+            | D::Float64
+            | D::Date32
+            | D::Date64
+            | D::Time32(_)
+            | D::Time64(_)
+            | D::Timestamp(_, _)
+            | D::Duration(_)
+            | D::Utf8
+            | D::LargeUtf8
+            | D::Utf8View
+            | D::Binary
+            | D::LargeBinary
+            | D::BinaryView
+    )
+}
+
+/// Builds the bitset of a split-block bloom filter containing all non-null values of `array`.
+///
+/// The values are hashed as their plain encoded physical type, matching how they are written by
+/// [`array_to_columns`](super::array_to_columns). Returns `None` if the data type is not supported.
+pub fn array_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    use ArrowDataType as D;
+
+    let hashes = match array.dtype().to_logical_type() {
+        D::UInt8 => hash_primitive::<u8, i32>(array),
+        D::UInt16 => hash_primitive::<u16, i32>(array),
+        D::UInt32 => hash_primitive::<u32, i32>(array),
+        D::UInt64 => hash_primitive::<u64, i64>(array),
+        D::Int8 => hash_primitive::<i8, i32>(array),
+        D::Int16 => hash_primitive::<i16, i32>(array),
+        D::Int32 | D::Date32 | D::Time32(_) => hash_primitive::<i32, i32>(array),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            hash_primitive::<i64, i64>(array)
+        },
+        D::Float32 => hash_primitive::<f32, f32>(array),
+        D::Float64 => hash_primitive::<f64, f64>(array),
+        D::Utf8 => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()?
+                .non_null_values_iter(),
+        ),
+        D::LargeUtf8 => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()?
+                .non_null_values_iter(),
+        ),
+        D::Utf8View => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()?
+                .non_null_values_iter(),
+        ),
+        D::Binary => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()?
+                .non_null_values_iter(),
+        ),
+        D::LargeBinary => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()?
+                .non_null_values_iter(),
+        ),
+        D::BinaryView => hash_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()?
+                .non_null_values_iter(),
+        ),
+        _ => return None,
+    }?;
+
+    let ndv = options.ndv.unwrap_or(hashes.len() as u64);
+    let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+    for hash in hashes {
+        insert(&mut bitset, hash);
+    }
+
+    Some(bitset)
+}
+
+fn hash_primitive<T, P>(array: &dyn Array) -> Option<PlHashSet<u64>>
+where
+    T: ArrowNativeType + AsPrimitive<P>,
+    P: NativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>()?;
+    Some(
+        array
+            .non_null_values_iter()
+            .map(|value| hash_native::<P>(value.as_()))
+            .collect(),
+    )
+}
+
+fn hash_bytes<I, B>(values: I) -> Option<PlHashSet<u64>>
+where
+    I: Iterator<Item = B>,
+    B: AsRef<[u8]>,
+{
+    Some(values.map(hash_byte).collect())
+}
@@ -5,6 +5,8 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{ThriftFileMetadata, WriteOptions, to_parquet_schema};
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::FileEncryptionProperties;
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
@@ -86,11 +88,25 @@ impl<W: Write> FileWriter<W> {
         ))
     }
 
+    /// Encrypts the file with the given [`FileEncryptionProperties`].
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIterColumns<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes the bloom filter bitsets of the column chunks of the last written row group, one
+    /// entry per leaf column.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filters(&mut self, bitsets: &[Option<Vec<u8>>]) -> PolarsResult<()> {
+        Ok(self.writer.write_bloom_filters(bitsets)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
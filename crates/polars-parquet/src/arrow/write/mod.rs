@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -92,6 +94,8 @@ pub struct WriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{BloomFilterOptions, array_to_bloom_filter, supports_bloom_filter};
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
@@ -370,6 +374,44 @@ pub fn array_to_page(
     array_to_page_nested(array, type_, nested, options, encoding)
 }
 
+/// Whether the data pages of a (non-nested) column of `dtype` can be written with `encoding`.
+///
+/// Dictionary encoding is not a data page encoding and is therefore not covered here.
+pub fn supports_encoding(dtype: &ArrowDataType, encoding: Encoding) -> bool {
+    use ArrowDataType as D;
+
+    match encoding {
+        Encoding::Plain => !matches!(dtype.to_logical_type(), D::Dictionary(_, _, _)),
+        Encoding::Rle => matches!(dtype.to_logical_type(), D::Boolean),
+        Encoding::DeltaBinaryPacked => matches!(
+            dtype.to_logical_type(),
+            D::Int8
+                | D::Int16
+                | D::Int32
+                | D::Int64
+                | D::UInt8
+                | D::UInt16
+                | D::UInt32
+                | D::UInt64
+                | D::Date32
+                | D::Date64
+                | D::Time32(_)
+                | D::Time64(_)
+                | D::Timestamp(_, _)
+                | D::Duration(_)
+        ),
+        Encoding::ByteStreamSplit => {
+            matches!(dtype.to_logical_type(), D::Float32 | D::Float64)
+                || supports_encoding(dtype, Encoding::DeltaBinaryPacked)
+        },
+        Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray => matches!(
+            dtype.to_logical_type(),
+            D::LargeBinary | D::LargeUtf8 | D::BinaryView | D::Utf8View
+        ),
+        _ => false,
+    }
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page_simple(
     array: &dyn Array,
@@ -455,16 +497,22 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
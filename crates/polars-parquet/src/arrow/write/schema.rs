@@ -2,10 +2,10 @@ use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUni
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::Repetition;
@@ -80,7 +80,28 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
 }
 
 /// Creates a [`ParquetType`] from a [`Field`].
+///
+/// The field id of the type is taken from the [`PARQUET_FIELD_ID_META_KEY`] metadata of the field.
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
+    let mut parquet_type = to_parquet_type_without_id(field)?;
+
+    if let Some(id) = field
+        .metadata
+        .as_deref()
+        .and_then(|metadata| metadata.get(PARQUET_FIELD_ID_META_KEY))
+    {
+        let id = id.parse::<i32>().map_err(|_| {
+            polars_err!(
+                ComputeError: "invalid Parquet field id '{}' of field '{}'", id, field.name
+            )
+        })?;
+        parquet_type.get_field_info_mut().id = Some(id);
+    }
+
+    Ok(parquet_type)
+}
+
+fn to_parquet_type_without_id(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
     let repetition = if field.is_nullable {
         Repetition::Optional
@@ -1,4 +1,5 @@
 use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_parquet_format::{
@@ -10,7 +11,8 @@ use crate::parquet::error::ParquetResult;
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
-/// Results in an empty `bitset` if there is no associated bloom filter or the algorithm is not supported.
+/// Results in an empty `bitset` if there is no associated bloom filter, the algorithm is not supported
+/// or the column chunk is encrypted.
 /// # Error
 /// Errors if the column contains no metadata or the filter can't be read or deserialized.
 pub fn read<R: Read + Seek>(
@@ -20,7 +22,7 @@ pub fn read<R: Read + Seek>(
 ) -> ParquetResult<()> {
     let offset = column_metadata.metadata().bloom_filter_offset;
 
-    let offset = if let Some(offset) = offset {
+    let offset = if let (Some(offset), false) = (offset, column_metadata.is_encrypted()) {
         offset as u64
     } else {
         bitset.clear();
@@ -49,3 +51,28 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the [`BloomFilterHeader`] at the start of `bytes`.
+///
+/// Returns the byte range of the bitset relative to the start of `bytes`, or `None` if the
+/// algorithm or compression is not supported. The range may extend past the end of `bytes`.
+/// # Error
+/// Errors if the header can't be deserialized.
+pub fn read_header(bytes: &[u8]) -> ParquetResult<Option<Range<usize>>> {
+    let mut reader = bytes;
+
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
+        return Ok(None);
+    }
+    if header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {}) {
+        return Ok(None);
+    }
+
+    let header_size = bytes.len() - reader.len();
+    let length: usize = header.num_bytes.try_into()?;
+
+    Ok(Some(header_size..header_size + length))
+}
@@ -0,0 +1,45 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// The minimum size of a bitset, the size of a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The maximum size of a bitset, as used by parquet-mr.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the optimal number of bytes of a bitset holding `ndv` distinct values with a false
+/// positive probability of at most `fpp`.
+///
+/// The result is a power of two between 32 bytes and 128MiB.
+pub fn optimal_num_bytes(ndv: u64, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+        .min(MAX_NUM_BYTES)
+}
+
+/// Writes `bitset` as a split-block bloom filter, including its header, to `writer`.
+/// Returns the number of bytes written.
+pub fn write<W: Write>(mut writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader::new(
+        bitset.len().try_into()?,
+        BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        BloomFilterHash::XXHASH(XxHash {}),
+        BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    );
+
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)?;
+    writer.write_all(bitset)?;
+
+    Ok((header_len + bitset.len()) as u64)
+}
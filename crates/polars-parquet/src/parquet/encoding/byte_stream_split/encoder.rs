@@ -0,0 +1,20 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes `data` according to BYTE_STREAM_SPLIT and appends the result to `buffer`.
+///
+/// The k-th byte of every value is written to the k-th stream, and the streams are written one
+/// after the other.
+pub fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
+    let num_elements = data.len();
+
+    let offset = buffer.len();
+    buffer.resize(offset + size_of_val(data), 0);
+    let out = &mut buffer[offset..];
+
+    for (i, v) in data.iter().enumerate() {
+        let value_bytes = v.to_le_bytes();
+        for (n, byte) in value_bytes.as_ref().iter().enumerate() {
+            out[(num_elements * n) + i] = *byte;
+        }
+    }
+}
@@ -1,12 +1,13 @@
 mod decoder;
+mod encoder;
 
 pub use decoder::Decoder;
+pub use encoder::encode;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parquet::error::ParquetError;
-    use crate::parquet::types::NativeType;
 
     #[test]
     fn round_trip_f32() -> Result<(), ParquetError> {
@@ -59,19 +60,4 @@ mod tests {
 
         Ok(())
     }
-
-    fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
-        let element_size = size_of::<T>();
-        let num_elements = data.len();
-        let total_length = size_of_val(data);
-        buffer.resize(total_length, 0);
-
-        for (i, v) in data.iter().enumerate() {
-            let value_bytes = v.to_le_bytes();
-            let value_bytes_ref = value_bytes.as_ref();
-            for n in 0..element_size {
-                buffer[(num_elements * n) + i] = value_bytes_ref[n];
-            }
-        }
-    }
 }
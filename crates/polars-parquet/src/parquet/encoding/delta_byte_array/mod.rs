@@ -24,4 +24,18 @@ mod tests {
         assert_eq!(values, b"Helloicopter");
         Ok(())
     }
+    #[test]
+    fn value_is_prefix_of_previous() -> Result<(), ParquetError> {
+        let data = vec![b"Helicopter".as_ref(), b"Heli", b""];
+        let mut buffer = vec![];
+        encode(data.clone().into_iter(), &mut buffer);
+
+        let mut decoder = Decoder::try_new(&buffer)?;
+        let values = decoder.by_ref().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            values,
+            vec![b"Helicopter".to_vec(), b"Heli".to_vec(), b"".to_vec()]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,662 @@
+//! Parquet [modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Only the `AES_GCM_V1` algorithm is supported. Files are written with an encrypted footer and
+//! either encrypt every column with the footer key or only the columns that were given their own
+//! key. Reading supports both encrypted and plaintext footers.
+use std::sync::{Arc, Mutex};
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    AesGcmV1, ColumnCryptoMetaData, ColumnMetaData, CompressionCodec, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData,
+    FileMetaData as TFileMetadata,
+};
+use polars_utils::aliases::PlHashMap;
+use ring::aead::{AES_128_GCM, AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use super::error::{ParquetError, ParquetResult};
+use super::metadata::{ColumnDescriptor, FileMetadata, SchemaDescriptor};
+
+/// The length of the little-endian length prefix of an encrypted module.
+const LENGTH_PREFIX_LEN: usize = 4;
+/// The length of the AES-GCM authentication tag.
+const TAG_LEN: usize = 16;
+/// The length of the random file identifier that is part of every module AAD.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// Retrieves the key of the footer or of a column from the key metadata stored in the file.
+///
+/// The key metadata is empty if the writer did not store any.
+pub type KeyRetriever = dyn Fn(&[u8]) -> ParquetResult<Vec<u8>> + Send + Sync;
+
+/// The type of an encrypted module, part of the module AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+/// An AES-GCM key, together with the metadata a reader uses to retrieve it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EncryptionKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("key", &"<redacted>")
+            .field("key_metadata", &self.key_metadata)
+            .finish()
+    }
+}
+
+impl EncryptionKey {
+    /// Returns a new [`EncryptionKey`]. The key must be 16 or 32 bytes long.
+    pub fn new(key: Vec<u8>) -> Self {
+        Self {
+            key,
+            key_metadata: None,
+        }
+    }
+
+    /// Sets the metadata stored in the file to retrieve this key, e.g. a key identifier.
+    pub fn with_key_metadata(mut self, key_metadata: Vec<u8>) -> Self {
+        self.key_metadata = Some(key_metadata);
+        self
+    }
+}
+
+/// The keys to encrypt a file with.
+///
+/// The footer is always encrypted with the footer key. If no column keys are set every column is
+/// encrypted with the footer key, otherwise only the columns with a key are encrypted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FileEncryptionProperties {
+    footer_key: EncryptionKey,
+    column_keys: Vec<(String, EncryptionKey)>,
+}
+
+impl FileEncryptionProperties {
+    /// Returns a new [`FileEncryptionProperties`] that encrypts the footer with `footer_key`.
+    pub fn new(footer_key: EncryptionKey) -> Self {
+        Self {
+            footer_key,
+            column_keys: vec![],
+        }
+    }
+
+    /// Encrypts the column named `column` with `key`. For nested columns this applies to all of
+    /// the leaf columns under it.
+    pub fn with_column_key(mut self, column: impl Into<String>, key: EncryptionKey) -> Self {
+        self.column_keys.push((column.into(), key));
+        self
+    }
+}
+
+/// Retrieved keys by their key metadata.
+type KeyCache = PlHashMap<Vec<u8>, Arc<[u8]>>;
+
+/// The key retriever used to decrypt a file.
+///
+/// Keys are only retrieved once they are needed, i.e. when the footer or a column chunk is
+/// decrypted, and are cached by their key metadata.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    key_retriever: Arc<KeyRetriever>,
+    keys: Arc<Mutex<KeyCache>>,
+}
+
+impl std::fmt::Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .finish_non_exhaustive()
+    }
+}
+
+impl FileDecryptionProperties {
+    /// Returns a new [`FileDecryptionProperties`] that retrieves keys with `key_retriever`.
+    pub fn new(key_retriever: Arc<KeyRetriever>) -> Self {
+        Self {
+            key_retriever,
+            keys: Default::default(),
+        }
+    }
+
+    fn retrieve_key(&self, key_metadata: Option<&[u8]>) -> ParquetResult<Arc<[u8]>> {
+        let key_metadata = key_metadata.unwrap_or_default();
+        if let Some(key) = self.keys.lock().unwrap().get(key_metadata) {
+            return Ok(key.clone());
+        }
+
+        let key = (self.key_retriever)(key_metadata)?;
+        cipher(&key)?;
+        let key: Arc<[u8]> = key.into();
+        self.keys
+            .lock()
+            .unwrap()
+            .insert(key_metadata.to_vec(), key.clone());
+        Ok(key)
+    }
+}
+
+fn cipher(key: &[u8]) -> ParquetResult<LessSafeKey> {
+    let algorithm = match key.len() {
+        16 => &AES_128_GCM,
+        32 => &AES_256_GCM,
+        len => {
+            return Err(ParquetError::InvalidParameter(format!(
+                "AES-GCM keys must be 16 or 32 bytes long, got a key of {len} bytes"
+            )));
+        },
+    };
+    Ok(LessSafeKey::new(UnboundKey::new(algorithm, key).unwrap()))
+}
+
+fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    ordinals: Option<(i16, i16)>,
+    page_ordinal: Option<i16>,
+) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if let Some((row_group_ordinal, column_ordinal)) = ordinals {
+        aad.extend_from_slice(&row_group_ordinal.to_le_bytes());
+        aad.extend_from_slice(&column_ordinal.to_le_bytes());
+    }
+    if let Some(page_ordinal) = page_ordinal {
+        aad.extend_from_slice(&page_ordinal.to_le_bytes());
+    }
+    aad
+}
+
+/// Encrypts `plaintext` into a module of the form `length | nonce | ciphertext | tag`.
+fn encrypt_module(key: &[u8], aad: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+    let cipher = cipher(key)?;
+
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| ParquetError::oos("Failed to generate a random nonce"))?;
+
+    let length: u32 = (NONCE_LEN + plaintext.len() + TAG_LEN)
+        .try_into()
+        .map_err(|_| ParquetError::oos("An encrypted module can only contain u32::MAX bytes"))?;
+
+    let mut module = Vec::with_capacity(LENGTH_PREFIX_LEN + length as usize);
+    module.extend_from_slice(&length.to_le_bytes());
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(plaintext);
+
+    let tag = cipher
+        .seal_in_place_separate_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(aad),
+            &mut module[LENGTH_PREFIX_LEN + NONCE_LEN..],
+        )
+        .map_err(|_| ParquetError::oos("Failed to encrypt a module"))?;
+    module.extend_from_slice(tag.as_ref());
+
+    Ok(module)
+}
+
+/// Decrypts a module of the form `length | nonce | ciphertext | tag`.
+fn decrypt_module(key: &[u8], aad: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    let length = encrypted_module_len(module)?;
+    if length != module.len() || length < LENGTH_PREFIX_LEN + NONCE_LEN + TAG_LEN {
+        return Err(ParquetError::oos(
+            "The encrypted module has an invalid length",
+        ));
+    }
+
+    let nonce =
+        Nonce::try_assume_unique_for_key(&module[LENGTH_PREFIX_LEN..LENGTH_PREFIX_LEN + NONCE_LEN])
+            .unwrap();
+    let mut buffer = module[LENGTH_PREFIX_LEN + NONCE_LEN..].to_vec();
+    let plaintext_len = cipher(key)?
+        .open_in_place(nonce, Aad::from(aad), &mut buffer)
+        .map_err(|_| {
+            ParquetError::oos(
+                "Failed to decrypt a module, the key is wrong or the file is corrupted",
+            )
+        })?
+        .len();
+    buffer.truncate(plaintext_len);
+
+    Ok(buffer)
+}
+
+/// Returns the total length of the encrypted module at the start of `bytes`, including its length
+/// prefix.
+pub(crate) fn encrypted_module_len(bytes: &[u8]) -> ParquetResult<usize> {
+    let length = bytes
+        .get(..LENGTH_PREFIX_LEN)
+        .ok_or_else(|| ParquetError::oos("The encrypted module is truncated"))?;
+    let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+    Ok(LENGTH_PREFIX_LEN + length)
+}
+
+fn ordinal(ordinal: usize, name: &str) -> ParquetResult<i16> {
+    ordinal.try_into().map_err(|_| {
+        ParquetError::not_supported(format!(
+            "Encrypted files can contain at most {} {name}s",
+            i16::MAX as usize + 1
+        ))
+    })
+}
+
+fn file_aad(algorithm: &EncryptionAlgorithm) -> ParquetResult<Vec<u8>> {
+    let EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+        aad_prefix,
+        aad_file_unique,
+        supply_aad_prefix,
+    }) = algorithm
+    else {
+        return Err(ParquetError::not_supported(
+            "Only the AES_GCM_V1 encryption algorithm is supported",
+        ));
+    };
+    if supply_aad_prefix.unwrap_or(false) {
+        return Err(ParquetError::not_supported(
+            "Decrypting files with a supplied AAD prefix",
+        ));
+    }
+
+    let mut file_aad = aad_prefix.clone().unwrap_or_default();
+    file_aad.extend(aad_file_unique.iter().flatten());
+    Ok(file_aad)
+}
+
+/// Encrypts the footer and the column chunks of a file that is being written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> ParquetResult<Self> {
+        cipher(&properties.footer_key.key)?;
+        for (name, key) in &properties.column_keys {
+            if !schema.fields().iter().any(|field| field.name() == name) {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "An encryption key was given for the column \"{name}\" which is not in the schema"
+                )));
+            }
+            cipher(&key.key)?;
+        }
+
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        SystemRandom::new()
+            .fill(&mut aad_file_unique)
+            .map_err(|_| ParquetError::oos("Failed to generate a random file identifier"))?;
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+        })
+    }
+
+    fn algorithm(&self) -> EncryptionAlgorithm {
+        EncryptionAlgorithm::AESGCMV1(AesGcmV1::new(None, self.aad_file_unique.clone(), None))
+    }
+
+    /// Returns the encryptor of the column chunk, or `None` if the column is not encrypted.
+    pub(crate) fn column_encryptor(
+        &self,
+        descriptor: &ColumnDescriptor,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> ParquetResult<Option<ColumnEncryptor>> {
+        let (key, crypto_metadata) = if self.properties.column_keys.is_empty() {
+            (
+                &self.properties.footer_key,
+                ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {}),
+            )
+        } else {
+            let root = &descriptor.path_in_schema[0];
+            let Some((_, key)) = self
+                .properties
+                .column_keys
+                .iter()
+                .find(|(name, _)| name == root)
+            else {
+                return Ok(None);
+            };
+            (
+                key,
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey::new(
+                    descriptor
+                        .path_in_schema
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>(),
+                    key.key_metadata.clone(),
+                )),
+            )
+        };
+
+        Ok(Some(ColumnEncryptor {
+            key: key.key.clone(),
+            file_aad: self.aad_file_unique.clone(),
+            ordinals: (
+                ordinal(row_group_ordinal, "row group")?,
+                ordinal(column_ordinal, "column")?,
+            ),
+            crypto_metadata,
+        }))
+    }
+
+    /// Serializes the [`FileCryptoMetaData`] followed by the encrypted `metadata`.
+    pub(crate) fn encrypt_footer(&self, metadata: &TFileMetadata) -> ParquetResult<Vec<u8>> {
+        let mut buffer = vec![];
+        let crypto_metadata = FileCryptoMetaData::new(
+            self.algorithm(),
+            self.properties.footer_key.key_metadata.clone(),
+        );
+        crypto_metadata.write_to_out_protocol(
+            &mut polars_parquet_format::thrift::protocol::TCompactOutputProtocol::new(&mut buffer),
+        )?;
+
+        let mut plaintext = vec![];
+        metadata.write_to_out_protocol(
+            &mut polars_parquet_format::thrift::protocol::TCompactOutputProtocol::new(
+                &mut plaintext,
+            ),
+        )?;
+        let aad = module_aad(&self.aad_file_unique, ModuleType::Footer, None, None);
+        buffer.extend(encrypt_module(
+            &self.properties.footer_key.key,
+            &aad,
+            &plaintext,
+        )?);
+
+        Ok(buffer)
+    }
+}
+
+/// Encrypts the modules of a single column chunk.
+pub(crate) struct ColumnEncryptor {
+    key: Vec<u8>,
+    file_aad: Vec<u8>,
+    ordinals: (i16, i16),
+    crypto_metadata: ColumnCryptoMetaData,
+}
+
+impl ColumnEncryptor {
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let page_ordinal = page_ordinal.map(|x| ordinal(x, "page")).transpose()?;
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            Some(self.ordinals),
+            page_ordinal,
+        );
+        encrypt_module(&self.key, &aad, plaintext)
+    }
+
+    pub(crate) fn crypto_metadata(&self) -> &ColumnCryptoMetaData {
+        &self.crypto_metadata
+    }
+
+    /// Whether the column is encrypted with its own key, in which case its metadata is stored
+    /// encrypted in the column chunk instead of in the footer.
+    pub(crate) fn uses_column_key(&self) -> bool {
+        matches!(
+            self.crypto_metadata,
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(_)
+        )
+    }
+}
+
+/// Decrypts the page headers and pages of a single column chunk.
+///
+/// The key of the column chunk is only retrieved when the first module is decrypted.
+#[derive(Clone)]
+pub struct ColumnDecryptor {
+    decryption: FileDecryptionProperties,
+    key_metadata: Option<Arc<[u8]>>,
+    file_aad: Arc<[u8]>,
+    ordinals: (i16, i16),
+    /// Set if the metadata of the column chunk could not be decrypted while reading the footer,
+    /// in which case the column chunk cannot be read.
+    metadata_error: Option<Arc<str>>,
+}
+
+impl PartialEq for ColumnDecryptor {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.decryption.keys, &other.decryption.keys)
+            && self.key_metadata == other.key_metadata
+            && self.file_aad == other.file_aad
+            && self.ordinals == other.ordinals
+    }
+}
+
+impl Eq for ColumnDecryptor {}
+
+impl std::fmt::Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("row_group_ordinal", &self.ordinals.0)
+            .field("column_ordinal", &self.ordinals.1)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ColumnDecryptor {
+    fn key(&self) -> ParquetResult<Arc<[u8]>> {
+        if let Some(err) = &self.metadata_error {
+            return Err(ParquetError::oos(format!(
+                "The metadata of an encrypted column chunk could not be decrypted: {err}"
+            )));
+        }
+        self.decryption.retrieve_key(self.key_metadata.as_deref())
+    }
+
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let page_ordinal = page_ordinal.map(|x| ordinal(x, "page")).transpose()?;
+        let aad = module_aad(
+            &self.file_aad,
+            module_type,
+            Some(self.ordinals),
+            page_ordinal,
+        );
+        decrypt_module(&self.key()?, &aad, module)
+    }
+
+    /// Retrieves the key of the column chunk, so that a missing key is reported before any of its
+    /// pages are read.
+    pub(crate) fn check_key(&self) -> ParquetResult<()> {
+        self.key().map(|_| ())
+    }
+}
+
+/// Deserializes the metadata of an encrypted file.
+///
+/// `metadata` are the bytes before the 8 byte footer. If `encrypted_footer` is set they contain the
+/// [`FileCryptoMetaData`] followed by the encrypted footer, otherwise the plaintext footer.
+///
+/// Only the footer key is retrieved here. The keys of the columns are retrieved when their pages
+/// are decrypted, so that columns whose key is not available can still be skipped.
+pub(crate) fn deserialize_metadata(
+    mut metadata: &[u8],
+    encrypted_footer: bool,
+    decryption: &FileDecryptionProperties,
+) -> ParquetResult<FileMetadata> {
+    // a highly nested but sparse struct could result in many allocations
+    let max_size = metadata.len() * 2 + 1024;
+
+    let (mut metadata, file_aad, footer_key_metadata) = if encrypted_footer {
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(
+            &mut TCompactInputProtocol::new(&mut metadata, max_size),
+        )?;
+        let file_aad = file_aad(&crypto_metadata.encryption_algorithm)?;
+        let footer_key = decryption.retrieve_key(crypto_metadata.key_metadata.as_deref())?;
+
+        let aad = module_aad(&file_aad, ModuleType::Footer, None, None);
+        let plaintext = decrypt_module(&footer_key, &aad, metadata)?;
+        let metadata = TFileMetadata::read_from_in_protocol(&mut TCompactInputProtocol::new(
+            plaintext.as_slice(),
+            max_size,
+        ))?;
+        (metadata, file_aad, crypto_metadata.key_metadata)
+    } else {
+        let metadata = TFileMetadata::read_from_in_protocol(&mut TCompactInputProtocol::new(
+            metadata, max_size,
+        ))?;
+        let file_aad = match &metadata.encryption_algorithm {
+            None => return FileMetadata::try_from_thrift(metadata),
+            Some(algorithm) => file_aad(algorithm)?,
+        };
+        let footer_key_metadata = metadata.footer_signing_key_metadata.clone();
+        (metadata, file_aad, footer_key_metadata)
+    };
+    let file_aad: Arc<[u8]> = file_aad.into();
+    let footer_key_metadata: Option<Arc<[u8]>> = footer_key_metadata.map(Into::into);
+
+    // The physical types of the leaf columns, in the order of the columns of a row group.
+    let leaf_types = metadata
+        .schema
+        .iter()
+        .skip(1)
+        .filter_map(|element| element.type_)
+        .collect::<Vec<_>>();
+
+    let mut decryptors = Vec::with_capacity(metadata.row_groups.len());
+    for (row_group_ordinal, row_group) in metadata.row_groups.iter_mut().enumerate() {
+        let mut row_group_decryptors = Vec::with_capacity(row_group.columns.len());
+        for (column_ordinal, column) in row_group.columns.iter_mut().enumerate() {
+            let key_metadata = match &column.crypto_metadata {
+                None => {
+                    row_group_decryptors.push(None);
+                    continue;
+                },
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                    footer_key_metadata.clone()
+                },
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key)) => {
+                    column_key.key_metadata.as_deref().map(Into::into)
+                },
+            };
+
+            let mut decryptor = ColumnDecryptor {
+                decryption: decryption.clone(),
+                key_metadata,
+                file_aad: file_aad.clone(),
+                ordinals: (
+                    ordinal(row_group_ordinal, "row group")?,
+                    ordinal(column_ordinal, "column")?,
+                ),
+                metadata_error: None,
+            };
+
+            // We write a copy of the metadata without statistics into the footer, so the column
+            // key is only needed to find the column chunk in files written by other writers.
+            if let (true, Some(encrypted)) = (
+                column.meta_data.is_none(),
+                &column.encrypted_column_metadata,
+            ) {
+                match decryptor.decrypt(ModuleType::ColumnMetaData, None, encrypted) {
+                    Ok(plaintext) => {
+                        column.meta_data = Some(ColumnMetaData::read_from_in_protocol(
+                            &mut TCompactInputProtocol::new(plaintext.as_slice(), max_size),
+                        )?);
+                    },
+                    Err(err) => {
+                        // The column chunk can't be located, reading it will return this error.
+                        let path_in_schema = match &column.crypto_metadata {
+                            Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key)) => {
+                                column_key.path_in_schema.clone()
+                            },
+                            _ => vec![],
+                        };
+                        let type_ = *leaf_types.get(column_ordinal).ok_or_else(|| {
+                            ParquetError::oos("The row group has more columns than the schema")
+                        })?;
+                        column.meta_data = Some(ColumnMetaData {
+                            type_,
+                            encodings: vec![],
+                            path_in_schema,
+                            codec: CompressionCodec::UNCOMPRESSED,
+                            num_values: row_group.num_rows,
+                            total_uncompressed_size: 0,
+                            total_compressed_size: 0,
+                            key_value_metadata: None,
+                            data_page_offset: 0,
+                            index_page_offset: None,
+                            dictionary_page_offset: None,
+                            statistics: None,
+                            encoding_stats: None,
+                            bloom_filter_offset: None,
+                            bloom_filter_length: None,
+                            size_statistics: None,
+                        });
+                        decryptor.metadata_error = Some(err.to_string().into());
+                    },
+                }
+            }
+
+            row_group_decryptors.push(Some(decryptor));
+        }
+        decryptors.push(row_group_decryptors);
+    }
+
+    let mut metadata = FileMetadata::try_from_thrift(metadata)?;
+    for (row_group, decryptors) in metadata.row_groups.iter_mut().zip(decryptors) {
+        for (column, decryptor) in row_group.columns_mut().iter_mut().zip(decryptors) {
+            column.set_decryptor(decryptor);
+        }
+    }
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_roundtrip() {
+        let aad = module_aad(b"file-aad", ModuleType::DataPage, Some((1, 2)), Some(3));
+
+        for key in [[7u8; 16].as_slice(), [9u8; 32].as_slice()] {
+            let module = encrypt_module(key, &aad, b"plaintext").unwrap();
+            assert_eq!(encrypted_module_len(&module).unwrap(), module.len());
+            assert_eq!(decrypt_module(key, &aad, &module).unwrap(), b"plaintext");
+
+            // Modules are bound to their position in the file.
+            let other_aad = module_aad(b"file-aad", ModuleType::DataPage, Some((1, 2)), Some(4));
+            assert!(decrypt_module(key, &other_aad, &module).is_err());
+        }
+
+        assert!(
+            decrypt_module(
+                &[8u8; 16],
+                &aad,
+                &encrypt_module(&[7u8; 16], &aad, b"").unwrap()
+            )
+            .is_err()
+        );
+        assert!(encrypt_module(&[7u8; 24], &aad, b"plaintext").is_err());
+    }
+}
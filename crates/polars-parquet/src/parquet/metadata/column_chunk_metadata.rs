@@ -2,6 +2,8 @@ use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -39,6 +41,9 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(feature = "serde_types", serde(skip))]
+    decryptor: Option<ColumnDecryptor>,
 }
 
 #[cfg(feature = "serde_types")]
@@ -79,6 +84,8 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            #[cfg(feature = "encryption")]
+            decryptor: None,
         }
     }
 
@@ -175,6 +182,53 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Whether this column chunk is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// The decryptor of the pages of this column chunk, set if the column chunk is encrypted and
+    /// the file was read with decryption properties.
+    #[cfg(feature = "encryption")]
+    pub fn decryptor(&self) -> Option<&ColumnDecryptor> {
+        self.decryptor.as_ref()
+    }
+
+    #[cfg(feature = "encryption")]
+    pub(crate) fn set_decryptor(&mut self, decryptor: Option<ColumnDecryptor>) {
+        self.decryptor = decryptor;
+    }
+
+    /// Returns the byte range of the serialized [`ColumnIndex`] of this column chunk, if any.
+    ///
+    /// The indexes of encrypted column chunks are not read.
+    ///
+    /// [`ColumnIndex`]: polars_parquet_format::ColumnIndex
+    pub fn column_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        if self.is_encrypted() {
+            return None;
+        }
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the byte range of the serialized [`OffsetIndex`] of this column chunk, if any.
+    ///
+    /// The indexes of encrypted column chunks are not read.
+    ///
+    /// [`OffsetIndex`]: polars_parquet_format::OffsetIndex
+    pub fn offset_index_byte_range(&self) -> Option<core::ops::Range<u64>> {
+        if self.is_encrypted() {
+            return None;
+        }
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
@@ -197,6 +251,8 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            #[cfg(feature = "encryption")]
+            decryptor: None,
         })
     }
 
@@ -217,3 +273,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
@@ -68,10 +68,36 @@ impl RowGroupMetadata {
         self.column_lookup.get(root_name).map(|x| x.as_slice())
     }
 
+    /// Returns the row group with the columns under the root names in `renames` looked up under
+    /// their new names. Columns under other root names can no longer be looked up.
+    pub fn with_renamed_roots(&self, renames: &[(PlSmallStr, PlSmallStr)]) -> Self {
+        let column_lookup = renames
+            .iter()
+            .filter_map(|(from, to)| Some((to.clone(), self.column_lookup.get(from)?.clone())))
+            .collect();
+
+        Self {
+            columns: self.columns.clone(),
+            column_lookup,
+            num_rows: self.num_rows,
+            total_byte_size: self.total_byte_size,
+            full_byte_range: self.full_byte_range.clone(),
+            sorting_columns: self.sorting_columns.clone(),
+        }
+    }
+
     pub fn parquet_columns(&self) -> &[ColumnChunkMetadata] {
         self.columns.as_ref().as_slice()
     }
 
+    /// Mutable access to the columns, only possible while the columns are not shared.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn columns_mut(&mut self) -> &mut [ColumnChunkMetadata] {
+        Arc::get_mut(&mut self.columns)
+            .expect("columns are not shared")
+            .as_mut_slice()
+    }
+
     /// Number of rows in this row group.
     pub fn num_rows(&self) -> usize {
         self.num_rows
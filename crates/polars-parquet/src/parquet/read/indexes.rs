@@ -0,0 +1,76 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::PrimitiveType;
+use crate::parquet::statistics::{ParquetStatistics, Statistics};
+
+/// Deserializes the [`ColumnIndex`] of a column chunk from `bytes`.
+///
+/// `bytes` must hold the range returned by
+/// [`ColumnChunkMetadata::column_index_byte_range`](crate::parquet::metadata::ColumnChunkMetadata::column_index_byte_range).
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes the [`OffsetIndex`] of a column chunk from `bytes`.
+///
+/// `bytes` must hold the range returned by
+/// [`ColumnChunkMetadata::offset_index_byte_range`](crate::parquet::metadata::ColumnChunkMetadata::offset_index_byte_range).
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Returns the [`Statistics`] of every data page in `column_index`.
+///
+/// Pages that only contain nulls have no min and max value.
+/// # Error
+/// Errors if the index is malformed or the values are not plain encoded as `primitive_type`.
+pub fn column_index_statistics(
+    column_index: &ColumnIndex,
+    primitive_type: &PrimitiveType,
+) -> ParquetResult<Vec<Statistics>> {
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|v| v.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "The lists of a column index must have the same length",
+        ));
+    }
+
+    (0..num_pages)
+        .map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let (min_value, max_value) = if is_null_page {
+                (None, None)
+            } else {
+                (
+                    Some(column_index.min_values[i].clone()),
+                    Some(column_index.max_values[i].clone()),
+                )
+            };
+
+            let statistics = ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: column_index.null_counts.as_ref().map(|v| v[i]),
+                distinct_count: None,
+                max_value,
+                min_value,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+
+            Statistics::deserialize(&statistics, primitive_type.clone())
+        })
+        .collect()
+}
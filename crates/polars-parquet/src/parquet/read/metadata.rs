@@ -5,7 +5,14 @@ use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC,
+    PARQUET_MAGIC_ENCRYPTED_FOOTER,
+};
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::FileDecryptionProperties;
+#[cfg(not(feature = "encryption"))]
+use crate::parquet::error::Feature;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> i32 {
@@ -38,6 +45,28 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
 ) -> ParquetResult<FileMetadata> {
+    let footer = read_footer(reader, file_size)?;
+    deserialize_footer_impl(
+        &footer,
+        #[cfg(feature = "encryption")]
+        None,
+    )
+}
+
+/// Reads a [`FileMetadata`] from the reader, located at the end of the file, decrypting it with
+/// `decryption` if the file is encrypted.
+#[cfg(feature = "encryption")]
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    let footer = read_footer(reader, file_size)?;
+    deserialize_footer_impl(&footer, decryption)
+}
+
+/// Reads the metadata and the 8 byte footer at the end of the file.
+fn read_footer<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetResult<Vec<u8>> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
             "A parquet file must contain a header and footer with at least 12 bytes",
@@ -55,9 +84,7 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
-    }
+    check_magic(&buffer[default_end_len - 4..])?;
 
     let metadata_len = metadata_len(&buffer, default_end_len);
 
@@ -70,10 +97,10 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         ));
     }
 
-    let reader: &[u8] = if (footer_len as usize) < buffer.len() {
+    if (footer_len as usize) < buffer.len() {
         // the whole metadata is in the bytes we already read
         let remaining = buffer.len() - footer_len as usize;
-        &buffer[remaining..]
+        buffer.drain(..remaining);
     } else {
         // the end of file read by default is not long enough, read again including the metadata.
         reader.seek(SeekFrom::End(-(footer_len as i64)))?;
@@ -81,14 +108,70 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         buffer.clear();
         buffer.try_reserve(footer_len as usize)?;
         reader.take(footer_len).read_to_end(&mut buffer)?;
+    }
 
-        &buffer
-    };
+    Ok(buffer)
+}
+
+/// Checks that `magic` marks the end of a parquet file, with a plaintext or an encrypted footer.
+pub(crate) fn check_magic(magic: &[u8]) -> ParquetResult<()> {
+    if magic == PARQUET_MAGIC || magic == PARQUET_MAGIC_ENCRYPTED_FOOTER {
+        Ok(())
+    } else {
+        Err(ParquetError::oos("The file must end with PAR1"))
+    }
+}
+
+/// Parse the loaded footer, which consists of the metadata followed by the 8 byte length and
+/// magic. Encrypted files are decrypted with `decryption`.
+#[cfg(feature = "encryption")]
+pub fn deserialize_footer(
+    footer: &[u8],
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    if footer.len() < FOOTER_SIZE as usize {
+        return Err(ParquetError::oos("The footer must be at least 8 bytes"));
+    }
+    check_magic(&footer[footer.len() - 4..])?;
+    deserialize_footer_impl(footer, decryption)
+}
+
+fn deserialize_footer_impl(
+    footer: &[u8],
+    #[cfg(feature = "encryption")] decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let (metadata, footer) = footer.split_at(footer.len() - FOOTER_SIZE as usize);
+    let encrypted_footer = footer[4..] == PARQUET_MAGIC_ENCRYPTED_FOOTER;
+
+    #[cfg(feature = "encryption")]
+    match (decryption, encrypted_footer) {
+        (Some(decryption), _) => {
+            return crate::parquet::encryption::deserialize_metadata(
+                metadata,
+                encrypted_footer,
+                decryption,
+            );
+        },
+        (None, true) => {
+            return Err(ParquetError::InvalidParameter(
+                "The file has an encrypted footer, reading it requires decryption properties"
+                    .to_string(),
+            ));
+        },
+        (None, false) => {},
+    }
+    #[cfg(not(feature = "encryption"))]
+    if encrypted_footer {
+        return Err(ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "read files with an encrypted footer".to_string(),
+        ));
+    }
 
     // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
+    let max_size = metadata.len() * 2 + 1024;
 
-    deserialize_metadata(reader, max_size)
+    deserialize_metadata(metadata, max_size)
 }
 
 /// Parse loaded metadata bytes
@@ -1,5 +1,6 @@
 mod column;
 mod compression;
+pub mod indexes;
 pub mod levels;
 mod metadata;
 mod page;
@@ -10,6 +11,8 @@ use std::io::{Seek, SeekFrom};
 
 pub use column::*;
 pub use compression::{BasicDecompressor, decompress};
+#[cfg(feature = "encryption")]
+pub use metadata::{deserialize_footer, read_metadata_with_decryption};
 pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size};
 pub use page::{PageIterator, PageMetaData, PageReader};
 #[cfg(feature = "async")]
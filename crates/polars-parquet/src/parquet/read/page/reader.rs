@@ -1,4 +1,4 @@
-use std::io::Seek;
+use std::io::{Read, Seek};
 use std::sync::OnceLock;
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
@@ -7,6 +7,8 @@ use polars_utils::mmap::{MemReader, MemSlice};
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::{ColumnDecryptor, ModuleType, encrypted_module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +28,9 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// The decryptor of the pages if this column chunk is encrypted
+    #[cfg(feature = "encryption")]
+    pub decryptor: Option<ColumnDecryptor>,
 }
 
 impl PageMetaData {
@@ -41,6 +46,8 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            #[cfg(feature = "encryption")]
+            decryptor: None,
         }
     }
 }
@@ -52,6 +59,8 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            #[cfg(feature = "encryption")]
+            decryptor: column.decryptor().cloned(),
         }
     }
 }
@@ -81,6 +90,13 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    #[cfg(feature = "encryption")]
+    decryptor: Option<ColumnDecryptor>,
+
+    // The ordinal of the next data page, part of the AAD of encrypted pages.
+    #[cfg(feature = "encryption")]
+    data_page_ordinal: usize,
 }
 
 impl PageReader {
@@ -114,6 +130,10 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            #[cfg(feature = "encryption")]
+            decryptor: reader_meta.decryptor,
+            #[cfg(feature = "encryption")]
+            data_page_ordinal: 0,
         }
     }
 
@@ -137,7 +157,7 @@ impl PageReader {
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -152,6 +172,46 @@ impl PageReader {
             return Err(ParquetError::WouldOverAllocate);
         }
 
+        let buffer = self.read_page(&page_header, read_size)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        #[cfg(feature = "encryption")]
+        if let Some(decryptor) = &self.decryptor {
+            decryptor.check_key()?;
+            let position = self.reader.position();
+            let prefix = self.reader.read_slice(4);
+            self.reader
+                .seek(std::io::SeekFrom::Start(position as u64))?;
+            let module = self.reader.read_slice(encrypted_module_len(&prefix)?);
+
+            // Only the first page can be a dictionary page, whose header is encrypted without a
+            // page ordinal. The AAD differs, so at most one of the two attempts authenticates.
+            let header = match self.data_page_ordinal {
+                0 => decryptor
+                    .decrypt(ModuleType::DictionaryPageHeader, None, &module)
+                    .or_else(|_| decryptor.decrypt(ModuleType::DataPageHeader, Some(0), &module)),
+                ordinal => decryptor.decrypt(ModuleType::DataPageHeader, Some(ordinal), &module),
+            };
+            return read_page_header(&mut header?.as_slice(), self.max_page_size);
+        }
+
+        read_page_header(&mut self.reader, self.max_page_size)
+    }
+
+    fn read_page(
+        &mut self,
+        page_header: &ParquetPageHeader,
+        read_size: usize,
+    ) -> ParquetResult<MemSlice> {
         let buffer = self.reader.read_slice(read_size);
 
         if buffer.len() != read_size {
@@ -160,13 +220,25 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
+        #[cfg(feature = "encryption")]
+        if let Some(decryptor) = &self.decryptor {
+            let buffer = if page_header.type_ == polars_parquet_format::PageType::DICTIONARY_PAGE {
+                decryptor.decrypt(ModuleType::DictionaryPage, None, &buffer)?
             } else {
-                unreachable!()
-            }
-        })
+                let buffer = decryptor.decrypt(
+                    ModuleType::DataPage,
+                    Some(self.data_page_ordinal),
+                    &buffer,
+                )?;
+                self.data_page_ordinal += 1;
+                buffer
+            };
+            return Ok(MemSlice::from_vec(buffer));
+        }
+        #[cfg(not(feature = "encryption"))]
+        let _ = page_header;
+
+        Ok(buffer)
     }
 }
 
@@ -191,8 +263,8 @@ impl Iterator for PageReader {
 }
 
 /// Reads Page header from Thrift.
-pub(super) fn read_page_header(
-    reader: &mut MemReader,
+pub(super) fn read_page_header<R: Read>(
+    reader: &mut R,
     max_size: usize,
 ) -> ParquetResult<ParquetPageHeader> {
     let mut prot = TCompactInputProtocol::new(reader, max_size);
@@ -210,7 +282,7 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
@@ -220,13 +292,7 @@ pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<Compre
         return Err(ParquetError::WouldOverAllocate);
     }
 
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page(&page_header, read_size)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -9,6 +9,8 @@ use polars_parquet_format::{ColumnChunk, ColumnMetaData, Type};
 use polars_utils::aliases::PlHashSet;
 
 use super::DynStreamingIterator;
+#[cfg(feature = "encryption")]
+use super::page::is_data_page;
 #[cfg(feature = "async")]
 use super::page::write_page_async;
 use super::page::{PageWriteSpec, write_page};
@@ -16,6 +18,8 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::{ColumnEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -25,6 +29,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    #[cfg(feature = "encryption")] encryptor: Option<&ColumnEncryptor>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +41,48 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    #[cfg(feature = "encryption")]
+    let mut data_page_ordinal = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = write_page(
+            writer,
+            offset,
+            compressed_page,
+            #[cfg(feature = "encryption")]
+            encryptor.map(|encryptor| (encryptor, data_page_ordinal)),
+        )?;
+        #[cfg(feature = "encryption")]
+        {
+            data_page_ordinal += is_data_page(&spec) as usize;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    #[cfg_attr(not(feature = "encryption"), allow(unused_mut))]
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    // the metadata of encrypted columns is written encrypted and, if the column has its own key,
+    // stored encrypted in the footer as well
+    #[cfg(feature = "encryption")]
+    if let Some(encryptor) = encryptor {
+        let mut metadata = vec![];
+        column_chunk
+            .meta_data
+            .as_ref()
+            .unwrap()
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut metadata))?;
+        let metadata = encryptor.encrypt(ModuleType::ColumnMetaData, None, &metadata)?;
+        writer.write_all(&metadata)?;
+        bytes_written += metadata.len() as u64;
+
+        column_chunk.crypto_metadata = Some(encryptor.crypto_metadata().clone());
+        if encryptor.uses_column_key() {
+            column_chunk.encrypted_column_metadata = Some(metadata);
+        }
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
@@ -7,15 +7,19 @@ use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+#[cfg(feature = "encryption")]
+use crate::parquet::PARQUET_MAGIC_ENCRYPTED_FOOTER;
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::{FileEncryptionProperties, FileEncryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
 use crate::parquet::write::State;
 use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
 
-pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
-    writer.write_all(&PARQUET_MAGIC)?;
-    Ok(PARQUET_MAGIC.len() as u64)
+pub(super) fn start_file<W: Write>(writer: &mut W, magic: [u8; 4]) -> ParquetResult<u64> {
+    writer.write_all(&magic)?;
+    Ok(magic.len() as u64)
 }
 
 pub(super) fn end_file<W: Write>(
@@ -26,17 +30,39 @@ pub(super) fn end_file<W: Write>(
     let mut protocol = TCompactOutputProtocol::new(&mut writer);
     let metadata_len = metadata.write_to_out_protocol(&mut protocol)? as i32;
 
-    // Write footer
+    write_footer(writer, metadata_len, PARQUET_MAGIC)?;
+    Ok(metadata_len as u64 + FOOTER_SIZE)
+}
+
+/// Writes the encrypted footer of a file encrypted by `encryptor`.
+#[cfg(feature = "encryption")]
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let footer = encryptor.encrypt_footer(metadata)?;
+    writer.write_all(&footer)?;
+
+    let metadata_len = footer
+        .len()
+        .try_into()
+        .map_err(|_| ParquetError::oos("The encrypted footer can only contain i32::MAX bytes"))?;
+    write_footer(writer, metadata_len, PARQUET_MAGIC_ENCRYPTED_FOOTER)?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
+fn write_footer<W: Write>(writer: &mut W, metadata_len: i32, magic: [u8; 4]) -> ParquetResult<()> {
     let metadata_bytes = metadata_len.to_le_bytes();
     let mut footer_buffer = [0u8; FOOTER_SIZE as usize];
     (0..4).for_each(|i| {
         footer_buffer[i] = metadata_bytes[i];
     });
 
-    (&mut footer_buffer[4..]).write_all(&PARQUET_MAGIC)?;
+    (&mut footer_buffer[4..]).write_all(&magic)?;
     writer.write_all(&footer_buffer)?;
     writer.flush()?;
-    Ok(metadata_len as u64 + FOOTER_SIZE)
+    Ok(())
 }
 
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_format::ColumnOrder> {
@@ -70,6 +96,9 @@ pub struct FileWriter<W: Write> {
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetadata>,
+
+    #[cfg(feature = "encryption")]
+    encryptor: Option<FileEncryptor>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -83,7 +112,7 @@ pub fn write_metadata_sidecar<W: Write>(
     writer: &mut W,
     metadata: &ThriftFileMetadata,
 ) -> ParquetResult<u64> {
-    let mut len = start_file(writer)?;
+    let mut len = start_file(writer, PARQUET_MAGIC)?;
     len += end_file(writer, metadata)?;
     Ok(len)
 }
@@ -127,9 +156,24 @@ impl<W: Write> FileWriter<W> {
             page_specs: vec![],
             state: State::Initialised,
             metadata: None,
+            #[cfg(feature = "encryption")]
+            encryptor: None,
         }
     }
 
+    /// Encrypts the file with the given [`FileEncryptionProperties`].
+    ///
+    /// The footer is encrypted, bloom filters cannot be written for encrypted columns and no page
+    /// indexes are written for them.
+    ///
+    /// # Errors
+    /// Returns an error if a key is invalid or refers to a column that is not in the schema.
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        self.encryptor = Some(FileEncryptor::try_new(properties, &self.schema)?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -138,7 +182,15 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            #[cfg(feature = "encryption")]
+            let magic = if self.encryptor.is_some() {
+                PARQUET_MAGIC_ENCRYPTED_FOOTER
+            } else {
+                PARQUET_MAGIC
+            };
+            #[cfg(not(feature = "encryption"))]
+            let magic = PARQUET_MAGIC;
+            self.offset = start_file(&mut self.writer, magic)?;
             self.state = State::Started;
             Ok(())
         } else {
@@ -166,6 +218,8 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            #[cfg(feature = "encryption")]
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
@@ -173,6 +227,47 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes the bloom filter bitsets of the column chunks of the last written row group.
+    ///
+    /// `bitsets` contains an entry for every column of the row group, columns without a bloom
+    /// filter are `None`.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filters(&mut self, bitsets: &[Option<Vec<u8>>]) -> ParquetResult<()> {
+        let Some(group) = self.row_groups.last_mut() else {
+            return Err(ParquetError::InvalidParameter(
+                "Bloom filters can only be written after a row group".to_string(),
+            ));
+        };
+        if bitsets.len() != group.columns.len() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "The number of bloom filters ({}) must equal the number of columns ({})",
+                bitsets.len(),
+                group.columns.len()
+            )));
+        }
+
+        for (column, bitset) in group.columns.iter_mut().zip(bitsets) {
+            let Some(bitset) = bitset else {
+                continue;
+            };
+            if column.crypto_metadata.is_some() {
+                return Err(ParquetError::InvalidParameter(
+                    "Bloom filters cannot be written for encrypted columns".to_string(),
+                ));
+            }
+
+            let offset = self.offset;
+            let length = crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+            self.offset += length;
+
+            let metadata = column.meta_data.as_mut().unwrap();
+            metadata.bloom_filter_offset = Some(offset as i64);
+            metadata.bloom_filter_length = Some(length.try_into()?);
+        }
+
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
@@ -196,6 +291,10 @@ impl<W: Write> FileWriter<W> {
                 .try_for_each(|(group, pages)| {
                     group.columns.iter_mut().zip(pages.iter()).try_for_each(
                         |(column, pages)| {
+                            // indexes of encrypted columns would have to be encrypted as well
+                            if column.crypto_metadata.is_some() {
+                                return Ok(());
+                            }
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
@@ -218,6 +317,9 @@ impl<W: Write> FileWriter<W> {
                     .iter_mut()
                     .zip(pages.iter())
                     .try_for_each(|(column, pages)| {
+                        if column.crypto_metadata.is_some() {
+                            return Ok(());
+                        }
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
                         self.offset += write_offset_index(&mut self.writer, pages)?;
@@ -227,11 +329,29 @@ impl<W: Write> FileWriter<W> {
                 ParquetResult::Ok(())
             })?;
 
+        #[cfg_attr(not(feature = "encryption"), allow(unused_mut))]
+        let mut row_groups = self.row_groups.clone();
+        // columns encrypted with their own key store their metadata encrypted in the footer, and
+        // a copy without statistics so that readers can locate them without the column key
+        #[cfg(feature = "encryption")]
+        for column in row_groups
+            .iter_mut()
+            .flat_map(|group| group.columns.iter_mut())
+        {
+            if column.encrypted_column_metadata.is_some() {
+                let meta_data = column.meta_data.as_mut().unwrap();
+                meta_data.statistics = None;
+                meta_data.key_value_metadata = None;
+                meta_data.encoding_stats = None;
+                meta_data.size_statistics = None;
+            }
+        }
+
         let metadata = ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
             num_rows,
-            self.row_groups.clone(),
+            row_groups,
             key_value_metadata,
             self.created_by.clone(),
             Some(create_column_orders(&self.schema)),
@@ -239,6 +359,12 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
+        #[cfg(feature = "encryption")]
+        let len = match &self.encryptor {
+            Some(encryptor) => end_encrypted_file(&mut self.writer, &metadata, encryptor)?,
+            None => end_file(&mut self.writer, &metadata)?,
+        };
+        #[cfg(not(feature = "encryption"))]
         let len = end_file(&mut self.writer, &metadata)?;
         self.state = State::Finished;
         self.metadata = Some(metadata);
@@ -8,6 +8,8 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::{ColumnEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -50,34 +52,39 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes a page. `encryption` holds the encryptor of the column chunk and the ordinal of the
+/// page among the data pages of the column chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    #[cfg(feature = "encryption")] encryption: Option<(&ColumnEncryptor, usize)>,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    #[cfg_attr(not(feature = "encryption"), allow(unused_mut))]
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
+    #[cfg(feature = "encryption")]
+    let (header_size, bytes_written) = match encryption {
+        Some((encryptor, data_page_ordinal)) => {
+            write_encrypted_page(writer, &mut header, buffer, encryptor, data_page_ordinal)?
         },
+        None => write_plain_page(writer, &header, buffer)?,
     };
+    #[cfg(not(feature = "encryption"))]
+    let (header_size, bytes_written) = write_plain_page(writer, &header, buffer)?;
 
     let statistics = match &compressed_page {
         CompressedPage::Data(compressed_page) => compressed_page.statistics().transpose()?,
@@ -96,6 +103,55 @@ pub fn write_page<W: Write>(
     })
 }
 
+/// Writes the page header and the page. Returns the size of the header and the total size.
+fn write_plain_page<W: Write>(
+    writer: &mut W,
+    header: &ParquetPageHeader,
+    buffer: &[u8],
+) -> ParquetResult<(u64, u64)> {
+    let header_size = write_page_header(writer, header)?;
+    writer.write_all(buffer)?;
+    Ok((header_size, header_size + buffer.len() as u64))
+}
+
+/// Encrypts and writes the page header and the page. The compressed page size of `header` is set
+/// to the size of the encrypted page.
+#[cfg(feature = "encryption")]
+fn write_encrypted_page<W: Write>(
+    writer: &mut W,
+    header: &mut ParquetPageHeader,
+    buffer: &[u8],
+    encryptor: &ColumnEncryptor,
+    data_page_ordinal: usize,
+) -> ParquetResult<(u64, u64)> {
+    let (page_module, header_module, page_ordinal) = if header.type_ == PageType::DICTIONARY_PAGE {
+        (
+            ModuleType::DictionaryPage,
+            ModuleType::DictionaryPageHeader,
+            None,
+        )
+    } else {
+        (
+            ModuleType::DataPage,
+            ModuleType::DataPageHeader,
+            Some(data_page_ordinal),
+        )
+    };
+
+    let page = encryptor.encrypt(page_module, page_ordinal, buffer)?;
+    (_, header.compressed_page_size) = maybe_bytes(0, page.len())?;
+
+    let mut serialized_header = vec![];
+    header.write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut serialized_header))?;
+    let encrypted_header = encryptor.encrypt(header_module, page_ordinal, &serialized_header)?;
+
+    writer.write_all(&encrypted_header)?;
+    writer.write_all(&page)?;
+
+    let header_size = encrypted_header.len() as u64;
+    Ok((header_size, header_size + page.len() as u64))
+}
+
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub async fn write_page_async<W: AsyncWrite + Unpin + Send>(
@@ -9,6 +9,8 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{PageWriteSpec, is_data_page};
 use super::{DynIter, DynStreamingIterator};
+#[cfg(feature = "encryption")]
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -76,6 +78,7 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    #[cfg(feature = "encryption")] encryptor: Option<&FileEncryptor>,
 ) -> ParquetResult<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
@@ -85,10 +88,23 @@ where
     let column_iter = descriptors.iter().zip(columns);
 
     let initial = offset;
+    #[cfg_attr(not(feature = "encryption"), allow(clippy::unused_enumerate_index))]
     let columns = column_iter
-        .map(|(descriptor, page_iter)| {
-            let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+        .enumerate()
+        .map(|(_column_ordinal, (descriptor, page_iter))| {
+            #[cfg(feature = "encryption")]
+            let column_encryptor = encryptor
+                .map(|encryptor| encryptor.column_encryptor(descriptor, ordinal, _column_ordinal))
+                .transpose()?
+                .flatten();
+            let (column, page_specs, size) = write_column_chunk(
+                writer,
+                offset,
+                descriptor,
+                page_iter?,
+                #[cfg(feature = "encryption")]
+                column_encryptor.as_ref(),
+            )?;
             offset += size;
             Ok((column, page_specs))
         })
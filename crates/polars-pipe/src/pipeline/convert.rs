@@ -148,7 +148,7 @@ where
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => Box::new(ParquetSink::new(
                             path,
-                            *options,
+                            options.clone(),
                             input_schema.as_ref(),
                             cloud_options.as_ref(),
                         )?)
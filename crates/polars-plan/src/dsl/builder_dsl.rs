@@ -1,10 +1,26 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::DeltaScanOptions;
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FwfReadOptions;
+#[cfg(feature = "iceberg")]
+use polars_io::iceberg::IcebergScanOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcStreamScanOptions;
+#[cfg(feature = "json")]
+use polars_io::json::array::JsonArrayReadOptions;
+#[cfg(feature = "lines")]
+use polars_io::lines::LinesReadOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 
@@ -90,6 +106,140 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "ipc_streaming")]
+    pub fn scan_ipc_stream(
+        sources: ScanSources,
+        options: IpcStreamScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::IpcStream { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Avro { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "orc")]
+    pub fn scan_orc(
+        sources: ScanSources,
+        options: OrcScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Orc { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "fwf")]
+    pub fn scan_fwf(
+        sources: ScanSources,
+        options: FwfReadOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Fwf { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "lines")]
+    pub fn scan_lines(
+        sources: ScanSources,
+        options: LinesReadOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Lines { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "json")]
+    pub fn scan_json(
+        sources: ScanSources,
+        options: JsonArrayReadOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Json { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "delta")]
+    pub fn scan_delta(
+        sources: ScanSources,
+        options: DeltaScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Delta {
+                options,
+                snapshot: None,
+            }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "iceberg")]
+    pub fn scan_iceberg(
+        sources: ScanSources,
+        options: IcebergScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            file_info: None,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScan::Iceberg {
+                options,
+                table: None,
+            }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
@@ -1,11 +1,27 @@
 use std::hash::Hash;
 use std::sync::Mutex;
 
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "delta")]
+use polars_io::delta::{DeltaScanOptions, DeltaSnapshot};
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FwfReadOptions;
+#[cfg(feature = "iceberg")]
+use polars_io::iceberg::{IcebergScanOptions, IcebergTable};
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcStreamScanOptions;
+#[cfg(feature = "json")]
+use polars_io::json::array::JsonArrayReadOptions;
+#[cfg(feature = "lines")]
+use polars_io::lines::LinesReadOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -40,6 +56,10 @@ pub enum FileScan {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    /// A single JSON array of records per file.
+    #[cfg(feature = "json")]
+    Json { options: JsonArrayReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -54,6 +74,41 @@ pub enum FileScan {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    /// Arrow IPC streams, which are read from start to end.
+    #[cfg(feature = "ipc_streaming")]
+    IpcStream { options: IpcStreamScanOptions },
+
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
+    #[cfg(feature = "fwf")]
+    Fwf { options: FwfReadOptions },
+
+    #[cfg(feature = "lines")]
+    Lines { options: LinesReadOptions },
+
+    /// Resolved to a Parquet scan over the active files of the table during IR conversion.
+    #[cfg(feature = "delta")]
+    Delta {
+        options: DeltaScanOptions,
+        /// Set when the files of a table are split over multiple scans.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        snapshot: Option<Arc<DeltaSnapshot>>,
+    },
+
+    /// Resolved to Parquet scans over the data files of the table by the `ExpandDatasets`
+    /// optimization, after predicates have been pushed down to prune the files.
+    #[cfg(feature = "iceberg")]
+    Iceberg {
+        options: IcebergScanOptions,
+        /// Loaded during IR conversion.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        table: Option<Arc<IcebergTable>>,
+    },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -76,10 +131,22 @@ impl FileScan {
             Self::Csv { .. } => ScanFlags::empty(),
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => ScanFlags::empty(),
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => ScanFlags::empty(),
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "fwf")]
+            Self::Fwf { .. } => ScanFlags::empty(),
+            #[cfg(feature = "lines")]
+            Self::Lines { .. } => ScanFlags::empty(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => ScanFlags::empty(),
+            #[cfg(feature = "json")]
+            Self::Json { .. } => ScanFlags::empty(),
             #[allow(unreachable_patterns)]
             _ => ScanFlags::empty(),
         }
@@ -91,6 +158,18 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => _has_row_index,
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => _has_row_index,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => _has_row_index,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => _has_row_index,
+            #[cfg(feature = "fwf")]
+            Self::Fwf { .. } => true,
+            #[cfg(feature = "lines")]
+            Self::Lines { .. } => true,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => true,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => false,
             #[allow(unreachable_patterns)]
@@ -104,10 +183,22 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => false,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => false,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => false,
+            #[cfg(feature = "fwf")]
+            Self::Fwf { .. } => false,
+            #[cfg(feature = "lines")]
+            Self::Lines { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -129,6 +220,9 @@ pub enum CastColumnsPolicy {
     /// Raise an error if the datatypes do not match
     #[default]
     ErrorOnMismatch,
+    /// Cast columns to the target datatype if this is a lossless widening, e.g. `Int32` to
+    /// `Int64`. Raise an error for other mismatches.
+    AllowWidening,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
@@ -140,6 +234,31 @@ pub enum ExtraColumnsPolicy {
     Ignore,
 }
 
+/// Positions of the rows that are deleted from each source of a scan, e.g. by the deletion vectors
+/// of a Delta table or the positional delete files of an Iceberg table.
+///
+/// The deleted rows of a source are removed before the row index, slice and predicate are applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeletedRows(Arc<[Option<Arc<[IdxSize]>>]>);
+
+impl DeletedRows {
+    /// Takes the sorted positions of the deleted rows of every source, in the order of the
+    /// sources. Returns `None` if no rows are deleted.
+    pub fn new(deleted_rows: impl IntoIterator<Item = Option<Arc<[IdxSize]>>>) -> Option<Self> {
+        let deleted_rows: Arc<[_]> = deleted_rows.into_iter().collect();
+        deleted_rows
+            .iter()
+            .any(Option::is_some)
+            .then_some(Self(deleted_rows))
+    }
+
+    /// Sorted positions of the rows that are deleted from the source at `scan_source_idx`.
+    pub fn get(&self, scan_source_idx: usize) -> Option<&Arc<[IdxSize]>> {
+        self.0.get(scan_source_idx)?.as_ref()
+    }
+}
+
 /// Scan arguments shared across different scan types.
 #[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -162,6 +281,8 @@ pub struct UnifiedScanArgs {
     pub cast_columns_policy: CastColumnsPolicy,
     pub missing_columns_policy: MissingColumnsPolicy,
     pub include_file_paths: Option<PlSmallStr>,
+    /// Rows that are deleted from the sources, e.g. by Delta deletion vectors.
+    pub deleted_rows: Option<DeletedRows>,
 }
 
 /// Manual impls of Eq/Hash, as some fields are `Arc<T>` where T does not have Eq/Hash. For these
@@ -201,6 +322,11 @@ mod _file_scan_eq_hash {
             options: &'a crate::prelude::NDJsonReadOptions,
         },
 
+        #[cfg(feature = "json")]
+        Json {
+            options: &'a polars_io::json::array::JsonArrayReadOptions,
+        },
+
         #[cfg(feature = "parquet")]
         Parquet {
             options: &'a polars_io::prelude::ParquetOptions,
@@ -213,6 +339,43 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "ipc_streaming")]
+        IpcStream {
+            options: &'a polars_io::ipc::IpcStreamScanOptions,
+        },
+
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+        },
+
+        #[cfg(feature = "orc")]
+        Orc {
+            options: &'a polars_io::orc::OrcScanOptions,
+        },
+
+        #[cfg(feature = "fwf")]
+        Fwf {
+            options: &'a polars_io::fwf::FwfReadOptions,
+        },
+
+        #[cfg(feature = "lines")]
+        Lines {
+            options: &'a polars_io::lines::LinesReadOptions,
+        },
+
+        #[cfg(feature = "delta")]
+        Delta {
+            options: &'a polars_io::delta::DeltaScanOptions,
+            snapshot: Option<usize>,
+        },
+
+        #[cfg(feature = "iceberg")]
+        Iceberg {
+            options: &'a polars_io::iceberg::IcebergScanOptions,
+            table: Option<usize>,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -238,6 +401,9 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options } => FileScanEqHashWrap::NDJson { options },
 
+                #[cfg(feature = "json")]
+                FileScan::Json { options } => FileScanEqHashWrap::Json { options },
+
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet { options, metadata } => FileScanEqHashWrap::Parquet {
                     options,
@@ -250,6 +416,33 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { options } => FileScanEqHashWrap::IpcStream { options },
+
+                #[cfg(feature = "avro")]
+                FileScan::Avro { options } => FileScanEqHashWrap::Avro { options },
+
+                #[cfg(feature = "orc")]
+                FileScan::Orc { options } => FileScanEqHashWrap::Orc { options },
+
+                #[cfg(feature = "fwf")]
+                FileScan::Fwf { options } => FileScanEqHashWrap::Fwf { options },
+
+                #[cfg(feature = "lines")]
+                FileScan::Lines { options } => FileScanEqHashWrap::Lines { options },
+
+                #[cfg(feature = "delta")]
+                FileScan::Delta { options, snapshot } => FileScanEqHashWrap::Delta {
+                    options,
+                    snapshot: snapshot.as_ref().map(arc_as_ptr),
+                },
+
+                #[cfg(feature = "iceberg")]
+                FileScan::Iceberg { options, table } => FileScanEqHashWrap::Iceberg {
+                    options,
+                    table: table.as_ref().map(arc_as_ptr),
+                },
+
                 #[cfg(feature = "python")]
                 FileScan::PythonDataset {
                     dataset_object,
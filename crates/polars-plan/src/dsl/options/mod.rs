@@ -8,6 +8,8 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -16,6 +18,12 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "pg_copy")]
+use polars_io::pg_copy::PgCopyWriterOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::utils::compression::ExternalCompression;
+#[cfg(feature = "json")]
+use polars_io::utils::rejected_rows::RejectedRowsOptions;
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
@@ -320,10 +328,16 @@ pub enum FileType {
     Parquet(ParquetWriteOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    #[cfg(feature = "ipc_streaming")]
+    IpcStream(IpcWriterOptions),
     #[cfg(feature = "csv")]
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
+    #[cfg(feature = "pg_copy")]
+    PgCopy(PgCopyWriterOptions),
 }
 
 impl FileType {
@@ -333,10 +347,24 @@ impl FileType {
             Self::Parquet(_) => "parquet",
             #[cfg(feature = "ipc")]
             Self::Ipc(_) => "ipc",
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream(_) => "arrows",
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => match options.compression {
+                ExternalCompression::Uncompressed => "csv",
+                ExternalCompression::Gzip { .. } => "csv.gz",
+                ExternalCompression::Zstd { .. } => "csv.zst",
+            },
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => match options.compression {
+                ExternalCompression::Uncompressed => "jsonl",
+                ExternalCompression::Gzip { .. } => "jsonl.gz",
+                ExternalCompression::Zstd { .. } => "jsonl.zst",
+            },
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
+            #[cfg(feature = "pg_copy")]
+            Self::PgCopy(_) => "bin",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -396,4 +424,6 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Writes lines that fail to parse to files instead of raising an error.
+    pub rejected_rows: Option<RejectedRowsOptions>,
 }
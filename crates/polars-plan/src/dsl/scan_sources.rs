@@ -1,5 +1,6 @@
 use std::fmt::{Debug, Formatter};
 use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -174,7 +175,12 @@ impl ScanSources {
 
     /// This will update `scan_args.hive_options.enabled` to `true` if the existing value is `None`
     /// and the paths are expanded from a single directory. Otherwise the existing value is maintained.
-    #[cfg(any(feature = "ipc", feature = "parquet"))]
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "avro",
+        feature = "orc"
+    ))]
     pub fn expand_paths_with_hive_update(
         &self,
         scan_args: &mut UnifiedScanArgs,
@@ -414,6 +420,26 @@ impl ScanSourceRef<'_> {
         }
     }
 
+    /// Open the source to read it from start to end, without mapping it into memory.
+    ///
+    /// Opened files are rewound to the start if they can be seeked, other files such as pipes and
+    /// stdin are read from their current position. Cloud sources are read from the file cache.
+    pub fn to_sequential_reader(&self, run_async: bool) -> PolarsResult<Box<dyn Read + Send>> {
+        match self {
+            Self::Path(_) if run_async => Ok(Box::new(Cursor::new(
+                self.to_memslice_async_check_latest(run_async)?,
+            ))),
+            Self::Path(path) => Ok(Box::new(polars_utils::open_file(path)?)),
+            Self::File(file) => {
+                let mut file = file.try_clone()?;
+                // Ignore the error for files that can't be seeked.
+                _ = file.seek(SeekFrom::Start(0));
+                Ok(Box::new(file))
+            },
+            Self::Buffer(buff) => Ok(Box::new(Cursor::new((*buff).clone()))),
+        }
+    }
+
     #[cfg(feature = "cloud")]
     pub async fn to_dyn_byte_source(
         &self,
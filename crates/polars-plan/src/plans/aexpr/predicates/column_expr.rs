@@ -1,15 +1,27 @@
 //! This module creates predicates splits predicates into partial per-column predicates.
 
+use hashbrown::hash_map::Entry;
 use polars_core::datatypes::DataType;
+#[cfg(feature = "is_in")]
+use polars_core::prelude::AnyValue;
 use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
+use polars_core::series::Series;
 use polars_io::predicates::SpecializedColumnPredicateExpr;
 use polars_utils::aliases::PlHashMap;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 
+#[cfg(feature = "is_in")]
+use super::super::BooleanFunction;
+#[cfg(feature = "is_in")]
+use super::super::evaluate::{constant_evaluate, into_column};
 use super::get_binary_expr_col_and_lv;
+#[cfg(feature = "is_in")]
+use crate::dsl::FunctionExpr;
 use crate::dsl::Operator;
+#[cfg(feature = "is_in")]
+use crate::plans::LiteralValue;
 use crate::plans::{AExpr, MintermIter, aexpr_to_leaf_names_iter};
 
 pub struct ColumnPredicates {
@@ -117,3 +129,92 @@ pub fn aexpr_to_column_predicates(
         is_sumwise_complete,
     }
 }
+
+/// Get, per column, a set of values of which the column has to equal one for the predicate to
+/// hold.
+///
+/// Only equality and `is_in` comparisons with literals that are AND-ed together are considered.
+/// Nulls are never part of a set.
+pub fn aexpr_to_column_value_sets(
+    root: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> PlHashMap<PlSmallStr, Series> {
+    let mut value_sets = PlHashMap::<PlSmallStr, Series>::default();
+
+    for minterm in MintermIter::new(root, expr_arena) {
+        let Some((column, values)) = minterm_to_value_set(minterm, expr_arena, schema) else {
+            continue;
+        };
+
+        // All minterms have to hold, so keep the most selective set.
+        match value_sets.entry(column) {
+            Entry::Occupied(mut entry) => {
+                if values.len() < entry.get().len() {
+                    *entry.get_mut() = values;
+                }
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(values);
+            },
+        }
+    }
+
+    value_sets
+}
+
+fn minterm_to_value_set(
+    minterm: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> Option<(PlSmallStr, Series)> {
+    let (column, values) = match expr_arena.get(minterm) {
+        // col(A) == v, which can only hold if v is not null.
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::Eq | Operator::EqValidity,
+            right,
+        } => {
+            let ((column, _), (lv, _)) =
+                get_binary_expr_col_and_lv(*left, *right, expr_arena, schema)?;
+            let av = lv?.to_any_value()?.into_static();
+            if av.is_null() {
+                return None;
+            }
+
+            let values = Scalar::new(av.dtype(), av).into_series(column.clone());
+            (column, values)
+        },
+        // col(A).is_in([v1, ..., vn])
+        #[cfg(feature = "is_in")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }),
+            ..
+        } => {
+            let column = into_column(input[0].node(), expr_arena, schema, 0)?;
+            let lv = constant_evaluate(input[1].node(), expr_arena, schema, 0)??;
+            let values = match lv.as_ref() {
+                LiteralValue::Series(s) => (**s).clone(),
+                lv => match lv.to_any_value()? {
+                    AnyValue::List(s) => s,
+                    _ => return None,
+                },
+            };
+
+            // A null in the set matches the nulls of the column.
+            if *nulls_equal && values.has_nulls() {
+                return None;
+            }
+
+            (column, values)
+        },
+        _ => return None,
+    };
+
+    if schema.get(column)? != values.dtype() {
+        return None;
+    }
+
+    Some((column.clone(), values.drop_nulls()))
+}
@@ -146,6 +146,22 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 let cloud_options = unified_scan_args_box.cloud_options.clone();
                 let cloud_options = cloud_options.as_ref();
 
+                #[cfg(feature = "delta")]
+                let delta_snapshot = match &*scan_type {
+                    FileScan::Delta { options, snapshot } => {
+                        let snapshot = match snapshot {
+                            Some(snapshot) => snapshot.clone(),
+                            None => Arc::new(
+                                scans::delta_snapshot(&sources, options, cloud_options)
+                                    .map_err(|e| e.context(failed_here!(delta scan)))?,
+                            ),
+                        };
+
+                        Some(snapshot)
+                    },
+                    _ => None,
+                };
+
                 let unified_scan_args = unified_scan_args_box.as_mut();
                 let mut scan_type = scan_type.clone();
 
@@ -172,6 +188,29 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         #[cfg(feature = "ipc")]
                         FileScan::Ipc { .. } => sources
                             .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
+                        #[cfg(feature = "ipc_streaming")]
+                        FileScan::IpcStream { .. } => {
+                            sources.expand_paths(unified_scan_args, cloud_options)?
+                        },
+                        #[cfg(feature = "avro")]
+                        FileScan::Avro { .. } => sources
+                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
+                        #[cfg(feature = "orc")]
+                        FileScan::Orc { .. } => sources
+                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
+                        #[cfg(feature = "delta")]
+                        FileScan::Delta { .. } => ScanSources::Paths(
+                            delta_snapshot
+                                .as_ref()
+                                .unwrap()
+                                .files
+                                .iter()
+                                .map(|f| f.path.clone())
+                                .collect(),
+                        ),
+                        // The data files are only listed after predicate pushdown.
+                        #[cfg(feature = "iceberg")]
+                        FileScan::Iceberg { .. } => sources,
                         #[cfg(feature = "csv")]
                         FileScan::Csv { .. } => {
                             sources.expand_paths(unified_scan_args, cloud_options)?
@@ -180,6 +219,18 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         FileScan::NDJson { .. } => {
                             sources.expand_paths(unified_scan_args, cloud_options)?
                         },
+                        #[cfg(feature = "json")]
+                        FileScan::Json { .. } => {
+                            sources.expand_paths(unified_scan_args, cloud_options)?
+                        },
+                        #[cfg(feature = "fwf")]
+                        FileScan::Fwf { .. } => {
+                            sources.expand_paths(unified_scan_args, cloud_options)?
+                        },
+                        #[cfg(feature = "lines")]
+                        FileScan::Lines { .. } => {
+                            sources.expand_paths(unified_scan_args, cloud_options)?
+                        },
                         #[cfg(feature = "python")]
                         FileScan::PythonDataset { .. } => {
                             // There are a lot of places that short-circuit if the paths is empty,
@@ -195,6 +246,26 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         if let Some(schema) = &options.schema {
                             // We were passed a schema, we don't have to call `parquet_file_info`,
                             // but this does mean we don't have `row_estimation` and `first_metadata`.
+                            // The exception is matching by field id without given field ids, as
+                            // these are taken from the first file.
+                            if matches!(
+                                options.column_matching,
+                                polars_io::prelude::ParquetColumnMatching::FieldId {
+                                    field_ids: None
+                                }
+                            ) {
+                                let (_, md) = scans::parquet_file_info(
+                                    &sources,
+                                    None,
+                                    cloud_options,
+                                    options.key_retriever.as_ref(),
+                                    &mut options.column_matching,
+                                )
+                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+
+                                *metadata = md;
+                            }
+
                             FileInfo {
                                 schema: schema.clone(),
                                 reader_schema: Some(either::Either::Left(Arc::new(
@@ -207,6 +278,8 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                                 &sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options.key_retriever.as_ref(),
+                                &mut options.column_matching,
                             )
                             .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
@@ -225,6 +298,69 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         *metadata = Some(Arc::new(md));
                         file_info
                     },
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { options } => scans::ipc_stream_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        options,
+                        cloud_options,
+                    )
+                    .map_err(|e| e.context(failed_here!(ipc stream scan)))?,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => scans::avro_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        cloud_options,
+                    )
+                    .map_err(|e| e.context(failed_here!(avro scan)))?,
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => scans::orc_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        cloud_options,
+                    )
+                    .map_err(|e| e.context(failed_here!(orc scan)))?,
+                    #[cfg(feature = "delta")]
+                    FileScan::Delta { .. } => {
+                        let (file_info, options) =
+                            scans::delta_file_info(delta_snapshot.as_ref().unwrap());
+
+                        // Partition values are read from the log instead of the paths, and files
+                        // written before a column was added to the table do not contain it.
+                        unified_scan_args.hive_options.enabled = Some(false);
+                        unified_scan_args.hive_options.schema =
+                            Some(scans::delta_hive_schema(delta_snapshot.as_ref().unwrap()));
+                        unified_scan_args.missing_columns_policy = MissingColumnsPolicy::Insert;
+                        unified_scan_args.deleted_rows = DeletedRows::new(
+                            delta_snapshot
+                                .as_ref()
+                                .unwrap()
+                                .files
+                                .iter()
+                                .map(|f| f.deleted_rows.clone()),
+                        );
+                        *scan_type = FileScan::Parquet {
+                            options,
+                            metadata: None,
+                        };
+                        file_info
+                    },
+                    #[cfg(feature = "iceberg")]
+                    FileScan::Iceberg { options, table } => {
+                        let loaded = match table {
+                            Some(table) => table.clone(),
+                            None => Arc::new(
+                                scans::iceberg_table(&sources, options, cloud_options)
+                                    .map_err(|e| e.context(failed_here!(iceberg scan)))?,
+                            ),
+                        };
+                        *table = Some(loaded.clone());
+
+                        // Files written before a column was added to the table do not contain it.
+                        unified_scan_args.hive_options.enabled = Some(false);
+                        unified_scan_args.missing_columns_policy = MissingColumnsPolicy::Insert;
+                        scans::iceberg_file_info(&loaded)
+                    },
                     #[cfg(feature = "csv")]
                     FileScan::Csv { options } => {
                         // TODO: This is a hack. We conditionally set `allow_missing_columns` to
@@ -250,6 +386,28 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         cloud_options,
                     )
                     .map_err(|e| e.context(failed_here!(ndjson scan)))?,
+                    #[cfg(feature = "json")]
+                    FileScan::Json { options } => scans::json_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        options,
+                        cloud_options,
+                    )
+                    .map_err(|e| e.context(failed_here!(json scan)))?,
+                    #[cfg(feature = "fwf")]
+                    FileScan::Fwf { options } => scans::fwf_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        options,
+                    )
+                    .map_err(|e| e.context(failed_here!(fwf scan)))?,
+                    #[cfg(feature = "lines")]
+                    FileScan::Lines { options } => scans::lines_file_info(
+                        &sources,
+                        unified_scan_args.row_index.as_ref(),
+                        options,
+                    )
+                    .map_err(|e| e.context(failed_here!(lines scan)))?,
                     #[cfg(feature = "python")]
                     FileScan::PythonDataset { dataset_object, .. } => {
                         if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -302,6 +460,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     None
                 };
 
+                #[cfg(feature = "delta")]
+                let hive_parts = match &delta_snapshot {
+                    Some(snapshot) => scans::delta_hive_partitions(snapshot)?,
+                    None => hive_parts,
+                };
+
                 if let Some(ref hive_parts) = hive_parts {
                     let hive_schema = hive_parts.schema();
                     file_info.update_schema_with_hive_schema(hive_schema.clone());
@@ -7,7 +7,11 @@ mod ir_to_dsl;
     feature = "ipc",
     feature = "parquet",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf",
+    feature = "lines"
 ))]
 mod scans;
 mod stack_opt;
@@ -27,7 +31,11 @@ use recursive::recursive;
     feature = "ipc",
     feature = "parquet",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf",
+    feature = "lines"
 ))]
 pub use scans::*;
 mod functions;
@@ -8,7 +8,12 @@ use polars_io::utils::compression::maybe_decompress_bytes;
 
 use super::*;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.clone(), IDX_DTYPE);
@@ -16,7 +21,7 @@ fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> Sc
     Arc::new(schema)
 }
 
-#[cfg(any(feature = "json", feature = "csv"))]
+#[cfg(any(feature = "json", feature = "csv", feature = "fwf", feature = "lines"))]
 fn prepare_schemas(mut schema: Schema, row_index: Option<&RowIndex>) -> (SchemaRef, SchemaRef) {
     if let Some(rc) = row_index {
         let reader_schema = schema.clone();
@@ -33,8 +38,11 @@ pub(super) fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    key_retriever: Option<&ParquetKeyRetriever>,
+    column_matching: &mut ParquetColumnMatching,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
+    use polars_io::parquet::field_id::{field_ids_from_parquet_schema, match_field_ids};
 
     let (reader_schema, num_rows, metadata) = {
         if sources.is_cloud_url() {
@@ -42,8 +50,9 @@ pub(super) fn parquet_file_info(
             feature_gated!("cloud", {
                 let uri = first_path.to_string_lossy();
                 get_runtime().block_in_place_on(async {
-                    let mut reader =
-                        ParquetObjectStore::from_uri(&uri, cloud_options, None).await?;
+                    let mut reader = ParquetObjectStore::from_uri(&uri, cloud_options, None)
+                        .await?
+                        .with_key_retriever(key_retriever.cloned());
 
                     PolarsResult::Ok((
                         reader.schema().await?,
@@ -57,7 +66,8 @@ pub(super) fn parquet_file_info(
                 .first()
                 .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 source"))?;
             let memslice = first_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_key_retriever(key_retriever.cloned());
             (
                 reader.schema()?,
                 Some(reader.num_rows()?),
@@ -66,6 +76,23 @@ pub(super) fn parquet_file_info(
         }
     };
 
+    // When matching by field id, the table consists of the columns of the first file that have a
+    // field id, unless the field ids of the table were given.
+    let reader_schema = match column_matching {
+        ParquetColumnMatching::Name => reader_schema,
+        ParquetColumnMatching::FieldId { field_ids } => {
+            let parquet_fields = metadata.as_ref().unwrap().schema().fields();
+            let field_ids =
+                field_ids.get_or_insert_with(|| field_ids_from_parquet_schema(parquet_fields));
+            polars_ensure!(
+                !field_ids.is_empty(),
+                ComputeError: "cannot match columns by field id: the first file has no Parquet field ids"
+            );
+
+            Arc::new(match_field_ids(&reader_schema, parquet_fields, field_ids).table_schema())
+        },
+    };
+
     let schema =
         prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index);
 
@@ -129,6 +156,167 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::avro::AvroFileInfo;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.as_paths().unwrap()[0].to_str().unwrap())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let AvroFileInfo { schema, .. } = AvroFileInfo::try_from_bytes(&memslice)?;
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(schema.as_ref()), row_index),
+        Some(Either::Left(schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn ipc_stream_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &IpcStreamScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    // A given schema means that the sources are only read once, by the reader.
+    let schema = if let Some(schema) = &options.schema {
+        schema.as_ref().clone()
+    } else {
+        let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+        if run_async {
+            feature_gated!("cloud", {
+                polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.as_paths().unwrap()[0].to_str().unwrap())],
+                    cloud_options,
+                )?;
+            })
+        }
+
+        let reader = std::io::BufReader::new(first.to_sequential_reader(run_async)?);
+        IpcStreamReader::new(reader).schema()?
+    };
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(schema.clone(), row_index),
+        Some(Either::Right(Arc::new(schema))),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::orc::OrcMetadata;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.as_paths().unwrap()[0].to_str().unwrap())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let OrcMetadata {
+        schema, num_rows, ..
+    } = OrcMetadata::try_from_bytes(&memslice)?;
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(schema.as_ref()), row_index),
+        Some(Either::Left(schema)),
+        (Some(num_rows), num_rows),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "fwf")]
+pub(super) fn fwf_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &polars_io::fwf::FwfReadOptions,
+) -> PolarsResult<FileInfo> {
+    polars_ensure!(!sources.is_empty(), ComputeError: "expected at least 1 source");
+
+    // The schema is given by the column spec, so the files are not read here.
+    let (reader_schema, schema) = prepare_schemas(options.schema()?, row_index);
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
+#[cfg(feature = "lines")]
+pub(super) fn lines_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &polars_io::lines::LinesReadOptions,
+) -> PolarsResult<FileInfo> {
+    polars_ensure!(!sources.is_empty(), ComputeError: "expected at least 1 source");
+
+    // The schema is given by the capture groups of the pattern, so the files are not read here.
+    let (reader_schema, schema) = prepare_schemas(options.schema()?, row_index);
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub fn isolated_csv_file_info(
     source: ScanSourceRef,
@@ -138,6 +326,7 @@ pub fn isolated_csv_file_info(
 ) -> PolarsResult<FileInfo> {
     use std::io::{Read, Seek};
 
+    use polars_io::csv::read::maybe_transcode_schema_inference_bytes;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
     use polars_io::utils::get_reader_bytes;
 
@@ -145,7 +334,13 @@ pub fn isolated_csv_file_info(
 
     let memslice = source.to_memslice_async_assume_latest(run_async)?;
     let owned = &mut vec![];
-    let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+    let bytes = maybe_decompress_bytes(&memslice, owned)?;
+    let bytes_total = bytes.len();
+    // Only the start of the file is transcoded, the size of the rest is used as is to estimate
+    // the number of rows.
+    let transcoded = &mut vec![];
+    let bytes = maybe_transcode_schema_inference_bytes(bytes, csv_options, transcoded)?;
+    let mut reader = std::io::Cursor::new(bytes);
     if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
         polars_bail!(NoData: "empty CSV")
     }
@@ -155,7 +350,8 @@ pub fn isolated_csv_file_info(
 
     // this needs a way to estimated bytes/rows.
     let si_result =
-        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)?;
+        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)?
+            .with_bytes_total(bytes_total);
 
     csv_options.update_with_inference_result(&si_result);
 
@@ -194,6 +390,7 @@ pub fn csv_file_info(
 
     use polars_core::error::feature_gated;
     use polars_core::{POOL, config};
+    use polars_io::csv::read::maybe_transcode_schema_inference_bytes;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -230,7 +427,11 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let bytes = maybe_decompress_bytes(&memslice, owned)?;
+        let bytes_total = bytes.len();
+        let transcoded = &mut vec![];
+        let bytes = maybe_transcode_schema_inference_bytes(bytes, csv_options, transcoded)?;
+        let mut reader = std::io::Cursor::new(bytes);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -239,7 +440,10 @@ pub fn csv_file_info(
         let reader_bytes = get_reader_bytes(&mut reader).expect("could not mmap file");
 
         // this needs a way to estimated bytes/rows.
-        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)
+        Ok(
+            SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)?
+                .with_bytes_total(bytes_total),
+        )
     };
 
     let merge_func = |a: PolarsResult<SchemaInferenceResult>,
@@ -372,3 +576,153 @@ pub fn ndjson_file_info(
         (None, usize::MAX),
     ))
 }
+
+#[cfg(feature = "json")]
+pub(super) fn json_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    options: &polars_io::json::array::JsonArrayReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let schema = if options.schema.is_some() {
+        polars_io::json::array::infer_schema(&[], options)?
+    } else {
+        let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+        let cache_entries = {
+            if run_async {
+                feature_gated!("cloud", {
+                    Some(polars_io::file_cache::init_entries_from_uri_list(
+                        sources
+                            .as_paths()
+                            .unwrap()
+                            .iter()
+                            .map(|path| Arc::from(path.to_str().unwrap()))
+                            .collect::<Vec<_>>()
+                            .as_slice(),
+                        cloud_options,
+                    )?)
+                })
+            } else {
+                None
+            }
+        };
+
+        // Only the first records of the first file are parsed.
+        let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+        let owned = &mut vec![];
+        polars_io::json::array::infer_schema(maybe_decompress_bytes(&memslice, owned)?, options)?
+    };
+
+    let (reader_schema, schema) = prepare_schemas(schema, row_index);
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
+#[cfg(feature = "delta")]
+pub(super) fn delta_snapshot(
+    sources: &ScanSources,
+    options: &polars_io::delta::DeltaScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<polars_io::delta::DeltaSnapshot> {
+    let paths = sources
+        .as_paths()
+        .ok_or_else(|| polars_err!(nyi = "scanning Delta tables from in-memory buffers"))?;
+    let [path] = paths else {
+        polars_bail!(ComputeError: "expected the path of a single Delta table, got {} paths", paths.len());
+    };
+
+    polars_io::delta::DeltaSnapshot::try_new(&path.to_string_lossy(), options, cloud_options)
+}
+
+/// Returns the file info and Parquet options to read the data files of a Delta table. The
+/// partition columns are not part of the data files and are added as hive columns.
+#[cfg(feature = "delta")]
+pub(super) fn delta_file_info(
+    snapshot: &polars_io::delta::DeltaSnapshot,
+) -> (FileInfo, ParquetOptions) {
+    let file_schema = snapshot
+        .schema
+        .iter()
+        .filter(|(name, _)| !snapshot.partition_columns.contains(name))
+        .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+        .collect::<Schema>();
+    let file_schema = Arc::new(file_schema);
+
+    let file_info = FileInfo {
+        schema: file_schema.clone(),
+        reader_schema: Some(Either::Left(Arc::new(
+            file_schema.to_arrow(CompatLevel::newest()),
+        ))),
+        row_estimation: (None, 0),
+    };
+    let options = ParquetOptions {
+        schema: Some(file_schema),
+        parallel: ParallelStrategy::default(),
+        low_memory: false,
+        use_statistics: true,
+        key_retriever: None,
+        column_matching: ParquetColumnMatching::Name,
+    };
+
+    (file_info, options)
+}
+
+#[cfg(feature = "delta")]
+pub(super) fn delta_hive_schema(snapshot: &polars_io::delta::DeltaSnapshot) -> SchemaRef {
+    let hive_schema = snapshot
+        .partition_columns
+        .iter()
+        .map(|name| Field::new(name.clone(), snapshot.schema.get(name).unwrap().clone()))
+        .collect::<Schema>();
+    Arc::new(hive_schema)
+}
+
+#[cfg(feature = "delta")]
+pub(super) fn delta_hive_partitions(
+    snapshot: &polars_io::delta::DeltaSnapshot,
+) -> PolarsResult<Option<crate::plans::hive::HivePartitionsDf>> {
+    crate::plans::hive::hive_partitions_from_values(
+        &delta_hive_schema(snapshot),
+        snapshot.files.len(),
+        snapshot.files.iter().map(|f| f.partition_values.as_slice()),
+    )
+}
+
+#[cfg(feature = "iceberg")]
+pub(super) fn iceberg_table(
+    sources: &ScanSources,
+    options: &polars_io::iceberg::IcebergScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<polars_io::iceberg::IcebergTable> {
+    let paths = sources
+        .as_paths()
+        .ok_or_else(|| polars_err!(nyi = "scanning Iceberg tables from in-memory buffers"))?;
+    let [path] = paths else {
+        polars_bail!(ComputeError: "expected the path of a single Iceberg table, got {} paths", paths.len());
+    };
+
+    polars_io::iceberg::IcebergTable::try_new(&path.to_string_lossy(), options, cloud_options)
+}
+
+#[cfg(feature = "iceberg")]
+pub(super) fn iceberg_file_info(table: &polars_io::iceberg::IcebergTable) -> FileInfo {
+    FileInfo {
+        schema: table.schema.clone(),
+        reader_schema: Some(Either::Left(Arc::new(
+            table.schema.to_arrow(CompatLevel::newest()),
+        ))),
+        row_estimation: (None, usize::MAX),
+    }
+}
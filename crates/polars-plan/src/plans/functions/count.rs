@@ -4,7 +4,11 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf",
+    feature = "lines"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
@@ -12,6 +16,8 @@ use polars_io::SerReader;
 #[cfg(any(feature = "parquet", feature = "json"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
+use polars_io::parquet::encryption::ParquetKeyRetriever;
+#[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
@@ -29,7 +35,11 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc",
+        feature = "fwf",
+        feature = "lines"
     )))]
     {
         unreachable!()
@@ -39,14 +49,20 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc",
+        feature = "fwf",
+        feature = "lines"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
             #[cfg(feature = "csv")]
             FileScan::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScan::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            FileScan::Parquet { options, .. } => {
+                count_rows_parquet(sources, cloud_options, options.key_retriever.as_ref())
+            },
             #[cfg(feature = "ipc")]
             FileScan::Ipc { options, metadata } => count_rows_ipc(
                 sources,
@@ -54,8 +70,26 @@ pub fn count_rows(
                 cloud_options,
                 metadata.as_deref(),
             ),
+            #[cfg(feature = "ipc_streaming")]
+            FileScan::IpcStream { .. } => count_rows_ipc_stream(sources, cloud_options),
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "json")]
+            FileScan::Json { .. } => count_rows_json(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScan::Avro { .. } => count_rows_avro(sources, cloud_options),
+            #[cfg(feature = "orc")]
+            FileScan::Orc { .. } => count_rows_orc(sources, cloud_options),
+            #[cfg(feature = "fwf")]
+            FileScan::Fwf { options } => count_rows_fwf(sources, options, cloud_options),
+            #[cfg(feature = "lines")]
+            FileScan::Lines { options } => count_rows_lines(sources, options, cloud_options),
+            // Converted to a Parquet scan during IR conversion.
+            #[cfg(feature = "delta")]
+            FileScan::Delta { .. } => unreachable!(),
+            // Not counted with the fast path, see `CountStar`.
+            #[cfg(feature = "iceberg")]
+            FileScan::Iceberg { .. } => unreachable!(),
             #[cfg(feature = "python")]
             FileScan::PythonDataset { .. } => unreachable!(),
             FileScan::Anonymous { .. } => {
@@ -76,26 +110,43 @@ fn count_all_rows_csv(
     sources: &ScanSources,
     options: &polars_io::prelude::CsvReadOptions,
 ) -> PolarsResult<usize> {
+    use polars_io::csv::read::{CsvEncoding, maybe_transcode_bytes};
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
     let parse_options = options.get_parse_options();
 
     sources
         .iter()
         .map(|source| match source {
-            ScanSourceRef::Path(path) => polars_io::csv::read::count_rows(
-                path,
-                parse_options.separator,
-                parse_options.quote_char,
-                parse_options.comment_prefix.as_ref(),
-                parse_options.eol_char,
-                options.has_header,
-            ),
+            ScanSourceRef::Path(path)
+                if matches!(
+                    parse_options.encoding,
+                    CsvEncoding::Utf8 | CsvEncoding::LossyUtf8
+                ) =>
+            {
+                polars_io::csv::read::count_rows(
+                    path,
+                    parse_options.separator,
+                    parse_options.quote_char,
+                    parse_options.escape_char,
+                    parse_options.comment_prefix.as_ref(),
+                    parse_options.eol_char,
+                    options.has_header,
+                )
+            },
             _ => {
                 let memslice = source.to_memslice()?;
+                let mut owned = vec![];
+                let mut transcoded = vec![];
+                let bytes = maybe_decompress_bytes(&memslice, &mut owned)?;
+                let bytes =
+                    maybe_transcode_bytes(bytes, parse_options.encoding, None, &mut transcoded)?;
 
                 polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
+                    bytes,
                     parse_options.separator,
                     parse_options.quote_char,
+                    parse_options.escape_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
                     options.has_header,
@@ -109,6 +160,7 @@ fn count_all_rows_csv(
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -120,13 +172,16 @@ pub(super) fn count_rows_parquet(
             get_runtime().block_on(count_rows_cloud_parquet(
                 sources.as_paths().unwrap(),
                 cloud_options,
+                key_retriever,
             ))
         })
     } else {
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_key_retriever(key_retriever.cloned())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -136,13 +191,16 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     paths: &[std::path::PathBuf],
     cloud_options: Option<&CloudOptions>,
+    key_retriever: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<usize> {
     use polars_io::prelude::ParquetObjectStore;
 
     let collection = paths.iter().map(|path| {
         with_concurrency_budget(1, || async {
             let mut reader =
-                ParquetObjectStore::from_uri(&path.to_string_lossy(), cloud_options, None).await?;
+                ParquetObjectStore::from_uri(&path.to_string_lossy(), cloud_options, None)
+                    .await?
+                    .with_key_retriever(key_retriever.cloned());
             reader.num_rows().await
         })
     });
@@ -248,3 +306,276 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "json")]
+pub(super) fn count_rows_json(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            let owned = &mut vec![];
+            polars_io::json::array::count_rows(maybe_decompress_bytes(&memslice[..], owned)?)
+        })
+        .sum()
+}
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            polars_io::avro::count_rows(&memslice)
+        })
+        .sum()
+}
+
+#[cfg(feature = "ipc_streaming")]
+pub(super) fn count_rows_ipc_stream(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::SerReader;
+    use polars_io::ipc::IpcStreamReader;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    if run_async {
+        feature_gated!("cloud", {
+            polars_io::file_cache::init_entries_from_uri_list(
+                sources
+                    .as_paths()
+                    .unwrap()
+                    .iter()
+                    .map(|path| Arc::from(path.to_str().unwrap()))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                cloud_options,
+            )?;
+        })
+    }
+
+    sources
+        .iter()
+        .map(|source| {
+            let reader = std::io::BufReader::new(source.to_sequential_reader(run_async)?);
+            IpcStreamReader::new(reader).count_rows()
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn count_rows_orc(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            polars_io::orc::count_rows(&memslice)
+        })
+        .sum()
+}
+
+#[cfg(feature = "fwf")]
+pub(super) fn count_rows_fwf(
+    sources: &ScanSources,
+    options: &polars_io::fwf::FwfReadOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            let owned = &mut vec![];
+            let bytes = maybe_decompress_bytes(&memslice[..], owned)?;
+            Ok(polars_io::fwf::count_rows(bytes, options))
+        })
+        .sum()
+}
+
+#[cfg(feature = "lines")]
+pub(super) fn count_rows_lines(
+    sources: &ScanSources,
+    options: &polars_io::lines::LinesReadOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let is_cloud_url = sources.is_cloud_url();
+    let run_async = is_cloud_url || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+
+            let owned = &mut vec![];
+            let bytes = maybe_decompress_bytes(&memslice[..], owned)?;
+            polars_io::lines::count_rows(bytes, options)
+        })
+        .sum()
+}
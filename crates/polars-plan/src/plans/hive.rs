@@ -217,6 +217,49 @@ pub fn hive_partitions_from_paths(
     )?)))
 }
 
+/// Builds the hive partitions of files whose partition values are stored as strings outside of
+/// their paths, e.g. in the transaction log of a table format. The values of every file must be in
+/// the order of `hive_schema`.
+#[cfg(feature = "delta")]
+pub fn hive_partitions_from_values<'a>(
+    hive_schema: &Schema,
+    num_files: usize,
+    values: impl Iterator<Item = &'a [Option<PlSmallStr>]>,
+) -> PolarsResult<Option<HivePartitionsDf>> {
+    if hive_schema.is_empty() {
+        return Ok(None);
+    }
+
+    let mut buffers = polars_io::csv::read::buffer::init_buffers(
+        &(0..hive_schema.len()).collect::<Vec<_>>(),
+        num_files,
+        hive_schema,
+        None,
+        polars_io::prelude::CsvEncoding::Utf8,
+        false,
+    )?;
+
+    for file_values in values {
+        for (buf, value) in buffers.iter_mut().zip(file_values) {
+            match value {
+                Some(value) if !value.is_empty() => {
+                    buf.add(value.as_bytes(), false, false, false)?
+                },
+                _ => buf.add_null(false),
+            }
+        }
+    }
+
+    let buffers = buffers
+        .into_iter()
+        .map(|x| Ok(x.into_series()?.into_column()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(Some(HivePartitionsDf(DataFrame::new_with_height(
+        num_files, buffers,
+    )?)))
+}
+
 /// Determine the path separator for identifying Hive partitions.
 fn separator(url: &Path) -> &[char] {
     if cfg!(target_family = "windows") {
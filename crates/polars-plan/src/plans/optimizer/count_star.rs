@@ -169,11 +169,23 @@ fn visit_logical_plan_for_scan_paths(
             // New-streaming is generally on par for all except CSV (see https://github.com/pola-rs/polars/pull/22363).
             // In the future we can potentially remove the dedicated count codepaths.
 
-            let use_fast_file_count = use_fast_file_count.unwrap_or(match scan_type.as_ref() {
+            let use_fast_file_count = match scan_type.as_ref() {
+                // Deleted rows are not counted.
+                _ if unified_scan_args.deleted_rows.is_some() => false,
+                // The data files are only known after expansion, and may have deleted rows.
+                #[cfg(feature = "iceberg")]
+                FileScan::Iceberg { .. } => false,
+                // Rows that fail to parse are not counted.
                 #[cfg(feature = "csv")]
-                FileScan::Csv { .. } => true,
-                _ => false,
-            });
+                FileScan::Csv { options } if options.rejected_rows.is_some() => false,
+                #[cfg(feature = "json")]
+                FileScan::NDJson { options } if options.rejected_rows.is_some() => false,
+                _ => use_fast_file_count.unwrap_or(match scan_type.as_ref() {
+                    #[cfg(feature = "csv")]
+                    FileScan::Csv { .. } => true,
+                    _ => false,
+                }),
+            };
 
             if use_fast_file_count {
                 Some(CountStarExpr {
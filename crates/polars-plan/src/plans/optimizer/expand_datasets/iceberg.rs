@@ -0,0 +1,523 @@
+//! Expansion of a native Iceberg scan into Parquet scans over the data files of the table.
+//!
+//! Positional deletes are passed to the Parquet scan as the deleted rows of each file. Files
+//! without equality deletes or renamed columns are read by a single Parquet scan. Otherwise the
+//! files are split over multiple scans whose results are combined by a union, in which consecutive
+//! files that share their equality deletes and renamed columns are read together.
+use std::sync::Arc;
+
+use either::Either;
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_io::iceberg::{Comparison, IcebergDataFile, IcebergPredicate, IcebergTable};
+use polars_io::parquet::read::{ParallelStrategy, ParquetColumnMatching, ParquetOptions};
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+pub(super) fn expand_scan(
+    ir: IR,
+    table: &IcebergTable,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    let IR::Scan {
+        sources: _,
+        file_info,
+        hive_parts: _,
+        predicate,
+        output_schema,
+        scan_type: _,
+        mut unified_scan_args,
+    } = ir
+    else {
+        unreachable!()
+    };
+
+    let pruning_predicate = predicate
+        .as_ref()
+        .and_then(|p| to_iceberg_predicate(p.node(), expr_arena, &table.schema));
+    let files = table.plan_files(pruning_predicate.as_ref())?;
+
+    if config::verbose() {
+        eprintln!(
+            "expand_datasets(): iceberg: snapshot: {:?}, data files: {}",
+            table.snapshot_id,
+            files.len()
+        )
+    }
+
+    let output_schema = output_schema.unwrap_or_else(|| file_info.schema.clone());
+    if files.is_empty() {
+        return Ok(IR::DataFrameScan {
+            df: Arc::new(DataFrame::empty_with_schema(&output_schema)),
+            schema: output_schema,
+            output_schema: None,
+        });
+    }
+
+    if files
+        .iter()
+        .all(|f| f.equality_deletes.is_empty() && f.renamed_columns.is_none())
+    {
+        // Data files can contain columns that were dropped from the table.
+        if unified_scan_args.projection.is_none() {
+            unified_scan_args.projection = Some(table.schema.iter_names_cloned().collect());
+        }
+        unified_scan_args.deleted_rows =
+            DeletedRows::new(files.iter().map(|f| f.deleted_rows.clone()));
+
+        return Ok(IR::Scan {
+            sources: ScanSources::Paths(files.into_iter().map(|f| f.path).collect()),
+            file_info,
+            hive_parts: None,
+            predicate,
+            output_schema: Some(output_schema),
+            scan_type: Box::new(parquet_scan_type(table.schema.clone())),
+            unified_scan_args,
+        });
+    }
+
+    // The row index and slice apply to the rows of the whole table after the deletes, so they are
+    // moved on top of the union, together with a predicate that refers to the row index.
+    let row_index = unified_scan_args.row_index.take();
+    let pre_slice = unified_scan_args.pre_slice.take();
+    let filter_after_union = row_index.is_some() && predicate.is_some();
+
+    let mut union_schema = (*output_schema).clone();
+    if let Some(row_index) = &row_index {
+        union_schema.shift_remove(&row_index.name);
+    }
+    let predicate_columns = predicate
+        .as_ref()
+        .map(|p| aexpr_to_leaf_names(p.node(), expr_arena))
+        .unwrap_or_default();
+    if filter_after_union {
+        for name in &predicate_columns {
+            if let Some(dtype) = table.schema.get(name) {
+                if !union_schema.contains(name) {
+                    union_schema.with_column(name.clone(), dtype.clone());
+                }
+            }
+        }
+    }
+
+    let expansion = Expansion {
+        table,
+        unified_scan_args: &unified_scan_args,
+        predicate: predicate.as_ref().filter(|_| !filter_after_union),
+        predicate_columns: &predicate_columns,
+        union_schema: &union_schema,
+    };
+    let inputs = group_files(files)
+        .into_iter()
+        .map(|group| expansion.scan_group(group, lp_arena, expr_arena))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let mut node = match inputs.as_slice() {
+        [input] => *input,
+        _ => lp_arena.add(IR::Union {
+            inputs,
+            options: UnionOptions {
+                parallel: true,
+                maintain_order: true,
+                ..Default::default()
+            },
+        }),
+    };
+
+    if let Some(row_index) = row_index {
+        node = IRBuilder::new(node, expr_arena, lp_arena)
+            .row_index(row_index.name, Some(row_index.offset))
+            .node();
+    }
+    if let Some(pre_slice) = pre_slice {
+        let (offset, len) = match pre_slice {
+            Slice::Positive { offset, len } => (offset as i64, len),
+            Slice::Negative {
+                offset_from_end,
+                len,
+            } => (-(offset_from_end as i64), len),
+        };
+        node = lp_arena.add(IR::Slice {
+            input: node,
+            offset,
+            len: len.try_into().unwrap_or(IdxSize::MAX),
+        });
+    }
+    if filter_after_union {
+        node = lp_arena.add(IR::Filter {
+            input: node,
+            predicate: predicate.unwrap(),
+        });
+    }
+    if lp_arena.get(node).schema(lp_arena).as_ref() != &output_schema {
+        node = lp_arena.add(IR::SimpleProjection {
+            input: node,
+            columns: output_schema,
+        });
+    }
+
+    Ok(lp_arena.take(node))
+}
+
+fn parquet_scan_type(schema: SchemaRef) -> FileScan {
+    FileScan::Parquet {
+        options: ParquetOptions {
+            schema: Some(schema),
+            parallel: ParallelStrategy::default(),
+            low_memory: false,
+            use_statistics: true,
+            key_retriever: None,
+            column_matching: ParquetColumnMatching::Name,
+        },
+        metadata: None,
+    }
+}
+
+/// Data files that are read by a single Parquet scan.
+struct FileGroup {
+    paths: Vec<std::path::PathBuf>,
+    deleted_rows: Vec<Option<Arc<[IdxSize]>>>,
+    /// The first file of the group, which has the same equality deletes and renamed columns as
+    /// the other files.
+    file: IcebergDataFile,
+}
+
+/// Groups consecutive files that share their equality deletes and renamed columns.
+fn group_files(files: Vec<IcebergDataFile>) -> Vec<FileGroup> {
+    let mut groups: Vec<FileGroup> = vec![];
+
+    for file in files {
+        if let Some(group) = groups.last_mut() {
+            let same_renames = match (&group.file.renamed_columns, &file.renamed_columns) {
+                (Some(l), Some(r)) => Arc::ptr_eq(l, r),
+                (l, r) => l.is_none() && r.is_none(),
+            };
+            let same_deletes = group.file.equality_deletes.len() == file.equality_deletes.len()
+                && group
+                    .file
+                    .equality_deletes
+                    .iter()
+                    .zip(&file.equality_deletes)
+                    .all(|(l, r)| Arc::ptr_eq(l, r));
+
+            if same_renames && same_deletes {
+                group.paths.push(file.path);
+                group.deleted_rows.push(file.deleted_rows);
+                continue;
+            }
+        }
+
+        groups.push(FileGroup {
+            paths: vec![file.path.clone()],
+            deleted_rows: vec![file.deleted_rows.clone()],
+            file,
+        });
+    }
+
+    groups
+}
+
+struct Expansion<'a> {
+    table: &'a IcebergTable,
+    unified_scan_args: &'a UnifiedScanArgs,
+    /// Predicate that is applied to every group of files, if it is not applied after the union.
+    predicate: Option<&'a ExprIR>,
+    predicate_columns: &'a [PlSmallStr],
+    union_schema: &'a Schema,
+}
+
+impl Expansion<'_> {
+    /// Adds the plan that reads a group of files with their deletes applied, and returns its node.
+    /// The output has the schema of the union.
+    fn scan_group(
+        &self,
+        group: FileGroup,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<Node> {
+        let FileGroup {
+            paths,
+            deleted_rows,
+            file,
+        } = group;
+        let renamed_columns = file.renamed_columns.as_deref().unwrap_or_default();
+        let file_name = |name: &PlSmallStr| {
+            renamed_columns
+                .iter()
+                .find(|(_, table_name)| table_name == name)
+                .map_or(name, |(file_name, _)| file_name)
+                .clone()
+        };
+
+        // Table columns that are read, including those needed to apply the deletes and the
+        // predicate.
+        let mut columns = self
+            .union_schema
+            .iter_names()
+            .filter(|name| self.table.schema.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+        let predicate_columns = match self.predicate {
+            Some(_) => self.predicate_columns,
+            None => &[],
+        };
+        let extra_columns = file
+            .equality_deletes
+            .iter()
+            .flat_map(|deletes| deletes.rows.get_column_names_owned())
+            .chain(predicate_columns.iter().cloned());
+        for name in extra_columns {
+            if self.table.schema.contains(&name) && !columns.contains(&name) {
+                columns.push(name);
+            }
+        }
+
+        // The table schema with the column names of the files.
+        let file_schema = self
+            .table
+            .schema
+            .iter()
+            .map(|(name, dtype)| Field::new(file_name(name), dtype.clone()))
+            .collect::<Schema>();
+
+        let file_path_column = self.unified_scan_args.include_file_paths.as_ref();
+
+        let mut scan_schema = file_schema.clone();
+        let mut scan_output_schema = columns
+            .iter()
+            .map(|name| {
+                Field::new(
+                    file_name(name),
+                    self.table.schema.get(name).unwrap().clone(),
+                )
+            })
+            .collect::<Schema>();
+        for schema in [&mut scan_schema, &mut scan_output_schema] {
+            if let Some(name) = file_path_column {
+                schema.insert_at_index(schema.len(), name.clone(), DataType::String)?;
+            }
+        }
+
+        let unified_scan_args = UnifiedScanArgs {
+            projection: Some(columns.iter().map(file_name).collect()),
+            row_index: None,
+            pre_slice: None,
+            deleted_rows: DeletedRows::new(deleted_rows),
+            ..self.unified_scan_args.clone()
+        };
+        let scan = IR::Scan {
+            sources: ScanSources::Paths(paths.into()),
+            file_info: FileInfo {
+                schema: Arc::new(scan_schema),
+                reader_schema: Some(Either::Left(Arc::new(
+                    file_schema.to_arrow(CompatLevel::newest()),
+                ))),
+                row_estimation: (None, usize::MAX),
+            },
+            hive_parts: None,
+            // The predicate refers to the table names of the columns.
+            predicate: self
+                .predicate
+                .filter(|_| renamed_columns.is_empty())
+                .cloned(),
+            output_schema: Some(Arc::new(scan_output_schema.clone())),
+            scan_type: Box::new(parquet_scan_type(Arc::new(file_schema))),
+            unified_scan_args: Box::new(unified_scan_args),
+        };
+
+        let renames = (!renamed_columns.is_empty()).then(|| {
+            scan_output_schema
+                .iter_names()
+                .map(|name| {
+                    let node = expr_arena.add(AExpr::Column(name.clone()));
+                    match renamed_columns
+                        .iter()
+                        .find(|(file_name, _)| file_name == name)
+                    {
+                        Some((_, table_name)) => {
+                            ExprIR::new(node, OutputName::Alias(table_name.clone()))
+                        },
+                        None => ExprIR::new(node, OutputName::ColumnLhs(name.clone())),
+                    }
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut node = lp_arena.add(scan);
+        if let Some(renames) = renames {
+            node = IRBuilder::new(node, expr_arena, lp_arena)
+                .project(renames, ProjectionOptions::default())
+                .node();
+
+            if let Some(predicate) = self.predicate {
+                node = lp_arena.add(IR::Filter {
+                    input: node,
+                    predicate: predicate.clone(),
+                });
+            }
+        }
+
+        if let Some(condition) = delete_condition(&file) {
+            let predicate = to_expr_ir(condition, expr_arena)?;
+            node = IRBuilder::new(node, expr_arena, lp_arena)
+                .add_alp_optimize_exprs(|input| IR::Filter { input, predicate })?
+                .node();
+        }
+
+        if lp_arena.get(node).schema(lp_arena).as_ref().as_ref() != self.union_schema {
+            node = lp_arena.add(IR::SimpleProjection {
+                input: node,
+                columns: Arc::new(self.union_schema.clone()),
+            });
+        }
+
+        Ok(node)
+    }
+}
+
+/// Returns the condition that rows of a data file must fulfill to not be deleted by its equality
+/// deletes.
+fn delete_condition(file: &IcebergDataFile) -> Option<Expr> {
+    let mut conditions = vec![];
+
+    // Nulls of the equality columns are equal to each other.
+    for deletes in &file.equality_deletes {
+        let (key, values) = match deletes.rows.get_columns() {
+            [column] => (
+                col(column.name().clone()),
+                column.as_materialized_series().clone(),
+            ),
+            columns => (
+                as_struct(columns.iter().map(|c| col(c.name().clone())).collect()),
+                deletes
+                    .rows
+                    .clone()
+                    .into_struct(PlSmallStr::EMPTY)
+                    .into_series(),
+            ),
+        };
+        conditions.push(key.is_in(lit(values).implode(), true).not());
+    }
+
+    conditions.into_iter().reduce(Expr::and)
+}
+
+/// Converts the parts of a predicate that can be evaluated on the statistics of the files. Terms
+/// of a conjunction that cannot be converted are left out, which only makes the pruning less
+/// selective.
+fn to_iceberg_predicate(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> Option<IcebergPredicate> {
+    let mut terms = MintermIter::new(node, expr_arena)
+        .filter_map(|term| to_iceberg_term(term, expr_arena, schema))
+        .collect::<Vec<_>>();
+
+    match terms.len() {
+        0 => None,
+        1 => terms.pop(),
+        _ => Some(IcebergPredicate::And(terms)),
+    }
+}
+
+fn to_iceberg_term(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+) -> Option<IcebergPredicate> {
+    let column = |node: Node| match expr_arena.get(node) {
+        AExpr::Column(name) => schema.get(name).map(|dtype| (name.clone(), dtype)),
+        _ => None,
+    };
+
+    match expr_arena.get(node) {
+        AExpr::BinaryExpr { left, op, right } => {
+            if matches!(op, Operator::Or | Operator::LogicalOr) {
+                return Some(IcebergPredicate::Or(vec![
+                    to_iceberg_predicate(*left, expr_arena, schema)?,
+                    to_iceberg_predicate(*right, expr_arena, schema)?,
+                ]));
+            }
+
+            let op = match op {
+                Operator::Eq => Comparison::Eq,
+                Operator::NotEq => Comparison::NotEq,
+                Operator::Lt => Comparison::Lt,
+                Operator::LtEq => Comparison::LtEq,
+                Operator::Gt => Comparison::Gt,
+                Operator::GtEq => Comparison::GtEq,
+                _ => return None,
+            };
+            let (column, op, value) = match (expr_arena.get(*left), expr_arena.get(*right)) {
+                (_, AExpr::Literal(value)) => (column(*left)?, op, value),
+                (AExpr::Literal(value), _) => {
+                    let op = match op {
+                        Comparison::Lt => Comparison::Gt,
+                        Comparison::LtEq => Comparison::GtEq,
+                        Comparison::Gt => Comparison::Lt,
+                        Comparison::GtEq => Comparison::LtEq,
+                        op => op,
+                    };
+                    (column(*right)?, op, value)
+                },
+                _ => return None,
+            };
+
+            Some(IcebergPredicate::Compare {
+                value: to_scalar(value.to_any_value()?, column.1)?,
+                column: column.0,
+                op,
+            })
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+            ..
+        } => match function {
+            BooleanFunction::IsNull => Some(IcebergPredicate::IsNull(column(input[0].node())?.0)),
+            BooleanFunction::IsNotNull => {
+                Some(IcebergPredicate::IsNotNull(column(input[0].node())?.0))
+            },
+            BooleanFunction::IsIn { .. } => {
+                let (column, dtype) = column(input[0].node())?;
+                let AExpr::Literal(values) = expr_arena.get(input[1].node()) else {
+                    return None;
+                };
+                let values = match values {
+                    LiteralValue::Series(s) => (**s).clone(),
+                    values => match values.to_any_value()? {
+                        AnyValue::List(s) => s,
+                        _ => return None,
+                    },
+                };
+                let values = values
+                    .iter()
+                    .map(|value| to_scalar(value, dtype))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(IcebergPredicate::IsIn { column, values })
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Converts a literal to a scalar of the data type of the column it is compared with. Only
+/// conversions that cannot change the outcome of the comparison are done.
+fn to_scalar(value: AnyValue, dtype: &DataType) -> Option<Scalar> {
+    let value = value.into_static();
+    let scalar = Scalar::new(value.dtype(), value);
+
+    if scalar.is_null() || scalar.dtype() == dtype {
+        Some(Scalar::new(dtype.clone(), scalar.into_value()))
+    } else if scalar.dtype().is_integer() && dtype.is_integer() {
+        scalar.cast_with_options(dtype, CastOptions::Strict).ok()
+    } else {
+        None
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "iceberg")]
+mod iceberg;
+
 use std::fmt::Debug;
 use std::sync::Arc;
 
@@ -17,7 +20,8 @@ use crate::dsl::{DslPlan, FileScan, UnifiedScanArgs};
 use crate::plans::IR;
 
 /// Note: Currently only used for iceberg. This is so that we can call iceberg to fetch the files
-/// list with a potential row limit from slice pushdown.
+/// list with a potential row limit from slice pushdown, or with the pushed down predicate to prune
+/// the files of a native Iceberg scan.
 ///
 /// In the future this can also apply to hive path expansion with predicates.
 pub(super) struct ExpandDatasets;
@@ -26,7 +30,9 @@ impl OptimizationRule for ExpandDatasets {
     fn optimize_plan(
         &mut self,
         lp_arena: &mut Arena<IR>,
-        _expr_arena: &mut Arena<crate::prelude::AExpr>,
+        #[cfg_attr(not(feature = "iceberg"), allow(unused_variables))] expr_arena: &mut Arena<
+            crate::prelude::AExpr,
+        >,
         node: Node,
     ) -> PolarsResult<Option<IR>> {
         let ir = lp_arena.get(node);
@@ -44,6 +50,16 @@ impl OptimizationRule for ExpandDatasets {
             };
 
             match scan_type.as_ref() {
+                #[cfg(feature = "iceberg")]
+                FileScan::Iceberg { table, .. } => {
+                    let table = table
+                        .clone()
+                        .expect("Iceberg table should be loaded during IR conversion");
+                    let ir = ir.clone();
+
+                    return iceberg::expand_scan(ir, &table, lp_arena, expr_arena).map(Some);
+                },
+
                 #[cfg(feature = "python")]
                 FileScan::PythonDataset {
                     dataset_object,
@@ -127,6 +143,7 @@ impl OptimizationRule for ExpandDatasets {
                                 cast_columns_policy,
                                 missing_columns_policy,
                                 include_file_paths: _include_file_paths @ None,
+                                deleted_rows: _deleted_rows @ None,
                             } = *resolved_unified_scan_args
                             else {
                                 panic!(
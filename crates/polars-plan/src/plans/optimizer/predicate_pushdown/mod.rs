@@ -361,6 +361,12 @@ impl PredicatePushDown<'_> {
                     FileScan::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => {},
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => {},
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => {},
+                    #[cfg(feature = "fwf")]
+                    FileScan::Fwf { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
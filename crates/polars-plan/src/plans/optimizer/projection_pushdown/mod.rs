@@ -442,10 +442,26 @@ impl ProjectionPushDown {
                     FileScan::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => true,
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { .. } => true,
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => true,
+                    #[cfg(feature = "fwf")]
+                    FileScan::Fwf { .. } => true,
+                    #[cfg(feature = "lines")]
+                    FileScan::Lines { .. } => true,
+                    #[cfg(feature = "json")]
+                    FileScan::Json { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => true,
+                    #[cfg(feature = "delta")]
+                    FileScan::Delta { .. } => unreachable!(),
+                    #[cfg(feature = "iceberg")]
+                    FileScan::Iceberg { .. } => true,
                     // MultiScan will handle it if the PythonDataset cannot do projections.
                     #[cfg(feature = "python")]
                     FileScan::PythonDataset { .. } => true,
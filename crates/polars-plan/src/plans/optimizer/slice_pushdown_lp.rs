@@ -223,6 +223,30 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScan::Ipc { .. } => true,
 
+                #[cfg(feature = "ipc_streaming")]
+                FileScan::IpcStream { .. } => true,
+
+                #[cfg(feature = "avro")]
+                FileScan::Avro { .. } => true,
+
+                #[cfg(feature = "orc")]
+                FileScan::Orc { .. } => true,
+
+                #[cfg(feature = "fwf")]
+                FileScan::Fwf { .. } => true,
+
+                #[cfg(feature = "lines")]
+                FileScan::Lines { .. } => true,
+
+                #[cfg(feature = "json")]
+                FileScan::Json { .. } => true,
+
+                #[cfg(feature = "delta")]
+                FileScan::Delta { .. } => unreachable!(),
+
+                #[cfg(feature = "iceberg")]
+                FileScan::Iceberg { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScan::Csv { .. } => true,
 
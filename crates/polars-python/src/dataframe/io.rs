@@ -445,6 +445,12 @@ impl PyDataFrame {
                     statistics: statistics.0,
                     row_group_size,
                     data_page_size,
+                    bloom_filters: Vec::new(),
+                    column_options: Vec::new(),
+                    key_value_metadata: Vec::new(),
+                    field_metadata: Vec::new(),
+                    field_ids: Vec::new(),
+                    encryption: None,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
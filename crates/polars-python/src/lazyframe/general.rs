@@ -356,6 +356,8 @@ impl PyLazyFrame {
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
             allow_missing_columns,
+            key_retriever: None,
+            column_matching: Default::default(),
         };
 
         let sources = sources.0;
@@ -773,6 +775,12 @@ impl PyLazyFrame {
             statistics: statistics.0,
             row_group_size,
             data_page_size,
+            bloom_filters: Vec::new(),
+            column_options: Vec::new(),
+            key_value_metadata: Vec::new(),
+            field_metadata: Vec::new(),
+            field_ids: Vec::new(),
+            encryption: None,
         };
 
         let cloud_options = match target.base_path() {
@@ -914,7 +922,9 @@ impl PyLazyFrame {
             float_scientific,
             float_precision,
             separator,
+            multi_byte_separator: None,
             quote_char,
+            escape_char: None,
             null: null_value,
             line_terminator,
             quote_style,
@@ -925,6 +935,7 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: Default::default(),
         };
 
         #[cfg(feature = "cloud")]
@@ -980,7 +991,7 @@ impl PyLazyFrame {
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions::default();
 
         let cloud_options = match target.base_path() {
             None => None,
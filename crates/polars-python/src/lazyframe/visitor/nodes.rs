@@ -39,6 +39,22 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScan::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "ipc_streaming")]
+        FileScan::IpcStream { .. } => Err(PyNotImplementedError::new_err("ipc stream scan")),
+        #[cfg(feature = "avro")]
+        FileScan::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
+        #[cfg(feature = "orc")]
+        FileScan::Orc { .. } => Err(PyNotImplementedError::new_err("orc scan")),
+        #[cfg(feature = "fwf")]
+        FileScan::Fwf { .. } => Err(PyNotImplementedError::new_err("fwf scan")),
+        #[cfg(feature = "lines")]
+        FileScan::Lines { .. } => Err(PyNotImplementedError::new_err("lines scan")),
+        #[cfg(feature = "json")]
+        FileScan::Json { .. } => Err(PyNotImplementedError::new_err("json scan")),
+        #[cfg(feature = "delta")]
+        FileScan::Delta { .. } => Err(PyNotImplementedError::new_err("delta scan")),
+        #[cfg(feature = "iceberg")]
+        FileScan::Iceberg { .. } => Err(PyNotImplementedError::new_err("iceberg scan")),
         #[cfg(feature = "json")]
         FileScan::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
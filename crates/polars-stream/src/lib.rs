@@ -10,6 +10,8 @@ mod execute;
 pub(crate) mod expression;
 mod graph;
 pub use skeleton::{QueryResult, StreamingQuery};
+#[doc(hidden)]
+pub use utils::spill::with_memory_budget;
 mod morsel;
 mod nodes;
 mod physical_plan;
@@ -1,33 +1,42 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 
-use polars_core::POOL;
-use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
+use polars_core::prelude::{Column, IntoColumn, PlHashSet, PlRandomState, Scalar};
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::{POOL, config};
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
 use crate::async_executor;
 use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
-use crate::morsel::get_ideal_morsel_size;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    PartitionedSpiller, SpillDir, SpilledDataFrame, merge_spilled_partitions,
+};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+/// When spilling, each partition is split into this many spill partitions so
+/// only a fraction of its groups has to be in memory when they are combined.
+const SPILL_PARTITIONS_PER_PARTITION: usize = 4;
+
 struct LocalGroupBySinkState {
     hot_grouper: Box<dyn HotGrouper>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -47,6 +56,13 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // Estimated number of bytes held by the cold morsels and pre-aggregates.
+    // Once this exceeds our share of the memory budget they are written to
+    // disk together with the hot groups, partitioned by the spill partitioner.
+    mem_bytes: usize,
+    row_spiller: PartitionedSpiller,
+    state_spiller: PartitionedSpiller,
 }
 
 impl LocalGroupBySinkState {
@@ -55,6 +71,7 @@ impl LocalGroupBySinkState {
         reductions: Vec<Box<dyn GroupedReduction>>,
         hot_table_size: usize,
         num_partitions: usize,
+        spill_partitioner: HashPartitioner,
     ) -> Self {
         let hot_grouper = new_hash_hot_grouper(key_schema, hot_table_size);
         Self {
@@ -70,6 +87,10 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            mem_bytes: 0,
+            row_spiller: PartitionedSpiller::new(spill_partitioner.clone()),
+            state_spiller: PartitionedSpiller::new(spill_partitioner),
         }
     }
 
@@ -99,9 +120,113 @@ impl LocalGroupBySinkState {
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    fn has_spilled(&self) -> bool {
+        self.row_spiller.has_spilled() || self.state_spiller.has_spilled()
+    }
+
+    /// Writes the hot groups, cold morsels and pre-aggregates to disk. The hot
+    /// keys stay in the table with their states reset, so that later rows of
+    /// those groups are still aggregated in memory.
+    fn spill(
+        &mut self,
+        grouper_template: &dyn Grouper,
+        key_schema: &Schema,
+        uniq_grouped_reduction_cols: &[PlSmallStr],
+        partitioner: &HashPartitioner,
+        spill_dir: &SpillDir,
+    ) -> PolarsResult<()> {
+        if self.hot_grouper.num_evictions() > 0 {
+            self.flush_evictions(partitioner);
+        }
+        let hot_keys = self.hot_grouper.keys();
+        let num_hot_groups = self.hot_grouper.num_groups();
+        let hot_reductions = self
+            .hot_grouped_reductions
+            .iter()
+            .map(|r| {
+                let mut r = r.new_empty();
+                r.resize(num_hot_groups);
+                r
+            })
+            .collect();
+        let hot_reductions = core::mem::replace(&mut self.hot_grouped_reductions, hot_reductions);
+
+        for (hash_keys, mut reductions) in
+            self.pre_aggs.drain(..).chain([(hot_keys, hot_reductions)])
+        {
+            let keys = keys_in_row_order(grouper_template, &hash_keys, key_schema);
+            let states = reductions
+                .iter_mut()
+                .flat_map(|r| r.take_state())
+                .map(Column::from);
+            let df = spill_frame(keys, states);
+            self.state_spiller.spill(&df, &hash_keys, true, spill_dir)?;
+        }
+
+        for (seq, hash_keys, cold_df) in self.cold_morsels.drain(..) {
+            let keys = keys_in_row_order(grouper_template, &hash_keys, key_schema);
+            let height = keys.height();
+            let values = uniq_grouped_reduction_cols
+                .iter()
+                .map(|c| cold_df.column(c).unwrap().clone())
+                .chain([Column::new_scalar(
+                    PlSmallStr::EMPTY,
+                    Scalar::from(seq),
+                    height,
+                )]);
+            let df = spill_frame(keys, values);
+            self.row_spiller.spill(&df, &hash_keys, true, spill_dir)?;
+        }
+
+        let num_partitions = self.morsel_idxs_values_per_p.len();
+        for idxs in self
+            .morsel_idxs_values_per_p
+            .iter_mut()
+            .chain(&mut self.pre_agg_idxs_values_per_p)
+        {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.truncate(num_partitions);
+        self.pre_agg_idxs_offsets_per_p.truncate(num_partitions);
+        self.mem_bytes = 0;
+        Ok(())
+    }
+}
+
+/// Returns the key of every row in `hash_keys`.
+fn keys_in_row_order(
+    grouper_template: &dyn Grouper,
+    hash_keys: &HashKeys,
+    key_schema: &Schema,
+) -> DataFrame {
+    let mut grouper = grouper_template.new_empty();
+    let mut group_idxs = Vec::new();
+    let subset = (0..hash_keys.len() as IdxSize).collect_vec();
+    unsafe {
+        grouper.insert_keys_subset(hash_keys, &subset, Some(&mut group_idxs));
+        grouper
+            .get_keys_in_group_order(key_schema)
+            .take_slice_unchecked_impl(&group_idxs, false)
+    }
+}
+
+/// Builds a frame to write to disk from the keys followed by `columns`. The
+/// columns are named by their position as the original names may clash.
+fn spill_frame(keys: DataFrame, columns: impl Iterator<Item = Column>) -> DataFrame {
+    let height = keys.height();
+    let columns = keys
+        .take_columns()
+        .into_iter()
+        .chain(columns)
+        .enumerate()
+        .map(|(i, c)| c.with_name(format_pl_smallstr!("{i}")))
+        .collect();
+    unsafe { DataFrame::new_no_checks(height, columns) }
 }
 
 struct GroupBySinkState {
+    key_schema: Arc<Schema>,
     key_selectors: Vec<StreamExpr>,
     grouper: Box<dyn Grouper>,
     uniq_grouped_reduction_cols: Vec<PlSmallStr>,
@@ -110,6 +235,7 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    memory_budget: usize,
 }
 
 impl GroupBySinkState {
@@ -117,11 +243,15 @@ impl GroupBySinkState {
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
         receivers: Vec<Receiver<Morsel>>,
+        spill_dir: &'env SpillDir,
         state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        let local_memory_budget = self.memory_budget / self.locals.len();
         for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let key_schema = &*self.key_schema;
             let key_selectors = &self.key_selectors;
+            let grouper_template = &*self.grouper;
             let uniq_grouped_reduction_cols = &self.uniq_grouped_reduction_cols;
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
@@ -141,6 +271,7 @@ impl GroupBySinkState {
                     }
                     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
                     let hash_keys = HashKeys::from_df(&keys, *random_state, true, false);
+                    let key_bytes_per_row = keys.estimated_size() / keys.height().max(1);
 
                     hot_idxs.clear();
                     hot_group_idxs.clear();
@@ -191,14 +322,32 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+                            local.mem_bytes +=
+                                cold_df.estimated_size() + cold_idxs.len() * key_bytes_per_row;
                             local.cold_morsels.push((seq, cold_keys, cold_df));
                         }
                     }
 
                     // If we have too many evicted rows, flush them.
-                    if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
+                    let num_evictions = local.hot_grouper.num_evictions();
+                    if num_evictions >= get_ideal_morsel_size() {
+                        // Rough estimate, most reduction states are a single value.
+                        local.mem_bytes += num_evictions
+                            * (key_bytes_per_row + grouped_reduction_cols.len() * size_of::<u64>());
                         local.flush_evictions(&partitioner);
                     }
+
+                    // Once we're over our memory budget everything is written
+                    // to disk, it is combined when the input is done.
+                    if local.mem_bytes >= local_memory_budget {
+                        local.spill(
+                            grouper_template,
+                            key_schema,
+                            uniq_grouped_reduction_cols,
+                            &partitioner,
+                            spill_dir,
+                        )?;
+                    }
                 }
                 Ok(())
             }));
@@ -423,6 +572,181 @@ impl GroupBySinkState {
 
         Ok(output_per_partition.try_assume_init().ok().unwrap())
     }
+
+    /// Writes the groups that are still in memory to disk if anything was
+    /// spilled, and collects the spilled rows and states of all locals per
+    /// spill partition. Returns None if nothing was spilled.
+    fn take_spilled(&mut self, spill_dir: &SpillDir) -> PolarsResult<Option<SpilledGroups>> {
+        if !self.locals.iter().any(|l| l.has_spilled()) {
+            return Ok(None);
+        }
+
+        let key_schema = &*self.key_schema;
+        let grouper_template = &*self.grouper;
+        let uniq_grouped_reduction_cols = &self.uniq_grouped_reduction_cols;
+        let partitioner = &self.partitioner;
+        POOL.install(|| {
+            self.locals
+                .as_mut_slice()
+                .into_par_iter()
+                .with_max_len(1)
+                .try_for_each(|l| {
+                    l.spill(
+                        grouper_template,
+                        key_schema,
+                        uniq_grouped_reduction_cols,
+                        partitioner,
+                        spill_dir,
+                    )
+                })
+        })?;
+
+        let mut rows = Vec::with_capacity(self.locals.len());
+        let mut states = Vec::with_capacity(self.locals.len());
+        for l in &mut self.locals {
+            rows.push(l.row_spiller.take_spilled(spill_dir)?);
+            states.push(l.state_spiller.take_spilled(spill_dir)?);
+        }
+        Ok(Some(SpilledGroups {
+            rows_per_p: merge_spilled_partitions(rows),
+            states_per_p: merge_spilled_partitions(states),
+        }))
+    }
+
+    /// Combines the spilled states and rows one spill partition at a time, and
+    /// writes the finalized groups to disk.
+    fn combine_spilled(
+        &self,
+        spilled: SpilledGroups,
+        output_schema: &Schema,
+        spill_dir: &SpillDir,
+    ) -> PolarsResult<VecDeque<SpilledDataFrame>> {
+        // The spilled states consist of the keys followed by the state columns
+        // of each reduction. The spilled rows consist of the keys, the unique
+        // reduction inputs and the morsel sequence id, in that order.
+        let num_keys = self.key_schema.len();
+        let state_widths = self
+            .grouped_reductions
+            .iter()
+            .map(|r| r.new_empty().take_state().len())
+            .collect_vec();
+        let reduction_col_idxs = self
+            .grouped_reduction_cols
+            .iter()
+            .map(|c| {
+                let uniq_idx = self.uniq_grouped_reduction_cols.iter().position(|u| u == c);
+                num_keys + uniq_idx.unwrap()
+            })
+            .collect_vec();
+        let seq_col_idx = num_keys + self.uniq_grouped_reduction_cols.len();
+
+        if config::verbose() {
+            let spilled_rows: usize = spilled
+                .rows_per_p
+                .iter()
+                .flatten()
+                .map(|s| s.height())
+                .sum();
+            let spilled_groups: usize = spilled
+                .states_per_p
+                .iter()
+                .flatten()
+                .map(|s| s.height())
+                .sum();
+            eprintln!(
+                "[group-by]: combining {spilled_groups} spilled groups and {spilled_rows} spilled rows"
+            );
+        }
+
+        let mut out = VecDeque::new();
+        let mut group_idxs = Vec::new();
+        for (rows, states) in spilled.rows_per_p.into_iter().zip(spilled.states_per_p) {
+            let mut grouper = self.grouper.new_empty();
+            let mut reductions = self
+                .grouped_reductions
+                .iter()
+                .map(|gr| gr.new_empty())
+                .collect_vec();
+
+            for chunk in states {
+                let mut df = chunk.load()?;
+                df.rechunk_mut();
+                let columns = df.get_columns();
+                let chunk_keys =
+                    unsafe { DataFrame::new_no_checks(df.height(), columns[..num_keys].to_vec()) };
+                let chunk_hash_keys =
+                    HashKeys::from_df(&chunk_keys, self.random_state, true, false);
+                let state = columns[num_keys..]
+                    .iter()
+                    .map(|c| c.as_materialized_series().clone())
+                    .collect_vec();
+                let subset = (0..df.height() as IdxSize).collect_vec();
+                unsafe {
+                    group_idxs.clear();
+                    grouper.insert_keys_subset(&chunk_hash_keys, &subset, Some(&mut group_idxs));
+                    let mut start = 0;
+                    for (r, width) in reductions.iter_mut().zip(&state_widths) {
+                        let other = r.load_state(&state[start..start + width])?;
+                        start += width;
+                        r.resize(grouper.num_groups());
+                        r.combine_subset(&*other, &subset, &group_idxs)?;
+                    }
+                }
+            }
+
+            // Insert the spilled rows, per original morsel so the
+            // order-sensitive reductions get the right sequence id.
+            for chunk in rows {
+                let mut df = chunk.load()?;
+                df.rechunk_mut();
+                let columns = df.get_columns();
+                let chunk_keys =
+                    unsafe { DataFrame::new_no_checks(df.height(), columns[..num_keys].to_vec()) };
+                let chunk_hash_keys =
+                    HashKeys::from_df(&chunk_keys, self.random_state, true, false);
+                let seqs = columns[seq_col_idx].u64()?.cont_slice()?;
+                let mut start = 0;
+                for seq_run in seqs.chunk_by(|l, r| l == r) {
+                    let stop = start + seq_run.len();
+                    let subset = (start as IdxSize..stop as IdxSize).collect_vec();
+                    start = stop;
+                    unsafe {
+                        group_idxs.clear();
+                        grouper.insert_keys_subset(
+                            &chunk_hash_keys,
+                            &subset,
+                            Some(&mut group_idxs),
+                        );
+                        for (r, col_idx) in reductions.iter_mut().zip(&reduction_col_idxs) {
+                            r.resize(grouper.num_groups());
+                            r.update_groups_subset(
+                                &columns[*col_idx],
+                                &subset,
+                                &group_idxs,
+                                seq_run[0],
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            let partition = GroupByPartition {
+                grouper,
+                grouped_reductions: reductions,
+            };
+            let mut df = partition.into_df(&self.key_schema, output_schema)?;
+            if df.height() > 0 {
+                out.push_back(spill_dir.spill(&mut df)?);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// The aggregation states and rows that were written to disk, per spill partition.
+struct SpilledGroups {
+    rows_per_p: Vec<Vec<SpilledDataFrame>>,
+    states_per_p: Vec<Vec<SpilledDataFrame>>,
 }
 
 struct GroupByPartition {
@@ -443,9 +767,41 @@ impl GroupByPartition {
     }
 }
 
+/// Outputs the finalized groups that were written to disk.
+struct SpilledSource {
+    chunks: VecDeque<SpilledDataFrame>,
+    current: Option<DataFrame>,
+    seq: u64,
+}
+
+impl SpilledSource {
+    fn next_morsel(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            if let Some(df) = self.current.take() {
+                if df.height() > get_ideal_morsel_size() {
+                    let (head, tail) = df.split_at(get_ideal_morsel_size() as i64);
+                    self.current = Some(tail);
+                    return Ok(Some(head));
+                }
+                return Ok(Some(df));
+            }
+
+            let Some(chunk) = self.chunks.pop_front() else {
+                return Ok(None);
+            };
+            self.current = Some(chunk.load()?);
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.current.is_none() && self.chunks.is_empty()
+    }
+}
+
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource(SpilledSource),
     Done,
 }
 
@@ -453,6 +809,7 @@ pub struct GroupByNode {
     state: GroupByState,
     key_schema: Arc<Schema>,
     output_schema: Arc<Schema>,
+    spill_dir: SpillDir,
 }
 
 impl GroupByNode {
@@ -466,6 +823,7 @@ impl GroupByNode {
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
         num_pipelines: usize,
+        memory_budget: usize,
     ) -> Self {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
@@ -477,6 +835,9 @@ impl GroupByNode {
             .collect::<PlHashSet<_>>()
             .into_iter()
             .collect_vec();
+        let partitioner = HashPartitioner::new(num_partitions, 0);
+        let spill_partitioner =
+            HashPartitioner::new(num_partitions * SPILL_PARTITIONS_PER_PARTITION, 0);
         let locals = (0..num_pipelines)
             .map(|_| {
                 let reductions = grouped_reductions.iter().map(|gr| gr.new_empty()).collect();
@@ -485,12 +846,13 @@ impl GroupByNode {
                     reductions,
                     hot_table_size,
                     num_partitions,
+                    spill_partitioner.clone(),
                 )
             })
             .collect();
-        let partitioner = HashPartitioner::new(num_partitions, 0);
         Self {
             state: GroupByState::Sink(GroupBySinkState {
+                key_schema: key_schema.clone(),
                 key_selectors,
                 grouped_reductions,
                 grouper,
@@ -499,9 +861,11 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                memory_budget,
             }),
             key_schema,
             output_schema,
+            spill_dir: SpillDir::new("group-by"),
         }
     }
 }
@@ -532,17 +896,27 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
-                let dfs = POOL.install(|| {
-                    partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
-
-                let df = accumulate_dataframes_vertical_unchecked(dfs);
-                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
-                self.state = GroupByState::Source(source);
+                self.state = if let Some(spilled) = sink.take_spilled(&self.spill_dir)? {
+                    let chunks =
+                        sink.combine_spilled(spilled, &self.output_schema, &self.spill_dir)?;
+                    GroupByState::SpilledSource(SpilledSource {
+                        chunks,
+                        current: None,
+                        seq: 0,
+                    })
+                } else {
+                    let partitions = sink.combine_locals()?;
+                    let dfs = POOL.install(|| {
+                        partitions
+                            .into_par_iter()
+                            .map(|p| p.into_df(&self.key_schema, &self.output_schema))
+                            .collect::<Result<Vec<_>, _>>()
+                    })?;
+
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                    GroupByState::Source(source)
+                };
             },
             // Defer to source node implementation.
             GroupByState::Source(src) => {
@@ -551,6 +925,11 @@ impl ComputeNode for GroupByNode {
                     self.state = GroupByState::Done;
                 }
             },
+            GroupByState::SpilledSource(src) => {
+                if src.is_exhausted() {
+                    self.state = GroupByState::Done;
+                }
+            },
             // Nothing to change.
             GroupByState::Done | GroupByState::Sink(_) => {},
         }
@@ -561,7 +940,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource(..) => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -588,6 +967,7 @@ impl ComputeNode for GroupByNode {
                 sink.spawn(
                     scope,
                     recv_ports[0].take().unwrap().parallel(),
+                    &self.spill_dir,
                     state,
                     join_handles,
                 )
@@ -596,6 +976,29 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource(source) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    while let Some(df) = source.next_morsel()? {
+                        let seq = MorselSeq::new(source.seq);
+                        source.seq += 1;
+                        let mut morsel = Morsel::new(df, seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+
+                        wait_group.wait().await;
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
             GroupByState::Done => unreachable!(),
         }
     }
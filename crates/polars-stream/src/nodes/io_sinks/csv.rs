@@ -91,6 +91,10 @@ impl SinkNode for CsvSinkNode {
                             .with_separator(options.serialize_options.separator)
                             .with_line_terminator(options.serialize_options.line_terminator.clone())
                             .with_quote_char(options.serialize_options.quote_char)
+                            .with_multi_byte_separator(
+                                options.serialize_options.multi_byte_separator.clone(),
+                            )
+                            .with_escape_char(options.serialize_options.escape_char)
                             .with_datetime_format(options.serialize_options.datetime_format.clone())
                             .with_date_format(options.serialize_options.date_format.clone())
                             .with_time_format(options.serialize_options.time_format.clone())
@@ -104,6 +108,15 @@ impl SinkNode for CsvSinkNode {
                         writer.write_batch(&df)?;
 
                         allocation_size = allocation_size.max(buffer.len());
+                        // Every morsel is compressed as a separate block, so the compression is
+                        // spread over the encode tasks.
+                        if options.compression.is_compressed() {
+                            let mut compressed = Vec::with_capacity(buffer.len() / 2);
+                            options
+                                .compression
+                                .compress_block(&buffer, &mut compressed)?;
+                            buffer = compressed;
+                        }
                         if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
                             return Ok(());
                         }
@@ -131,14 +144,20 @@ impl SinkNode for CsvSinkNode {
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
-            // Write the header
-            if options.include_header || options.include_bom {
+            // Write the header. With compression this is also done without a header, so that
+            // the output is a valid compressed stream even if there are no rows.
+            if options.include_header || options.include_bom || options.compression.is_compressed()
+            {
                 let mut writer = CsvWriter::new(&mut *file)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
                     .with_line_terminator(options.serialize_options.line_terminator.clone())
                     .with_quote_char(options.serialize_options.quote_char)
+                    .with_multi_byte_separator(
+                        options.serialize_options.multi_byte_separator.clone(),
+                    )
+                    .with_escape_char(options.serialize_options.escape_char)
                     .with_datetime_format(options.serialize_options.datetime_format.clone())
                     .with_date_format(options.serialize_options.date_format.clone())
                     .with_time_format(options.serialize_options.time_format.clone())
@@ -146,6 +165,7 @@ impl SinkNode for CsvSinkNode {
                     .with_float_precision(options.serialize_options.float_precision)
                     .with_null_value(options.serialize_options.null.clone())
                     .with_quote_style(options.serialize_options.quote_style)
+                    .with_compression(options.compression)
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
@@ -11,7 +11,9 @@ use polars_core::utils::arrow::io::ipc::write::{
 use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
-use polars_io::ipc::{IpcWriter, IpcWriterOptions};
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::{BatchedStreamWriter, IpcStreamWriter};
+use polars_io::ipc::{BatchedWriter, IpcWriter, IpcWriterOptions};
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -34,6 +36,8 @@ pub struct IpcSinkNode {
     write_options: IpcWriterOptions,
     sink_options: SinkOptions,
     cloud_options: Option<CloudOptions>,
+    /// Write the streaming format instead of the file format.
+    stream: bool,
 }
 
 impl IpcSinkNode {
@@ -51,13 +55,68 @@ impl IpcSinkNode {
             write_options,
             sink_options,
             cloud_options,
+            stream: false,
+        }
+    }
+
+    /// Sink that writes Arrow's Streaming IPC format.
+    #[cfg(feature = "ipc_streaming")]
+    pub fn new_stream(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: IpcWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> Self {
+        Self {
+            stream: true,
+            ..Self::new(
+                input_schema,
+                target,
+                sink_options,
+                write_options,
+                cloud_options,
+            )
+        }
+    }
+}
+
+/// The encoded record batches are the same for both formats, only the framing differs.
+enum EncodedWriter<W: std::io::Write> {
+    File(BatchedWriter<W>),
+    #[cfg(feature = "ipc_streaming")]
+    Stream(BatchedStreamWriter<W>),
+}
+
+impl<W: std::io::Write> EncodedWriter<W> {
+    fn write_encoded(
+        &mut self,
+        dictionaries: &[EncodedData],
+        message: &EncodedData,
+    ) -> PolarsResult<()> {
+        match self {
+            Self::File(writer) => writer.write_encoded(dictionaries, message),
+            #[cfg(feature = "ipc_streaming")]
+            Self::Stream(writer) => writer.write_encoded(dictionaries, message),
+        }
+    }
+
+    fn finish(&mut self) -> PolarsResult<()> {
+        match self {
+            Self::File(writer) => writer.finish(),
+            #[cfg(feature = "ipc_streaming")]
+            Self::Stream(writer) => writer.finish(),
         }
     }
 }
 
 impl SinkNode for IpcSinkNode {
     fn name(&self) -> &str {
-        "ipc-sink"
+        if self.stream {
+            "ipc-stream-sink"
+        } else {
+            "ipc-sink"
+        }
     }
 
     fn is_sink_input_parallel(&self) -> bool {
@@ -298,15 +357,32 @@ impl SinkNode for IpcSinkNode {
         let write_options = self.write_options;
         let cloud_options = self.cloud_options.clone();
         let input_schema = self.input_schema.clone();
+        let stream = self.stream;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             let mut file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
             let writer = BufWriter::new(&mut *file);
-            let mut writer = IpcWriter::new(writer)
-                .with_compression(write_options.compression)
-                .with_parallel(false)
-                .batched(&input_schema)?;
+            let mut writer = if stream {
+                #[cfg(feature = "ipc_streaming")]
+                {
+                    EncodedWriter::Stream(
+                        IpcStreamWriter::new(writer)
+                            .with_compression(write_options.compression)
+                            .with_compat_level(write_options.compat_level)
+                            .batched(&input_schema)?,
+                    )
+                }
+                #[cfg(not(feature = "ipc_streaming"))]
+                unreachable!()
+            } else {
+                EncodedWriter::File(
+                    IpcWriter::new(writer)
+                        .with_compression(write_options.compression)
+                        .with_parallel(false)
+                        .batched(&input_schema)?,
+                )
+            };
 
             while let Ok((dicts, record_batch)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
@@ -2,7 +2,7 @@ use std::cmp::Reverse;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -17,17 +17,20 @@ use crate::nodes::{JoinHandle, TaskPriority};
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 }
 impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
         }
     }
@@ -65,6 +68,8 @@ impl SinkNode for NDJsonSinkNode {
         //
         // Task encodes the columns into their corresponding JSON encoding.
         join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
+            let compression = self.write_options.compression;
+
             spawn(TaskPriority::High, async move {
                 // Amortize the allocations over time. If we see that we need to do way larger
                 // allocations, we adjust to that over time.
@@ -80,6 +85,13 @@ impl SinkNode for NDJsonSinkNode {
                         writer.write_batch(&df)?;
 
                         allocation_size = allocation_size.max(buffer.len());
+                        // Every morsel is compressed as a separate block, so the compression is
+                        // spread over the encode tasks.
+                        if compression.is_compressed() {
+                            let mut compressed = Vec::with_capacity(buffer.len() / 2);
+                            compression.compress_block(&buffer, &mut compressed)?;
+                            buffer = compressed;
+                        }
                         if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
                             return Ok(());
                         }
@@ -98,6 +110,7 @@ impl SinkNode for NDJsonSinkNode {
         // Task that will actually do write to the target file.
         let sink_options = self.sink_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
@@ -106,6 +119,14 @@ impl SinkNode for NDJsonSinkNode {
                 .await?
                 .try_into_async_writeable()?;
 
+            // Start with an empty block, so that the output is a valid compressed stream even if
+            // there are no rows.
+            if compression.is_compressed() {
+                let mut empty_block = vec![];
+                compression.compress_block(&[], &mut empty_block)?;
+                file.write_all(&empty_block).await?;
+            }
+
             while let Ok(mut lin_rx) = io_rx.recv().await {
                 while let Some(Priority(_, buffer)) = lin_rx.get().await {
                     file.write_all(&buffer).await?;
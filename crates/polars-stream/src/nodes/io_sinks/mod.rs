@@ -20,6 +20,8 @@ use crate::nodes::TaskPriority;
 mod phase;
 use phase::PhaseOutcome;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 #[cfg(feature = "csv")]
 pub mod csv;
 #[cfg(feature = "ipc")]
@@ -29,6 +31,8 @@ pub mod json;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod partition;
+#[cfg(feature = "pg_copy")]
+pub mod pg_copy;
 
 // This needs to be low to increase the backpressure.
 static DEFAULT_SINK_LINEARIZER_BUFFER_SIZE: LazyLock<usize> = LazyLock::new(|| {
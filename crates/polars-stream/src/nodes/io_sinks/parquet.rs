@@ -6,14 +6,19 @@ use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::write::BatchedWriter;
+use polars_io::parquet::field_id::add_field_ids;
+use polars_io::parquet::write::{
+    BatchedWriter, RowGroupBloomFilters, add_field_metadata, encrypt_writer, get_bloom_filters,
+    get_compressions, get_key_value_metadata,
+};
 use polars_io::prelude::{ParquetWriteOptions, get_encodings};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    CompressedPage, Compressor, Encoding, FileWriter, SchemaDescriptor, Version, WriteOptions,
-    array_to_columns, to_parquet_schema,
+    BloomFilterOptions, CompressedPage, CompressionOptions, Compressor, Encoding, FileWriter,
+    KeyValue, SchemaDescriptor, Version, WriteOptions, array_to_bloom_filter, array_to_columns,
+    to_parquet_schema, transverse,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -40,6 +45,9 @@ pub struct ParquetSinkNode {
     parquet_schema: SchemaDescriptor,
     arrow_schema: ArrowSchema,
     encodings: Vec<Vec<Encoding>>,
+    compressions: Vec<CompressionOptions>,
+    bloom_filters: Vec<Option<BloomFilterOptions>>,
+    key_value_metadata: Option<Vec<KeyValue>>,
     cloud_options: Option<CloudOptions>,
 }
 
@@ -51,20 +59,32 @@ impl ParquetSinkNode {
         write_options: &ParquetWriteOptions,
         cloud_options: Option<CloudOptions>,
     ) -> PolarsResult<Self> {
-        let schema = schema_to_arrow_checked(&input_schema, CompatLevel::newest(), "parquet")?;
+        let mut schema = schema_to_arrow_checked(&input_schema, CompatLevel::newest(), "parquet")?;
+        add_field_metadata(&mut schema, &write_options.field_metadata)?;
+        add_field_ids(&mut schema, &write_options.field_ids)?;
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings: Vec<Vec<Encoding>> = get_encodings(&schema);
+        let encodings: Vec<Vec<Encoding>> = get_encodings(&schema, &write_options.column_options)?;
+        let compressions = get_compressions(
+            &schema,
+            write_options.compression.into(),
+            &write_options.column_options,
+        )?;
+        let bloom_filters = get_bloom_filters(&schema, &write_options.bloom_filters)?;
+        let key_value_metadata = get_key_value_metadata(&write_options.key_value_metadata)?;
 
         Ok(Self {
             target,
 
             input_schema,
             sink_options,
-            write_options: *write_options,
+            write_options: write_options.clone(),
 
             parquet_schema,
             arrow_schema: schema,
             encodings,
+            compressions,
+            bloom_filters,
+            key_value_metadata,
             cloud_options,
         })
     }
@@ -98,9 +118,10 @@ impl SinkNode for ParquetSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (mut io_tx, mut io_rx) =
+            connector::<(Vec<Vec<CompressedPage>>, Option<RowGroupBloomFilters>)>();
 
-        let write_options = self.write_options;
+        let write_options = &self.write_options;
 
         let options = WriteOptions {
             statistics: write_options.statistics,
@@ -129,15 +150,30 @@ impl SinkNode for ParquetSinkNode {
                 .map(|(mut dist_rx, mut lin_tx)| {
                     let parquet_schema = self.parquet_schema.clone();
                     let encodings = self.encodings.clone();
+                    let compressions = self.compressions.clone();
+                    let bloom_filters = self.bloom_filters.clone();
 
                     spawn(TaskPriority::High, async move {
                         while let Ok((rg_idx, col_idx, column)) = dist_rx.recv().await {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let encodings = &encodings[col_idx];
+                            let options = WriteOptions {
+                                compression: compressions[col_idx],
+                                ..options
+                            };
 
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
 
+                            // Bloom filters are only written for non-nested columns, so there is
+                            // at most one per Polars column.
+                            let bloom_filters = match &bloom_filters[col_idx] {
+                                Some(options) => {
+                                    vec![array_to_bloom_filter(array.as_ref(), options)]
+                                },
+                                None => transverse(array.dtype(), |_| None),
+                            };
+
                             // @TODO: This causes all structs fields to be handled on a single thread. It
                             // would be preferable to split the encoding among multiple threads.
 
@@ -168,7 +204,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, compressed_pages, bloom_filters),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -186,11 +225,12 @@ impl SinkNode for ParquetSinkNode {
         // Collects all the encoded data and packs it together for the IO task to write it.
         let input_schema = self.input_schema.clone();
         let num_parquet_columns = self.parquet_schema.leaves().len();
+        let has_bloom_filters = self.bloom_filters.iter().any(Option::is_some);
         join_handles.push(spawn(TaskPriority::High, async move {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<(Vec<Vec<CompressedPage>>, RowGroupBloomFilters)>>,
             }
 
             let mut current = Current {
@@ -200,14 +240,16 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, compressed_pages, bloom_filters))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some((compressed_pages, bloom_filters));
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -215,11 +257,20 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut current_bloom_filters: RowGroupBloomFilters =
+                        Vec::with_capacity(num_parquet_columns);
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, bloom_filters) = column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        current_bloom_filters.extend(bloom_filters);
                     }
+                    let current_bloom_filters = has_bloom_filters.then_some(current_bloom_filters);
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((current_row_group, current_bloom_filters))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -236,36 +287,55 @@ impl SinkNode for ParquetSinkNode {
         let target = self.target.clone();
         let sink_options = self.sink_options.clone();
         let cloud_options = self.cloud_options.clone();
-        let write_options = self.write_options;
+        let write_options = self.write_options.clone();
         let arrow_schema = self.arrow_schema.clone();
         let parquet_schema = self.parquet_schema.clone();
         let encodings = self.encodings.clone();
+        let compressions = self.compressions.clone();
+        let bloom_filters = self.bloom_filters.clone();
+        let key_value_metadata = self.key_value_metadata.clone();
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             let mut file = target
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
             let writer = BufWriter::new(&mut *file);
+            let encryption = write_options.encryption;
+            let bloom_filter_options = write_options.bloom_filters;
             let write_options = WriteOptions {
                 statistics: write_options.statistics,
                 compression: write_options.compression.into(),
                 version: Version::V1,
                 data_page_size: write_options.data_page_size,
             };
-            let file_writer = Mutex::new(FileWriter::new_with_parquet_schema(
+            let file_writer = FileWriter::new_with_parquet_schema(
                 writer,
                 arrow_schema,
                 parquet_schema,
                 write_options,
-            ));
-            let mut writer = BatchedWriter::new(file_writer, encodings, write_options, false);
+            );
+            let file_writer =
+                encrypt_writer(file_writer, encryption.as_ref(), &bloom_filter_options)?;
+            let file_writer = Mutex::new(file_writer);
+            let mut writer = BatchedWriter::new(
+                file_writer,
+                encodings,
+                compressions,
+                bloom_filters,
+                key_value_metadata,
+                write_options,
+                false,
+            );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, current_bloom_filters)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
                 writer.write_row_group(&current_row_group)?;
+                if let Some(current_bloom_filters) = current_bloom_filters {
+                    writer.write_bloom_filters(&current_bloom_filters)?;
+                }
             }
 
             writer.finish()?;
@@ -45,11 +45,23 @@ pub fn get_create_new_fn(
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
         }) as _,
+        #[cfg(feature = "ipc_streaming")]
+        FileType::IpcStream(ipc_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::ipc::IpcSinkNode::new_stream(
+                input_schema,
+                target,
+                sink_options.clone(),
+                ipc_writer_options,
+                cloud_options.clone(),
+            )) as Box<dyn SinkNode + Send + Sync>;
+            Ok(sink)
+        }) as _,
         #[cfg(feature = "json")]
-        FileType::Json(_ndjson_writer_options) => Arc::new(move |_input_schema, target| {
+        FileType::Json(ndjson_writer_options) => Arc::new(move |_input_schema, target| {
             let sink = Box::new(super::json::NDJsonSinkNode::new(
                 target,
                 sink_options.clone(),
+                ndjson_writer_options,
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send + Sync>;
             Ok(sink)
@@ -67,6 +79,28 @@ pub fn get_create_new_fn(
                 Ok(sink)
             }) as _
         },
+        #[cfg(feature = "avro")]
+        FileType::Avro(avro_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::avro::AvroSinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                &avro_writer_options,
+                cloud_options.clone(),
+            )?) as Box<dyn SinkNode + Send + Sync>;
+            Ok(sink)
+        }) as _,
+        #[cfg(feature = "pg_copy")]
+        FileType::PgCopy(pg_copy_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::pg_copy::PgCopySinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                &pg_copy_writer_options,
+                cloud_options.clone(),
+            )?) as Box<dyn SinkNode + Send + Sync>;
+            Ok(sink)
+        }) as _,
         #[cfg(feature = "csv")]
         FileType::Csv(csv_writer_options) => Arc::new(move |input_schema, target| {
             let sink = Box::new(super::csv::CsvSinkNode::new(
@@ -82,7 +116,9 @@ pub fn get_create_new_fn(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "avro",
+            feature = "pg_copy"
         )))]
         _ => {
             panic!("activate source feature")
@@ -0,0 +1,464 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::TryExtend;
+use arrow::io::avro::read::{self, BlockInfo};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::DataType;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::avro::AvroFileInfo;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder;
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = AvroFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    file_info: Arc<AvroFileInfo>,
+    blocks: Arc<[BlockInfo]>,
+    n_rows_in_file: IdxSize,
+}
+
+fn get_max_morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_AVRO_SOURCE_MAX_MORSEL_SIZE")
+        .map_or_else(
+            |_| get_ideal_morsel_size(),
+            |v| {
+                v.parse::<usize>().expect(
+                    "POLARS_STREAMING_AVRO_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
+                )
+            },
+        )
+        .max(1)
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution only reads the header of the first file.
+        if let ScanSourceRef::Path(p) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(p.to_str().unwrap())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let file_info = AvroFileInfo::try_from_bytes(&memslice)?;
+        let blocks: Arc<[BlockInfo]> = file_info.block_infos(&memslice)?.into();
+
+        let n_rows: usize = blocks.iter().map(|block| block.num_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            file_info: Arc::new(file_info),
+            blocks,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            file_info,
+            blocks,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let normalized_pre_slice = pre_slice_arg.clone().map(|pre_slice| {
+            pre_slice.restrict_to_bounds(usize::try_from(n_rows_in_file).unwrap())
+        });
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(
+                file_info.schema.as_ref(),
+            )));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[AvroFileReader]: early return: \
+                    n_rows_in_file: {} \
+                    pre_slice: {:?} \
+                    resolved_pre_slice: {:?} \
+                    ",
+                    n_rows_in_file, pre_slice_arg, normalized_pre_slice
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // Avro blocks are row-oriented, so unprojected columns are skipped while decoding.
+        let projection: Arc<[bool]> = file_info
+            .schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+
+        let projected_pl_schema: Arc<Schema> = Arc::new(
+            file_info
+                .schema
+                .iter_values()
+                .zip(projection.iter())
+                .filter(|(_, p)| **p)
+                .map(|(f, _)| (f.name.clone(), DataType::from_arrow_field(f)))
+                .collect(),
+        );
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                blocks: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                projected_pl_schema.len(),
+                file_info.schema.len(),
+                blocks.len(),
+                pre_slice_arg,
+                normalized_pre_slice
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_max_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct BatchMessage {
+            /// Row index of the first row of the first block.
+            row_idx_offset: IdxSize,
+            /// Slice relative to the first row of the first block.
+            slice: Range<usize>,
+            block_range: Range<usize>,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute morsels from large blocks over the output
+        // pipelines, see the IPC source for details.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Decompresses and deserializes a contiguous range of blocks into a single DataFrame,
+        // which is then split into morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(batch_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let file_info = file_info.clone();
+                let blocks = blocks.clone();
+                let projection = projection.clone();
+                let projected_pl_schema = projected_pl_schema.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let BatchMessage {
+                            row_idx_offset,
+                            slice,
+                            block_range,
+                            morsel_seq_base,
+                        } = m;
+
+                        let mut df = if projected_pl_schema.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let first_block = &blocks[block_range.start];
+                            let reader = read::Reader::new(
+                                &memslice[first_block.offset..],
+                                file_info.metadata.clone(),
+                                file_info.schema.as_ref().clone(),
+                                Some(projection.to_vec()),
+                            );
+
+                            let mut df = DataFrame::empty_with_schema(&projected_pl_schema);
+                            df.try_extend(reader.take(block_range.len()))?;
+                            df.slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = IdxSize::try_from(slice.start)
+                                .ok()
+                                .and_then(|start| row_idx_offset.checked_add(start))
+                                .ok_or_else(
+                                    || polars_err!(ComputeError: "avro row index overflowed"),
+                                )?;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Groups the blocks that overlap with the slice into batches for the decoder tasks. The
+        // block headers were already read during initialization, so this does no IO.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+
+            // Batch completion parameters
+            let batch_size_limit = get_ideal_morsel_size();
+            let sliced_batch_size_limit = slice.len().div_ceil(num_pipelines);
+            let batch_block_limit = blocks.len().div_ceil(num_pipelines);
+
+            let mut morsel_seq: u64 = 0;
+            let mut block_idx = 0;
+            let mut rows_before_block = 0;
+
+            // Skip over all blocks that end before the slice starts.
+            while block_idx < blocks.len()
+                && rows_before_block + blocks[block_idx].num_rows <= slice.start
+            {
+                rows_before_block += blocks[block_idx].num_rows;
+                block_idx += 1;
+            }
+
+            while block_idx < blocks.len() && rows_before_block < slice.end {
+                let batch_start_row = rows_before_block;
+                let block_start = block_idx;
+                let mut num_rows = 0;
+
+                // Batch blocks such that we send appropriately sized morsels. We guarantee a
+                // lower bound here, but not an upper bound.
+                while block_idx < blocks.len() && batch_start_row + num_rows < slice.end {
+                    num_rows += blocks[block_idx].num_rows;
+                    block_idx += 1;
+
+                    if num_rows >= batch_size_limit
+                        || num_rows >= sliced_batch_size_limit
+                        || block_idx - block_start >= batch_block_limit
+                    {
+                        break;
+                    }
+                }
+
+                rows_before_block += num_rows;
+
+                let batch_slice = slice.start.saturating_sub(batch_start_row)
+                    ..(slice.end - batch_start_row).min(num_rows);
+                let batch_slice_len = batch_slice.len();
+
+                let message = BatchMessage {
+                    // Cannot overflow, the total row count of the file fits in IdxSize.
+                    row_idx_offset: row_idx_base + batch_start_row as IdxSize,
+                    slice: batch_slice,
+                    block_range: block_start..block_idx,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if batch_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                morsel_seq += batch_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<polars_core::schema::SchemaRef> {
+        let InitializedState { file_info, .. } = self.init_data.as_ref().unwrap();
+        Ok(Arc::new(Schema::from_arrow_schema(
+            file_info.schema.as_ref(),
+        )))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
@@ -10,18 +10,20 @@ use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::_csv_read_internal::{
-    CountLines, NullValuesCompiled, cast_columns, find_starting_point, prepare_csv_schema,
-    read_chunk,
+    CountLines, LineAlignedTranscoder, LineCounter, NullValuesCompiled, cast_columns,
+    find_starting_point, prepare_csv_schema, read_chunk, read_chunk_rejecting,
 };
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, RejectedRows,
+    count_rows_from_slice,
 };
 use polars_io::utils::compression::maybe_decompress_bytes;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
 use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
@@ -55,7 +57,9 @@ pub mod builder {
         fn reader_capabilities(&self) -> ReaderCapabilities {
             use ReaderCapabilities as RC;
 
-            if self.parse_options.comment_prefix.is_some() {
+            // Rejected rows change the row count of a chunk, so slicing and the row index are
+            // left to the multi-file reader.
+            if self.parse_options.comment_prefix.is_some() || self.rejected_rows.is_some() {
                 RC::empty()
             } else {
                 RC::PRE_SLICE
@@ -66,7 +70,7 @@ pub mod builder {
             &self,
             source: ScanSource,
             cloud_options: Option<Arc<CloudOptions>>,
-            _scan_source_idx: usize,
+            scan_source_idx: usize,
         ) -> Box<dyn FileReader> {
             let scan_source = source;
             let verbose = config::verbose();
@@ -76,6 +80,7 @@ pub mod builder {
                 scan_source,
                 cloud_options,
                 options,
+                scan_source_idx,
                 verbose,
                 cached_bytes: None,
             };
@@ -90,14 +95,18 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 /// This is used if we finish the slice but still need a row count. It signals to the workers to
 /// go into line-counting mode where they can skip parsing the chunks.
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
+/// Number of input bytes that are transcoded up front for the schema inference, this grows until
+/// the transcoded part contains enough lines.
+const TRANSCODE_HEAD_SIZE: usize = 1 << 16;
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
     row_offset: usize,
+    /// Line number of the first line of this chunk, only set when rejecting rows.
+    first_line: usize,
     morsel_seq: MorselSeq,
 }
 
@@ -106,6 +115,7 @@ struct CsvFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<CsvReadOptions>,
+    scan_source_idx: usize,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<MemSlice>,
     verbose: bool,
@@ -170,8 +180,47 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        let line_counter = CountLines::new(
+            self.options.parse_options.quote_char,
+            self.options.parse_options.escape_char,
+            self.options.parse_options.eol_char,
+        );
+
+        // Input that is not UTF-8 is transcoded piece by piece while the lines are split. Only the
+        // start of the file is transcoded up front, for the schema inference and the header.
+        let mut transcoder = LineAlignedTranscoder::new(
+            memslice.clone(),
+            self.options.parse_options.encoding,
+            self.options.parse_options.eol_char,
+        );
+        let head = match &mut transcoder {
+            None => memslice,
+            Some(transcoder) => {
+                let n_head_lines = [
+                    self.options.skip_lines,
+                    self.options.skip_rows,
+                    self.options.has_header as usize,
+                    self.options.skip_rows_after_header,
+                    infer_schema_length.unwrap_or(usize::MAX),
+                ]
+                .into_iter()
+                .fold(1, usize::saturating_add);
+
+                let mut head = vec![];
+                let mut min_len = TRANSCODE_HEAD_SIZE;
+                while line_counter.count(&head).0 < n_head_lines {
+                    let Some(piece) = transcoder.next_piece(min_len)? else {
+                        break;
+                    };
+                    head.extend_from_slice(&piece);
+                    min_len = min_len.saturating_mul(2);
+                }
+                MemSlice::from_vec(head)
+            },
+        };
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
-            &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
+            &polars_io::mmap::ReaderBytes::Owned(head.clone()),
             &self.options.parse_options,
             infer_schema_length,
             self.options.has_header,
@@ -245,12 +294,32 @@ impl FileReader for CsvFileReader {
         // Only used on empty projection, or if we need the exact row count.
         let alt_count_lines: Option<Arc<CountLinesWithComments>> =
             CountLinesWithComments::opt_new(&self.options.parse_options).map(Arc::new);
+        let path = match &self.scan_source {
+            ScanSource::Path(path) => Some(path.to_string_lossy().as_ref().into()),
+            ScanSource::File(_) | ScanSource::Buffer(_) => None,
+        };
+        // Slicing is not supported when rejecting rows, so a slice is only passed when counting
+        // rows. The rejected rows are written by the actual read.
+        let rejected_rows = self
+            .options
+            .rejected_rows
+            .as_ref()
+            .map(|options| {
+                if pre_slice.is_some() {
+                    Ok(RejectedRows::discard())
+                } else {
+                    RejectedRows::try_new(options, self.scan_source_idx)
+                }
+            })
+            .transpose()?;
         let chunk_reader = Arc::new(ChunkReader::try_new(
             self.options.clone(),
             inferred_schema.clone(),
             projection,
             row_index,
-            alt_count_lines.clone(),
+            alt_count_lines,
+            rejected_rows.clone(),
+            path,
         )?);
 
         let needs_full_row_count = n_rows_in_file_tx.is_some();
@@ -261,11 +330,9 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
-                line_counter: CountLines::new(
-                    self.options.parse_options.quote_char,
-                    self.options.parse_options.eol_char,
-                ),
+                head,
+                transcoder,
+                line_counter,
                 line_batch_tx,
                 options: self.options.clone(),
                 file_schema_len: inferred_schema.len(),
@@ -286,15 +353,12 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
-                let alt_count_lines = alt_count_lines.clone();
 
                 AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
                     while let Ok(LineBatch {
@@ -302,22 +366,22 @@ impl FileReader for CsvFileReader {
                         n_lines,
                         slice,
                         row_offset,
+                        first_line,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
-                        let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                        let (df, n_rows_in_chunk) = chunk_reader.read_chunk(
+                            &bytes,
+                            n_lines,
+                            (offset, len),
+                            row_offset,
+                            first_line,
+                        )?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -347,18 +411,14 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             slice,
                             row_offset: _,
+                            first_line: _,
                             morsel_seq: _,
                         }) = line_batch_rx.recv().await
                         {
                             assert_eq!(slice, SLICE_ENDED);
 
-                            let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
-                            } else {
-                                n_lines
-                            };
-
-                            n_rows_processed = n_rows_processed.saturating_add(n_lines);
+                            n_rows_processed = n_rows_processed
+                                .saturating_add(chunk_reader.count_rows(&bytes, n_lines)?);
                         }
                     }
 
@@ -382,6 +442,10 @@ impl FileReader for CsvFileReader {
                     row_position.saturating_add(rows_skipped)
                 };
 
+                if let Some(rejected_rows) = rejected_rows {
+                    rejected_rows.finish()?;
+                }
+
                 let row_position = IdxSize::try_from(row_position)
                     .map_err(|_| polars_err!(bigidx, ctx = "csv file", size = row_position))?;
 
@@ -416,7 +480,10 @@ impl CsvFileReader {
 }
 
 struct LineBatchSource {
-    memslice: MemSlice,
+    /// The start of the file, or all of it if it does not need to be transcoded.
+    head: MemSlice,
+    /// Transcodes the rest of the file.
+    transcoder: Option<LineAlignedTranscoder>,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -431,7 +498,8 @@ impl LineBatchSource {
     /// Returns the number of rows skipped from the start of the file according to CountLines.
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
-            memslice,
+            head,
+            mut transcoder,
             line_counter,
             mut line_batch_tx,
             options,
@@ -462,13 +530,11 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
-
         let i = {
             let parse_options = options.parse_options.as_ref();
 
             let quote_char = parse_options.quote_char;
+            let escape_char = parse_options.escape_char;
             let eol_char = parse_options.eol_char;
 
             let skip_lines = options.skip_lines;
@@ -478,8 +544,9 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &head,
                 quote_char,
+                escape_char,
                 eol_char,
                 file_schema_len,
                 skip_lines,
@@ -490,14 +557,25 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        // The lines are split from `block`, which is replaced by the next transcoded piece once
+        // it has no complete line left. `offset` is the position in `block`.
+        let mut block = head;
+        let mut offset = i;
+        let mut line_numbers = options
+            .rejected_rows
+            .is_some()
+            .then(|| LineCounter::new(options.parse_options.eol_char));
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                let file_len = match &transcoder {
+                    Some(transcoder) => transcoder.input_len(),
+                    None => block.len(),
+                };
+                std::cmp::min(file_len / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -509,11 +587,33 @@ impl LineBatchSource {
         };
 
         loop {
+            let bytes = &block[offset..];
+            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
+
+            if count == 0 {
+                if let Some(piece) = transcoder
+                    .as_mut()
+                    .map(|t| t.next_piece(chunk_size))
+                    .transpose()?
+                    .flatten()
+                {
+                    // Continue with the incomplete line in front of the next piece.
+                    if let Some(line_numbers) = &mut line_numbers {
+                        line_numbers.rebase(&block, offset);
+                    }
+                    let mut next_block = Vec::with_capacity(bytes.len() + piece.len());
+                    next_block.extend_from_slice(bytes);
+                    next_block.extend_from_slice(&piece);
+                    block = MemSlice::from_vec(next_block);
+                    offset = 0;
+                    continue;
+                }
+            }
+
             if bytes.is_empty() {
                 break;
             }
 
-            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
             let (count, position) = if count == 0 {
                 (1, bytes.len())
             } else {
@@ -521,9 +621,8 @@ impl LineBatchSource {
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
-
-            bytes = &bytes[position..];
+            let slice_start = offset;
+            offset += position;
 
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
@@ -555,16 +654,21 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
+            let bytes_this_chunk = block.slice(slice_start..slice_start + position);
 
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
+            let first_line = line_numbers
+                .as_mut()
+                .map_or(0, |l| l.line_at(&block, slice_start));
+
             let batch = LineBatch {
                 bytes: bytes_this_chunk,
                 n_lines: count,
                 slice,
                 row_offset: current_row_offset,
+                first_line,
                 morsel_seq,
             };
 
@@ -591,6 +695,8 @@ struct ChunkReader {
     row_index: Option<RowIndex>,
     // Alternate line counter when there are comments. This is used on empty projection.
     alt_count_lines: Option<Arc<CountLinesWithComments>>,
+    rejected_rows: Option<RejectedRows>,
+    path: Option<PlSmallStr>,
 }
 
 impl ChunkReader {
@@ -600,6 +706,8 @@ impl ChunkReader {
         projection: Vec<usize>,
         row_index: Option<RowIndex>,
         alt_count_lines: Option<Arc<CountLinesWithComments>>,
+        rejected_rows: Option<RejectedRows>,
+        path: Option<PlSmallStr>,
     ) -> PolarsResult<Self> {
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
         let has_categorical = prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
@@ -617,7 +725,7 @@ impl ChunkReader {
             .map(|nv| nv.compile(&reader_schema))
             .transpose()?;
 
-        let validate_utf8 = matches!(parse_options.encoding, CsvEncoding::Utf8)
+        let validate_utf8 = !matches!(parse_options.encoding, CsvEncoding::LossyUtf8)
             && reader_schema.iter_fields().any(|f| f.dtype().is_string());
 
         Ok(Self {
@@ -632,9 +740,22 @@ impl ChunkReader {
             validate_utf8,
             row_index,
             alt_count_lines,
+            rejected_rows,
+            path,
         })
     }
 
+    /// Count the rows in the chunk without parsing it, unless rows are rejected.
+    fn count_rows(&self, chunk: &[u8], n_lines: usize) -> PolarsResult<usize> {
+        if self.rejected_rows.is_some() {
+            Ok(self.read_chunk(chunk, n_lines, (0, 0), 0, 0)?.1)
+        } else if let Some(v) = &self.alt_count_lines {
+            v.count_lines(chunk)
+        } else {
+            Ok(n_lines)
+        }
+    }
+
     /// The 2nd return value indicates how many rows exist in the chunk.
     fn read_chunk(
         &self,
@@ -643,7 +764,34 @@ impl ChunkReader {
         n_lines: usize,
         slice: (usize, usize),
         chunk_row_offset: usize,
+        first_line: usize,
     ) -> PolarsResult<(DataFrame, usize)> {
+        if let Some(rejected_rows) = &self.rejected_rows {
+            debug_assert!(self.row_index.is_none());
+
+            // Slicing is not supported in this mode, so a slice is only passed when counting rows.
+            // Rejected rows must not be counted, but they are recorded by the actual read.
+            let rejected_rows = if slice == NO_SLICE {
+                rejected_rows.clone()
+            } else {
+                RejectedRows::discard()
+            };
+            let df = read_chunk_rejecting(
+                chunk,
+                &self.parse_options,
+                &self.reader_schema,
+                &self.projection,
+                self.null_values.as_ref(),
+                &self.fields_to_cast,
+                &rejected_rows,
+                self.path.as_ref(),
+                first_line,
+            )?;
+            let height = df.height();
+            let df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
+            return Ok((df, height));
+        }
+
         if self.validate_utf8 && !validate_utf8(chunk) {
             polars_bail!(ComputeError: "invalid utf-8 sequence")
         }
@@ -704,6 +852,7 @@ impl ChunkReader {
 
 struct CountLinesWithComments {
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     comment_prefix: CommentPrefix,
 }
@@ -715,6 +864,7 @@ impl CountLinesWithComments {
             .clone()
             .map(|comment_prefix| CountLinesWithComments {
                 quote_char: parse_options.quote_char,
+                escape_char: parse_options.escape_char,
                 eol_char: parse_options.eol_char,
                 comment_prefix,
             })
@@ -724,6 +874,7 @@ impl CountLinesWithComments {
         count_rows_from_slice(
             bytes,
             self.quote_char,
+            self.escape_char,
             Some(&self.comment_prefix),
             self.eol_char,
             false, // has_header
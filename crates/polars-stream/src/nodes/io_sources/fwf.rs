@@ -0,0 +1,422 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::fwf::{FwfChunk, FwfChunkParser, FwfReadOptions, split_chunks};
+use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::fwf::FwfReadOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::FwfFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct FwfReaderBuilder {
+        pub options: FwfReadOptions,
+    }
+
+    impl FileReaderBuilder for FwfReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "fwf"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = FwfFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct FwfFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: FwfReadOptions,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    /// Decompressed file contents.
+    memslice: MemSlice,
+    chunks: Arc<[FwfChunk]>,
+    file_schema: SchemaRef,
+    n_rows_in_file: IdxSize,
+}
+
+fn get_chunk_size() -> usize {
+    std::env::var("POLARS_STREAMING_FWF_SOURCE_CHUNK_SIZE")
+        .map_or(1 << 22, |v| {
+            v.parse::<usize>()
+                .expect("POLARS_STREAMING_FWF_SOURCE_CHUNK_SIZE does not contain valid size")
+        })
+        .max(1)
+}
+
+#[async_trait]
+impl FileReader for FwfFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let file_schema = Arc::new(self.options.schema()?);
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        let memslice = {
+            let mut out = vec![];
+            maybe_decompress_bytes(&memslice, &mut out)?;
+
+            if out.is_empty() {
+                memslice
+            } else {
+                MemSlice::from_vec(out)
+            }
+        };
+
+        // The rows are counted up front, so that the walker knows which chunks overlap with the
+        // slice and the row index of every chunk.
+        let chunks: Arc<[FwfChunk]> =
+            split_chunks(&memslice, &self.options, get_chunk_size()).into();
+        let n_rows: usize = chunks.iter().map(|chunk| chunk.n_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "fwf file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            chunks,
+            file_schema,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            chunks,
+            file_schema,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let normalized_pre_slice = pre_slice_arg.clone().map(|pre_slice| {
+            pre_slice.restrict_to_bounds(usize::try_from(n_rows_in_file).unwrap())
+        });
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(file_schema.clone());
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[FwfFileReader]: early return: \
+                    n_rows_in_file: {} \
+                    pre_slice: {:?} \
+                    resolved_pre_slice: {:?} \
+                    ",
+                    n_rows_in_file, pre_slice_arg, normalized_pre_slice
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // Only the byte ranges of the projected columns are parsed.
+        let projection: Vec<usize> = file_schema
+            .iter_names()
+            .enumerate()
+            .filter(|(_, name)| projected_schema.contains(name))
+            .map(|(i, _)| i)
+            .collect();
+        let n_projected = projection.len();
+        let parser = Arc::new(FwfChunkParser::new(&self.options, Some(projection))?);
+
+        if verbose {
+            eprintln!(
+                "[FwfFileReader]: \
+                project: {} / {}, \
+                chunks: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                n_projected,
+                file_schema.len(),
+                chunks.len(),
+                pre_slice_arg,
+                normalized_pre_slice,
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct ChunkMessage {
+            /// Row index of the first row of the chunk.
+            row_idx_offset: IdxSize,
+            /// Slice relative to the first row of the chunk.
+            slice: Range<usize>,
+            chunk: usize,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut chunk_tx, chunk_rxs) =
+            distributor_channel::<ChunkMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Distributor task.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Parses the projected columns of the sliced rows of a chunk, which is then split into
+        // morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(chunk_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let chunks = chunks.clone();
+                let parser = parser.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let ChunkMessage {
+                            row_idx_offset,
+                            slice,
+                            chunk,
+                            morsel_seq_base,
+                        } = m;
+
+                        let mut df = if n_projected == 0 {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let bytes = &memslice[chunks[chunk].range.clone()];
+                            parser.parse(bytes, slice.start, slice.len())?
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = IdxSize::try_from(slice.start)
+                                .ok()
+                                .and_then(|start| row_idx_offset.checked_add(start))
+                                .ok_or_else(
+                                    || polars_err!(ComputeError: "fwf row index overflowed"),
+                                )?;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Sends the chunks that overlap with the slice to the decoder tasks.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+
+            let mut morsel_seq: u64 = 0;
+            let mut rows_before_chunk = 0;
+
+            for (chunk, FwfChunk { n_rows, .. }) in chunks.iter().enumerate() {
+                let chunk_start_row = rows_before_chunk;
+                rows_before_chunk += n_rows;
+
+                if chunk_start_row >= slice.end {
+                    break;
+                }
+                if rows_before_chunk <= slice.start {
+                    continue;
+                }
+
+                let chunk_slice = slice.start.saturating_sub(chunk_start_row)
+                    ..(slice.end - chunk_start_row).min(*n_rows);
+                let chunk_slice_len = chunk_slice.len();
+
+                let message = ChunkMessage {
+                    // Cannot overflow, the total row count of the file fits in IdxSize.
+                    row_idx_offset: row_idx_base + chunk_start_row as IdxSize,
+                    slice: chunk_slice,
+                    chunk,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if chunk_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                morsel_seq += chunk_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().file_schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
@@ -0,0 +1,389 @@
+use std::io::{BufReader, Read};
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::io::ipc::read::{self, StreamState};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::BeginReadArgs;
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use crate::async_executor::{JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::connector;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::IpcStreamFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    /// The schema is resolved during IR conversion, so the options are not needed here.
+    #[derive(Debug)]
+    pub struct IpcStreamReaderBuilder;
+
+    impl FileReaderBuilder for IpcStreamReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "ipc_stream"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            // A stream has no footer, so the rows are only known after reading it to the end.
+            // Negative slices are left to the multi-file reader.
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = IpcStreamFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+/// Reads a single Arrow IPC stream from start to end.
+///
+/// The source is opened again for every read and is read on a blocking thread. Sources that can
+/// only be read once, such as pipes, therefore only support queries that need a single pass.
+struct IpcStreamFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+}
+
+fn get_max_morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_IPC_STREAM_SOURCE_MAX_MORSEL_SIZE")
+        .map_or_else(
+            |_| get_ideal_morsel_size(),
+            |v| {
+                v.parse::<usize>().expect(
+                    "POLARS_STREAMING_IPC_STREAM_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
+                )
+            },
+        )
+        .max(1)
+}
+
+fn to_idx_size(n_rows: usize) -> PolarsResult<IdxSize> {
+    IdxSize::try_from(n_rows).map_err(|_| polars_err!(bigidx, ctx = "ipc stream", size = n_rows))
+}
+
+/// Record batches of a stream, decoded with a projection.
+enum BatchReader<R: Read> {
+    Decode(Box<read::StreamReader<R>>),
+    /// Only the lengths of the batches are read if no columns are projected.
+    Lengths {
+        reader: R,
+        message_buffer: Vec<u8>,
+    },
+}
+
+impl<R: Read> BatchReader<R> {
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        match self {
+            Self::Decode(reader) => match reader.next().transpose()? {
+                // A stream that ends without the end-of-stream marker is also finished.
+                None | Some(StreamState::Waiting) => Ok(None),
+                Some(StreamState::Some(batch)) => Ok(Some(DataFrame::from(batch))),
+            },
+            Self::Lengths {
+                reader,
+                message_buffer,
+            } => Ok(read::skip_next_record_batch(reader, message_buffer)?
+                .map(DataFrame::empty_with_height)),
+        }
+    }
+
+    /// Count the rows of the remaining batches without decoding them.
+    fn count_remaining_rows(self) -> PolarsResult<usize> {
+        let mut reader = match self {
+            Self::Decode(reader) => reader.into_inner(),
+            Self::Lengths { reader, .. } => reader,
+        };
+        Ok(read::get_stream_row_count(&mut reader)? as usize)
+    }
+}
+
+struct StreamReadArgs {
+    scan_source: ScanSource,
+    projected_schema: SchemaRef,
+    row_index: Option<RowIndex>,
+    slice: Range<usize>,
+    needs_n_rows_in_file: bool,
+    needs_row_position: bool,
+    file_schema_tx: Option<connector::Sender<SchemaRef>>,
+    frame_tx: tokio::sync::mpsc::Sender<DataFrame>,
+}
+
+/// Reads the record batches of a stream that overlap with the slice and sends them to
+/// `frame_tx`. Returns the number of rows that were read or counted.
+///
+/// This does blocking IO and must run on a blocking thread.
+fn read_stream(args: StreamReadArgs) -> PolarsResult<usize> {
+    let StreamReadArgs {
+        scan_source,
+        projected_schema,
+        row_index,
+        slice,
+        needs_n_rows_in_file,
+        needs_row_position,
+        file_schema_tx,
+        frame_tx,
+    } = args;
+
+    let mut reader = BufReader::new(
+        scan_source
+            .as_scan_source_ref()
+            .to_sequential_reader(scan_source.run_async())?,
+    );
+    let metadata = read::read_stream_metadata(&mut reader)?;
+
+    if let Some(mut file_schema_tx) = file_schema_tx {
+        _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(&metadata.schema)));
+    }
+
+    let projection = metadata
+        .schema
+        .iter_names()
+        .enumerate()
+        .filter(|(_, name)| projected_schema.contains(name))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let mut batches = if projection.is_empty() {
+        BatchReader::Lengths {
+            reader,
+            message_buffer: Vec::new(),
+        }
+    } else {
+        BatchReader::Decode(Box::new(read::StreamReader::new(
+            reader,
+            metadata,
+            Some(projection),
+        )))
+    };
+
+    let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+    let mut n_rows_seen: usize = 0;
+
+    // Dictionaries can be sent in any message before the batch that uses them, so the batches
+    // before the slice are decoded as well.
+    while n_rows_seen < slice.end {
+        let Some(df) = batches.next_batch()? else {
+            return Ok(n_rows_seen);
+        };
+        let batch_start = n_rows_seen;
+        n_rows_seen += df.height();
+
+        if n_rows_seen <= slice.start {
+            continue;
+        }
+
+        let batch_slice =
+            slice.start.saturating_sub(batch_start)..(slice.end - batch_start).min(df.height());
+        let mut df = df.slice(batch_slice.start as i64, batch_slice.len());
+
+        if let Some(RowIndex { name, offset: _ }) = &row_index {
+            let offset = to_idx_size(batch_start + batch_slice.start)
+                .ok()
+                .and_then(|offset| row_idx_base.checked_add(offset))
+                .ok_or_else(|| polars_err!(ComputeError: "ipc stream row index overflowed"))?;
+            df = df.with_row_index(name.clone(), Some(offset))?;
+        }
+
+        if frame_tx.blocking_send(df).is_err() {
+            // The receiver has broken off, meaning no further input will be needed.
+            break;
+        }
+    }
+
+    drop(frame_tx);
+
+    // Count the remaining rows without decoding them if the row count is needed.
+    if needs_n_rows_in_file || (needs_row_position && n_rows_seen < slice.end) {
+        n_rows_seen += batches.count_remaining_rows()?;
+    }
+
+    Ok(n_rows_seen)
+}
+
+#[async_trait]
+impl FileReader for IpcStreamFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        // The stream itself is only opened when it is read, IR resolution only reads the schema
+        // of the first source.
+        if let ScanSourceRef::Path(p) = self.scan_source.as_scan_source_ref() {
+            if self.scan_source.run_async() {
+                polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(p.to_str().unwrap())],
+                    self.cloud_options.as_deref(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = match pre_slice_arg.clone() {
+            None => 0..usize::MAX,
+            Some(Slice::Positive { offset, len }) => offset..offset.saturating_add(len),
+            // The builder does not indicate support for negative slices.
+            Some(Slice::Negative { .. }) => unreachable!(),
+        };
+
+        let needs_n_rows_in_file = n_rows_in_file_tx.is_some();
+        let needs_row_position = row_position_on_end_tx.is_some();
+
+        if slice.is_empty()
+            && file_schema_tx.is_none()
+            && !needs_n_rows_in_file
+            && !needs_row_position
+        {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[IpcStreamFileReader]: early return: pre_slice: {:?}",
+                    pre_slice_arg
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        if verbose {
+            eprintln!(
+                "[IpcStreamFileReader]: \
+                project: {}, \
+                pre_slice: {:?}, \
+                row_index: {:?} \
+                ",
+                projected_schema.len(),
+                pre_slice_arg,
+                row_index,
+            )
+        }
+
+        let max_morsel_size = get_max_morsel_size();
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Reader thread -> Distributor task. The bound limits the number of decoded batches that
+        // are kept in memory.
+        let (frame_tx, mut frame_rx) = tokio::sync::mpsc::channel::<DataFrame>(2);
+
+        // Reader thread.
+        //
+        // Stream messages have to be read in order, so the stream is read and decoded on a single
+        // blocking thread.
+        let read_args = StreamReadArgs {
+            scan_source: self.scan_source.clone(),
+            projected_schema,
+            row_index,
+            slice: slice.clone(),
+            needs_n_rows_in_file,
+            needs_row_position,
+            file_schema_tx,
+            frame_tx,
+        };
+        let read_handle =
+            polars_io::pl_async::get_runtime().spawn_blocking(move || read_stream(read_args));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                // Distributor task.
+                //
+                // Splits the decoded batches into morsels.
+                // Note: We don't use this (it is handled by the bridge). But morsels require a
+                // source token.
+                let source_token = SourceToken::new();
+                let mut morsel_seq: u64 = 0;
+
+                'recv: while let Some(df) = frame_rx.recv().await {
+                    for i in 0..df.height().div_ceil(max_morsel_size) {
+                        let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                        let morsel = Morsel::new(
+                            morsel_df,
+                            MorselSeq::new(morsel_seq),
+                            source_token.clone(),
+                        );
+                        morsel_seq += 1;
+
+                        if morsel_sender.send_morsel(morsel).await.is_err() {
+                            break 'recv;
+                        }
+                    }
+                }
+
+                drop(frame_rx);
+
+                let n_rows_seen = read_handle
+                    .await
+                    .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))?;
+
+                if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                    _ = n_rows_in_file_tx.try_send(to_idx_size(n_rows_seen)?);
+                }
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx.try_send(to_idx_size(n_rows_seen.min(slice.end))?);
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+}
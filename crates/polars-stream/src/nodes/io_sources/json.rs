@@ -0,0 +1,407 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::json::array::{JsonArrayChunk, JsonArrayChunkParser, JsonArraySplitter};
+use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::JsonFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    /// The schema is resolved during IR conversion, so the options are not needed here.
+    #[derive(Debug)]
+    pub struct JsonReaderBuilder;
+
+    impl FileReaderBuilder for JsonReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "json"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            // The records are only counted while walking the array, so negative slices are left
+            // to the multi-file reader.
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = JsonFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct JsonFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    /// Decompressed file contents.
+    memslice: MemSlice,
+    /// Cached after the records are counted.
+    n_rows_in_file: Option<IdxSize>,
+}
+
+fn get_chunk_size() -> usize {
+    std::env::var("POLARS_STREAMING_JSON_SOURCE_CHUNK_SIZE")
+        .map_or(1 << 22, |v| {
+            v.parse::<usize>()
+                .expect("POLARS_STREAMING_JSON_SOURCE_CHUNK_SIZE does not contain valid size")
+        })
+        .max(1)
+}
+
+fn to_idx_size(n_rows: usize) -> PolarsResult<IdxSize> {
+    IdxSize::try_from(n_rows).map_err(|_| polars_err!(bigidx, ctx = "json file", size = n_rows))
+}
+
+#[async_trait]
+impl FileReader for JsonFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        let memslice = {
+            let mut out = vec![];
+            maybe_decompress_bytes(&memslice, &mut out)?;
+
+            if out.is_empty() {
+                memslice
+            } else {
+                MemSlice::from_vec(out)
+            }
+        };
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            n_rows_in_file: None,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let InitializedState {
+            memslice,
+            n_rows_in_file: _,
+        } = self.init_data.clone().unwrap();
+
+        // Fields that are missing in a record are null, so the projected schema is used as the
+        // file schema and only the projected fields are parsed.
+        let parser = Arc::new(JsonArrayChunkParser::new(projected_schema.clone()));
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(projected_schema.clone());
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = match pre_slice_arg.clone() {
+            None => 0..usize::MAX,
+            Some(Slice::Positive { offset, len }) => offset..offset.saturating_add(len),
+            // The builder does not indicate support for negative slices.
+            Some(Slice::Negative { .. }) => unreachable!(),
+        };
+
+        let needs_n_rows_in_file = n_rows_in_file_tx.is_some();
+        let needs_row_position = row_position_on_end_tx.is_some();
+
+        if slice.is_empty() && !needs_n_rows_in_file && !needs_row_position {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[JsonFileReader]: early return: pre_slice: {:?}",
+                    pre_slice_arg
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        if verbose {
+            eprintln!(
+                "[JsonFileReader]: \
+                project: {}, \
+                pre_slice: {:?}, \
+                row_index: {:?} \
+                ",
+                projected_schema.len(),
+                pre_slice_arg,
+                row_index,
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct ChunkMessage {
+            /// Row index of the first row of the chunk.
+            row_idx_offset: IdxSize,
+            /// Records of the chunk to parse.
+            slice: Range<usize>,
+            chunk: JsonArrayChunk,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut chunk_tx, chunk_rxs) =
+            distributor_channel::<ChunkMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Distributor task.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Parses the sliced records of a chunk, which is then split into morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(chunk_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let parser = parser.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let ChunkMessage {
+                            row_idx_offset,
+                            slice,
+                            chunk,
+                            morsel_seq_base,
+                        } = m;
+
+                        let mut df =
+                            parser.parse(&memslice[chunk.range], slice.start, slice.len())?;
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            df = df.with_row_index(name.clone(), Some(row_idx_offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                return PolarsResult::Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Splits the array into chunks of whole records and sends the chunks that overlap with
+        // the slice to the decoder tasks. Returns the number of records it has walked over.
+        let walker_slice = slice.clone();
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+            let chunk_size = get_chunk_size();
+
+            let mut splitter = JsonArraySplitter::new(&memslice)?;
+            let mut morsel_seq: u64 = 0;
+            let mut n_records_seen: usize = 0;
+
+            while n_records_seen < walker_slice.end {
+                let Some(chunk) = splitter.next_chunk(chunk_size)? else {
+                    break;
+                };
+                let chunk_start = n_records_seen;
+                n_records_seen += chunk.n_records;
+
+                if n_records_seen <= walker_slice.start {
+                    continue;
+                }
+
+                let chunk_slice = walker_slice.start.saturating_sub(chunk_start)
+                    ..(walker_slice.end - chunk_start).min(chunk.n_records);
+                let chunk_slice_len = chunk_slice.len();
+
+                let row_idx_offset = to_idx_size(chunk_start + chunk_slice.start)
+                    .ok()
+                    .and_then(|offset| row_idx_base.checked_add(offset))
+                    .ok_or_else(|| polars_err!(ComputeError: "json row index overflowed"))?;
+
+                let message = ChunkMessage {
+                    row_idx_offset,
+                    slice: chunk_slice,
+                    chunk,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if chunk_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                morsel_seq += chunk_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            drop(chunk_tx);
+
+            // Count the remaining records without sending them if the row count is needed.
+            if needs_n_rows_in_file || (needs_row_position && n_records_seen < walker_slice.end) {
+                while splitter.next_record()?.is_some() {
+                    n_records_seen += 1;
+                }
+            }
+
+            PolarsResult::Ok(n_records_seen)
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                let n_records_seen = walker_handle.await?;
+
+                if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                    _ = n_rows_in_file_tx.try_send(to_idx_size(n_records_seen)?);
+                }
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx
+                        .try_send(to_idx_size(n_records_seen.min(slice.end))?);
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        let init_data = self.init_data.as_mut().unwrap();
+
+        if let Some(n_rows_in_file) = init_data.n_rows_in_file {
+            return Ok(n_rows_in_file);
+        }
+
+        // Only walk the array, without parsing the records.
+        let n_rows_in_file = to_idx_size(polars_io::json::array::count_rows(&init_data.memslice)?)?;
+        init_data.n_rows_in_file = Some(n_rows_in_file);
+
+        Ok(n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.n_rows_in_file().await?,
+            pre_slice,
+        ))
+    }
+}
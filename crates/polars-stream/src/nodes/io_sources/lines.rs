@@ -0,0 +1,500 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::lines::{
+    LinesChunk, LinesChunkParser, LinesReadOptions, UnmatchedLines, split_chunks,
+};
+use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::lines::{LinesReadOptions, UnmatchedLines};
+    use polars_plan::dsl::ScanSource;
+
+    use super::LinesFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct LinesReaderBuilder {
+        pub options: LinesReadOptions,
+    }
+
+    impl FileReaderBuilder for LinesReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "lines"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            // Dropped lines change the row count of a chunk, so slicing and the row index are
+            // left to the multi-file reader.
+            match self.options.unmatched {
+                UnmatchedLines::Drop => RC::empty(),
+                UnmatchedLines::Reject { .. } => {
+                    RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+                },
+            }
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = LinesFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct LinesFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: LinesReadOptions,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    /// Decompressed file contents.
+    memslice: MemSlice,
+    chunks: Arc<[LinesChunk]>,
+    file_schema: SchemaRef,
+    /// Known up front if every line is a row, otherwise cached after it is counted.
+    n_rows_in_file: Option<IdxSize>,
+}
+
+fn get_chunk_size() -> usize {
+    std::env::var("POLARS_STREAMING_LINES_SOURCE_CHUNK_SIZE")
+        .map_or(1 << 22, |v| {
+            v.parse::<usize>()
+                .expect("POLARS_STREAMING_LINES_SOURCE_CHUNK_SIZE does not contain valid size")
+        })
+        .max(1)
+}
+
+fn to_idx_size(n_rows: usize) -> PolarsResult<IdxSize> {
+    IdxSize::try_from(n_rows).map_err(|_| polars_err!(bigidx, ctx = "lines file", size = n_rows))
+}
+
+#[async_trait]
+impl FileReader for LinesFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let file_schema = Arc::new(self.options.schema()?);
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        let memslice = {
+            let mut out = vec![];
+            maybe_decompress_bytes(&memslice, &mut out)?;
+
+            if out.is_empty() {
+                memslice
+            } else {
+                MemSlice::from_vec(out)
+            }
+        };
+
+        // The lines are counted up front, so that the walker knows which chunks overlap with the
+        // slice and the row index of every chunk when every line is a row.
+        let chunks: Arc<[LinesChunk]> =
+            split_chunks(&memslice, self.options.eol_char, get_chunk_size()).into();
+        let n_rows_in_file = match self.options.unmatched {
+            UnmatchedLines::Drop => None,
+            UnmatchedLines::Reject { .. } => {
+                Some(to_idx_size(chunks.iter().map(|chunk| chunk.n_lines).sum())?)
+            },
+        };
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            chunks,
+            file_schema,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    mut n_rows_in_file_tx,
+                    mut row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let InitializedState {
+            memslice,
+            chunks,
+            file_schema,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        // Only the projected capture groups are parsed.
+        let projection: Vec<usize> = file_schema
+            .iter_names()
+            .enumerate()
+            .filter(|(_, name)| projected_schema.contains(name))
+            .map(|(i, _)| i)
+            .collect();
+        let n_projected = projection.len();
+        let parser = Arc::new(LinesChunkParser::new(&self.options, Some(projection))?);
+        let eol_char = self.options.eol_char;
+
+        // Slicing and the row index count lines, which are only rows if no lines are dropped. The
+        // builder does not indicate support for them otherwise.
+        assert!(!parser.drops_unmatched() || (pre_slice_arg.is_none() && row_index.is_none()));
+
+        let normalized_pre_slice = pre_slice_arg.clone().map(|pre_slice| {
+            pre_slice.restrict_to_bounds(usize::try_from(n_rows_in_file.unwrap()).unwrap())
+        });
+
+        if let Some(n_rows_in_file) = n_rows_in_file {
+            if let Some(n_rows_in_file_tx) = n_rows_in_file_tx.as_mut() {
+                _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+            }
+
+            if let Some(row_position_on_end_tx) = row_position_on_end_tx.as_mut() {
+                _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                    n_rows_in_file,
+                    normalized_pre_slice.clone(),
+                ));
+            }
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(file_schema.clone());
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[LinesFileReader]: early return: \
+                    n_rows_in_file: {:?} \
+                    pre_slice: {:?} \
+                    resolved_pre_slice: {:?} \
+                    ",
+                    n_rows_in_file, pre_slice_arg, normalized_pre_slice
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Rows are only counted while reading if the row count isn't known up front.
+        let needs_row_count = n_rows_in_file.is_none()
+            && (n_rows_in_file_tx.is_some() || row_position_on_end_tx.is_some());
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        if verbose {
+            eprintln!(
+                "[LinesFileReader]: \
+                project: {} / {}, \
+                chunks: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                n_projected,
+                file_schema.len(),
+                chunks.len(),
+                pre_slice_arg,
+                normalized_pre_slice,
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct ChunkMessage {
+            /// Row index of the first row of the chunk.
+            row_idx_offset: IdxSize,
+            /// Lines of the chunk to parse.
+            slice: Range<usize>,
+            chunk: usize,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut chunk_tx, chunk_rxs) =
+            distributor_channel::<ChunkMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Distributor task.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Matches the sliced lines of a chunk and parses the projected capture groups, which is
+        // then split into morsels. Returns the number of rows of the chunks it has seen.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(chunk_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let chunks = chunks.clone();
+                let parser = parser.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    let mut n_rows_seen: usize = 0;
+                    let mut output_closed = false;
+
+                    while let Ok(m) = rx.recv().await {
+                        let ChunkMessage {
+                            row_idx_offset,
+                            slice,
+                            chunk,
+                            morsel_seq_base,
+                        } = m;
+
+                        let bytes = &memslice[chunks[chunk].range.clone()];
+
+                        // The output has closed, but the row count is still needed.
+                        if output_closed {
+                            n_rows_seen += parser.count_rows(bytes, eol_char);
+                            continue;
+                        }
+
+                        let mut df = parser.parse(bytes, eol_char, slice.start, slice.len())?;
+                        n_rows_seen += df.height();
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = IdxSize::try_from(slice.start)
+                                .ok()
+                                .and_then(|start| row_idx_offset.checked_add(start))
+                                .ok_or_else(
+                                    || polars_err!(ComputeError: "lines row index overflowed"),
+                                )?;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                output_closed = true;
+                                break;
+                            }
+                        }
+
+                        if output_closed && !needs_row_count {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(n_rows_seen)
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Sends the chunks that overlap with the slice to the decoder tasks.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+
+            let mut morsel_seq: u64 = 0;
+            let mut lines_before_chunk = 0;
+
+            for (chunk, LinesChunk { n_lines, .. }) in chunks.iter().enumerate() {
+                let chunk_start_line = lines_before_chunk;
+                lines_before_chunk += n_lines;
+
+                if chunk_start_line >= slice.end {
+                    break;
+                }
+                if lines_before_chunk <= slice.start {
+                    continue;
+                }
+
+                let chunk_slice = slice.start.saturating_sub(chunk_start_line)
+                    ..(slice.end - chunk_start_line).min(*n_lines);
+                let chunk_slice_len = chunk_slice.len();
+
+                let message = ChunkMessage {
+                    // Only used if every line is a row, in which case this cannot overflow as the
+                    // row count of the file fits in IdxSize.
+                    row_idx_offset: row_idx_base.wrapping_add(chunk_start_line as IdxSize),
+                    slice: chunk_slice,
+                    chunk,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if chunk_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                // The number of lines is an upper bound for the number of rows, so the sequence
+                // numbers of the chunks don't overlap if lines are dropped.
+                morsel_seq += chunk_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                let mut n_rows_seen: usize = 0;
+                for handle in decoder_handles {
+                    n_rows_seen += handle.await?;
+                }
+
+                walker_handle.await?;
+
+                if needs_row_count {
+                    let n_rows_in_file = to_idx_size(n_rows_seen)?;
+
+                    if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                        _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+                    }
+
+                    if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                        _ = row_position_on_end_tx.try_send(n_rows_in_file);
+                    }
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().file_schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        let init_data = self.init_data.as_mut().unwrap();
+
+        if let Some(n_rows_in_file) = init_data.n_rows_in_file {
+            return Ok(n_rows_in_file);
+        }
+
+        // Only match the lines, without parsing the captures.
+        let parser = LinesChunkParser::new(&self.options, Some(vec![]))?;
+        let n_rows: usize = init_data
+            .chunks
+            .iter()
+            .map(|chunk| {
+                parser.count_rows(
+                    &init_data.memslice[chunk.range.clone()],
+                    self.options.eol_char,
+                )
+            })
+            .sum();
+        let n_rows_in_file = to_idx_size(n_rows)?;
+        init_data.n_rows_in_file = Some(n_rows_in_file);
+
+        Ok(n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.n_rows_in_file().await?,
+            pre_slice,
+        ))
+    }
+}
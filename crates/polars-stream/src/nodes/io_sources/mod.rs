@@ -1,11 +1,23 @@
 pub mod multi_file_reader;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(feature = "ipc")]
 pub mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub mod ipc_stream;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "lines")]
+pub mod lines;
 #[cfg(feature = "json")]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
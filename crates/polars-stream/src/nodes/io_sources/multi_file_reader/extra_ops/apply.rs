@@ -1,9 +1,10 @@
 //! Implementation of applying the operations during execution.
 use std::sync::Arc;
 
+use arrow::bitmap::MutableBitmap;
 use polars_core::frame::DataFrame;
 use polars_core::frame::column::ScalarColumn;
-use polars_core::prelude::{AnyValue, Column, DataType, IntoColumn};
+use polars_core::prelude::{AnyValue, BooleanChunked, Column, DataType, IntoColumn};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
@@ -12,11 +13,12 @@ use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::ScanSource;
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
-use super::ExtraOperations;
 use super::cast_columns::CastColumns;
 use super::reorder_columns::ReorderColumns;
+use super::{ExtraOperations, n_deleted_rows_before};
 use crate::nodes::io_sources::multi_file_reader::extra_ops::missing_columns::initialize_missing_columns_policy;
 
 /// Apply extra operations onto morsels originating from a reader. This should be initialized
@@ -38,6 +40,7 @@ pub enum ApplyExtraOps {
 
     Initialized {
         // Note: These fields are ordered according to when they (should be) applied.
+        deleted_rows: Option<Arc<[IdxSize]>>,
         row_index: Option<RowIndex>,
         pre_slice: Option<Slice>,
         cast_columns: Option<CastColumns>,
@@ -67,6 +70,7 @@ impl ApplyExtraOps {
                 projected_file_schema,
                 extra_ops:
                     ExtraOperations {
+                        deleted_rows,
                         row_index,
                         pre_slice,
                         cast_columns_policy,
@@ -131,6 +135,7 @@ impl ApplyExtraOps {
                 debug_assert_eq!(extra_columns.len(), n_expected_extra_columns);
 
                 let mut slf = Self::Initialized {
+                    deleted_rows,
                     row_index,
                     pre_slice,
                     cast_columns,
@@ -172,6 +177,7 @@ impl ApplyExtraOps {
                 // can see the `Noop` and avoid running through an extra distributor pipeline.
                 let slf = match slf {
                     Initialized {
+                        deleted_rows: None,
                         row_index: None,
                         pre_slice: None,
                         cast_columns: None,
@@ -190,14 +196,17 @@ impl ApplyExtraOps {
         }
     }
 
+    /// `current_row_position` is the position in the file of the first row of `df`.
+    ///
     /// # Panics
     /// Panics if `self` is `Uninitialized`
     pub fn apply_to_df(
         &self,
         df: &mut DataFrame,
-        current_row_position: IdxSize,
+        mut current_row_position: IdxSize,
     ) -> PolarsResult<()> {
         let Self::Initialized {
+            deleted_rows,
             row_index,
             pre_slice,
             cast_columns,
@@ -217,6 +226,25 @@ impl ApplyExtraOps {
             unreachable!();
         };
 
+        if let Some(deleted_rows) = deleted_rows {
+            let start = n_deleted_rows_before(deleted_rows, current_row_position) as usize;
+            let end_position = current_row_position
+                .saturating_add(IdxSize::try_from(df.height()).unwrap_or(IdxSize::MAX));
+            let end = n_deleted_rows_before(deleted_rows, end_position) as usize;
+
+            if start < end {
+                let mut mask = MutableBitmap::from_len_set(df.height());
+                for &i in &deleted_rows[start..end] {
+                    mask.set((i - current_row_position) as usize, false);
+                }
+                let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask.freeze());
+                *df = df._filter_seq(&mask)?;
+            }
+
+            // The remaining operations apply to the positions after the deletes.
+            current_row_position -= start as IdxSize;
+        }
+
         if let Some(ri) = row_index {
             unsafe {
                 df.with_column_unchecked(Column::new_row_index(
@@ -3,9 +3,13 @@ use polars_core::prelude::DataType;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail};
 use polars_plan::dsl::CastColumnsPolicy;
+use polars_utils::pl_str::PlSmallStr;
 
 #[derive(Debug)]
-pub struct CastColumns {}
+pub struct CastColumns {
+    /// Columns to cast, with the datatype to cast them to.
+    casts: Vec<(PlSmallStr, DataType)>,
+}
 
 impl CastColumns {
     pub fn try_init_from_policy(
@@ -45,11 +49,44 @@ impl CastColumns {
 
                 Ok(None)
             },
+
+            CastColumnsPolicy::AllowWidening => {
+                let mut casts = vec![];
+
+                for (name, dtype) in incoming_schema_iter {
+                    let Some(target_dtype) = target_schema.get(name) else {
+                        panic!("impl error: column '{}' should exist in casting map", name)
+                    };
+
+                    if dtype == target_dtype {
+                        continue;
+                    }
+
+                    if !dtype.is_lossless_widening_to(target_dtype) {
+                        polars_bail!(
+                            SchemaMismatch:
+                            "data type mismatch for column {}: expected: {}, found: {}, which cannot be widened to the expected type",
+                            name, target_dtype, dtype
+                        )
+                    }
+
+                    casts.push((PlSmallStr::from_str(name), target_dtype.clone()));
+                }
+
+                Ok((!casts.is_empty()).then_some(Self { casts }))
+            },
         }
     }
 
-    pub fn apply_cast(&self, _df: &mut DataFrame) -> PolarsResult<()> {
-        unimplemented!()
-        // df.clear_schema(); // remember to do this if cast was applied
+    pub fn apply_cast(&self, df: &mut DataFrame) -> PolarsResult<()> {
+        for (name, dtype) in &self.casts {
+            let idx = df.try_get_column_index(name)?;
+            let columns = unsafe { df.get_columns_mut() };
+            columns[idx] = columns[idx].strict_cast(dtype)?;
+        }
+
+        df.clear_schema();
+
+        Ok(())
     }
 }
@@ -4,11 +4,14 @@ pub mod cast_columns;
 pub mod missing_columns;
 pub mod reorder_columns;
 
+use std::sync::Arc;
+
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail};
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy};
+use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
@@ -20,6 +23,8 @@ use polars_utils::slice_enum::Slice;
 #[derive(Debug, Default, Clone)]
 pub struct ExtraOperations {
     // Note: These fields are ordered according to when they (should be) applied.
+    /// Sorted positions of the rows that are deleted from the file.
+    pub deleted_rows: Option<Arc<[IdxSize]>>,
     pub row_index: Option<RowIndex>,
     pub pre_slice: Option<Slice>,
     pub cast_columns_policy: CastColumnsPolicy,
@@ -34,6 +39,11 @@ impl ExtraOperations {
     }
 }
 
+/// Number of deleted rows that are before `row_position` in the file.
+pub fn n_deleted_rows_before(deleted_rows: &[IdxSize], row_position: IdxSize) -> IdxSize {
+    IdxSize::try_from(deleted_rows.partition_point(|&i| i < row_position)).unwrap()
+}
+
 pub fn apply_extra_columns_policy(
     policy: &ExtraColumnsPolicy,
     target_schema: SchemaRef,
@@ -8,6 +8,7 @@ use polars_utils::slice_enum::Slice;
 
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::nodes::io_sources::multi_file_reader::MultiFileReaderConfig;
+use crate::nodes::io_sources::multi_file_reader::extra_ops::n_deleted_rows_before;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
 
 pub struct ResolvedSliceInfo {
@@ -117,6 +118,16 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
         })
         .buffered(config.n_readers_pre_init.min(config.sources.len()));
 
+    // The slice applies to the rows that are left after the deletes.
+    let n_rows_after_deletes = |scan_source_idx: usize, n_rows: IdxSize| match config
+        .deleted_rows
+        .as_ref()
+        .and_then(|x| x.get(scan_source_idx))
+    {
+        None => n_rows,
+        Some(deleted_rows) => n_rows - n_deleted_rows_before(deleted_rows, n_rows),
+    };
+
     let n_rows_needed = IdxSize::try_from(offset_from_end).unwrap();
     let slice_len_idxsize = IdxSize::try_from(slice_len).unwrap_or(IdxSize::MAX);
 
@@ -125,7 +136,8 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
     let mut n_files_from_end: usize = 0;
 
     while let Some(mut file_reader) = readers_init_iter.next().await.transpose()? {
-        let n_rows = file_reader.n_rows_in_file().await?;
+        let scan_source_idx = config.sources.len() - 1 - n_files_from_end;
+        let n_rows = n_rows_after_deletes(scan_source_idx, file_reader.n_rows_in_file().await?);
 
         // push_front: we are walking in reverse
         initialized_readers.push_front((file_reader, n_rows));
@@ -186,8 +198,10 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
         let mut n_rows_skipped_from_start: IdxSize = 0;
 
         // Fully traverse to the beginning to update the row index offset.
+        let mut scan_source_idx = config.sources.len() - n_files_from_end;
         while let Some(mut reader) = readers_init_iter.next().await.transpose()? {
-            let n_rows = reader.n_rows_in_file().await?;
+            scan_source_idx -= 1;
+            let n_rows = n_rows_after_deletes(scan_source_idx, reader.n_rows_in_file().await?);
             n_rows_skipped_from_start = n_rows_skipped_from_start.saturating_add(n_rows);
         }
 
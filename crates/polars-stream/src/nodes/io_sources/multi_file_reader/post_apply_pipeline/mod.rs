@@ -10,6 +10,7 @@ use crate::async_primitives::{connector, distributor_channel};
 use crate::morsel::Morsel;
 use crate::nodes::io_sources::multi_file_reader::bridge::BridgeRecvPort;
 use crate::nodes::io_sources::multi_file_reader::extra_ops::apply::ApplyExtraOps;
+use crate::nodes::io_sources::multi_file_reader::extra_ops::n_deleted_rows_before;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
 
 /// Pool of workers to apply operations on morsels originating from a reader.
@@ -84,7 +85,12 @@ impl PostApplyPool {
                 let mut morsel = first_morsel;
 
                 // Should only run the pipeline if we have an operation we need to apply.
-                let ApplyExtraOps::Initialized { pre_slice, .. } = ops_applier.as_ref() else {
+                let ApplyExtraOps::Initialized {
+                    deleted_rows,
+                    pre_slice,
+                    ..
+                } = ops_applier.as_ref()
+                else {
                     unreachable!();
                 };
 
@@ -94,7 +100,14 @@ impl PostApplyPool {
 
                     // We hit this if a reader does not support PRE_SLICE.
                     if pre_slice.clone().is_some_and(|x| {
-                        x.offsetted(usize::try_from(n_rows_received).unwrap()).len() == 0
+                        // The slice applies to the rows that are left after the deletes.
+                        let n_rows_after_deletes = n_rows_received
+                            - deleted_rows.as_deref().map_or(0, |deleted_rows| {
+                                n_deleted_rows_before(deleted_rows, n_rows_received)
+                            });
+                        x.offsetted(usize::try_from(n_rows_after_deletes).unwrap())
+                            .len()
+                            == 0
                     }) {
                         // Note: We do not return any flag indicating that we have reached end of slice
                         // from this context. The read should be stopped on a higher level by using
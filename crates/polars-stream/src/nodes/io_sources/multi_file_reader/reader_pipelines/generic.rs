@@ -9,7 +9,9 @@ use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{ExtraColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_plan::dsl::{
+    CastColumnsPolicy, DeletedRows, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSource,
+};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::IdxSize;
 use polars_utils::slice_enum::Slice;
@@ -22,7 +24,7 @@ use crate::nodes::io_sources::multi_file_reader::bridge::BridgeRecvPort;
 use crate::nodes::io_sources::multi_file_reader::extra_ops::apply::ApplyExtraOps;
 use crate::nodes::io_sources::multi_file_reader::extra_ops::missing_columns::initialize_missing_columns_policy;
 use crate::nodes::io_sources::multi_file_reader::extra_ops::{
-    ExtraOperations, apply_extra_columns_policy,
+    ExtraOperations, apply_extra_columns_policy, n_deleted_rows_before,
 };
 use crate::nodes::io_sources::multi_file_reader::initialization::slice::{
     ResolvedSliceInfo, resolve_to_positive_slice,
@@ -66,6 +68,7 @@ impl MultiScanTaskInitializer {
             // This can hugely benefit NDJSON, as it can read backwards.
             Some(Slice::Negative { .. })
                 if self.config.sources.len() == 1
+                    && self.config.deleted_rows.is_none()
                     && reader_capabilities.contains(ReaderCapabilities::NEGATIVE_PRE_SLICE)
                     && (self.config.row_index.is_none()
                         || reader_capabilities.contains(ReaderCapabilities::ROW_INDEX)) =>
@@ -99,6 +102,8 @@ impl MultiScanTaskInitializer {
         let include_file_paths = self.config.include_file_paths.clone();
 
         let extra_ops = ExtraOperations {
+            // Set per file.
+            deleted_rows: None,
             row_index,
             pre_slice,
             cast_columns_policy,
@@ -235,6 +240,7 @@ impl MultiScanTaskInitializer {
                 started_reader_tx,
                 max_concurrent_scans,
                 skip_files_mask,
+                deleted_rows: self.config.deleted_rows.clone(),
                 extra_ops,
                 constant_args: StartReaderArgsConstant {
                     hive_parts,
@@ -284,6 +290,7 @@ struct ReaderStarter {
     )>,
     max_concurrent_scans: usize,
     skip_files_mask: Option<Bitmap>,
+    deleted_rows: Option<DeletedRows>,
     extra_ops: ExtraOperations,
     constant_args: StartReaderArgsConstant,
     num_pipelines: usize,
@@ -299,6 +306,7 @@ impl ReaderStarter {
             started_reader_tx,
             max_concurrent_scans,
             skip_files_mask,
+            deleted_rows,
             extra_ops,
             constant_args,
             num_pipelines,
@@ -360,6 +368,14 @@ impl ReaderStarter {
                 eprintln!("[ReaderStarter]: scan_source_idx: {}", scan_source_idx)
             }
 
+            // Readers are not given the row index, slice and predicate of files with deleted rows,
+            // as those apply to the rows that are left after the deletes. Deletes are always
+            // applied in post.
+            let deleted_rows_this_file = deleted_rows
+                .as_ref()
+                .and_then(|x| x.get(scan_source_idx))
+                .cloned();
+
             if skip_files_mask
                 .as_ref()
                 .is_some_and(|x| x.get_bit(scan_source_idx))
@@ -378,9 +394,15 @@ impl ReaderStarter {
                         panic!();
                     }
 
-                    current_row_position = current_row_position.saturating_add(
-                        reader.row_position_after_slice(pre_slice_this_file).await?,
-                    );
+                    let n_rows = match &deleted_rows_this_file {
+                        None => reader.row_position_after_slice(pre_slice_this_file).await?,
+                        Some(deleted_rows) => {
+                            let n_rows = reader.row_position_after_slice(None).await?;
+                            n_rows - n_deleted_rows_before(deleted_rows, n_rows)
+                        },
+                    };
+
+                    current_row_position = current_row_position.saturating_add(n_rows);
                 }
 
                 continue;
@@ -392,17 +414,22 @@ impl ReaderStarter {
             });
 
             let extra_ops_this_file = ExtraOperations {
+                deleted_rows: deleted_rows_this_file.clone(),
                 row_index: row_index_this_file,
                 pre_slice: pre_slice_this_file.clone(),
                 // Other operations don't need updating per file
                 ..extra_ops.clone()
             };
 
+            // The row position sent by the reader does not account for the deleted rows.
+            let mut deleted_rows_on_end = None;
+
             let (row_position_on_end_tx, row_position_on_end_rx) =
                 if extra_ops.has_row_index_or_slice() && n_sources - scan_source_idx > 1 {
                     let (mut tx, rx) = connector::connector();
 
                     // See if we have the value leftover from negative slice initialization, so we don't duplicate row counting.
+                    // Note that this count already excludes the deleted rows.
                     if let Some(mut n_rows) = opt_n_rows_in_file {
                         if let Some(pre_slice) = pre_slice_this_file {
                             n_rows = IdxSize::try_from(
@@ -416,6 +443,7 @@ impl ReaderStarter {
                         _ = tx.try_send(n_rows);
                         (None, Some(rx))
                     } else {
+                        deleted_rows_on_end = deleted_rows_this_file.clone();
                         (Some(tx), Some(rx))
                     }
                 } else {
@@ -431,13 +459,18 @@ impl ReaderStarter {
 
             let mut extra_ops_post = extra_ops_this_file;
 
-            let row_index = if reader_capabilities.contains(ReaderCapabilities::ROW_INDEX) {
+            let has_deleted_rows = extra_ops_post.deleted_rows.is_some();
+
+            let row_index = if reader_capabilities.contains(ReaderCapabilities::ROW_INDEX)
+                && !has_deleted_rows
+            {
                 extra_ops_post.row_index.take()
             } else {
                 None
             };
 
             let pre_slice = match &extra_ops_post.pre_slice {
+                _ if has_deleted_rows => None,
                 Some(Slice::Positive { .. })
                     if reader_capabilities.contains(ReaderCapabilities::PRE_SLICE) =>
                 {
@@ -453,6 +486,7 @@ impl ReaderStarter {
 
             // Note: We do set_external_columns later below to avoid blocking this loop.
             let predicate = if extra_ops_post.predicate.is_some()
+                && !has_deleted_rows
                 && reader_capabilities.contains(ReaderCapabilities::PARTIAL_FILTER)
                 && extra_ops_post.row_index.is_none()
                 && extra_ops_post.pre_slice.is_none()
@@ -511,7 +545,11 @@ impl ReaderStarter {
             // * Parallelize the CSV row count
             // * NDJSON skips rows (i.e. non-zero offset) in a single-threaded manner.
             if let Some(mut rx) = row_position_on_end_rx {
-                if let Ok(n) = rx.recv().await {
+                if let Ok(mut n) = rx.recv().await {
+                    if let Some(deleted_rows) = &deleted_rows_on_end {
+                        n -= n_deleted_rows_before(deleted_rows, n);
+                    }
+
                     current_row_position = current_row_position.saturating_add(n);
                 }
             }
@@ -566,7 +604,7 @@ async fn start_reader_impl(
         scan_source_idx,
         mut reader,
         mut begin_read_args,
-        extra_ops_post,
+        mut extra_ops_post,
     } = args_this_file;
 
     let pre_slice_to_reader = begin_read_args.pre_slice.clone();
@@ -600,6 +638,27 @@ async fn start_reader_impl(
             && begin_read_args.callbacks.n_rows_in_file_tx.is_some()),
     );
 
+    // Readers evaluate the predicate on the columns as they are in the file, so if any of its
+    // columns need to be cast we filter in post instead, where the casts are applied first.
+    if begin_read_args.predicate.is_some()
+        && !matches!(
+            extra_ops_post.cast_columns_policy,
+            CastColumnsPolicy::ErrorOnMismatch
+        )
+    {
+        let file_schema = get_file_schema!();
+        let predicate = begin_read_args.predicate.as_ref().unwrap();
+
+        if predicate.live_columns.iter().any(|name| {
+            file_schema
+                .get(name)
+                .zip(projected_file_schema.get(name))
+                .is_some_and(|(dtype, target_dtype)| dtype != target_dtype)
+        }) {
+            extra_ops_post.predicate = begin_read_args.predicate.take();
+        }
+    }
+
     if let Some(predicate) = begin_read_args.predicate.as_mut() {
         let mut external_predicate_cols = Vec::with_capacity(
             hive_parts.as_ref().map_or(0, |x| x.df().width())
@@ -741,6 +800,8 @@ impl AttachReaderToBridge {
         let mut n_readers_received: usize = 0;
 
         let mut post_apply_pool: Option<PostApplyPool> = None;
+        // Reader whose output was closed before it ended.
+        let mut stopped_reader_handle = None;
 
         while let Some((init_task_handle, wait_token)) = started_reader_rx.recv().await {
             n_readers_received = n_readers_received.saturating_add(1);
@@ -774,6 +835,7 @@ impl AttachReaderToBridge {
                             .await
                             .is_err()
                         {
+                            stopped_reader_handle = Some(reader_handle);
                             break;
                         }
                     },
@@ -795,6 +857,7 @@ impl AttachReaderToBridge {
                             .await?;
 
                         if bridge_recv_port_tx.send(bridge_recv_port).await.is_err() {
+                            stopped_reader_handle = Some(reader_handle);
                             break;
                         }
 
@@ -814,6 +877,12 @@ impl AttachReaderToBridge {
             post_apply_pool.shutdown().await?;
         }
 
+        // The reader stops once its output is closed. Wait for it, so errors it raises while
+        // finishing, e.g. when writing rejected rows, are not lost.
+        if let Some(reader_handle) = stopped_reader_handle {
+            reader_handle.await?;
+        }
+
         Ok(())
     }
 }
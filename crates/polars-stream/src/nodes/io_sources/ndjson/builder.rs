@@ -17,14 +17,20 @@ impl FileReaderBuilder for Arc<polars_plan::dsl::NDJsonReadOptions> {
     fn reader_capabilities(&self) -> ReaderCapabilities {
         use ReaderCapabilities as RC;
 
-        RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        // Rejected lines change the row count of a chunk, so slicing and the row index are left
+        // to the multi-file reader.
+        if self.rejected_rows.is_some() {
+            RC::empty()
+        } else {
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
     }
 
     fn build_file_reader(
         &self,
         source: ScanSource,
         cloud_options: Option<Arc<CloudOptions>>,
-        _scan_source_idx: usize,
+        scan_source_idx: usize,
     ) -> Box<dyn FileReader> {
         let scan_source = source;
         let options = self.clone();
@@ -34,6 +40,7 @@ impl FileReaderBuilder for Arc<polars_plan::dsl::NDJsonReadOptions> {
             scan_source,
             cloud_options,
             options,
+            scan_source_idx,
             cached_bytes: None,
             verbose,
         };
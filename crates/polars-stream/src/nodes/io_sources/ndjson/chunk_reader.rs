@@ -1,8 +1,9 @@
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::ndjson;
-use polars_io::prelude::parse_ndjson;
+use polars_io::prelude::{RejectedRows, parse_ndjson, parse_ndjson_rejecting};
 use polars_plan::dsl::NDJsonReadOptions;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::compute_node_prelude::*;
 
@@ -13,6 +14,11 @@ pub(super) struct ChunkReader {
     #[cfg(feature = "dtype-categorical")]
     _cat_lock: Option<polars_core::StringCacheHolder>,
     ignore_errors: bool,
+    rejected_rows: Option<RejectedRows>,
+    /// Schema the lines are parsed with when rejecting rows. This contains all columns of the
+    /// file, so that which lines are rejected does not depend on the projection.
+    parse_schema: SchemaRef,
+    path: Option<PlSmallStr>,
 }
 
 impl ChunkReader {
@@ -33,14 +39,60 @@ impl ChunkReader {
             #[cfg(feature = "dtype-categorical")]
             _cat_lock,
             ignore_errors: options.ignore_errors,
+            rejected_rows: None,
+            parse_schema: Default::default(),
+            path: None,
         })
     }
 
-    pub(super) fn read_chunk(&self, chunk: &[u8]) -> PolarsResult<DataFrame> {
-        if self.projected_schema.is_empty() {
+    pub(super) fn with_rejected_rows(
+        mut self,
+        rejected_rows: RejectedRows,
+        parse_schema: SchemaRef,
+        path: Option<PlSmallStr>,
+    ) -> Self {
+        self.rejected_rows = Some(rejected_rows);
+        self.parse_schema = parse_schema;
+        self.path = path;
+        self
+    }
+
+    /// `first_line` is only used when rejecting rows.
+    pub(super) fn read_chunk(&self, chunk: &[u8], first_line: usize) -> PolarsResult<DataFrame> {
+        if let Some(rejected_rows) = &self.rejected_rows {
+            let df = parse_ndjson_rejecting(
+                chunk,
+                &self.parse_schema,
+                rejected_rows,
+                self.path.as_ref(),
+                first_line,
+            )?;
+
+            if self.projected_schema.is_empty() {
+                Ok(DataFrame::empty_with_height(df.height()))
+            } else {
+                df.select(self.projected_schema.iter_names().cloned())
+            }
+        } else if self.projected_schema.is_empty() {
             Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)))
         } else {
             parse_ndjson(chunk, None, &self.projected_schema, self.ignore_errors)
         }
     }
+
+    pub(super) fn count_rows(&self, chunk: &[u8]) -> PolarsResult<usize> {
+        if self.rejected_rows.is_some() {
+            // Rejected lines are not counted. They are recorded by the read itself, not here.
+            let df = parse_ndjson_rejecting(
+                chunk,
+                &self.parse_schema,
+                &RejectedRows::discard(),
+                None,
+                0,
+            )?;
+            Ok(df.height())
+        } else {
+            Ok(ndjson::count_rows(chunk))
+        }
+    }
 }
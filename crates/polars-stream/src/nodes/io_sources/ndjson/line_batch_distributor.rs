@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::rejected_rows::LineCounter;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -12,6 +13,8 @@ pub(super) struct LineBatchDistributor {
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
+    /// Set when rejecting rows, which needs the line number of every chunk.
+    pub(super) line_numbers: Option<LineCounter>,
     pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
 }
 
@@ -23,9 +26,12 @@ impl LineBatchDistributor {
             chunk_size,
             n_rows_to_skip,
             reverse,
+            mut line_numbers,
             mut line_batch_distribute_tx,
         } = self;
 
+        assert!(!(reverse && line_numbers.is_some()));
+
         // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
         let global_bytes: &'static [u8] =
             unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
@@ -103,11 +109,19 @@ impl LineBatchDistributor {
                 prev_remainder = &[];
                 row_skipper.skip_rows(&mut full_chunk);
 
+                let first_line = line_numbers.as_mut().map_or(0, |l| {
+                    l.line_at(
+                        global_bytes,
+                        full_chunk.as_ptr() as usize - global_bytes.as_ptr() as usize,
+                    )
+                });
+
                 if !full_chunk.is_empty()
                     && line_batch_distribute_tx
                         .send(LineBatch {
                             bytes: full_chunk,
                             chunk_idx,
+                            first_line,
                         })
                         .await
                         .is_err()
@@ -2,7 +2,6 @@ use std::cmp::Reverse;
 use std::sync::Arc;
 
 use polars_error::PolarsResult;
-use polars_io::ndjson;
 use polars_utils::mmap::MemSlice;
 use polars_utils::priority::Priority;
 
@@ -59,8 +58,13 @@ impl LineBatchProcessor {
 
         let mut n_rows_processed: usize = 0;
 
-        while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
-            let df = chunk_reader.read_chunk(bytes)?;
+        while let Ok(LineBatch {
+            bytes,
+            chunk_idx,
+            first_line,
+        }) = line_batch_rx.recv().await
+        {
+            let df = chunk_reader.read_chunk(bytes, first_line)?;
 
             n_rows_processed = n_rows_processed.saturating_add(df.height());
 
@@ -82,9 +86,10 @@ impl LineBatchProcessor {
             while let Ok(LineBatch {
                 bytes,
                 chunk_idx: _,
+                first_line: _,
             }) = line_batch_rx.recv().await
             {
-                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(bytes));
+                n_rows_processed = n_rows_processed.saturating_add(chunk_reader.count_rows(bytes)?);
             }
         }
 
@@ -101,6 +106,8 @@ pub(super) struct LineBatch {
     /// Safety: This is sent between 2 places that both hold a reference to the underlying MemSlice.
     pub(super) bytes: &'static [u8],
     pub(super) chunk_idx: usize,
+    /// Line number of the first line of this chunk, only set when rejecting rows.
+    pub(super) first_line: usize,
 }
 
 /// We are connected to different outputs depending on query.
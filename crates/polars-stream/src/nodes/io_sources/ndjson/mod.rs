@@ -1,6 +1,7 @@
 pub mod builder;
 
 use std::cmp::Reverse;
+use std::io::Cursor;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -11,8 +12,9 @@ use negative_slice_pass::MorselStreamReverser;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
-use polars_io::prelude::estimate_n_lines_in_file;
+use polars_io::prelude::{RejectedRows, estimate_n_lines_in_file, overwrite_schema};
 use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::rejected_rows::LineCounter;
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -42,6 +44,7 @@ pub struct NDJsonFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<NDJsonReadOptions>,
+    scan_source_idx: usize,
     verbose: bool,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<MemSlice>,
@@ -283,7 +286,9 @@ impl FileReader for NDJsonFileReader {
         };
 
         let schema = Arc::new(schema);
-        let chunk_reader = Arc::new(self.try_init_chunk_reader(&schema)?);
+        let (chunk_reader, rejected_rows) =
+            self.try_init_chunk_reader(&schema, &global_bytes, pre_slice.is_some())?;
+        let chunk_reader = Arc::new(chunk_reader);
 
         if !is_negative_slice {
             get_memory_prefetch_func(verbose)(global_bytes.as_ref());
@@ -349,6 +354,11 @@ impl FileReader for NDJsonFileReader {
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
+                line_numbers: self
+                    .options
+                    .rejected_rows
+                    .is_some()
+                    .then(|| LineCounter::new(b'\n')),
                 line_batch_distribute_tx,
             }
             .run(),
@@ -368,6 +378,10 @@ impl FileReader for NDJsonFileReader {
                 n_rows_processed = n_rows_processed.saturating_add(handle.await?);
             }
 
+            if let Some(rejected_rows) = rejected_rows {
+                rejected_rows.finish()?;
+            }
+
             let total_row_count =
                 needs_total_row_count.then_some(n_rows_skipped.saturating_add(n_rows_processed));
 
@@ -439,8 +453,49 @@ impl FileReader for NDJsonFileReader {
 }
 
 impl NDJsonFileReader {
-    fn try_init_chunk_reader(&self, schema: &SchemaRef) -> PolarsResult<ChunkReader> {
-        ChunkReader::try_new(&self.options, schema)
+    fn try_init_chunk_reader(
+        &self,
+        schema: &SchemaRef,
+        global_bytes: &[u8],
+        has_pre_slice: bool,
+    ) -> PolarsResult<(ChunkReader, Option<RejectedRows>)> {
+        let chunk_reader = ChunkReader::try_new(&self.options, schema)?;
+
+        let Some(options) = &self.options.rejected_rows else {
+            return Ok((chunk_reader, None));
+        };
+
+        // Slicing is not supported when rejecting rows, so a slice is only passed when counting
+        // rows. The rejected rows are written by the actual read.
+        let rejected_rows = if has_pre_slice {
+            RejectedRows::discard()
+        } else {
+            RejectedRows::try_new(options, self.scan_source_idx)?
+        };
+
+        let mut parse_schema = match &self.options.schema {
+            Some(schema) => schema.as_ref().clone(),
+            None => polars_io::ndjson::infer_schema(
+                &mut Cursor::new(global_bytes),
+                self.options.infer_schema_length,
+            )?,
+        };
+        if let Some(schema_overwrite) = &self.options.schema_overwrite {
+            overwrite_schema(&mut parse_schema, schema_overwrite)?;
+        }
+        for (name, dtype) in schema.iter() {
+            parse_schema.with_column(name.clone(), dtype.clone());
+        }
+
+        let path = match &self.scan_source {
+            ScanSource::Path(path) => Some(path.to_string_lossy().as_ref().into()),
+            ScanSource::File(_) | ScanSource::Buffer(_) => None,
+        };
+
+        let chunk_reader =
+            chunk_reader.with_rejected_rows(rejected_rows.clone(), Arc::new(parse_schema), path);
+
+        Ok((chunk_reader, Some(rejected_rows)))
     }
 
     fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
@@ -0,0 +1,457 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::{OrcMetadata, OrcScanOptions};
+use polars_io::predicates::ScanIOPredicate;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::orc::OrcScanOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder {
+        pub options: OrcScanOptions,
+    }
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE | RC::PARTIAL_FILTER
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = OrcFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: OrcScanOptions,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<OrcMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+fn get_max_morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_ORC_SOURCE_MAX_MORSEL_SIZE")
+        .map_or_else(
+            |_| get_ideal_morsel_size(),
+            |v| {
+                v.parse::<usize>().expect(
+                    "POLARS_STREAMING_ORC_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
+                )
+            },
+        )
+        .max(1)
+}
+
+/// Returns whether the statistics of a stripe show that no row of it can match the predicate.
+fn can_skip_stripe(
+    metadata: &OrcMetadata,
+    stripe: usize,
+    predicate: &ScanIOPredicate,
+) -> PolarsResult<bool> {
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(false);
+    };
+
+    let mut statistics = metadata.stripe_statistics(stripe, &predicate.live_columns);
+    // Statistics of columns that are cast to the table schema cannot be compared to its values.
+    statistics.retain(|name, stats| sbp.schema().get(name) == Some(&stats.dtype));
+
+    sbp.can_skip_batch(
+        metadata.stripe_num_rows(stripe) as IdxSize,
+        &predicate.live_columns,
+        statistics,
+    )
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution only reads the tail of the first file.
+        if let ScanSourceRef::Path(p) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(p.to_str().unwrap())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let metadata = OrcMetadata::try_from_bytes(&memslice)?;
+        let n_rows_in_file = IdxSize::try_from(metadata.num_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "orc file", size = metadata.num_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata: Arc::new(metadata),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let normalized_pre_slice = pre_slice_arg.clone().map(|pre_slice| {
+            pre_slice.restrict_to_bounds(usize::try_from(n_rows_in_file).unwrap())
+        });
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(
+                metadata.schema.as_ref(),
+            )));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[OrcFileReader]: early return: \
+                    n_rows_in_file: {} \
+                    pre_slice: {:?} \
+                    resolved_pre_slice: {:?} \
+                    ",
+                    n_rows_in_file, pre_slice_arg, normalized_pre_slice
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // ORC is columnar, so only the streams of projected columns are decompressed.
+        let projection: Arc<[usize]> = metadata
+            .schema
+            .iter_names()
+            .enumerate()
+            .filter(|(_, name)| projected_schema.contains(name))
+            .map(|(i, _)| i)
+            .collect();
+
+        let predicate = predicate.filter(|_| self.options.use_statistics);
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                stripes: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?}, \
+                use_statistics: {} \
+                ",
+                projection.len(),
+                metadata.schema.len(),
+                metadata.num_stripes(),
+                pre_slice_arg,
+                normalized_pre_slice,
+                predicate.is_some(),
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_max_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct StripeMessage {
+            /// Row index of the first row of the stripe.
+            row_idx_offset: IdxSize,
+            /// Slice relative to the first row of the stripe.
+            slice: Range<usize>,
+            stripe: usize,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut stripe_tx, stripe_rxs) =
+            distributor_channel::<StripeMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute morsels from large stripes over the output
+        // pipelines, see the IPC source for details.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Decompresses and decodes the projected columns of a single stripe, which is then split
+        // into morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(stripe_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let projection = projection.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let StripeMessage {
+                            row_idx_offset,
+                            slice,
+                            stripe,
+                            morsel_seq_base,
+                        } = m;
+
+                        let mut df = if projection.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let batch = metadata.read_stripe(&memslice, stripe, &projection)?;
+                            DataFrame::from(batch).slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = IdxSize::try_from(slice.start)
+                                .ok()
+                                .and_then(|start| row_idx_offset.checked_add(start))
+                                .ok_or_else(
+                                    || polars_err!(ComputeError: "orc row index overflowed"),
+                                )?;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Sends the stripes that overlap with the slice to the decoder tasks, skipping the ones
+        // whose statistics show that they cannot match the predicate. The stripe statistics were
+        // already read during initialization, so this does no IO.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let row_idx_base: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+
+            let mut morsel_seq: u64 = 0;
+            let mut rows_before_stripe = 0;
+            let mut num_skipped = 0;
+
+            for stripe in 0..metadata.num_stripes() {
+                let stripe_start_row = rows_before_stripe;
+                let num_rows = metadata.stripe_num_rows(stripe);
+                rows_before_stripe += num_rows;
+
+                if stripe_start_row >= slice.end {
+                    break;
+                }
+                if rows_before_stripe <= slice.start {
+                    continue;
+                }
+                if let Some(predicate) = &predicate {
+                    if can_skip_stripe(&metadata, stripe, predicate)? {
+                        num_skipped += 1;
+                        continue;
+                    }
+                }
+
+                let stripe_slice = slice.start.saturating_sub(stripe_start_row)
+                    ..(slice.end - stripe_start_row).min(num_rows);
+                let stripe_slice_len = stripe_slice.len();
+
+                let message = StripeMessage {
+                    // Cannot overflow, the total row count of the file fits in IdxSize.
+                    row_idx_offset: row_idx_base + stripe_start_row as IdxSize,
+                    slice: stripe_slice,
+                    stripe,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if stripe_tx.send(message).await.is_err() {
+                    // This should only happen if the receiver of the decoder
+                    // has broken off, meaning no further input will be needed.
+                    break;
+                }
+
+                morsel_seq += stripe_slice_len.div_ceil(max_morsel_size) as u64;
+            }
+
+            if verbose && predicate.is_some() {
+                eprintln!(
+                    "[OrcFileReader]: predicate skipped {} / {} stripes",
+                    num_skipped,
+                    metadata.num_stripes()
+                );
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<polars_core::schema::SchemaRef> {
+        let InitializedState { metadata, .. } = self.init_data.as_ref().unwrap();
+        Ok(Arc::new(Schema::from_arrow_schema(
+            metadata.schema.as_ref(),
+        )))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
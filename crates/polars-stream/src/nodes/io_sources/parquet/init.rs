@@ -2,16 +2,21 @@ use std::ops::Range;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{Column, DataType, IDX_DTYPE, IntoColumn};
+use polars_core::prelude::{Column, DataType, IDX_DTYPE, IntoColumn, PlHashMap};
 use polars_core::series::Series;
-use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::utils::arrow::datatypes::ArrowSchemaRef;
 use polars_error::{PolarsResult, polars_ensure};
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::_internal::{PrefilterMaskSetting, collect_statistics_with_live_columns};
+use polars_io::prelude::_internal::{
+    PrefilterMaskSetting, bloom_filter_bitset_range, bloom_filter_byte_range,
+    bloom_filter_excludes_all, collect_statistics_with_live_columns,
+};
 use polars_io::prelude::{FileMetadata, ParallelStrategy};
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_utils::{IdxSize, format_pl_smallstr};
 
+use super::page_selection::calculate_page_selections;
 use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
@@ -123,6 +128,123 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Extends `skip_row_group_mask` with the row groups for which the bloom filters prove that no
+/// row can match the value sets of the predicate.
+#[allow(clippy::too_many_arguments)]
+async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    reader_schema: &ArrowSchemaRef,
+    byte_source: &DynByteSource,
+    skip_row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics {
+        return Ok(skip_row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(skip_row_group_mask);
+    };
+    if predicate.column_value_sets.is_empty() {
+        return Ok(skip_row_group_mask);
+    }
+
+    let file_size = byte_source.get_size().await?;
+
+    // (row group index in slice, column name, column chunk, bloom filter byte range)
+    let mut candidates = Vec::new();
+    for (i, rg) in metadata.row_groups[row_group_slice.clone()]
+        .iter()
+        .enumerate()
+    {
+        if skip_row_group_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        for name in predicate.column_value_sets.keys() {
+            let Some(mut columns) = rg.columns_under_root_iter(name) else {
+                continue;
+            };
+            // Nested columns are not supported.
+            if columns.len() != 1 {
+                continue;
+            }
+            let column = columns.next().unwrap();
+
+            let Some(range) = bloom_filter_byte_range(column) else {
+                continue;
+            };
+            // The range only covers a size hint if the metadata has no bloom filter length.
+            let range = range.start..range.end.min(file_size);
+            if range.is_empty() {
+                continue;
+            }
+
+            candidates.push((i, name, column, range));
+        }
+    }
+
+    if candidates.is_empty() {
+        return Ok(skip_row_group_mask);
+    }
+
+    let mut ranges = candidates
+        .iter()
+        .map(|(_, _, _, range)| range.clone())
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let num_row_groups = row_group_slice.len();
+    let mut mask = match skip_row_group_mask {
+        Some(mask) => mask.make_mut(),
+        None => MutableBitmap::from_len_zeroed(num_row_groups),
+    };
+    let num_skipped_by_statistics = mask.set_bits();
+
+    for (i, name, column, range) in candidates {
+        if mask.get(i) {
+            continue;
+        }
+
+        let bytes = bytes_map.get(&range.start).unwrap();
+        let Some(bitset_range) = bloom_filter_bitset_range(bytes)? else {
+            continue;
+        };
+
+        let bitset = if bitset_range.end <= bytes.len() {
+            bytes.slice(bitset_range)
+        } else {
+            let bitset_range = range.start + bitset_range.start..range.start + bitset_range.end;
+            polars_ensure!(
+                bitset_range.end <= file_size,
+                ComputeError: "parquet bloom filter of column '{}' is out of bounds",
+                name
+            );
+            byte_source.get_range(bitset_range).await?
+        };
+
+        let field = reader_schema.get(name).unwrap();
+        let values = predicate.column_value_sets.get(name).unwrap();
+        if bloom_filter_excludes_all(&bitset, column, field, values) {
+            mask.set(i, true);
+        }
+    }
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+                                skipped {} / {} row groups",
+            mask.set_bits() - num_skipped_by_statistics,
+            num_row_groups,
+        );
+    }
+
+    Ok(Some(mask.freeze()))
+}
+
 impl ParquetReadImpl {
     /// Constructs the task that distributes morsels across the engine pipelines.
     #[allow(clippy::type_complexity)]
@@ -235,6 +357,36 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &reader_schema,
+                &byte_source,
+                row_group_mask,
+                verbose,
+            )
+            .await?;
+
+            // Page-level pruning is not combined with slices.
+            let (row_group_mask, page_selections) = if slice_range.is_none() {
+                calculate_page_selections(
+                    row_group_slice.clone(),
+                    use_statistics,
+                    predicate.as_ref(),
+                    &metadata,
+                    &reader_schema,
+                    projection.as_ref(),
+                    &byte_source,
+                    row_group_mask,
+                    verbose,
+                )
+                .await?
+            } else {
+                (row_group_mask, PlHashMap::default())
+            };
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection,
                 predicate,
@@ -244,6 +396,7 @@ impl ParquetReadImpl {
                 byte_source,
                 row_group_slice,
                 row_group_mask,
+                page_selections,
                 row_offset,
             };
 
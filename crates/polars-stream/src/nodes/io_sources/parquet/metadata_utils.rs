@@ -8,8 +8,8 @@ pub async fn read_parquet_metadata_bytes(
     byte_source: &DynByteSource,
     verbose: bool,
 ) -> PolarsResult<(MemSlice, Option<MemSlice>)> {
-    use polars_parquet::parquet::PARQUET_MAGIC;
     use polars_parquet::parquet::error::ParquetError;
+    use polars_parquet::parquet::{PARQUET_MAGIC, PARQUET_MAGIC_ENCRYPTED_FOOTER};
 
     const FOOTER_HEADER_SIZE: usize = polars_parquet::parquet::FOOTER_SIZE as usize;
 
@@ -39,7 +39,7 @@ pub async fn read_parquet_metadata_bytes(
     let (v, remaining) = footer_header_bytes.split_at(4);
     let footer_size = i32::from_le_bytes(v.try_into().unwrap());
 
-    if remaining != PARQUET_MAGIC {
+    if remaining != PARQUET_MAGIC && remaining != PARQUET_MAGIC_ENCRYPTED_FOOTER {
         return Err(ParquetError::OutOfSpec(format!(
             r#"expected parquet magic bytes "{}" in footer, got "{}" instead"#,
             std::str::from_utf8(&PARQUET_MAGIC).unwrap(),
@@ -6,12 +6,14 @@ use polars_core::prelude::ArrowSchema;
 use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_io::cloud::CloudOptions;
+use polars_io::parquet::encryption::deserialize_footer;
+use polars_io::parquet::field_id::match_field_ids;
 use polars_io::pl_async;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::{FileMetadata, ParquetOptions};
+use polars_io::prelude::{FileMetadata, ParquetColumnMatching, ParquetOptions};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder, MemSliceByteSource};
 use polars_parquet::read::schema::infer_schema_with_options;
-use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
+use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::index::AtomicIdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -33,6 +35,7 @@ use crate::utils::task_handles_ext;
 pub mod builder;
 mod init;
 mod metadata_utils;
+mod page_selection;
 mod row_group_data_fetch;
 mod row_group_decode;
 
@@ -82,7 +85,7 @@ impl FileReader for ParquetFileReader {
 
         let mut byte_source = Arc::new(byte_source);
 
-        let file_metadata = if let Some(v) = self.metadata.clone() {
+        let mut file_metadata = if let Some(v) = self.metadata.clone() {
             v
         } else {
             let (metadata_bytes, opt_full_bytes) = {
@@ -100,13 +103,24 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
+            Arc::new(deserialize_footer(
                 metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
+                self.config.key_retriever.as_ref(),
             )?)
         };
 
-        let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
+        let mut file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
+
+        // Present the columns of the file under their names in the table, the rest of the reader
+        // then only sees the matched columns.
+        if let ParquetColumnMatching::FieldId { field_ids } = &self.config.column_matching {
+            let Some(field_ids) = field_ids else {
+                panic!("impl error: parquet field ids should be resolved during IR conversion")
+            };
+            let matched = match_field_ids(&file_schema, file_metadata.schema().fields(), field_ids);
+            file_metadata = Arc::new(matched.rename_metadata(&file_metadata));
+            file_schema = Arc::new(matched.schema);
+        }
 
         self.init_data = Some(InitializedState {
             file_metadata,
@@ -228,12 +242,11 @@ impl FileReader for ParquetFileReader {
 
         // If are handling predicates we apply missing / cast columns policy here as those need to
         // happen before filtering. Otherwise we leave it to post.
+        //
+        // Predicates on columns that need to be cast are not passed to the reader, they are
+        // applied after the cast in post.
         if let Some(predicate) = predicate.as_mut() {
-            if !matches!(cast_columns_policy, CastColumnsPolicy::ErrorOnMismatch) {
-                unimplemented!("column casting w/ predicate in parquet")
-            }
-
-            CastColumns::try_init_from_policy_from_iter(
+            let cast_columns = CastColumns::try_init_from_policy_from_iter(
                 &cast_columns_policy,
                 &projected_schema,
                 &mut self
@@ -242,6 +255,7 @@ impl FileReader for ParquetFileReader {
                     .filter(|(name, _)| predicate.live_columns.contains(*name))
                     .map(|(name, dtype)| (name.as_ref(), dtype)),
             )?;
+            assert!(cast_columns.is_none());
         }
 
         let (output_recv, handle) = ParquetReadImpl {
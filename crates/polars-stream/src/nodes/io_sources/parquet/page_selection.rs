@@ -0,0 +1,530 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{
+    ArrowSchema, Column, DataType, IDX_DTYPE, IdxCa, IntoColumn, PlHashMap, PlIndexSet,
+};
+use polars_core::series::Series;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::utils::arrow::datatypes::ArrowSchemaRef;
+use polars_error::PolarsResult;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::PageMetaData;
+use polars_parquet::parquet::read::indexes::{
+    PageLocation, deserialize_column_index, deserialize_offset_index,
+};
+use polars_parquet::read::statistics::{deserialize_page_statistics, supports_page_statistics};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::{IdxSize, format_pl_smallstr};
+
+use super::row_group_data_fetch::FetchedBytes;
+use crate::async_executor;
+use crate::nodes::TaskPriority;
+
+/// The rows of a row group that can match the predicate according to the page indexes.
+pub(super) struct PageSelection {
+    /// Sorted, non-overlapping and non-adjacent ranges of selected rows.
+    rows: Vec<Range<usize>>,
+    /// The data pages of the column chunks that can be fetched page-by-page, by the index of the
+    /// column chunk in the row group. Other column chunks are fetched entirely.
+    page_locations: PlHashMap<usize, Vec<PageLocation>>,
+}
+
+impl PageSelection {
+    pub(super) fn rows(&self) -> &[Range<usize>] {
+        &self.rows
+    }
+
+    pub(super) fn num_rows(&self) -> usize {
+        self.rows.iter().map(|r| r.len()).sum()
+    }
+
+    /// Returns whether any row of `range` is selected.
+    fn overlaps(&self, range: Range<usize>) -> bool {
+        let i = self.rows.partition_point(|r| r.end <= range.start);
+        self.rows.get(i).is_some_and(|r| r.start < range.end)
+    }
+
+    /// Appends a bit for every row in `range` to `mask`, which is set if the row is selected.
+    fn extend_mask(&self, mask: &mut MutableBitmap, range: Range<usize>) {
+        let mut position = range.start;
+
+        let i = self.rows.partition_point(|r| r.end <= range.start);
+        for r in &self.rows[i..] {
+            if r.start >= range.end {
+                break;
+            }
+
+            let start = r.start.max(range.start);
+            let end = r.end.min(range.end);
+            mask.extend_constant(start - position, false);
+            mask.extend_constant(end - start, true);
+            position = end;
+        }
+
+        mask.extend_constant(range.end - position, false);
+    }
+
+    /// Returns the row ranges and locations of the selected data pages of the column chunk at
+    /// `column_idx`, or `None` if the column chunk is not fetched page-by-page.
+    fn selected_pages(
+        &self,
+        column_idx: usize,
+        num_rows: usize,
+    ) -> Option<impl Iterator<Item = (Range<usize>, &PageLocation)>> {
+        let locations = self.page_locations.get(&column_idx)?;
+
+        Some(
+            locations
+                .iter()
+                .enumerate()
+                .map(move |(i, location)| {
+                    let end = locations
+                        .get(i + 1)
+                        .map_or(num_rows, |next| next.first_row_index as usize);
+                    (location.first_row_index as usize..end, location)
+                })
+                .filter(|(rows, _)| self.overlaps(rows.clone())),
+        )
+    }
+}
+
+/// Returns the byte ranges of the column chunk at `column_idx` that are needed to decode the rows
+/// selected by `page_selection`.
+///
+/// For a column chunk that is fetched page-by-page these are the dictionary page and the selected
+/// data pages, adjacent pages are merged into a single range.
+pub(super) fn column_byte_ranges(
+    row_group_metadata: &RowGroupMetadata,
+    column_idx: usize,
+    page_selection: Option<&PageSelection>,
+) -> Vec<Range<usize>> {
+    let column = &row_group_metadata.parquet_columns()[column_idx];
+    let byte_range = column.byte_range();
+    let byte_range = byte_range.start as usize..byte_range.end as usize;
+
+    let Some(pages) =
+        page_selection.and_then(|p| p.selected_pages(column_idx, row_group_metadata.num_rows()))
+    else {
+        return vec![byte_range];
+    };
+
+    let locations = &page_selection.unwrap().page_locations[&column_idx];
+    let first_page_offset = locations[0].offset as usize;
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    if first_page_offset > byte_range.start {
+        // The dictionary page.
+        ranges.push(byte_range.start..first_page_offset);
+    }
+
+    for (_, location) in pages {
+        let start = location.offset as usize;
+        let end = start + location.compressed_page_size as usize;
+
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+
+    ranges
+}
+
+/// Returns the column chunks of the leaf columns at `column_idxs` that need to be deserialized and
+/// the mask that selects the rows of the page selection from the deserialized rows.
+pub(super) fn columns_to_deserialize(
+    row_group_metadata: &RowGroupMetadata,
+    column_idxs: &[usize],
+    page_selection: &PageSelection,
+    fetched_bytes: &FetchedBytes,
+) -> (Vec<(PageMetaData, MemSlice)>, Bitmap) {
+    let num_rows = row_group_metadata.num_rows();
+    let mut mask = MutableBitmap::new();
+
+    let columns = column_idxs
+        .iter()
+        .map(|&column_idx| {
+            let column = &row_group_metadata.parquet_columns()[column_idx];
+            let mut page_meta = PageMetaData::from(column);
+
+            let ranges = column_byte_ranges(row_group_metadata, column_idx, Some(page_selection));
+            let bytes = if ranges.len() == 1 {
+                fetched_bytes.get_range(ranges[0].clone())
+            } else {
+                let mut bytes = Vec::with_capacity(ranges.iter().map(|r| r.len()).sum());
+                for range in ranges {
+                    bytes.extend_from_slice(&fetched_bytes.get_range(range));
+                }
+                MemSlice::from_vec(bytes)
+            };
+
+            // All leaf columns of a nested field are fetched entirely, so they share the mask.
+            if mask.is_empty() {
+                match page_selection.selected_pages(column_idx, num_rows) {
+                    None => page_selection.extend_mask(&mut mask, 0..num_rows),
+                    Some(pages) => {
+                        for (rows, _) in pages {
+                            page_selection.extend_mask(&mut mask, rows);
+                        }
+                        page_meta.num_values = mask.len() as i64;
+                    },
+                }
+            }
+
+            (page_meta, bytes)
+        })
+        .collect();
+
+    (columns, mask.freeze())
+}
+
+/// Reads the page indexes of the predicate columns to select the rows of every row group that
+/// can match the predicate.
+///
+/// Row groups without any matching page are added to `skip_row_group_mask`. The returned
+/// selections are keyed by the index of the row group in the file and only contain row groups for
+/// which some rows were pruned.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_page_selections(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<FileMetadata>,
+    reader_schema: &ArrowSchemaRef,
+    projection: Option<&ArrowSchemaRef>,
+    byte_source: &DynByteSource,
+    skip_row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<(Option<Bitmap>, PlHashMap<usize, PageSelection>)> {
+    if !use_statistics {
+        return Ok((skip_row_group_mask, PlHashMap::default()));
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok((skip_row_group_mask, PlHashMap::default()));
+    };
+    let Some(sbp) = predicate.skip_batch_predicate.clone() else {
+        return Ok((skip_row_group_mask, PlHashMap::default()));
+    };
+
+    let projected_names = projection
+        .unwrap_or(reader_schema)
+        .iter_names()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // Only non-repeated columns are supported, as the offset index only stores the number of rows
+    // of a page and not its number of values. Encrypted columns are always fetched entirely, their
+    // indexes would have to be decrypted and the decryption of a page depends on its ordinal in the
+    // column chunk.
+    let page_indexed_column_idx = |rg: &RowGroupMetadata, name: &str| {
+        let [column_idx] = rg.columns_idxs_under_root_iter(name)? else {
+            return None;
+        };
+        let column = &rg.parquet_columns()[*column_idx];
+        (column.descriptor().descriptor.max_rep_level == 0
+            && !column.is_encrypted()
+            && column.offset_index_byte_range().is_some())
+        .then_some(*column_idx)
+    };
+
+    // (row group index, live columns with a column index, columns with an offset index)
+    let mut candidates = Vec::new();
+    let mut ranges = Vec::new();
+    for (i, rg) in metadata.row_groups[row_group_slice.clone()]
+        .iter()
+        .enumerate()
+    {
+        if skip_row_group_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        let live_columns = predicate
+            .live_columns
+            .iter()
+            .map(|name| {
+                let field = reader_schema.get(name)?;
+                if !supports_page_statistics(field.dtype()) {
+                    return None;
+                }
+
+                page_indexed_column_idx(rg, name).filter(|&column_idx| {
+                    rg.parquet_columns()[column_idx]
+                        .column_index_byte_range()
+                        .is_some()
+                })
+            })
+            .collect::<Vec<_>>();
+        if live_columns.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let mut indexed_columns = live_columns.iter().flatten().copied().collect::<Vec<_>>();
+        indexed_columns.extend(
+            projected_names
+                .iter()
+                .filter_map(|name| page_indexed_column_idx(rg, name)),
+        );
+        indexed_columns.sort_unstable();
+        indexed_columns.dedup();
+
+        for &column_idx in live_columns.iter().flatten() {
+            let range = rg.parquet_columns()[column_idx]
+                .column_index_byte_range()
+                .unwrap();
+            ranges.push(range.start as usize..range.end as usize);
+        }
+        for &column_idx in &indexed_columns {
+            let range = rg.parquet_columns()[column_idx]
+                .offset_index_byte_range()
+                .unwrap();
+            ranges.push(range.start as usize..range.end as usize);
+        }
+
+        candidates.push((i, live_columns, indexed_columns));
+    }
+
+    if candidates.is_empty() {
+        return Ok((skip_row_group_mask, PlHashMap::default()));
+    }
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let num_row_groups = row_group_slice.len();
+    let metadata = metadata.clone();
+    let live_columns = predicate.live_columns.clone();
+    let reader_schema = reader_schema.clone();
+    let (mask, page_selections, num_rows, num_selected_rows) =
+        async_executor::spawn(TaskPriority::High, async move {
+            let mut mask = match skip_row_group_mask {
+                Some(mask) => mask.make_mut(),
+                None => MutableBitmap::from_len_zeroed(num_row_groups),
+            };
+            let mut page_selections = PlHashMap::default();
+            let mut num_rows = 0;
+            let mut num_selected_rows = 0;
+
+            for (i, live_column_idxs, indexed_columns) in candidates {
+                let rg_idx = row_group_slice.start + i;
+                let rg = &metadata.row_groups[rg_idx];
+
+                let page_locations = indexed_columns
+                    .iter()
+                    .map(|&column_idx| {
+                        let range = rg.parquet_columns()[column_idx]
+                            .offset_index_byte_range()
+                            .unwrap();
+                        let offset_index =
+                            deserialize_offset_index(&bytes_map[&(range.start as usize)])?;
+                        Ok((column_idx, offset_index.page_locations))
+                    })
+                    .collect::<PolarsResult<PlHashMap<_, _>>>()?;
+
+                // Malformed offset indexes are ignored, the column chunk is then fetched entirely.
+                let page_locations = page_locations
+                    .into_iter()
+                    .filter(|(column_idx, locations)| {
+                        is_valid_offset_index(
+                            locations,
+                            &rg.parquet_columns()[*column_idx],
+                            rg.num_rows(),
+                        )
+                    })
+                    .collect::<PlHashMap<_, _>>();
+
+                // Split the row group into intervals at every page boundary of the live columns.
+                let mut boundaries = vec![0, rg.num_rows()];
+                for column_idx in live_column_idxs.iter().flatten() {
+                    if let Some(locations) = page_locations.get(column_idx) {
+                        boundaries.extend(locations.iter().map(|l| l.first_row_index as usize));
+                    }
+                }
+                boundaries.sort_unstable();
+                boundaries.dedup();
+                let intervals = boundaries
+                    .windows(2)
+                    .map(|w| w[0]..w[1])
+                    .collect::<Vec<_>>();
+
+                let statistics_df = page_statistics_df(
+                    rg,
+                    &intervals,
+                    &live_columns,
+                    &live_column_idxs,
+                    &page_locations,
+                    reader_schema.as_ref(),
+                    &bytes_map,
+                )?;
+                let skip_interval_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+                let mut rows: Vec<Range<usize>> = Vec::new();
+                for (interval, skip) in intervals.into_iter().zip(skip_interval_mask.iter()) {
+                    if skip {
+                        continue;
+                    }
+                    match rows.last_mut() {
+                        Some(last) if last.end == interval.start => last.end = interval.end,
+                        _ => rows.push(interval),
+                    }
+                }
+
+                let page_selection = PageSelection {
+                    rows,
+                    page_locations,
+                };
+                let num_rg_selected_rows = page_selection.num_rows();
+                num_rows += rg.num_rows();
+                num_selected_rows += num_rg_selected_rows;
+
+                if num_rg_selected_rows == 0 {
+                    mask.set(i, true);
+                } else if num_rg_selected_rows < rg.num_rows() {
+                    page_selections.insert(rg_idx, page_selection);
+                }
+            }
+
+            PolarsResult::Ok((mask.freeze(), page_selections, num_rows, num_selected_rows))
+        })
+        .await?;
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Page index pushdown: \
+                                reading {} / {} rows of {} row groups",
+            num_selected_rows,
+            num_rows,
+            page_selections.len(),
+        );
+    }
+
+    Ok((Some(mask), page_selections))
+}
+
+/// Creates a statistics DataFrame with a row for every interval of rows, using the statistics of
+/// the pages containing the intervals.
+fn page_statistics_df(
+    rg: &RowGroupMetadata,
+    intervals: &[Range<usize>],
+    live_columns: &PlIndexSet<PlSmallStr>,
+    live_column_idxs: &[Option<usize>],
+    page_locations: &PlHashMap<usize, Vec<PageLocation>>,
+    reader_schema: &ArrowSchema,
+    bytes_map: &PlHashMap<usize, MemSlice>,
+) -> PolarsResult<DataFrame> {
+    let num_intervals = intervals.len();
+
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = intervals.iter().map(|r| r.len() as IdxSize).collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for (c, column_idx) in live_columns.iter().zip(live_column_idxs) {
+        let field = reader_schema.get(c).unwrap();
+
+        let min_name = format_pl_smallstr!("{c}_min");
+        let max_name = format_pl_smallstr!("{c}_max");
+        let nc_name = format_pl_smallstr!("{c}_nc");
+
+        let stats = match column_idx.and_then(|idx| Some((idx, page_locations.get(&idx)?))) {
+            None => None,
+            Some((column_idx, locations)) => {
+                let column = &rg.parquet_columns()[column_idx];
+                let range = column.column_index_byte_range().unwrap();
+                let column_index = deserialize_column_index(&bytes_map[&(range.start as usize)])?;
+
+                deserialize_page_statistics(
+                    field,
+                    &column.descriptor().descriptor.primitive_type,
+                    &column_index,
+                )?
+                .filter(|stats| stats.null_count.len() == locations.len())
+                .map(|stats| (stats, locations))
+            },
+        };
+
+        let (min, max, nc) = match stats {
+            None => {
+                let dtype = DataType::from_arrow_field(field);
+
+                (
+                    Column::full_null(min_name, num_intervals, &dtype),
+                    Column::full_null(max_name, num_intervals, &dtype),
+                    Column::full_null(nc_name, num_intervals, &IDX_DTYPE),
+                )
+            },
+            Some((stats, locations)) => {
+                let md = field.metadata.as_deref();
+
+                // The index of the page that contains each interval.
+                let page_idxs = IdxCa::from_vec(
+                    PlSmallStr::EMPTY,
+                    intervals
+                        .iter()
+                        .map(|r| {
+                            (locations.partition_point(|l| l.first_row_index as usize <= r.start)
+                                - 1) as IdxSize
+                        })
+                        .collect(),
+                );
+
+                (
+                    unsafe {
+                        Series::_try_from_arrow_unchecked_with_md(
+                            min_name,
+                            vec![stats.min_value],
+                            field.dtype(),
+                            md,
+                        )
+                    }?
+                    .take(&page_idxs)?
+                    .into_column(),
+                    unsafe {
+                        Series::_try_from_arrow_unchecked_with_md(
+                            max_name,
+                            vec![stats.max_value],
+                            field.dtype(),
+                            md,
+                        )
+                    }?
+                    .take(&page_idxs)?
+                    .into_column(),
+                    Series::from_arrow(nc_name, stats.null_count.boxed())?
+                        .take(&page_idxs)?
+                        .into_column(),
+                )
+            },
+        };
+
+        columns.extend([min, max, nc]);
+    }
+
+    DataFrame::new_with_height(num_intervals, columns)
+}
+
+/// Checks that the pages of an offset index are in order, start at the first row and lie within
+/// the column chunk.
+fn is_valid_offset_index(
+    locations: &[PageLocation],
+    column: &ColumnChunkMetadata,
+    num_rows: usize,
+) -> bool {
+    let byte_range = column.byte_range();
+
+    locations.first().is_some_and(|l| l.first_row_index == 0)
+        && locations
+            .windows(2)
+            .all(|w| w[0].first_row_index < w[1].first_row_index && w[0].offset < w[1].offset)
+        && locations.iter().all(|l| {
+            (l.first_row_index as usize) < num_rows
+                && l.offset >= 0
+                && l.compressed_page_size >= 0
+                && (l.offset as u64) >= byte_range.start
+                && (l.offset as u64 + l.compressed_page_size as u64) <= byte_range.end
+        })
+}
@@ -13,6 +13,7 @@ use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_selection::{PageSelection, column_byte_ranges};
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -22,6 +23,8 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: PlHashMap<usize, IsSorted>,
+    /// If set, only the pages containing the selected rows were fetched.
+    pub(super) page_selection: Option<PageSelection>,
 }
 
 pub(super) struct RowGroupDataFetcher {
@@ -35,6 +38,8 @@ pub(super) struct RowGroupDataFetcher {
 
     pub(super) row_group_slice: Range<usize>,
     pub(super) row_group_mask: Option<Bitmap>,
+    /// Page selections by row group index.
+    pub(super) page_selections: PlHashMap<usize, PageSelection>,
 
     pub(super) row_offset: usize,
 }
@@ -76,6 +81,7 @@ impl RowGroupDataFetcher {
                 }
             }
 
+            let page_selection = self.page_selections.remove(&idx);
             let metadata = self.metadata.clone();
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
@@ -96,6 +102,14 @@ impl RowGroupDataFetcher {
                                 for range in get_row_group_byte_ranges_for_projection(
                                     row_group_metadata,
                                     &mut columns.iter_names(),
+                                    page_selection.as_ref(),
+                                ) {
+                                    memory_prefetch_func(unsafe { slice.get_unchecked(range) })
+                                }
+                            } else if page_selection.is_some() {
+                                for range in get_row_group_byte_ranges(
+                                    row_group_metadata,
+                                    page_selection.as_ref(),
                                 ) {
                                     memory_prefetch_func(unsafe { slice.get_unchecked(range) })
                                 }
@@ -119,6 +133,7 @@ impl RowGroupDataFetcher {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
                             &mut columns.iter_names(),
+                            page_selection.as_ref(),
                         )
                         .collect::<Vec<_>>();
 
@@ -135,10 +150,9 @@ impl RowGroupDataFetcher {
                         // would naively concatenate the memory blocks of the entire row group, while
                         // `get_ranges()` can skip concatenation since the downloaded blocks are
                         // aligned to the columns.
-                        let mut ranges = row_group_metadata
-                            .byte_ranges_iter()
-                            .map(|x| x.start as usize..x.end as usize)
-                            .collect::<Vec<_>>();
+                        let mut ranges =
+                            get_row_group_byte_ranges(row_group_metadata, page_selection.as_ref())
+                                .collect::<Vec<_>>();
 
                         let n_ranges = ranges.len();
 
@@ -156,6 +170,7 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
@@ -189,20 +204,29 @@ impl FetchedBytes {
     }
 }
 
+fn get_row_group_byte_ranges<'a>(
+    row_group_metadata: &'a RowGroupMetadata,
+    page_selection: Option<&'a PageSelection>,
+) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
+    (0..row_group_metadata.n_columns()).flat_map(move |column_idx| {
+        column_byte_ranges(row_group_metadata, column_idx, page_selection)
+    })
+}
+
 fn get_row_group_byte_ranges_for_projection<'a>(
     row_group_metadata: &'a RowGroupMetadata,
     columns: &'a mut dyn Iterator<Item = &PlSmallStr>,
+    page_selection: Option<&'a PageSelection>,
 ) -> impl Iterator<Item = std::ops::Range<usize>> + 'a {
-    columns.flat_map(|col_name| {
+    columns.flat_map(move |col_name| {
         row_group_metadata
-            .columns_under_root_iter(col_name)
+            .columns_idxs_under_root_iter(col_name)
             // `Option::into_iter` so that we return an empty iterator for the
             // `allow_missing_columns` case
             .into_iter()
             .flatten()
-            .map(|col| {
-                let byte_range = col.byte_range();
-                byte_range.start as usize..byte_range.end as usize
+            .flat_map(move |&column_idx| {
+                column_byte_ranges(row_group_metadata, column_idx, page_selection)
             })
     })
 }
@@ -20,6 +20,7 @@ use polars_utils::IdxSize;
 use polars_utils::index::AtomicIdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_selection::columns_to_deserialize;
 use super::row_group_data_fetch::RowGroupData;
 use crate::async_primitives::opt_spawned_future::parallelize_first_to_local;
 
@@ -50,6 +51,7 @@ impl RowGroupDecoder {
 
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_selection.is_none()
             && !self.predicate_arrow_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -79,15 +81,18 @@ impl RowGroupDecoder {
             out_columns.push(s);
         }
 
-        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        // The page selection is applied when decoding the columns.
+        let (projection_height, filter) = match row_group_data.page_selection.as_ref() {
+            Some(page_selection) => (page_selection.num_rows(), None),
+            None => (
+                slice_range.len(),
+                Some(polars_parquet::read::Filter::Range(slice_range.clone())),
+            ),
+        };
 
-        let projection_height = slice_range.len();
+        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, filter)
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -143,10 +148,23 @@ impl RowGroupDecoder {
             // The DataFrame can be empty at this point if no columns were projected from the file,
             // so we create the row index column manually instead of using `df.with_row_index` to
             // ensure it has the correct number of rows.
-            let mut ca = IdxCa::from_vec(
-                name.clone(),
-                (offset..offset + projection_height as IdxSize).collect(),
-            );
+            let mut ca = if let Some(page_selection) = row_group_data.page_selection.as_ref() {
+                debug_assert_eq!(slice_range.start, 0);
+
+                IdxCa::from_vec(
+                    name.clone(),
+                    page_selection
+                        .rows()
+                        .iter()
+                        .flat_map(|r| offset + r.start as IdxSize..offset + r.end as IdxSize)
+                        .collect(),
+                )
+            } else {
+                IdxCa::from_vec(
+                    name.clone(),
+                    (offset..offset + projection_height as IdxSize).collect(),
+                )
+            };
             ca.set_sorted_flag(IsSorted::Ascending);
 
             Ok(Some(ca.into_column()))
@@ -164,11 +182,16 @@ impl RowGroupDecoder {
         filter: Option<polars_parquet::read::Filter>,
     ) -> PolarsResult<()> {
         let projected_arrow_schema = &self.projected_arrow_schema;
-        let expected_num_rows = filter
-            .as_ref()
-            .map_or(row_group_data.row_group_metadata.num_rows(), |x| {
-                x.num_rows(row_group_data.row_group_metadata.num_rows())
-            });
+        let expected_num_rows = if let Some(page_selection) = row_group_data.page_selection.as_ref()
+        {
+            page_selection.num_rows()
+        } else {
+            filter
+                .as_ref()
+                .map_or(row_group_data.row_group_metadata.num_rows(), |x| {
+                    x.num_rows(row_group_data.row_group_metadata.num_rows())
+                })
+        };
 
         let Some((cols_per_thread, _)) = calc_cols_per_thread(
             row_group_data.row_group_metadata.num_rows(),
@@ -256,26 +279,46 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
+    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    let (array, pred_true_mask) = if let Some(page_selection) = &row_group_data.page_selection {
+        debug_assert!(filter.is_none());
 
-    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
+        let (columns_to_deserialize, mask) = columns_to_deserialize(
+            &row_group_data.row_group_metadata,
+            row_group_data
+                .row_group_metadata
+                .columns_idxs_under_root_iter(&arrow_field.name)
+                .unwrap(),
+            page_selection,
+            &row_group_data.fetched_bytes,
+        );
 
-    let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+        polars_io::prelude::_internal::to_deserializer_with_page_meta(
+            columns_to_deserialize,
+            arrow_field.clone(),
+            Some(Filter::Mask(mask)),
+        )?
+    } else {
+        let columns_to_deserialize = iter
+            .map(|col_md| {
+                let byte_range = col_md.byte_range();
+
+                (
+                    col_md,
+                    row_group_data
+                        .fetched_bytes
+                        .get_range(byte_range.start as usize..byte_range.end as usize),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        polars_io::prelude::_internal::to_deserializer(
+            columns_to_deserialize,
+            arrow_field.clone(),
+            filter,
+        )?
+    };
 
     if !skip_num_rows_check {
         assert_eq!(array.len(), expected_num_rows);
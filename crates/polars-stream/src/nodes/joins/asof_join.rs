@@ -0,0 +1,315 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::{AnyValue, Column};
+use polars_core::schema::Schema;
+use polars_error::polars_ensure;
+use polars_ops::frame::AsofStrategy;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::partition_point;
+use crate::async_primitives::connector::Receiver;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+/// The first and last non-null value of a sorted key column, or `None` if all
+/// values are null.
+fn key_bounds(key: &Column) -> PolarsResult<Option<(AnyValue<'static>, AnyValue<'static>)>> {
+    let key = key.to_physical_repr();
+    let null_count = key.null_count();
+    if null_count == key.len() {
+        return Ok(None);
+    }
+
+    // In a sorted column the nulls are either all at the start or all at the end.
+    let (start, end) = if key.get(0)?.is_null() {
+        (null_count, key.len())
+    } else {
+        (0, key.len() - null_count)
+    };
+    Ok(Some((
+        key.get(start)?.into_static(),
+        key.get(end - 1)?.into_static(),
+    )))
+}
+
+/// Receives all remaining morsels from a port after requesting it to stop.
+async fn recv_remaining(port: &mut Receiver<Morsel>) -> Vec<DataFrame> {
+    let mut out = Vec::new();
+    let Ok(morsel) = port.recv().await else {
+        return out;
+    };
+    morsel.source_token().stop();
+    out.push(morsel.into_df());
+    while let Ok(morsel) = port.recv().await {
+        out.push(morsel.into_df());
+    }
+    out
+}
+
+/// An as-of join over inputs which are sorted on their join key.
+///
+/// The left input is streamed through in order. A left morsel is joined as
+/// soon as the right input has advanced past its largest key, after which all
+/// right rows that can no longer be matched by any later left row are
+/// dropped. The actual joining of a morsel against the buffered right rows is
+/// done by the in-memory engine.
+///
+/// Joins with `by` groups only need their inputs to be sorted within each
+/// group, so they are left to the in-memory engine.
+pub struct AsOfJoinNode {
+    left_key: PlSmallStr,
+    right_key: PlSmallStr,
+    strategy: AsofStrategy,
+    check_sortedness: bool,
+    joiner: Arc<dyn Fn(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send + Sync>,
+
+    seq: MorselSeq,
+
+    /// Left morsels (and their largest key) waiting on more right input.
+    left_pending: VecDeque<(DataFrame, Option<AnyValue<'static>>)>,
+    left_max: Option<AnyValue<'static>>,
+
+    /// Right rows which can still be matched, sorted on the key, with null
+    /// keys removed. Newly received rows are kept apart until the next join.
+    right_buffer: DataFrame,
+    right_unmerged: Vec<DataFrame>,
+    right_max: Option<AnyValue<'static>>,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key: PlSmallStr,
+        right_key: PlSmallStr,
+        strategy: AsofStrategy,
+        check_sortedness: bool,
+        joiner: Arc<dyn Fn(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send + Sync>,
+    ) -> Self {
+        Self {
+            left_key,
+            right_key,
+            strategy,
+            check_sortedness,
+            joiner,
+            seq: MorselSeq::default(),
+            left_pending: VecDeque::new(),
+            left_max: None,
+            right_buffer: DataFrame::empty_with_schema(&right_input_schema),
+            right_unmerged: Vec::new(),
+            right_max: None,
+        }
+    }
+
+    fn push_left(&mut self, df: DataFrame) -> PolarsResult<()> {
+        let bounds = key_bounds(df.column(&self.left_key)?)?;
+        let mut max = None;
+        if let Some((first, last)) = bounds {
+            if self.check_sortedness {
+                polars_ensure!(
+                    self.left_max.as_ref().is_none_or(|prev| first >= *prev),
+                    InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+                );
+            }
+            self.left_max = Some(last.clone());
+            max = Some(last);
+        }
+        self.left_pending.push_back((df, max));
+        Ok(())
+    }
+
+    fn push_right(&mut self, df: DataFrame) -> PolarsResult<()> {
+        // Rows with a null key never match, so we don't keep them around.
+        let key = df.column(&self.right_key)?;
+        let df = if key.has_nulls() {
+            df.filter(&key.is_not_null())?
+        } else {
+            df
+        };
+
+        let Some((first, last)) = key_bounds(df.column(&self.right_key)?)? else {
+            return Ok(());
+        };
+        if self.check_sortedness {
+            polars_ensure!(
+                self.right_max.as_ref().is_none_or(|prev| first >= *prev),
+                InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+            );
+        }
+        self.right_max = Some(last);
+        self.right_unmerged.push(df);
+        Ok(())
+    }
+
+    /// Whether the first pending left morsel can be joined, that is, whether
+    /// no right row that is yet to come could be its match.
+    fn front_is_ready(&self, right_done: bool) -> PolarsResult<bool> {
+        let Some((_, left_max)) = self.left_pending.front() else {
+            return Ok(false);
+        };
+        if right_done {
+            return Ok(true);
+        }
+
+        match (left_max, &self.right_max) {
+            (None, _) => Ok(true),
+            (Some(l), Some(r)) => Ok(r > l),
+            (Some(_), None) => Ok(false),
+        }
+    }
+
+    fn join_front(&mut self) -> PolarsResult<DataFrame> {
+        let (left, left_max) = self.left_pending.pop_front().unwrap();
+        if !self.right_unmerged.is_empty() {
+            for df in self.right_unmerged.drain(..) {
+                self.right_buffer.vstack_mut_owned(df)?;
+            }
+            self.right_buffer.rechunk_mut();
+        }
+
+        let out = (self.joiner)(left, self.right_buffer.clone())?;
+        if let Some(left_max) = left_max {
+            self.prune_right(&left_max)?;
+        }
+        Ok(out)
+    }
+
+    /// Drops the right rows that can't be matched by any left row with a key
+    /// of at least `left_max`.
+    fn prune_right(&mut self, left_max: &AnyValue<'_>) -> PolarsResult<()> {
+        let right_key = self
+            .right_buffer
+            .column(&self.right_key)?
+            .to_physical_repr();
+        let offset = partition_point(&right_key, |v| v < left_max)?;
+        if offset == 0 {
+            return Ok(());
+        }
+
+        self.right_buffer = match self.strategy {
+            AsofStrategy::Forward => self.right_buffer.slice(offset as i64, usize::MAX),
+            _ => self.right_buffer.slice(offset as i64 - 1, usize::MAX),
+        };
+        Ok(())
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Every output row comes from a left row, so we're done as soon as the
+        // left side is.
+        let left_done = recv[0] == PortState::Done && self.left_pending.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.left_pending.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let mut send = send_ports[0].take().unwrap().serial();
+        let mut left = recv_ports[0].take().map(|p| p.serial());
+        let mut right = recv_ports[1].take().map(|p| p.serial());
+
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let source_token = SourceToken::new();
+
+            loop {
+                while self.front_is_ready(right.is_none())? {
+                    let df = self.join_front()?;
+                    let morsel = Morsel::new(df, self.seq, source_token.clone());
+                    self.seq = self.seq.successor();
+                    if send.send(morsel).await.is_err() {
+                        return Ok(());
+                    }
+                }
+
+                // Left morsels waiting on the right side block reading more
+                // from the left side.
+                let recv_left = self.left_pending.is_empty();
+                let port = if recv_left {
+                    left.as_mut()
+                } else {
+                    right.as_mut()
+                };
+                let Some(port) = port else {
+                    break;
+                };
+
+                let received = if source_token.stop_requested() {
+                    None
+                } else {
+                    port.recv().await.ok()
+                };
+                let Some(morsel) = received else {
+                    // Buffer everything that was already produced, so the
+                    // phase can end.
+                    if let Some(p) = &mut left {
+                        for df in recv_remaining(p).await {
+                            self.push_left(df)?;
+                        }
+                    }
+                    if let Some(p) = &mut right {
+                        for df in recv_remaining(p).await {
+                            self.push_right(df)?;
+                        }
+                    }
+                    break;
+                };
+
+                if recv_left {
+                    self.push_left(morsel.into_df())?;
+                } else {
+                    self.push_right(morsel.into_df())?;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
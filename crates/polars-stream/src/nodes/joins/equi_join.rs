@@ -1,5 +1,5 @@
 use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
@@ -22,7 +22,7 @@ use polars_utils::sparse_init_vec::SparseInitVec;
 use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
-use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
+use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR, sample_morsels};
 use crate::async_executor;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::async_primitives::wait_group::WaitGroup;
@@ -30,6 +30,18 @@ use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    PartitionedSpiller, SpillDir, SpilledDataFrame, merge_spilled_partitions,
+};
+
+/// The number of partitions both sides are split into when the build side
+/// doesn't fit in memory. The partitions are joined one at a time.
+const NUM_SPILL_PARTITIONS: usize = 64;
+
+/// How often a spilled partition whose build side still doesn't fit in memory
+/// is split again. Partitions that can't be split, e.g. because all their rows
+/// have the same key, are joined in memory after this many levels.
+const MAX_SPILL_DEPTH: usize = 4;
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -46,6 +58,11 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+
+    // The memory budget for the build side, None if this join may not spill.
+    build_memory_budget: Option<usize>,
+    spill_partitioner: HashPartitioner,
+    spill_dir: SpillDir,
 }
 
 impl EquiJoinParams {
@@ -235,31 +252,6 @@ struct SampleState {
 }
 
 impl SampleState {
-    async fn sink(
-        mut recv: Receiver<Morsel>,
-        morsels: &mut Vec<Morsel>,
-        len: &mut usize,
-        this_final_len: Arc<AtomicUsize>,
-        other_final_len: Arc<AtomicUsize>,
-    ) -> PolarsResult<()> {
-        while let Ok(mut morsel) = recv.recv().await {
-            *len += morsel.df().height();
-            if *len >= *JOIN_SAMPLE_LIMIT
-                || *len
-                    >= other_final_len
-                        .load(Ordering::Relaxed)
-                        .saturating_mul(LOPSIDED_SAMPLE_FACTOR)
-            {
-                morsel.source_token().stop();
-            }
-
-            drop(morsel.take_consume_token());
-            morsels.push(morsel);
-        }
-        this_final_len.store(*len, Ordering::Relaxed);
-        Ok(())
-    }
-
     fn try_transition_to_build(
         &mut self,
         recv: &[PortState],
@@ -340,43 +332,12 @@ impl SampleState {
             core::mem::swap(&mut sampled_build_morsels, &mut sampled_probe_morsels);
         }
 
-        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
         let mut build_state = BuildState::new(
             state.num_pipelines,
             state.num_pipelines,
             sampled_probe_morsels,
         );
-
-        // Simulate the sample build morsels flowing into the build side.
-        if !sampled_build_morsels.is_empty() {
-            crate::async_executor::task_scope(|scope| {
-                let mut join_handles = Vec::new();
-                let receivers = sampled_build_morsels
-                    .reinsert(state.num_pipelines, None, scope, &mut join_handles)
-                    .unwrap();
-
-                for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
-                    join_handles.push(scope.spawn_task(
-                        TaskPriority::High,
-                        BuildState::partition_and_sink(
-                            recv,
-                            local_builder,
-                            partitioner.clone(),
-                            params,
-                            state,
-                        ),
-                    ));
-                }
-
-                polars_io::pl_async::get_runtime().block_on(async move {
-                    for handle in join_handles {
-                        handle.await?;
-                    }
-                    PolarsResult::Ok(())
-                })
-            })?;
-        }
-
+        build_state.sink_buffered(sampled_build_morsels, true, params, state)?;
         Ok(Some(build_state))
     }
 }
@@ -395,6 +356,37 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // If this join may spill we keep the original morsels around so they can
+    // be partitioned to disk once we exceed our share of the memory budget,
+    // after which all further morsels go to the spiller.
+    raw_morsels: Vec<DataFrame>,
+    mem_bytes: usize,
+    spiller: Option<PartitionedSpiller>,
+}
+
+impl LocalBuilder {
+    /// Moves all morsels seen so far to disk, and spills all further morsels.
+    fn start_spilling(&mut self, params: &EquiJoinParams) -> PolarsResult<()> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let spiller = self
+            .spiller
+            .get_or_insert_with(|| PartitionedSpiller::new(params.spill_partitioner.clone()));
+        for ((_seq, _payload, hash_keys), df) in
+            self.morsels.drain(..).zip(self.raw_morsels.drain(..))
+        {
+            spiller.spill(&df, &hash_keys, track_unmatchable, &params.spill_dir)?;
+        }
+
+        let num_partitions = self.sketch_per_p.len();
+        self.sketch_per_p = vec![CardinalitySketch::default(); num_partitions];
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.truncate(num_partitions);
+        self.mem_bytes = 0;
+        Ok(())
+    }
 }
 
 struct BuildState {
@@ -414,6 +406,9 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                raw_morsels: Vec::new(),
+                mem_bytes: 0,
+                spiller: None,
             })
             .collect();
         Self {
@@ -422,14 +417,62 @@ impl BuildState {
         }
     }
 
+    /// Sinks buffered morsels into the build side as if they came from the
+    /// build input.
+    fn sink_buffered(
+        &mut self,
+        morsels: BufferedStream,
+        may_spill: bool,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        if morsels.is_empty() {
+            return Ok(());
+        }
+
+        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+        crate::async_executor::task_scope(|scope| {
+            let mut join_handles = Vec::new();
+            let receivers = morsels
+                .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                .unwrap();
+
+            for (local_builder, recv) in self.local_builders.iter_mut().zip(receivers) {
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::High,
+                    BuildState::partition_and_sink(
+                        recv,
+                        local_builder,
+                        partitioner.clone(),
+                        may_spill,
+                        params,
+                        state,
+                    ),
+                ));
+            }
+
+            polars_io::pl_async::get_runtime().block_on(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            })
+        })
+    }
+
     async fn partition_and_sink(
         mut recv: Receiver<Morsel>,
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
+        may_spill: bool,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
+        let local_memory_budget = match params.build_memory_budget {
+            Some(budget) if may_spill => budget / state.num_pipelines,
+            _ => usize::MAX,
+        };
         let (key_selectors, payload_selector);
         if params.left_is_build.unwrap() {
             payload_selector = &params.left_payload_select;
@@ -449,6 +492,16 @@ impl BuildState {
                 &state.in_memory_exec_state,
             )
             .await?;
+            if let Some(spiller) = &mut local.spiller {
+                spiller.spill(
+                    morsel.df(),
+                    &hash_keys,
+                    track_unmatchable,
+                    &params.spill_dir,
+                )?;
+                continue;
+            }
+
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -462,11 +515,42 @@ impl BuildState {
             local
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-            local.morsels.push((morsel.seq(), payload, hash_keys));
+            let seq = morsel.seq();
+            if local_memory_budget < usize::MAX {
+                local.mem_bytes += payload.estimated_size();
+                local.raw_morsels.push(morsel.into_df());
+            }
+            local.morsels.push((seq, payload, hash_keys));
+
+            if local.mem_bytes >= local_memory_budget {
+                local.start_spilling(params)?;
+            }
         }
         Ok(())
     }
 
+    /// Whether any of the builders ran out of memory, in which case we
+    /// perform a grace hash join.
+    fn has_spilled(&self) -> bool {
+        self.local_builders.iter().any(|l| l.spiller.is_some())
+    }
+
+    /// Moves the entire build side to disk, partitioned by the spill
+    /// partitioner.
+    fn spill_all(&mut self, params: &EquiJoinParams) -> PolarsResult<Vec<Vec<SpilledDataFrame>>> {
+        let spilled = POOL.install(|| {
+            self.local_builders
+                .par_iter_mut()
+                .with_max_len(1)
+                .map(|l| {
+                    l.start_spilling(params)?;
+                    l.spiller.as_mut().unwrap().take_spilled(&params.spill_dir)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(merge_spilled_partitions(spilled))
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -1127,9 +1211,197 @@ impl EmitUnmatchedState {
     }
 }
 
+/// Partitions the probe side to disk after the build side was spilled.
+struct SpillProbeState {
+    build_per_p: Vec<Vec<SpilledDataFrame>>,
+    spillers: Vec<PartitionedSpiller>,
+}
+
+impl SpillProbeState {
+    /// Spills buffered morsels as if they came from the probe input.
+    fn spill_buffered(
+        &mut self,
+        morsels: BufferedStream,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        if morsels.is_empty() {
+            return Ok(());
+        }
+
+        crate::async_executor::task_scope(|scope| {
+            let mut join_handles = Vec::new();
+            let receivers = morsels
+                .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                .unwrap();
+
+            for (spiller, recv) in self.spillers.iter_mut().zip(receivers) {
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::High,
+                    SpillProbeState::partition_and_spill(recv, spiller, params, state),
+                ));
+            }
+
+            polars_io::pl_async::get_runtime().block_on(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            })
+        })
+    }
+
+    async fn partition_and_spill(
+        mut recv: Receiver<Morsel>,
+        spiller: &mut PartitionedSpiller,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let emit_unmatched = params.emit_unmatched_probe();
+        let key_selectors = if params.left_is_build.unwrap() {
+            &params.right_key_selectors
+        } else {
+            &params.left_key_selectors
+        };
+
+        while let Ok(morsel) = recv.recv().await {
+            let hash_keys = select_keys(
+                morsel.df(),
+                key_selectors,
+                params,
+                &state.in_memory_exec_state,
+            )
+            .await?;
+            spiller.spill(morsel.df(), &hash_keys, emit_unmatched, &params.spill_dir)?;
+        }
+        Ok(())
+    }
+
+    fn into_grace_state(mut self, params: &EquiJoinParams) -> PolarsResult<GraceState> {
+        let probe_per_p = merge_spilled_partitions(
+            self.spillers
+                .iter_mut()
+                .map(|s| s.take_spilled(&params.spill_dir))
+                .collect::<PolarsResult<Vec<_>>>()?,
+        );
+        Ok(GraceState {
+            partitions: self
+                .build_per_p
+                .into_iter()
+                .zip(probe_per_p)
+                .map(|(build, probe)| GracePartition {
+                    build,
+                    probe,
+                    depth: 0,
+                })
+                .collect(),
+            next_seq: MorselSeq::default(),
+        })
+    }
+}
+
+/// A spilled partition of both sides of a grace hash join.
+struct GracePartition {
+    build: Vec<SpilledDataFrame>,
+    probe: Vec<SpilledDataFrame>,
+    // The number of times the rows of this partition were split again.
+    depth: usize,
+}
+
+impl GracePartition {
+    /// Splits this partition into `num_partitions` partitions, using a
+    /// different hash seed than the partitioning that produced it.
+    fn repartition(
+        self,
+        num_partitions: usize,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Vec<GracePartition>> {
+        let partitioner = HashPartitioner::new(num_partitions, self.depth as u64 + 2);
+        let (build_key_selectors, probe_key_selectors) = if params.left_is_build.unwrap() {
+            (&params.left_key_selectors, &params.right_key_selectors)
+        } else {
+            (&params.right_key_selectors, &params.left_key_selectors)
+        };
+
+        let build_per_p = repartition_spilled(
+            self.build,
+            build_key_selectors,
+            params.emit_unmatched_build(),
+            &partitioner,
+            params,
+            state,
+        )?;
+        let probe_per_p = repartition_spilled(
+            self.probe,
+            probe_key_selectors,
+            params.emit_unmatched_probe(),
+            &partitioner,
+            params,
+            state,
+        )?;
+        Ok(build_per_p
+            .into_iter()
+            .zip(probe_per_p)
+            .map(|(build, probe)| GracePartition {
+                build,
+                probe,
+                depth: self.depth + 1,
+            })
+            .collect())
+    }
+}
+
+/// Loads the spilled frames one at a time and spills them again, partitioned
+/// by the hash of their keys.
+fn repartition_spilled(
+    spilled: Vec<SpilledDataFrame>,
+    key_selectors: &[StreamExpr],
+    partition_nulls: bool,
+    partitioner: &HashPartitioner,
+    params: &EquiJoinParams,
+    state: &StreamingExecutionState,
+) -> PolarsResult<Vec<Vec<SpilledDataFrame>>> {
+    let runtime = get_runtime();
+    let mut spiller = PartitionedSpiller::new(partitioner.clone());
+    for s in spilled {
+        let df = s.load()?;
+        let hash_keys = runtime.block_on(select_keys(
+            &df,
+            key_selectors,
+            params,
+            &state.in_memory_exec_state,
+        ))?;
+        spiller.spill(&df, &hash_keys, partition_nulls, &params.spill_dir)?;
+    }
+    spiller.take_spilled(&params.spill_dir)
+}
+
+/// The spilled partitions of a grace hash join that remain to be joined.
+struct GraceState {
+    partitions: VecDeque<GracePartition>,
+    next_seq: MorselSeq,
+}
+
+fn load_spilled_morsels(spilled: Vec<SpilledDataFrame>) -> PolarsResult<Vec<Morsel>> {
+    POOL.install(|| {
+        spilled
+            .into_par_iter()
+            .map(|s| {
+                Ok(Morsel::new(
+                    s.load()?,
+                    MorselSeq::default(),
+                    SourceToken::new(),
+                ))
+            })
+            .collect()
+    })
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
+    SpillProbe(SpillProbeState),
     Probe(ProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
@@ -1140,6 +1412,7 @@ pub struct EquiJoinNode {
     state: EquiJoinState,
     params: EquiJoinParams,
     table: Box<dyn IdxTable>,
+    grace: Option<GraceState>,
 }
 
 impl EquiJoinNode {
@@ -1154,6 +1427,7 @@ impl EquiJoinNode {
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
         num_pipelines: usize,
+        memory_budget: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
             MaintainOrderJoin::None => {
@@ -1188,6 +1462,11 @@ impl EquiJoinNode {
             &args,
         )?;
 
+        // Spilling the build side requires partitioning both sides, which
+        // loses the order of the input.
+        let build_memory_budget =
+            (args.maintain_order == MaintainOrderJoin::None).then_some(memory_budget);
+
         let state = if left_is_build.is_some() {
             EquiJoinState::Build(BuildState::new(
                 num_pipelines,
@@ -1217,10 +1496,82 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                build_memory_budget,
+                spill_partitioner: HashPartitioner::new(NUM_SPILL_PARTITIONS, 1),
+                spill_dir: SpillDir::new("equi-join"),
             },
             table: new_idx_table(unique_key_schema),
+            grace: None,
         })
     }
+
+    /// Builds the hash table for the next spilled partition of a grace hash
+    /// join, skipping partitions that can't produce any output and splitting
+    /// partitions whose build side doesn't fit in the memory budget.
+    fn next_grace_partition(
+        &mut self,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<EquiJoinState> {
+        let grace = self.grace.as_mut().unwrap();
+        let budget = self.params.build_memory_budget.unwrap_or(usize::MAX);
+        while let Some(partition) = grace.partitions.pop_front() {
+            let has_output = match (partition.build.is_empty(), partition.probe.is_empty()) {
+                (false, false) => true,
+                (true, false) => self.params.emit_unmatched_probe(),
+                (false, true) => self.params.emit_unmatched_build(),
+                (true, true) => false,
+            };
+            if !has_output {
+                continue;
+            }
+
+            let build_bytes: usize = partition.build.iter().map(|s| s.bytes()).sum();
+            if build_bytes > budget && partition.depth < MAX_SPILL_DEPTH {
+                let num_partitions = build_bytes
+                    .div_ceil(budget.max(1))
+                    .clamp(2, NUM_SPILL_PARTITIONS);
+                if config::verbose() {
+                    eprintln!(
+                        "[equi-join]: spilled partition of {build_bytes} bytes exceeds memory budget, splitting it into {num_partitions} partitions"
+                    );
+                }
+                let partitions = partition.repartition(num_partitions, &self.params, state)?;
+                for p in partitions.into_iter().rev() {
+                    grace.partitions.push_front(p);
+                }
+                continue;
+            }
+
+            let GracePartition { build, probe, .. } = partition;
+
+            let mut build_state = BuildState::new(
+                state.num_pipelines,
+                state.num_pipelines,
+                BufferedStream::default(),
+            );
+            build_state.sink_buffered(
+                BufferedStream::new(load_spilled_morsels(build)?, MorselSeq::default()),
+                false,
+                &self.params,
+                state,
+            )?;
+            let mut probe_state = build_state.finalize_unordered(&self.params, &*self.table);
+            // The counter is passed to MorselSeq::new, which doubles it.
+            probe_state.unordered_morsel_seq = AtomicU64::new(grace.next_seq.to_u64() / 2);
+            if probe.is_empty() {
+                return Ok(EquiJoinState::EmitUnmatchedBuild(EmitUnmatchedState {
+                    partitions: core::mem::take(&mut probe_state.table_per_partition),
+                    active_partition_idx: 0,
+                    offset_in_active_p: 0,
+                    morsel_seq: grace.next_seq,
+                }));
+            }
+            probe_state.sampled_probe_morsels =
+                BufferedStream::new(load_spilled_morsels(probe)?, MorselSeq::default());
+            return Ok(EquiJoinState::Probe(probe_state));
+        }
+        Ok(EquiJoinState::Done)
+    }
 }
 
 impl ComputeNode for EquiJoinNode {
@@ -1257,9 +1608,26 @@ impl ComputeNode for EquiJoinNode {
         };
         let probe_idx = 1 - build_idx;
 
-        // If we are building and the build input is done, transition to probing.
+        // If we are building and the build input is done, transition to probing,
+        // or to spilling the probe side if the build side didn't fit in memory.
         if let EquiJoinState::Build(build_state) = &mut self.state {
-            if recv[build_idx] == PortState::Done {
+            if recv[build_idx] == PortState::Done && build_state.has_spilled() {
+                if config::verbose() {
+                    eprintln!("[equi-join]: build side exceeds memory budget, spilling both sides");
+                }
+                let mut spill_state = SpillProbeState {
+                    build_per_p: build_state.spill_all(&self.params)?,
+                    spillers: (0..state.num_pipelines)
+                        .map(|_| PartitionedSpiller::new(self.params.spill_partitioner.clone()))
+                        .collect(),
+                };
+                spill_state.spill_buffered(
+                    core::mem::take(&mut build_state.sampled_probe_morsels),
+                    &self.params,
+                    state,
+                )?;
+                self.state = EquiJoinState::SpillProbe(spill_state);
+            } else if recv[build_idx] == PortState::Done {
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -1269,11 +1637,28 @@ impl ComputeNode for EquiJoinNode {
             }
         }
 
+        // If the probe side is spilled, join the spilled partitions one by one.
+        if let EquiJoinState::SpillProbe(_) = &self.state {
+            if recv[probe_idx] == PortState::Done {
+                let EquiJoinState::SpillProbe(spill_state) =
+                    core::mem::replace(&mut self.state, EquiJoinState::Done)
+                else {
+                    unreachable!()
+                };
+                self.grace = Some(spill_state.into_grace_state(&self.params)?);
+                self.state = self.next_grace_partition(state)?;
+            }
+        }
+
         // If we are probing and the probe input is done, emit unmatched if
-        // necessary, otherwise we're done.
+        // necessary, otherwise we're done (with this partition).
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
             if samples_consumed && recv[probe_idx] == PortState::Done {
+                if let Some(grace) = &mut self.grace {
+                    grace.next_seq = grace.next_seq.max(probe_state.max_seq_sent.successor());
+                }
+
                 if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
@@ -1283,13 +1668,21 @@ impl ComputeNode for EquiJoinNode {
                         );
                         self.state = EquiJoinState::EmitUnmatchedBuildInOrder(src);
                     } else {
+                        let morsel_seq = match &self.grace {
+                            Some(grace) => grace.next_seq,
+                            None => probe_state.max_seq_sent.successor(),
+                        };
                         self.state = EquiJoinState::EmitUnmatchedBuild(EmitUnmatchedState {
                             partitions: core::mem::take(&mut probe_state.table_per_partition),
                             active_partition_idx: 0,
                             offset_in_active_p: 0,
-                            morsel_seq: probe_state.max_seq_sent.successor(),
+                            morsel_seq,
                         });
                     }
+                } else if self.grace.is_some() {
+                    // Free this partition before loading the next one.
+                    self.state = EquiJoinState::Done;
+                    self.state = self.next_grace_partition(state)?;
                 } else {
                     self.state = EquiJoinState::Done;
                 }
@@ -1299,7 +1692,13 @@ impl ComputeNode for EquiJoinNode {
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
-                self.state = EquiJoinState::Done;
+                if let Some(grace) = &mut self.grace {
+                    grace.next_seq = grace.next_seq.max(emit_state.morsel_seq);
+                    self.state = EquiJoinState::Done;
+                    self.state = self.next_grace_partition(state)?;
+                } else {
+                    self.state = EquiJoinState::Done;
+                }
             }
         }
 
@@ -1330,6 +1729,13 @@ impl ComputeNode for EquiJoinNode {
                     recv[probe_idx] = PortState::Blocked;
                 }
             },
+            EquiJoinState::SpillProbe(_) => {
+                send[0] = PortState::Blocked;
+                recv[build_idx] = PortState::Done;
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Ready;
+                }
+            },
             EquiJoinState::Probe(probe_state) => {
                 if recv[probe_idx] != PortState::Done {
                     core::mem::swap(&mut send[0], &mut recv[probe_idx]);
@@ -1407,7 +1813,7 @@ impl ComputeNode for EquiJoinNode {
                 if let Some(left_recv) = recv_ports[0].take() {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
-                        SampleState::sink(
+                        sample_morsels(
                             left_recv.serial(),
                             &mut sample_state.left,
                             &mut sample_state.left_len,
@@ -1419,7 +1825,7 @@ impl ComputeNode for EquiJoinNode {
                 if let Some(right_recv) = recv_ports[1].take() {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
-                        SampleState::sink(
+                        sample_morsels(
                             right_recv.serial(),
                             &mut sample_state.right,
                             &mut sample_state.right_len,
@@ -1442,12 +1848,24 @@ impl ComputeNode for EquiJoinNode {
                             recv,
                             local_builder,
                             partitioner.clone(),
+                            true,
                             &self.params,
                             state,
                         ),
                     ));
                 }
             },
+            EquiJoinState::SpillProbe(spill_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+                for (spiller, recv) in spill_state.spillers.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        SpillProbeState::partition_and_spill(recv, spiller, &self.params, state),
+                    ));
+                }
+            },
             EquiJoinState::Probe(probe_state) => {
                 assert!(recv_ports[build_idx].is_none());
                 let senders = send_ports[0].take().unwrap().parallel();
@@ -0,0 +1,563 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+use polars_ops::frame::{
+    DataFrameJoinOps, IEJoinOptions, InequalityOperator, JoinArgs, JoinTypeOptions,
+};
+use polars_utils::itertools::Itertools;
+
+use super::{
+    BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR, partition_point, sample_morsels,
+};
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::expression::StreamExpr;
+use crate::nodes::compute_node_prelude::*;
+
+struct IEJoinParams {
+    left_is_build: Option<bool>,
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    args: JoinArgs,
+    options: IEJoinOptions,
+}
+
+impl IEJoinParams {
+    fn build_key_selectors(&self) -> &[StreamExpr] {
+        if self.left_is_build.unwrap() {
+            &self.left_key_selectors
+        } else {
+            &self.right_key_selectors
+        }
+    }
+
+    fn probe_key_selectors(&self) -> &[StreamExpr] {
+        if self.left_is_build.unwrap() {
+            &self.right_key_selectors
+        } else {
+            &self.left_key_selectors
+        }
+    }
+
+    /// The operator `op` of a predicate `left op right`, as seen from the
+    /// build side, i.e. such that the predicate is `build op' probe`.
+    fn build_operator(&self, op: InequalityOperator) -> InequalityOperator {
+        use InequalityOperator::*;
+        if self.left_is_build.unwrap() {
+            return op;
+        }
+        match op {
+            Lt => Gt,
+            LtEq => GtEq,
+            Gt => Lt,
+            GtEq => LtEq,
+        }
+    }
+}
+
+fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Series>> {
+    let mut key_columns = Vec::new();
+    for selector in key_selectors {
+        key_columns.push(selector.evaluate_blocking(df, state)?);
+    }
+    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    Ok(keys
+        .take_columns()
+        .into_iter()
+        .map(Column::take_materialized_series)
+        .collect())
+}
+
+#[derive(Default)]
+struct SampleState {
+    left: Vec<Morsel>,
+    left_len: usize,
+    right: Vec<Morsel>,
+    right_len: usize,
+}
+
+impl SampleState {
+    fn try_transition_to_build(
+        &mut self,
+        recv: &[PortState],
+        params: &mut IEJoinParams,
+        num_pipelines: usize,
+    ) -> Option<BuildState> {
+        let left_saturated = self.left_len >= *JOIN_SAMPLE_LIMIT;
+        let right_saturated = self.right_len >= *JOIN_SAMPLE_LIMIT;
+        let left_done = recv[0] == PortState::Done || left_saturated;
+        let right_done = recv[1] == PortState::Done || right_saturated;
+        #[expect(clippy::nonminimal_bool)]
+        let stop_sampling = (left_done && right_done)
+            || (left_done && self.right_len >= LOPSIDED_SAMPLE_FACTOR * self.left_len)
+            || (right_done && self.left_len >= LOPSIDED_SAMPLE_FACTOR * self.right_len);
+        if !stop_sampling {
+            return None;
+        }
+
+        // Buffer the smaller side. A saturated side could be arbitrarily big.
+        let left_is_build = match (left_saturated, right_saturated) {
+            (false, true) => true,
+            (true, false) => false,
+            _ => self.left_len < self.right_len,
+        };
+
+        if config::verbose() {
+            eprintln!(
+                "[ie-join]: build side chosen: {}, sample lengths are: {} vs. {}",
+                if left_is_build { "left" } else { "right" },
+                self.left_len,
+                self.right_len
+            );
+        }
+
+        params.left_is_build = Some(left_is_build);
+        let mut sampled_build_morsels = core::mem::take(&mut self.left);
+        let mut sampled_probe_morsels = core::mem::take(&mut self.right);
+        if !left_is_build {
+            core::mem::swap(&mut sampled_build_morsels, &mut sampled_probe_morsels);
+        }
+
+        let mut frames_per_pipeline = vec![Vec::new(); num_pipelines];
+        frames_per_pipeline[0] = sampled_build_morsels
+            .into_iter()
+            .map(Morsel::into_df)
+            .collect();
+        Some(BuildState {
+            frames_per_pipeline,
+            sampled_probe_morsels: BufferedStream::new(sampled_probe_morsels, MorselSeq::default()),
+        })
+    }
+}
+
+struct BuildState {
+    frames_per_pipeline: Vec<Vec<DataFrame>>,
+    sampled_probe_morsels: BufferedStream,
+}
+
+impl BuildState {
+    async fn sink(mut recv: Receiver<Morsel>, frames: &mut Vec<DataFrame>) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            frames.push(morsel.into_df());
+        }
+        Ok(())
+    }
+
+    /// Sorts the build side on its first key. Returns `None` if the build
+    /// side can't match anything.
+    fn finalize(
+        &mut self,
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<Option<ProbeState>> {
+        let frames = core::mem::take(&mut self.frames_per_pipeline);
+        let Some(mut df) =
+            accumulate_dataframes_vertical_unchecked_optional(frames.into_iter().flatten())
+        else {
+            return Ok(None);
+        };
+        df.rechunk_mut();
+        let mut keys = select_keys(
+            &df,
+            params.build_key_selectors(),
+            &state.in_memory_exec_state,
+        )?;
+
+        // Rows with a null key never match.
+        if keys.iter().any(|k| k.has_nulls()) {
+            let mask = keys
+                .iter()
+                .map(|k| k.is_not_null())
+                .reduce(|a, b| &a & &b)
+                .unwrap();
+            df = df.filter(&mask)?;
+            keys = keys.iter().map(|k| k.filter(&mask)).try_collect_vec()?;
+        }
+        if df.height() == 0 {
+            return Ok(None);
+        }
+
+        let order = keys[0].arg_sort(SortOptions::default().with_maintain_order(true));
+        // SAFETY: the sort indices are in-bounds.
+        unsafe {
+            df = df.take_unchecked(&order);
+            keys = keys.iter().map(|k| k.take_unchecked(&order)).collect();
+        }
+        keys[0].set_sorted_flag(IsSorted::Ascending);
+        let sorted_x = keys[0].to_physical_repr().into_owned().into_column();
+
+        Ok(Some(ProbeState {
+            build: BuildSide { df, keys, sorted_x },
+            sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
+        }))
+    }
+}
+
+/// The buffered build side, sorted on its first key without null keys.
+struct BuildSide {
+    df: DataFrame,
+    keys: Vec<Series>,
+    sorted_x: Column,
+}
+
+impl BuildSide {
+    /// Selects the build rows which could match any of the probe rows, judged
+    /// by the range of the probe keys.
+    fn candidates(
+        &self,
+        probe_keys: &[Series],
+        params: &IEJoinParams,
+    ) -> PolarsResult<Option<(DataFrame, Vec<Series>)>> {
+        use InequalityOperator::*;
+
+        let probe_x = probe_keys[0].to_physical_repr();
+        let (min, max) = (probe_x.min_reduce()?, probe_x.max_reduce()?);
+        if min.is_null() {
+            return Ok(None);
+        }
+        let (min, max) = (min.value(), max.value());
+
+        // The build side is sorted on the first key, so the candidates for the
+        // first predicate form a contiguous range.
+        let (start, end) = match params.build_operator(params.options.operator1) {
+            Lt => (0, partition_point(&self.sorted_x, |v| v < max)?),
+            LtEq => (0, partition_point(&self.sorted_x, |v| v <= max)?),
+            Gt => (
+                partition_point(&self.sorted_x, |v| v <= min)?,
+                self.df.height(),
+            ),
+            GtEq => (
+                partition_point(&self.sorted_x, |v| v < min)?,
+                self.df.height(),
+            ),
+        };
+        if start >= end {
+            return Ok(None);
+        }
+        let mut df = self.df.slice(start as i64, end - start);
+        let mut keys = self
+            .keys
+            .iter()
+            .map(|k| k.slice(start as i64, end - start))
+            .collect_vec();
+
+        if let Some(operator2) = params.options.operator2 {
+            let probe_y = probe_keys[1].to_physical_repr();
+            let (min, max) = (probe_y.min_reduce()?, probe_y.max_reduce()?);
+            if min.is_null() {
+                return Ok(None);
+            }
+            let build_y = keys[1].to_physical_repr();
+            let mask = match params.build_operator(operator2) {
+                Lt => build_y.lt(&max.into_series(PlSmallStr::EMPTY))?,
+                LtEq => build_y.lt_eq(&max.into_series(PlSmallStr::EMPTY))?,
+                Gt => build_y.gt(&min.into_series(PlSmallStr::EMPTY))?,
+                GtEq => build_y.gt_eq(&min.into_series(PlSmallStr::EMPTY))?,
+            };
+            df = df.filter(&mask)?;
+            keys = keys.iter().map(|k| k.filter(&mask)).try_collect_vec()?;
+        }
+
+        Ok((df.height() > 0).then_some((df, keys)))
+    }
+}
+
+struct ProbeState {
+    build: BuildSide,
+    sampled_probe_morsels: BufferedStream,
+}
+
+impl ProbeState {
+    async fn probe(
+        mut recv: Receiver<Morsel>,
+        mut send: Sender<Morsel>,
+        build: &BuildSide,
+        params: &IEJoinParams,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(mut morsel) = recv.recv().await {
+            let probe_keys = select_keys(morsel.df(), params.probe_key_selectors(), state)?;
+            let Some((build_df, build_keys)) = build.candidates(&probe_keys, params)? else {
+                continue;
+            };
+
+            let probe_df = morsel.df();
+            let (left, right, left_keys, right_keys) = if params.left_is_build.unwrap() {
+                (&build_df, probe_df, build_keys, probe_keys)
+            } else {
+                (probe_df, &build_df, probe_keys, build_keys)
+            };
+            let out = left._join_impl(
+                right,
+                left_keys,
+                right_keys,
+                params.args.clone(),
+                Some(JoinTypeOptions::IEJoin(params.options.clone())),
+                false,
+                false,
+            )?;
+            if out.height() == 0 {
+                continue;
+            }
+
+            *morsel.df_mut() = out;
+            if send.send(morsel).await.is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+enum IEJoinState {
+    Sample(SampleState),
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+/// An inner join on one or two inequality predicates.
+///
+/// The smaller input is buffered and sorted on its first key, after which the
+/// other input is streamed through. Each probe morsel is joined against the
+/// range of build rows its keys can possibly match.
+pub struct IEJoinNode {
+    state: IEJoinState,
+    params: IEJoinParams,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        mut args: JoinArgs,
+        options: IEJoinOptions,
+    ) -> Self {
+        // Slicing is done by a separate node.
+        args.slice = None;
+
+        Self {
+            state: IEJoinState::Sample(SampleState::default()),
+            params: IEJoinParams {
+                left_is_build: None,
+                left_key_selectors,
+                right_key_selectors,
+                args,
+                options,
+            },
+        }
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "ie-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // If we are sampling and both sides are done/filled, transition to building.
+        if let IEJoinState::Sample(sample_state) = &mut self.state {
+            if let Some(build_state) =
+                sample_state.try_transition_to_build(recv, &mut self.params, state.num_pipelines)
+            {
+                self.state = IEJoinState::Build(build_state);
+            }
+        }
+
+        let build_idx = if self.params.left_is_build == Some(true) {
+            0
+        } else {
+            1
+        };
+        let probe_idx = 1 - build_idx;
+
+        // If we are building and the build input is done, transition to probing.
+        if let IEJoinState::Build(build_state) = &mut self.state {
+            if recv[build_idx] == PortState::Done {
+                self.state = match build_state.finalize(&self.params, state)? {
+                    Some(probe_state) => IEJoinState::Probe(probe_state),
+                    None => IEJoinState::Done,
+                };
+            }
+        }
+
+        // If we are probing and the probe input is done, we're done.
+        if let IEJoinState::Probe(probe_state) = &mut self.state {
+            let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
+            if samples_consumed && recv[probe_idx] == PortState::Done {
+                self.state = IEJoinState::Done;
+            }
+        }
+
+        match &mut self.state {
+            IEJoinState::Sample(sample_state) => {
+                send[0] = PortState::Blocked;
+                if recv[0] != PortState::Done {
+                    recv[0] = if sample_state.left_len < *JOIN_SAMPLE_LIMIT {
+                        PortState::Ready
+                    } else {
+                        PortState::Blocked
+                    };
+                }
+                if recv[1] != PortState::Done {
+                    recv[1] = if sample_state.right_len < *JOIN_SAMPLE_LIMIT {
+                        PortState::Ready
+                    } else {
+                        PortState::Blocked
+                    };
+                }
+            },
+            IEJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                if recv[build_idx] != PortState::Done {
+                    recv[build_idx] = PortState::Ready;
+                }
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Blocked;
+                }
+            },
+            IEJoinState::Probe(probe_state) => {
+                if recv[probe_idx] != PortState::Done {
+                    core::mem::swap(&mut send[0], &mut recv[probe_idx]);
+                } else {
+                    let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
+                    send[0] = if samples_consumed {
+                        PortState::Done
+                    } else {
+                        PortState::Ready
+                    };
+                }
+                recv[build_idx] = PortState::Done;
+            },
+            IEJoinState::Done => {
+                send[0] = PortState::Done;
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(
+            self.state,
+            IEJoinState::Sample { .. } | IEJoinState::Build { .. }
+        )
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2);
+        assert!(send_ports.len() == 1);
+
+        let build_idx = if self.params.left_is_build == Some(true) {
+            0
+        } else {
+            1
+        };
+        let probe_idx = 1 - build_idx;
+
+        match &mut self.state {
+            IEJoinState::Sample(sample_state) => {
+                assert!(send_ports[0].is_none());
+                let left_final_len = Arc::new(AtomicUsize::new(if recv_ports[0].is_none() {
+                    sample_state.left_len
+                } else {
+                    usize::MAX
+                }));
+                let right_final_len = Arc::new(AtomicUsize::new(if recv_ports[1].is_none() {
+                    sample_state.right_len
+                } else {
+                    usize::MAX
+                }));
+
+                if let Some(left_recv) = recv_ports[0].take() {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        sample_morsels(
+                            left_recv.serial(),
+                            &mut sample_state.left,
+                            &mut sample_state.left_len,
+                            left_final_len.clone(),
+                            right_final_len.clone(),
+                        ),
+                    ));
+                }
+                if let Some(right_recv) = recv_ports[1].take() {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        sample_morsels(
+                            right_recv.serial(),
+                            &mut sample_state.right,
+                            &mut sample_state.right_len,
+                            right_final_len,
+                            left_final_len,
+                        ),
+                    ));
+                }
+            },
+            IEJoinState::Build(build_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let receivers = recv_ports[build_idx].take().unwrap().parallel();
+                for (frames, recv) in build_state.frames_per_pipeline.iter_mut().zip(receivers) {
+                    join_handles
+                        .push(scope.spawn_task(TaskPriority::High, BuildState::sink(recv, frames)));
+                }
+            },
+            IEJoinState::Probe(probe_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let receivers = probe_state
+                    .sampled_probe_morsels
+                    .reinsert(
+                        state.num_pipelines,
+                        recv_ports[probe_idx].take(),
+                        scope,
+                        join_handles,
+                    )
+                    .unwrap();
+
+                for (recv, send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        ProbeState::probe(
+                            recv,
+                            send,
+                            &probe_state.build,
+                            &self.params,
+                            &state.in_memory_exec_state,
+                        ),
+                    ));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
@@ -1,7 +1,10 @@
-use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
 
 use crossbeam_queue::ArrayQueue;
 use polars_core::POOL;
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+use polars_core::prelude::{AnyValue, Column};
 use polars_error::PolarsResult;
 use polars_utils::itertools::Itertools;
 use rayon::prelude::*;
@@ -12,7 +15,11 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::RecvPort;
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod equi_join;
+#[cfg(feature = "iejoin")]
+pub mod ie_join;
 pub mod in_memory;
 pub mod semi_anti_join;
 
@@ -26,6 +33,53 @@ static JOIN_SAMPLE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
 // smaller side as the build side without checking cardinalities.
 const LOPSIDED_SAMPLE_FACTOR: usize = 10;
 
+/// Returns the number of leading values in `sorted` for which `pred` holds,
+/// `pred` must be true for a prefix of the column and false for the rest.
+#[cfg(any(feature = "asof_join", feature = "iejoin"))]
+fn partition_point(
+    sorted: &Column,
+    mut pred: impl FnMut(&AnyValue<'_>) -> bool,
+) -> PolarsResult<usize> {
+    let (mut lo, mut hi) = (0, sorted.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&sorted.get(mid)?) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/// Buffers morsels from a join input while choosing the build side. Stops the
+/// input once the sample limit is reached or it is much larger than the other
+/// input, whose final length is only known once it is done.
+async fn sample_morsels(
+    mut recv: Receiver<Morsel>,
+    morsels: &mut Vec<Morsel>,
+    len: &mut usize,
+    this_final_len: Arc<AtomicUsize>,
+    other_final_len: Arc<AtomicUsize>,
+) -> PolarsResult<()> {
+    while let Ok(mut morsel) = recv.recv().await {
+        *len += morsel.df().height();
+        if *len >= *JOIN_SAMPLE_LIMIT
+            || *len
+                >= other_final_len
+                    .load(Ordering::Relaxed)
+                    .saturating_mul(LOPSIDED_SAMPLE_FACTOR)
+        {
+            morsel.source_token().stop();
+        }
+
+        drop(morsel.take_consume_token());
+        morsels.push(morsel);
+    }
+    this_final_len.store(*len, Ordering::Relaxed);
+    Ok(())
+}
+
 // TODO: improve, generalize this, and move it away from here.
 struct BufferedStream {
     morsels: ArrayQueue<Morsel>,
@@ -0,0 +1,597 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded_ca;
+use polars_core::prelude::{
+    ChunkSort, Column, DataType, IntoSeries, Scalar, SortMultipleOptions, SortOptions,
+};
+use polars_core::schema::Schema;
+use polars_core::utils::{
+    accumulate_dataframes_vertical_unchecked, accumulate_dataframes_vertical_unchecked_optional,
+    slice_offsets,
+};
+use polars_core::{POOL, config};
+use polars_error::polars_ensure;
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::{PlSmallStr, unique_column_name};
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{SpillDir, SpilledDataFrame};
+
+/// Sort `df` by its row-encoded `key` column and keep the first `limit` rows.
+fn sort_by_key(
+    df: DataFrame,
+    key: &PlSmallStr,
+    limit: Option<usize>,
+    multithreaded: bool,
+) -> PolarsResult<DataFrame> {
+    let keys = df.column(key)?.binary_offset()?;
+    let idx = keys.arg_sort(SortOptions::default().with_multithreaded(multithreaded));
+    let idx = match limit {
+        Some(limit) if limit < idx.len() => idx.slice(0, limit),
+        _ => idx,
+    };
+    // SAFETY: the indices come from sorting the rows of df.
+    Ok(unsafe { df.take_unchecked_impl(&idx, multithreaded) })
+}
+
+/// A chunk of a sorted run, its first row comes after the last row of the
+/// previous chunk of the run.
+enum RunChunk {
+    InMemory(DataFrame),
+    Spilled(SpilledDataFrame),
+}
+
+impl RunChunk {
+    fn height(&self) -> usize {
+        match self {
+            Self::InMemory(df) => df.height(),
+            Self::Spilled(spilled) => spilled.height(),
+        }
+    }
+
+    fn load(self) -> PolarsResult<DataFrame> {
+        match self {
+            Self::InMemory(df) => Ok(df),
+            Self::Spilled(spilled) => spilled.load(),
+        }
+    }
+}
+
+/// Split a sorted `df` into chunks of a run, spilling them if `spill_dir` is given.
+fn into_run(df: DataFrame, spill_dir: Option<&SpillDir>) -> PolarsResult<VecDeque<RunChunk>> {
+    let chunk_size = get_ideal_morsel_size();
+    (0..df.height())
+        .step_by(chunk_size)
+        .map(|offset| {
+            let mut chunk = df.slice(offset as i64, chunk_size);
+            Ok(match spill_dir {
+                Some(spill_dir) => RunChunk::Spilled(spill_dir.spill(&mut chunk)?),
+                None => RunChunk::InMemory(chunk),
+            })
+        })
+        .collect()
+}
+
+struct LocalSortSinkState {
+    /// Morsels with their row-encoded key that aren't part of a run yet.
+    pending: Vec<DataFrame>,
+    pending_rows: usize,
+    pending_bytes: usize,
+    runs: Vec<VecDeque<RunChunk>>,
+}
+
+impl LocalSortSinkState {
+    fn take_pending(&mut self) -> Option<DataFrame> {
+        self.pending_rows = 0;
+        self.pending_bytes = 0;
+        accumulate_dataframes_vertical_unchecked_optional(core::mem::take(&mut self.pending))
+    }
+}
+
+struct SortSinkState {
+    by_column: Vec<StreamExpr>,
+    locals: Vec<LocalSortSinkState>,
+}
+
+impl SortSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        params: &'env SortParams,
+        spill_dir: &'env SpillDir,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let run_budget = params.memory_budget / self.locals.len();
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let by_column = &self.by_column;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(morsel) = recv.recv().await {
+                    let seq = morsel.seq().to_u64();
+                    let mut df = morsel.into_df();
+                    let height = df.height();
+                    if height == 0 {
+                        continue;
+                    }
+
+                    let mut keys = Vec::with_capacity(params.descending.len());
+                    for selector in by_column {
+                        let s = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                        polars_ensure!(
+                            s.len() == height || s.len() == 1,
+                            ShapeMismatch: "sort expressions must have same \
+                            length as DataFrame, got DataFrame height: {} and Series length: {}",
+                            height, s.len()
+                        );
+                        keys.push(if s.len() == height {
+                            s
+                        } else {
+                            s.new_from_index(0, height)
+                        });
+                    }
+                    if params.maintain_order {
+                        // Break ties by the position of the row in the input.
+                        keys.push(Column::new_scalar(
+                            PlSmallStr::EMPTY,
+                            Scalar::from(seq),
+                            height,
+                        ));
+                        keys.push(Column::new_row_index(PlSmallStr::EMPTY, 0, height)?);
+                    }
+                    let key = _get_rows_encoded_ca(
+                        params.key.clone(),
+                        &keys,
+                        &params.descending,
+                        &params.nulls_last,
+                    )?;
+                    // Keep the key as a series, a scalar column of BinaryOffset
+                    // can't be materialized again once it is sliced.
+                    let key = Column::Series(key.into_series().into());
+                    // SAFETY: the key has a unique name and the height of df.
+                    unsafe { df.with_column_unchecked(key) };
+
+                    local.pending_rows += height;
+                    local.pending_bytes += df.estimated_size();
+                    local.pending.push(df);
+
+                    if local.pending_bytes >= run_budget {
+                        let df = local.take_pending().unwrap();
+                        let df = sort_by_key(df, &params.key, params.limit, false)?;
+                        local.runs.push(into_run(df, Some(spill_dir))?);
+                    } else if let Some(limit) = params.limit {
+                        // Only the first rows can end up in the output, drop the others.
+                        let max_rows = limit.saturating_mul(2).max(get_ideal_morsel_size());
+                        if local.pending_rows >= max_rows {
+                            let df = local.take_pending().unwrap();
+                            let df = sort_by_key(df, &params.key, Some(limit), false)?;
+                            local.pending_rows = df.height();
+                            local.pending_bytes = df.estimated_size();
+                            local.pending.push(df);
+                        }
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+}
+
+struct SortParams {
+    /// Name of the column holding the row-encoded sort key.
+    key: PlSmallStr,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    /// The number of leading rows that are needed for the output, if known.
+    limit: Option<usize>,
+    memory_budget: usize,
+}
+
+/// The position in a sorted run during the merge.
+struct RunCursor {
+    chunks: VecDeque<RunChunk>,
+    df: DataFrame,
+    keys: BinaryArray<i64>,
+    offset: usize,
+}
+
+/// Load a chunk of a run and split off its keys.
+fn load_chunk(chunk: RunChunk, key: &PlSmallStr) -> PolarsResult<(DataFrame, BinaryArray<i64>)> {
+    let mut df = chunk.load()?;
+    let keys = df.drop_in_place(key)?;
+    let keys = match keys.dtype() {
+        // The key is read back as a binary view from spilled chunks.
+        DataType::Binary => {
+            let keys = keys.binary()?.rechunk();
+            BinaryArray::from_iter_values(keys.downcast_as_array().values_iter())
+        },
+        _ => keys.binary_offset()?.rechunk().downcast_as_array().clone(),
+    };
+    Ok((df, keys))
+}
+
+impl RunCursor {
+    fn new(mut chunks: VecDeque<RunChunk>, key: &PlSmallStr) -> PolarsResult<Option<Self>> {
+        let Some(chunk) = chunks.pop_front() else {
+            return Ok(None);
+        };
+        let (df, keys) = load_chunk(chunk, key)?;
+        Ok(Some(Self {
+            chunks,
+            df,
+            keys,
+            offset: 0,
+        }))
+    }
+
+    /// Load the next chunk of the run, returns false if the run is exhausted.
+    fn next_chunk(&mut self, key: &PlSmallStr) -> PolarsResult<bool> {
+        let Some(chunk) = self.chunks.pop_front() else {
+            return Ok(false);
+        };
+        (self.df, self.keys) = load_chunk(chunk, key)?;
+        self.offset = 0;
+        Ok(true)
+    }
+}
+
+/// Merges the sorted runs into a stream of morsels.
+struct SortedRunMerger {
+    cursors: Vec<RunCursor>,
+    /// The runs by their current key, smallest first.
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    key: PlSmallStr,
+    /// The number of rows that were merged and the range of them to output.
+    position: usize,
+    start: usize,
+    end: usize,
+    seq: u64,
+}
+
+impl SortedRunMerger {
+    fn new(
+        runs: Vec<VecDeque<RunChunk>>,
+        key: PlSmallStr,
+        start: usize,
+        end: usize,
+    ) -> PolarsResult<Self> {
+        let mut cursors = Vec::with_capacity(runs.len());
+        let mut heap = BinaryHeap::with_capacity(runs.len());
+        for chunks in runs {
+            if let Some(cursor) = RunCursor::new(chunks, &key)? {
+                heap.push(Reverse((cursor.keys.value(0).to_vec(), cursors.len())));
+                cursors.push(cursor);
+            }
+        }
+        Ok(Self {
+            cursors,
+            heap,
+            key,
+            position: 0,
+            start,
+            end,
+            seq: 0,
+        })
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.heap.is_empty() || self.position >= self.end
+    }
+
+    /// Merge the next rows of the runs, returns `None` if all rows are output.
+    fn next_morsel(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let morsel_size = get_ideal_morsel_size();
+        let mut out = Vec::new();
+        let mut out_rows = 0;
+        while out_rows < morsel_size && !self.is_exhausted() {
+            let Reverse((_, run)) = self.heap.pop().unwrap();
+            let cursor = &mut self.cursors[run];
+            let keys = &cursor.keys;
+
+            // Take all rows of this run up to the smallest key of the other runs.
+            let start = cursor.offset;
+            let mut stop = keys.len();
+            if let Some(Reverse((next_key, _))) = self.heap.peek() {
+                let mut lo = start + 1;
+                while lo < stop {
+                    let mid = lo + (stop - lo) / 2;
+                    if keys.value(mid) <= next_key.as_slice() {
+                        lo = mid + 1;
+                    } else {
+                        stop = mid;
+                    }
+                }
+            }
+            let stop = stop.min(start + morsel_size - out_rows);
+
+            // Only output the rows that fall within the slice.
+            let len = stop - start;
+            let skip = self.start.saturating_sub(self.position).min(len);
+            let take = (len - skip).min(self.end - (self.position + skip));
+            if take > 0 {
+                out.push(cursor.df.slice((start + skip) as i64, take));
+                out_rows += take;
+            }
+            self.position += len;
+
+            cursor.offset = stop;
+            if cursor.offset < cursor.keys.len() || cursor.next_chunk(&self.key)? {
+                let key = cursor.keys.value(cursor.offset).to_vec();
+                self.heap.push(Reverse((key, run)));
+            }
+        }
+
+        if out.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(accumulate_dataframes_vertical_unchecked(out)))
+    }
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    InMemorySource(InMemorySourceNode),
+    MergeSource(SortedRunMerger),
+    Done,
+}
+
+/// Sorts its input out-of-core.
+///
+/// The morsels are buffered together with their row-encoded sort key. Once the
+/// buffer of a pipeline exceeds its share of the memory budget it is sorted and
+/// spilled to disk as a sorted run. If nothing was spilled the buffers are
+/// sorted in memory, otherwise the runs are k-way merged into the output.
+pub struct SortNode {
+    state: SortState,
+    params: SortParams,
+    slice: Option<(i64, usize)>,
+    output_schema: Arc<Schema>,
+    spill_dir: SpillDir,
+}
+
+impl SortNode {
+    pub fn new(
+        by_column: Vec<StreamExpr>,
+        slice: Option<(i64, usize)>,
+        sort_options: SortMultipleOptions,
+        output_schema: Arc<Schema>,
+        num_pipelines: usize,
+        memory_budget: usize,
+    ) -> Self {
+        let num_keys = by_column.len();
+        let broadcast = |v: &[bool]| match v {
+            [v] => vec![*v; num_keys],
+            v => v.to_vec(),
+        };
+        let mut descending = broadcast(&sort_options.descending);
+        let mut nulls_last = broadcast(&sort_options.nulls_last);
+        if sort_options.maintain_order {
+            descending.extend([false, false]);
+            nulls_last.extend([false, false]);
+        }
+
+        let limit = match slice {
+            Some((offset, len)) if offset >= 0 => Some((offset as usize).saturating_add(len)),
+            _ => None,
+        };
+        let limit = match (limit, sort_options.limit) {
+            (Some(l), Some(r)) => Some(l.min(r as usize)),
+            (l, r) => l.or(r.map(|r| r as usize)),
+        };
+
+        let locals = (0..num_pipelines)
+            .map(|_| LocalSortSinkState {
+                pending: Vec::new(),
+                pending_rows: 0,
+                pending_bytes: 0,
+                runs: Vec::new(),
+            })
+            .collect();
+        Self {
+            state: SortState::Sink(SortSinkState { by_column, locals }),
+            params: SortParams {
+                key: unique_column_name(),
+                descending,
+                nulls_last,
+                maintain_order: sort_options.maintain_order,
+                limit,
+                memory_budget,
+            },
+            slice,
+            output_schema,
+            spill_dir: SpillDir::new("sort"),
+        }
+    }
+
+    fn finish_sink(&self, mut sink: SortSinkState) -> PolarsResult<SortState> {
+        let total_rows: usize = sink
+            .locals
+            .iter()
+            .map(|l| {
+                let run_rows: usize = l.runs.iter().flatten().map(RunChunk::height).sum();
+                l.pending_rows + run_rows
+            })
+            .sum();
+
+        if sink.locals.iter().all(|l| l.runs.is_empty()) {
+            let pending = sink.locals.iter_mut().filter_map(|l| l.take_pending());
+            let mut df = match accumulate_dataframes_vertical_unchecked_optional(pending) {
+                Some(df) => {
+                    let mut df = sort_by_key(df, &self.params.key, self.params.limit, true)?;
+                    df.drop_in_place(&self.params.key)?;
+                    df
+                },
+                None => DataFrame::empty_with_schema(&self.output_schema),
+            };
+            if let Some((offset, len)) = self.slice {
+                df = df.slice(offset, len);
+            }
+            let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+            return Ok(SortState::InMemorySource(source));
+        }
+
+        // Turn the remaining buffers into in-memory runs.
+        let params = &self.params;
+        let runs = POOL.install(|| {
+            sink.locals
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|mut l| {
+                    if let Some(df) = l.take_pending() {
+                        let df = sort_by_key(df, &params.key, params.limit, false)?;
+                        l.runs.push(into_run(df, None)?);
+                    }
+                    PolarsResult::Ok(l.runs)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let runs = runs.into_iter().flatten().collect_vec();
+
+        // The runs may be cut off after the limit, only the rows before it are
+        // output and the slice is relative to those.
+        let total_rows = total_rows.min(params.limit.unwrap_or(usize::MAX));
+        let (start, end) = match self.slice {
+            Some((offset, len)) => {
+                let (start, len) = slice_offsets(offset, len, total_rows);
+                (start, start + len)
+            },
+            None => (0, total_rows),
+        };
+        if config::verbose() {
+            eprintln!(
+                "[sort]: merging {} sorted runs of {total_rows} rows",
+                runs.len()
+            );
+        }
+        let merger = SortedRunMerger::new(runs, params.key.clone(), start, end)?;
+        Ok(SortState::MergeSource(merger))
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink(_) if matches!(recv[0], PortState::Done) => {
+                let SortState::Sink(sink) = core::mem::replace(&mut self.state, SortState::Done)
+                else {
+                    unreachable!()
+                };
+                self.state = self.finish_sink(sink)?;
+            },
+            // Defer to source node implementation.
+            SortState::InMemorySource(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = SortState::Done;
+                }
+            },
+            SortState::MergeSource(merger) => {
+                if merger.is_exhausted() {
+                    self.state = SortState::Done;
+                }
+            },
+            // Nothing to change.
+            SortState::Done | SortState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::InMemorySource(..) | SortState::MergeSource(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    &self.params,
+                    &self.spill_dir,
+                    state,
+                    join_handles,
+                )
+            },
+            SortState::InMemorySource(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            SortState::MergeSource(merger) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    while let Some(df) = merger.next_morsel()? {
+                        let seq = MorselSeq::new(merger.seq);
+                        merger.seq += 1;
+                        let mut morsel = Morsel::new(df, seq, source_token.clone());
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+
+                        wait_group.wait().await;
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -122,10 +122,16 @@ fn visualize_plan_rec(
             FileType::Parquet(_) => ("parquet-sink".to_string(), from_ref(input)),
             #[cfg(feature = "ipc")]
             FileType::Ipc(_) => ("ipc-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "ipc_streaming")]
+            FileType::IpcStream(_) => ("ipc-stream-sink".to_string(), from_ref(input)),
             #[cfg(feature = "csv")]
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "pg_copy")]
+            FileType::PgCopy(_) => ("pg-copy-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -146,10 +152,16 @@ fn visualize_plan_rec(
                 FileType::Parquet(_) => (format!("{}[parquet]", variant), from_ref(input)),
                 #[cfg(feature = "ipc")]
                 FileType::Ipc(_) => (format!("{}[ipc]", variant), from_ref(input)),
+                #[cfg(feature = "ipc_streaming")]
+                FileType::IpcStream(_) => (format!("{}[ipc-stream]", variant), from_ref(input)),
                 #[cfg(feature = "csv")]
                 FileType::Csv(_) => (format!("{}[csv]", variant), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{}[ndjson]", variant), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{}[avro]", variant), from_ref(input)),
+                #[cfg(feature = "pg_copy")]
+                FileType::PgCopy(_) => (format!("{}[pg-copy]", variant), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -194,6 +206,7 @@ fn visualize_plan_rec(
             cast_columns_policy: _,
             missing_columns_policy: _,
             extra_columns_policy: _,
+            deleted_rows,
             file_schema: _,
         } => {
             let mut out = format!("multi-scan[{}]", file_reader_builder.reader_name());
@@ -240,6 +253,10 @@ fn visualize_plan_rec(
                 write!(f, "\nfilter: {}", predicate.display(expr_arena)).unwrap();
             }
 
+            if deleted_rows.is_some() {
+                write!(f, "\ndeleted rows").unwrap();
+            }
+
             if let Some(v) = hive_parts.as_ref().map(|h| h.df().width()) {
                 write!(f, "\nhive: {} column", v).unwrap();
 
@@ -314,6 +331,46 @@ fn visualize_plan_rec(
             }
             (label, &[*input_left, *input_right][..])
         },
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            ..
+        } => {
+            let mut label = "asof-join".to_string();
+            write!(label, r"\nleft_on:\n{}", fmt_exprs(left_on, expr_arena)).unwrap();
+            write!(label, r"\nright_on:\n{}", fmt_exprs(right_on, expr_arena)).unwrap();
+            if let JoinType::AsOf(options) = &args.how {
+                write!(
+                    label,
+                    r"\nstrategy: {}",
+                    escape_graphviz(&format!("{:?}", options.strategy))
+                )
+                .unwrap();
+            }
+            (label, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } => {
+            let mut label = "ie-join".to_string();
+            write!(label, r"\nleft_on:\n{}", fmt_exprs(left_on, expr_arena)).unwrap();
+            write!(label, r"\nright_on:\n{}", fmt_exprs(right_on, expr_arena)).unwrap();
+            write!(label, r"\noperator1: {:?}", options.operator1).unwrap();
+            if let Some(operator2) = options.operator2 {
+                write!(label, r"\noperator2: {operator2:?}").unwrap();
+            }
+            (label, &[*input_left, *input_right][..])
+        },
         #[cfg(feature = "merge_sorted")]
         PhysNodeKind::MergeSorted {
             input_left,
@@ -8,6 +8,8 @@ use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::JoinType;
 use polars_plan::dsl::{
     ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, SinkTypeIR,
 };
@@ -478,6 +480,56 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "ipc_streaming")]
+                    FileScan::IpcStream { options: _ } => Arc::new(
+                        crate::nodes::io_sources::ipc_stream::builder::IpcStreamReaderBuilder,
+                    )
+                        as Arc<dyn FileReaderBuilder>,
+
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder)
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { options } => {
+                        Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {
+                            options: options.clone(),
+                        }) as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "fwf")]
+                    FileScan::Fwf { options } => {
+                        Arc::new(crate::nodes::io_sources::fwf::builder::FwfReaderBuilder {
+                            options: options.clone(),
+                        }) as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "lines")]
+                    FileScan::Lines { options } => Arc::new(
+                        crate::nodes::io_sources::lines::builder::LinesReaderBuilder {
+                            options: options.clone(),
+                        },
+                    )
+                        as Arc<dyn FileReaderBuilder>,
+
+                    #[cfg(feature = "json")]
+                    FileScan::Json { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::json::builder::JsonReaderBuilder)
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "delta")]
+                    FileScan::Delta { .. } => {
+                        unreachable!("Delta scans are converted to Parquet scans")
+                    },
+
+                    #[cfg(feature = "iceberg")]
+                    FileScan::Iceberg { .. } => {
+                        unreachable!("Iceberg scans are expanded to Parquet scans")
+                    },
+
                     #[cfg(feature = "csv")]
                     FileScan::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -561,6 +613,7 @@ pub fn lower_ir(
                         missing_columns_policy: unified_scan_args.missing_columns_policy,
                         extra_columns_policy,
                         include_file_paths: unified_scan_args.include_file_paths,
+                        deleted_rows: unified_scan_args.deleted_rows,
                         file_schema,
                     };
 
@@ -793,6 +846,44 @@ pub fn lower_ir(
             let options = options.options.clone();
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
+
+            // The as-of join node reads its keys directly from the inputs, so
+            // we only use it when joining on plain columns. With `by` groups
+            // the inputs only need to be sorted within each group, which the
+            // node can't stream, so those go to the in-memory engine.
+            #[cfg(feature = "asof_join")]
+            if let JoinType::AsOf(asof_options) = &args.how {
+                let is_column = |e: &[ExprIR]| {
+                    e.len() == 1 && matches!(expr_arena.get(e[0].node()), AExpr::Column(_))
+                };
+                let has_by = asof_options.left_by.is_some() || asof_options.right_by.is_some();
+                if !has_by && is_column(&left_on) && is_column(&right_on) {
+                    let mut args = args.clone();
+                    let slice = args.slice.take();
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::AsOfJoin {
+                            input_left: phys_left,
+                            input_right: phys_right,
+                            left_on,
+                            right_on,
+                            args,
+                            options,
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+            }
+
+            // polars-ops may be built with IEJoin support while this crate is not.
+            #[cfg(feature = "iejoin")]
+            let supported_join_type =
+                args.how.is_equi() || args.how.is_semi_anti() || args.how.is_ie();
+            #[cfg(not(feature = "iejoin"))]
             let supported_join_type = args.how.is_equi() || args.how.is_semi_anti();
             if supported_join_type && !args.validation.needs_checks() {
                 // When lowering the expressions for the keys we need to ensure we keep around the
@@ -827,6 +918,27 @@ pub fn lower_ir(
                             args: args.clone(),
                         },
                     ))
+                } else if args.how.is_ie() {
+                    #[cfg(feature = "iejoin")]
+                    {
+                        let Some(polars_plan::dsl::JoinTypeOptionsIR::IEJoin(ie_options)) = options
+                        else {
+                            unreachable!()
+                        };
+                        phys_sm.insert(PhysNode::new(
+                            output_schema,
+                            PhysNodeKind::IEJoin {
+                                input_left: trans_input_left,
+                                input_right: trans_input_right,
+                                left_on: trans_left_on,
+                                right_on: trans_right_on,
+                                args: args.clone(),
+                                options: ie_options,
+                            },
+                        ))
+                    }
+                    #[cfg(not(feature = "iejoin"))]
+                    unreachable!()
                 } else {
                     phys_sm.insert(PhysNode::new(
                         output_schema,
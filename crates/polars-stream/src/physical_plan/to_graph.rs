@@ -3,8 +3,9 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
 use parking_lot::Mutex;
+use polars_core::frame::DataFrame;
 use polars_core::prelude::PlRandomState;
-use polars_core::schema::Schema;
+use polars_core::schema::{Schema, SchemaRef};
 use polars_core::{POOL, config};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_expr::groups::new_hash_grouper;
@@ -12,7 +13,8 @@ use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
-use polars_plan::dsl::{JoinOptions, PartitionVariantIR, ScanSources};
+use polars_ops::frame::JoinArgs;
+use polars_plan::dsl::{JoinOptions, JoinTypeOptionsIR, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
 use polars_plan::prelude::{FileType, FunctionFlags};
@@ -33,8 +35,11 @@ use crate::nodes::io_sinks::SinkComputeNode;
 use crate::nodes::io_sources::multi_file_reader::MultiFileReaderConfig;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
-use crate::physical_plan::lower_expr::compute_output_schema;
+use crate::physical_plan::lower_expr::{
+    ExprCache, compute_output_schema, is_elementwise_rec_cached,
+};
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
+use crate::utils::spill::memory_budget;
 
 fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
     arena.iter(node).any(|(_n, ae)| match ae {
@@ -61,6 +66,59 @@ fn create_stream_expr(
     Ok(StreamExpr::new(phys, reentrant))
 }
 
+/// Joins two fully materialized inputs.
+type InMemoryJoiner = Arc<dyn Fn(DataFrame, DataFrame) -> PolarsResult<DataFrame> + Send + Sync>;
+
+/// Creates a function which joins two fully materialized inputs using the
+/// in-memory engine.
+#[allow(clippy::too_many_arguments)]
+fn create_in_memory_joiner(
+    left_input_schema: SchemaRef,
+    right_input_schema: SchemaRef,
+    output_schema: SchemaRef,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    args: JoinArgs,
+    options: Option<JoinTypeOptionsIR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<InMemoryJoiner> {
+    let mut lp_arena = Arena::default();
+    let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
+    let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
+
+    let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema));
+    let right_node = lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema));
+    let join_node = lp_arena.add(IR::Join {
+        input_left: left_node,
+        input_right: right_node,
+        schema: output_schema,
+        left_on,
+        right_on,
+        options: Arc::new(JoinOptions {
+            allow_parallel: true,
+            force_parallel: false,
+            args,
+            options,
+            rows_left: (None, 0),
+            rows_right: (None, 0),
+        }),
+    });
+
+    // An executor takes its inputs when it runs, so one is created for every
+    // join from a copy of the plan.
+    let arenas = Mutex::new((lp_arena, expr_arena.clone()));
+    Ok(Arc::new(move |left, right| {
+        let mut arenas = arenas.lock();
+        let (lp_arena, expr_arena) = &mut *arenas;
+        let mut executor =
+            create_physical_plan(join_node, &mut lp_arena.clone(), expr_arena, None)?;
+        left_lmdf.set_materialized_dataframe(left);
+        right_lmdf.set_materialized_dataframe(right);
+        let mut state = ExecutionState::new();
+        executor.execute(&mut state)
+    }))
+}
+
 struct GraphConversionContext<'a> {
     phys_sm: &'a SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &'a mut Arena<AExpr>,
@@ -68,6 +126,8 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    /// The share of the query's memory budget given to each node that may spill.
+    node_memory_budget: usize,
 }
 
 pub fn physical_plan_to_graph(
@@ -77,6 +137,17 @@ pub fn physical_plan_to_graph(
 ) -> PolarsResult<(Graph, SecondaryMap<PhysNodeKey, GraphNodeKey>)> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
+    let num_spilling_nodes = phys_sm
+        .values()
+        .filter(|node| {
+            matches!(
+                node.kind,
+                PhysNodeKind::Sort { .. }
+                    | PhysNodeKind::GroupBy { .. }
+                    | PhysNodeKind::EquiJoin { .. }
+            )
+        })
+        .count();
     let mut ctx = GraphConversionContext {
         phys_sm,
         expr_arena,
@@ -84,6 +155,7 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        node_memory_budget: memory_budget() / num_spilling_nodes.max(1),
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -263,11 +335,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "ipc_streaming")]
+                FileType::IpcStream(ipc_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::ipc::IpcSinkNode::new_stream(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        *ipc_writer_options,
+                        cloud_options.clone(),
+                    )),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(ndjson_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *ndjson_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -283,6 +367,28 @@ fn to_graph_rec<'a>(
                     )?),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        avro_writer_options,
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
+                #[cfg(feature = "pg_copy")]
+                FileType::PgCopy(pg_copy_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::pg_copy::PgCopySinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        pg_copy_writer_options,
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(feature = "csv")]
                 FileType::Csv(csv_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::csv::CsvSinkNode::new(
@@ -298,7 +404,9 @@ fn to_graph_rec<'a>(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro",
+                    feature = "pg_copy"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -405,34 +513,60 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
 
-            let input_key = to_graph_rec(input.node, ctx)?;
-            ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
-                    input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
-                ),
-                [(input_key, input.port)],
-            )
+            // Keys that are computed per row can be evaluated per morsel, which allows
+            // sorting out-of-core.
+            let mut expr_cache = ExprCache::with_capacity(by_column.len());
+            if by_column
+                .iter()
+                .all(|e| is_elementwise_rec_cached(e.node(), ctx.expr_arena, &mut expr_cache))
+            {
+                let by_column = by_column
+                    .iter()
+                    .map(|e| create_stream_expr(e, ctx, &input_schema))
+                    .try_collect_vec()?;
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(
+                        by_column,
+                        *slice,
+                        sort_options.clone(),
+                        input_schema,
+                        ctx.num_pipelines,
+                        ctx.node_memory_budget,
+                    ),
+                    [(input_key, input.port)],
+                )
+            } else {
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                let mut lp_arena = Arena::default();
+                let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                let sort_node = lp_arena.add(IR::Sort {
+                    input: df_node,
+                    by_column: by_column.clone(),
+                    slice: *slice,
+                    sort_options: sort_options.clone(),
+                });
+                let executor = Mutex::new(create_physical_plan(
+                    sort_node,
+                    &mut lp_arena,
+                    ctx.expr_arena,
+                    None,
+                )?);
+
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(
+                        input_schema,
+                        Arc::new(move |df| {
+                            lmdf.set_materialized_dataframe(df);
+                            let mut state = ExecutionState::new();
+                            executor.lock().execute(&mut state)
+                        }),
+                    ),
+                    [(input_key, input.port)],
+                )
+            }
         },
 
         OrderedUnion { inputs } => {
@@ -484,6 +618,7 @@ fn to_graph_rec<'a>(
             extra_columns_policy,
             cast_columns_policy,
             include_file_paths,
+            deleted_rows,
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
@@ -520,6 +655,7 @@ fn to_graph_rec<'a>(
             let missing_columns_policy = missing_columns_policy.clone();
             let extra_columns_policy = extra_columns_policy.clone();
             let cast_columns_policy = cast_columns_policy.clone();
+            let deleted_rows = deleted_rows.clone();
 
             let verbose = config::verbose();
 
@@ -540,6 +676,7 @@ fn to_graph_rec<'a>(
                         missing_columns_policy,
                         extra_columns_policy,
                         cast_columns_policy,
+                        deleted_rows,
                         // Initialized later
                         num_pipelines: AtomicUsize::new(0),
                         n_readers_pre_init:
@@ -585,6 +722,7 @@ fn to_graph_rec<'a>(
                     node.output_schema.clone(),
                     PlRandomState::default(),
                     ctx.num_pipelines,
+                    ctx.node_memory_budget,
                 ),
                 [(input_key, input.port)],
             )
@@ -603,46 +741,140 @@ fn to_graph_rec<'a>(
             let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
             let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
 
-            let mut lp_arena = Arena::default();
-            let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
-
-            let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema.clone()));
-            let right_node =
-                lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema.clone()));
-            let join_node = lp_arena.add(IR::Join {
-                input_left: left_node,
-                input_right: right_node,
-                schema: node.output_schema.clone(),
-                left_on: left_on.clone(),
-                right_on: right_on.clone(),
-                options: Arc::new(JoinOptions {
-                    allow_parallel: true,
-                    force_parallel: false,
-                    args: args.clone(),
-                    options: options.clone(),
-                    rows_left: (None, 0),
-                    rows_right: (None, 0),
-                }),
-            });
-
-            let executor = Mutex::new(create_physical_plan(
-                join_node,
-                &mut lp_arena,
+            let joiner = create_in_memory_joiner(
+                left_input_schema.clone(),
+                right_input_schema.clone(),
+                node.output_schema.clone(),
+                left_on.clone(),
+                right_on.clone(),
+                args.clone(),
+                options.clone(),
                 ctx.expr_arena,
-                None,
-            )?);
+            )?;
 
             ctx.graph.add_node(
                 nodes::joins::in_memory::InMemoryJoinNode::new(
                     left_input_schema,
                     right_input_schema,
-                    Arc::new(move |left, right| {
-                        left_lmdf.set_materialized_dataframe(left);
-                        right_lmdf.set_materialized_dataframe(right);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    joiner,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            use polars_ops::frame::JoinType;
+
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let key_name = |e: &ExprIR| match ctx.expr_arena.get(e.node()) {
+                AExpr::Column(name) => name.clone(),
+                _ => unreachable!(),
+            };
+            let left_key = key_name(&left_on[0]);
+            let right_key = key_name(&right_on[0]);
+
+            let args = args.clone();
+            let JoinType::AsOf(asof_options) = &args.how else {
+                unreachable!()
+            };
+            let strategy = asof_options.strategy;
+            let check_sortedness = asof_options.check_sortedness;
+
+            let joiner = create_in_memory_joiner(
+                left_input_schema,
+                right_input_schema.clone(),
+                node.output_schema.clone(),
+                left_on.clone(),
+                right_on.clone(),
+                args,
+                options.clone(),
+                ctx.expr_arena,
+            )?;
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_key,
+                    right_key,
+                    strategy,
+                    check_sortedness,
+                    joiner,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let args = args.clone();
+            let options = options.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_schema =
+                compute_output_schema(&left_input_schema, left_on, ctx.expr_arena)?;
+            let right_key_schema =
+                compute_output_schema(&right_input_schema, right_on, ctx.expr_arena)?;
+            polars_ensure!(
+                left_on.len() == right_on.len() &&
+                left_on.iter().zip(right_on.iter()).all(|(l, r)| {
+                    let l_dtype = left_key_schema.get(l.output_name()).unwrap();
+                    let r_dtype = right_key_schema.get(r.output_name()).unwrap();
+                    l_dtype == r_dtype
+                }),
+                SchemaMismatch: "join received different key types on left and right side"
+            );
+
+            let left_key_selectors = left_on
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let e = e.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&e, ctx, &left_input_schema)
+                })
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let e = e.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&e, ctx, &right_input_schema)
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::ie_join::IEJoinNode::new(
+                    left_key_selectors,
+                    right_key_selectors,
+                    args,
+                    options,
                 ),
                 [
                     (left_input_key, input_left.port),
@@ -741,6 +973,7 @@ fn to_graph_rec<'a>(
                         right_key_selectors,
                         args,
                         ctx.num_pipelines,
+                        ctx.node_memory_budget,
                     )?,
                     [
                         (left_input_key, input_left.port),
@@ -937,6 +1170,7 @@ fn to_graph_rec<'a>(
             let missing_columns_policy = MissingColumnsPolicy::Raise;
             let extra_columns_policy = ExtraColumnsPolicy::Ignore;
             let cast_columns_policy = CastColumnsPolicy::ErrorOnMismatch;
+            let deleted_rows = None;
             let verbose = config::verbose();
 
             ctx.graph.add_node(
@@ -956,6 +1190,7 @@ fn to_graph_rec<'a>(
                         missing_columns_policy,
                         extra_columns_policy,
                         cast_columns_policy,
+                        deleted_rows,
                         // Initialized later
                         num_pipelines: AtomicUsize::new(0),
                         n_readers_pre_init:
@@ -0,0 +1,277 @@
+use std::cell::{Cell, RefCell};
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+use polars_error::{PolarsResult, polars_err, polars_warn};
+use polars_expr::hash_keys::HashKeys;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+use polars_io::{SerReader, SerWriter};
+use polars_utils::IdxSize;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::sys::MEMINFO;
+
+use crate::morsel::get_ideal_morsel_size;
+
+/// Number of spill directories created by this process, to give each a unique name.
+static SPILL_DIR_COUNT: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Memory budget of the queries built on this thread, see [`with_memory_budget`].
+    static MEMORY_BUDGET_OVERRIDE: Cell<Option<usize>> = const { Cell::new(None) };
+    /// Number of rows spilled by the nodes created on this thread, see [`with_memory_budget`].
+    static SPILLED_ROWS: RefCell<Option<Arc<AtomicU64>>> = const { RefCell::new(None) };
+}
+
+/// The number of bytes the memory intensive nodes of a query may hold in
+/// memory together before they start spilling to disk. It is computed once
+/// when the query is built and split evenly over those nodes.
+///
+/// Defaults to half of the memory that is available when the query is built,
+/// can be overridden with `POLARS_STREAMING_MEMORY_BUDGET` (in bytes).
+pub fn memory_budget() -> usize {
+    if let Some(budget) = MEMORY_BUDGET_OVERRIDE.get() {
+        return budget;
+    }
+
+    let default = || MEMINFO.free() as usize / 2;
+    match std::env::var("POLARS_STREAMING_MEMORY_BUDGET") {
+        Ok(sz) => sz.parse::<usize>().unwrap_or_else(|_| {
+            polars_warn!(
+                "POLARS_STREAMING_MEMORY_BUDGET must be a number of bytes, got {:?}; using the default memory budget",
+                sz
+            );
+            default()
+        }),
+        Err(_) => default(),
+    }
+}
+
+/// Runs `f` with the memory budget of the queries it builds on this thread
+/// set to `budget` bytes, e.g. to force spilling in tests. Returns the output
+/// of `f` and the number of rows those queries spilled to disk.
+///
+/// `f` has to build the query on the calling thread, which `collect` does.
+pub fn with_memory_budget<R>(budget: usize, f: impl FnOnce() -> R) -> (R, u64) {
+    struct Reset(Option<usize>, Option<Arc<AtomicU64>>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            MEMORY_BUDGET_OVERRIDE.set(self.0);
+            SPILLED_ROWS.set(self.1.take());
+        }
+    }
+
+    let spilled_rows = Arc::new(AtomicU64::new(0));
+    let _reset = Reset(
+        MEMORY_BUDGET_OVERRIDE.replace(Some(budget)),
+        SPILLED_ROWS.replace(Some(spilled_rows.clone())),
+    );
+    let out = f();
+    (out, spilled_rows.load(Ordering::Relaxed))
+}
+
+/// A directory on local disk that a node spills its data to.
+///
+/// The directory is created on the first spill and removed with everything in
+/// it when this is dropped.
+pub struct SpillDir {
+    operation_name: &'static str,
+    path: Mutex<Option<PathBuf>>,
+    file_count: AtomicU64,
+    spilled_rows: Option<Arc<AtomicU64>>,
+}
+
+impl SpillDir {
+    pub fn new(operation_name: &'static str) -> Self {
+        Self {
+            operation_name,
+            path: Mutex::new(None),
+            file_count: AtomicU64::new(0),
+            spilled_rows: SPILLED_ROWS.with_borrow(|rows| rows.clone()),
+        }
+    }
+
+    fn path(&self) -> PolarsResult<PathBuf> {
+        let mut guard = self.path.lock();
+        if guard.is_none() {
+            let id = SPILL_DIR_COUNT.fetch_add(1, Ordering::Relaxed);
+            let path = POLARS_TEMP_DIR_BASE_PATH.join(format!(
+                "spill/{}-{}-{id}",
+                self.operation_name,
+                std::process::id()
+            ));
+            std::fs::create_dir_all(&path).map_err(|err| {
+                polars_err!(ComputeError: "failed to create spill directory {}: {err}", path.display())
+            })?;
+            if polars_core::config::verbose() {
+                eprintln!("[{}]: spilling to {}", self.operation_name, path.display());
+            }
+            *guard = Some(path);
+        }
+        Ok(guard.clone().unwrap())
+    }
+
+    /// Write `df` to a new IPC file in this directory.
+    pub fn spill(&self, df: &mut DataFrame) -> PolarsResult<SpilledDataFrame> {
+        let id = self.file_count.fetch_add(1, Ordering::Relaxed);
+        let path = self.path()?.join(format!("{id}.ipc"));
+        let file = File::create(&path)?;
+        df.align_chunks_par();
+        IpcWriter::new(file)
+            .with_compat_level(CompatLevel::newest())
+            .finish(df)?;
+        if let Some(rows) = &self.spilled_rows {
+            rows.fetch_add(df.height() as u64, Ordering::Relaxed);
+        }
+        Ok(SpilledDataFrame {
+            path,
+            height: df.height(),
+            bytes: df.estimated_size(),
+        })
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        if let Some(path) = self.path.get_mut() {
+            // The files may already be removed, which is fine.
+            let _ = std::fs::remove_dir_all(path);
+        }
+    }
+}
+
+/// A [`DataFrame`] that was written to disk by [`SpillDir::spill`].
+pub struct SpilledDataFrame {
+    path: PathBuf,
+    height: usize,
+    bytes: usize,
+}
+
+impl SpilledDataFrame {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The estimated size of the [`DataFrame`] when it is loaded.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Read the [`DataFrame`] back into memory and remove its file.
+    pub fn load(self) -> PolarsResult<DataFrame> {
+        let df = IpcReader::new(File::open(&self.path)?).finish()?;
+        let _ = std::fs::remove_file(&self.path);
+        Ok(df)
+    }
+}
+
+/// Spills rows to a [`SpillDir`] partitioned by the hash of their keys.
+///
+/// Rows are buffered per partition and written out once a morsel worth of rows
+/// is buffered in total.
+pub struct PartitionedSpiller {
+    partitioner: HashPartitioner,
+    idxs_per_p: Vec<Vec<IdxSize>>,
+    buffers_per_p: Vec<Vec<DataFrame>>,
+    buffered_rows: usize,
+    spilled_per_p: Vec<Vec<SpilledDataFrame>>,
+}
+
+impl PartitionedSpiller {
+    pub fn new(partitioner: HashPartitioner) -> Self {
+        let num_partitions = partitioner.num_partitions();
+        Self {
+            partitioner,
+            idxs_per_p: vec![Vec::new(); num_partitions],
+            buffers_per_p: vec![Vec::new(); num_partitions],
+            buffered_rows: 0,
+            spilled_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    /// Whether any rows were given to this spiller since it was last taken.
+    pub fn has_spilled(&self) -> bool {
+        self.buffered_rows > 0 || self.spilled_per_p.iter().any(|s| !s.is_empty())
+    }
+
+    /// Spills the rows of `df`, partitioned by `hash_keys`. Rows with null keys
+    /// are dropped unless `partition_nulls` is set.
+    pub fn spill(
+        &mut self,
+        df: &DataFrame,
+        hash_keys: &HashKeys,
+        partition_nulls: bool,
+        spill_dir: &SpillDir,
+    ) -> PolarsResult<()> {
+        assert!(df.height() == hash_keys.len());
+        hash_keys.gen_idxs_per_partition(
+            &self.partitioner,
+            &mut self.idxs_per_p,
+            &mut [],
+            partition_nulls,
+        );
+        for (idxs, buffer) in self.idxs_per_p.iter_mut().zip(&mut self.buffers_per_p) {
+            if !idxs.is_empty() {
+                unsafe {
+                    buffer.push(df.take_slice_unchecked_impl(idxs, false));
+                }
+                self.buffered_rows += idxs.len();
+                idxs.clear();
+            }
+        }
+
+        if self.buffered_rows >= get_ideal_morsel_size() {
+            self.flush(spill_dir)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, spill_dir: &SpillDir) -> PolarsResult<()> {
+        for (buffer, spilled) in self.buffers_per_p.iter_mut().zip(&mut self.spilled_per_p) {
+            if let Some(mut df) =
+                accumulate_dataframes_vertical_unchecked_optional(buffer.drain(..))
+            {
+                spilled.push(spill_dir.spill(&mut df)?);
+            }
+        }
+        self.buffered_rows = 0;
+        Ok(())
+    }
+
+    /// Writes out the buffered rows and takes the spilled frames per partition.
+    pub fn take_spilled(
+        &mut self,
+        spill_dir: &SpillDir,
+    ) -> PolarsResult<Vec<Vec<SpilledDataFrame>>> {
+        self.flush(spill_dir)?;
+        let num_partitions = self.partitioner.num_partitions();
+        Ok(std::mem::replace(
+            &mut self.spilled_per_p,
+            (0..num_partitions).map(|_| Vec::new()).collect(),
+        ))
+    }
+}
+
+/// Concatenates the spilled frames of each partition over multiple spillers.
+pub fn merge_spilled_partitions(
+    spilled: impl IntoIterator<Item = Vec<Vec<SpilledDataFrame>>>,
+) -> Vec<Vec<SpilledDataFrame>> {
+    let mut out: Vec<Vec<SpilledDataFrame>> = Vec::new();
+    for spilled_per_p in spilled {
+        if out.is_empty() {
+            out = spilled_per_p;
+            continue;
+        }
+        for (o, mut s) in out.iter_mut().zip(spilled_per_p) {
+            o.append(&mut s);
+        }
+    }
+    out
+}
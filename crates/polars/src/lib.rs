@@ -202,13 +202,19 @@
 //!     - `serde-lazy` - Support for [serde](https://crates.io/crates/serde) serialization and deserialization.
 //!       Can be used for JSON and more serde supported serialization formats.
 //!     - `parquet` - Read Apache Parquet format
+//!     - `parquet_encryption` - Read and write Parquet files with modular encryption
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `ipc_streaming` - Arrow's IPC stream format serialization, lazy scans and sinks
+//!     - `fwf` - Read fixed-width text files
+//!     - `lines` - Read line-oriented text files, such as logs, with a regex
+//!     - `pg_copy` - Read and write the PostgreSQL binary `COPY` format, with a streaming sink
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!     - `compress` - Gzip and zstd compressed output for the CSV and NDJSON writers.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -228,6 +228,25 @@ fn set() {
     assert_eq!(bitmap.as_slice().0[0], 0b00000000);
 }
 
+#[test]
+fn and_or_pos() {
+    let mut bitmap = MutableBitmap::from_len_set(12);
+    unsafe {
+        bitmap.and_pos_unchecked(1, true);
+        bitmap.and_pos_unchecked(2, false);
+        bitmap.and_pos_unchecked(10, false);
+    }
+    let expected = (0..12).map(|i| i != 2 && i != 10).collect::<Vec<_>>();
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), expected);
+
+    unsafe {
+        bitmap.or_pos_unchecked(2, false);
+        bitmap.or_pos_unchecked(10, true);
+    }
+    let expected = (0..12).map(|i| i != 2).collect::<Vec<_>>();
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), expected);
+}
+
 #[test]
 fn extend_from_bitmap() {
     let other = Bitmap::from(&[true, false, true]);
@@ -485,3 +485,34 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_empty_lists() -> PolarsResult<()> {
+    use polars::prelude::{IntoSeries, NamedFrom, Series, StructChunked};
+
+    let list = Series::new(
+        "list".into(),
+        [
+            Series::new("".into(), [1i32]),
+            Series::new("".into(), Vec::<i32>::new()),
+            Series::new("".into(), [2i32, 3]),
+        ],
+    );
+    let string = Series::new("string".into(), ["a", "b", "c"]);
+    let struct_ = StructChunked::from_series("struct".into(), 3, [&list, &string].into_iter())?;
+    let mut write_df = df!(
+        "list" => list.clone(),
+        "struct" => struct_.into_series(),
+        "string" => string,
+    )?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf).finish(&mut write_df)?;
+    buf.set_position(0);
+
+    let read_df = AvroReader::new(buf).finish()?;
+    assert!(write_df.equals(&read_df));
+
+    Ok(())
+}
@@ -857,6 +857,128 @@ fn test_utf8() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_non_utf8_encodings() -> PolarsResult<()> {
+    let read = |bytes: &[u8], encoding: CsvEncoding| {
+        CsvReadOptions::default()
+            .map_parse_options(|opts| opts.with_encoding(encoding))
+            .into_reader_with_file_handle(Cursor::new(bytes.to_vec()))
+            .finish()
+    };
+
+    let df = read(b"city,n\nK\xf6ln,1\nS\xe3o Paulo,2\n", CsvEncoding::Latin1)?;
+    let expected = df!("city" => ["Köln", "São Paulo"], "n" => [1i64, 2])?;
+    assert_eq!(df, expected);
+
+    let df = read(b"price\n\x8010\n", CsvEncoding::Windows1252)?;
+    assert_eq!(df, df!("price" => ["€10"])?);
+
+    let df = read(
+        b"\x96\xbc\x91\x4f,n\n\x93\xfa\x96\x7b,1\n",
+        CsvEncoding::ShiftJis,
+    )?;
+    assert_eq!(df, df!("名前" => ["日本"], "n" => [1i64])?);
+
+    let utf16 = |bom: [u8; 2], f: fn(u16) -> [u8; 2]| {
+        bom.into_iter()
+            .chain("a,b\nü,2\n".encode_utf16().flat_map(f))
+            .collect::<Vec<_>>()
+    };
+    let expected = df!("a" => ["ü"], "b" => [2i64])?;
+    let le = utf16([0xFF, 0xFE], u16::to_le_bytes);
+    let be = utf16([0xFE, 0xFF], u16::to_be_bytes);
+    assert_eq!(read(&le, CsvEncoding::Utf16Le)?, expected);
+    assert_eq!(read(&be, CsvEncoding::Utf16Be)?, expected);
+    assert_eq!(read(&le, CsvEncoding::Auto)?, expected);
+    assert_eq!(read(&be, CsvEncoding::Auto)?, expected);
+    assert_eq!(
+        read(b"\xef\xbb\xbfa,b\n\xc3\xbc,2\n", CsvEncoding::Auto)?,
+        expected
+    );
+
+    // Malformed input is an error rather than silently replaced.
+    assert!(read(b"a\n\x81\n", CsvEncoding::ShiftJis).is_err());
+
+    // Reading the first rows stops transcoding early, quoted line endings are not counted.
+    let bytes = (0..100_000).fold(b"city,n\n".to_vec(), |mut acc, i| {
+        acc.extend_from_slice(b"\"K\xf6\nln\",");
+        acc.extend_from_slice(format!("{i}\n").as_bytes());
+        acc
+    });
+    let df = CsvReadOptions::default()
+        .with_n_rows(Some(2))
+        .map_parse_options(|opts| opts.with_encoding(CsvEncoding::Latin1))
+        .into_reader_with_file_handle(Cursor::new(bytes))
+        .finish()?;
+    assert_eq!(df, df!("city" => ["Kö\nln", "Kö\nln"], "n" => [0i64, 1])?);
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_rejected_rows() -> PolarsResult<()> {
+    let mut csv = String::from("a,b\n");
+    for i in 0..50 {
+        match i {
+            7 => csv.push_str("x,7\n"),
+            20 => csv.push_str("20\n"),
+            33 => csv.push_str("33,\"multi\nline\",2\n"),
+            _ => csv.push_str(&format!("{i},\"{i}\"\n")),
+        }
+    }
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]);
+    let read = |rejected_rows: Option<RejectedRowsOptions>| {
+        CsvReadOptions::default()
+            .with_schema(Some(Arc::new(schema.clone())))
+            .with_rejected_rows(rejected_rows)
+            .into_reader_with_file_handle(Cursor::new(csv.clone()))
+            .finish()
+    };
+
+    assert!(read(None).is_err());
+
+    let rejected_path = std::env::temp_dir().join("polars_test_csv_rejected_rows");
+    // Write every rejected row as soon as it is found.
+    let df = read(Some(
+        RejectedRowsOptions::new(&rejected_path).with_max_buffered_bytes(0),
+    ))?;
+    let good = (0..50i64).filter(|i| ![7, 20, 33].contains(i));
+    let expected = df!(
+        "a" => good.clone().collect::<Vec<_>>(),
+        "b" => good.map(|i| i.to_string()).collect::<Vec<_>>(),
+    )?;
+    assert_eq!(df, expected);
+
+    let rejected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(rejected_path.join("0.csv")))?
+        .finish()?
+        .sort(["line"], SortMultipleOptions::default())?;
+    assert_eq!(
+        rejected.get_column_names_str(),
+        ["path", "line", "raw", "error"]
+    );
+    assert_eq!(rejected.column("path")?.null_count(), 3);
+    assert_eq!(
+        rejected.column("line")?.i64()?.to_vec(),
+        [Some(9), Some(22), Some(35)]
+    );
+    assert_eq!(
+        rejected.column("raw")?.str()?.iter().collect::<Vec<_>>(),
+        [Some("x,7"), Some("20"), Some("33,\"multi\nline\",2")]
+    );
+    let errors = rejected.column("error")?.str()?;
+    assert!(errors.get(0).unwrap().contains("could not parse `x`"));
+    assert_eq!(errors.get(1), Some("expected 2 fields, found 1"));
+    assert_eq!(errors.get(2), Some("expected 2 fields, found 3"));
+
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    Ok(())
+}
+
 #[test]
 fn test_header_inference() -> PolarsResult<()> {
     let csv = r#"not_a_header,really,even_if,it_looks_like_one
@@ -1282,6 +1404,82 @@ fn test_escaping_quotes() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_escape_char() -> PolarsResult<()> {
+    // Escaped separators, quotes and line endings as written by MySQL.
+    let mut csv = String::from("id,te\\,xt,n\n");
+    for i in 0..100 {
+        csv.push_str(&format!("{i},a\\,b \\\"{i}\\\" \\\nc\\\\\\td,{i}\n"));
+    }
+    csv.push_str("100,\"quoted \\\" ,\\n\",100\n");
+
+    let df = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_escape_char(Some(b'\\')))
+        .into_reader_with_file_handle(Cursor::new(csv.clone()))
+        .finish()?;
+    assert_eq!(df.get_column_names(), ["id", "te,xt", "n"]);
+    assert_eq!(df.height(), 101);
+    assert_eq!(df.column("n")?.i64()?.get(42), Some(42));
+    let text = df.column("te,xt")?.str()?;
+    assert_eq!(text.get(42), Some("a,b \"42\" \nc\\\td"));
+    assert_eq!(text.get(100), Some("quoted \" ,\n"));
+
+    let mut out = vec![];
+    for quote_style in [QuoteStyle::Necessary, QuoteStyle::Never] {
+        out.clear();
+        CsvWriter::new(&mut out)
+            .with_escape_char(Some(b'\\'))
+            .with_quote_style(quote_style)
+            .finish(&mut df.clone())?;
+        let read = CsvReadOptions::default()
+            .map_parse_options(|opts| opts.with_escape_char(Some(b'\\')))
+            .into_reader_with_file_handle(Cursor::new(&out))
+            .finish()?;
+        assert_eq!(read, df);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_byte_separator() -> PolarsResult<()> {
+    let csv = "a||b|c||d\n1||x|y||2.5\n2||\"p||q\"||\n3||||0.5\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_multi_byte_separator("||"))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    let expected = df!(
+        "a" => [1i64, 2, 3],
+        "b|c" => [Some("x|y"), Some("p||q"), None],
+        "d" => [Some(2.5), None, Some(0.5)],
+    )?;
+    assert_eq!(df, expected);
+
+    // Only the projected column is parsed, but the fields still need to be split correctly.
+    let df = CsvReadOptions::default()
+        .with_columns(Some(["d".into()].into()))
+        .map_parse_options(|opts| opts.with_multi_byte_separator("||"))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df, expected.select(["d"])?);
+
+    let mut out = vec![];
+    CsvWriter::new(&mut out)
+        .with_multi_byte_separator(Some("\x01\x02".into()))
+        .finish(&mut expected.clone())?;
+    assert_eq!(
+        out,
+        b"a\x01\x02b|c\x01\x02d\n1\x01\x02x|y\x01\x022.5\n2\x01\x02p||q\x01\x02\n3\x01\x02\x01\x020.5\n"
+    );
+    let df = CsvReadOptions::default()
+        .map_parse_options(|opts| opts.with_multi_byte_separator("\x01\x02"))
+        .into_reader_with_file_handle(Cursor::new(&out))
+        .finish()?;
+    assert_eq!(df, expected);
+
+    Ok(())
+}
+
 #[test]
 fn test_header_only() -> PolarsResult<()> {
     let csv = "a,b,c";
@@ -1403,3 +1601,45 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+fn test_write_csv_compressed() -> PolarsResult<()> {
+    // Large enough to be compressed as several blocks.
+    let n = 200_000;
+    let mut df = df!(
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("row {}", i % 1000)).collect::<Vec<_>>(),
+    )?;
+
+    for (compression, magic) in [
+        (ExternalCompression::Gzip { level: None }, [0x1f, 0x8b]),
+        (ExternalCompression::Zstd { level: Some(1) }, [0x28, 0xb5]),
+    ] {
+        let mut out = vec![];
+        CsvWriter::new(&mut out)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        assert_eq!(out[..2], magic);
+        let read = CsvReader::new(Cursor::new(&out)).finish()?;
+        assert!(read.equals(&df));
+
+        // The batched writer compresses every batch separately.
+        out.clear();
+        let mut writer = CsvWriter::new(&mut out)
+            .with_compression(compression)
+            .batched(df.schema())?;
+        writer.write_batch(&df.slice(0, 1000))?;
+        writer.write_batch(&df.slice(1000, n as usize))?;
+        writer.finish()?;
+        let read = CsvReader::new(Cursor::new(&out)).finish()?;
+        assert!(read.equals(&df));
+    }
+
+    let mut out = vec![];
+    let result = CsvWriter::new(&mut out)
+        .with_compression(ExternalCompression::Gzip { level: Some(10) })
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use std::io::Cursor;
+
+use polars::io::fwf::{FwfColumn, FwfOffsetUnit, FwfReadOptions, FwfReader};
+use polars::prelude::*;
+
+const ACCOUNTS: &str = "\
+ACCOUNT   NAME      OPENED      BALANCE
+0000000001Alice     2021-03-04   1042.50
+0000000002Bob       2019-11-30     -3.25
+0000000003          ??????????     NULL\r
+0000000004Dave
+";
+
+fn accounts_options() -> FwfReadOptions {
+    FwfReadOptions::new([
+        FwfColumn::new("account", 0, 10, DataType::Int64),
+        FwfColumn::new("name", 10, 10, DataType::String),
+        FwfColumn::new("opened", 20, 10, DataType::Date),
+        FwfColumn::new("balance", 30, 10, DataType::Float64),
+    ])
+    .with_skip_rows(1)
+    .with_null_values(Some(NullValues::AllColumns(vec![
+        "NULL".into(),
+        "??????????".into(),
+    ])))
+}
+
+fn read(data: &str, options: FwfReadOptions) -> PolarsResult<DataFrame> {
+    FwfReader::new(Cursor::new(data))
+        .with_options(options)
+        .finish()
+}
+
+#[test]
+fn test_read_fwf() -> PolarsResult<()> {
+    let df = read(ACCOUNTS, accounts_options())?;
+
+    let mut expected = df![
+        "account" => [1i64, 2, 3, 4],
+        "name" => [Some("Alice"), Some("Bob"), None, Some("Dave")],
+        "opened" => [Some("2021-03-04"), Some("2019-11-30"), None, None],
+        "balance" => [Some(1042.5), Some(-3.25), None, None],
+    ]?;
+    expected.try_apply("opened", |s| s.cast(&DataType::Date))?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_projection_and_n_rows() -> PolarsResult<()> {
+    let df = FwfReader::new(Cursor::new(ACCOUNTS))
+        .with_options(accounts_options())
+        .with_columns(Some(vec!["balance".into(), "account".into()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+
+    // Columns are returned in the order of the spec.
+    let expected = df![
+        "account" => [1i64, 2],
+        "balance" => [1042.5, -3.25],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Fields that are not projected are not parsed.
+    let df = FwfReader::new(Cursor::new("1  xx\n2  yy\n"))
+        .with_options(FwfReadOptions::new([
+            FwfColumn::new("a", 0, 3, DataType::Int32),
+            FwfColumn::new("b", 3, 2, DataType::Int32),
+        ]))
+        .with_projection(Some(vec![0]))
+        .finish()?;
+    assert!(df.equals(&df!["a" => [1i32, 2]]?));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_trim_and_time() -> PolarsResult<()> {
+    let data = "  ab  12:30:00\n c    08:00:15\n";
+    let columns = [
+        FwfColumn::new("s", 0, 6, DataType::String),
+        FwfColumn::new("t", 6, 8, DataType::Time),
+    ];
+
+    let df = read(data, FwfReadOptions::new(columns.clone()))?;
+    assert_eq!(
+        df.column("s")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        ["ab", "c"]
+    );
+    assert_eq!(df.column("t")?.dtype(), &DataType::Time);
+    assert_eq!(
+        df.column("t")?.to_physical_repr().i64()?.get(1),
+        Some(8 * 3_600_000_000_000 + 15_000_000_000)
+    );
+
+    let df = read(data, FwfReadOptions::new(columns).with_trim(false))?;
+    assert_eq!(
+        df.column("s")?
+            .str()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        ["  ab  ", " c    "]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_character_offsets() -> PolarsResult<()> {
+    let data = "Zoë  1\nAnn  2\n日本語  3\n";
+    let columns = [
+        FwfColumn::new("name", 0, 4, DataType::String),
+        FwfColumn::new("n", 5, 1, DataType::Int32),
+    ];
+
+    let df = read(
+        data,
+        FwfReadOptions::new(columns.clone()).with_offset_unit(FwfOffsetUnit::Characters),
+    )?;
+    let expected = df![
+        "name" => ["Zoë", "Ann", "日本語"],
+        "n" => [1i32, 2, 3],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Byte offsets cut through the multi-byte characters.
+    let err = read(data, FwfReadOptions::new(columns.clone())).unwrap_err();
+    assert!(err.to_string().contains("splits a multi-byte character"));
+
+    let df = read(data, FwfReadOptions::new(columns).with_ignore_errors(true))?;
+    assert_eq!(df.column("name")?.null_count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_errors() -> PolarsResult<()> {
+    let data = "12\nab\n";
+    let columns = [FwfColumn::new("a", 0, 2, DataType::Int64)];
+
+    let err = read(data, FwfReadOptions::new(columns.clone())).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("could not parse `ab` as dtype `i64`")
+    );
+
+    let df = read(data, FwfReadOptions::new(columns).with_ignore_errors(true))?;
+    assert!(df.equals_missing(&df!["a" => [Some(12i64), None]]?));
+
+    let duplicate = [
+        FwfColumn::new("a", 0, 2, DataType::Int64),
+        FwfColumn::new("a", 2, 2, DataType::Int64),
+    ];
+    assert!(read(data, FwfReadOptions::new(duplicate)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_many_chunks() -> PolarsResult<()> {
+    let n = 50_000;
+    let data = (0..n)
+        .map(|i| format!("{i:>8}{:>8.2}\n", i as f64 / 4.0))
+        .collect::<String>();
+    let options = FwfReadOptions::new([
+        FwfColumn::new("i", 0, 8, DataType::UInt32),
+        FwfColumn::new("f", 8, 8, DataType::Float64),
+    ]);
+
+    let df = read(&data, options.clone())?;
+    assert_eq!(df.height(), n);
+    assert_eq!(
+        df.column("i")?
+            .u32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (0..n as u32).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        df.column("f")?.f64()?.get(n - 1),
+        Some((n - 1) as f64 / 4.0)
+    );
+
+    assert_eq!(polars::io::fwf::count_rows(data.as_bytes(), &options), n);
+
+    Ok(())
+}
@@ -156,4 +156,28 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    fn write_batched_and_count_ipc_stream() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i32, 2, 3],
+            "b" => ["x", "y", "z"],
+        )?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut writer = IpcStreamWriter::new(&mut buf).batched(df.schema())?;
+        writer.write_batch(&df)?;
+        writer.write_batch(&df.slice(1, 2))?;
+        writer.finish()?;
+
+        buf.set_position(0);
+        assert_eq!(IpcStreamReader::new(&mut buf).count_rows()?, 5);
+
+        buf.set_position(0);
+        let actual = IpcStreamReader::new(buf).finish()?;
+        let expected = df.vstack(&df.slice(1, 2))?;
+        assert_df_eq!(actual, expected);
+
+        Ok(())
+    }
 }
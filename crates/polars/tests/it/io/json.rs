@@ -185,3 +185,190 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_rejected_rows() -> PolarsResult<()> {
+    let json = "{\"a\":1,\"b\":\"x\"}\n\
+                {\"a\":\"two\",\"b\":\"y\"}\n\
+                \n\
+                {\"a\":3,\"b\":\n\
+                {\"a\":4,\"b\":\"z\"}\n";
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]);
+
+    let rejected_path = std::env::temp_dir().join("polars_test_read_ndjson_rejected_rows");
+    let df = JsonLineReader::new(Cursor::new(json))
+        .with_schema(Arc::new(schema))
+        .with_rejected_rows(Some(RejectedRowsOptions::new(&rejected_path)))
+        .finish()?;
+    assert_eq!(df, df!("a" => [1i64, 4], "b" => ["x", "z"])?);
+
+    let rejected = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(rejected_path.join("0.csv")))?
+        .finish()?
+        .sort(["line"], SortMultipleOptions::default())?;
+    assert_eq!(rejected.column("line")?.i64()?.to_vec(), [Some(2), Some(4)]);
+    assert_eq!(
+        rejected.column("raw")?.str()?.to_vec(),
+        [Some(r#"{"a":"two","b":"y"}"#), Some(r#"{"a":3,"b":"#)]
+    );
+    assert!(
+        rejected
+            .column("error")?
+            .str()?
+            .get(1)
+            .unwrap()
+            .starts_with("error parsing line")
+    );
+
+    std::fs::remove_dir_all(&rejected_path).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn test_write_ndjson_compressed() -> PolarsResult<()> {
+    let mut df = df!(
+        "a" => (0..100_000i64).collect::<Vec<_>>(),
+        "b" => (0..100_000).map(|i| format!("row {}", i % 1000)).collect::<Vec<_>>(),
+    )?;
+
+    for compression in [
+        ExternalCompression::Gzip { level: Some(9) },
+        ExternalCompression::Zstd { level: None },
+    ] {
+        let mut out = vec![];
+        JsonWriter::new(&mut out)
+            .with_json_format(JsonFormat::JsonLines)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        // The eager NDJSON reader doesn't decompress its input.
+        let mut decompressed = vec![];
+        let bytes = polars_io::utils::compression::maybe_decompress_bytes(&out, &mut decompressed)?;
+        let read = JsonLineReader::new(Cursor::new(bytes)).finish()?;
+        assert!(read.equals(&df));
+
+        out.clear();
+        JsonWriter::new(&mut out)
+            .with_json_format(JsonFormat::Json)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        let read = JsonReader::new(Cursor::new(&out)).finish()?;
+        assert!(read.equals(&df));
+    }
+
+    Ok(())
+}
+
+const JSON_ARRAY: &str = r#" [
+  {"id": 1, "name": "a, [b]", "tags": ["x", "y"], "nested": {"k": "}"}},
+  {"id": 2, "name": "quote \" and \\", "tags": [], "nested": null} ,
+  null,
+  {"id": 4, "score": 1.5}
+]
+"#;
+
+#[test]
+fn test_json_array_splitter() -> PolarsResult<()> {
+    use polars::io::json::array::{JsonArraySplitter, count_rows};
+
+    let bytes = JSON_ARRAY.as_bytes();
+    let mut splitter = JsonArraySplitter::new(bytes)?;
+    let mut records = vec![];
+    while let Some(range) = splitter.next_record()? {
+        records.push(std::str::from_utf8(&bytes[range]).unwrap());
+    }
+    assert_eq!(
+        records,
+        [
+            r#"{"id": 1, "name": "a, [b]", "tags": ["x", "y"], "nested": {"k": "}"}}"#,
+            r#"{"id": 2, "name": "quote \" and \\", "tags": [], "nested": null}"#,
+            "null",
+            r#"{"id": 4, "score": 1.5}"#,
+        ]
+    );
+
+    // A chunk is cut after the first record that reaches the chunk size.
+    let mut splitter = JsonArraySplitter::new(bytes)?;
+    let chunk = splitter.next_chunk(80)?.unwrap();
+    assert_eq!(chunk.n_records, 2);
+    assert_eq!(splitter.next_chunk(80)?.unwrap().n_records, 2);
+    assert!(splitter.next_chunk(80)?.is_none());
+
+    assert_eq!(count_rows(bytes)?, 4);
+    assert_eq!(count_rows(b" [ ] ")?, 0);
+    assert_eq!(count_rows(b"\xEF\xBB\xBF[{}]")?, 1);
+
+    for invalid in [
+        r#"{"a": 1}"#,
+        r#"[{"a": 1},]"#,
+        r#"[{"a": 1}"#,
+        r#"[{"a": "1}]"#,
+        r#"[{"a": 1}] [{"a": 2}]"#,
+    ] {
+        assert!(count_rows(invalid.as_bytes()).is_err(), "{invalid}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_json_array_parse_chunks() -> PolarsResult<()> {
+    use polars::io::json::array::{
+        JsonArrayChunkParser, JsonArrayReadOptions, JsonArraySplitter, infer_schema,
+    };
+
+    let bytes = JSON_ARRAY.as_bytes();
+
+    // Only the first records are used for inference.
+    let options = JsonArrayReadOptions::default()
+        .with_infer_schema_length(NonZeroUsize::new(2))
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "id".into(),
+            DataType::Int32,
+        )]))));
+    let schema = infer_schema(bytes, &options)?;
+    assert_eq!(
+        schema.iter_names().cloned().collect::<Vec<_>>(),
+        ["id", "name", "tags", "nested"]
+    );
+    assert_eq!(schema.get("id"), Some(&DataType::Int32));
+    assert!(!schema.contains("score"));
+
+    let schema = infer_schema(bytes, &JsonArrayReadOptions::default())?;
+    assert_eq!(schema.get("score"), Some(&DataType::Float64));
+
+    let mut splitter = JsonArraySplitter::new(bytes)?;
+    let chunk = splitter.next_chunk(usize::MAX)?.unwrap();
+    let records = &bytes[chunk.range];
+
+    // Only the projected fields are materialized, other fields are ignored.
+    let projected = Arc::new(Schema::from_iter([
+        Field::new("id".into(), DataType::Int64),
+        Field::new("score".into(), DataType::Float64),
+    ]));
+    let parser = JsonArrayChunkParser::new(projected);
+    let df = parser.parse(records, 1, 3)?;
+    let expected = df![
+        "id" => [Some(2i64), None, Some(4)],
+        "score" => [None, None, Some(1.5f64)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    let df = parser.parse(records, 0, 1)?;
+    assert_eq!(df.shape(), (1, 2));
+
+    let df = JsonArrayChunkParser::new(Default::default()).parse(records, 0, 4)?;
+    assert_eq!(df.shape(), (4, 0));
+
+    // Records that aren't objects can't be read into the schema.
+    let parser = JsonArrayChunkParser::new(Arc::new(Schema::from_iter([Field::new(
+        "a".into(),
+        DataType::Int64,
+    )])));
+    assert!(parser.parse(br#"{"a": 1}, [1, 2]"#, 0, 2).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use std::io::Cursor;
+
+use polars::io::lines::{LinesReadOptions, LinesReader, UnmatchedLines};
+use polars::prelude::*;
+
+const ACCESS_LOG: &str = "\
+10.0.0.1 - - [04/Mar/2024:10:00:00 +0000] \"GET /index.html HTTP/1.1\" 200 1042
+10.0.0.2 - - [04/Mar/2024:10:00:01 +0000] \"POST /login HTTP/1.1\" 302 -
+garbage line\r
+10.0.0.1 - - [04/Mar/2024:10:00:05 +0000] \"GET /missing HTTP/1.1\" 404 512
+
+";
+
+const ACCESS_LOG_PATTERN: &str = r#"^(?P<host>\S+) \S+ \S+ \[[^\]]+\] "(?P<method>[A-Z]+) (?P<path>\S+) [^"]*" (?P<status>\d{3}) (?P<size>\S+)$"#;
+
+fn access_log_options() -> LinesReadOptions {
+    LinesReadOptions::new(ACCESS_LOG_PATTERN)
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+            Field::new("status".into(), DataType::UInt16),
+            Field::new("size".into(), DataType::Int64),
+        ]))))
+        .with_null_values(Some(NullValues::AllColumns(vec!["-".into()])))
+}
+
+fn read(data: &str, options: LinesReadOptions) -> PolarsResult<DataFrame> {
+    LinesReader::new(Cursor::new(data))
+        .with_options(options)
+        .finish()
+}
+
+#[test]
+fn test_read_lines() -> PolarsResult<()> {
+    let df = read(ACCESS_LOG, access_log_options())?;
+
+    let expected = df![
+        "host" => ["10.0.0.1", "10.0.0.2", "10.0.0.1"],
+        "method" => ["GET", "POST", "GET"],
+        "path" => ["/index.html", "/login", "/missing"],
+        "status" => [200u16, 302, 404],
+        "size" => [Some(1042i64), None, Some(512)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_lines_reject() -> PolarsResult<()> {
+    let options = access_log_options().with_unmatched(UnmatchedLines::Reject {
+        column: "rejected".into(),
+    });
+    let df = read(ACCESS_LOG, options.clone())?;
+
+    // Empty lines are skipped, other lines that don't match are kept.
+    assert_eq!(df.height(), 4);
+    assert_eq!(
+        df.get_column_names_str(),
+        ["host", "method", "path", "status", "size", "rejected"]
+    );
+    assert_eq!(
+        df.column("rejected")?
+            .str()?
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [None, None, Some("garbage line"), None]
+    );
+    assert_eq!(
+        df.column("status")?.u16()?.into_iter().collect::<Vec<_>>(),
+        [Some(200), Some(302), None, Some(404)]
+    );
+
+    assert_eq!(
+        polars::io::lines::count_rows(ACCESS_LOG.as_bytes(), &options)?,
+        4
+    );
+    assert_eq!(
+        polars::io::lines::count_rows(ACCESS_LOG.as_bytes(), &access_log_options())?,
+        3
+    );
+
+    let err = read(
+        ACCESS_LOG,
+        access_log_options().with_unmatched(UnmatchedLines::Reject {
+            column: "host".into(),
+        }),
+    )
+    .unwrap_err();
+    assert!(matches!(err, PolarsError::Duplicate(_)));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_lines_projection_and_n_rows() -> PolarsResult<()> {
+    let df = LinesReader::new(Cursor::new(ACCESS_LOG))
+        .with_options(access_log_options())
+        .with_columns(Some(vec!["status".into(), "host".into()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+
+    // Columns are returned in the order of the capture groups.
+    let expected = df![
+        "host" => ["10.0.0.1", "10.0.0.2"],
+        "status" => [200u16, 302],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Capture groups that are not projected are not parsed.
+    let df = LinesReader::new(Cursor::new("1 x\n2 y\n"))
+        .with_options(
+            LinesReadOptions::new(r"^(?P<a>\d+) (?P<b>\w+)$").with_schema_overwrite(Some(
+                Arc::new(Schema::from_iter([
+                    Field::new("a".into(), DataType::Int32),
+                    Field::new("b".into(), DataType::Int32),
+                ])),
+            )),
+        )
+        .with_projection(Some(vec![0]))
+        .finish()?;
+    assert!(df.equals(&df!["a" => [1i32, 2]]?));
+
+    // An empty projection still drops the lines that don't match.
+    let df = LinesReader::new(Cursor::new(ACCESS_LOG))
+        .with_options(access_log_options())
+        .with_projection(Some(vec![]))
+        .finish()?;
+    assert_eq!(df.shape(), (3, 0));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_lines_temporal_and_optional_groups() -> PolarsResult<()> {
+    let data = "2024-03-04 10:00:00 INFO started\n2024-03-04 10:00:02 WARN\n";
+    let options =
+        LinesReadOptions::new(r"^(?P<ts>\S+ \S+) (?P<level>[A-Z]+)(?: (?P<message>.*))?$")
+            .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+                "ts".into(),
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            )]))));
+
+    let df = read(data, options)?;
+    assert_eq!(
+        df.column("ts")?.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(
+        df.column("ts")?.to_physical_repr().i64()?.get(1),
+        Some(1_709_546_402_000_000)
+    );
+    assert_eq!(
+        df.column("message")?.str()?.into_iter().collect::<Vec<_>>(),
+        [Some("started"), None]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_read_lines_invalid_pattern() {
+    let err = read(ACCESS_LOG, LinesReadOptions::new(r"^(\S+) (\S+)")).unwrap_err();
+    assert!(err.to_string().contains("no named capture groups"));
+
+    assert!(read(ACCESS_LOG, LinesReadOptions::new(r"^(?P<a>\S+")).is_err());
+
+    let options = LinesReadOptions::new(r"^(?P<a>\S+)").with_schema_overwrite(Some(Arc::new(
+        Schema::from_iter([Field::new("b".into(), DataType::Int32)]),
+    )));
+    assert!(matches!(
+        read(ACCESS_LOG, options),
+        Err(PolarsError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_read_lines_many_chunks() -> PolarsResult<()> {
+    let n = 50_000;
+    let data = (0..n)
+        .map(|i| {
+            if i % 10 == 0 {
+                "# comment\n".to_string()
+            } else {
+                format!("id={i} value={}\n", i as f64 / 4.0)
+            }
+        })
+        .collect::<String>();
+    let options = LinesReadOptions::new(r"^id=(?P<id>\d+) value=(?P<value>\S+)$")
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([
+            Field::new("id".into(), DataType::UInt32),
+            Field::new("value".into(), DataType::Float64),
+        ]))));
+
+    let df = read(&data, options)?;
+    assert_eq!(df.height(), n - n / 10);
+    assert_eq!(
+        df.column("id")?
+            .u32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (0..n as u32).filter(|i| i % 10 != 0).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        df.column("value")?.f64()?.get(df.height() - 1),
+        Some((n - 1) as f64 / 4.0)
+    );
+
+    Ok(())
+}
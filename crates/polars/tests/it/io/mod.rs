@@ -9,10 +9,18 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(feature = "fwf")]
+mod fwf;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(feature = "lines")]
+mod lines;
+#[cfg(feature = "orc")]
+mod orc;
+#[cfg(feature = "pg_copy")]
+mod pg_copy;
 
 use polars::prelude::*;
 
@@ -0,0 +1,178 @@
+use std::io::Cursor;
+
+use polars::io::orc::{OrcCompression, OrcReader, OrcWriter};
+use polars::prelude::*;
+
+use super::create_df;
+
+fn roundtrip(
+    df: &mut DataFrame,
+    writer: impl FnOnce(OrcWriter<&mut Cursor<Vec<u8>>>) -> OrcWriter<&mut Cursor<Vec<u8>>>,
+) -> DataFrame {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    writer(OrcWriter::new(&mut buf)).finish(df).unwrap();
+    buf.set_position(0);
+    OrcReader::new(buf).finish().unwrap()
+}
+
+#[test]
+fn write_and_read_orc() {
+    let mut df = create_df();
+    let out = roundtrip(&mut df, |w| w);
+    assert!(df.equals(&out));
+}
+
+#[test]
+fn test_orc_roundtrip_dtypes() {
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true)],
+        "i8" => [Some(-128i8), Some(0), None, Some(127)],
+        "i16" => [Some(-3i16), None, Some(300), Some(i16::MAX)],
+        "i32" => [Some(i32::MIN), Some(0), Some(7), None],
+        "i64" => [Some(i64::MIN), Some(i64::MAX), None, Some(-1)],
+        "f32" => [Some(1.5f32), None, Some(f32::NAN), Some(-0.25)],
+        "f64" => [Some(0.1), Some(f64::INFINITY), Some(-2.5), None],
+        "str" => [Some("a"), None, Some(""), Some("a somewhat longer string value")],
+        "bin" => [Some(b"\x00\x01".as_slice()), Some(b"".as_slice()), None, Some(b"xyz".as_slice())],
+    ]
+    .unwrap();
+    df.with_column(
+        Column::new("date".into(), [Some(-1i32), Some(0), None, Some(19_000)])
+            .cast(&DataType::Date)
+            .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Column::new(
+            "datetime".into(),
+            [Some(-1_500i64), Some(0), Some(1_700_000_000_123), None],
+        )
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+        .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Column::new("decimal".into(), [Some(12345i128), None, Some(-1), Some(0)])
+            .cast(&DataType::Int128)
+            .unwrap()
+            .cast(&DataType::Decimal(Some(10), Some(0)))
+            .unwrap()
+            .cast(&DataType::Decimal(Some(12), Some(2)))
+            .unwrap(),
+    )
+    .unwrap();
+
+    // ORC timestamps always have nanosecond precision.
+    let mut expected = df.clone();
+    expected
+        .with_column(
+            df.column("datetime")
+                .unwrap()
+                .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+                .unwrap(),
+        )
+        .unwrap();
+
+    for compression in [
+        OrcCompression::Uncompressed,
+        OrcCompression::Zlib,
+        OrcCompression::Snappy,
+        OrcCompression::Lz4,
+        OrcCompression::Zstd,
+    ] {
+        let out = roundtrip(&mut df, |w| w.with_compression(compression));
+        assert_eq!(out.schema(), expected.schema());
+        assert!(out.equals_missing(&expected), "{compression:?}");
+    }
+}
+
+#[test]
+fn test_orc_roundtrip_nested() {
+    let list = Column::new(
+        "list".into(),
+        [
+            Some(Series::new("".into(), [1i64, 2, 3])),
+            None,
+            Some(Series::new("".into(), Vec::<i64>::new())),
+            Some(Series::new("".into(), [4i64])),
+        ],
+    );
+    let a = Series::new("a".into(), [Some(1i32), None, Some(3), Some(4)]);
+    let b = Series::new("b".into(), [Some("x"), Some("y"), None, Some("z")]);
+    let st = StructChunked::from_series("st".into(), 4, [a, b].iter())
+        .unwrap()
+        .into_series();
+    let mut df = DataFrame::new(vec![list, st.into()]).unwrap();
+
+    let out = roundtrip(&mut df, |w| w);
+    assert_eq!(out.schema(), df.schema());
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_orc_stripes_and_n_rows() {
+    let mut df = df!("a" => (0..1000).collect::<Vec<i64>>()).unwrap();
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    OrcWriter::new(&mut buf)
+        .with_stripe_size(Some(100))
+        .finish(&mut df)
+        .unwrap();
+
+    buf.set_position(0);
+    let out = OrcReader::new(&mut buf).finish().unwrap();
+    assert!(out.equals(&df));
+
+    buf.set_position(0);
+    let out = OrcReader::new(&mut buf)
+        .with_n_rows(Some(150))
+        .finish()
+        .unwrap();
+    assert!(out.equals(&df.head(Some(150))));
+}
+
+#[test]
+fn test_orc_projection() {
+    let mut df = df!("a" => [1, 2, 3], "b" => [2, 3, 4], "c" => [3, 4, 5]).unwrap();
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    OrcWriter::new(&mut buf).finish(&mut df).unwrap();
+
+    buf.set_position(0);
+    let out = OrcReader::new(&mut buf)
+        .with_projection(Some(vec![1, 2]))
+        .finish()
+        .unwrap();
+    assert!(out.equals(&df.select(["b", "c"]).unwrap()));
+
+    buf.set_position(0);
+    let out = OrcReader::new(&mut buf)
+        .with_columns(Some(vec!["c".to_string(), "a".to_string()]))
+        .finish()
+        .unwrap();
+    assert!(out.equals(&df.select(["c", "a"]).unwrap()));
+}
+
+#[test]
+fn test_orc_writable_casts() {
+    let mut df = df![
+        "u8" => [0u8, 255],
+        "u32" => [0u32, u32::MAX],
+        "cat" => ["a", "b"],
+    ]
+    .unwrap();
+    df.with_column(
+        df.column("cat")
+            .unwrap()
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap(),
+    )
+    .unwrap();
+
+    let out = roundtrip(&mut df, |w| w);
+    let expected = df![
+        "u8" => [0i16, 255],
+        "u32" => [0i64, u32::MAX as i64],
+        "cat" => ["a", "b"],
+    ]
+    .unwrap();
+    assert!(out.equals(&expected));
+}
@@ -399,6 +399,50 @@ fn utf8_required_v2_delta() -> PolarsResult<()> {
     )
 }
 
+#[test]
+fn utf8_optional_v2_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "nullable",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn utf8_required_v1_delta_byte_array() -> PolarsResult<()> {
+    round_trip(
+        "string",
+        "required",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::DeltaByteArray],
+    )
+}
+
+#[test]
+fn float64_optional_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "float64",
+        "nullable",
+        Version::V1,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
+#[test]
+fn int64_required_byte_stream_split() -> PolarsResult<()> {
+    round_trip(
+        "int64",
+        "required",
+        Version::V2,
+        CompressionOptions::Uncompressed,
+        vec![Encoding::ByteStreamSplit],
+    )
+}
+
 #[test]
 fn struct_v1() -> PolarsResult<()> {
     round_trip(
@@ -0,0 +1,118 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::array::{Int64Array, Utf8ViewArray};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+use arrow::record_batch::RecordBatchT;
+use polars::prelude::*;
+use polars_parquet::arrow::write::{FileWriter, WriteOptions};
+use polars_parquet::parquet::bloom_filter::{hash_native, insert};
+use polars_parquet::parquet::write::write_metadata_sidecar;
+use polars_parquet::read::read_metadata;
+use polars_parquet::write::{
+    CompressionOptions, Encoding, RowGroupIterator, StatisticsOptions, Version,
+};
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+/// Writes one row group per `(a, b)` pair, where `a` gets a bloom filter and `b` is repeated for
+/// every row.
+fn write_with_bloom_filters(row_groups: &[(&[i64], &str)]) -> PolarsResult<Vec<u8>> {
+    let schema = ArrowSchema::from_iter([
+        Field::new("a".into(), ArrowDataType::Int64, true),
+        Field::new("b".into(), ArrowDataType::Utf8View, true),
+    ]);
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_page_size: None,
+    };
+
+    let iter = row_groups.iter().map(|(a, b)| {
+        RecordBatchT::try_new(
+            a.len(),
+            Arc::new(schema.clone()),
+            vec![
+                Int64Array::from_slice(a).boxed(),
+                Utf8ViewArray::from_slice_values(vec![*b; a.len()]).boxed(),
+            ],
+        )
+    });
+    let encodings = vec![vec![Encoding::Plain], vec![Encoding::Plain]];
+    let iter = RowGroupIterator::try_new(iter, &schema, options, encodings)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    for group in iter {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    let (data, mut metadata) = writer.into_inner_and_metadata();
+    let mut data = data.into_inner();
+
+    // Drop the footer, append the bloom filters and write the footer again with their offsets.
+    let footer_start = data.len() - 8;
+    let metadata_len = i32::from_le_bytes(data[footer_start..footer_start + 4].try_into().unwrap());
+    data.truncate(footer_start - metadata_len as usize);
+
+    for ((a, _), row_group) in row_groups.iter().zip(&mut metadata.row_groups) {
+        let mut bitset = vec![0; 32];
+        for v in a.iter() {
+            insert(&mut bitset, hash_native(*v));
+        }
+
+        let header = BloomFilterHeader::new(
+            bitset.len() as i32,
+            BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+            BloomFilterHash::XXHASH(XxHash {}),
+            BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+        );
+        let offset = data.len() as i64;
+        header
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut data))
+            .unwrap();
+        data.extend_from_slice(&bitset);
+
+        let column = row_group.columns[0].meta_data.as_mut().unwrap();
+        column.bloom_filter_offset = Some(offset);
+    }
+
+    let mut footer = vec![];
+    write_metadata_sidecar(&mut footer, &metadata)?;
+    // The sidecar starts with the magic bytes, which are already at the start of `data`.
+    data.extend_from_slice(&footer[4..]);
+    Ok(data)
+}
+
+#[test]
+fn bloom_filter_skips_row_groups() -> PolarsResult<()> {
+    // Both row groups span the same range, so statistics cannot skip either of them.
+    let row_groups: [(&[i64], &str); 2] =
+        [(&[0, 2, 4, 6, 8, 100], "x"), (&[1, 3, 5, 7, 9, 100], "y")];
+    let data = write_with_bloom_filters(&row_groups)?;
+
+    // Overwrite the column chunks of the second row group, so the scan only succeeds if the
+    // bloom filters skip it without reading it.
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    let mut corrupted = data.clone();
+    for range in metadata.row_groups[1].byte_ranges_iter() {
+        corrupted[range.start as usize..range.end as usize].fill(0xFF);
+    }
+
+    let path = std::env::temp_dir().join("polars_test_bloom_filter_skips_row_groups.parquet");
+    std::fs::write(&path, &corrupted)?;
+
+    let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+    let out = lf.clone().filter(col("a").eq(lit(4i64))).collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("x"));
+    assert_eq!(out.height(), 1);
+    // Without the bloom filter the corrupted row group has to be read.
+    assert!(lf.filter(col("a").eq(lit(5i64))).collect().is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
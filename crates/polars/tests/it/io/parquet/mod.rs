@@ -1,5 +1,7 @@
 #![forbid(unsafe_code)]
 mod arrow;
+#[cfg(feature = "lazy")]
+mod bloom_filter;
 pub(crate) mod read;
 mod roundtrip;
 mod write;
@@ -206,3 +208,245 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_parquet_column_options() -> PolarsResult<()> {
+    use polars::io::parquet::write::{ParquetColumnOptions, ParquetCompression, ParquetEncoding};
+    use polars_parquet::parquet::compression::Compression;
+    use polars_parquet::parquet::encoding::Encoding;
+    use polars_parquet::read::read_metadata;
+
+    let mut df = df! {
+        "sensor" => (0..1000).map(|i| i as f64 / 7.0).collect::<Vec<_>>(),
+        "blob" => (0..1000).map(|i| format!("blob-{}", i % 3)).collect::<Vec<_>>(),
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+    }?;
+
+    let column_options = vec![
+        (
+            "sensor".into(),
+            ParquetColumnOptions {
+                encoding: Some(ParquetEncoding::ByteStreamSplit),
+                ..Default::default()
+            },
+        ),
+        (
+            "blob".into(),
+            ParquetColumnOptions {
+                compression: Some(ParquetCompression::Uncompressed),
+                dictionary: Some(false),
+                ..Default::default()
+            },
+        ),
+        (
+            "id".into(),
+            ParquetColumnOptions {
+                encoding: Some(ParquetEncoding::DeltaBinaryPacked),
+                ..Default::default()
+            },
+        ),
+    ];
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_compression(ParquetCompression::Snappy)
+        .with_column_options(column_options)
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = read_metadata(&mut buf)?;
+    let encodings = |name: &str| -> Vec<Encoding> {
+        let column = metadata.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap();
+        column
+            .column_encoding()
+            .iter()
+            .map(|e| (*e).try_into().unwrap())
+            .collect()
+    };
+    let compression = |name: &str| {
+        let column = metadata.row_groups[0]
+            .columns_under_root_iter(name)
+            .unwrap()
+            .next()
+            .unwrap();
+        column.compression()
+    };
+    assert!(encodings("sensor").contains(&Encoding::ByteStreamSplit));
+    assert_eq!(compression("sensor"), Compression::Snappy);
+    assert!(!encodings("blob").contains(&Encoding::RleDictionary));
+    assert_eq!(compression("blob"), Compression::Uncompressed);
+    assert!(encodings("id").contains(&Encoding::DeltaBinaryPacked));
+
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    // String columns cannot be byte stream split.
+    let column_options = vec![(
+        "blob".into(),
+        ParquetColumnOptions {
+            encoding: Some(ParquetEncoding::ByteStreamSplit),
+            ..Default::default()
+        },
+    )];
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_column_options(column_options)
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_key_value_and_field_metadata() -> PolarsResult<()> {
+    use polars::io::parquet::metadata::{field_metadata, key_value_metadata};
+
+    let mut df = df! {
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    }?;
+
+    let field_md: ::arrow::datatypes::Metadata =
+        [("contract".into(), "c-42".into())].into_iter().collect();
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_key_value_metadata(vec![
+            ("source".into(), "crm".into()),
+            ("pipeline_version".into(), "1.2.0".into()),
+        ])
+        .with_field_metadata(vec![("b".into(), field_md.clone())])
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = ParquetReader::new(&mut buf).get_metadata()?.clone();
+    assert_eq!(
+        key_value_metadata(&metadata),
+        vec![
+            ("source".into(), Some("crm".into())),
+            ("pipeline_version".into(), Some("1.2.0".into())),
+        ]
+    );
+    assert_eq!(field_metadata(&metadata)?, vec![("b".into(), field_md)]);
+
+    buf.set_position(0);
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    // The key of the embedded Arrow schema is reserved.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_key_value_metadata(vec![("ARROW:schema".into(), "".into())])
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_parquet_write_field_ids() -> PolarsResult<()> {
+    use polars::io::parquet::field_id::{ParquetFieldId, field_ids_from_parquet_schema};
+
+    let x = Series::new("x".into(), [1i32, 2]);
+    let y = Series::new("y".into(), ["a", "b"]);
+    let s = StructChunked::from_series("s".into(), 2, [x, y].iter())?.into_series();
+    let mut df = DataFrame::new(vec![
+        Column::new("a".into(), [1i64, 2]),
+        s.into(),
+        Column::new("b".into(), [1.0f64, 2.0]),
+    ])?;
+
+    // Only `x` of the struct gets a field id, `b` gets none.
+    let field_ids = vec![
+        ParquetFieldId::new("a", 1),
+        ParquetFieldId::new("s", 2).with_fields(vec![ParquetFieldId::new("x", 3)]),
+    ];
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_field_ids(field_ids.clone())
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = ParquetReader::new(&mut buf).get_metadata()?.clone();
+    assert_eq!(
+        field_ids_from_parquet_schema(metadata.schema().fields()),
+        field_ids
+    );
+
+    buf.set_position(0);
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    // Field ids can only be set for existing columns and struct fields.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_field_ids(vec![
+            ParquetFieldId::new("s", 2).with_fields(vec![ParquetFieldId::new("z", 3)]),
+        ])
+        .finish(&mut df);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet_encryption")]
+fn test_parquet_encryption() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::{ParquetEncryptionOptions, ParquetKeyRetriever};
+
+    let key_retriever = ParquetKeyRetriever::from_func(|key_metadata| match key_metadata {
+        b"footer-key" => Ok(vec![1; 16]),
+        b"column-key" => Ok(vec![2; 32]),
+        _ => polars_bail!(ComputeError: "unknown key"),
+    });
+
+    let mut df = df! {
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name-{}", i % 7)).collect::<Vec<_>>(),
+        "score" => (0..1000).map(|i| (i % 3 != 0).then_some(i as f64)).collect::<Vec<_>>(),
+    }?;
+
+    let write = |df: &mut DataFrame, encryption: ParquetEncryptionOptions| {
+        let mut buf = Cursor::new(Vec::new());
+        ParquetWriter::new(&mut buf)
+            .with_row_group_size(Some(300))
+            .with_data_page_size(Some(1024))
+            .with_encryption(Some(encryption))
+            .finish(df)?;
+        buf.set_position(0);
+        PolarsResult::Ok(buf)
+    };
+
+    // All columns encrypted with the footer key.
+    let encryption = ParquetEncryptionOptions::new(b"footer-key".to_vec(), key_retriever.clone());
+    let buf = write(&mut df, encryption)?;
+    assert_eq!(&buf.get_ref()[buf.get_ref().len() - 4..], b"PARE");
+    let read_df = ParquetReader::new(buf.clone())
+        .with_key_retriever(Some(key_retriever.clone()))
+        .finish()?;
+    assert!(df.equals_missing(&read_df));
+
+    // The file cannot be read without the keys.
+    assert!(ParquetReader::new(buf).finish().is_err());
+
+    // A column encrypted with its own key.
+    let encryption = ParquetEncryptionOptions::new(b"footer-key".to_vec(), key_retriever.clone())
+        .with_column_key("name".into(), b"column-key".to_vec());
+    let buf = write(&mut df, encryption)?;
+    let read_df = ParquetReader::new(buf.clone())
+        .with_key_retriever(Some(key_retriever))
+        .finish()?;
+    assert!(df.equals_missing(&read_df));
+
+    // A wrong key fails to decrypt.
+    let wrong_key_retriever = ParquetKeyRetriever::from_func(|_| Ok(vec![3; 16]));
+    let result = ParquetReader::new(buf)
+        .with_key_retriever(Some(wrong_key_retriever))
+        .finish();
+    assert!(result.is_err());
+
+    Ok(())
+}
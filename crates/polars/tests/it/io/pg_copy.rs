@@ -0,0 +1,254 @@
+use std::io::Cursor;
+
+use polars::io::pg_copy::{PgCopyReader, PgCopyWriter, PgType};
+use polars::prelude::*;
+
+fn write(df: &mut DataFrame, pg_types: Vec<(PlSmallStr, PgType)>) -> Vec<u8> {
+    let mut buf = vec![];
+    PgCopyWriter::new(&mut buf)
+        .with_pg_types(pg_types)
+        .finish(df)
+        .unwrap();
+    buf
+}
+
+fn read(
+    bytes: Vec<u8>,
+    schema: &Schema,
+    pg_types: Vec<(PlSmallStr, PgType)>,
+) -> PolarsResult<DataFrame> {
+    PgCopyReader::new(Cursor::new(bytes))
+        .with_schema(Arc::new(schema.clone()))
+        .with_pg_types(pg_types)
+        .finish()
+}
+
+fn roundtrip(df: &mut DataFrame, pg_types: Vec<(PlSmallStr, PgType)>) -> DataFrame {
+    let schema = df.schema().clone();
+    let bytes = write(df, pg_types.clone());
+    read(bytes, &schema, pg_types).unwrap()
+}
+
+#[test]
+fn test_pg_copy_roundtrip_dtypes() {
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true)],
+        "i16" => [Some(-3i16), None, Some(300), Some(i16::MAX)],
+        "i32" => [Some(i32::MIN), Some(0), Some(7), None],
+        "i64" => [Some(i64::MIN), Some(i64::MAX), None, Some(-1)],
+        "f32" => [Some(1.5f32), None, Some(f32::NAN), Some(-0.25)],
+        "f64" => [Some(0.1), Some(f64::INFINITY), Some(-2.5), None],
+        "str" => [Some("a"), None, Some(""), Some("a somewhat longer string value")],
+        "bin" => [Some(b"\x00\x01".as_slice()), Some(b"".as_slice()), None, Some(b"xyz".as_slice())],
+    ]
+    .unwrap();
+    df.with_column(
+        Column::new("date".into(), [Some(-1i32), Some(0), None, Some(19_737)])
+            .cast(&DataType::Date)
+            .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Column::new(
+            "time".into(),
+            [Some(0i64), None, Some(45_296_789_012_000), Some(1_000)],
+        )
+        .cast(&DataType::Time)
+        .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Column::new(
+            "datetime".into(),
+            [Some(-1_500i64), Some(0), Some(1_700_000_000_123_456), None],
+        )
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+        .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Column::new(
+            "duration".into(),
+            [None, Some(-1i64), Some(90_000_000_000), Some(1)],
+        )
+        .cast(&DataType::Duration(TimeUnit::Microseconds))
+        .unwrap(),
+    )
+    .unwrap();
+    df.with_column(
+        Int128Chunked::from_slice_options(
+            "decimal".into(),
+            &[Some(123_456_789), None, Some(-1), Some(0)],
+        )
+        .into_decimal_unchecked(Some(12), 5)
+        .into_column(),
+    )
+    .unwrap();
+
+    let out = roundtrip(&mut df, vec![]);
+    assert_eq!(out.schema(), df.schema());
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_pg_copy_roundtrip_arrays() {
+    let ints = Column::new(
+        "ints".into(),
+        [
+            Some(Series::new("".into(), [Some(1i64), None, Some(3)])),
+            None,
+            Some(Series::new("".into(), Vec::<i64>::new())),
+            Some(Series::new("".into(), [4i64])),
+        ],
+    );
+    let strs = Column::new(
+        "strs".into(),
+        [
+            None,
+            Some(Series::new("".into(), ["x", "y"])),
+            Some(Series::new("".into(), [None, Some("z")])),
+            Some(Series::new("".into(), Vec::<&str>::new())),
+        ],
+    );
+    let mut df = DataFrame::new(vec![ints, strs]).unwrap();
+
+    let out = roundtrip(&mut df, vec![]);
+    assert_eq!(out.schema(), df.schema());
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_pg_copy_pg_types() {
+    let mut df = df![
+        "id" => [Some("123e4567-e89b-12d3-a456-426614174000"), None, Some("00000000000000000000000000000001")],
+        "doc" => [Some(r#"{"a": 1}"#), Some("[]"), None],
+        "meta" => [None, Some("null"), Some(r#""text""#)],
+    ]
+    .unwrap();
+    let pg_types = vec![
+        ("id".into(), PgType::Uuid),
+        ("doc".into(), PgType::Jsonb),
+        ("meta".into(), PgType::Json),
+    ];
+
+    let out = roundtrip(&mut df, pg_types);
+    let expected = df![
+        "id" => [Some("123e4567-e89b-12d3-a456-426614174000"), None, Some("00000000-0000-0000-0000-000000000001")],
+        "doc" => [Some(r#"{"a": 1}"#), Some("[]"), None],
+        "meta" => [None, Some("null"), Some(r#""text""#)],
+    ]
+    .unwrap();
+    assert!(out.equals_missing(&expected));
+
+    let mut df = df!["id" => ["not a uuid"]].unwrap();
+    let mut buf = vec![];
+    let result = PgCopyWriter::new(&mut buf)
+        .with_pg_types(vec![("id".into(), PgType::Uuid)])
+        .finish(&mut df);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_pg_copy_schema_mapping() {
+    let mut df = df![
+        "a" => [Some(1i32), None, Some(-3)],
+        "b" => [Some(u64::MAX), Some(0), None],
+    ]
+    .unwrap();
+    let bytes = write(&mut df, vec![]);
+
+    // Values are read as their Postgres type and cast to the datatype of the schema.
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::UInt64),
+    ]);
+    let out = read(bytes.clone(), &schema, vec![("a".into(), PgType::Int4)]).unwrap();
+    let expected = df![
+        "a" => [Some(1i64), None, Some(-3)],
+        "b" => [Some(u64::MAX), Some(0), None],
+    ]
+    .unwrap();
+    assert!(out.equals_missing(&expected));
+
+    // Without the override `a` is read as an `int8`, which doesn't match the data.
+    assert!(read(bytes.clone(), &schema, vec![]).is_err());
+    // Overrides must name a column of the schema.
+    assert!(read(bytes.clone(), &schema, vec![("c".into(), PgType::Int4)]).is_err());
+    // The format doesn't store the types of the columns.
+    assert!(PgCopyReader::new(Cursor::new(bytes)).finish().is_err());
+}
+
+#[test]
+fn test_pg_copy_read_fixture() {
+    let mut bytes = b"PGCOPY\n\xff\r\n\0".to_vec();
+    // Flags with OIDs and a header extension of 2 bytes.
+    bytes.extend_from_slice(&(1i32 << 16).to_be_bytes());
+    bytes.extend_from_slice(&2i32.to_be_bytes());
+    bytes.extend_from_slice(&[0xAB, 0xCD]);
+
+    // A tuple of an OID, the numeric -12.345 and the date 2024-01-15.
+    bytes.extend_from_slice(&2i16.to_be_bytes());
+    bytes.extend_from_slice(&4i32.to_be_bytes());
+    bytes.extend_from_slice(&16384u32.to_be_bytes());
+    bytes.extend_from_slice(&12i32.to_be_bytes());
+    for v in [2i16, 0, 0x4000, 3, 12, 3450] {
+        bytes.extend_from_slice(&(v as u16).to_be_bytes());
+    }
+    bytes.extend_from_slice(&4i32.to_be_bytes());
+    bytes.extend_from_slice(&8780i32.to_be_bytes());
+
+    // A tuple of an OID and two nulls.
+    bytes.extend_from_slice(&2i16.to_be_bytes());
+    bytes.extend_from_slice(&4i32.to_be_bytes());
+    bytes.extend_from_slice(&16385u32.to_be_bytes());
+    bytes.extend_from_slice(&(-1i32).to_be_bytes());
+    bytes.extend_from_slice(&(-1i32).to_be_bytes());
+
+    bytes.extend_from_slice(&(-1i16).to_be_bytes());
+
+    let schema = Schema::from_iter([
+        Field::new("n".into(), DataType::Decimal(Some(10), Some(2))),
+        Field::new("d".into(), DataType::Date),
+    ]);
+    let out = read(bytes.clone(), &schema, vec![]).unwrap();
+
+    // The numeric is rounded half away from zero to the scale of the decimal.
+    let mut expected = DataFrame::new(vec![
+        Int128Chunked::from_slice_options("n".into(), &[Some(-1235), None])
+            .into_decimal_unchecked(Some(10), 2)
+            .into_column(),
+    ])
+    .unwrap();
+    expected
+        .with_column(
+            Column::new("d".into(), [Some(19_737i32), None])
+                .cast(&DataType::Date)
+                .unwrap(),
+        )
+        .unwrap();
+    assert!(out.equals_missing(&expected));
+
+    let out = PgCopyReader::new(Cursor::new(bytes))
+        .with_schema(Arc::new(schema))
+        .with_columns(Some(vec!["d".into()]))
+        .with_n_rows(Some(1))
+        .finish()
+        .unwrap();
+    assert!(out.equals(&expected.select(["d"]).unwrap().head(Some(1))));
+}
+
+#[test]
+fn test_pg_copy_write_layout() {
+    let mut df = df!["a" => [Some(1i16), None]].unwrap();
+    let bytes = write(&mut df, vec![]);
+
+    let mut expected = b"PGCOPY\n\xff\r\n\0".to_vec();
+    expected.extend_from_slice(&[0; 8]);
+    expected.extend_from_slice(&1i16.to_be_bytes());
+    expected.extend_from_slice(&2i32.to_be_bytes());
+    expected.extend_from_slice(&1i16.to_be_bytes());
+    expected.extend_from_slice(&1i16.to_be_bytes());
+    expected.extend_from_slice(&(-1i32).to_be_bytes());
+    expected.extend_from_slice(&(-1i16).to_be_bytes());
+    assert_eq!(bytes, expected);
+}